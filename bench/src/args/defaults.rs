@@ -13,6 +13,9 @@ pub const DEFAULT_QUIC_SERVER_NAME: &str = "localhost";
 pub const DEFAULT_QUIC_VALIDATE_CERTIFICATE: bool = false;
 pub const DEFAULT_QUIC_START_STREAM_ID: NonZeroU32 = u32!(2000000);
 
+pub const DEFAULT_UNIX_SOCKET_PATH: &str = "/tmp/iggy.sock";
+pub const DEFAULT_UNIX_START_STREAM_ID: NonZeroU32 = u32!(4000000);
+
 pub const DEFAULT_DISABLE_PARALLEL_PRODUCER_STREAMS: bool = false;
 pub const DEFAULT_DISABLE_PARALLEL_CONSUMER_STREAMS: bool = false;
 
@@ -9,6 +9,8 @@ pub enum BenchmarkTransportCommand {
     Http(HttpArgs),
     Tcp(TcpArgs),
     Quic(QuicArgs),
+    #[cfg(unix)]
+    Unix(UnixArgs),
 }
 
 impl BenchmarkTransportProps for BenchmarkTransportCommand {
@@ -37,6 +39,8 @@ impl BenchmarkTransportProps for BenchmarkTransportCommand {
             BenchmarkTransportCommand::Http(args) => args,
             BenchmarkTransportCommand::Tcp(args) => args,
             BenchmarkTransportCommand::Quic(args) => args,
+            #[cfg(unix)]
+            BenchmarkTransportCommand::Unix(args) => args,
         }
     }
 }
@@ -107,6 +111,41 @@ impl BenchmarkTransportProps for TcpArgs {
     }
 }
 
+#[cfg(unix)]
+#[derive(Parser, Debug)]
+pub struct UnixArgs {
+    /// Path of the Unix domain socket of the iggy-server
+    #[arg(long, default_value_t = DEFAULT_UNIX_SOCKET_PATH.to_owned())]
+    pub socket_path: String,
+
+    /// Start stream id
+    #[arg(long, default_value_t = DEFAULT_UNIX_START_STREAM_ID)]
+    pub start_stream_id: NonZeroU32,
+}
+
+#[cfg(unix)]
+impl BenchmarkTransportProps for UnixArgs {
+    fn transport(&self) -> &Transport {
+        &Transport::Unix
+    }
+
+    fn server_address(&self) -> &str {
+        &self.socket_path
+    }
+
+    fn start_stream_id(&self) -> u32 {
+        self.start_stream_id.get()
+    }
+
+    fn validate_certificate(&self) -> bool {
+        panic!("Cannot validate certificate for Unix domain socket transport!")
+    }
+
+    fn client_address(&self) -> &str {
+        panic!("Setting client address for Unix domain socket transport is not supported!")
+    }
+}
+
 #[derive(Parser, Debug)]
 pub struct QuicArgs {
     /// Address to which the QUIC client will bind
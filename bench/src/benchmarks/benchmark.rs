@@ -13,6 +13,7 @@ use iggy::client::{StreamClient, TopicClient};
 use iggy::clients::client::{IggyClient, IggyClientBackgroundConfig};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use integration::test_server::{login_root, ClientFactory};
 use std::{pin::Pin, sync::Arc};
@@ -91,6 +92,8 @@ pub trait Benchmarkable {
                         None,
                         IggyExpiry::NeverExpire,
                         None,
+                        None,
+                        TopicConfigOverrides::default(),
                     )
                     .await?;
             }
@@ -1,14 +1,18 @@
 use crate::args::common::IggyBenchArgs;
+use iggy::http::messages_format::MessagesFormat;
 use integration::http_client::HttpClientFactory;
 use integration::quic_client::QuicClientFactory;
 use integration::tcp_client::TcpClientFactory;
 use integration::test_server::{ClientFactory, Transport};
+#[cfg(unix)]
+use integration::unix_client::UnixClientFactory;
 use std::sync::Arc;
 
 pub fn create_client_factory(args: &IggyBenchArgs) -> Arc<dyn ClientFactory> {
     match &args.transport() {
         Transport::Http => Arc::new(HttpClientFactory {
             server_addr: args.server_address().to_owned(),
+            messages_format: MessagesFormat::default(),
         }),
         Transport::Tcp => Arc::new(TcpClientFactory {
             server_addr: args.server_address().to_owned(),
@@ -16,5 +20,9 @@ pub fn create_client_factory(args: &IggyBenchArgs) -> Arc<dyn ClientFactory> {
         Transport::Quic => Arc::new(QuicClientFactory {
             server_addr: args.server_address().to_owned(),
         }),
+        #[cfg(unix)]
+        Transport::Unix => Arc::new(UnixClientFactory {
+            socket_path: args.server_address().to_owned(),
+        }),
     }
 }
@@ -11,6 +11,7 @@ struct ServerConfig {
     http: ConfigAddress,
     tcp: ConfigAddress,
     quic: ConfigAddress,
+    unix: ConfigPath,
 }
 
 #[derive(Debug, Deserialize)]
@@ -18,6 +19,11 @@ struct ConfigAddress {
     address: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct ConfigPath {
+    path: String,
+}
+
 pub async fn start_server_if_needed(args: &mut IggyBenchArgs) -> Option<TestServer> {
     if args.skip_server_start {
         info!("Skipping iggy-server start");
@@ -80,6 +86,24 @@ pub async fn start_server_if_needed(args: &mut IggyBenchArgs) -> Option<TestServ
                 envs,
             )
         }
+        #[cfg(unix)]
+        Transport::Unix => {
+            let envs = HashMap::from([
+                ("IGGY_UNIX_ENABLED".to_owned(), "true".to_owned()),
+                (
+                    "IGGY_UNIX_PATH".to_owned(),
+                    default_config.unix.path.to_owned(),
+                ),
+                ("IGGY_HTTP_ENABLED".to_owned(), "false".to_owned()),
+                ("IGGY_QUIC_ENABLED".to_owned(), "false".to_owned()),
+            ]);
+
+            (
+                args.server_address() == default_config.unix.path
+                    && !is_unix_socket_in_use(args.server_address()).await,
+                envs,
+            )
+        }
     };
 
     if should_start {
@@ -132,6 +156,11 @@ async fn is_tcp_addr_in_use(addr: &SocketAddr) -> bool {
     TcpStream::connect(addr).await.is_ok()
 }
 
+#[cfg(unix)]
+async fn is_unix_socket_in_use(path: &str) -> bool {
+    tokio::net::UnixStream::connect(path).await.is_ok()
+}
+
 async fn is_udp_addr_in_use(addr: &SocketAddr) -> bool {
     UdpSocket::bind(addr).await.is_err()
 }
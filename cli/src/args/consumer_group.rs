@@ -1,5 +1,5 @@
 use crate::args::common::ListMode;
-use clap::{Args, Subcommand};
+use clap::{ArgGroup, Args, Subcommand};
 use iggy::identifier::Identifier;
 
 #[derive(Debug, Clone, Subcommand)]
@@ -63,6 +63,19 @@ pub(crate) enum ConsumerGroupAction {
     ///  iggy consumer-group list production sensor -l table
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(ConsumerGroupListArgs),
+    /// Reset offsets of consumer group with given ID for all partitions of given stream ID and topic ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Consumer group ID can be specified as a consumer group name or ID
+    ///
+    /// Examples:
+    ///  iggy consumer-group reset-offsets 1 2 3 --to-earliest
+    ///  iggy consumer-group reset-offsets stream topic group --to-latest
+    ///  iggy consumer-group reset-offsets stream topic group --to-offset 100
+    ///  iggy consumer-group reset-offsets stream topic group --shift-by -10 --dry-run
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    ResetOffsets(ConsumerGroupResetOffsetsArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -138,3 +151,53 @@ pub(crate) struct ConsumerGroupListArgs {
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
 }
+
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("reset_strategy").required(true))]
+pub(crate) struct ConsumerGroupResetOffsetsArgs {
+    /// Stream ID to reset consumer group offsets
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to reset consumer group offsets
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Consumer group ID to reset offsets
+    ///
+    /// Consumer group ID can be specified as a consumer group name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) group_id: Identifier,
+    /// Continue from the first message in each partition
+    #[clap(short = 'e', long, default_value_t = false, group = "reset_strategy")]
+    pub(crate) to_earliest: bool,
+    /// Continue from the messages sent after the reset
+    #[clap(short = 'l', long, default_value_t = false, group = "reset_strategy")]
+    pub(crate) to_latest: bool,
+    /// Continue from the messages with the given timestamp
+    ///
+    /// Timestamp must be specified as a number
+    /// of microseconds since the Unix epoch
+    #[clap(verbatim_doc_comment)]
+    #[clap(short = 't', long, group = "reset_strategy")]
+    pub(crate) to_timestamp: Option<u64>,
+    /// Continue from the message with the given offset
+    #[clap(short = 'o', long, group = "reset_strategy")]
+    pub(crate) to_offset: Option<u64>,
+    /// Shift the current position by the given number of messages
+    ///
+    /// Negative value moves the position backward
+    #[clap(verbatim_doc_comment)]
+    #[clap(
+        short = 's',
+        long,
+        allow_negative_numbers = true,
+        group = "reset_strategy"
+    )]
+    pub(crate) shift_by: Option<i64>,
+    /// Only print the planned offsets without storing them
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) dry_run: bool,
+}
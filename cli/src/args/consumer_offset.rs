@@ -36,6 +36,18 @@ pub(crate) enum ConsumerOffsetAction {
     ///  iggy consumer-offset set consumer stream topic 1 100
     #[clap(verbatim_doc_comment, visible_alias = "s")]
     Set(ConsumerOffsetSetArgs),
+    /// Retrieve the lag of a consumer or consumer group for all partitions of a topic
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples:
+    ///  iggy consumer-offset lag 1 3 5
+    ///  iggy consumer-offset lag consumer stream topic
+    ///  iggy consumer-offset lag --consumer-group group stream topic
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    Lag(ConsumerOffsetLagArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -85,3 +97,26 @@ pub(crate) struct ConsumerOffsetSetArgs {
     /// Offset to set
     pub(crate) offset: u64,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ConsumerOffsetLagArgs {
+    /// Consumer or consumer group for which the lag is retrieved
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    #[clap(verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer_id: Identifier,
+    /// Stream ID for which consumer lag is retrieved
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID for which consumer lag is retrieved
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Treat the consumer ID as a consumer group ID
+    #[arg(short = 'g', long, default_value_t = false)]
+    pub(crate) consumer_group: bool,
+}
@@ -1,8 +1,12 @@
-use clap::{ArgGroup, Args, Subcommand};
+use clap::{ArgGroup, Args, Subcommand, ValueEnum};
+use iggy::cli::message::messages_file::MessagesFileFormat;
+use iggy::cli::message::tail_messages::TailMessagesOutput;
 use iggy::error::IggyError;
 use iggy::error::IggyError::InvalidFormat;
 use iggy::identifier::Identifier;
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::utils::duration::IggyDuration;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Subcommand)]
@@ -31,6 +35,41 @@ pub(crate) enum MessageAction {
     ///  iggy message poll --offset 0 stream topic 1
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Poll(PollMessagesArgs),
+    /// Continuously print new messages from given topic ID and given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Messages from all partitions are printed unless partition ID is given
+    ///
+    /// Examples:
+    ///  iggy message tail 1 2
+    ///  iggy message tail --partition-id 1 --first stream topic
+    ///  iggy message tail --timestamp 1694968446131680 stream topic
+    ///  iggy message tail --output json stream topic
+    #[clap(verbatim_doc_comment, visible_alias = "t")]
+    Tail(TailMessagesArgs),
+    /// Export messages from given partition to a file
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples:
+    ///  iggy message export 1 2 1 messages.jsonl
+    ///  iggy message export --start-offset 100 --end-offset 199 stream topic 1 messages.jsonl
+    ///  iggy message export --format binary stream topic 1 messages.bin
+    #[clap(verbatim_doc_comment, visible_alias = "e")]
+    Export(ExportMessagesArgs),
+    /// Import messages from a file to given topic ID and given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples:
+    ///  iggy message import 1 2 messages.jsonl
+    ///  iggy message import --partition-id 1 stream topic messages.jsonl
+    ///  iggy message import --format binary stream topic messages.bin
+    #[clap(verbatim_doc_comment, visible_alias = "i")]
+    Import(ImportMessagesArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -150,6 +189,177 @@ pub(crate) struct PollMessagesArgs {
     #[clap(verbatim_doc_comment)]
     #[clap(short, long, default_value_t = false)]
     pub(crate) show_headers: bool,
+
+    /// Include the messages key in the output
+    ///
+    /// Flag indicates whether to include the messages key,
+    /// used to route the message to the partition, in the output.
+    #[clap(verbatim_doc_comment)]
+    #[clap(short = 'k', long, default_value_t = false)]
+    pub(crate) show_key: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum MessageOutput {
+    Raw,
+    Hex,
+    Base64,
+    Json,
+}
+
+impl From<MessageOutput> for TailMessagesOutput {
+    fn from(output: MessageOutput) -> Self {
+        match output {
+            MessageOutput::Raw => TailMessagesOutput::Raw,
+            MessageOutput::Hex => TailMessagesOutput::Hex,
+            MessageOutput::Base64 => TailMessagesOutput::Base64,
+            MessageOutput::Json => TailMessagesOutput::Json,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum FileFormat {
+    JsonLines,
+    Binary,
+}
+
+impl From<FileFormat> for MessagesFileFormat {
+    fn from(format: FileFormat) -> Self {
+        match format {
+            FileFormat::JsonLines => MessagesFileFormat::JsonLines,
+            FileFormat::Binary => MessagesFileFormat::Binary,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("start_position"))]
+pub(crate) struct TailMessagesArgs {
+    /// ID of the stream from which messages will be tailed
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// ID of the topic from which messages will be tailed
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID from which messages will be tailed
+    ///
+    /// If not specified, messages from all partitions are tailed
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: Option<u32>,
+    /// Start from the first message in the partition
+    ///
+    /// By default, only the messages sent after starting
+    /// the command are printed
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = false, group = "start_position")]
+    pub(crate) first: bool,
+    /// Start from the messages with the given timestamp
+    ///
+    /// Timestamp must be specified as a number
+    /// of microseconds since the Unix epoch
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, group = "start_position")]
+    pub(crate) timestamp: Option<u64>,
+    /// Maximum number of messages polled at once from a partition
+    #[clap(short, long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+    /// Interval between polls when there are no new messages
+    #[clap(short, long, default_value = "500ms")]
+    pub(crate) interval: IggyDuration,
+    /// Stop after printing the given number of messages
+    #[clap(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub(crate) limit: Option<u64>,
+    /// Format in which the messages are printed
+    ///
+    /// JSON output contains also the offset, timestamp,
+    /// ID and headers of the message
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_enum, default_value_t = MessageOutput::Raw)]
+    pub(crate) output: MessageOutput,
+    /// Regular consumer which will poll messages
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = Identifier::default(), value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ExportMessagesArgs {
+    /// ID of the stream from which messages will be exported
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// ID of the topic from which messages will be exported
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID from which messages will be exported
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Path to the file to which messages will be exported
+    pub(crate) file: PathBuf,
+    /// Offset of the first exported message
+    #[clap(short, long, default_value_t = 0)]
+    pub(crate) start_offset: u64,
+    /// Offset of the last exported message (inclusive)
+    ///
+    /// If not specified, all messages available
+    /// at the time of the export are exported
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long)]
+    pub(crate) end_offset: Option<u64>,
+    /// Maximum number of messages polled at once
+    #[clap(short, long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+    /// Format of the file
+    #[clap(short, long, value_enum, default_value_t = FileFormat::JsonLines)]
+    pub(crate) format: FileFormat,
+    /// Regular consumer which will poll messages
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = Identifier::default(), value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer: Identifier,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ImportMessagesArgs {
+    /// ID of the stream to which messages will be imported
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// ID of the topic to which messages will be imported
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Path to the file from which messages will be imported
+    pub(crate) file: PathBuf,
+    /// ID of the partition to which messages will be imported
+    #[clap(short, long, group = "partitioning")]
+    pub(crate) partition_id: Option<u32>,
+    /// Messages key which will be used to partition the messages
+    ///
+    /// Value of the key will be used by the server to calculate the partition ID
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, group = "partitioning")]
+    pub(crate) message_key: Option<String>,
+    /// Maximum number of messages sent at once
+    #[clap(short, long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+    /// Format of the file
+    #[clap(short, long, value_enum, default_value_t = FileFormat::JsonLines)]
+    pub(crate) format: FileFormat,
 }
 
 #[cfg(test)]
@@ -29,6 +29,47 @@ pub(crate) enum PartitionAction {
     ///  iggy partition delete 1 sensor 16
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(PartitionDeleteArgs),
+    /// Get offsets corresponding to the given timestamp
+    /// for the partitions of the specified topic ID and stream ID.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition offsets 1 1 1718000000000000
+    ///  iggy partition offsets prod sensor 1718000000000000 -p 1 -p 2
+    #[clap(verbatim_doc_comment, visible_alias = "o")]
+    Offsets(PartitionOffsetsArgs),
+    /// Delete messages before the given offset
+    /// in the partition of the specified topic ID and stream ID.
+    ///
+    /// The offset becomes the new low watermark of the partition,
+    /// earlier messages are no longer returned when polling
+    /// and segments containing only such messages are deleted.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition truncate 1 1 1 100
+    ///  iggy partition truncate prod sensor 2 1000
+    #[clap(verbatim_doc_comment, visible_alias = "t")]
+    Truncate(PartitionTruncateArgs),
+    /// Report which messages keys are moved to other partitions
+    /// after adding the given count of partitions
+    /// to the specified topic ID and stream ID.
+    ///
+    /// The keys are mapped using the partitioning scheme of the topic,
+    /// no partitions are created by this command.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition moves 1 1 2 user-1 user-2 user-3
+    ///  iggy partition moves prod sensor 1 device-1 device-2
+    #[clap(verbatim_doc_comment, visible_alias = "m")]
+    Moves(PartitionMovesArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -64,3 +105,68 @@ pub(crate) struct PartitionDeleteArgs {
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) partitions_count: u32,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionOffsetsArgs {
+    /// Stream ID to get partition offsets
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to get partition offsets
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Timestamp for which the offsets are resolved
+    ///
+    /// Timestamp must be specified as a number
+    /// of microseconds since the Unix epoch
+    #[clap(verbatim_doc_comment)]
+    pub(crate) timestamp: u64,
+    /// Partition ID for which the offsets are resolved
+    ///
+    /// Can be specified multiple times, all partitions by default
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionTruncateArgs {
+    /// Stream ID of the truncated partition
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID of the truncated partition
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to truncate
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Offset before which all messages are deleted
+    pub(crate) offset: u64,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionMovesArgs {
+    /// Stream ID of the topic
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to which the partitions would be added
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partitions count which would be added
+    #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
+    pub(crate) partitions_count: u32,
+    /// Messages keys to check
+    #[arg(required = true)]
+    pub(crate) keys: Vec<String>,
+}
@@ -1,4 +1,5 @@
 use crate::args::common::ListMode;
+use crate::args::topic::TopicConfigOverridesArgs;
 use clap::{Args, Subcommand};
 use iggy::identifier::Identifier;
 
@@ -29,6 +30,7 @@ pub(crate) enum StreamAction {
     /// Examples:
     ///  iggy stream update 1 production
     ///  iggy stream update test development
+    ///  iggy stream update test development --segment-size 100MB
     #[clap(verbatim_doc_comment, visible_alias = "u")]
     Update(StreamUpdateArgs),
     /// Get details of a single stream with given ID
@@ -85,6 +87,8 @@ pub(crate) struct StreamUpdateArgs {
     pub(crate) stream_id: Identifier,
     /// New name for the stream
     pub(crate) name: String,
+    #[command(flatten)]
+    pub(crate) topic_config_overrides: TopicConfigOverridesArgs,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -2,8 +2,13 @@ use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::durability::Durability;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::partitioning_scheme::PartitioningScheme;
+use iggy::utils::timestamp_type::TimestampType;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum TopicAction {
@@ -17,6 +22,7 @@ pub(crate) enum TopicAction {
     ///  iggy topic create prod sensor2 2 none
     ///  iggy topic create test debugs 2 gzip 1day 1hour 1min 1sec
     ///  iggy topic create -t 3 1 sensor3 2 none unlimited
+    ///  iggy topic create prod sensor4 2 none --segment-size 100MB --enforce-fsync true
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(TopicCreateArgs),
     /// Delete topic with given ID in given stream ID
@@ -42,6 +48,7 @@ pub(crate) enum TopicAction {
     ///  iggy update test debugs ready gzip 15days
     ///  iggy update 1 1 new-name gzip
     ///  iggy update 1 2 new-name none 1day 1hour 1min 1sec
+    ///  iggy update 1 2 new-name none --cache-indexes false
     #[clap(verbatim_doc_comment, visible_alias = "u")]
     Update(TopicUpdateArgs),
     /// Get topic detail for given topic ID and stream ID
@@ -106,6 +113,14 @@ pub(crate) struct TopicCreateArgs {
     /// Replication factor for the topic
     #[arg(short, long, default_value = "1")]
     pub(crate) replication_factor: u8,
+    /// Durability level for the topic
+    ///
+    /// One of: none, interval, group_commit or every_batch
+    /// (skipping parameter uses the default durability level configured on the server)
+    #[arg(long, value_parser = clap::value_parser!(Durability), verbatim_doc_comment)]
+    pub(crate) durability: Option<Durability>,
+    #[command(flatten)]
+    pub(crate) config_overrides: TopicConfigOverridesArgs,
     /// Message expiry time in human-readable format like 15days 2min 2s
     ///
     /// ("unlimited" or skipping parameter disables message expiry functionality in topic)
@@ -113,6 +128,79 @@ pub(crate) struct TopicCreateArgs {
     pub(crate) message_expiry: Vec<IggyExpiry>,
 }
 
+/// Settings of the topic overriding the server configuration,
+/// the skipped ones are inherited from the stream defaults or the server configuration.
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicConfigOverridesArgs {
+    /// Maximum size of the segment, after which a new one is created
+    #[arg(long)]
+    pub(crate) segment_size: Option<IggyByteSize>,
+    /// Whether every write to the segment files is followed by fsync
+    #[arg(long)]
+    pub(crate) enforce_fsync: Option<bool>,
+    /// Number of buffered messages, which triggers writing them to disk
+    #[arg(long)]
+    pub(crate) messages_required_to_save: Option<u32>,
+    /// Whether the checksums of the messages are validated when loading the segments
+    #[arg(long)]
+    pub(crate) validate_checksum: Option<bool>,
+    /// Whether the indexes of the segments are kept in memory
+    #[arg(long)]
+    pub(crate) cache_indexes: Option<bool>,
+    /// Whether the time indexes of the segments are kept in memory
+    #[arg(long)]
+    pub(crate) cache_time_indexes: Option<bool>,
+    /// Whether the messages with the already seen IDs are dropped
+    #[arg(long)]
+    pub(crate) message_deduplication: Option<bool>,
+    /// Maximum number of message IDs tracked by the deduplication, 0 means unlimited
+    #[arg(long)]
+    pub(crate) message_deduplication_max_entries: Option<u64>,
+    /// Expiry of the message IDs tracked by the deduplication in human-readable format like 1h 15min
+    #[arg(long)]
+    pub(crate) message_deduplication_expiry: Option<IggyDuration>,
+    /// Maximum age of the segment in human-readable format like 1day 12h, after which a new one is created
+    #[arg(long)]
+    pub(crate) segment_max_age: Option<IggyDuration>,
+    /// Maximum number of messages in the segment, after which a new one is created, 0 means unlimited
+    #[arg(long)]
+    pub(crate) segment_max_messages: Option<u64>,
+    /// Type of the timestamp stored with the messages and used by the time index
+    ///
+    /// One of: log_append_time or create_time (the event timestamp set by the producer)
+    #[arg(long, value_parser = clap::value_parser!(TimestampType), verbatim_doc_comment)]
+    pub(crate) timestamp_type: Option<TimestampType>,
+    /// Maximum difference between the event timestamp set by the producer and the server time, 0 means unlimited
+    #[arg(long)]
+    pub(crate) max_timestamp_skew: Option<IggyDuration>,
+    /// Scheme mapping the messages key to the partition, it can be only set when the topic is created
+    ///
+    /// One of: modulo or jump_hash (keeps most of the keys in place when partitions are added)
+    #[arg(long, value_parser = clap::value_parser!(PartitioningScheme), verbatim_doc_comment)]
+    pub(crate) partitioning_scheme: Option<PartitioningScheme>,
+}
+
+impl From<&TopicConfigOverridesArgs> for TopicConfigOverrides {
+    fn from(args: &TopicConfigOverridesArgs) -> Self {
+        TopicConfigOverrides {
+            segment_size: args.segment_size,
+            enforce_fsync: args.enforce_fsync,
+            messages_required_to_save: args.messages_required_to_save,
+            validate_checksum: args.validate_checksum,
+            cache_indexes: args.cache_indexes,
+            cache_time_indexes: args.cache_time_indexes,
+            message_deduplication: args.message_deduplication,
+            message_deduplication_max_entries: args.message_deduplication_max_entries,
+            message_deduplication_expiry: args.message_deduplication_expiry,
+            segment_max_age: args.segment_max_age,
+            segment_max_messages: args.segment_max_messages,
+            timestamp_type: args.timestamp_type,
+            max_timestamp_skew: args.max_timestamp_skew,
+            partitioning_scheme: args.partitioning_scheme,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct TopicDeleteArgs {
     /// Stream ID to delete topic
@@ -153,6 +241,14 @@ pub(crate) struct TopicUpdateArgs {
     #[arg(short, long, default_value = "1")]
     /// New replication factor for the topic
     pub(crate) replication_factor: u8,
+    /// New durability level for the topic
+    ///
+    /// One of: none, interval, group_commit or every_batch
+    /// (skipping parameter keeps the current durability level of the topic)
+    #[arg(long, value_parser = clap::value_parser!(Durability), verbatim_doc_comment)]
+    pub(crate) durability: Option<Durability>,
+    #[command(flatten)]
+    pub(crate) config_overrides: TopicConfigOverridesArgs,
     /// New message expiry time in human-readable format like 15days 2min 2s
     ///
     /// ("unlimited" or skipping parameter causes removal of expiry parameter in topic)
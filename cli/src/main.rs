@@ -28,13 +28,23 @@ use iggy::cli::{
         create_consumer_group::CreateConsumerGroupCmd,
         delete_consumer_group::DeleteConsumerGroupCmd, get_consumer_group::GetConsumerGroupCmd,
         get_consumer_groups::GetConsumerGroupsCmd,
+        reset_consumer_group_offsets::ResetConsumerGroupOffsetsCmd,
     },
     consumer_offset::{
-        get_consumer_offset::GetConsumerOffsetCmd, set_consumer_offset::SetConsumerOffsetCmd,
+        get_consumer_lag::GetConsumerLagCmd, get_consumer_offset::GetConsumerOffsetCmd,
+        set_consumer_offset::SetConsumerOffsetCmd,
     },
     context::get_contexts::GetContextsCmd,
-    message::{poll_messages::PollMessagesCmd, send_messages::SendMessagesCmd},
-    partitions::{create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd},
+    message::{
+        export_messages::ExportMessagesCmd, import_messages::ImportMessagesCmd,
+        poll_messages::PollMessagesCmd, send_messages::SendMessagesCmd,
+        tail_messages::TailMessagesCmd,
+    },
+    partitions::{
+        create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd,
+        get_moved_keys::GetMovedKeysCmd, get_offsets_for_timestamp::GetOffsetsForTimestampCmd,
+        truncate_partition::TruncatePartitionCmd,
+    },
     personal_access_tokens::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
@@ -82,6 +92,7 @@ fn get_command(
             StreamAction::Update(args) => Box::new(UpdateStreamCmd::new(
                 args.stream_id.clone(),
                 args.name.clone(),
+                (&args.topic_config_overrides).into(),
             )),
             StreamAction::Get(args) => Box::new(GetStreamCmd::new(args.stream_id.clone())),
             StreamAction::List(args) => Box::new(GetStreamsCmd::new(args.list_mode.into())),
@@ -97,6 +108,8 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.durability,
+                (&args.config_overrides).into(),
             )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
@@ -110,6 +123,8 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.durability,
+                (&args.config_overrides).into(),
             )),
             TopicAction::Get(args) => Box::new(GetTopicCmd::new(
                 args.stream_id.clone(),
@@ -135,6 +150,24 @@ fn get_command(
                 args.topic_id.clone(),
                 args.partitions_count,
             )),
+            PartitionAction::Offsets(args) => Box::new(GetOffsetsForTimestampCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.timestamp,
+                args.partition_id.clone(),
+            )),
+            PartitionAction::Truncate(args) => Box::new(TruncatePartitionCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id,
+                args.offset,
+            )),
+            PartitionAction::Moves(args) => Box::new(GetMovedKeysCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partitions_count,
+                args.keys.clone(),
+            )),
         },
         Command::Ping(args) => Box::new(PingCmd::new(args.count)),
         Command::Me => Box::new(GetMeCmd::new()),
@@ -225,6 +258,19 @@ fn get_command(
                 list_args.topic_id.clone(),
                 list_args.list_mode.into(),
             )),
+            ConsumerGroupAction::ResetOffsets(reset_args) => {
+                Box::new(ResetConsumerGroupOffsetsCmd::new(
+                    reset_args.stream_id.clone(),
+                    reset_args.topic_id.clone(),
+                    reset_args.group_id.clone(),
+                    reset_args.to_earliest,
+                    reset_args.to_latest,
+                    reset_args.to_timestamp,
+                    reset_args.to_offset,
+                    reset_args.shift_by,
+                    reset_args.dry_run,
+                ))
+            }
         },
         Command::Message(command) => match command {
             MessageAction::Send(send_args) => Box::new(SendMessagesCmd::new(
@@ -247,6 +293,39 @@ fn get_command(
                 poll_args.next,
                 poll_args.consumer.clone(),
                 poll_args.show_headers,
+                poll_args.show_key,
+            )),
+            MessageAction::Tail(tail_args) => Box::new(TailMessagesCmd::new(
+                tail_args.stream_id.clone(),
+                tail_args.topic_id.clone(),
+                tail_args.partition_id,
+                tail_args.consumer.clone(),
+                tail_args.first,
+                tail_args.timestamp,
+                tail_args.batch_size,
+                tail_args.interval,
+                tail_args.limit,
+                tail_args.output.into(),
+            )),
+            MessageAction::Export(export_args) => Box::new(ExportMessagesCmd::new(
+                export_args.stream_id.clone(),
+                export_args.topic_id.clone(),
+                export_args.partition_id,
+                export_args.consumer.clone(),
+                export_args.start_offset,
+                export_args.end_offset,
+                export_args.batch_size,
+                export_args.file.clone(),
+                export_args.format.into(),
+            )),
+            MessageAction::Import(import_args) => Box::new(ImportMessagesCmd::new(
+                import_args.stream_id.clone(),
+                import_args.topic_id.clone(),
+                import_args.partition_id,
+                import_args.message_key.clone(),
+                import_args.batch_size,
+                import_args.file.clone(),
+                import_args.format.into(),
             )),
         },
         Command::ConsumerOffset(command) => match command {
@@ -263,6 +342,12 @@ fn get_command(
                 set_args.partition_id,
                 set_args.offset,
             )),
+            ConsumerOffsetAction::Lag(lag_args) => Box::new(GetConsumerLagCmd::new(
+                lag_args.consumer_id.clone(),
+                lag_args.stream_id.clone(),
+                lag_args.topic_id.clone(),
+                lag_args.consumer_group,
+            )),
         },
         Command::Context(command) => match command {
             ContextAction::List(list_args) => {
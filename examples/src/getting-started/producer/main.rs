@@ -3,6 +3,7 @@ use iggy::clients::builder::IggyClientBuilder;
 use iggy::clients::client::IggyClient;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::users::defaults::*;
 use iggy::utils::expiry::IggyExpiry;
 use std::env;
@@ -54,6 +55,8 @@ async fn init_system(client: &IggyClient) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
     {
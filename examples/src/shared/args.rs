@@ -107,6 +107,33 @@ pub struct Args {
 
     #[arg(long, default_value = "false")]
     pub quic_validate_certificate: bool,
+
+    #[arg(long, default_value = "127.0.0.1:8070")]
+    pub grpc_server_address: String,
+
+    #[arg(long, default_value = "3")]
+    pub grpc_reconnection_retries: u32,
+
+    #[arg(long, default_value = "1000")]
+    pub grpc_reconnection_interval: u64,
+
+    #[arg(long, default_value = "false")]
+    pub grpc_tls_enabled: bool,
+
+    #[arg(long, default_value = "localhost")]
+    pub grpc_tls_domain: String,
+
+    #[arg(long, default_value = "")]
+    pub grpc_tls_ca_file: String,
+
+    #[arg(long, default_value = "/tmp/iggy.sock")]
+    pub unix_socket_path: String,
+
+    #[arg(long, default_value = "3")]
+    pub unix_reconnection_retries: u32,
+
+    #[arg(long, default_value = "1000")]
+    pub unix_reconnection_interval: u64,
 }
 
 impl Args {
@@ -135,6 +162,15 @@ impl Args {
             quic_keep_alive_interval: self.quic_keep_alive_interval,
             quic_max_idle_timeout: self.quic_max_idle_timeout,
             quic_validate_certificate: self.quic_validate_certificate,
+            grpc_server_address: self.grpc_server_address.clone(),
+            grpc_reconnection_retries: self.grpc_reconnection_retries,
+            grpc_reconnection_interval: self.grpc_reconnection_interval,
+            grpc_tls_enabled: self.grpc_tls_enabled,
+            grpc_tls_domain: self.grpc_tls_domain.clone(),
+            grpc_tls_ca_file: self.grpc_tls_ca_file.clone(),
+            unix_socket_path: self.unix_socket_path.clone(),
+            unix_reconnection_retries: self.unix_reconnection_retries,
+            unix_reconnection_interval: self.unix_reconnection_interval,
         }
     }
 }
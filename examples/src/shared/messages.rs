@@ -34,6 +34,15 @@ impl Envelope {
     }
 }
 
+// The tagged enum can be used instead of the envelope to send the different types of messages to the same topic using the typed producer and consumer.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "message_type", rename_all = "snake_case")]
+pub enum Order {
+    OrderCreated(OrderCreated),
+    OrderConfirmed(OrderConfirmed),
+    OrderRejected(OrderRejected),
+}
+
 #[derive(Deserialize, Serialize)]
 pub struct OrderCreated {
     pub order_id: u64,
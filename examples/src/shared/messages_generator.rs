@@ -1,4 +1,6 @@
-use crate::shared::messages::{OrderConfirmed, OrderCreated, OrderRejected, SerializableMessage};
+use crate::shared::messages::{
+    Order, OrderConfirmed, OrderCreated, OrderRejected, SerializableMessage,
+};
 use crate::shared::utils;
 use rand::rngs::ThreadRng;
 use rand::Rng;
@@ -20,17 +22,25 @@ impl MessagesGenerator {
     }
 
     pub fn generate(&mut self) -> Box<dyn SerializableMessage> {
+        match self.generate_order() {
+            Order::OrderCreated(order) => Box::new(order),
+            Order::OrderConfirmed(order) => Box::new(order),
+            Order::OrderRejected(order) => Box::new(order),
+        }
+    }
+
+    pub fn generate_order(&mut self) -> Order {
         match self.rng.gen_range(0..=2) {
-            0 => self.generate_order_created(),
-            1 => self.generate_order_confirmed(),
-            2 => self.generate_order_rejected(),
+            0 => Order::OrderCreated(self.generate_order_created()),
+            1 => Order::OrderConfirmed(self.generate_order_confirmed()),
+            2 => Order::OrderRejected(self.generate_order_rejected()),
             _ => panic!("Unexpected message type"),
         }
     }
 
-    fn generate_order_created(&mut self) -> Box<dyn SerializableMessage> {
+    fn generate_order_created(&mut self) -> OrderCreated {
         self.order_id += 1;
-        Box::new(OrderCreated {
+        OrderCreated {
             order_id: self.order_id,
             timestamp: utils::timestamp(),
             currency_pair: CURRENCY_PAIRS[self.rng.gen_range(0..CURRENCY_PAIRS.len())].to_string(),
@@ -41,19 +51,19 @@ impl MessagesGenerator {
                 _ => "sell",
             }
             .to_string(),
-        })
+        }
     }
 
-    fn generate_order_confirmed(&mut self) -> Box<dyn SerializableMessage> {
-        Box::new(OrderConfirmed {
+    fn generate_order_confirmed(&mut self) -> OrderConfirmed {
+        OrderConfirmed {
             order_id: self.order_id,
             timestamp: utils::timestamp(),
             price: self.rng.gen_range(10.0..=1000.0),
-        })
+        }
     }
 
-    fn generate_order_rejected(&mut self) -> Box<dyn SerializableMessage> {
-        Box::new(OrderRejected {
+    fn generate_order_rejected(&mut self) -> OrderRejected {
+        OrderRejected {
             order_id: self.order_id,
             timestamp: utils::timestamp(),
             reason: match self.rng.gen_range(0..=1) {
@@ -61,6 +71,6 @@ impl MessagesGenerator {
                 _ => "other",
             }
             .to_string(),
-        })
+        }
     }
 }
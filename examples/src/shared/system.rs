@@ -6,6 +6,7 @@ use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::models::messages::PolledMessage;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::users::defaults::*;
 use iggy::utils::expiry::IggyExpiry;
 use tracing::info;
@@ -75,6 +76,8 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Ig
             Some(args.topic_id),
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await?;
     Ok(())
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use clap::Parser;
+use iggy::client_provider;
+use iggy::client_provider::ClientProviderConfig;
+use iggy::clients::client::IggyClient;
+use iggy::clients::typed_consumer::TypedConsumer;
+use iggy::codecs::json_codec::JsonCodec;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::identifier::Identifier;
+use iggy_examples::shared::args::Args;
+use iggy_examples::shared::messages::Order;
+use iggy_examples::shared::system;
+use std::error::Error;
+use std::sync::Arc;
+use tracing::info;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    tracing_subscriber::fmt::init();
+    info!(
+        "Typed messages consumer has started, selected transport: {}",
+        args.transport
+    );
+    let client_provider_config = Arc::new(ClientProviderConfig::from_args(args.to_sdk_args())?);
+    let client = client_provider::get_raw_connected_client(client_provider_config).await?;
+    let client = IggyClient::builder().with_client(client).build()?;
+    system::login_root(&client).await;
+    system::init_by_consumer(&args, &client).await;
+    let consumer = Consumer {
+        kind: ConsumerKind::from_code(args.consumer_kind)?,
+        id: Identifier::numeric(args.consumer_id)?,
+    };
+    // The typed consumer decodes the payloads with the provided codec, the invalid messages are logged and skipped by default.
+    let mut consumer = TypedConsumer::new(
+        Arc::new(client),
+        &consumer,
+        &args.stream_id.try_into()?,
+        &args.topic_id.try_into()?,
+        JsonCodec,
+    )
+    .with_partition_id(args.partition_id)
+    .with_batch_size(args.messages_per_batch);
+    consume_messages(&args, &mut consumer).await
+}
+
+async fn consume_messages(
+    args: &Args,
+    consumer: &mut TypedConsumer<Order, JsonCodec>,
+) -> Result<(), Box<dyn Error>> {
+    info!("Messages will be polled by consumer: {} from stream: {}, topic: {}, partition: {} with interval {} ms.",
+        args.consumer_id, args.stream_id, args.topic_id, args.partition_id, args.interval);
+    let mut interval = tokio::time::interval(std::time::Duration::from_millis(args.interval));
+    let mut consumed_batches = 0;
+    loop {
+        if args.message_batches_limit > 0 && consumed_batches == args.message_batches_limit {
+            info!("Consumed {consumed_batches} batches of messages, exiting.");
+            return Ok(());
+        }
+
+        let messages = consumer.poll().await?;
+        if messages.is_empty() {
+            info!("No messages found.");
+            interval.tick().await;
+            continue;
+        }
+        consumed_batches += 1;
+        for message in messages {
+            info!("Handling order at offset: {}...", message.offset);
+            info!("{:#?}", message.value);
+        }
+        interval.tick().await;
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use clap::Parser;
+use iggy::client_provider;
+use iggy::client_provider::ClientProviderConfig;
+use iggy::clients::client::IggyClient;
+use iggy::clients::typed_producer::TypedProducer;
+use iggy::codecs::json_codec::JsonCodec;
+use iggy::messages::send_messages::Partitioning;
+use iggy_examples::shared::args::Args;
+use iggy_examples::shared::messages::Order;
+use iggy_examples::shared::messages_generator::MessagesGenerator;
+use iggy_examples::shared::system;
+use std::error::Error;
+use std::sync::Arc;
+use tracing::info;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    tracing_subscriber::fmt::init();
+    info!(
+        "Typed messages producer has started, selected transport: {}",
+        args.transport
+    );
+    let client_provider_config = Arc::new(ClientProviderConfig::from_args(args.to_sdk_args())?);
+    let client = client_provider::get_raw_connected_client(client_provider_config).await?;
+    let client = IggyClient::builder().with_client(client).build()?;
+    system::login_root(&client).await;
+    system::init_by_producer(&args, &client).await?;
+    // The typed producer encodes the values with the provided codec and sets the content type header.
+    let producer = TypedProducer::new(
+        Arc::new(client),
+        &args.stream_id.try_into()?,
+        &args.topic_id.try_into()?,
+        JsonCodec,
+    )
+    .with_partitioning(Partitioning::partition_id(args.partition_id));
+    produce_messages(&args, &producer).await
+}
+
+async fn produce_messages(
+    args: &Args,
+    producer: &TypedProducer<Order, JsonCodec>,
+) -> Result<(), Box<dyn Error>> {
+    info!(
+        "Messages will be sent to stream: {}, topic: {}, partition: {} with interval {} ms.",
+        args.stream_id, args.topic_id, args.partition_id, args.interval
+    );
+    let mut interval = tokio::time::interval(std::time::Duration::from_millis(args.interval));
+    let mut message_generator = MessagesGenerator::new();
+    let mut sent_batches = 0;
+    loop {
+        if args.message_batches_limit > 0 && sent_batches == args.message_batches_limit {
+            info!("Sent {sent_batches} batches of messages, exiting.");
+            return Ok(());
+        }
+
+        // You can send the different message types to the same partition, as the enum variant is encoded with the payload.
+        let orders = (0..args.messages_per_batch)
+            .map(|_| message_generator.generate_order())
+            .collect::<Vec<_>>();
+        producer.send(&orders).await?;
+        sent_batches += 1;
+        info!("Sent messages: {:#?}", orders);
+        interval.tick().await;
+    }
+}
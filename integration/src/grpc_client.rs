@@ -0,0 +1,39 @@
+use crate::test_server::ClientFactory;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::grpc::client::GrpcClient;
+use iggy::grpc::config::GrpcClientConfig;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct GrpcClientFactory {
+    pub server_addr: String,
+}
+
+#[async_trait]
+impl ClientFactory for GrpcClientFactory {
+    async fn create_client(&self) -> Box<dyn Client> {
+        let config = GrpcClientConfig {
+            server_address: self.server_addr.clone(),
+            ..GrpcClientConfig::default()
+        };
+        let client = GrpcClient::create(Arc::new(config)).unwrap_or_else(|e| {
+            panic!(
+                "Failed to create GrpcClient, iggy-server has address {}, error: {:?}",
+                self.server_addr, e
+            )
+        });
+        iggy::client::Client::connect(&client)
+            .await
+            .unwrap_or_else(|e| {
+                panic!(
+                    "Failed to connect to iggy-server at {}, error: {:?}",
+                    self.server_addr, e
+                )
+            });
+        Box::new(client)
+    }
+}
+
+unsafe impl Send for GrpcClientFactory {}
+unsafe impl Sync for GrpcClientFactory {}
@@ -3,11 +3,13 @@ use async_trait::async_trait;
 use iggy::client::Client;
 use iggy::http::client::HttpClient;
 use iggy::http::config::HttpClientConfig;
+use iggy::http::messages_format::MessagesFormat;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct HttpClientFactory {
     pub server_addr: String,
+    pub messages_format: MessagesFormat,
 }
 
 #[async_trait]
@@ -15,6 +17,7 @@ impl ClientFactory for HttpClientFactory {
     async fn create_client(&self) -> Box<dyn Client> {
         let config = HttpClientConfig {
             api_url: format!("http://{}", self.server_addr.clone()),
+            messages_format: self.messages_format,
             ..HttpClientConfig::default()
         };
         let client = HttpClient::create(Arc::new(config)).unwrap();
@@ -1,9 +1,16 @@
 pub mod file;
 #[allow(deprecated)]
+pub mod grpc_client;
+#[allow(deprecated)]
 pub mod http_client;
 #[allow(deprecated)]
+pub mod mqtt_client;
+#[allow(deprecated)]
 pub mod quic_client;
 #[allow(deprecated)]
 pub mod tcp_client;
 #[allow(deprecated)]
 pub mod test_server;
+#[cfg(unix)]
+#[allow(deprecated)]
+pub mod unix_client;
@@ -0,0 +1,185 @@
+use bytes::{Bytes, BytesMut};
+use server::mqtt::codec::{
+    self, ConnAck, Connect, Packet, Publish, SubAck, Subscribe, UnsubAck, Unsubscribe,
+};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+const RECEIVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Minimal MQTT client speaking the same protocol subset as the MQTT gateway,
+/// used to verify the compatibility without depending on the external MQTT client libraries.
+#[derive(Debug)]
+pub struct MqttClient {
+    stream: TcpStream,
+    protocol_version: u8,
+    packet_id: u16,
+}
+
+impl MqttClient {
+    /// Connects to the gateway and returns the client along with the received CONNACK packet.
+    pub async fn connect(
+        server_addr: &str,
+        protocol_version: u8,
+        username: Option<&str>,
+        password: Option<&str>,
+    ) -> (Self, ConnAck) {
+        Self::connect_with(
+            server_addr,
+            Connect {
+                protocol_version,
+                clean_session: true,
+                keep_alive: 60,
+                client_id: "iggy-mqtt-test-client".to_string(),
+                username: username.map(|username| username.to_string()),
+                password: password.map(|password| Bytes::copy_from_slice(password.as_bytes())),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Connects to the gateway with the custom CONNECT packet, e.g. to resume the session.
+    pub async fn connect_with(server_addr: &str, connect: Connect) -> (Self, ConnAck) {
+        let stream = TcpStream::connect(server_addr)
+            .await
+            .expect("Failed to connect to the MQTT gateway.");
+        let mut client = Self {
+            stream,
+            protocol_version: connect.protocol_version,
+            packet_id: 0,
+        };
+        client.send(Packet::Connect(connect)).await;
+        let Some(Packet::ConnAck(connack)) = client.receive().await else {
+            panic!("Expected the CONNACK packet.");
+        };
+        (client, connack)
+    }
+
+    pub async fn send(&mut self, packet: Packet) {
+        self.stream
+            .write_all(&packet.encode(self.protocol_version))
+            .await
+            .expect("Failed to send the MQTT packet.");
+    }
+
+    /// Returns the next packet, or `None` if the connection has been closed or nothing has been received in time.
+    pub async fn receive(&mut self) -> Option<Packet> {
+        timeout(RECEIVE_TIMEOUT, self.read_packet())
+            .await
+            .ok()
+            .flatten()
+    }
+
+    async fn read_packet(&mut self) -> Option<Packet> {
+        let first_byte = self.stream.read_u8().await.ok()?;
+        let mut length = Vec::new();
+        let length = loop {
+            length.push(self.stream.read_u8().await.ok()?);
+            if let Some((length, _)) = codec::decode_variable_integer(&length).ok()? {
+                break length as usize;
+            }
+        };
+        let mut body = BytesMut::zeroed(length);
+        self.stream.read_exact(&mut body).await.ok()?;
+        Packet::decode(first_byte, body.freeze(), self.protocol_version).ok()
+    }
+
+    /// Publishes the message, returns the PUBACK reason code for QoS 1, or `None` for QoS 0.
+    pub async fn publish(&mut self, topic: &str, payload: &[u8], qos: u8) -> Option<u8> {
+        let packet_id = (qos > 0).then(|| self.next_packet_id());
+        self.send(Packet::Publish(Publish {
+            qos,
+            topic: topic.to_string(),
+            packet_id,
+            payload: Bytes::copy_from_slice(payload),
+            ..Default::default()
+        }))
+        .await;
+        packet_id?;
+        match self.receive().await {
+            Some(Packet::PubAck {
+                packet_id: id,
+                code,
+            }) if Some(id) == packet_id => Some(code),
+            packet => panic!("Expected the PUBACK packet, received: {packet:?}"),
+        }
+    }
+
+    /// Subscribes to the topic filters with the requested QoS and returns the SUBACK codes.
+    pub async fn subscribe(&mut self, filters: &[(&str, u8)]) -> Vec<u8> {
+        let packet_id = self.next_packet_id();
+        self.send(Packet::Subscribe(Subscribe {
+            packet_id,
+            properties: Vec::new(),
+            filters: filters
+                .iter()
+                .map(|(filter, qos)| (filter.to_string(), *qos))
+                .collect(),
+        }))
+        .await;
+        match self.receive().await {
+            Some(Packet::SubAck(SubAck {
+                packet_id: id,
+                codes,
+                ..
+            })) if id == packet_id => codes,
+            packet => panic!("Expected the SUBACK packet, received: {packet:?}"),
+        }
+    }
+
+    pub async fn unsubscribe(&mut self, filters: &[&str]) -> Vec<u8> {
+        let packet_id = self.next_packet_id();
+        self.send(Packet::Unsubscribe(Unsubscribe {
+            packet_id,
+            properties: Vec::new(),
+            filters: filters.iter().map(|filter| filter.to_string()).collect(),
+        }))
+        .await;
+        match self.receive().await {
+            Some(Packet::UnsubAck(UnsubAck {
+                packet_id: id,
+                codes,
+                ..
+            })) if id == packet_id => codes,
+            packet => panic!("Expected the UNSUBACK packet, received: {packet:?}"),
+        }
+    }
+
+    /// Receives the next published message, acknowledging it if it was delivered with QoS 1.
+    pub async fn receive_publish(&mut self) -> Option<Publish> {
+        let publish = self.receive_unacknowledged_publish().await?;
+        if let Some(packet_id) = publish.packet_id {
+            self.acknowledge(packet_id).await;
+        }
+        Some(publish)
+    }
+
+    /// Receives the next published message without acknowledging it.
+    pub async fn receive_unacknowledged_publish(&mut self) -> Option<Publish> {
+        match self.receive().await? {
+            Packet::Publish(publish) => Some(publish),
+            packet => panic!("Expected the PUBLISH packet, received: {packet:?}"),
+        }
+    }
+
+    pub async fn acknowledge(&mut self, packet_id: u16) {
+        self.send(Packet::PubAck { packet_id, code: 0 }).await;
+    }
+
+    pub async fn ping(&mut self) -> bool {
+        self.send(Packet::PingReq).await;
+        matches!(self.receive().await, Some(Packet::PingResp))
+    }
+
+    pub async fn disconnect(mut self) {
+        self.send(Packet::Disconnect { code: 0 }).await;
+    }
+
+    fn next_packet_id(&mut self) -> u16 {
+        self.packet_id = self.packet_id.wrapping_add(1).max(1);
+        self.packet_id
+    }
+}
@@ -51,6 +51,10 @@ pub enum Transport {
 
     #[display(fmt = "tcp")]
     Tcp,
+
+    #[cfg(unix)]
+    #[display(fmt = "unix")]
+    Unix,
 }
 
 #[derive(Display, Debug)]
@@ -63,6 +67,15 @@ enum ServerProtocolAddr {
 
     #[display(fmt = "QUIC_UDP:{_0}")]
     QuicUdp(SocketAddr),
+
+    #[display(fmt = "GRPC_TCP:{_0}")]
+    GrpcTcp(SocketAddr),
+
+    #[display(fmt = "KAFKA_TCP:{_0}")]
+    KafkaTcp(SocketAddr),
+
+    #[display(fmt = "MQTT_TCP:{_0}")]
+    MqttTcp(SocketAddr),
 }
 
 #[derive(Debug)]
@@ -71,6 +84,7 @@ pub struct TestServer {
     envs: HashMap<String, String>,
     child_handle: Option<Child>,
     server_addrs: Vec<ServerProtocolAddr>,
+    unix_socket_path: Option<String>,
     stdout_file_path: Option<PathBuf>,
     stderr_file_path: Option<PathBuf>,
     cleanup: bool,
@@ -128,6 +142,18 @@ impl TestServer {
             server_addrs.push(ServerProtocolAddr::QuicUdp(quic_addr.parse().unwrap()));
         }
 
+        if let Some(grpc_addr) = envs.get("IGGY_GRPC_ADDRESS") {
+            server_addrs.push(ServerProtocolAddr::GrpcTcp(grpc_addr.parse().unwrap()));
+        }
+
+        if let Some(kafka_addr) = envs.get("IGGY_KAFKA_ADDRESS") {
+            server_addrs.push(ServerProtocolAddr::KafkaTcp(kafka_addr.parse().unwrap()));
+        }
+
+        if let Some(mqtt_addr) = envs.get("IGGY_MQTT_ADDRESS") {
+            server_addrs.push(ServerProtocolAddr::MqttTcp(mqtt_addr.parse().unwrap()));
+        }
+
         if server_addrs.is_empty() {
             server_addrs = match ip_kind {
                 IpAddrKind::V6 => Self::get_server_ipv6_addrs_with_random_port(),
@@ -140,6 +166,7 @@ impl TestServer {
             envs,
             child_handle: None,
             server_addrs,
+            unix_socket_path: None,
             stdout_file_path: None,
             stderr_file_path: None,
             cleanup,
@@ -253,6 +280,9 @@ impl TestServer {
             ServerProtocolAddr::QuicUdp(addr),
             ServerProtocolAddr::RawTcp(addr),
             ServerProtocolAddr::HttpTcp(addr),
+            ServerProtocolAddr::GrpcTcp(addr),
+            ServerProtocolAddr::KafkaTcp(addr),
+            ServerProtocolAddr::MqttTcp(addr),
         ]
     }
 
@@ -262,6 +292,9 @@ impl TestServer {
             ServerProtocolAddr::QuicUdp(addr),
             ServerProtocolAddr::RawTcp(addr),
             ServerProtocolAddr::HttpTcp(addr),
+            ServerProtocolAddr::GrpcTcp(addr),
+            ServerProtocolAddr::KafkaTcp(addr),
+            ServerProtocolAddr::MqttTcp(addr),
         ]
     }
 
@@ -277,10 +310,31 @@ impl TestServer {
                 ServerProtocolAddr::QuicUdp(addr) => {
                     ("IGGY_QUIC_ADDRESS".to_string(), addr.to_string())
                 }
+                ServerProtocolAddr::GrpcTcp(addr) => {
+                    ("IGGY_GRPC_ADDRESS".to_string(), addr.to_string())
+                }
+                ServerProtocolAddr::KafkaTcp(addr) => {
+                    ("IGGY_KAFKA_ADDRESS".to_string(), addr.to_string())
+                }
+                ServerProtocolAddr::MqttTcp(addr) => {
+                    ("IGGY_MQTT_ADDRESS".to_string(), addr.to_string())
+                }
             };
 
             self.envs.entry(key.0).or_insert(key.1);
         }
+
+        // The socket is kept in the data directory, so it's removed along with it.
+        if self
+            .envs
+            .get("IGGY_UNIX_ENABLED")
+            .map(|enabled| enabled.as_str())
+            == Some("true")
+        {
+            self.envs
+                .entry("IGGY_UNIX_PATH".to_string())
+                .or_insert(format!("{}/iggy.sock", self.local_data_path));
+        }
     }
 
     fn wait_until_server_has_bound(&mut self) {
@@ -326,6 +380,26 @@ impl TestServer {
             self.server_addrs.push(ServerProtocolAddr::HttpTcp(
                 config.http.address.parse().unwrap(),
             ));
+
+            self.server_addrs.push(ServerProtocolAddr::GrpcTcp(
+                config.grpc.address.parse().unwrap(),
+            ));
+
+            if config.kafka.enabled {
+                self.server_addrs.push(ServerProtocolAddr::KafkaTcp(
+                    config.kafka.address.parse().unwrap(),
+                ));
+            }
+
+            if config.mqtt.enabled {
+                self.server_addrs.push(ServerProtocolAddr::MqttTcp(
+                    config.mqtt.address.parse().unwrap(),
+                ));
+            }
+
+            if config.unix.enabled {
+                self.unix_socket_path = Some(config.unix.path);
+            }
         } else {
             panic!(
                 "Failed to load config from file {} in {} s!",
@@ -389,6 +463,37 @@ impl TestServer {
         None
     }
 
+    pub fn get_grpc_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::GrpcTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
+    pub fn get_kafka_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::KafkaTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
+    pub fn get_unix_socket_path(&self) -> Option<String> {
+        self.unix_socket_path.clone()
+    }
+
+    pub fn get_mqtt_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::MqttTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
     pub fn get_server_ip_addr(&self) -> Option<String> {
         if let Some(server_address) = self
             .get_raw_tcp_addr()
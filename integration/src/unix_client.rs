@@ -0,0 +1,36 @@
+use crate::test_server::ClientFactory;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::unix::client::UnixClient;
+use iggy::unix::config::UnixClientConfig;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct UnixClientFactory {
+    pub socket_path: String,
+}
+
+#[async_trait]
+impl ClientFactory for UnixClientFactory {
+    async fn create_client(&self) -> Box<dyn Client> {
+        let config = UnixClientConfig {
+            socket_path: self.socket_path.clone(),
+            ..UnixClientConfig::default()
+        };
+        let client = UnixClient::create(Arc::new(config)).unwrap_or_else(|e| {
+            panic!(
+                "Failed to create UnixClient, iggy-server has socket path {}, error: {:?}",
+                self.socket_path, e
+            )
+        });
+        iggy::client::Client::connect(&client)
+            .await
+            .unwrap_or_else(|e| {
+                panic!(
+                    "Failed to connect to iggy-server at {}, error: {:?}",
+                    self.socket_path, e
+                )
+            });
+        Box::new(client)
+    }
+}
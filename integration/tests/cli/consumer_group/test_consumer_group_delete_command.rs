@@ -5,6 +5,7 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use predicates::str::diff;
 use serial_test::parallel;
@@ -85,6 +86,8 @@ impl IggyCmdTestCase for TestConsumerGroupDeleteCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -5,6 +5,7 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -82,6 +83,8 @@ impl IggyCmdTestCase for TestConsumerGroupListCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
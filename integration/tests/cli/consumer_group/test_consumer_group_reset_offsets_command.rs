@@ -0,0 +1,420 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestConsumerGroupId, TestHelpCmd, TestStreamId,
+    TestTopicId, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::expiry::IggyExpiry;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+enum TestResetStrategy {
+    Earliest,
+    Latest,
+    Offset(u64),
+    Shift(i64),
+}
+
+struct TestConsumerGroupResetOffsetsCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    group_id: u32,
+    group_name: String,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    using_group_id: TestConsumerGroupId,
+    strategy: TestResetStrategy,
+    dry_run: bool,
+    messages_count: u64,
+}
+
+impl TestConsumerGroupResetOffsetsCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        group_id: u32,
+        group_name: String,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+        using_group_id: TestConsumerGroupId,
+        strategy: TestResetStrategy,
+        dry_run: bool,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            group_id,
+            group_name,
+            using_stream_id,
+            using_topic_id,
+            using_group_id,
+            strategy,
+            dry_run,
+            messages_count: 100,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push(match self.using_group_id {
+            TestConsumerGroupId::Numeric => format!("{}", self.group_id),
+            TestConsumerGroupId::Named => self.group_name.clone(),
+        });
+
+        match self.strategy {
+            TestResetStrategy::Earliest => command.push(String::from("--to-earliest")),
+            TestResetStrategy::Latest => command.push(String::from("--to-latest")),
+            TestResetStrategy::Offset(offset) => {
+                command.push(String::from("--to-offset"));
+                command.push(format!("{offset}"));
+            }
+            TestResetStrategy::Shift(shift) => {
+                command.push(String::from("--shift-by"));
+                command.push(format!("{shift}"));
+            }
+        }
+
+        if self.dry_run {
+            command.push(String::from("--dry-run"));
+        }
+
+        command
+    }
+
+    fn expected_lag(&self) -> u64 {
+        if self.dry_run {
+            return self.messages_count;
+        }
+
+        match self.strategy {
+            TestResetStrategy::Earliest => self.messages_count,
+            TestResetStrategy::Latest => 0,
+            TestResetStrategy::Offset(offset) => self.messages_count - offset,
+            TestResetStrategy::Shift(shift) => {
+                self.messages_count - (shift.max(0) as u64).min(self.messages_count)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerGroupResetOffsetsCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, Some(self.stream_id))
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                1,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                None,
+                None,
+                TopicConfigOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let consumer_group = client
+            .create_consumer_group(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &self.group_name,
+                self.group_id.into(),
+            )
+            .await;
+        assert!(consumer_group.is_ok());
+
+        let mut messages = (1..=self.messages_count)
+            .filter_map(|id| Message::from_str(format!("Test message {id}").as_str()).ok())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("consumer-group")
+            .arg("reset-offsets")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let group_id = match self.using_group_id {
+            TestConsumerGroupId::Numeric => format!("{}", self.group_id),
+            TestConsumerGroupId::Named => self.group_name.clone(),
+        };
+
+        let start_message = format!(
+            "Executing reset offsets ({}) of consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+            match self.strategy {
+                TestResetStrategy::Earliest => String::from("earliest"),
+                TestResetStrategy::Latest => String::from("latest"),
+                TestResetStrategy::Offset(offset) => format!("offset:{offset}"),
+                TestResetStrategy::Shift(shift) => format!("shift:{shift}"),
+            },
+            group_id,
+            topic_id,
+            stream_id
+        );
+        let end_message = match self.dry_run {
+            true => String::from("Dry run, the offsets were not changed"),
+            false => format!(
+                "Offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {} were reset",
+                group_id, topic_id, stream_id
+            ),
+        };
+
+        command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(contains("Partition ID"))
+            .stdout(contains(end_message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let consumer_lag = client
+            .get_consumer_lag(
+                &Consumer::group(Identifier::numeric(self.group_id).unwrap()),
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(consumer_lag.is_ok());
+        assert_eq!(consumer_lag.unwrap().total_lag, self.expected_lag());
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_parameters = vec![
+        (
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+            TestConsumerGroupId::Numeric,
+            TestResetStrategy::Offset(40),
+            false,
+        ),
+        (
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+            TestConsumerGroupId::Numeric,
+            TestResetStrategy::Latest,
+            false,
+        ),
+        (
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+            TestConsumerGroupId::Named,
+            TestResetStrategy::Earliest,
+            false,
+        ),
+        (
+            TestStreamId::Named,
+            TestTopicId::Named,
+            TestConsumerGroupId::Named,
+            TestResetStrategy::Shift(25),
+            false,
+        ),
+        (
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+            TestConsumerGroupId::Named,
+            TestResetStrategy::Shift(-5),
+            false,
+        ),
+        (
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+            TestConsumerGroupId::Numeric,
+            TestResetStrategy::Latest,
+            true,
+        ),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (using_stream_id, using_topic_id, using_group_id, strategy, dry_run) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestConsumerGroupResetOffsetsCmd::new(
+                1,
+                String::from("stream"),
+                2,
+                String::from("topic"),
+                3,
+                String::from("group"),
+                using_stream_id,
+                using_topic_id,
+                using_group_id,
+                strategy,
+                dry_run,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "reset-offsets", "--help"],
+            format!(
+                r#"Reset offsets of consumer group with given ID for all partitions of given stream ID and topic ID
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+Consumer group ID can be specified as a consumer group name or ID
+
+Examples:
+ iggy consumer-group reset-offsets 1 2 3 --to-earliest
+ iggy consumer-group reset-offsets stream topic group --to-latest
+ iggy consumer-group reset-offsets stream topic group --to-offset 100
+ iggy consumer-group reset-offsets stream topic group --shift-by -10 --dry-run
+
+{USAGE_PREFIX} consumer-group reset-offsets [OPTIONS] <--to-earliest|--to-latest|--to-timestamp <TO_TIMESTAMP>|--to-offset <TO_OFFSET>|--shift-by <SHIFT_BY>> <STREAM_ID> <TOPIC_ID> <GROUP_ID>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to reset consumer group offsets
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to reset consumer group offsets
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <GROUP_ID>
+          Consumer group ID to reset offsets
+{CLAP_INDENT}
+          Consumer group ID can be specified as a consumer group name or ID
+
+Options:
+  -e, --to-earliest
+          Continue from the first message in each partition
+
+  -l, --to-latest
+          Continue from the messages sent after the reset
+
+  -t, --to-timestamp <TO_TIMESTAMP>
+          Continue from the messages with the given timestamp
+{CLAP_INDENT}
+          Timestamp must be specified as a number
+          of microseconds since the Unix epoch
+
+  -o, --to-offset <TO_OFFSET>
+          Continue from the message with the given offset
+
+  -s, --shift-by <SHIFT_BY>
+          Shift the current position by the given number of messages
+{CLAP_INDENT}
+          Negative value moves the position backward
+
+  -d, --dry-run
+          Only print the planned offsets without storing them
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "reset-offsets", "-h"],
+            format!(
+                r#"Reset offsets of consumer group with given ID for all partitions of given stream ID and topic ID
+
+{USAGE_PREFIX} consumer-group reset-offsets [OPTIONS] <--to-earliest|--to-latest|--to-timestamp <TO_TIMESTAMP>|--to-offset <TO_OFFSET>|--shift-by <SHIFT_BY>> <STREAM_ID> <TOPIC_ID> <GROUP_ID>
+
+Arguments:
+  <STREAM_ID>  Stream ID to reset consumer group offsets
+  <TOPIC_ID>   Topic ID to reset consumer group offsets
+  <GROUP_ID>   Consumer group ID to reset offsets
+
+Options:
+  -e, --to-earliest                  Continue from the first message in each partition
+  -l, --to-latest                    Continue from the messages sent after the reset
+  -t, --to-timestamp <TO_TIMESTAMP>  Continue from the messages with the given timestamp
+  -o, --to-offset <TO_OFFSET>        Continue from the message with the given offset
+  -s, --shift-by <SHIFT_BY>          Shift the current position by the given number of messages
+  -d, --dry-run                      Only print the planned offsets without storing them
+  -h, --help                         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -1,2 +1,3 @@
 mod test_consumer_offset_get_command;
+mod test_consumer_offset_lag_command;
 mod test_consumer_offset_set_command;
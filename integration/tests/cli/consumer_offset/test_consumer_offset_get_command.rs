@@ -8,6 +8,7 @@ use iggy::client::Client;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -98,6 +99,8 @@ impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -10,7 +10,7 @@ pub async fn should_help_match() {
         .execute_test_for_help_command(TestHelpCmd::new(
             vec!["help"],
             format!(
-                r#"Iggy is the persistent message streaming platform written in Rust, supporting QUIC, TCP and HTTP transport protocols, capable of processing millions of messages per second.
+                r#"Iggy is the persistent message streaming platform written in Rust, supporting QUIC, TCP, HTTP and gRPC transport protocols, capable of processing millions of messages per second.
 
 {USAGE_PREFIX} [OPTIONS] [COMMAND]
 
@@ -34,7 +34,7 @@ Commands:
 
 Options:
       --transport <TRANSPORT>
-          The transport to use. Valid values are `quic`, `http` and `tcp`
+          The transport to use. Valid values are `quic`, `http`, `tcp`, `grpc` and `unix`
 {CLAP_INDENT}
           [default: tcp]
 
@@ -144,6 +144,49 @@ Options:
       --quic-validate-certificate
           Flag to enable certificate validation for QUIC
 
+      --grpc-server-address <GRPC_SERVER_ADDRESS>
+          The optional server address for the gRPC transport
+{CLAP_INDENT}
+          [default: 127.0.0.1:8070]
+
+      --grpc-reconnection-retries <GRPC_RECONNECTION_RETRIES>
+          The optional number of reconnect retries for the gRPC transport
+{CLAP_INDENT}
+          [default: 3]
+
+      --grpc-reconnection-interval <GRPC_RECONNECTION_INTERVAL>
+          The optional reconnect interval for the gRPC transport
+{CLAP_INDENT}
+          [default: 1000]
+
+      --grpc-tls-enabled
+          Flag to enable TLS for the gRPC transport
+
+      --grpc-tls-domain <GRPC_TLS_DOMAIN>
+          The optional TLS domain for the gRPC transport
+{CLAP_INDENT}
+          [default: localhost]
+
+      --grpc-tls-ca-file <GRPC_TLS_CA_FILE>
+          The optional path to the CA certificate used by the gRPC transport to verify the server
+{CLAP_INDENT}
+          [default: ]
+
+      --unix-socket-path <UNIX_SOCKET_PATH>
+          The optional socket path for the Unix domain socket transport
+{CLAP_INDENT}
+          [default: /tmp/iggy.sock]
+
+      --unix-reconnection-retries <UNIX_RECONNECTION_RETRIES>
+          The optional number of reconnect retries for the Unix domain socket transport
+{CLAP_INDENT}
+          [default: 3]
+
+      --unix-reconnection-interval <UNIX_RECONNECTION_INTERVAL>
+          The optional reconnect interval for the Unix domain socket transport
+{CLAP_INDENT}
+          [default: 1000]
+
   -q, --quiet
           Quiet mode (disabled stdout printing)
 
@@ -1,3 +1,6 @@
+mod test_message_export_command;
 mod test_message_help_command;
+mod test_message_import_command;
 mod test_message_poll_command;
 mod test_message_send_command;
+mod test_message_tail_command;
@@ -0,0 +1,389 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::cli::message::messages_file::{self, MessagesFileFormat};
+use iggy::client::Client;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::expiry::IggyExpiry;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+struct TestMessageExportCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    messages: Vec<String>,
+    partition_id: u32,
+    start_offset: u64,
+    end_offset: Option<u64>,
+    format: MessagesFileFormat,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    temp_dir: TempDir,
+}
+
+impl TestMessageExportCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        messages: &[String],
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: Option<u64>,
+        format: MessagesFileFormat,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            messages: messages.to_owned(),
+            partition_id,
+            start_offset,
+            end_offset,
+            format,
+            using_stream_id,
+            using_topic_id,
+            temp_dir: TempDir::new().unwrap(),
+        }
+    }
+
+    fn get_file_path(&self) -> PathBuf {
+        self.temp_dir.path().join("messages")
+    }
+
+    fn get_expected_messages(&self) -> Vec<String> {
+        let end_offset = self.end_offset.unwrap_or(self.messages.len() as u64 - 1);
+        self.messages
+            .iter()
+            .skip(self.start_offset as usize)
+            .take((end_offset - self.start_offset + 1) as usize)
+            .cloned()
+            .collect()
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec!["--start-offset".into(), format!("{}", self.start_offset)];
+
+        if let Some(end_offset) = self.end_offset {
+            command.extend(vec!["--end-offset".into(), format!("{end_offset}")]);
+        }
+
+        command.extend(vec![
+            "--batch-size".into(),
+            "2".into(),
+            "--format".into(),
+            match self.format {
+                MessagesFileFormat::JsonLines => "json-lines".into(),
+                MessagesFileFormat::Binary => "binary".into(),
+            },
+        ]);
+
+        command.push(match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        });
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push(format!("{}", self.partition_id));
+        command.push(self.get_file_path().display().to_string());
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageExportCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                1,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                None,
+                None,
+                TopicConfigOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let mut messages = self
+            .messages
+            .iter()
+            .map(|s| Message::new(None, Bytes::from(s.as_bytes().to_vec()), None))
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(self.partition_id),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("export")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let message = format!(
+            "Executing export messages from topic ID: {} and stream with ID: {} (partition with ID: {}) to file: {}\n",
+            topic_id,
+            stream_id,
+            self.partition_id,
+            self.get_file_path().display()
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(message))
+            .stdout(contains(format!(
+                "Exported {} messages",
+                self.get_expected_messages().len()
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let bytes = std::fs::read(self.get_file_path()).unwrap();
+        let messages = messages_file::read_messages(Bytes::from(bytes), self.format).unwrap();
+        let payloads = messages
+            .iter()
+            .map(|message| String::from_utf8(message.payload.to_vec()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(payloads, self.get_expected_messages());
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_messages: Vec<String> = vec![
+        "Lorem ipsum dolor sit amet, consectetur adipiscing elit".into(),
+        "sed do eiusmod tempor incididunt ut labore et dolore magna aliqua".into(),
+        "Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris".into(),
+        "nisi ut aliquip ex ea commodo consequat".into(),
+        "Duis aute irure dolor in reprehenderit in voluptate velit esse".into(),
+    ];
+
+    let test_parameters: Vec<(
+        u64,
+        Option<u64>,
+        MessagesFileFormat,
+        TestStreamId,
+        TestTopicId,
+    )> = vec![
+        (
+            0,
+            None,
+            MessagesFileFormat::JsonLines,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ),
+        (
+            1,
+            Some(3),
+            MessagesFileFormat::JsonLines,
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+        ),
+        (
+            0,
+            None,
+            MessagesFileFormat::Binary,
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+        ),
+        (
+            2,
+            Some(2),
+            MessagesFileFormat::Binary,
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (start_offset, end_offset, format, using_stream_id, using_topic_id) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestMessageExportCmd::new(
+                1,
+                String::from("stream"),
+                2,
+                String::from("topic"),
+                &test_messages,
+                1,
+                start_offset,
+                end_offset,
+                format,
+                using_stream_id,
+                using_topic_id,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "export", "--help"],
+            format!(
+                r#"Export messages from given partition to a file
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples:
+ iggy message export 1 2 1 messages.jsonl
+ iggy message export --start-offset 100 --end-offset 199 stream topic 1 messages.jsonl
+ iggy message export --format binary stream topic 1 messages.bin
+
+{USAGE_PREFIX} message export [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <FILE>
+
+Arguments:
+  <STREAM_ID>
+          ID of the stream from which messages will be exported
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          ID of the topic from which messages will be exported
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <PARTITION_ID>
+          Partition ID from which messages will be exported
+
+  <FILE>
+          Path to the file to which messages will be exported
+
+Options:
+  -s, --start-offset <START_OFFSET>
+          Offset of the first exported message
+{CLAP_INDENT}
+          [default: 0]
+
+  -e, --end-offset <END_OFFSET>
+          Offset of the last exported message (inclusive)
+{CLAP_INDENT}
+          If not specified, all messages available
+          at the time of the export are exported
+
+  -b, --batch-size <BATCH_SIZE>
+          Maximum number of messages polled at once
+{CLAP_INDENT}
+          [default: 1000]
+
+  -f, --format <FORMAT>
+          Format of the file
+{CLAP_INDENT}
+          [default: json-lines]
+          [possible values: json-lines, binary]
+
+  -c, --consumer <CONSUMER>
+          Regular consumer which will poll messages
+{CLAP_INDENT}
+          Consumer ID can be specified as a consumer name or ID
+{CLAP_INDENT}
+          [default: 1]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "export", "-h"],
+            format!(
+                r#"Export messages from given partition to a file
+
+{USAGE_PREFIX} message export [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <FILE>
+
+Arguments:
+  <STREAM_ID>     ID of the stream from which messages will be exported
+  <TOPIC_ID>      ID of the topic from which messages will be exported
+  <PARTITION_ID>  Partition ID from which messages will be exported
+  <FILE>          Path to the file to which messages will be exported
+
+Options:
+  -s, --start-offset <START_OFFSET>  Offset of the first exported message [default: 0]
+  -e, --end-offset <END_OFFSET>      Offset of the last exported message (inclusive)
+  -b, --batch-size <BATCH_SIZE>      Maximum number of messages polled at once [default: 1000]
+  -f, --format <FORMAT>              Format of the file [default: json-lines] [possible values: json-lines, binary]
+  -c, --consumer <CONSUMER>          Regular consumer which will poll messages [default: 1]
+  -h, --help                         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
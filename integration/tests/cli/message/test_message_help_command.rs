@@ -15,9 +15,12 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} message <COMMAND>
 
 Commands:
-  send  Send messages to given topic ID and given stream ID [aliases: s]
-  poll  Poll messages from given topic ID and given stream ID [aliases: p]
-  help  Print this message or the help of the given subcommand(s)
+  send    Send messages to given topic ID and given stream ID [aliases: s]
+  poll    Poll messages from given topic ID and given stream ID [aliases: p]
+  tail    Continuously print new messages from given topic ID and given stream ID [aliases: t]
+  export  Export messages from given partition to a file [aliases: e]
+  import  Import messages from a file to given topic ID and given stream ID [aliases: i]
+  help    Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
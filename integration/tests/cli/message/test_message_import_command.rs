@@ -0,0 +1,316 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::consumer::Consumer;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::text;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+struct TestMessageImportCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    messages: Vec<String>,
+    partition_id: u32,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    temp_dir: TempDir,
+}
+
+impl TestMessageImportCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        messages: &[String],
+        partition_id: u32,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            messages: messages.to_owned(),
+            partition_id,
+            using_stream_id,
+            using_topic_id,
+            temp_dir: TempDir::new().unwrap(),
+        }
+    }
+
+    fn get_file_path(&self) -> PathBuf {
+        self.temp_dir.path().join("messages.jsonl")
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec![
+            "--partition-id".into(),
+            format!("{}", self.partition_id),
+            "--batch-size".into(),
+            "2".into(),
+        ];
+
+        command.push(match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        });
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push(self.get_file_path().display().to_string());
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageImportCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                2,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                None,
+                None,
+                TopicConfigOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let content = self
+            .messages
+            .iter()
+            .map(|message| {
+                format!(
+                    "{{\"id\":0,\"payload\":\"{}\",\"headers\":null}}\n",
+                    text::as_base64(message.as_bytes())
+                )
+            })
+            .collect::<String>();
+        std::fs::write(self.get_file_path(), content).unwrap();
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("import")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let message = format!(
+            "Executing import messages to topic with ID: {} and stream with ID: {} from file: {}\n",
+            topic_id,
+            stream_id,
+            self.get_file_path().display()
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(message))
+            .stdout(contains(format!(
+                "Imported {} messages",
+                self.messages.len()
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let polled_messages = client
+            .poll_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                Some(self.partition_id),
+                &Consumer::default(),
+                &PollingStrategy::first(),
+                self.messages.len() as u32 + 1,
+                false,
+            )
+            .await
+            .unwrap();
+        let payloads = polled_messages
+            .messages
+            .iter()
+            .map(|message| String::from_utf8(message.payload.to_vec()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(payloads, self.messages);
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_messages: Vec<String> = vec![
+        "Lorem ipsum dolor sit amet, consectetur adipiscing elit".into(),
+        "sed do eiusmod tempor incididunt ut labore et dolore magna aliqua".into(),
+        "Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris".into(),
+    ];
+
+    let test_parameters = vec![
+        (1, TestStreamId::Numeric, TestTopicId::Numeric),
+        (2, TestStreamId::Named, TestTopicId::Numeric),
+        (1, TestStreamId::Numeric, TestTopicId::Named),
+        (2, TestStreamId::Named, TestTopicId::Named),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (partition_id, using_stream_id, using_topic_id) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestMessageImportCmd::new(
+                1,
+                String::from("stream"),
+                2,
+                String::from("topic"),
+                &test_messages,
+                partition_id,
+                using_stream_id,
+                using_topic_id,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "import", "--help"],
+            format!(
+                r#"Import messages from a file to given topic ID and given stream ID
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples:
+ iggy message import 1 2 messages.jsonl
+ iggy message import --partition-id 1 stream topic messages.jsonl
+ iggy message import --format binary stream topic messages.bin
+
+{USAGE_PREFIX} message import [OPTIONS] <STREAM_ID> <TOPIC_ID> <FILE>
+
+Arguments:
+  <STREAM_ID>
+          ID of the stream to which messages will be imported
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          ID of the topic to which messages will be imported
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <FILE>
+          Path to the file from which messages will be imported
+
+Options:
+  -p, --partition-id <PARTITION_ID>
+          ID of the partition to which messages will be imported
+
+  -m, --message-key <MESSAGE_KEY>
+          Messages key which will be used to partition the messages
+{CLAP_INDENT}
+          Value of the key will be used by the server to calculate the partition ID
+
+  -b, --batch-size <BATCH_SIZE>
+          Maximum number of messages sent at once
+{CLAP_INDENT}
+          [default: 1000]
+
+  -f, --format <FORMAT>
+          Format of the file
+{CLAP_INDENT}
+          [default: json-lines]
+          [possible values: json-lines, binary]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "import", "-h"],
+            format!(
+                r#"Import messages from a file to given topic ID and given stream ID
+
+{USAGE_PREFIX} message import [OPTIONS] <STREAM_ID> <TOPIC_ID> <FILE>
+
+Arguments:
+  <STREAM_ID>  ID of the stream to which messages will be imported
+  <TOPIC_ID>   ID of the topic to which messages will be imported
+  <FILE>       Path to the file from which messages will be imported
+
+Options:
+  -p, --partition-id <PARTITION_ID>  ID of the partition to which messages will be imported
+  -m, --message-key <MESSAGE_KEY>    Messages key which will be used to partition the messages
+  -b, --batch-size <BATCH_SIZE>      Maximum number of messages sent at once [default: 1000]
+  -f, --format <FORMAT>              Format of the file [default: json-lines] [possible values: json-lines, binary]
+  -h, --help                         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
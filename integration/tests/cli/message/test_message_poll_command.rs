@@ -9,6 +9,7 @@ use iggy::client::Client;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -122,6 +123,8 @@ impl IggyCmdTestCase for TestMessagePollCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -401,6 +404,12 @@ Options:
           Flag indicates whether to include headers in the output
           after polling the messages.
 
+  -k, --show-key
+          Include the messages key in the output
+{CLAP_INDENT}
+          Flag indicates whether to include the messages key,
+          used to route the message to the partition, in the output.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -436,6 +445,7 @@ Options:
   -n, --next                           Polling strategy - start polling from the next message
   -c, --consumer <CONSUMER>            Regular consumer which will poll messages [default: 1]
   -s, --show-headers                   Include the message headers in the output
+  -k, --show-key                       Include the messages key in the output
   -h, --help                           Print help (see more with '--help')
 "#,
             ),
@@ -0,0 +1,393 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::client::Client;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::expiry::IggyExpiry;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+#[derive(Debug, Clone, Copy)]
+enum TestOutput {
+    Raw,
+    Hex,
+    Json,
+}
+
+struct TestMessageTailCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partitions_count: u32,
+    messages: Vec<String>,
+    partition_id: u32,
+    tail_partition_id: Option<u32>,
+    output: TestOutput,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestMessageTailCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partitions_count: u32,
+        messages: &[String],
+        partition_id: u32,
+        tail_partition_id: Option<u32>,
+        output: TestOutput,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        assert!(partition_id <= partitions_count);
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partitions_count,
+            messages: messages.to_owned(),
+            partition_id,
+            tail_partition_id,
+            output,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec![
+            "--first".into(),
+            "--limit".into(),
+            format!("{}", self.messages.len()),
+        ];
+
+        if let Some(partition_id) = self.tail_partition_id {
+            command.extend(vec!["--partition-id".into(), format!("{partition_id}")]);
+        }
+
+        command.extend(vec![
+            "--output".into(),
+            match self.output {
+                TestOutput::Raw => "raw".into(),
+                TestOutput::Hex => "hex".into(),
+                TestOutput::Json => "json".into(),
+            },
+        ]);
+
+        command.push(match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        });
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageTailCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                self.partitions_count,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                None,
+                None,
+                TopicConfigOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let mut messages = self
+            .messages
+            .iter()
+            .map(|s| Message::new(None, Bytes::from(s.as_bytes().to_vec()), None))
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(self.partition_id),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("tail")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let partitions = match self.tail_partition_id {
+            Some(partition_id) => format!("partition with ID: {partition_id}"),
+            None => String::from("all partitions"),
+        };
+
+        let message = format!(
+            "Executing tail messages from topic ID: {topic_id} and stream with ID: {stream_id} ({partitions})\n"
+        );
+
+        let status = command_state.success().stdout(starts_with(message));
+        let status = match self.output {
+            TestOutput::Json => status.stdout(
+                contains(format!("\"partition_id\":{}", self.partition_id))
+                    .count(self.messages.len()),
+            ),
+            _ => status,
+        };
+
+        self.messages.iter().fold(status, |status, message| {
+            status.stdout(contains(match self.output {
+                TestOutput::Raw => message.clone(),
+                TestOutput::Hex => message
+                    .as_bytes()
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect(),
+                TestOutput::Json => iggy::utils::text::as_base64(message.as_bytes()),
+            }))
+        });
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_messages: Vec<String> = vec![
+        "Lorem ipsum dolor sit amet, consectetur adipiscing elit".into(),
+        "sed do eiusmod tempor incididunt ut labore et dolore magna aliqua".into(),
+        "Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris".into(),
+        "nisi ut aliquip ex ea commodo consequat".into(),
+    ];
+
+    let test_parameters: Vec<(u32, Option<u32>, TestOutput, TestStreamId, TestTopicId)> = vec![
+        (
+            1,
+            Some(1),
+            TestOutput::Raw,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ),
+        (
+            2,
+            None,
+            TestOutput::Raw,
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+        ),
+        (
+            3,
+            Some(3),
+            TestOutput::Hex,
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+        ),
+        (
+            2,
+            None,
+            TestOutput::Json,
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (partition_id, tail_partition_id, output, using_stream_id, using_topic_id) in
+        test_parameters
+    {
+        iggy_cmd_test
+            .execute_test(TestMessageTailCmd::new(
+                1,
+                String::from("stream"),
+                2,
+                String::from("topic"),
+                3,
+                &test_messages,
+                partition_id,
+                tail_partition_id,
+                output,
+                using_stream_id,
+                using_topic_id,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "tail", "--help"],
+            format!(
+                r#"Continuously print new messages from given topic ID and given stream ID
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+Messages from all partitions are printed unless partition ID is given
+
+Examples:
+ iggy message tail 1 2
+ iggy message tail --partition-id 1 --first stream topic
+ iggy message tail --timestamp 1694968446131680 stream topic
+ iggy message tail --output json stream topic
+
+{USAGE_PREFIX} message tail [OPTIONS] <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <STREAM_ID>
+          ID of the stream from which messages will be tailed
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          ID of the topic from which messages will be tailed
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+Options:
+  -p, --partition-id <PARTITION_ID>
+          Partition ID from which messages will be tailed
+{CLAP_INDENT}
+          If not specified, messages from all partitions are tailed
+
+  -f, --first
+          Start from the first message in the partition
+{CLAP_INDENT}
+          By default, only the messages sent after starting
+          the command are printed
+
+  -t, --timestamp <TIMESTAMP>
+          Start from the messages with the given timestamp
+{CLAP_INDENT}
+          Timestamp must be specified as a number
+          of microseconds since the Unix epoch
+
+  -b, --batch-size <BATCH_SIZE>
+          Maximum number of messages polled at once from a partition
+{CLAP_INDENT}
+          [default: 100]
+
+  -i, --interval <INTERVAL>
+          Interval between polls when there are no new messages
+{CLAP_INDENT}
+          [default: 500ms]
+
+  -l, --limit <LIMIT>
+          Stop after printing the given number of messages
+
+  -o, --output <OUTPUT>
+          Format in which the messages are printed
+{CLAP_INDENT}
+          JSON output contains also the offset, timestamp,
+          ID and headers of the message
+{CLAP_INDENT}
+          [default: raw]
+          [possible values: raw, hex, base64, json]
+
+  -c, --consumer <CONSUMER>
+          Regular consumer which will poll messages
+{CLAP_INDENT}
+          Consumer ID can be specified as a consumer name or ID
+{CLAP_INDENT}
+          [default: 1]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "tail", "-h"],
+            format!(
+                r#"Continuously print new messages from given topic ID and given stream ID
+
+{USAGE_PREFIX} message tail [OPTIONS] <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <STREAM_ID>  ID of the stream from which messages will be tailed
+  <TOPIC_ID>   ID of the topic from which messages will be tailed
+
+Options:
+  -p, --partition-id <PARTITION_ID>  Partition ID from which messages will be tailed
+  -f, --first                        Start from the first message in the partition
+  -t, --timestamp <TIMESTAMP>        Start from the messages with the given timestamp
+  -b, --batch-size <BATCH_SIZE>      Maximum number of messages polled at once from a partition [default: 100]
+  -i, --interval <INTERVAL>          Interval between polls when there are no new messages [default: 500ms]
+  -l, --limit <LIMIT>                Stop after printing the given number of messages
+  -o, --output <OUTPUT>              Format in which the messages are printed [default: raw] [possible values: raw, hex, base64, json]
+  -c, --consumer <CONSUMER>          Regular consumer which will poll messages [default: 1]
+  -h, --help                         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -1,3 +1,6 @@
 mod test_partition_create_command;
 mod test_partition_delete_command;
 mod test_partition_help_command;
+mod test_partition_moves_command;
+mod test_partition_offsets_command;
+mod test_partition_truncate_command;
@@ -15,11 +15,18 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} partition <COMMAND>
 
 Commands:
-  create  Create partitions for the specified topic ID
-              and stream ID based on the given count. [aliases: c]
-  delete  Delete partitions for the specified topic ID
-              and stream ID based on the given count. [aliases: d]
-  help    Print this message or the help of the given subcommand(s)
+  create    Create partitions for the specified topic ID
+                and stream ID based on the given count. [aliases: c]
+  delete    Delete partitions for the specified topic ID
+                and stream ID based on the given count. [aliases: d]
+  offsets   Get offsets corresponding to the given timestamp
+                for the partitions of the specified topic ID and stream ID. [aliases: o]
+  truncate  Delete messages before the given offset
+                in the partition of the specified topic ID and stream ID. [aliases: t]
+  moves     Report which messages keys are moved to other partitions
+                after adding the given count of partitions
+                to the specified topic ID and stream ID. [aliases: m]
+  help      Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
@@ -0,0 +1,280 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::partitioning_scheme::PartitioningScheme;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestPartitionMovesCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partitions_count: u32,
+    new_partitions: u32,
+    partitioning_scheme: PartitioningScheme,
+    keys: Vec<String>,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestPartitionMovesCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partitions_count: u32,
+        new_partitions: u32,
+        partitioning_scheme: PartitioningScheme,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partitions_count,
+            new_partitions,
+            partitioning_scheme,
+            keys: (1..=100).map(|id| format!("user-{id}")).collect(),
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push(format!("{}", self.new_partitions));
+        command.extend(self.keys.iter().cloned());
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestPartitionMovesCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                self.partitions_count,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                None,
+                None,
+                TopicConfigOverrides {
+                    partitioning_scheme: Some(self.partitioning_scheme),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(topic.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("partition")
+            .arg("moves")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let moved_keys = self.partitioning_scheme.get_moved_keys(
+            &self.keys,
+            self.partitions_count,
+            self.partitions_count + self.new_partitions,
+        );
+
+        let message = format!(
+            "Executing get messages keys moved after adding {} partitions to topic with ID: {} and stream with ID: {}",
+            self.new_partitions, topic_id, stream_id
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(message))
+            .stdout(contains(format!(
+                "using {} partitioning scheme moves {} of {} messages keys",
+                self.partitioning_scheme,
+                moved_keys.len(),
+                self.keys.len()
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .get_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+        let topic = topic.unwrap();
+        assert_eq!(topic.partitions_count, self.partitions_count);
+        assert_eq!(topic.partitioning_scheme, self.partitioning_scheme);
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestPartitionMovesCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            4,
+            1,
+            PartitioningScheme::JumpHash,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestPartitionMovesCmd::new(
+            2,
+            String::from("stream"),
+            3,
+            String::from("topic"),
+            3,
+            2,
+            PartitioningScheme::Modulo,
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "moves", "--help"],
+            format!(
+                r#"Report which messages keys are moved to other partitions
+after adding the given count of partitions
+to the specified topic ID and stream ID.
+
+The keys are mapped using the partitioning scheme of the topic,
+no partitions are created by this command.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy partition moves 1 1 2 user-1 user-2 user-3
+ iggy partition moves prod sensor 1 device-1 device-2
+
+{USAGE_PREFIX} partition moves <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <KEYS>...
+
+Arguments:
+  <STREAM_ID>
+          Stream ID of the topic
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to which the partitions would be added
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <PARTITIONS_COUNT>
+          Partitions count which would be added
+
+  <KEYS>...
+          Messages keys to check
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "moves", "-h"],
+            format!(
+                r#"Report which messages keys are moved to other partitions
+after adding the given count of partitions
+to the specified topic ID and stream ID.
+
+{USAGE_PREFIX} partition moves <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <KEYS>...
+
+Arguments:
+  <STREAM_ID>         Stream ID of the topic
+  <TOPIC_ID>          Topic ID to which the partitions would be added
+  <PARTITIONS_COUNT>  Partitions count which would be added
+  <KEYS>...           Messages keys to check
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,294 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+use std::time::Duration;
+
+struct TestPartitionOffsetsCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partition_ids: Vec<u32>,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    messages_count: u64,
+    timestamp: u64,
+}
+
+impl TestPartitionOffsetsCmd {
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partition_ids: Vec<u32>,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partition_ids,
+            using_stream_id,
+            using_topic_id,
+            messages_count: 10,
+            timestamp: 0,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push(format!("{}", self.timestamp));
+
+        for partition_id in &self.partition_ids {
+            command.push(String::from("-p"));
+            command.push(format!("{partition_id}"));
+        }
+
+        command
+    }
+
+    async fn send_messages(&self, client: &dyn Client) {
+        let mut messages = (1..=self.messages_count)
+            .filter_map(|id| Message::from_str(format!("Test message {id}").as_str()).ok())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestPartitionOffsetsCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                2,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                None,
+                None,
+                TopicConfigOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        self.send_messages(client).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        self.timestamp = IggyTimestamp::now().to_micros();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        self.send_messages(client).await;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("partition")
+            .arg("offsets")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let message = format!(
+            "Executing get offsets for timestamp: {} for topic with ID: {} and stream with ID: {}",
+            self.timestamp, topic_id, stream_id
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(message))
+            .stdout(contains(format!(
+                "| 1            | 0            | {:<11} | {:<6} |",
+                2 * self.messages_count - 1,
+                self.messages_count
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let offsets = client
+            .get_offsets_for_timestamp(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &[],
+                self.timestamp,
+            )
+            .await;
+        assert!(offsets.is_ok());
+        let offsets = offsets.unwrap();
+        assert_eq!(offsets.len(), 2);
+        assert_eq!(offsets[0].offset, Some(self.messages_count));
+        assert_eq!(offsets[1].first_offset, None);
+        assert_eq!(offsets[1].offset, None);
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestPartitionOffsetsCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            vec![],
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestPartitionOffsetsCmd::new(
+            2,
+            String::from("stream"),
+            3,
+            String::from("topic"),
+            vec![1],
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "offsets", "--help"],
+            format!(
+                r#"Get offsets corresponding to the given timestamp
+for the partitions of the specified topic ID and stream ID.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy partition offsets 1 1 1718000000000000
+ iggy partition offsets prod sensor 1718000000000000 -p 1 -p 2
+
+{USAGE_PREFIX} partition offsets [OPTIONS] <STREAM_ID> <TOPIC_ID> <TIMESTAMP>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to get partition offsets
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to get partition offsets
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <TIMESTAMP>
+          Timestamp for which the offsets are resolved
+{CLAP_INDENT}
+          Timestamp must be specified as a number
+          of microseconds since the Unix epoch
+
+Options:
+  -p, --partition-id <PARTITION_ID>
+          Partition ID for which the offsets are resolved
+{CLAP_INDENT}
+          Can be specified multiple times, all partitions by default
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "offsets", "-h"],
+            format!(
+                r#"Get offsets corresponding to the given timestamp
+for the partitions of the specified topic ID and stream ID.
+
+{USAGE_PREFIX} partition offsets [OPTIONS] <STREAM_ID> <TOPIC_ID> <TIMESTAMP>
+
+Arguments:
+  <STREAM_ID>  Stream ID to get partition offsets
+  <TOPIC_ID>   Topic ID to get partition offsets
+  <TIMESTAMP>  Timestamp for which the offsets are resolved
+
+Options:
+  -p, --partition-id <PARTITION_ID>  Partition ID for which the offsets are resolved
+  -h, --help                         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,293 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::consumer::Consumer;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::expiry::IggyExpiry;
+use predicates::str::diff;
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestPartitionTruncateCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partition_id: u32,
+    offset: u64,
+    messages_count: u32,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestPartitionTruncateCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        offset: u64,
+        messages_count: u32,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partition_id: 1,
+            offset,
+            messages_count,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push(format!("{}", self.partition_id));
+        command.push(format!("{}", self.offset));
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestPartitionTruncateCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                1,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                None,
+                None,
+                TopicConfigOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let mut messages = (1..=self.messages_count)
+            .filter_map(|id| Message::from_str(format!("Test message {id}").as_str()).ok())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(self.partition_id),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("partition")
+            .arg("truncate")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let message = format!(
+            "Executing truncate partition with ID: {} before offset: {} for topic with ID: {} and stream with ID: {}\n\
+            Partition with ID: {} for topic with ID: {} and stream with ID: {} truncated, low watermark: {}, deleted 0 segments and 0 messages\n",
+            self.partition_id,
+            self.offset,
+            topic_id,
+            stream_id,
+            self.partition_id,
+            topic_id,
+            stream_id,
+            self.offset.min(self.messages_count as u64)
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let low_watermark = self.offset.min(self.messages_count as u64);
+        let polled_messages = client
+            .poll_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                Some(self.partition_id),
+                &Consumer::default(),
+                &PollingStrategy::first(),
+                self.messages_count,
+                false,
+            )
+            .await;
+        assert!(polled_messages.is_ok());
+        let polled_messages = polled_messages.unwrap();
+        assert_eq!(
+            polled_messages.messages.len() as u64,
+            self.messages_count as u64 - low_watermark
+        );
+        if let Some(message) = polled_messages.messages.first() {
+            assert_eq!(message.offset, low_watermark);
+        }
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestPartitionTruncateCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            5,
+            10,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestPartitionTruncateCmd::new(
+            2,
+            String::from("stream"),
+            3,
+            String::from("topic"),
+            100,
+            10,
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "truncate", "--help"],
+            format!(
+                r#"Delete messages before the given offset
+in the partition of the specified topic ID and stream ID.
+
+The offset becomes the new low watermark of the partition,
+earlier messages are no longer returned when polling
+and segments containing only such messages are deleted.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy partition truncate 1 1 1 100
+ iggy partition truncate prod sensor 2 1000
+
+{USAGE_PREFIX} partition truncate <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <OFFSET>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID of the truncated partition
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID of the truncated partition
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <PARTITION_ID>
+          Partition ID to truncate
+
+  <OFFSET>
+          Offset before which all messages are deleted
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "truncate", "-h"],
+            format!(
+                r#"Delete messages before the given offset
+in the partition of the specified topic ID and stream ID.
+
+{USAGE_PREFIX} partition truncate <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <OFFSET>
+
+Arguments:
+  <STREAM_ID>     Stream ID of the truncated partition
+  <TOPIC_ID>      Topic ID of the truncated partition
+  <PARTITION_ID>  Partition ID to truncate
+  <OFFSET>        Offset before which all messages are deleted
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -56,13 +56,14 @@ impl IggyCmdTestCase for TestStreamGetCmd {
             .success()
             .stdout(starts_with(start_message))
             .stdout(contains(format!(
-                "Stream ID            | {}",
+                "Stream ID              | {}",
                 self.stream_id
             )))
-            .stdout(contains(format!("Stream name          | {}", self.name)))
-            .stdout(contains("Stream size          | 0"))
-            .stdout(contains("Stream message count | 0"))
-            .stdout(contains("Stream topics count  | 0"));
+            .stdout(contains(format!("Stream name            | {}", self.name)))
+            .stdout(contains("Stream size            | 0"))
+            .stdout(contains("Stream message count   | 0"))
+            .stdout(contains("Stream topics count    | 0"))
+            .stdout(contains("Topic config overrides | default"));
     }
 
     async fn verify_server_state(&self, _client: &dyn Client) {}
@@ -107,8 +107,9 @@ Stream ID can be specified as a stream name or ID
 Examples:
  iggy stream update 1 production
  iggy stream update test development
+ iggy stream update test development --segment-size 100MB
 
-{USAGE_PREFIX} stream update <STREAM_ID> <NAME>
+{USAGE_PREFIX} stream update [OPTIONS] <STREAM_ID> <NAME>
 
 Arguments:
   <STREAM_ID>
@@ -120,6 +121,62 @@ Arguments:
           New name for the stream
 
 Options:
+      --segment-size <SEGMENT_SIZE>
+          Maximum size of the segment, after which a new one is created
+
+      --enforce-fsync <ENFORCE_FSYNC>
+          Whether every write to the segment files is followed by fsync
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          Number of buffered messages, which triggers writing them to disk
+
+      --validate-checksum <VALIDATE_CHECKSUM>
+          Whether the checksums of the messages are validated when loading the segments
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --cache-indexes <CACHE_INDEXES>
+          Whether the indexes of the segments are kept in memory
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --cache-time-indexes <CACHE_TIME_INDEXES>
+          Whether the time indexes of the segments are kept in memory
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --message-deduplication <MESSAGE_DEDUPLICATION>
+          Whether the messages with the already seen IDs are dropped
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --message-deduplication-max-entries <MESSAGE_DEDUPLICATION_MAX_ENTRIES>
+          Maximum number of message IDs tracked by the deduplication, 0 means unlimited
+
+      --message-deduplication-expiry <MESSAGE_DEDUPLICATION_EXPIRY>
+          Expiry of the message IDs tracked by the deduplication in human-readable format like 1h 15min
+
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Maximum age of the segment in human-readable format like 1day 12h, after which a new one is created
+
+      --segment-max-messages <SEGMENT_MAX_MESSAGES>
+          Maximum number of messages in the segment, after which a new one is created, 0 means unlimited
+
+      --timestamp-type <TIMESTAMP_TYPE>
+          Type of the timestamp stored with the messages and used by the time index
+{CLAP_INDENT}
+          One of: log_append_time or create_time (the event timestamp set by the producer)
+
+      --max-timestamp-skew <MAX_TIMESTAMP_SKEW>
+          Maximum difference between the event timestamp set by the producer and the server time, 0 means unlimited
+
+      --partitioning-scheme <PARTITIONING_SCHEME>
+          Scheme mapping the messages key to the partition, it can be only set when the topic is created
+{CLAP_INDENT}
+          One of: modulo or jump_hash (keeps most of the keys in place when partitions are added)
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -139,14 +196,43 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Update stream name for given stream ID
 
-{USAGE_PREFIX} stream update <STREAM_ID> <NAME>
+{USAGE_PREFIX} stream update [OPTIONS] <STREAM_ID> <NAME>
 
 Arguments:
   <STREAM_ID>  Stream ID to update
   <NAME>       New name for the stream
 
 Options:
-  -h, --help  Print help (see more with '--help')
+      --segment-size <SEGMENT_SIZE>
+          Maximum size of the segment, after which a new one is created
+      --enforce-fsync <ENFORCE_FSYNC>
+          Whether every write to the segment files is followed by fsync [possible values: true, false]
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          Number of buffered messages, which triggers writing them to disk
+      --validate-checksum <VALIDATE_CHECKSUM>
+          Whether the checksums of the messages are validated when loading the segments [possible values: true, false]
+      --cache-indexes <CACHE_INDEXES>
+          Whether the indexes of the segments are kept in memory [possible values: true, false]
+      --cache-time-indexes <CACHE_TIME_INDEXES>
+          Whether the time indexes of the segments are kept in memory [possible values: true, false]
+      --message-deduplication <MESSAGE_DEDUPLICATION>
+          Whether the messages with the already seen IDs are dropped [possible values: true, false]
+      --message-deduplication-max-entries <MESSAGE_DEDUPLICATION_MAX_ENTRIES>
+          Maximum number of message IDs tracked by the deduplication, 0 means unlimited
+      --message-deduplication-expiry <MESSAGE_DEDUPLICATION_EXPIRY>
+          Expiry of the message IDs tracked by the deduplication in human-readable format like 1h 15min
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Maximum age of the segment in human-readable format like 1day 12h, after which a new one is created
+      --segment-max-messages <SEGMENT_MAX_MESSAGES>
+          Maximum number of messages in the segment, after which a new one is created, 0 means unlimited
+      --timestamp-type <TIMESTAMP_TYPE>
+          Type of the timestamp stored with the messages and used by the time index
+      --max-timestamp-skew <MAX_TIMESTAMP_SKEW>
+          Maximum difference between the event timestamp set by the producer and the server time, 0 means unlimited
+      --partitioning-scheme <PARTITIONING_SCHEME>
+          Scheme mapping the messages key to the partition, it can be only set when the topic is created
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
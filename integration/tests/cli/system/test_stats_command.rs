@@ -6,6 +6,7 @@ use async_trait::async_trait;
 use iggy::cli::system::stats::GetStatsOutput;
 use iggy::client::Client;
 use iggy::identifier::Identifier;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -58,6 +59,8 @@ impl IggyCmdTestCase for TestStatsCmd {
                 Some(1),
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -262,6 +262,7 @@ Examples
  iggy topic create prod sensor2 2 none
  iggy topic create test debugs 2 gzip 1day 1hour 1min 1sec
  iggy topic create -t 3 1 sensor3 2 none unlimited
+ iggy topic create prod sensor4 2 none --segment-size 100MB --enforce-fsync true
 
 {USAGE_PREFIX} topic create [OPTIONS] <STREAM_ID> <NAME> <PARTITIONS_COUNT> <COMPRESSION_ALGORITHM> [MESSAGE_EXPIRY]...
 
@@ -302,6 +303,68 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --durability <DURABILITY>
+          Durability level for the topic
+{CLAP_INDENT}
+          One of: none, interval, group_commit or every_batch
+          (skipping parameter uses the default durability level configured on the server)
+
+      --segment-size <SEGMENT_SIZE>
+          Maximum size of the segment, after which a new one is created
+
+      --enforce-fsync <ENFORCE_FSYNC>
+          Whether every write to the segment files is followed by fsync
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          Number of buffered messages, which triggers writing them to disk
+
+      --validate-checksum <VALIDATE_CHECKSUM>
+          Whether the checksums of the messages are validated when loading the segments
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --cache-indexes <CACHE_INDEXES>
+          Whether the indexes of the segments are kept in memory
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --cache-time-indexes <CACHE_TIME_INDEXES>
+          Whether the time indexes of the segments are kept in memory
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --message-deduplication <MESSAGE_DEDUPLICATION>
+          Whether the messages with the already seen IDs are dropped
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --message-deduplication-max-entries <MESSAGE_DEDUPLICATION_MAX_ENTRIES>
+          Maximum number of message IDs tracked by the deduplication, 0 means unlimited
+
+      --message-deduplication-expiry <MESSAGE_DEDUPLICATION_EXPIRY>
+          Expiry of the message IDs tracked by the deduplication in human-readable format like 1h 15min
+
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Maximum age of the segment in human-readable format like 1day 12h, after which a new one is created
+
+      --segment-max-messages <SEGMENT_MAX_MESSAGES>
+          Maximum number of messages in the segment, after which a new one is created, 0 means unlimited
+
+      --timestamp-type <TIMESTAMP_TYPE>
+          Type of the timestamp stored with the messages and used by the time index
+{CLAP_INDENT}
+          One of: log_append_time or create_time (the event timestamp set by the producer)
+
+      --max-timestamp-skew <MAX_TIMESTAMP_SKEW>
+          Maximum difference between the event timestamp set by the producer and the server time, 0 means unlimited
+
+      --partitioning-scheme <PARTITIONING_SCHEME>
+          Scheme mapping the messages key to the partition, it can be only set when the topic is created
+{CLAP_INDENT}
+          One of: modulo or jump_hash (keeps most of the keys in place when partitions are added)
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -330,10 +393,44 @@ Arguments:
   [MESSAGE_EXPIRY]...      Message expiry time in human-readable format like 15days 2min 2s
 
 Options:
-  -t, --topic-id <TOPIC_ID>                      Topic ID to create
-  -m, --max-topic-size <MAX_TOPIC_SIZE>          Max topic size [default: unlimited]
-  -r, --replication-factor <REPLICATION_FACTOR>  Replication factor for the topic [default: 1]
-  -h, --help                                     Print help (see more with '--help')
+  -t, --topic-id <TOPIC_ID>
+          Topic ID to create
+  -m, --max-topic-size <MAX_TOPIC_SIZE>
+          Max topic size [default: unlimited]
+  -r, --replication-factor <REPLICATION_FACTOR>
+          Replication factor for the topic [default: 1]
+      --durability <DURABILITY>
+          Durability level for the topic
+      --segment-size <SEGMENT_SIZE>
+          Maximum size of the segment, after which a new one is created
+      --enforce-fsync <ENFORCE_FSYNC>
+          Whether every write to the segment files is followed by fsync [possible values: true, false]
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          Number of buffered messages, which triggers writing them to disk
+      --validate-checksum <VALIDATE_CHECKSUM>
+          Whether the checksums of the messages are validated when loading the segments [possible values: true, false]
+      --cache-indexes <CACHE_INDEXES>
+          Whether the indexes of the segments are kept in memory [possible values: true, false]
+      --cache-time-indexes <CACHE_TIME_INDEXES>
+          Whether the time indexes of the segments are kept in memory [possible values: true, false]
+      --message-deduplication <MESSAGE_DEDUPLICATION>
+          Whether the messages with the already seen IDs are dropped [possible values: true, false]
+      --message-deduplication-max-entries <MESSAGE_DEDUPLICATION_MAX_ENTRIES>
+          Maximum number of message IDs tracked by the deduplication, 0 means unlimited
+      --message-deduplication-expiry <MESSAGE_DEDUPLICATION_EXPIRY>
+          Expiry of the message IDs tracked by the deduplication in human-readable format like 1h 15min
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Maximum age of the segment in human-readable format like 1day 12h, after which a new one is created
+      --segment-max-messages <SEGMENT_MAX_MESSAGES>
+          Maximum number of messages in the segment, after which a new one is created, 0 means unlimited
+      --timestamp-type <TIMESTAMP_TYPE>
+          Type of the timestamp stored with the messages and used by the time index
+      --max-timestamp-skew <MAX_TIMESTAMP_SKEW>
+          Maximum difference between the event timestamp set by the producer and the server time, 0 means unlimited
+      --partitioning-scheme <PARTITIONING_SCHEME>
+          Scheme mapping the messages key to the partition, it can be only set when the topic is created
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -5,6 +5,7 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -70,6 +71,8 @@ impl IggyCmdTestCase for TestTopicGetCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -111,6 +114,7 @@ impl IggyCmdTestCase for TestTopicGetCmd {
             .stdout(contains("Message expiry      | unlimited"))
             .stdout(contains("Max topic size      | unlimited"))
             .stdout(contains("Topic message count | 0"))
+            .stdout(contains("Partitioning scheme | modulo"))
             .stdout(contains("Partitions count    | 1"));
     }
 
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use humantime::Duration as HumanDuration;
 use iggy::client::Client;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use predicates::str::diff;
@@ -130,6 +131,8 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
                 Some(self.topic_id),
                 message_expiry.into(),
                 self.max_topic_size,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -372,6 +375,7 @@ Examples
  iggy update test debugs ready gzip 15days
  iggy update 1 1 new-name gzip
  iggy update 1 2 new-name none 1day 1hour 1min 1sec
+ iggy update 1 2 new-name none --cache-indexes false
 
 {USAGE_PREFIX} topic update [OPTIONS] <STREAM_ID> <TOPIC_ID> <NAME> <COMPRESSION_ALGORITHM> [MESSAGE_EXPIRY]...
 
@@ -411,6 +415,68 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --durability <DURABILITY>
+          New durability level for the topic
+{CLAP_INDENT}
+          One of: none, interval, group_commit or every_batch
+          (skipping parameter keeps the current durability level of the topic)
+
+      --segment-size <SEGMENT_SIZE>
+          Maximum size of the segment, after which a new one is created
+
+      --enforce-fsync <ENFORCE_FSYNC>
+          Whether every write to the segment files is followed by fsync
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          Number of buffered messages, which triggers writing them to disk
+
+      --validate-checksum <VALIDATE_CHECKSUM>
+          Whether the checksums of the messages are validated when loading the segments
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --cache-indexes <CACHE_INDEXES>
+          Whether the indexes of the segments are kept in memory
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --cache-time-indexes <CACHE_TIME_INDEXES>
+          Whether the time indexes of the segments are kept in memory
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --message-deduplication <MESSAGE_DEDUPLICATION>
+          Whether the messages with the already seen IDs are dropped
+{CLAP_INDENT}
+          [possible values: true, false]
+
+      --message-deduplication-max-entries <MESSAGE_DEDUPLICATION_MAX_ENTRIES>
+          Maximum number of message IDs tracked by the deduplication, 0 means unlimited
+
+      --message-deduplication-expiry <MESSAGE_DEDUPLICATION_EXPIRY>
+          Expiry of the message IDs tracked by the deduplication in human-readable format like 1h 15min
+
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Maximum age of the segment in human-readable format like 1day 12h, after which a new one is created
+
+      --segment-max-messages <SEGMENT_MAX_MESSAGES>
+          Maximum number of messages in the segment, after which a new one is created, 0 means unlimited
+
+      --timestamp-type <TIMESTAMP_TYPE>
+          Type of the timestamp stored with the messages and used by the time index
+{CLAP_INDENT}
+          One of: log_append_time or create_time (the event timestamp set by the producer)
+
+      --max-timestamp-skew <MAX_TIMESTAMP_SKEW>
+          Maximum difference between the event timestamp set by the producer and the server time, 0 means unlimited
+
+      --partitioning-scheme <PARTITIONING_SCHEME>
+          Scheme mapping the messages key to the partition, it can be only set when the topic is created
+{CLAP_INDENT}
+          One of: modulo or jump_hash (keeps most of the keys in place when partitions are added)
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -440,9 +506,42 @@ Arguments:
   [MESSAGE_EXPIRY]...      New message expiry time in human-readable format like 15days 2min 2s
 
 Options:
-  -m, --max-topic-size <MAX_TOPIC_SIZE>          New max topic size [default: unlimited]
-  -r, --replication-factor <REPLICATION_FACTOR>  New replication factor for the topic [default: 1]
-  -h, --help                                     Print help (see more with '--help')
+  -m, --max-topic-size <MAX_TOPIC_SIZE>
+          New max topic size [default: unlimited]
+  -r, --replication-factor <REPLICATION_FACTOR>
+          New replication factor for the topic [default: 1]
+      --durability <DURABILITY>
+          New durability level for the topic
+      --segment-size <SEGMENT_SIZE>
+          Maximum size of the segment, after which a new one is created
+      --enforce-fsync <ENFORCE_FSYNC>
+          Whether every write to the segment files is followed by fsync [possible values: true, false]
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          Number of buffered messages, which triggers writing them to disk
+      --validate-checksum <VALIDATE_CHECKSUM>
+          Whether the checksums of the messages are validated when loading the segments [possible values: true, false]
+      --cache-indexes <CACHE_INDEXES>
+          Whether the indexes of the segments are kept in memory [possible values: true, false]
+      --cache-time-indexes <CACHE_TIME_INDEXES>
+          Whether the time indexes of the segments are kept in memory [possible values: true, false]
+      --message-deduplication <MESSAGE_DEDUPLICATION>
+          Whether the messages with the already seen IDs are dropped [possible values: true, false]
+      --message-deduplication-max-entries <MESSAGE_DEDUPLICATION_MAX_ENTRIES>
+          Maximum number of message IDs tracked by the deduplication, 0 means unlimited
+      --message-deduplication-expiry <MESSAGE_DEDUPLICATION_EXPIRY>
+          Expiry of the message IDs tracked by the deduplication in human-readable format like 1h 15min
+      --segment-max-age <SEGMENT_MAX_AGE>
+          Maximum age of the segment in human-readable format like 1day 12h, after which a new one is created
+      --segment-max-messages <SEGMENT_MAX_MESSAGES>
+          Maximum number of messages in the segment, after which a new one is created, 0 means unlimited
+      --timestamp-type <TIMESTAMP_TYPE>
+          Type of the timestamp stored with the messages and used by the time index
+      --max-timestamp-skew <MAX_TIMESTAMP_SKEW>
+          Maximum difference between the event timestamp set by the producer and the server time, 0 means unlimited
+      --partitioning-scheme <PARTITIONING_SCHEME>
+          Scheme mapping the messages key to the partition, it can be only set when the topic is created
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -2,11 +2,13 @@ mod test_basic;
 mod test_getting_started;
 mod test_message_envelope;
 mod test_message_headers;
+mod test_typed_messages;
 
 use assert_cmd::Command;
 use iggy::client::{Client, StreamClient, SystemClient, TopicClient, UserClient};
 use iggy::clients::client::{IggyClient, IggyClientBackgroundConfig};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::tcp::client::TcpClient;
 use iggy::tcp::config::TcpClientConfig;
 use iggy::users::defaults::*;
@@ -127,6 +129,8 @@ impl<'a> IggyExampleTest<'a> {
                     None,
                     IggyExpiry::NeverExpire,
                     None,
+                    None,
+                    TopicConfigOverrides::default(),
                 )
                 .await
                 .unwrap();
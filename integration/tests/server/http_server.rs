@@ -1,6 +1,8 @@
 use crate::server::scenarios::{
-    create_message_payload, stream_size_validation_scenario, system_scenario, user_scenario,
+    create_message_payload, live_messages_scenario, message_headers_scenario, producer_scenario,
+    schema_registry_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
 };
+use iggy::http::messages_format::MessagesFormat;
 use integration::{http_client::HttpClientFactory, test_server::TestServer};
 use serial_test::parallel;
 
@@ -10,7 +12,10 @@ async fn create_message_payload_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_http_api_addr().unwrap();
-    let client_factory = HttpClientFactory { server_addr };
+    let client_factory = HttpClientFactory {
+        server_addr,
+        messages_format: MessagesFormat::default(),
+    };
     create_message_payload::run(&client_factory).await;
 }
 
@@ -20,7 +25,10 @@ async fn message_headers_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_http_api_addr().unwrap();
-    let client_factory = HttpClientFactory { server_addr };
+    let client_factory = HttpClientFactory {
+        server_addr,
+        messages_format: MessagesFormat::default(),
+    };
     create_message_payload::run(&client_factory).await;
 }
 
@@ -30,7 +38,10 @@ async fn stream_size_validation_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_http_api_addr().unwrap();
-    let client_factory = HttpClientFactory { server_addr };
+    let client_factory = HttpClientFactory {
+        server_addr,
+        messages_format: MessagesFormat::default(),
+    };
     stream_size_validation_scenario::run(&client_factory).await;
 }
 
@@ -40,7 +51,23 @@ async fn system_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_http_api_addr().unwrap();
-    let client_factory = HttpClientFactory { server_addr };
+    let client_factory = HttpClientFactory {
+        server_addr,
+        messages_format: MessagesFormat::default(),
+    };
+    system_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn system_scenario_should_be_valid_for_ndjson_messages() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory {
+        server_addr,
+        messages_format: MessagesFormat::Ndjson,
+    };
     system_scenario::run(&client_factory).await;
 }
 
@@ -50,6 +77,68 @@ async fn user_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_http_api_addr().unwrap();
-    let client_factory = HttpClientFactory { server_addr };
+    let client_factory = HttpClientFactory {
+        server_addr,
+        messages_format: MessagesFormat::default(),
+    };
     user_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn message_headers_scenario_should_be_valid_for_all_messages_formats() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    for messages_format in [
+        MessagesFormat::Json,
+        MessagesFormat::Binary,
+        MessagesFormat::MessagePack,
+        MessagesFormat::Ndjson,
+    ] {
+        let client_factory = HttpClientFactory {
+            server_addr: server_addr.clone(),
+            messages_format,
+        };
+        message_headers_scenario::run(&client_factory).await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+async fn producer_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory {
+        server_addr,
+        messages_format: MessagesFormat::default(),
+    };
+    producer_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schema_registry_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory {
+        server_addr,
+        messages_format: MessagesFormat::default(),
+    };
+    schema_registry_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn live_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory {
+        server_addr,
+        messages_format: MessagesFormat::default(),
+    };
+    live_messages_scenario::run(&client_factory).await;
+}
@@ -0,0 +1,427 @@
+use bytes::{Bytes, BytesMut};
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientBackgroundConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::models::header::HeaderKey;
+use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::models::user_status::UserStatus;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp_type::TimestampType;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{login_root, ClientFactory, IpAddrKind, TestServer};
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{BaseConsumer, CommitMode, Consumer as KafkaConsumer, StreamConsumer};
+use rdkafka::message::{Header, Headers, Message as KafkaMessage, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::types::RDKafkaRespErr;
+use rdkafka::{Offset, Timestamp, TopicPartitionList};
+use serial_test::parallel;
+use server::kafka::codec::{Decoder, Encoder};
+use server::kafka::protocol::{self, error_codes};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::Instant;
+
+const STREAM_NAME: &str = "kafka";
+const TOPIC_NAME: &str = "orders";
+const PARTITIONS_COUNT: u32 = 3;
+const GROUP: &str = "analytics";
+const USERNAME: &str = "kafka-user";
+const PASSWORD: &str = "kafka-secret";
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+async fn start_server(extra_envs: &[(&str, &str)]) -> (TestServer, IggyClient) {
+    let mut envs = HashMap::from([
+        ("IGGY_KAFKA_ENABLED".to_string(), "true".to_string()),
+        (
+            "IGGY_KAFKA_INITIAL_REBALANCE_DELAY".to_string(),
+            "500 ms".to_string(),
+        ),
+    ]);
+    for (key, value) in extra_envs {
+        envs.insert(key.to_string(), value.to_string());
+    }
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+    };
+    let client = IggyClient::create(
+        client_factory.create_client().await,
+        IggyClientBackgroundConfig::default(),
+        None,
+        None,
+        None,
+    );
+    login_root(&client).await;
+    client.create_stream(STREAM_NAME, None).await.unwrap();
+    client
+        .create_topic(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            None,
+            None,
+            // Kafka producers set the record timestamps, which are kept with the create time.
+            TopicConfigOverrides {
+                timestamp_type: Some(TimestampType::CreateTime),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    client
+        .create_user(
+            USERNAME,
+            PASSWORD,
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_topics: true,
+                    poll_messages: true,
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+        )
+        .await
+        .unwrap();
+    (test_server, client)
+}
+
+fn kafka_config(test_server: &TestServer, username: &str, password: &str) -> ClientConfig {
+    let mut config = ClientConfig::new();
+    config
+        .set("bootstrap.servers", test_server.get_kafka_addr().unwrap())
+        .set("broker.address.family", "v4")
+        .set("security.protocol", "SASL_PLAINTEXT")
+        .set("sasl.mechanism", "PLAIN")
+        .set("sasl.username", username)
+        .set("sasl.password", password);
+    config
+}
+
+fn create_producer(test_server: &TestServer) -> FutureProducer {
+    kafka_config(test_server, USERNAME, PASSWORD)
+        .set("message.timeout.ms", "10000")
+        .create()
+        .unwrap()
+}
+
+fn create_consumer(test_server: &TestServer) -> StreamConsumer {
+    kafka_config(test_server, USERNAME, PASSWORD)
+        .set("group.id", GROUP)
+        .set("enable.auto.commit", "false")
+        .set("auto.offset.reset", "earliest")
+        .set("session.timeout.ms", "10000")
+        .set("heartbeat.interval.ms", "500")
+        .create()
+        .unwrap()
+}
+
+async fn produce(producer: &FutureProducer, partition: i32, count: i64, timestamp: i64) {
+    for index in 0..count {
+        let key = format!("key-{index}");
+        let value = format!("value-{index}");
+        let record = FutureRecord::to(TOPIC_NAME)
+            .partition(partition)
+            .key(&key)
+            .payload(&value)
+            .timestamp(timestamp + index)
+            .headers(OwnedHeaders::new().insert(Header {
+                key: "source",
+                value: Some("kafka"),
+            }));
+        producer.send(record, TIMEOUT).await.unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+async fn produced_records_should_be_consumed_and_visible_to_iggy_clients() {
+    let (test_server, client) = start_server(&[]).await;
+    let producer = create_producer(&test_server);
+    let timestamp = 1_700_000_000_000;
+    produce(&producer, 1, 3, timestamp).await;
+
+    let consumer = create_consumer(&test_server);
+    let mut assignment = TopicPartitionList::new();
+    assignment
+        .add_partition_offset(TOPIC_NAME, 1, Offset::Beginning)
+        .unwrap();
+    consumer.assign(&assignment).unwrap();
+    for offset in 0..3 {
+        let message = tokio::time::timeout(TIMEOUT, consumer.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(message.partition(), 1);
+        assert_eq!(message.offset(), offset);
+        assert_eq!(
+            message.timestamp(),
+            Timestamp::CreateTime(timestamp + offset)
+        );
+        assert_eq!(message.key().unwrap(), format!("key-{offset}").as_bytes());
+        assert_eq!(
+            message.payload().unwrap(),
+            format!("value-{offset}").as_bytes()
+        );
+        let header = message.headers().unwrap().get(0);
+        assert_eq!(header.key, "source");
+        assert_eq!(header.value.unwrap(), b"kafka");
+    }
+
+    // Kafka partition 1 is Iggy partition 2, the record key is kept as the messages key.
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            Some(2),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 3);
+    let message = &polled_messages.messages[0];
+    assert_eq!(message.payload, Bytes::from_static(b"value-0"));
+    assert_eq!(message.timestamp, timestamp as u64 * 1000);
+    assert_eq!(message.key, Some(Bytes::from_static(b"key-0")));
+    assert_eq!(
+        message
+            .headers
+            .as_ref()
+            .unwrap()
+            .get(&HeaderKey::new("source").unwrap())
+            .unwrap()
+            .as_raw()
+            .unwrap(),
+        b"kafka"
+    );
+}
+
+#[tokio::test]
+#[parallel]
+async fn offsets_should_be_listed_by_watermarks_and_timestamp() {
+    let (test_server, _client) = start_server(&[]).await;
+    let producer = create_producer(&test_server);
+    let timestamp = 1_700_000_000_000;
+    produce(&producer, 0, 5, timestamp).await;
+
+    let consumer: BaseConsumer = kafka_config(&test_server, USERNAME, PASSWORD)
+        .set("group.id", GROUP)
+        .create()
+        .unwrap();
+    let (low, high) = consumer.fetch_watermarks(TOPIC_NAME, 0, TIMEOUT).unwrap();
+    assert_eq!((low, high), (0, 5));
+
+    let mut timestamps = TopicPartitionList::new();
+    timestamps
+        .add_partition_offset(TOPIC_NAME, 0, Offset::Offset(timestamp + 3))
+        .unwrap();
+    let offsets = consumer.offsets_for_times(timestamps, TIMEOUT).unwrap();
+    assert_eq!(
+        offsets.find_partition(TOPIC_NAME, 0).unwrap().offset(),
+        Offset::Offset(3)
+    );
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_members_should_share_partitions_and_commit_iggy_consumer_offsets() {
+    let (test_server, client) = start_server(&[]).await;
+    let producer = create_producer(&test_server);
+    for partition in 0..PARTITIONS_COUNT as i32 {
+        produce(&producer, partition, 3, 1_700_000_000_000).await;
+    }
+
+    let first = create_consumer(&test_server);
+    let second = create_consumer(&test_server);
+    first.subscribe(&[TOPIC_NAME]).unwrap();
+    second.subscribe(&[TOPIC_NAME]).unwrap();
+
+    // The members may consume the same partition twice while the group is being rebalanced.
+    let mut consumed = HashSet::new();
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while consumed.len() < 3 * PARTITIONS_COUNT as usize {
+        let message = tokio::select! {
+            message = first.recv() => message.unwrap().detach(),
+            message = second.recv() => message.unwrap().detach(),
+            _ = tokio::time::sleep_until(deadline) => panic!("Not all the records have been consumed."),
+        };
+        consumed.insert((message.partition(), message.offset()));
+    }
+
+    // Once the group is stable, each partition is assigned to exactly one of the members.
+    let deadline = Instant::now() + Duration::from_secs(30);
+    loop {
+        let first_assignment = first.assignment().unwrap().elements().len();
+        let second_assignment = second.assignment().unwrap().elements().len();
+        if first_assignment > 0
+            && second_assignment > 0
+            && first_assignment + second_assignment == PARTITIONS_COUNT as usize
+        {
+            break;
+        }
+        assert!(Instant::now() < deadline, "The group hasn't been balanced.");
+        tokio::select! {
+            _ = first.recv() => {},
+            _ = second.recv() => {},
+            _ = tokio::time::sleep(Duration::from_millis(100)) => {},
+        }
+    }
+
+    let mut offsets = TopicPartitionList::new();
+    for partition in 0..PARTITIONS_COUNT as i32 {
+        offsets
+            .add_partition_offset(TOPIC_NAME, partition, Offset::Offset(3))
+            .unwrap();
+    }
+    first.commit(&offsets, CommitMode::Sync).unwrap();
+
+    // Iggy stores the offset of the last consumed message, Kafka the offset of the next one.
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let consumer_offset = client
+            .get_consumer_offset(
+                &Consumer::new(Identifier::named(GROUP).unwrap()),
+                &Identifier::named(STREAM_NAME).unwrap(),
+                &Identifier::named(TOPIC_NAME).unwrap(),
+                Some(partition_id),
+            )
+            .await
+            .unwrap();
+        assert_eq!(consumer_offset.stored_offset, 2);
+    }
+    let committed = second.committed(TIMEOUT).unwrap();
+    for element in committed.elements() {
+        assert_eq!(element.offset(), Offset::Offset(3));
+    }
+}
+
+#[tokio::test]
+#[parallel]
+async fn connections_should_be_authenticated_as_sasl_users() {
+    let (test_server, client) = start_server(&[]).await;
+    client
+        .create_user("reader", "secret", UserStatus::Active, None)
+        .await
+        .unwrap();
+
+    let consumer: BaseConsumer = kafka_config(&test_server, USERNAME, "invalid")
+        .create()
+        .unwrap();
+    assert!(consumer
+        .fetch_metadata(Some(TOPIC_NAME), Duration::from_secs(3))
+        .is_err());
+
+    // Without the SASL authentication (and the configured fallback user), the connection is closed.
+    let consumer: BaseConsumer = ClientConfig::new()
+        .set("bootstrap.servers", test_server.get_kafka_addr().unwrap())
+        .set("broker.address.family", "v4")
+        .create()
+        .unwrap();
+    assert!(consumer
+        .fetch_metadata(Some(TOPIC_NAME), Duration::from_secs(3))
+        .is_err());
+
+    // The permissions of the authenticated user apply.
+    let consumer: BaseConsumer = kafka_config(&test_server, "reader", "secret")
+        .create()
+        .unwrap();
+    let metadata = consumer.fetch_metadata(Some(TOPIC_NAME), TIMEOUT).unwrap();
+    assert_eq!(
+        metadata.topics()[0].error(),
+        Some(RDKafkaRespErr::RD_KAFKA_RESP_ERR_TOPIC_AUTHORIZATION_FAILED)
+    );
+
+    let consumer: BaseConsumer =
+        kafka_config(&test_server, DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+            .create()
+            .unwrap();
+    let metadata = consumer.fetch_metadata(Some(TOPIC_NAME), TIMEOUT).unwrap();
+    let topic = &metadata.topics()[0];
+    assert_eq!(topic.error(), None);
+    assert_eq!(topic.partitions().len(), PARTITIONS_COUNT as usize);
+}
+
+#[tokio::test]
+#[parallel]
+async fn protocol_errors_should_be_returned_in_responses() {
+    // The clients not using SASL act on behalf of the configured user.
+    let (test_server, _client) = start_server(&[
+        ("IGGY_KAFKA_USERNAME", USERNAME),
+        ("IGGY_KAFKA_PASSWORD", PASSWORD),
+    ])
+    .await;
+    let mut stream = TcpStream::connect(test_server.get_kafka_addr().unwrap())
+        .await
+        .unwrap();
+
+    // The unsupported API key or version is answered with the error, instead of closing the connection.
+    let mut response = send_request(&mut stream, protocol::PRODUCE, 0, &[]).await;
+    assert_eq!(response.i16().unwrap(), error_codes::UNSUPPORTED_VERSION);
+    let mut response = send_request(&mut stream, 1000, 0, &[]).await;
+    assert_eq!(response.i16().unwrap(), error_codes::UNSUPPORTED_VERSION);
+
+    for (fetch_offset, error_code) in [
+        (0, error_codes::NONE),
+        (-5, error_codes::OFFSET_OUT_OF_RANGE),
+        (1, error_codes::OFFSET_OUT_OF_RANGE),
+    ] {
+        let mut request = Encoder::new();
+        request.i32(-1).i32(0).i32(1).i32(1024).i8(0);
+        request.array(&[TOPIC_NAME], |encoder, topic| {
+            encoder.string(topic);
+            encoder.array(&[0], |encoder, partition| {
+                encoder.i32(*partition).i64(fetch_offset).i32(1024);
+            });
+        });
+        let mut response = send_request(&mut stream, protocol::FETCH, 4, &request.freeze()).await;
+        response.i32().unwrap();
+        assert_eq!(response.i32().unwrap(), 1);
+        assert_eq!(response.string().unwrap(), TOPIC_NAME);
+        assert_eq!(response.i32().unwrap(), 1);
+        assert_eq!(response.i32().unwrap(), 0);
+        assert_eq!(response.i16().unwrap(), error_code);
+    }
+}
+
+async fn send_request(
+    stream: &mut TcpStream,
+    api_key: i16,
+    api_version: i16,
+    body: &[u8],
+) -> Decoder {
+    let mut request = Encoder::new();
+    request
+        .i16(api_key)
+        .i16(api_version)
+        .i32(api_key as i32)
+        .nullable_string(Some("iggy-test"))
+        .raw(body);
+    let request = request.freeze();
+    stream.write_i32(request.len() as i32).await.unwrap();
+    stream.write_all(&request).await.unwrap();
+
+    let length = stream.read_i32().await.unwrap();
+    let mut response = BytesMut::zeroed(length as usize);
+    stream.read_exact(&mut response).await.unwrap();
+    let mut decoder = Decoder::new(response.freeze());
+    assert_eq!(decoder.i32().unwrap(), api_key as i32);
+    decoder
+}
@@ -1,4 +1,9 @@
+mod grpc_server;
 mod http_server;
+mod kafka_server;
+mod mqtt_server;
 mod quic_server;
 mod scenarios;
 mod tcp_server;
+#[cfg(unix)]
+mod unix_server;
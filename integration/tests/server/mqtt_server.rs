@@ -0,0 +1,493 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, PersonalAccessTokenClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientBackgroundConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::HeaderKey;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use integration::mqtt_client::MqttClient;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{login_root, ClientFactory, IpAddrKind, TestServer};
+use serial_test::parallel;
+use server::mqtt::codec::{
+    property_ids, reason_codes, return_codes, Connect, Packet, Property, PropertyValue, Publish,
+    PROTOCOL_V311, PROTOCOL_V5,
+};
+use server::mqtt::TOPIC_HEADER;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::time::Duration;
+
+const STREAM_NAME: &str = "factory";
+const TOPIC_NAME: &str = "sensors";
+const PARTITIONS_COUNT: u32 = 3;
+const USERNAME: &str = "iggy";
+const PASSWORD: &str = "iggy";
+
+async fn start_server() -> (TestServer, IggyClient) {
+    start_server_with_resend_interval("10 s").await
+}
+
+async fn start_server_with_resend_interval(resend_interval: &str) -> (TestServer, IggyClient) {
+    let envs = HashMap::from([
+        ("IGGY_MQTT_ENABLED".to_string(), "true".to_string()),
+        ("IGGY_MQTT_POLL_INTERVAL".to_string(), "10 ms".to_string()),
+        (
+            "IGGY_MQTT_RESEND_INTERVAL".to_string(),
+            resend_interval.to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+    };
+    let client = IggyClient::create(
+        client_factory.create_client().await,
+        IggyClientBackgroundConfig::default(),
+        None,
+        None,
+        None,
+    );
+    login_root(&client).await;
+    client.create_stream(STREAM_NAME, None).await.unwrap();
+    client
+        .create_topic(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            None,
+            None,
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+    (test_server, client)
+}
+
+async fn connect(test_server: &TestServer, protocol_version: u8) -> MqttClient {
+    let (client, connack) = MqttClient::connect(
+        &test_server.get_mqtt_addr().unwrap(),
+        protocol_version,
+        Some(USERNAME),
+        Some(PASSWORD),
+    )
+    .await;
+    assert_eq!(connack.code, return_codes::ACCEPTED);
+    assert!(!connack.session_present);
+    client
+}
+
+/// Connects the client with the persistent session, returns the client along with the session present flag.
+async fn connect_persistent(
+    test_server: &TestServer,
+    protocol_version: u8,
+    clean_session: bool,
+) -> (MqttClient, bool) {
+    let properties = if protocol_version == PROTOCOL_V5 {
+        vec![Property::new(
+            property_ids::SESSION_EXPIRY_INTERVAL,
+            PropertyValue::FourByteInteger(60),
+        )]
+    } else {
+        Vec::new()
+    };
+    let (client, connack) = MqttClient::connect_with(
+        &test_server.get_mqtt_addr().unwrap(),
+        Connect {
+            protocol_version,
+            clean_session,
+            keep_alive: 60,
+            client_id: "iggy-mqtt-persistent-client".to_string(),
+            username: Some(USERNAME.to_string()),
+            password: Some(Bytes::from_static(PASSWORD.as_bytes())),
+            properties,
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(connack.code, return_codes::ACCEPTED);
+    (client, connack.session_present)
+}
+
+async fn poll_all_messages(client: &IggyClient) -> Vec<(u32, String, String)> {
+    let mut messages = Vec::new();
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let polled_messages = client
+            .poll_messages(
+                &Identifier::named(STREAM_NAME).unwrap(),
+                &Identifier::named(TOPIC_NAME).unwrap(),
+                Some(partition_id),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                100,
+                false,
+            )
+            .await
+            .unwrap();
+        for message in polled_messages.messages {
+            let topic = message
+                .headers
+                .as_ref()
+                .unwrap()
+                .get(&HeaderKey::new(TOPIC_HEADER).unwrap())
+                .unwrap()
+                .as_str()
+                .unwrap()
+                .to_string();
+            let payload = String::from_utf8(message.payload.to_vec()).unwrap();
+            messages.push((partition_id, topic, payload));
+        }
+    }
+    messages
+}
+
+#[tokio::test]
+#[parallel]
+async fn connect_should_authenticate_with_user_credentials_or_personal_access_token() {
+    let (test_server, client) = start_server().await;
+    let address = test_server.get_mqtt_addr().unwrap();
+
+    let (_, connack) =
+        MqttClient::connect(&address, PROTOCOL_V311, Some(USERNAME), Some("invalid")).await;
+    assert_eq!(connack.code, return_codes::BAD_USERNAME_OR_PASSWORD);
+
+    let (_, connack) = MqttClient::connect(&address, PROTOCOL_V311, None, None).await;
+    assert_eq!(connack.code, return_codes::NOT_AUTHORIZED);
+
+    let (_, connack) =
+        MqttClient::connect(&address, PROTOCOL_V5, Some(USERNAME), Some("invalid")).await;
+    assert_eq!(connack.code, reason_codes::BAD_USERNAME_OR_PASSWORD);
+
+    let (mut mqtt_client, connack) =
+        MqttClient::connect(&address, PROTOCOL_V5, Some(USERNAME), Some(PASSWORD)).await;
+    assert_eq!(connack.code, reason_codes::SUCCESS);
+    assert!(connack
+        .properties
+        .iter()
+        .any(|property| property.id == property_ids::MAXIMUM_QOS
+            && property.value == PropertyValue::Byte(1)));
+    assert!(mqtt_client.ping().await);
+
+    let personal_access_token = client
+        .create_personal_access_token("mqtt", PersonalAccessTokenExpiry::NeverExpire)
+        .await
+        .unwrap()
+        .token;
+    let (mut mqtt_client, connack) = MqttClient::connect(
+        &address,
+        PROTOCOL_V311,
+        Some(""),
+        Some(&personal_access_token),
+    )
+    .await;
+    assert_eq!(connack.code, return_codes::ACCEPTED);
+    assert!(mqtt_client.ping().await);
+}
+
+#[tokio::test]
+#[parallel]
+async fn published_messages_should_be_appended_to_mapped_stream_and_topic() {
+    let (test_server, client) = start_server().await;
+    let mut mqtt_client = connect(&test_server, PROTOCOL_V5).await;
+
+    let topic = "factory/sensors/device-1/temperature";
+    assert_eq!(mqtt_client.publish(topic, b"21.5", 0).await, None);
+    assert_eq!(
+        mqtt_client.publish(topic, b"21.7", 1).await,
+        Some(reason_codes::SUCCESS)
+    );
+    assert_eq!(
+        mqtt_client.publish("factory", b"invalid", 1).await,
+        Some(reason_codes::TOPIC_NAME_INVALID)
+    );
+    assert_eq!(
+        mqtt_client
+            .publish("factory/unknown/device-1", b"unknown", 1)
+            .await,
+        Some(reason_codes::TOPIC_NAME_INVALID)
+    );
+
+    // The QoS 0 message has no acknowledgement, so it's awaited until the server has appended it.
+    let mut messages = Vec::new();
+    for _ in 0..100 {
+        messages = poll_all_messages(&client).await;
+        if messages.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(messages.len(), 2);
+    // The messages with the same MQTT topic are appended to the same partition.
+    assert_eq!(messages[0].0, messages[1].0);
+    assert_eq!(messages[0].1, topic);
+    assert_eq!(messages[0].2, "21.5");
+    assert_eq!(messages[1].1, topic);
+    assert_eq!(messages[1].2, "21.7");
+
+    // QoS 2 is not supported, so the MQTT 5 client is disconnected with the reason.
+    mqtt_client
+        .send(Packet::Publish(Publish {
+            qos: 2,
+            topic: topic.to_string(),
+            packet_id: Some(100),
+            ..Default::default()
+        }))
+        .await;
+    assert_eq!(
+        mqtt_client.receive().await,
+        Some(Packet::Disconnect {
+            code: reason_codes::QOS_NOT_SUPPORTED
+        })
+    );
+}
+
+#[tokio::test]
+#[parallel]
+async fn subscriptions_should_deliver_messages_matching_topic_filters() {
+    let (test_server, client) = start_server().await;
+    let mut publisher = connect(&test_server, PROTOCOL_V311).await;
+    let mut subscriber = connect(&test_server, PROTOCOL_V311).await;
+
+    let codes = subscriber
+        .subscribe(&[
+            ("factory/sensors/+/temperature", 1),
+            ("factory/sensors", 2),
+            ("factory/unknown/#", 0),
+            ("+/sensors", 0),
+        ])
+        .await;
+    assert_eq!(codes, vec![1, 1, return_codes::SUBSCRIPTION_FAILURE, 0]);
+
+    publisher
+        .publish("factory/sensors/device-1/temperature", b"21.5", 1)
+        .await;
+    publisher
+        .publish("factory/sensors/device-1/humidity", b"40", 1)
+        .await;
+    publisher
+        .publish("factory/sensors/device-2/temperature", b"22.1", 0)
+        .await;
+    // The messages appended by the Iggy clients are published to the `stream/topic` MQTT topic,
+    // and the one matching multiple subscriptions is delivered once with the highest QoS.
+    client
+        .send_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            &Partitioning::partition_id(1),
+            &mut [Message::from_str("iggy").unwrap()],
+        )
+        .await
+        .unwrap();
+
+    let mut received = HashSet::new();
+    for _ in 0..3 {
+        let publish = subscriber.receive_publish().await.unwrap();
+        assert_eq!(publish.qos, 1);
+        assert!(publish.packet_id.is_some());
+        received.insert((
+            publish.topic,
+            String::from_utf8(publish.payload.to_vec()).unwrap(),
+        ));
+    }
+    assert_eq!(
+        received,
+        HashSet::from([
+            (
+                "factory/sensors/device-1/temperature".to_string(),
+                "21.5".to_string()
+            ),
+            (
+                "factory/sensors/device-2/temperature".to_string(),
+                "22.1".to_string()
+            ),
+            ("factory/sensors".to_string(), "iggy".to_string()),
+        ])
+    );
+
+    assert_eq!(
+        subscriber
+            .unsubscribe(&[
+                "factory/sensors/+/temperature",
+                "factory/sensors",
+                "+/sensors"
+            ])
+            .await,
+        Vec::<u8>::new()
+    );
+    publisher
+        .publish("factory/sensors/device-1/temperature", b"21.9", 1)
+        .await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    // No more messages are delivered, so the next packet is the ping response.
+    assert!(subscriber.ping().await);
+    subscriber.disconnect().await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn mqtt5_subscriptions_should_return_reason_codes() {
+    let (test_server, _client) = start_server().await;
+    let mut publisher = connect(&test_server, PROTOCOL_V5).await;
+    let mut subscriber = connect(&test_server, PROTOCOL_V5).await;
+
+    let codes = subscriber
+        .subscribe(&[
+            ("factory/#", 0),
+            ("factory/#/device-1", 0),
+            ("$share/group/factory/sensors", 0),
+        ])
+        .await;
+    assert_eq!(
+        codes,
+        vec![
+            0,
+            reason_codes::TOPIC_FILTER_INVALID,
+            reason_codes::SHARED_SUBSCRIPTIONS_NOT_SUPPORTED
+        ]
+    );
+
+    publisher
+        .publish("factory/sensors/device-1/temperature", b"21.5", 1)
+        .await;
+    let publish = subscriber.receive_publish().await.unwrap();
+    assert_eq!(publish.qos, 0);
+    assert_eq!(publish.topic, "factory/sensors/device-1/temperature");
+    assert_eq!(publish.payload.as_ref(), b"21.5");
+
+    assert_eq!(
+        subscriber.unsubscribe(&["factory/#", "factory/+"]).await,
+        vec![reason_codes::SUCCESS, reason_codes::NO_SUBSCRIPTION_EXISTED]
+    );
+}
+
+#[tokio::test]
+#[parallel]
+async fn topic_names_should_be_case_sensitive() {
+    let (test_server, _client) = start_server().await;
+    let mut publisher = connect(&test_server, PROTOCOL_V5).await;
+    let mut subscriber = connect(&test_server, PROTOCOL_V5).await;
+
+    assert_eq!(
+        subscriber
+            .subscribe(&[("#", 0), ("Factory/sensors/#", 0)])
+            .await,
+        vec![0, reason_codes::TOPIC_FILTER_INVALID]
+    );
+    assert_eq!(
+        publisher
+            .publish("Factory/sensors/device-1", b"invalid", 1)
+            .await,
+        Some(reason_codes::TOPIC_NAME_INVALID)
+    );
+    publisher
+        .publish("factory/sensors/Device-1/Temperature", b"21.5", 1)
+        .await;
+
+    let publish = subscriber.receive_publish().await.unwrap();
+    assert_eq!(publish.topic, "factory/sensors/Device-1/Temperature");
+    assert_eq!(publish.payload.as_ref(), b"21.5");
+}
+
+#[tokio::test]
+#[parallel]
+async fn unacknowledged_messages_should_be_resent_when_session_is_resumed() {
+    let (test_server, _client) = start_server().await;
+    let mut publisher = connect(&test_server, PROTOCOL_V5).await;
+    let (mut subscriber, session_present) =
+        connect_persistent(&test_server, PROTOCOL_V311, false).await;
+    assert!(!session_present);
+    assert_eq!(
+        subscriber.subscribe(&[("factory/sensors/#", 1)]).await,
+        vec![1]
+    );
+
+    publisher
+        .publish("factory/sensors/device-1/temperature", b"21.5", 1)
+        .await;
+    publisher
+        .publish("factory/sensors/device-1/temperature", b"21.7", 1)
+        .await;
+    let first = subscriber.receive_unacknowledged_publish().await.unwrap();
+    let second = subscriber.receive_unacknowledged_publish().await.unwrap();
+    assert_eq!(first.payload.as_ref(), b"21.5");
+    assert_eq!(second.payload.as_ref(), b"21.7");
+    assert_ne!(first.packet_id, second.packet_id);
+    subscriber.acknowledge(first.packet_id.unwrap()).await;
+    subscriber.disconnect().await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // Only the message which hasn't been acknowledged is sent again, and the subscription is kept.
+    let (mut subscriber, session_present) =
+        connect_persistent(&test_server, PROTOCOL_V311, false).await;
+    assert!(session_present);
+    let resent = subscriber.receive_publish().await.unwrap();
+    assert!(resent.dup);
+    assert_eq!(resent.packet_id, second.packet_id);
+    assert_eq!(resent.payload.as_ref(), b"21.7");
+    publisher
+        .publish("factory/sensors/device-2/temperature", b"22.1", 1)
+        .await;
+    let publish = subscriber.receive_publish().await.unwrap();
+    assert!(!publish.dup);
+    assert_eq!(publish.payload.as_ref(), b"22.1");
+    subscriber.disconnect().await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // The clean session discards the stored one.
+    let (mut subscriber, session_present) =
+        connect_persistent(&test_server, PROTOCOL_V5, true).await;
+    assert!(!session_present);
+    publisher
+        .publish("factory/sensors/device-1/temperature", b"21.9", 1)
+        .await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(subscriber.ping().await);
+}
+
+#[tokio::test]
+#[parallel]
+async fn unacknowledged_messages_should_be_resent_to_mqtt311_clients_after_resend_interval() {
+    let (test_server, _client) = start_server_with_resend_interval("200 ms").await;
+    let mut publisher = connect(&test_server, PROTOCOL_V5).await;
+    let mut subscriber = connect(&test_server, PROTOCOL_V311).await;
+    let mut mqtt5_subscriber = connect(&test_server, PROTOCOL_V5).await;
+    assert_eq!(
+        subscriber.subscribe(&[("factory/sensors", 1)]).await,
+        vec![1]
+    );
+    assert_eq!(
+        mqtt5_subscriber.subscribe(&[("factory/sensors", 1)]).await,
+        vec![1]
+    );
+
+    publisher.publish("factory/sensors", b"21.5", 1).await;
+    let publish = subscriber.receive_unacknowledged_publish().await.unwrap();
+    assert!(!publish.dup);
+    let resent = subscriber.receive_unacknowledged_publish().await.unwrap();
+    assert!(resent.dup);
+    assert_eq!(resent.packet_id, publish.packet_id);
+    assert_eq!(resent.payload.as_ref(), b"21.5");
+    subscriber.acknowledge(resent.packet_id.unwrap()).await;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert!(subscriber.ping().await);
+
+    // MQTT 5 allows resending the messages only after reconnecting.
+    let publish = mqtt5_subscriber
+        .receive_unacknowledged_publish()
+        .await
+        .unwrap();
+    assert!(!publish.dup);
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert!(mqtt5_subscriber.ping().await);
+}
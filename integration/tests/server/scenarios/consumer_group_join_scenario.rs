@@ -9,6 +9,7 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
 use iggy::models::client_info::ClientInfoDetails;
 use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use integration::test_server::{
     assert_clean_system, create_user, login_root, login_user, ClientFactory,
@@ -40,6 +41,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
@@ -11,6 +11,7 @@ use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use integration::test_server::{
     assert_clean_system, create_user, login_root, login_user, ClientFactory,
@@ -56,6 +57,8 @@ async fn init_system(
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
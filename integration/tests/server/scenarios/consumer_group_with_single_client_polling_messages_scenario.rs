@@ -3,6 +3,7 @@ use crate::server::scenarios::{
     CONSUMER_GROUP_NAME, MESSAGES_COUNT, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID,
     TOPIC_NAME,
 };
+use bytes::Bytes;
 use iggy::client::{ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient};
 use iggy::clients::client::IggyClient;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
@@ -10,6 +11,7 @@ use iggy::consumer::Consumer;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use integration::test_server::{assert_clean_system, login_root, ClientFactory};
 use std::str::{from_utf8, FromStr};
@@ -47,6 +49,8 @@ async fn init_system(client: &IggyClient) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
@@ -113,6 +117,17 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
             .unwrap();
 
         total_read_messages_count += polled_messages.messages.len() as u32;
+        for message in polled_messages.messages {
+            let payload = String::from_utf8(message.payload.to_vec()).unwrap();
+            let entity_id = payload
+                .trim_start_matches("message-")
+                .parse::<u32>()
+                .unwrap();
+            assert_eq!(
+                message.key,
+                Some(Bytes::copy_from_slice(&entity_id.to_le_bytes()))
+            );
+        }
     }
 
     assert_eq!(total_read_messages_count, MESSAGES_COUNT);
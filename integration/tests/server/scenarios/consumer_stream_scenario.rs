@@ -0,0 +1,239 @@
+use crate::server::scenarios::{
+    cleanup, create_client, CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME, PARTITIONS_COUNT, STREAM_ID,
+    STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use futures::StreamExt;
+use iggy::client::{
+    ConsumerGroupClient, ConsumerOffsetClient, MessageClient, StreamClient, TopicClient,
+};
+use iggy::clients::client::IggyClient;
+use iggy::clients::consumer::{CommitMode, IggyConsumer, IggyConsumerConfig, ReceivedMessage};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const MESSAGES_PER_PARTITION: u32 = 10;
+const BATCH_SIZE: u32 = 5;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let client = Arc::new(client);
+
+    for partition_id in 1..=PARTITIONS_COUNT {
+        send_messages(&client, partition_id, 1, MESSAGES_PER_PARTITION).await;
+    }
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let consumer_group_id = Identifier::numeric(CONSUMER_GROUP_ID).unwrap();
+    let group = Consumer::group(consumer_group_id.clone());
+
+    // 1. The consumer joins the group and gets all the partitions assigned
+    let mut consumer = create_consumer(&client, CommitMode::AfterEachMessage);
+    consumer.init().await.unwrap();
+    assert_eq!(consumer.assigned_partitions(), vec![1, 2, 3]);
+
+    // 2. The partitions are polled fairly, one batch at a time
+    let messages = receive_messages(&mut consumer, PARTITIONS_COUNT * BATCH_SIZE).await;
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let partition_messages = messages
+            .iter()
+            .filter(|message| message.partition_id == partition_id)
+            .collect::<Vec<_>>();
+        assert_eq!(partition_messages.len() as u32, BATCH_SIZE);
+        for (offset, message) in partition_messages.iter().enumerate() {
+            assert_eq!(message.message.offset, offset as u64);
+            assert_eq!(
+                message.message.payload,
+                format!("{partition_id}-{}", offset + 1).as_bytes()
+            );
+        }
+    }
+
+    // 3. The paused partition isn't consumed until it's resumed
+    consumer.pause(1);
+    assert_eq!(consumer.paused_partitions(), vec![1]);
+    let messages = receive_messages(&mut consumer, 2 * BATCH_SIZE).await;
+    assert!(messages.iter().all(|message| message.partition_id != 1));
+
+    consumer.resume(1);
+    let messages = receive_messages(&mut consumer, BATCH_SIZE).await;
+    assert!(messages.iter().all(|message| message.partition_id == 1));
+    assert_eq!(messages[0].message.offset, BATCH_SIZE as u64);
+
+    // 4. The shutdown commits the offsets of the consumed messages and leaves the group
+    consumer.shutdown().await.unwrap();
+    let consumer_group = client
+        .get_consumer_group(&stream_id, &topic_id, &consumer_group_id)
+        .await
+        .unwrap();
+    assert_eq!(consumer_group.members_count, 0);
+
+    // 5. The next consumer in the group continues from the committed offsets
+    send_messages(&client, 2, MESSAGES_PER_PARTITION + 1, 1).await;
+    let mut consumer = create_consumer(
+        &client,
+        CommitMode::Periodic(IggyDuration::new(Duration::from_secs(60))),
+    );
+    let messages = receive_messages(&mut consumer, 1).await;
+    assert_eq!(messages[0].partition_id, 2);
+    assert_eq!(messages[0].message.offset, MESSAGES_PER_PARTITION as u64);
+
+    // 6. The rebalancing commits the offsets of the messages consumed from the revoked partitions
+    send_messages(&client, 1, MESSAGES_PER_PARTITION + 1, 1).await;
+    send_messages(&client, 3, MESSAGES_PER_PARTITION + 1, 1).await;
+    receive_messages(&mut consumer, 2).await;
+    let other_client = create_client(client_factory).await;
+    login_root(&other_client).await;
+    other_client
+        .join_consumer_group(&stream_id, &topic_id, &consumer_group_id)
+        .await
+        .unwrap();
+    consumer.init().await.unwrap();
+    let assigned_partitions = consumer.assigned_partitions();
+    let revoked_partitions = (1..=PARTITIONS_COUNT)
+        .filter(|partition_id| !assigned_partitions.contains(partition_id))
+        .collect::<Vec<_>>();
+    assert!(!revoked_partitions.is_empty());
+    for partition_id in &revoked_partitions {
+        let offset = client
+            .get_consumer_offset(&group, &stream_id, &topic_id, Some(*partition_id))
+            .await
+            .unwrap();
+        assert_eq!(offset.stored_offset, MESSAGES_PER_PARTITION as u64);
+    }
+
+    // 7. The partition which isn't assigned to the member can't be polled explicitly
+    let result = client
+        .poll_assigned_messages(
+            &stream_id,
+            &topic_id,
+            Some(revoked_partitions[0]),
+            &group,
+            &PollingStrategy::next(),
+            1,
+            false,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(IggyError::InvalidResponse(code, _, _))
+            if code == IggyError::ConsumerGroupPartitionNotAssigned(0, 0, 0).as_code()
+    ));
+
+    // 8. The partition ID is ignored by the regular polling, which uses the partition assigned to the member
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(revoked_partitions[0]),
+            &group,
+            &PollingStrategy::next(),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    assert!(assigned_partitions.contains(&polled_messages.partition_id));
+
+    other_client
+        .leave_consumer_group(&stream_id, &topic_id, &consumer_group_id)
+        .await
+        .unwrap();
+    consumer.shutdown().await.unwrap();
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+fn create_consumer(client: &Arc<IggyClient>, commit_mode: CommitMode) -> IggyConsumer {
+    IggyConsumer::new(
+        client.clone(),
+        &Identifier::numeric(STREAM_ID).unwrap(),
+        &Identifier::numeric(TOPIC_ID).unwrap(),
+        &Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+        IggyConsumerConfig {
+            batch_size: BATCH_SIZE,
+            poll_interval: IggyDuration::new(Duration::from_millis(10)),
+            commit_mode,
+            ..Default::default()
+        },
+    )
+}
+
+async fn receive_messages(consumer: &mut IggyConsumer, count: u32) -> Vec<ReceivedMessage> {
+    let mut messages = Vec::new();
+    while messages.len() < count as usize {
+        let message = timeout(Duration::from_secs(5), consumer.next())
+            .await
+            .expect("Timed out waiting for the message")
+            .unwrap()
+            .unwrap();
+        messages.push(message);
+    }
+    messages
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32, start_id: u32, count: u32) {
+    let mut messages = (start_id..start_id + count)
+        .map(|id| Message::from_str(&format!("{partition_id}-{id}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            None,
+            None,
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+
+    // 3. Create the consumer group
+    client
+        .create_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+        )
+        .await
+        .unwrap();
+}
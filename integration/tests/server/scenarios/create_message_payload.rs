@@ -5,6 +5,7 @@ use iggy::consumer::Consumer;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use integration::test_server::{assert_clean_system, login_root, ClientFactory};
 use std::collections::HashMap;
@@ -39,6 +40,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         let headers = create_message_headers();
         messages.push(Message {
             id,
+            timestamp: 0,
             length: payload.len() as u32,
             payload,
             headers: Some(headers),
@@ -119,6 +121,8 @@ async fn init_system(client: &IggyClient) {
             None,
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
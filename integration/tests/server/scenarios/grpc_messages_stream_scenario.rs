@@ -0,0 +1,146 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use futures::StreamExt;
+use iggy::client::{Client, MessageClient, StreamClient, SystemClient, TopicClient, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::grpc::client::{GrpcClient, PolledMessagesStream};
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use integration::grpc_client::GrpcClientFactory;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const MESSAGES_COUNT: u64 = 3;
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+pub async fn run(client_factory: &GrpcClientFactory) {
+    let client = create_client(client_factory as &dyn ClientFactory).await;
+    login_root(&client).await;
+
+    // 1. The error of the failed command should be returned as the same error as for the TCP transport
+    let error = client
+        .get_stream(&Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::InvalidResponse(code, _, _) if code == IggyError::StreamIdNotFound(STREAM_ID).as_code()
+    ));
+
+    init_system(&client).await;
+    send_messages(&client, 0..MESSAGES_COUNT).await;
+
+    // 2. Streaming the messages should fail for the unauthenticated client
+    let grpc_client = GrpcClient::new(&client_factory.server_addr).unwrap();
+    grpc_client.connect().await.unwrap();
+    assert!(poll_messages_stream(&grpc_client).await.is_err());
+
+    // 3. Stream the existing messages and then the newly sent ones, once they're available
+    grpc_client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    let mut stream = poll_messages_stream(&grpc_client).await.unwrap();
+    let mut offsets = receive_offsets(&mut stream, MESSAGES_COUNT).await;
+    send_messages(&client, MESSAGES_COUNT..MESSAGES_COUNT * 2).await;
+    offsets.extend(receive_offsets(&mut stream, MESSAGES_COUNT).await);
+    assert_eq!(offsets, (0..MESSAGES_COUNT * 2).collect::<Vec<_>>());
+
+    // 4. Once disconnected, the client should be removed from the server
+    drop(stream);
+    grpc_client.disconnect().await.unwrap();
+    let mut clients_count = 2;
+    for _ in 0..50 {
+        clients_count = client.get_clients().await.unwrap().len();
+        if clients_count == 1 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(clients_count, 1);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            None,
+            None,
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
+
+async fn send_messages(client: &IggyClient, offsets: std::ops::Range<u64>) {
+    let mut messages = offsets
+        .map(|offset| Message::from_str(&format!("message {offset}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn poll_messages_stream(client: &GrpcClient) -> Result<PolledMessagesStream, IggyError> {
+    client
+        .poll_messages_stream(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+            IggyDuration::from_str("10ms").unwrap(),
+        )
+        .await
+}
+
+async fn receive_offsets(stream: &mut PolledMessagesStream, count: u64) -> Vec<u64> {
+    let mut offsets = Vec::new();
+    while (offsets.len() as u64) < count {
+        let polled_messages = timeout(TIMEOUT, stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        offsets.extend(
+            polled_messages
+                .messages
+                .iter()
+                .map(|message| message.offset),
+        );
+    }
+    offsets
+}
@@ -0,0 +1,290 @@
+use crate::server::scenarios::{
+    cleanup, create_client, get_consumer_group, CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME,
+    PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use futures::{SinkExt, StreamExt};
+use iggy::client::{
+    ConsumerGroupClient, MessageClient, PersonalAccessTokenClient, StreamClient, TopicClient,
+    UserClient,
+};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use integration::http_client::HttpClientFactory;
+use integration::test_server::{assert_clean_system, ClientFactory};
+use serde_json::{json, Value};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::Message as WebSocketMessage;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+const MESSAGES_COUNT: u64 = 3;
+const PAT_NAME: &str = "live-messages";
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+pub async fn run(client_factory: &HttpClientFactory) {
+    let client = create_client(client_factory as &dyn ClientFactory).await;
+    let identity = client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    let access_token = identity.tokens.unwrap().access_token.token;
+    init_system(&client).await;
+    let address = client_factory.server_addr.as_str();
+    let path = format!("/streams/{STREAM_ID}/topics/{TOPIC_ID}/messages");
+
+    // 1. Subscribing without the token or with the token passed as the parameter should fail
+    let (response, _) = send_request(address, &format!("{path}/sse"), &[]).await;
+    assert!(response.starts_with("HTTP/1.1 401"));
+    let (response, _) = send_request(
+        address,
+        &format!("{path}/sse?access_token={access_token}"),
+        &[],
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 401"));
+
+    // 2. Subscribe via SSE using the JWT passed as the header and receive the existing messages
+    let authorization = format!("Bearer {access_token}");
+    let (response, mut stream) = send_request(
+        address,
+        &format!("{path}/sse?partition_id={PARTITION_ID}&kind=offset&value=0"),
+        &[("Authorization", &authorization)],
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200"));
+    assert!(response.contains("content-type: text/event-stream"));
+    let events = read_until(&mut stream, response, "id: 2\n").await;
+    for offset in 0..MESSAGES_COUNT {
+        assert!(events.contains(&format!("event: message\nid: {offset}\n")));
+    }
+
+    // 3. Resume the SSE subscription after the last received event
+    let (response, mut stream) = send_request(
+        address,
+        &format!("{path}/sse?partition_id={PARTITION_ID}"),
+        &[("Authorization", &authorization), ("Last-Event-ID", "1")],
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200"));
+    let events = read_until(&mut stream, response, "id: 2\n").await;
+    assert!(!events.contains("id: 1\n"));
+
+    // 4. Connecting via WebSocket with the invalid credentials in the first message should close the connection
+    let url = format!("ws://{address}{path}/ws");
+    let (mut websocket, _) = connect_async(&url).await.unwrap();
+    send_json(&mut websocket, json!({ "access_token": "invalid" })).await;
+    let reply = read_json(&mut websocket).await;
+    assert_eq!(reply["type"], "error");
+    assert_eq!(
+        read_message(&mut websocket).await,
+        WebSocketMessage::Close(Some(CloseFrame {
+            code: CloseCode::Policy,
+            reason: "".into()
+        }))
+    );
+
+    // 5. Connect via WebSocket, authenticate using the personal access token and receive the existing messages
+    let personal_access_token = client
+        .create_personal_access_token(PAT_NAME, PersonalAccessTokenExpiry::NeverExpire)
+        .await
+        .unwrap()
+        .token;
+    let (mut websocket, _) = connect_async(format!(
+        "{url}?partition_id={PARTITION_ID}&kind=offset&value=0"
+    ))
+    .await
+    .unwrap();
+    send_json(
+        &mut websocket,
+        json!({ "personal_access_token": personal_access_token }),
+    )
+    .await;
+    let reply = read_json(&mut websocket).await;
+    assert_eq!(reply["type"], "authenticated");
+    let reply = read_json(&mut websocket).await;
+    assert_eq!(reply["type"], "messages");
+    assert_eq!(
+        reply["messages"].as_array().unwrap().len() as u64,
+        MESSAGES_COUNT
+    );
+
+    // 6. Send the message via WebSocket and receive it via the same subscription
+    send_json(
+        &mut websocket,
+        json!({
+            "partitioning": { "kind": "partition_id", "value": "AQAAAA==" },
+            "messages": [{ "payload": "bGl2ZQ==" }]
+        }),
+    )
+    .await;
+    let mut is_sent = false;
+    let mut is_received = false;
+    while !is_sent || !is_received {
+        let reply = read_json(&mut websocket).await;
+        match reply["type"].as_str().unwrap() {
+            "sent" => {
+                assert_eq!(reply["count"], 1);
+                is_sent = true;
+            }
+            "messages" => {
+                let message = &reply["messages"][0];
+                assert_eq!(message["offset"], MESSAGES_COUNT);
+                assert_eq!(message["payload"], "bGl2ZQ==");
+                is_received = true;
+            }
+            _ => panic!("Unexpected reply: {reply}"),
+        }
+    }
+
+    // 7. Ping and close the WebSocket connection
+    websocket
+        .send(WebSocketMessage::Ping(b"ping".to_vec()))
+        .await
+        .unwrap();
+    assert_eq!(
+        read_message(&mut websocket).await,
+        WebSocketMessage::Pong(b"ping".to_vec())
+    );
+    websocket.close(None).await.unwrap();
+    assert!(matches!(
+        read_message(&mut websocket).await,
+        WebSocketMessage::Close(_)
+    ));
+
+    // 8. Join the consumer group via WebSocket using the JWT passed as the header and leave it once the connection is closed
+    client
+        .create_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+        )
+        .await
+        .unwrap();
+    let mut request = format!("{url}?consumer_group_id={CONSUMER_GROUP_ID}")
+        .into_client_request()
+        .unwrap();
+    request
+        .headers_mut()
+        .insert("Authorization", authorization.parse().unwrap());
+    let (mut websocket, _) = connect_async(request).await.unwrap();
+    let mut members_count = 0;
+    for _ in 0..50 {
+        members_count = get_consumer_group(&client).await.members_count;
+        if members_count == 1 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(members_count, 1);
+    websocket.close(None).await.unwrap();
+    for _ in 0..50 {
+        members_count = get_consumer_group(&client).await.members_count;
+        if members_count == 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(members_count, 0);
+
+    client.delete_personal_access_token(PAT_NAME).await.unwrap();
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            None,
+            None,
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|offset| Message::from_str(&format!("message {offset}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+/// Sends the GET request and returns the response read so far (at least the headers) and the connection.
+async fn send_request(address: &str, path: &str, headers: &[(&str, &str)]) -> (String, TcpStream) {
+    let mut stream = TcpStream::connect(address).await.unwrap();
+    let mut request = format!("GET {path} HTTP/1.1\r\nHost: {address}\r\n");
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let response = read_until(&mut stream, String::new(), "\r\n\r\n").await;
+    (response, stream)
+}
+
+async fn read_until(stream: &mut TcpStream, mut data: String, pattern: &str) -> String {
+    let mut buffer = [0u8; 4096];
+    while !data.contains(pattern) {
+        let read = timeout(TIMEOUT, stream.read(&mut buffer))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(read > 0, "Connection closed before receiving: {pattern}");
+        data.push_str(&String::from_utf8_lossy(&buffer[..read]));
+    }
+    data
+}
+
+async fn send_json(websocket: &mut WebSocket, value: Value) {
+    websocket
+        .send(WebSocketMessage::Text(value.to_string()))
+        .await
+        .unwrap();
+}
+
+async fn read_message(websocket: &mut WebSocket) -> WebSocketMessage {
+    timeout(TIMEOUT, websocket.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap()
+}
+
+async fn read_json(websocket: &mut WebSocket) -> Value {
+    let WebSocketMessage::Text(text) = read_message(websocket).await else {
+        panic!("Expected the text message");
+    };
+    serde_json::from_str(&text).unwrap()
+}
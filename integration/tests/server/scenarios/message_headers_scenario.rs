@@ -11,6 +11,7 @@ use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use integration::test_server::{assert_clean_system, login_root, ClientFactory};
 use std::collections::HashMap;
@@ -29,6 +30,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         let headers = create_message_headers();
         messages.push(Message {
             id,
+            timestamp: 0,
             length: payload.len() as u32,
             payload,
             headers: Some(headers),
@@ -110,6 +112,8 @@ async fn init_system(client: &IggyClient) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
@@ -7,6 +7,7 @@ use iggy::error::IggyError::InvalidResponse;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use integration::test_server::{assert_clean_system, login_root, ClientFactory};
 use std::collections::HashMap;
@@ -129,6 +130,8 @@ async fn init_system(client: &IggyClient) {
             None,
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
@@ -223,6 +226,7 @@ fn create_message(header_size: Option<usize>, payload_size: usize) -> Message {
     let payload = create_string_of_size(payload_size);
     Message {
         id: 1u128,
+        timestamp: 0,
         length: payload.len() as u32,
         payload: Bytes::from(payload),
         headers,
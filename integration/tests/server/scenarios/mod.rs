@@ -8,11 +8,17 @@ use integration::test_server::{delete_user, ClientFactory};
 pub mod consumer_group_join_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
+pub mod consumer_stream_scenario;
 pub mod create_message_payload;
+pub mod grpc_messages_stream_scenario;
+pub mod live_messages_scenario;
 pub mod message_headers_scenario;
 pub mod message_size_scenario;
+pub mod producer_scenario;
+pub mod schema_registry_scenario;
 pub mod stream_size_validation_scenario;
 pub mod system_scenario;
+pub mod typed_messages_scenario;
 pub mod user_scenario;
 
 const STREAM_ID: u32 = 1;
@@ -0,0 +1,195 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::clients::producer::{IggyProducer, IggyProducerConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+const MESSAGES_COUNT: u32 = 100;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let client = Arc::new(client);
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let partitioning = Partitioning::partition_id(PARTITION_ID);
+
+    // 1. Send the messages using the small batches, to make sure they are split into many of them
+    let producer = IggyProducer::new(
+        client.clone(),
+        &stream_id,
+        &topic_id,
+        IggyProducerConfig {
+            batch_size_bytes: 500,
+            linger: IggyDuration::new(Duration::from_millis(10)),
+            max_in_flight_bytes: 2000,
+            ..Default::default()
+        },
+    );
+    let mut deliveries = Vec::new();
+    for id in 1..=MESSAGES_COUNT {
+        let message = Message::from_str(&format!("message-{id}")).unwrap();
+        deliveries.push(producer.send(&partitioning, message).await.unwrap());
+    }
+
+    // 2. Each message gets the delivery report with the consecutive offset
+    for (offset, delivery) in deliveries.into_iter().enumerate() {
+        let report = delivery.await.unwrap();
+        assert_eq!(report.partition_id, PARTITION_ID);
+        assert_eq!(report.offset, Some(offset as u64));
+    }
+
+    // 3. The delayed message is reported without the offset
+    let mut message = Message::from_str("delayed").unwrap();
+    message
+        .set_delay(IggyDuration::new(Duration::from_secs(60)))
+        .unwrap();
+    let delayed_delivery = producer.send(&partitioning, message).await.unwrap();
+    let delivery = producer
+        .send(&partitioning, Message::from_str("next").unwrap())
+        .await
+        .unwrap();
+    let delayed_report = delayed_delivery.await.unwrap();
+    assert_eq!(delayed_report.partition_id, PARTITION_ID);
+    assert_eq!(delayed_report.offset, None);
+    assert_eq!(delivery.await.unwrap().offset, Some(MESSAGES_COUNT as u64));
+    producer.flush().await.unwrap();
+
+    // 4. The messages are stored in the order they were sent
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(message.offset, index as u64);
+        assert_eq!(message.payload, format!("message-{}", index + 1).as_bytes());
+    }
+
+    // 5. The messages sent using the messages key end up in the same partition
+    let partitioning = Partitioning::messages_key_str("key").unwrap();
+    let first_delivery = producer
+        .send(&partitioning, Message::from_str("first").unwrap())
+        .await
+        .unwrap();
+    let second_delivery = producer
+        .send(&partitioning, Message::from_str("second").unwrap())
+        .await
+        .unwrap();
+    let first_report = first_delivery.await.unwrap();
+    let second_report = second_delivery.await.unwrap();
+    assert_eq!(first_report.partition_id, second_report.partition_id);
+    let topic = client.get_topic(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(
+        first_report.partition_id,
+        topic
+            .partitioning_scheme
+            .calculate_partition_id(b"key", PARTITIONS_COUNT)
+    );
+    assert!(first_report.offset < second_report.offset);
+
+    // 6. The messages sent using the messages key are stored along with it
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(first_report.partition_id),
+            &Consumer::default(),
+            &PollingStrategy::offset(first_report.offset.unwrap()),
+            2,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 2);
+    assert_eq!(polled_messages.messages[0].payload, "first".as_bytes());
+    assert_eq!(polled_messages.messages[1].payload, "second".as_bytes());
+    for message in polled_messages.messages {
+        assert_eq!(message.key, Some(Bytes::from_static(b"key")));
+    }
+
+    // 7. The balanced messages are sent to the partitions in turn
+    let mut partition_ids = Vec::new();
+    for _ in 0..PARTITIONS_COUNT {
+        let delivery = producer
+            .send(
+                &Partitioning::balanced(),
+                Message::from_str("balanced").unwrap(),
+            )
+            .await
+            .unwrap();
+        partition_ids.push(delivery.await.unwrap().partition_id);
+    }
+    partition_ids.sort();
+    assert_eq!(partition_ids, (1..=PARTITIONS_COUNT).collect::<Vec<_>>());
+
+    // 8. The message sent to the partition which doesn't exist is rejected
+    let result = producer
+        .send(
+            &Partitioning::partition_id(PARTITIONS_COUNT + 1),
+            Message::from_str("unknown").unwrap(),
+        )
+        .await;
+    assert!(matches!(result, Err(IggyError::PartitionNotFound(_, _, _))));
+
+    // 9. The closed producer doesn't accept the new messages
+    producer.close().await;
+    let result = producer
+        .send(&partitioning, Message::from_str("closed").unwrap())
+        .await;
+    assert!(matches!(result, Err(IggyError::ProducerClosed)));
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            None,
+            None,
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
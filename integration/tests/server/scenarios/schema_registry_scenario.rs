@@ -0,0 +1,244 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use bytes::Bytes;
+use iggy::client::{MessageClient, SchemaClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::schemas::schema_decoder::SchemaDecoder;
+use iggy::schemas::schema_id::{extract_schema_id, inject_schema_id};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::schema_compatibility::SchemaCompatibility;
+use iggy::utils::schema_type::SchemaType;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use serde_json::json;
+
+const SCHEMA_V1: &str = r#"{
+    "type": "object",
+    "properties": {
+        "id": { "type": "integer" },
+        "name": { "type": "string" }
+    },
+    "required": ["id", "name"]
+}"#;
+
+const INCOMPATIBLE_SCHEMA: &str = r#"{
+    "type": "object",
+    "properties": {
+        "id": { "type": "integer" },
+        "name": { "type": "string" },
+        "email": { "type": "string" }
+    },
+    "required": ["id", "name", "email"]
+}"#;
+
+const SCHEMA_V2: &str = r#"{
+    "type": "object",
+    "properties": {
+        "id": { "type": "integer" },
+        "name": { "type": "string" },
+        "email": { "type": "string" }
+    },
+    "required": ["id", "name"]
+}"#;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let partitioning = Partitioning::partition_id(PARTITION_ID);
+
+    // 1. Topic without the schema accepts any payload
+    let schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert!(schemas.is_empty());
+    let mut messages = vec![create_message(b"not a json", None)];
+    client
+        .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await
+        .unwrap();
+
+    // 2. Register the first version of the schema with the validation enabled
+    let schema_v1 = client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::Json,
+            SchemaCompatibility::Backward,
+            true,
+            SCHEMA_V1,
+        )
+        .await
+        .unwrap();
+    assert!(schema_v1.id > 0);
+    assert_eq!(schema_v1.stream_id, STREAM_ID);
+    assert_eq!(schema_v1.topic_id, TOPIC_ID);
+    assert_eq!(schema_v1.version, 1);
+    assert_eq!(schema_v1.schema_type, SchemaType::Json);
+    assert!(schema_v1.validation_enabled);
+
+    // 3. Registering the backward incompatible schema should fail
+    let result = client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::Json,
+            SchemaCompatibility::Backward,
+            true,
+            INCOMPATIBLE_SCHEMA,
+        )
+        .await;
+    assert!(result.is_err());
+
+    // 4. Registering the invalid definition should fail
+    let result = client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::Json,
+            SchemaCompatibility::Backward,
+            true,
+            "{ invalid",
+        )
+        .await;
+    assert!(result.is_err());
+
+    // 5. Register the second, backward compatible version of the schema
+    let schema_v2 = client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::Json,
+            SchemaCompatibility::Backward,
+            true,
+            SCHEMA_V2,
+        )
+        .await
+        .unwrap();
+    assert_ne!(schema_v2.id, schema_v1.id);
+    assert_eq!(schema_v2.version, 2);
+
+    let schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(schemas.len(), 2);
+    assert_eq!(schemas[0].id, schema_v1.id);
+    assert_eq!(schemas[1].id, schema_v2.id);
+
+    let schema = client.get_schema(schema_v2.id).await.unwrap();
+    assert_eq!(schema, schema_v2);
+
+    // 6. Valid payloads are accepted, the latest schema ID is stamped when the header is missing
+    let mut messages = vec![
+        create_message(br#"{"id": 1, "name": "first"}"#, None),
+        create_message(br#"{"id": 2, "name": "second"}"#, Some(schema_v1.id)),
+    ];
+    client
+        .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await
+        .unwrap();
+
+    // 7. Payloads not matching the schema are rejected
+    let mut messages = vec![create_message(br#"{"id": "3"}"#, None)];
+    let result = client
+        .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await;
+    assert!(result.is_err());
+
+    let mut messages = vec![create_message(b"not a json", None)];
+    let result = client
+        .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await;
+    assert!(result.is_err());
+
+    // 8. Messages referencing the schema not registered for the topic are rejected
+    let mut messages = vec![create_message(
+        br#"{"id": 4, "name": "fourth"}"#,
+        Some(schema_v2.id + 100),
+    )];
+    let result = client
+        .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await;
+    assert!(result.is_err());
+
+    // 9. Poll the messages and decode the payloads using the referenced schemas
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 3);
+
+    let decoder = SchemaDecoder::new();
+    let first = &polled_messages.messages[0];
+    assert!(extract_schema_id(&first.headers).unwrap().is_none());
+    assert!(decoder.decode(&client, first).await.unwrap().is_none());
+
+    let second = &polled_messages.messages[1];
+    assert_eq!(
+        extract_schema_id(&second.headers).unwrap(),
+        Some(schema_v2.id)
+    );
+    let value = decoder.decode(&client, second).await.unwrap().unwrap();
+    assert_eq!(value, json!({"id": 1, "name": "first"}));
+
+    let third = &polled_messages.messages[2];
+    assert_eq!(
+        extract_schema_id(&third.headers).unwrap(),
+        Some(schema_v1.id)
+    );
+    let value = decoder.decode(&client, third).await.unwrap().unwrap();
+    assert_eq!(value, json!({"id": 2, "name": "second"}));
+
+    // 10. Deleting the stream removes the schemas of its topics
+    cleanup(&client, false).await;
+    assert!(client.get_schema(schema_v1.id).await.is_err());
+    assert!(client.get_schema(schema_v2.id).await.is_err());
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            None,
+            None,
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
+
+fn create_message(payload: &'static [u8], schema_id: Option<u32>) -> Message {
+    let mut headers = None;
+    if let Some(schema_id) = schema_id {
+        inject_schema_id(&mut headers, schema_id).unwrap();
+    }
+    Message::new(None, Bytes::from_static(payload), headers)
+}
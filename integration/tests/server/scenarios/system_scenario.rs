@@ -12,11 +12,13 @@ use iggy::client::{
 use iggy::clients::client::{IggyClient, IggyClientBackgroundConfig};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::Consumer;
+use iggy::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::messages::PolledMessage;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
@@ -107,6 +109,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
@@ -191,6 +195,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await;
     assert!(create_topic_result.is_err());
@@ -206,6 +212,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID + 1),
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await;
     assert!(create_topic_result.is_err());
@@ -279,7 +287,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.name, TOPIC_NAME);
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
     assert_eq!(topic.partitions.len(), PARTITIONS_COUNT as usize);
-    assert_eq!(topic.size, 55914);
+    assert_eq!(topic.size, 56914);
     assert_eq!(topic.messages_count, MESSAGES_COUNT as u64);
     let topic_partition = topic.partitions.get((PARTITION_ID - 1) as usize).unwrap();
     assert_eq!(topic_partition.id, PARTITION_ID);
@@ -344,6 +352,25 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(offset.stored_offset, stored_offset);
 
+    let consumer_lag = client
+        .get_consumer_lag(
+            &consumer,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+        )
+        .await
+        .unwrap();
+    let expected_lag = MESSAGES_COUNT as u64 - stored_offset - 1;
+    assert_eq!(consumer_lag.total_lag, expected_lag);
+    let partition_lag = consumer_lag
+        .partitions
+        .iter()
+        .find(|partition| partition.partition_id == PARTITION_ID)
+        .unwrap();
+    assert_eq!(partition_lag.stored_offset, Some(stored_offset));
+    assert_eq!(partition_lag.end_offset, MESSAGES_COUNT as u64);
+    assert_eq!(partition_lag.lag, expected_lag);
+
     // 25. Poll messages from the specific partition in topic using next with auto commit
     let messages_count = 10;
     let polled_messages = client
@@ -379,6 +406,67 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(offset.stored_offset, expected_last_offset);
 
+    let consumer_offsets_reset = client
+        .reset_consumer_offsets(
+            &consumer,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            OffsetResetStrategy::Shift(-5),
+            true,
+        )
+        .await
+        .unwrap();
+    assert!(consumer_offsets_reset.dry_run);
+    let partition_reset = consumer_offsets_reset
+        .partitions
+        .iter()
+        .find(|partition| partition.partition_id == PARTITION_ID)
+        .unwrap();
+    assert_eq!(partition_reset.previous_offset, Some(expected_last_offset));
+    assert_eq!(partition_reset.new_offset, Some(expected_last_offset - 5));
+
+    client
+        .reset_consumer_offsets(
+            &consumer,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            OffsetResetStrategy::Earliest,
+            false,
+        )
+        .await
+        .unwrap();
+    let consumer_lag = client
+        .get_consumer_lag(
+            &consumer,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(consumer_lag.total_lag, MESSAGES_COUNT as u64);
+
+    let consumer_offsets_reset = client
+        .reset_consumer_offsets(
+            &consumer,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            OffsetResetStrategy::Offset(expected_last_offset + 1),
+            false,
+        )
+        .await
+        .unwrap();
+    assert!(!consumer_offsets_reset.dry_run);
+    let offset = client
+        .get_consumer_offset(
+            &consumer,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap();
+    assert_eq!(offset.stored_offset, expected_last_offset);
+
     // 27. Get the consumer groups and validate that there are no groups
     let consumer_groups = client
         .get_consumer_groups(
@@ -551,6 +639,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(updated_replication_factor),
             IggyExpiry::ExpireDuration(message_expiry_duration),
             Some(updated_max_topic_size),
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
@@ -575,7 +665,35 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(updated_topic.max_topic_size, Some(updated_max_topic_size));
     assert_eq!(updated_topic.replication_factor, updated_replication_factor);
 
-    // 37. Purge the existing topic and ensure it has no messages
+    // 37. Truncate the partition, then purge the existing topic and ensure it has no messages
+    let truncation = client
+        .truncate_partition(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITION_ID,
+            5,
+        )
+        .await
+        .unwrap();
+    assert_eq!(truncation.partition_id, PARTITION_ID);
+    assert_eq!(truncation.low_watermark, 5);
+    assert_eq!(truncation.deleted_segments, 0);
+
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &consumer,
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT - 5);
+    assert_eq!(polled_messages.messages[0].offset, 5);
+
     client
         .purge_topic(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -606,6 +724,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .update_stream(
             &Identifier::numeric(STREAM_ID).unwrap(),
             &updated_stream_name,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
@@ -617,10 +736,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(updated_stream.name, updated_stream_name);
 
-    // 39. Purge the existing stream and ensure it has no messages
+    // 39. Send the messages waiting for their durability, then purge the existing stream and ensure it has no messages
     let mut messages = create_messages();
     client
-        .send_messages(
+        .send_durable_messages(
             &Identifier::numeric(STREAM_ID).unwrap(),
             &Identifier::numeric(TOPIC_ID).unwrap(),
             &Partitioning::partition_id(PARTITION_ID),
@@ -689,6 +808,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             None,
             IggyExpiry::NeverExpire,
             None,
+            None,
+            TopicConfigOverrides::default(),
         )
         .await
         .unwrap();
@@ -740,6 +861,7 @@ fn create_messages() -> Vec<Message> {
         let payload = create_message_payload(offset as u64);
         messages.push(Message {
             id,
+            timestamp: 0,
             length: payload.len() as u32,
             payload,
             headers: None,
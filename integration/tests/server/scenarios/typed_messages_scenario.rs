@@ -0,0 +1,168 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::clients::typed_consumer::{DecodeErrorHandler, FailOnDecodeErrors, TypedConsumer};
+use iggy::clients::typed_producer::TypedProducer;
+use iggy::codecs::json_codec::JsonCodec;
+use iggy::codecs::msgpack_codec::MessagePackCodec;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::messages::PolledMessage;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::expiry::IggyExpiry;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+const ORDERS_COUNT: u64 = 5;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Order {
+    id: u64,
+    side: String,
+    price: f64,
+}
+
+#[derive(Debug, Default)]
+struct RecordingDecodeErrorHandler {
+    offsets: Arc<Mutex<Vec<u64>>>,
+}
+
+impl DecodeErrorHandler for RecordingDecodeErrorHandler {
+    fn handle(&self, message: &PolledMessage, _error: IggyError) -> Result<(), IggyError> {
+        self.offsets.lock().unwrap().push(message.offset);
+        Ok(())
+    }
+}
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let client = Arc::new(client);
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let partitioning = Partitioning::partition_id(PARTITION_ID);
+
+    // 1. Send the orders encoded as JSON
+    let producer = TypedProducer::new(client.clone(), &stream_id, &topic_id, JsonCodec)
+        .with_partitioning(partitioning.clone());
+    let orders = (1..=ORDERS_COUNT).map(create_order).collect::<Vec<_>>();
+    producer.send(&orders[..4]).await.unwrap();
+    producer.send_one(&orders[4]).await.unwrap();
+
+    // 2. Send the raw message which isn't a valid JSON order, and the order encoded with the different codec
+    let mut messages = vec![Message::from_str("not an order").unwrap()];
+    client
+        .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+        .await
+        .unwrap();
+    let msgpack_producer =
+        TypedProducer::new(client.clone(), &stream_id, &topic_id, MessagePackCodec)
+            .with_partitioning(partitioning.clone());
+    msgpack_producer
+        .send_one(&create_order(ORDERS_COUNT + 1))
+        .await
+        .unwrap();
+
+    // 3. Poll the orders, the messages which couldn't be decoded are routed to the handler
+    let decode_error_handler = RecordingDecodeErrorHandler::default();
+    let failed_offsets = decode_error_handler.offsets.clone();
+    let mut consumer: TypedConsumer<Order, _> = TypedConsumer::new(
+        client.clone(),
+        &Consumer::default(),
+        &stream_id,
+        &topic_id,
+        JsonCodec,
+    )
+    .with_partition_id(PARTITION_ID)
+    .with_polling_strategy(PollingStrategy::offset(0))
+    .with_auto_commit(false)
+    .with_decode_error_handler(Box::new(decode_error_handler));
+
+    let polled_orders = consumer.poll().await.unwrap();
+    assert_eq!(polled_orders.len() as u64, ORDERS_COUNT);
+    for (offset, message) in polled_orders.iter().enumerate() {
+        assert_eq!(message.offset, offset as u64);
+        assert_eq!(message.partition_id, PARTITION_ID);
+        assert_eq!(message.schema_id, None);
+        assert_eq!(message.value, orders[offset]);
+    }
+    assert_eq!(*failed_offsets.lock().unwrap(), vec![5, 6]);
+
+    // 4. The offset is advanced after each poll
+    let polled_orders = consumer.poll().await.unwrap();
+    assert!(polled_orders.is_empty());
+
+    // 5. The offset can be stored manually
+    consumer.store_offset(6).await.unwrap();
+    let offset = client
+        .get_consumer_offset(
+            &Consumer::default(),
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap();
+    assert_eq!(offset.stored_offset, 6);
+
+    // 6. The decode errors can fail the poll
+    let mut consumer: TypedConsumer<Order, _> = TypedConsumer::new(
+        client.clone(),
+        &Consumer::default(),
+        &stream_id,
+        &topic_id,
+        JsonCodec,
+    )
+    .with_partition_id(PARTITION_ID)
+    .with_polling_strategy(PollingStrategy::offset(0))
+    .with_auto_commit(false)
+    .with_decode_error_handler(Box::new(FailOnDecodeErrors));
+    assert!(consumer.poll().await.is_err());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            None,
+            None,
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
+
+fn create_order(id: u64) -> Order {
+    Order {
+        id,
+        side: ["buy", "sell"][(id % 2) as usize].to_string(),
+        price: id as f64 * 10.5,
+    }
+}
@@ -1,11 +1,14 @@
 use crate::server::scenarios::{
     consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, message_size_scenario, stream_size_validation_scenario,
-    system_scenario, user_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, consumer_stream_scenario,
+    create_message_payload, message_headers_scenario, message_size_scenario, producer_scenario,
+    schema_registry_scenario, stream_size_validation_scenario, system_scenario,
+    typed_messages_scenario, user_scenario,
 };
-use integration::{tcp_client::TcpClientFactory, test_server::TestServer};
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{IpAddrKind, TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
 
 #[tokio::test]
 #[parallel]
@@ -37,6 +40,66 @@ async fn message_headers_scenario_should_be_valid() {
     message_headers_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn consumer_stream_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    consumer_stream_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn producer_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    producer_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schema_registry_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    schema_registry_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schema_registry_scenario_with_encryption_should_be_valid() {
+    let envs = HashMap::from([
+        (
+            "IGGY_SYSTEM_ENCRYPTION_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_ENCRYPTION_KEY".to_string(),
+            "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    schema_registry_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn typed_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    typed_messages_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn create_message_payload_scenario_should_be_valid() {
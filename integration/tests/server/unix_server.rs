@@ -0,0 +1,101 @@
+use crate::server::scenarios::{
+    consumer_group_with_multiple_clients_polling_messages_scenario, message_headers_scenario,
+    system_scenario, user_scenario,
+};
+use iggy::client::{StreamClient, SystemClient};
+use iggy::clients::client::{IggyClient, IggyClientBackgroundConfig};
+use iggy::error::IggyError;
+use integration::test_server::{ClientFactory, IpAddrKind, TestServer};
+use integration::unix_client::UnixClientFactory;
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::os::unix::fs::PermissionsExt;
+use tokio::net::UnixStream;
+
+fn start_server(extra_envs: &[(&str, String)]) -> (TestServer, UnixClientFactory) {
+    let mut envs = HashMap::from([("IGGY_UNIX_ENABLED".to_string(), "true".to_string())]);
+    for (key, value) in extra_envs {
+        envs.insert(key.to_string(), value.clone());
+    }
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let socket_path = test_server.get_unix_socket_path().unwrap();
+    (test_server, UnixClientFactory { socket_path })
+}
+
+#[tokio::test]
+#[parallel]
+async fn system_scenario_should_be_valid() {
+    let (_test_server, client_factory) = start_server(&[]);
+    system_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn user_scenario_should_be_valid() {
+    let (_test_server, client_factory) = start_server(&[]);
+    user_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_headers_scenario_should_be_valid() {
+    let (_test_server, client_factory) = start_server(&[]);
+    message_headers_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_be_valid() {
+    let (_test_server, client_factory) = start_server(&[]);
+    consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn socket_should_be_created_with_configured_permissions() {
+    let (_test_server, client_factory) =
+        start_server(&[("IGGY_UNIX_PERMISSIONS", "600".to_string())]);
+    let metadata = std::fs::metadata(&client_factory.socket_path).unwrap();
+    assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+}
+
+#[tokio::test]
+#[parallel]
+async fn peer_mapped_by_uid_should_be_authenticated_without_credentials() {
+    // The server runs as the same user as the tests, so its UID is the one of the current process.
+    let (stream, _) = UnixStream::pair().unwrap();
+    let uid = stream.peer_cred().unwrap().uid();
+    let (_test_server, client_factory) =
+        start_server(&[("IGGY_UNIX_PEER_USERS", format!("{uid}=iggy"))]);
+
+    let client = IggyClient::create(
+        client_factory.create_client().await,
+        IggyClientBackgroundConfig::default(),
+        None,
+        None,
+        None,
+    );
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.user_id, Some(1));
+    assert_eq!(me.transport, "Unix");
+    client.create_stream("unix", None).await.unwrap();
+    assert_eq!(client.get_streams().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+#[parallel]
+async fn peer_not_mapped_by_uid_should_not_be_authenticated() {
+    let (_test_server, client_factory) =
+        start_server(&[("IGGY_UNIX_PEER_USERS", "4294967295=iggy".to_string())]);
+
+    let client = IggyClient::create(
+        client_factory.create_client().await,
+        IggyClientBackgroundConfig::default(),
+        None,
+        None,
+        None,
+    );
+    let error = client.get_streams().await.unwrap_err();
+    assert_eq!(error.as_code(), IggyError::Unauthenticated.as_code());
+}
@@ -1,6 +1,7 @@
 use crate::streaming::common::test_setup::TestSetup;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use server::streaming::topics::topic::Topic;
 use std::sync::{
     atomic::{AtomicU32, AtomicU64},
@@ -87,6 +88,8 @@ async fn init_topic(setup: &TestSetup) -> Topic {
         CompressionAlgorithm::default(),
         None,
         1,
+        None,
+        TopicConfigOverrides::default(),
     )
     .unwrap();
     topic.persist().await.unwrap();
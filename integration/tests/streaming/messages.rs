@@ -41,6 +41,7 @@ async fn should_persist_messages_and_then_load_them_by_timestamp() {
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU32::new(0)),
+        Default::default(),
     );
 
     let mut messages = Vec::with_capacity(messages_count as usize);
@@ -64,6 +65,7 @@ async fn should_persist_messages_and_then_load_them_by_timestamp() {
         );
         let message = Message {
             id,
+            timestamp: 0,
             length: payload.len() as u32,
             payload: payload.clone(),
             headers: Some(headers),
@@ -89,6 +91,7 @@ async fn should_persist_messages_and_then_load_them_by_timestamp() {
         );
         let message = Message {
             id,
+            timestamp: 0,
             length: payload.len() as u32,
             payload: payload.clone(),
             headers: Some(headers),
@@ -170,6 +173,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU32::new(0)),
+        Default::default(),
     );
 
     let mut messages = Vec::with_capacity(messages_count as usize);
@@ -192,12 +196,14 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         );
         let appended_message = Message {
             id,
+            timestamp: 0,
             length: payload.len() as u32,
             payload: payload.clone(),
             headers: Some(headers.clone()),
         };
         let message = Message {
             id,
+            timestamp: 0,
             length: payload.len() as u32,
             payload: payload.clone(),
             headers: Some(headers),
@@ -231,6 +237,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU32::new(0)),
+        Default::default(),
     );
     loaded_partition.load().await.unwrap();
     let loaded_messages = loaded_partition
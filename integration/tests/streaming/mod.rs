@@ -29,6 +29,7 @@ fn create_message(id: u128, payload: &str) -> Message {
     let payload = Bytes::from(payload.to_string());
     Message {
         id,
+        timestamp: 0,
         length: payload.len() as u32,
         payload,
         headers: None,
@@ -1,5 +1,11 @@
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::timestamp_type::TimestampType;
+use server::configs::system::{PartitionConfig, SegmentConfig, SystemConfig};
 use server::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use server::streaming::partitions::partition::Partition;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
@@ -29,6 +35,7 @@ async fn should_persist_partition_with_segment() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Default::default(),
         );
 
         partition.persist().await.unwrap();
@@ -59,6 +66,7 @@ async fn should_load_existing_partition_from_disk() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Default::default(),
         );
         partition.persist().await.unwrap();
         assert_persisted_partition(&partition.path, with_segment).await;
@@ -76,6 +84,7 @@ async fn should_load_existing_partition_from_disk() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Default::default(),
         );
         loaded_partition.load().await.unwrap();
 
@@ -125,6 +134,7 @@ async fn should_delete_existing_partition_from_disk() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Default::default(),
         );
         partition.persist().await.unwrap();
         assert_persisted_partition(&partition.path, with_segment).await;
@@ -157,6 +167,7 @@ async fn should_purge_existing_partition_on_disk() {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Default::default(),
         );
         partition.persist().await.unwrap();
         assert_persisted_partition(&partition.path, with_segment).await;
@@ -181,6 +192,404 @@ async fn should_purge_existing_partition_on_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_truncate_existing_partition_and_delete_covered_segments() {
+    let config = SystemConfig {
+        segment: SegmentConfig {
+            size: IggyByteSize::from(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        Default::default(),
+    );
+    partition.persist().await.unwrap();
+    // Each batch fills the whole segment, so every persisted batch closes its segment.
+    let batches_count = 3;
+    let messages_count = create_messages().len() as u64;
+    for _ in 0..batches_count {
+        let messages = create_messages();
+        let appendable_batch_info = AppendableBatchInfo::new(
+            messages.iter().map(|msg| msg.get_size_bytes() as u64).sum(),
+            partition.partition_id,
+        );
+        partition
+            .append_messages(appendable_batch_info, messages)
+            .await
+            .unwrap();
+        partition.persist_messages().await.unwrap();
+    }
+    assert_eq!(partition.get_segments_count(), batches_count);
+
+    let truncation = partition.truncate(messages_count + 2).await.unwrap();
+    assert_eq!(truncation.partition_id, partition_id);
+    assert_eq!(truncation.low_watermark, messages_count + 2);
+    assert_eq!(truncation.deleted_segments, 1);
+    assert_eq!(truncation.deleted_messages, messages_count);
+    assert_eq!(partition.get_segments_count(), batches_count - 1);
+    assert_eq!(partition.get_first_offset(), messages_count + 2);
+
+    let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+    assert_eq!(
+        loaded_messages.len() as u64,
+        batches_count as u64 * messages_count - messages_count - 2
+    );
+    assert_eq!(loaded_messages[0].offset, messages_count + 2);
+    let first_messages = partition.get_first_messages(1).await.unwrap();
+    assert_eq!(first_messages[0].offset, messages_count + 2);
+
+    let truncation = partition.truncate(1).await.unwrap();
+    assert_eq!(truncation.low_watermark, messages_count + 2);
+    assert_eq!(truncation.deleted_segments, 0);
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        Default::default(),
+    );
+    loaded_partition.load().await.unwrap();
+    assert_eq!(loaded_partition.low_watermark, messages_count + 2);
+    assert_eq!(loaded_partition.get_segments_count(), batches_count - 1);
+
+    let end_offset = loaded_partition.get_next_offset();
+    let truncation = loaded_partition.truncate(u64::MAX).await.unwrap();
+    assert_eq!(truncation.low_watermark, end_offset);
+    assert_eq!(truncation.deleted_segments, 1);
+    assert_eq!(loaded_partition.get_segments_count(), 1);
+    let loaded_messages = loaded_partition.get_last_messages(10).await.unwrap();
+    assert!(loaded_messages.is_empty());
+}
+
+#[tokio::test]
+async fn should_roll_segments_by_messages_count_and_age() {
+    let messages_count = create_messages().len() as u64;
+    let config = SystemConfig {
+        segment: SegmentConfig {
+            max_age: IggyDuration::from(60),
+            max_messages: messages_count,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        Default::default(),
+    );
+    partition.persist().await.unwrap();
+
+    // Each batch reaches the maximum messages count, so every persisted batch closes its segment.
+    for _ in 0..2 {
+        let messages = create_messages();
+        let appendable_batch_info = AppendableBatchInfo::new(
+            messages.iter().map(|msg| msg.get_size_bytes() as u64).sum(),
+            partition.partition_id,
+        );
+        partition
+            .append_messages(appendable_batch_info, messages)
+            .await
+            .unwrap();
+        partition.persist_messages().await.unwrap();
+    }
+    assert_eq!(partition.get_segments_count(), 2);
+    assert!(partition
+        .get_segments()
+        .iter()
+        .all(|segment| segment.is_closed));
+
+    let mut messages = create_messages();
+    messages.truncate(1);
+    let appendable_batch_info = AppendableBatchInfo::new(
+        messages.iter().map(|msg| msg.get_size_bytes() as u64).sum(),
+        partition.partition_id,
+    );
+    partition
+        .append_messages(appendable_batch_info, messages)
+        .await
+        .unwrap();
+    assert_eq!(partition.get_segments_count(), 3);
+
+    let now = IggyTimestamp::now().to_micros();
+    assert!(!partition.close_aged_segment(now).await.unwrap());
+    let after_max_age = now + IggyDuration::from(61).as_micros();
+    assert!(partition.close_aged_segment(after_max_age).await.unwrap());
+    let last_segment = partition.get_segments().last().unwrap();
+    assert!(last_segment.is_closed);
+    assert_eq!(last_segment.end_offset, 2 * messages_count);
+    let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+    assert_eq!(loaded_messages.len() as u64, 2 * messages_count + 1);
+}
+
+#[tokio::test]
+async fn loaded_segment_with_backfilled_messages_should_not_reach_max_age() {
+    let config = SystemConfig {
+        partition: PartitionConfig {
+            timestamp_type: TimestampType::CreateTime,
+            max_timestamp_skew: IggyDuration::from(24 * 3600),
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            max_age: IggyDuration::from(60),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        Default::default(),
+    );
+    partition.persist().await.unwrap();
+
+    let hour = IggyDuration::from(3600).as_micros();
+    let base_timestamp = IggyTimestamp::now().to_micros() - 10 * hour;
+    let messages = create_messages()
+        .into_iter()
+        .map(|message| message.with_timestamp(base_timestamp))
+        .collect::<Vec<_>>();
+    let appendable_batch_info = AppendableBatchInfo::new(
+        messages.iter().map(|msg| msg.get_size_bytes() as u64).sum(),
+        partition.partition_id,
+    );
+    partition
+        .append_messages(appendable_batch_info, messages)
+        .await
+        .unwrap();
+    partition.persist_messages().await.unwrap();
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        Default::default(),
+    );
+    loaded_partition.load().await.unwrap();
+
+    // The age of the segment is based on the append time of its first batch, not the event time of the messages.
+    let now = IggyTimestamp::now().to_micros();
+    assert!(!loaded_partition.close_aged_segment(now).await.unwrap());
+    let after_max_age = now + IggyDuration::from(61).as_micros();
+    assert!(loaded_partition
+        .close_aged_segment(after_max_age)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn should_store_create_time_and_get_messages_by_event_timestamp() {
+    let config = SystemConfig {
+        partition: PartitionConfig {
+            timestamp_type: TimestampType::CreateTime,
+            max_timestamp_skew: IggyDuration::from(24 * 3600),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        Default::default(),
+    );
+    partition.persist().await.unwrap();
+
+    let hour = IggyDuration::from(3600).as_micros();
+    let base_timestamp = IggyTimestamp::now().to_micros() - 10 * hour;
+    let messages = create_messages()
+        .into_iter()
+        .enumerate()
+        .map(|(index, message)| message.with_timestamp(base_timestamp + index as u64 * hour))
+        .collect::<Vec<_>>();
+    let messages_count = messages.len() as u64;
+    let appendable_batch_info = AppendableBatchInfo::new(
+        messages.iter().map(|msg| msg.get_size_bytes() as u64).sum(),
+        partition.partition_id,
+    );
+    partition
+        .append_messages(appendable_batch_info, messages)
+        .await
+        .unwrap();
+
+    let loaded_messages = partition
+        .get_messages_by_timestamp(base_timestamp + 2 * hour, 100)
+        .await
+        .unwrap();
+    assert_eq!(loaded_messages.len() as u64, messages_count - 2);
+    for (index, message) in loaded_messages.iter().enumerate() {
+        assert_eq!(message.offset, index as u64 + 2);
+        assert_eq!(
+            message.timestamp,
+            base_timestamp + (index as u64 + 2) * hour
+        );
+    }
+
+    let mut messages = create_messages();
+    messages.truncate(1);
+    let messages = vec![messages
+        .remove(0)
+        .with_timestamp(base_timestamp - 24 * hour)];
+    let appendable_batch_info = AppendableBatchInfo::new(
+        messages.iter().map(|msg| msg.get_size_bytes() as u64).sum(),
+        partition.partition_id,
+    );
+    let result = partition
+        .append_messages(appendable_batch_info, messages)
+        .await;
+    assert!(matches!(
+        result,
+        Err(IggyError::InvalidMessageTimestamp(timestamp)) if timestamp == base_timestamp - 24 * hour
+    ));
+    assert_eq!(partition.current_offset, messages_count - 1);
+}
+
+#[tokio::test]
+async fn should_get_offset_for_timestamp_in_the_middle_of_batch() {
+    let config = SystemConfig {
+        partition: PartitionConfig {
+            timestamp_type: TimestampType::CreateTime,
+            max_timestamp_skew: IggyDuration::from(24 * 3600),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        Default::default(),
+    );
+    partition.persist().await.unwrap();
+
+    let hour = IggyDuration::from(3600).as_micros();
+    let base_timestamp = IggyTimestamp::now().to_micros() - 10 * hour;
+    let messages = create_messages()
+        .into_iter()
+        .enumerate()
+        .map(|(index, message)| message.with_timestamp(base_timestamp + index as u64 * hour))
+        .collect::<Vec<_>>();
+    let appendable_batch_info = AppendableBatchInfo::new(
+        messages.iter().map(|msg| msg.get_size_bytes() as u64).sum(),
+        partition.partition_id,
+    );
+    partition
+        .append_messages(appendable_batch_info, messages)
+        .await
+        .unwrap();
+
+    let offsets = partition
+        .get_offsets_for_timestamp(base_timestamp + 2 * hour)
+        .await
+        .unwrap();
+    assert_eq!(offsets.offset, Some(2));
+
+    let offsets = partition
+        .get_offsets_for_timestamp(base_timestamp + 2 * hour - 1)
+        .await
+        .unwrap();
+    assert_eq!(offsets.offset, Some(2));
+
+    let offsets = partition
+        .get_offsets_for_timestamp(base_timestamp)
+        .await
+        .unwrap();
+    assert_eq!(offsets.offset, Some(0));
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     assert!(fs::metadata(&partition_path).await.is_ok());
 
@@ -169,6 +169,7 @@ async fn should_persist_and_load_segment_with_messages() {
             checksum: message.checksum,
             message_state: message.state,
             headers: message.headers.map(|headers| headers.as_bytes()),
+            key: None,
             payload: message.payload.clone(),
         };
         retained_message.extend(&mut batch_buffer);
@@ -266,6 +267,7 @@ async fn given_all_expired_messages_segment_should_be_expired() {
             checksum: message.checksum,
             message_state: message.state,
             headers: message.headers.map(|headers| headers.as_bytes()),
+            key: None,
             payload: message.payload.clone(),
         };
         retained_message.extend(&mut batch_buffer);
@@ -336,6 +338,7 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
         checksum: expired_message.checksum,
         message_state: expired_message.state,
         headers: expired_message.headers.map(|headers| headers.as_bytes()),
+        key: None,
         payload: expired_message.payload.clone(),
     };
     expired_retained_message.extend(&mut expired_batch_buffer);
@@ -357,6 +360,7 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
         headers: not_expired_message
             .headers
             .map(|headers| headers.as_bytes()),
+        key: None,
         payload: not_expired_message.payload.clone(),
     };
     not_expired_retained_message.extend(&mut not_expired_batch_buffer);
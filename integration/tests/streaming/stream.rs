@@ -3,6 +3,7 @@ use crate::streaming::create_messages;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::Partitioning;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::streams::stream::Stream;
 use tokio::fs;
@@ -94,7 +95,17 @@ async fn should_purge_existing_stream_on_disk() {
 
         let topic_id = 1;
         stream
-            .create_topic(Some(topic_id), "test", 1, None, Default::default(), None, 1)
+            .create_topic(
+                Some(topic_id),
+                "test",
+                1,
+                None,
+                Default::default(),
+                None,
+                1,
+                None,
+                TopicConfigOverrides::default(),
+            )
             .await
             .unwrap();
 
@@ -1,10 +1,21 @@
 use crate::streaming::common::test_setup::TestSetup;
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
-use server::configs::server::PersonalAccessTokenConfig;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use server::configs::server::{MessageSchedulerConfig, PersonalAccessTokenConfig};
+use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::session::Session;
+use server::streaming::systems::messages::PollingArgs;
 use server::streaming::systems::system::System;
 use std::net::{Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use std::time::Duration;
 use tokio::fs;
+use tokio::time::sleep;
 
 #[tokio::test]
 async fn should_initialize_system_and_base_directories() {
@@ -13,6 +24,7 @@ async fn should_initialize_system_and_base_directories() {
         setup.config.clone(),
         Some(setup.db.clone()),
         PersonalAccessTokenConfig::default(),
+        MessageSchedulerConfig::default(),
     );
 
     system.init().await.unwrap();
@@ -37,6 +49,7 @@ async fn should_create_and_persist_stream() {
         setup.config.clone(),
         Some(setup.db.clone()),
         PersonalAccessTokenConfig::default(),
+        MessageSchedulerConfig::default(),
     );
     let stream_id = 1;
     let stream_name = "test";
@@ -58,6 +71,7 @@ async fn should_create_and_persist_stream_with_automatically_generated_id() {
         setup.config.clone(),
         Some(setup.db.clone()),
         PersonalAccessTokenConfig::default(),
+        MessageSchedulerConfig::default(),
     );
     let stream_id = 1;
     let stream_name = "test";
@@ -79,6 +93,7 @@ async fn should_delete_persisted_stream() {
         setup.config.clone(),
         Some(setup.db.clone()),
         PersonalAccessTokenConfig::default(),
+        MessageSchedulerConfig::default(),
     );
     let stream_id = 1;
     let stream_name = "test";
@@ -110,3 +125,173 @@ async fn assert_persisted_stream(streams_path: &str, stream_id: u32) {
     let stream_metadata = fs::metadata(stream_path).await.unwrap();
     assert!(stream_metadata.is_dir());
 }
+
+#[tokio::test]
+async fn should_hide_delayed_and_scheduled_messages_until_they_are_delivered() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+        MessageSchedulerConfig::default(),
+    );
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system
+        .create_stream(&session, Some(1), "test")
+        .await
+        .unwrap();
+    system
+        .create_topic(
+            &session,
+            &stream_id,
+            Some(1),
+            "test",
+            1,
+            None,
+            Default::default(),
+            None,
+            None,
+            None,
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+
+    let now = IggyTimestamp::now().to_micros();
+    let deliver_at = now + 100_000;
+    let mut delayed_message = Message::from_str("delayed").unwrap();
+    delayed_message
+        .set_delay(IggyDuration::from_str("1h").unwrap())
+        .unwrap();
+    let mut scheduled_message = Message::from_str("scheduled").unwrap();
+    scheduled_message.set_deliver_at(deliver_at).unwrap();
+    let mut overdue_message = Message::from_str("overdue").unwrap();
+    overdue_message.set_deliver_at(now - 1).unwrap();
+    let messages = vec![
+        Message::from_str("regular").unwrap(),
+        delayed_message,
+        scheduled_message,
+        overdue_message,
+    ];
+    system
+        .append_messages(
+            &session,
+            stream_id.clone(),
+            topic_id.clone(),
+            Partitioning::partition_id(1),
+            messages,
+            false,
+        )
+        .await
+        .unwrap();
+
+    let payloads = poll_payloads(&system, &session, &stream_id, &topic_id).await;
+    assert_eq!(payloads, vec!["regular", "overdue"]);
+    assert_eq!(system.deliver_scheduled_messages(100).await.unwrap(), 0);
+
+    sleep(Duration::from_millis(200)).await;
+    assert_eq!(system.deliver_scheduled_messages(100).await.unwrap(), 1);
+
+    let polled_messages = system
+        .poll_messages(
+            &session,
+            PollingConsumer::Consumer(1, 1),
+            &stream_id,
+            &topic_id,
+            PollingArgs::new(PollingStrategy::offset(2), 10, false),
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    let delivered_message = &polled_messages.messages[0];
+    assert_eq!(delivered_message.payload, "scheduled".as_bytes());
+    assert_eq!(delivered_message.get_deliver_at(), Some(deliver_at));
+    assert_eq!(system.deliver_scheduled_messages(100).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn should_reject_messages_scheduled_further_than_max_delay() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+        MessageSchedulerConfig {
+            max_delay: IggyDuration::from_str("1h").unwrap(),
+            ..Default::default()
+        },
+    );
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system
+        .create_stream(&session, Some(1), "test")
+        .await
+        .unwrap();
+    system
+        .create_topic(
+            &session,
+            &stream_id,
+            Some(1),
+            "test",
+            1,
+            None,
+            Default::default(),
+            None,
+            None,
+            None,
+            TopicConfigOverrides::default(),
+        )
+        .await
+        .unwrap();
+
+    let mut delayed_message = Message::from_str("delayed").unwrap();
+    delayed_message
+        .set_delay(IggyDuration::from_str("2h").unwrap())
+        .unwrap();
+    let result = system
+        .append_messages(
+            &session,
+            stream_id.clone(),
+            topic_id.clone(),
+            Partitioning::partition_id(1),
+            vec![Message::from_str("regular").unwrap(), delayed_message],
+            false,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(IggyError::TooDistantMessageDelivery(_))
+    ));
+    assert!(poll_payloads(&system, &session, &stream_id, &topic_id)
+        .await
+        .is_empty());
+    assert_eq!(system.deliver_scheduled_messages(100).await.unwrap(), 0);
+}
+
+async fn poll_payloads(
+    system: &System,
+    session: &Session,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+) -> Vec<String> {
+    system
+        .poll_messages(
+            session,
+            PollingConsumer::Consumer(1, 1),
+            stream_id,
+            topic_id,
+            PollingArgs::new(PollingStrategy::offset(0), 10, false),
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| String::from_utf8_lossy(&message.payload).to_string())
+        .collect()
+}
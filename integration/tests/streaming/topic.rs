@@ -7,6 +7,8 @@ use crate::streaming::create_messages;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::Partitioning;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
+use iggy::utils::byte_size::IggyByteSize;
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::topics::topic::Topic;
 use tokio::fs;
@@ -34,6 +36,8 @@ async fn should_persist_topics_with_partitions_directories_and_info_file() {
             CompressionAlgorithm::default(),
             None,
             1,
+            None,
+            TopicConfigOverrides::default(),
         )
         .unwrap();
 
@@ -71,6 +75,8 @@ async fn should_load_existing_topic_from_disk() {
             CompressionAlgorithm::default(),
             None,
             1,
+            None,
+            TopicConfigOverrides::default(),
         )
         .unwrap();
         topic.persist().await.unwrap();
@@ -104,6 +110,51 @@ async fn should_load_existing_topic_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_load_existing_topic_with_config_overrides_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    setup.create_topics_directory(stream_id).await;
+    let config_overrides = TopicConfigOverrides {
+        segment_size: Some(IggyByteSize::from(1000)),
+        cache_indexes: Some(false),
+        ..Default::default()
+    };
+    let topic = Topic::create(
+        stream_id,
+        topic_id,
+        "test",
+        1,
+        setup.config.clone(),
+        setup.storage.clone(),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        None,
+        CompressionAlgorithm::default(),
+        None,
+        1,
+        None,
+        config_overrides,
+    )
+    .unwrap();
+    topic.persist().await.unwrap();
+
+    let mut loaded_topic = Topic::empty(
+        stream_id,
+        topic_id,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        setup.config.clone(),
+        setup.storage.clone(),
+    );
+    loaded_topic.load().await.unwrap();
+
+    assert_eq!(loaded_topic.config_overrides, config_overrides);
+}
+
 #[tokio::test]
 async fn should_delete_existing_topic_from_disk() {
     let setup = TestSetup::init().await;
@@ -127,6 +178,8 @@ async fn should_delete_existing_topic_from_disk() {
             CompressionAlgorithm::default(),
             None,
             1,
+            None,
+            TopicConfigOverrides::default(),
         )
         .unwrap();
         topic.persist().await.unwrap();
@@ -166,6 +219,8 @@ async fn should_purge_existing_topic_on_disk() {
             CompressionAlgorithm::default(),
             None,
             1,
+            None,
+            TopicConfigOverrides::default(),
         )
         .unwrap();
         topic.persist().await.unwrap();
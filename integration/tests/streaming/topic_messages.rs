@@ -3,6 +3,7 @@ use bytes::Bytes;
 use iggy::locking::IggySharedMutFn;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::byte_size::IggyByteSize;
 use server::configs::resource_quota::MemoryResourceQuota;
 use server::configs::system::{CacheConfig, SystemConfig};
@@ -234,6 +235,8 @@ async fn init_topic(setup: &TestSetup, partitions_count: u32) -> Topic {
         Default::default(),
         None,
         1,
+        None,
+        TopicConfigOverrides::default(),
     )
     .unwrap();
     topic.persist().await.unwrap();
@@ -243,6 +246,7 @@ async fn init_topic(setup: &TestSetup, partitions_count: u32) -> Topic {
 fn get_message(id: u128, payload: &str) -> Message {
     Message {
         id,
+        timestamp: 0,
         length: payload.len() as u32,
         payload: Bytes::from(payload.as_bytes().to_vec()),
         headers: None,
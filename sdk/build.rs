@@ -0,0 +1,20 @@
+use std::error;
+
+#[cfg(feature = "grpc")]
+const PROTO_FILE: &str = "proto/iggy.proto";
+
+fn main() -> Result<(), Box<dyn error::Error>> {
+    #[cfg(feature = "grpc")]
+    {
+        // The vendored protoc is used, so that building the SDK doesn't require protoc to be installed.
+        if std::env::var_os("PROTOC").is_none() {
+            std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+        }
+
+        println!("cargo:rerun-if-changed={PROTO_FILE}");
+        tonic_build::configure()
+            .bytes(["."])
+            .compile_protos(&[PROTO_FILE], &["proto"])?;
+    }
+    Ok(())
+}
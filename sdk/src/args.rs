@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Parser, Debug, Clone, Deserialize, Serialize, Default)]
 #[command(author, version, about, long_about = None)]
 pub struct ArgsOptional {
-    /// The transport to use. Valid values are `quic`, `http` and `tcp`
+    /// The transport to use. Valid values are `quic`, `http`, `tcp`, `grpc` and `unix`
     ///
     /// [default: tcp]
     #[arg(long)]
@@ -165,12 +165,73 @@ pub struct ArgsOptional {
     #[arg(long, default_missing_value(Some("true")), num_args(0..1))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quic_validate_certificate: Option<bool>,
+
+    /// The optional server address for the gRPC transport
+    ///
+    /// [default: 127.0.0.1:8070]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc_server_address: Option<String>,
+
+    /// The optional number of reconnect retries for the gRPC transport
+    ///
+    /// [default: 3]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc_reconnection_retries: Option<u32>,
+
+    /// The optional reconnect interval for the gRPC transport
+    ///
+    /// [default: 1000]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc_reconnection_interval: Option<u64>,
+
+    /// Flag to enable TLS for the gRPC transport
+    #[arg(long, default_missing_value(Some("true")), num_args(0..1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc_tls_enabled: Option<bool>,
+
+    /// The optional TLS domain for the gRPC transport
+    ///
+    /// [default: localhost]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc_tls_domain: Option<String>,
+
+    /// The optional path to the CA certificate used by the gRPC transport to verify the server
+    ///
+    /// [default: ]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc_tls_ca_file: Option<String>,
+
+    /// The optional socket path for the Unix domain socket transport
+    ///
+    /// [default: /tmp/iggy.sock]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unix_socket_path: Option<String>,
+
+    /// The optional number of reconnect retries for the Unix domain socket transport
+    ///
+    /// [default: 3]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unix_reconnection_retries: Option<u32>,
+
+    /// The optional reconnect interval for the Unix domain socket transport
+    ///
+    /// [default: 1000]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unix_reconnection_interval: Option<u64>,
 }
 
 /// The arguments used by the `ClientProviderConfig` to create a client.
 #[derive(Debug, Clone)]
 pub struct Args {
-    /// The transport to use. Valid values are `quic`, `http` and `tcp`
+    /// The transport to use. Valid values are `quic`, `http`, `tcp`, `grpc` and `unix`
     pub transport: String,
 
     /// Optional encryption key for the message payload used by the client
@@ -238,11 +299,40 @@ pub struct Args {
 
     /// Flag to enable certificate validation for QUIC
     pub quic_validate_certificate: bool,
+
+    /// The optional server address for the gRPC transport
+    pub grpc_server_address: String,
+
+    /// The optional number of reconnect retries for the gRPC transport
+    pub grpc_reconnection_retries: u32,
+
+    /// The optional reconnect interval for the gRPC transport
+    pub grpc_reconnection_interval: u64,
+
+    /// Flag to enable TLS for the gRPC transport
+    pub grpc_tls_enabled: bool,
+
+    /// The optional TLS domain for the gRPC transport
+    pub grpc_tls_domain: String,
+
+    /// The optional path to the CA certificate used by the gRPC transport to verify the server
+    pub grpc_tls_ca_file: String,
+
+    /// The optional socket path for the Unix domain socket transport
+    pub unix_socket_path: String,
+
+    /// The optional number of reconnect retries for the Unix domain socket transport
+    pub unix_reconnection_retries: u32,
+
+    /// The optional reconnect interval for the Unix domain socket transport
+    pub unix_reconnection_interval: u64,
 }
 
 const QUIC_TRANSPORT: &str = "quic";
 const HTTP_TRANSPORT: &str = "http";
 const TCP_TRANSPORT: &str = "tcp";
+const GRPC_TRANSPORT: &str = "grpc";
+const UNIX_TRANSPORT: &str = "unix";
 
 impl Args {
     pub fn get_server_address(&self) -> Option<String> {
@@ -259,6 +349,8 @@ impl Args {
                     .into(),
             ),
             TCP_TRANSPORT => Some(self.tcp_server_address.split(':').next().unwrap().into()),
+            GRPC_TRANSPORT => Some(self.grpc_server_address.split(':').next().unwrap().into()),
+            UNIX_TRANSPORT => Some(self.unix_socket_path.clone()),
             _ => None,
         }
     }
@@ -290,6 +382,15 @@ impl Default for Args {
             quic_keep_alive_interval: 5000,
             quic_max_idle_timeout: 10000,
             quic_validate_certificate: false,
+            grpc_server_address: "127.0.0.1:8070".to_string(),
+            grpc_reconnection_retries: 3,
+            grpc_reconnection_interval: 1000,
+            grpc_tls_enabled: false,
+            grpc_tls_domain: "localhost".to_string(),
+            grpc_tls_ca_file: "".to_string(),
+            unix_socket_path: "/tmp/iggy.sock".to_string(),
+            unix_reconnection_retries: 3,
+            unix_reconnection_interval: 1000,
         }
     }
 }
@@ -372,6 +473,33 @@ impl From<Vec<ArgsOptional>> for Args {
             if let Some(quic_validate_certificate) = optional_args.quic_validate_certificate {
                 args.quic_validate_certificate = quic_validate_certificate;
             }
+            if let Some(grpc_server_address) = optional_args.grpc_server_address {
+                args.grpc_server_address = grpc_server_address;
+            }
+            if let Some(grpc_reconnection_retries) = optional_args.grpc_reconnection_retries {
+                args.grpc_reconnection_retries = grpc_reconnection_retries;
+            }
+            if let Some(grpc_reconnection_interval) = optional_args.grpc_reconnection_interval {
+                args.grpc_reconnection_interval = grpc_reconnection_interval;
+            }
+            if let Some(grpc_tls_enabled) = optional_args.grpc_tls_enabled {
+                args.grpc_tls_enabled = grpc_tls_enabled;
+            }
+            if let Some(grpc_tls_domain) = optional_args.grpc_tls_domain {
+                args.grpc_tls_domain = grpc_tls_domain;
+            }
+            if let Some(grpc_tls_ca_file) = optional_args.grpc_tls_ca_file {
+                args.grpc_tls_ca_file = grpc_tls_ca_file;
+            }
+            if let Some(unix_socket_path) = optional_args.unix_socket_path {
+                args.unix_socket_path = unix_socket_path;
+            }
+            if let Some(unix_reconnection_retries) = optional_args.unix_reconnection_retries {
+                args.unix_reconnection_retries = unix_reconnection_retries;
+            }
+            if let Some(unix_reconnection_interval) = optional_args.unix_reconnection_interval {
+                args.unix_reconnection_interval = unix_reconnection_interval;
+            }
         }
 
         args
@@ -2,13 +2,20 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::ConsumerOffsetClient;
-use crate::command::{GET_CONSUMER_OFFSET_CODE, STORE_CONSUMER_OFFSET_CODE};
+use crate::command::{
+    GET_CONSUMER_LAG_CODE, GET_CONSUMER_OFFSET_CODE, RESET_CONSUMER_OFFSETS_CODE,
+    STORE_CONSUMER_OFFSET_CODE,
+};
 use crate::consumer::Consumer;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::{OffsetResetStrategy, ResetConsumerOffsets};
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offsets_reset::ConsumerOffsetsReset;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> ConsumerOffsetClient for B {
@@ -58,4 +65,50 @@ impl<B: BinaryClient> ConsumerOffsetClient for B {
             .await?;
         mapper::map_consumer_offset(response)
     }
+
+    async fn get_consumer_lag(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ConsumerLag, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(
+                GET_CONSUMER_LAG_CODE,
+                GetConsumerLag {
+                    consumer: consumer.clone(),
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                }
+                .as_bytes(),
+            )
+            .await?;
+        mapper::map_consumer_lag(response)
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        strategy: OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerOffsetsReset, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(
+                RESET_CONSUMER_OFFSETS_CODE,
+                ResetConsumerOffsets {
+                    consumer: consumer.clone(),
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    strategy,
+                    dry_run,
+                }
+                .as_bytes(),
+            )
+            .await?;
+        mapper::map_consumer_offsets_reset(response)
+    }
 }
@@ -1,20 +1,30 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::ConsumerKind;
 use crate::error::IggyError;
+use crate::models::appended_messages::AppendedMessages;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::consumer_lag::{ConsumerLag, PartitionLag};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offsets_reset::{ConsumerOffsetsReset, PartitionOffsetReset};
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{MessageState, PolledMessage, PolledMessages};
 use crate::models::partition::Partition;
+use crate::models::partition_offsets::PartitionOffsets;
+use crate::models::partition_truncation::PartitionTruncation;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::Schema;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
+use crate::topics::topic_options::TopicOptions;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
 use bytes::Bytes;
 use std::collections::HashMap;
 use std::str::from_utf8;
@@ -107,6 +117,150 @@ pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyErr
     })
 }
 
+pub fn map_consumer_lag(payload: Bytes) -> Result<ConsumerLag, IggyError> {
+    let kind = ConsumerKind::from_code(payload[0])?;
+    let consumer_id = u32::from_le_bytes(payload[1..5].try_into()?);
+    let total_lag = u64::from_le_bytes(payload[5..13].try_into()?);
+    let mut partitions = Vec::new();
+    let length = payload.len();
+    let mut position = 13;
+    while position < length {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let has_stored_offset = payload[position + 4] == 1;
+        let stored_offset = u64::from_le_bytes(payload[position + 5..position + 13].try_into()?);
+        let end_offset = u64::from_le_bytes(payload[position + 13..position + 21].try_into()?);
+        let lag = u64::from_le_bytes(payload[position + 21..position + 29].try_into()?);
+        let estimated_lag_time =
+            u64::from_le_bytes(payload[position + 29..position + 37].try_into()?);
+        partitions.push(PartitionLag {
+            partition_id,
+            stored_offset: if has_stored_offset {
+                Some(stored_offset)
+            } else {
+                None
+            },
+            end_offset,
+            lag,
+            estimated_lag_time,
+        });
+        position += 37;
+    }
+
+    Ok(ConsumerLag {
+        kind,
+        consumer_id,
+        total_lag,
+        partitions,
+    })
+}
+
+pub fn map_consumer_offsets_reset(payload: Bytes) -> Result<ConsumerOffsetsReset, IggyError> {
+    let kind = ConsumerKind::from_code(payload[0])?;
+    let consumer_id = u32::from_le_bytes(payload[1..5].try_into()?);
+    let dry_run = payload[5] == 1;
+    let mut partitions = Vec::new();
+    let length = payload.len();
+    let mut position = 6;
+    while position < length {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let previous_offset = map_optional_offset(&payload[position + 4..position + 13])?;
+        let new_offset = map_optional_offset(&payload[position + 13..position + 22])?;
+        partitions.push(PartitionOffsetReset {
+            partition_id,
+            previous_offset,
+            new_offset,
+        });
+        position += 22;
+    }
+
+    Ok(ConsumerOffsetsReset {
+        kind,
+        consumer_id,
+        dry_run,
+        partitions,
+    })
+}
+
+pub fn map_partition_offsets(payload: Bytes) -> Result<Vec<PartitionOffsets>, IggyError> {
+    let mut partitions = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let first_offset = map_optional_offset(&payload[position + 4..position + 13])?;
+        let last_offset = map_optional_offset(&payload[position + 13..position + 22])?;
+        let offset = map_optional_offset(&payload[position + 22..position + 31])?;
+        partitions.push(PartitionOffsets {
+            partition_id,
+            first_offset,
+            last_offset,
+            offset,
+        });
+        position += 31;
+    }
+
+    Ok(partitions)
+}
+
+pub fn map_partition_truncation(payload: Bytes) -> Result<PartitionTruncation, IggyError> {
+    let partition_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let low_watermark = u64::from_le_bytes(payload[4..12].try_into()?);
+    let deleted_segments = u32::from_le_bytes(payload[12..16].try_into()?);
+    let deleted_messages = u64::from_le_bytes(payload[16..24].try_into()?);
+    Ok(PartitionTruncation {
+        partition_id,
+        low_watermark,
+        deleted_segments,
+        deleted_messages,
+    })
+}
+
+pub fn map_appended_messages(payload: Bytes) -> Result<AppendedMessages, IggyError> {
+    if payload.len() < 20 {
+        return Err(IggyError::InvalidResponse(
+            0,
+            payload.len() as u32,
+            "invalid appended messages payload".to_string(),
+        ));
+    }
+
+    let partition_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let base_offset = u64::from_le_bytes(payload[4..12].try_into()?);
+    let messages_count = u32::from_le_bytes(payload[12..16].try_into()?);
+    let skipped_count = u32::from_le_bytes(payload[16..20].try_into()?) as usize;
+    if payload.len() < 20 + 4 * skipped_count {
+        return Err(IggyError::InvalidResponse(
+            0,
+            payload.len() as u32,
+            "invalid appended messages skipped indexes".to_string(),
+        ));
+    }
+
+    let mut skipped_indexes = Vec::with_capacity(skipped_count);
+    let mut position = 20;
+    for _ in 0..skipped_count {
+        skipped_indexes.push(u32::from_le_bytes(
+            payload[position..position + 4].try_into()?,
+        ));
+        position += 4;
+    }
+    Ok(AppendedMessages {
+        partition_id,
+        base_offset,
+        messages_count,
+        skipped_indexes,
+    })
+}
+
+fn map_optional_offset(payload: &[u8]) -> Result<Option<u64>, IggyError> {
+    let offset = u64::from_le_bytes(payload[1..9].try_into()?);
+    if payload[0] == 1 {
+        Ok(Some(offset))
+    } else {
+        Ok(None)
+    }
+}
+
 pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
     let (user, position) = map_to_user_info(payload.clone(), 0)?;
     let has_permissions = payload[position];
@@ -165,6 +319,62 @@ pub fn map_personal_access_tokens(
     Ok(personal_access_tokens)
 }
 
+pub fn map_schema(payload: Bytes) -> Result<Schema, IggyError> {
+    let (schema, _) = map_to_schema(payload, 0)?;
+    Ok(schema)
+}
+
+pub fn map_schemas(payload: Bytes) -> Result<Vec<Schema>, IggyError> {
+    let mut schemas = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (schema, read_bytes) = map_to_schema(payload.clone(), position)?;
+        schemas.push(schema);
+        position += read_bytes;
+    }
+    schemas.sort_by_key(|s| s.version);
+    Ok(schemas)
+}
+
+fn map_to_schema(payload: Bytes, position: usize) -> Result<(Schema, usize), IggyError> {
+    if payload.len() < position + 31 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let stream_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+    let topic_id = u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
+    let version = u32::from_le_bytes(payload[position + 12..position + 16].try_into()?);
+    let schema_type = SchemaType::from_code(payload[position + 16])?;
+    let compatibility = SchemaCompatibility::from_code(payload[position + 17])?;
+    let validation_enabled = payload[position + 18] == 1;
+    let created_at = u64::from_le_bytes(payload[position + 19..position + 27].try_into()?);
+    let definition_length =
+        u32::from_le_bytes(payload[position + 27..position + 31].try_into()?) as usize;
+    if payload.len() < position + 31 + definition_length {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let definition =
+        from_utf8(&payload[position + 31..position + 31 + definition_length])?.to_string();
+    let read_bytes = 31 + definition_length;
+    Ok((
+        Schema {
+            id,
+            stream_id,
+            topic_id,
+            version,
+            schema_type,
+            compatibility,
+            validation_enabled,
+            created_at,
+            definition,
+        },
+        read_bytes,
+    ))
+}
+
 pub fn map_identity_info(payload: Bytes) -> Result<IdentityInfo, IggyError> {
     let user_id = u32::from_le_bytes(payload[..4].try_into()?);
     Ok(IdentityInfo {
@@ -258,6 +468,13 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
             None
         };
         position += headers_length as usize;
+        let key_length = payload[position + 41] as usize;
+        let key = if key_length > 0 {
+            Some(payload.slice(position + 42..position + 42 + key_length))
+        } else {
+            None
+        };
+        position += 1 + key_length;
         let message_length = u32::from_le_bytes(payload[position + 41..position + 45].try_into()?);
         let payload_range = position + 45..position + 45 + message_length as usize;
         if payload_range.start > length || payload_range.end > length {
@@ -274,11 +491,12 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
             checksum,
             id,
             headers,
+            key,
             length: message_length,
             payload: Bytes::from(payload),
         });
 
-        if position + 45 >= length {
+        if position + 46 >= length {
             break;
         }
     }
@@ -310,6 +528,8 @@ pub fn map_streams(payload: Bytes) -> Result<Vec<Stream>, IggyError> {
 
 pub fn map_stream(payload: Bytes) -> Result<StreamDetails, IggyError> {
     let (stream, mut position) = map_to_stream(payload.clone(), 0)?;
+    let (options, options_bytes) = TopicOptions::from_block_bytes(&payload, position)?;
+    position += options_bytes;
     let mut topics = Vec::new();
     let length = payload.len();
     while position < length {
@@ -326,6 +546,7 @@ pub fn map_stream(payload: Bytes) -> Result<StreamDetails, IggyError> {
         size: stream.size,
         messages_count: stream.messages_count,
         name: stream.name,
+        topic_config_overrides: options.config_overrides.unwrap_or_default(),
         topics,
     };
     Ok(stream)
@@ -372,7 +593,7 @@ pub fn map_topics(payload: Bytes) -> Result<Vec<Topic>, IggyError> {
 }
 
 pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
-    let (topic, mut position) = map_to_topic(payload.clone(), 0)?;
+    let (topic, options, mut position) = map_to_topic_with_options(payload.clone(), 0)?;
     let mut partitions = Vec::new();
     let length = payload.len();
     while position < length {
@@ -392,14 +613,25 @@ pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
         compression_algorithm: topic.compression_algorithm,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        durability: topic.durability,
         #[allow(clippy::cast_possible_truncation)]
         partitions_count: partitions.len() as u32,
+        config_overrides: options.config_overrides.unwrap_or_default(),
+        partitioning_scheme: options.partitioning_scheme.unwrap_or_default(),
         partitions,
     };
     Ok(topic)
 }
 
 fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyError> {
+    let (topic, _, read_bytes) = map_to_topic_with_options(payload, position)?;
+    Ok((topic, read_bytes))
+}
+
+fn map_to_topic_with_options(
+    payload: Bytes,
+    position: usize,
+) -> Result<(Topic, TopicOptions, usize), IggyError> {
     let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
     let created_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
     let partitions_count = u32::from_le_bytes(payload[position + 12..position + 16].try_into()?);
@@ -422,7 +654,10 @@ fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyE
     let name_length = payload[position + 46];
     let name =
         from_utf8(&payload[position + 47..position + 47 + name_length as usize])?.to_string();
-    let read_bytes = 4 + 8 + 4 + 4 + 8 + 8 + 8 + 1 + 1 + 1 + name_length as usize;
+    let mut read_bytes = 4 + 8 + 4 + 4 + 8 + 8 + 8 + 1 + 1 + 1 + name_length as usize;
+    let (options, options_bytes) = TopicOptions::from_block_bytes(&payload, position + read_bytes)?;
+    read_bytes += options_bytes;
+    let durability = options.durability.unwrap_or_default();
     Ok((
         Topic {
             id,
@@ -435,7 +670,9 @@ fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyE
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            durability,
         },
+        options,
         read_bytes,
     ))
 }
@@ -562,6 +799,11 @@ fn map_to_client_info(
     let transport = match transport {
         1 => "TCP",
         2 => "QUIC",
+        3 => "HTTP",
+        4 => "gRPC",
+        5 => "Kafka",
+        6 => "MQTT",
+        7 => "Unix",
         _ => "Unknown",
     }
     .to_string();
@@ -1,13 +1,17 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::MessageClient;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{
+    POLL_MESSAGES_V2_CODE, POLL_MESSAGES_V3_CODE, SEND_DURABLE_MESSAGES_V2_CODE,
+    SEND_MESSAGES_V2_CODE,
+};
 use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::poll_messages::PollingStrategy;
 use crate::messages::send_messages::{Message, Partitioning};
 use crate::messages::{poll_messages, send_messages};
+use crate::models::appended_messages::AppendedMessages;
 use crate::models::messages::PolledMessages;
 
 #[async_trait::async_trait]
@@ -25,7 +29,35 @@ impl<B: BinaryClient> MessageClient for B {
         fail_if_not_authenticated(self).await?;
         let response = self
             .send_with_response(
-                POLL_MESSAGES_CODE,
+                POLL_MESSAGES_V2_CODE,
+                poll_messages::as_bytes(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    consumer,
+                    strategy,
+                    count,
+                    auto_commit,
+                ),
+            )
+            .await?;
+        mapper::map_polled_messages(response)
+    }
+
+    async fn poll_assigned_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(
+                POLL_MESSAGES_V3_CODE,
                 poll_messages::as_bytes(
                     stream_id,
                     topic_id,
@@ -49,10 +81,49 @@ impl<B: BinaryClient> MessageClient for B {
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(
-            SEND_MESSAGES_CODE,
+            SEND_MESSAGES_V2_CODE,
             send_messages::as_bytes(stream_id, topic_id, partitioning, messages),
         )
         .await?;
         Ok(())
     }
+
+    async fn send_durable_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(
+            SEND_DURABLE_MESSAGES_V2_CODE,
+            send_messages::as_bytes(stream_id, topic_id, partitioning, messages),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        wait_for_durability: bool,
+    ) -> Result<AppendedMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let code = if wait_for_durability {
+            SEND_DURABLE_MESSAGES_V2_CODE
+        } else {
+            SEND_MESSAGES_V2_CODE
+        };
+        let response = self
+            .send_with_response(
+                code,
+                send_messages::as_bytes(stream_id, topic_id, partitioning, messages),
+            )
+            .await?;
+        mapper::map_appended_messages(response)
+    }
 }
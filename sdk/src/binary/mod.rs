@@ -8,7 +8,7 @@ pub mod binary_client;
 pub mod consumer_groups;
 #[allow(deprecated)]
 pub mod consumer_offsets;
-mod mapper;
+pub(crate) mod mapper;
 #[allow(deprecated)]
 pub mod messages;
 #[allow(deprecated)]
@@ -16,6 +16,8 @@ pub mod partitions;
 #[allow(deprecated)]
 pub mod personal_access_tokens;
 #[allow(deprecated)]
+pub mod schemas;
+#[allow(deprecated)]
 pub mod streams;
 #[allow(deprecated)]
 pub mod system;
@@ -45,7 +47,9 @@ pub trait BinaryTransport {
     async fn send_with_response(&self, command: u32, payload: Bytes) -> Result<Bytes, IggyError>;
 }
 
-async fn fail_if_not_authenticated<T: BinaryTransport>(transport: &T) -> Result<(), IggyError> {
+pub(crate) async fn fail_if_not_authenticated<T: BinaryTransport>(
+    transport: &T,
+) -> Result<(), IggyError> {
     if transport.get_state().await != ClientState::Authenticated {
         return Err(IggyError::Unauthenticated);
     }
@@ -1,13 +1,20 @@
 #[allow(deprecated)]
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::fail_if_not_authenticated;
+use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::PartitionClient;
-use crate::command::{CREATE_PARTITIONS_CODE, DELETE_PARTITIONS_CODE};
+use crate::command::{
+    CREATE_PARTITIONS_CODE, DELETE_PARTITIONS_CODE, GET_OFFSETS_FOR_TIMESTAMP_CODE,
+    TRUNCATE_PARTITION_CODE,
+};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::partition_offsets::PartitionOffsets;
+use crate::models::partition_truncation::PartitionTruncation;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::truncate_partition::TruncatePartition;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> PartitionClient for B {
@@ -50,4 +57,50 @@ impl<B: BinaryClient> PartitionClient for B {
         .await?;
         Ok(())
     }
+
+    async fn get_offsets_for_timestamp(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_ids: &[u32],
+        timestamp: u64,
+    ) -> Result<Vec<PartitionOffsets>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(
+                GET_OFFSETS_FOR_TIMESTAMP_CODE,
+                GetOffsetsForTimestamp {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    timestamp,
+                    partition_ids: partition_ids.to_vec(),
+                }
+                .as_bytes(),
+            )
+            .await?;
+        mapper::map_partition_offsets(response)
+    }
+
+    async fn truncate_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<PartitionTruncation, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(
+                TRUNCATE_PARTITION_CODE,
+                TruncatePartition {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    offset,
+                }
+                .as_bytes(),
+            )
+            .await?;
+        mapper::map_partition_truncation(response)
+    }
 }
@@ -0,0 +1,71 @@
+#[allow(deprecated)]
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::SchemaClient;
+use crate::command::{GET_SCHEMAS_CODE, GET_SCHEMA_CODE, REGISTER_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::Schema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> SchemaClient for B {
+    async fn get_schema(&self, schema_id: u32) -> Result<Schema, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_SCHEMA_CODE, GetSchema { schema_id }.as_bytes())
+            .await?;
+        mapper::map_schema(response)
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(
+                GET_SCHEMAS_CODE,
+                GetSchemas {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                }
+                .as_bytes(),
+            )
+            .await?;
+        mapper::map_schemas(response)
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        validation_enabled: bool,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(
+                REGISTER_SCHEMA_CODE,
+                RegisterSchema {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    schema_type,
+                    compatibility,
+                    validation_enabled,
+                    definition: definition.to_string(),
+                }
+                .as_bytes(),
+            )
+            .await?;
+        mapper::map_schema(response)
+    }
+}
@@ -3,18 +3,20 @@ use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::StreamClient;
 use crate::command::{
-    CREATE_STREAM_CODE, DELETE_STREAM_CODE, GET_STREAMS_CODE, GET_STREAM_CODE, PURGE_STREAM_CODE,
-    UPDATE_STREAM_CODE,
+    CREATE_STREAM_CODE, DELETE_STREAM_CODE, GET_STREAMS_CODE, GET_STREAM_V2_CODE,
+    PURGE_STREAM_CODE, UPDATE_STREAM_CODE,
 };
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::models::stream::{Stream, StreamDetails};
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::topics::get_topic::TopicLayoutVersion;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> StreamClient for B {
@@ -22,9 +24,10 @@ impl<B: BinaryClient> StreamClient for B {
         fail_if_not_authenticated(self).await?;
         let response = self
             .send_with_response(
-                GET_STREAM_CODE,
+                GET_STREAM_V2_CODE,
                 GetStream {
                     stream_id: stream_id.clone(),
+                    version: TopicLayoutVersion::V2,
                 }
                 .as_bytes(),
             )
@@ -54,12 +57,18 @@ impl<B: BinaryClient> StreamClient for B {
         Ok(())
     }
 
-    async fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError> {
+    async fn update_stream(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        topic_config_overrides: TopicConfigOverrides,
+    ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(
             UPDATE_STREAM_CODE,
             UpdateStream {
                 stream_id: stream_id.clone(),
+                topic_config_overrides: Some(topic_config_overrides),
                 name: name.to_string(),
             }
             .as_bytes(),
@@ -3,20 +3,22 @@ use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::TopicClient;
 use crate::command::{
-    CREATE_TOPIC_CODE, DELETE_TOPIC_CODE, GET_TOPICS_CODE, GET_TOPIC_CODE, PURGE_TOPIC_CODE,
+    CREATE_TOPIC_CODE, DELETE_TOPIC_CODE, GET_TOPICS_V2_CODE, GET_TOPIC_V2_CODE, PURGE_TOPIC_CODE,
     UPDATE_TOPIC_CODE,
 };
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
-use crate::topics::get_topic::GetTopic;
+use crate::topics::get_topic::{GetTopic, TopicLayoutVersion};
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
 use crate::topics::update_topic::UpdateTopic;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
 use crate::utils::expiry::IggyExpiry;
 
 #[async_trait::async_trait]
@@ -29,10 +31,11 @@ impl<B: BinaryClient> TopicClient for B {
         fail_if_not_authenticated(self).await?;
         let response = self
             .send_with_response(
-                GET_TOPIC_CODE,
+                GET_TOPIC_V2_CODE,
                 GetTopic {
                     stream_id: stream_id.clone(),
                     topic_id: topic_id.clone(),
+                    version: TopicLayoutVersion::V2,
                 }
                 .as_bytes(),
             )
@@ -44,9 +47,10 @@ impl<B: BinaryClient> TopicClient for B {
         fail_if_not_authenticated(self).await?;
         let response = self
             .send_with_response(
-                GET_TOPICS_CODE,
+                GET_TOPICS_V2_CODE,
                 GetTopics {
                     stream_id: stream_id.clone(),
+                    version: TopicLayoutVersion::V2,
                 }
                 .as_bytes(),
             )
@@ -64,6 +68,8 @@ impl<B: BinaryClient> TopicClient for B {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(
@@ -77,6 +83,8 @@ impl<B: BinaryClient> TopicClient for B {
                 topic_id,
                 message_expiry: message_expiry.into(),
                 max_topic_size,
+                durability,
+                config_overrides,
             }
             .as_bytes(),
         )
@@ -93,6 +101,8 @@ impl<B: BinaryClient> TopicClient for B {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(
@@ -105,6 +115,8 @@ impl<B: BinaryClient> TopicClient for B {
                 replication_factor,
                 message_expiry: message_expiry.into(),
                 max_topic_size,
+                durability,
+                config_overrides: Some(config_overrides),
             }
             .as_bytes(),
         )
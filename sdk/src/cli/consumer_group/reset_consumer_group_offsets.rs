@@ -0,0 +1,120 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::consumer_offsets::reset_consumer_offsets::{OffsetResetStrategy, ResetConsumerOffsets};
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct ResetConsumerGroupOffsetsCmd {
+    reset_consumer_offsets: ResetConsumerOffsets,
+}
+
+impl ResetConsumerGroupOffsetsCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        consumer_group_id: Identifier,
+        to_earliest: bool,
+        to_latest: bool,
+        to_timestamp: Option<u64>,
+        to_offset: Option<u64>,
+        shift_by: Option<i64>,
+        dry_run: bool,
+    ) -> Self {
+        let strategy = match (to_earliest, to_latest, to_timestamp, to_offset, shift_by) {
+            (true, false, None, None, None) => OffsetResetStrategy::Earliest,
+            (false, true, None, None, None) => OffsetResetStrategy::Latest,
+            (false, false, Some(timestamp), None, None) => {
+                OffsetResetStrategy::Timestamp(timestamp)
+            }
+            (false, false, None, Some(offset), None) => OffsetResetStrategy::Offset(offset),
+            (false, false, None, None, Some(shift)) => OffsetResetStrategy::Shift(shift),
+            _ => unreachable!(
+                "Either earliest, latest, timestamp, offset or shift must be specified"
+            ),
+        };
+        Self {
+            reset_consumer_offsets: ResetConsumerOffsets {
+                consumer: Consumer::group(consumer_group_id),
+                stream_id,
+                topic_id,
+                strategy,
+                dry_run,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ResetConsumerGroupOffsetsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "reset offsets ({}) of consumer group with ID: {} for topic with ID: {} and stream with ID: {}{}",
+            self.reset_consumer_offsets.strategy,
+            self.reset_consumer_offsets.consumer.id,
+            self.reset_consumer_offsets.topic_id,
+            self.reset_consumer_offsets.stream_id,
+            match self.reset_consumer_offsets.dry_run {
+                true => " (dry run)",
+                false => "",
+            }
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let consumer_offsets_reset = client
+            .reset_consumer_offsets(
+                &self.reset_consumer_offsets.consumer,
+                &self.reset_consumer_offsets.stream_id,
+                &self.reset_consumer_offsets.topic_id,
+                self.reset_consumer_offsets.strategy,
+                self.reset_consumer_offsets.dry_run,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem resetting offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.reset_consumer_offsets.consumer.id,
+                    self.reset_consumer_offsets.topic_id,
+                    self.reset_consumer_offsets.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Partition ID", "Previous offset", "New offset"]);
+        for partition in &consumer_offsets_reset.partitions {
+            table.add_row(vec![
+                format!("{}", partition.partition_id),
+                format_offset(partition.previous_offset),
+                format_offset(partition.new_offset),
+            ]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+        match consumer_offsets_reset.dry_run {
+            true => {
+                event!(target: PRINT_TARGET, Level::INFO, "Dry run, the offsets were not changed")
+            }
+            false => event!(target: PRINT_TARGET, Level::INFO,
+                "Offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {} were reset",
+                self.reset_consumer_offsets.consumer.id,
+                self.reset_consumer_offsets.topic_id,
+                self.reset_consumer_offsets.stream_id
+            ),
+        }
+
+        Ok(())
+    }
+}
+
+fn format_offset(offset: Option<u64>) -> String {
+    match offset {
+        Some(offset) => format!("{offset}"),
+        None => String::from("-"),
+    }
+}
@@ -0,0 +1,110 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use crate::identifier::Identifier;
+use crate::utils::duration::IggyDuration;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use std::time::Duration;
+use tracing::{event, Level};
+
+pub struct GetConsumerLagCmd {
+    get_consumer_lag: GetConsumerLag,
+}
+
+impl GetConsumerLagCmd {
+    pub fn new(
+        consumer_id: Identifier,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        consumer_group: bool,
+    ) -> Self {
+        let kind = match consumer_group {
+            true => ConsumerKind::ConsumerGroup,
+            false => ConsumerKind::Consumer,
+        };
+        Self {
+            get_consumer_lag: GetConsumerLag {
+                consumer: Consumer {
+                    kind,
+                    id: consumer_id,
+                },
+                stream_id,
+                topic_id,
+            },
+        }
+    }
+
+    pub fn get_consumer_info(&self) -> String {
+        match self.get_consumer_lag.consumer.kind {
+            ConsumerKind::Consumer => {
+                format!("consumer with ID: {}", self.get_consumer_lag.consumer.id)
+            }
+            ConsumerKind::ConsumerGroup => format!(
+                "consumer group with ID: {}",
+                self.get_consumer_lag.consumer.id
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetConsumerLagCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get consumer lag for {} for stream with ID: {} and topic with ID: {}",
+            self.get_consumer_info(),
+            self.get_consumer_lag.stream_id,
+            self.get_consumer_lag.topic_id,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let consumer_lag = client
+            .get_consumer_lag(
+                &self.get_consumer_lag.consumer,
+                &self.get_consumer_lag.stream_id,
+                &self.get_consumer_lag.topic_id,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting consumer lag for {} for stream with ID: {} and topic with ID: {}",
+                    self.get_consumer_info(),
+                    self.get_consumer_lag.stream_id,
+                    self.get_consumer_lag.topic_id
+                )
+            })?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec![
+            "Partition ID",
+            "Stored offset",
+            "End offset",
+            "Lag",
+            "Estimated lag time",
+        ]);
+        for partition in &consumer_lag.partitions {
+            let estimated_lag_time =
+                IggyDuration::new(Duration::from_micros(partition.estimated_lag_time));
+            table.add_row(vec![
+                format!("{}", partition.partition_id),
+                match partition.stored_offset {
+                    Some(offset) => format!("{offset}"),
+                    None => String::from("-"),
+                },
+                format!("{}", partition.end_offset),
+                format!("{}", partition.lag),
+                estimated_lag_time.as_human_time_string(),
+            ]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+        event!(target: PRINT_TARGET, Level::INFO, "Total lag: {}", consumer_lag.total_lag);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use crate::cli::message::messages_file::{self, MessagesFileFormat};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use anyhow::Context;
+use async_trait::async_trait;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use tracing::{event, Level};
+
+pub struct ExportMessagesCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: u32,
+    consumer: Consumer,
+    start_offset: u64,
+    end_offset: Option<u64>,
+    batch_size: u32,
+    file: PathBuf,
+    format: MessagesFileFormat,
+}
+
+impl ExportMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        consumer: Identifier,
+        start_offset: u64,
+        end_offset: Option<u64>,
+        batch_size: u32,
+        file: PathBuf,
+        format: MessagesFileFormat,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer: Consumer::new(consumer),
+            start_offset,
+            end_offset,
+            batch_size,
+            file,
+            format,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ExportMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "export messages from topic ID: {} and stream with ID: {} (partition with ID: {}) to file: {}",
+            self.topic_id,
+            self.stream_id,
+            self.partition_id,
+            self.file.display()
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let file = File::create(&self.file)
+            .with_context(|| format!("Problem creating file: {}", self.file.display()))?;
+        let mut writer = BufWriter::new(file);
+
+        let mut offset = self.start_offset;
+        let mut end_offset = self.end_offset;
+        let mut exported_messages = 0u64;
+        loop {
+            if end_offset.is_some_and(|end_offset| offset > end_offset) {
+                break;
+            }
+
+            let count = match end_offset {
+                Some(end_offset) => (end_offset - offset + 1).min(self.batch_size as u64) as u32,
+                None => self.batch_size,
+            };
+            let polled_messages = client
+                .poll_messages(
+                    &self.stream_id,
+                    &self.topic_id,
+                    Some(self.partition_id),
+                    &self.consumer,
+                    &PollingStrategy::offset(offset),
+                    count,
+                    false,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem polling messages from topic with ID: {} and stream with ID: {} (partition with ID: {})",
+                        self.topic_id, self.stream_id, self.partition_id
+                    )
+                })?;
+
+            // Without the explicit end offset, export the messages available at the time of the first poll.
+            let end_offset = *end_offset.get_or_insert(polled_messages.current_offset);
+            let messages = polled_messages
+                .messages
+                .into_iter()
+                .filter(|message| message.offset <= end_offset)
+                .collect::<Vec<_>>();
+            let Some(last_message) = messages.last() else {
+                break;
+            };
+
+            offset = last_message.offset + 1;
+            exported_messages += messages.len() as u64;
+            messages_file::write_messages(&mut writer, self.format, &messages)
+                .with_context(|| format!("Problem writing to file: {}", self.file.display()))?;
+        }
+
+        writer
+            .flush()
+            .with_context(|| format!("Problem writing to file: {}", self.file.display()))?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Exported {} messages from topic with ID: {} and stream with ID: {} (partition with ID: {}) to file: {}",
+            exported_messages,
+            self.topic_id,
+            self.stream_id,
+            self.partition_id,
+            self.file.display()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::cli::message::messages_file::{self, MessagesFileFormat};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::Partitioning;
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::path::PathBuf;
+use tracing::{event, Level};
+
+pub struct ImportMessagesCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: Option<u32>,
+    message_key: Option<String>,
+    batch_size: u32,
+    file: PathBuf,
+    format: MessagesFileFormat,
+}
+
+impl ImportMessagesCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: Option<u32>,
+        message_key: Option<String>,
+        batch_size: u32,
+        file: PathBuf,
+        format: MessagesFileFormat,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            partition_id,
+            message_key,
+            batch_size,
+            file,
+            format,
+        }
+    }
+
+    fn get_partitioning(&self) -> anyhow::Result<Partitioning, anyhow::Error> {
+        match (self.partition_id, &self.message_key) {
+            (Some(_), Some(_)) => Err(anyhow!(
+                "Partition ID and message key cannot be used together"
+            )),
+            (Some(partition_id), None) => Ok(Partitioning::partition_id(partition_id)),
+            (None, Some(message_key)) => Partitioning::messages_key_str(message_key)
+                .with_context(|| format!("Invalid message key: {message_key}")),
+            (None, None) => Ok(Partitioning::default()),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ImportMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "import messages to topic with ID: {} and stream with ID: {} from file: {}",
+            self.topic_id,
+            self.stream_id,
+            self.file.display()
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let partitioning = self.get_partitioning()?;
+        let bytes = std::fs::read(&self.file)
+            .with_context(|| format!("Problem reading file: {}", self.file.display()))?;
+        let mut messages = messages_file::read_messages(Bytes::from(bytes), self.format)
+            .with_context(|| {
+                format!(
+                    "Problem parsing messages from file: {}",
+                    self.file.display()
+                )
+            })?;
+
+        for batch in messages.chunks_mut(self.batch_size as usize) {
+            client
+                .send_messages(&self.stream_id, &self.topic_id, &partitioning, batch)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem sending messages to topic with ID: {} and stream with ID: {}",
+                        self.topic_id, self.stream_id
+                    )
+                })?;
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Imported {} messages to topic with ID: {} and stream with ID: {} from file: {}",
+            messages.len(),
+            self.topic_id,
+            self.stream_id,
+            self.file.display()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::messages::send_messages::Message;
+use crate::models::messages::PolledMessage;
+use bytes::Bytes;
+use std::io::Write;
+
+/// Format of the file used to export and import the messages.
+/// - `JsonLines`: each line contains a single message serialized as JSON (the payload is encoded as base64).
+/// - `Binary`: each message is serialized in the binary format used by the `SendMessages` command,
+///   preceded by its length stored as `u32` (little endian).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessagesFileFormat {
+    JsonLines,
+    Binary,
+}
+
+/// Writes the polled messages to the provided writer using the selected format.
+pub fn write_messages(
+    writer: &mut dyn Write,
+    format: MessagesFileFormat,
+    messages: &[PolledMessage],
+) -> anyhow::Result<()> {
+    for message in messages {
+        match format {
+            MessagesFileFormat::JsonLines => {
+                serde_json::to_writer(&mut *writer, message)?;
+                writer.write_all(b"\n")?;
+            }
+            MessagesFileFormat::Binary => {
+                let message = Message::new(
+                    Some(message.id),
+                    message.payload.clone(),
+                    message.headers.clone(),
+                )
+                .with_timestamp(message.timestamp);
+                let bytes = message.as_bytes();
+                writer.write_all(&(bytes.len() as u32).to_le_bytes())?;
+                writer.write_all(&bytes)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads all the messages from the file content stored in the selected format.
+pub fn read_messages(bytes: Bytes, format: MessagesFileFormat) -> anyhow::Result<Vec<Message>> {
+    let mut messages = Vec::new();
+    match format {
+        MessagesFileFormat::JsonLines => {
+            for line in std::str::from_utf8(&bytes)?.lines() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let mut message = serde_json::from_str::<Message>(line)?;
+                message.length = message.payload.len() as u32;
+                messages.push(message);
+            }
+        }
+        MessagesFileFormat::Binary => {
+            let mut position = 0;
+            while position < bytes.len() {
+                if position + 4 > bytes.len() {
+                    return Err(IggyError::InvalidFormat.into());
+                }
+                let length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+                position += 4;
+                if position + length > bytes.len() {
+                    return Err(IggyError::InvalidFormat.into());
+                }
+                messages.push(Message::from_bytes(
+                    bytes.slice(position..position + length),
+                )?);
+                position += length;
+            }
+        }
+    }
+
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::header::{HeaderKey, HeaderValue};
+    use crate::models::messages::MessageState;
+    use bytes::{BufMut, BytesMut};
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    fn as_bytes(format: MessagesFileFormat, messages: &[PolledMessage]) -> anyhow::Result<Bytes> {
+        let mut bytes = BytesMut::new().writer();
+        write_messages(&mut bytes, format, messages)?;
+        Ok(bytes.into_inner().freeze())
+    }
+
+    fn polled_messages() -> Vec<PolledMessage> {
+        let headers = HashMap::from([(
+            HeaderKey::from_str("key").unwrap(),
+            HeaderValue::from_str("value").unwrap(),
+        )]);
+        vec![
+            PolledMessage {
+                offset: 0,
+                state: MessageState::Available,
+                timestamp: 1,
+                id: 1,
+                checksum: 0,
+                headers: None,
+                key: None,
+                length: 5,
+                payload: Bytes::from("hello"),
+            },
+            PolledMessage {
+                offset: 1,
+                state: MessageState::Available,
+                timestamp: 2,
+                id: 2,
+                checksum: 0,
+                headers: Some(headers),
+                key: Some(Bytes::from("key")),
+                length: 5,
+                payload: Bytes::from("world"),
+            },
+        ]
+    }
+
+    fn assert_round_trip(format: MessagesFileFormat) {
+        let polled_messages = polled_messages();
+        let bytes = as_bytes(format, &polled_messages).unwrap();
+        let messages = read_messages(bytes, format).unwrap();
+        assert_eq!(messages.len(), polled_messages.len());
+        for (message, polled_message) in messages.iter().zip(polled_messages.iter()) {
+            assert_eq!(message.id, polled_message.id);
+            assert_eq!(message.timestamp, polled_message.timestamp);
+            assert_eq!(message.length, polled_message.payload.len() as u32);
+            assert_eq!(message.payload, polled_message.payload);
+            assert_eq!(message.headers, polled_message.headers);
+        }
+    }
+
+    #[test]
+    fn messages_should_be_written_and_read_as_json_lines() {
+        assert_round_trip(MessagesFileFormat::JsonLines);
+    }
+
+    #[test]
+    fn messages_should_be_written_and_read_as_binary() {
+        assert_round_trip(MessagesFileFormat::Binary);
+    }
+
+    #[test]
+    fn truncated_binary_file_should_fail() {
+        let bytes = as_bytes(MessagesFileFormat::Binary, &polled_messages()).unwrap();
+        let truncated = bytes.slice(..bytes.len() - 1);
+        assert!(read_messages(truncated, MessagesFileFormat::Binary).is_err());
+    }
+}
@@ -1,2 +1,6 @@
+pub mod export_messages;
+pub mod import_messages;
+pub mod messages_file;
 pub mod poll_messages;
 pub mod send_messages;
+pub mod tail_messages;
@@ -2,7 +2,7 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer::Consumer;
 use crate::identifier::Identifier;
-use crate::messages::poll_messages::{PollMessages, PollingStrategy};
+use crate::messages::poll_messages::{PollMessages, PollMessagesVersion, PollingStrategy};
 use crate::models::header::{HeaderKey, HeaderKind};
 use crate::models::messages::PolledMessages;
 use crate::utils::{byte_size::IggyByteSize, duration::IggyDuration, timestamp::IggyTimestamp};
@@ -16,6 +16,7 @@ use tracing::{event, Level};
 pub struct PollMessagesCmd {
     poll_messages: PollMessages,
     show_headers: bool,
+    show_key: bool,
 }
 
 impl PollMessagesCmd {
@@ -32,6 +33,7 @@ impl PollMessagesCmd {
         next: bool,
         consumer: Identifier,
         show_headers: bool,
+        show_key: bool,
     ) -> Self {
         let strategy = match (offset, first, last, next) {
             (Some(offset), false, false, false) => PollingStrategy::offset(offset),
@@ -49,8 +51,10 @@ impl PollMessagesCmd {
                 strategy,
                 count: message_count,
                 auto_commit,
+                version: PollMessagesVersion::default(),
             },
             show_headers,
+            show_key,
         }
     }
 
@@ -74,14 +78,15 @@ impl PollMessagesCmd {
             .collect::<HashSet<_>>()
     }
 
-    fn create_table_header(header_key_set: &HashSet<(HeaderKey, HeaderKind)>) -> Row {
-        let mut table_header = vec![
-            Cell::new("Offset"),
-            Cell::new("Timestamp"),
-            Cell::new("ID"),
-            Cell::new("Length"),
-            Cell::new("Payload"),
-        ];
+    fn create_table_header(
+        header_key_set: &HashSet<(HeaderKey, HeaderKind)>,
+        show_key: bool,
+    ) -> Row {
+        let mut table_header = vec![Cell::new("Offset"), Cell::new("Timestamp"), Cell::new("ID")];
+        if show_key {
+            table_header.push(Cell::new("Key"));
+        }
+        table_header.extend([Cell::new("Length"), Cell::new("Payload")]);
         let message_headers = header_key_set
             .iter()
             .map(|(key, kind)| {
@@ -96,6 +101,7 @@ impl PollMessagesCmd {
     fn create_table_content(
         polled_messages: &PolledMessages,
         message_header_keys: &HashSet<(HeaderKey, HeaderKind)>,
+        show_key: bool,
     ) -> Vec<Row> {
         polled_messages
             .messages
@@ -105,9 +111,20 @@ impl PollMessagesCmd {
                     format!("{}", message.offset),
                     IggyTimestamp::from(message.timestamp).to_local_string("%Y-%m-%d %H:%M:%S%.6f"),
                     format!("{}", message.id),
+                ];
+                if show_key {
+                    row.push(
+                        message
+                            .key
+                            .as_ref()
+                            .map(|key| String::from_utf8_lossy(key).to_string())
+                            .unwrap_or_default(),
+                    );
+                }
+                row.extend([
                     format!("{}", message.payload.len()),
                     String::from_utf8_lossy(&message.payload).to_string(),
-                ];
+                ]);
 
                 let values = message_header_keys
                     .iter()
@@ -181,8 +198,9 @@ impl CliCommand for PollMessagesCmd {
         let message_header_keys = self.create_message_header_keys(&messages);
 
         let mut table = Table::new();
-        let table_header = Self::create_table_header(&message_header_keys);
-        let table_content = Self::create_table_content(&messages, &message_header_keys);
+        let table_header = Self::create_table_header(&message_header_keys, self.show_key);
+        let table_content =
+            Self::create_table_content(&messages, &message_header_keys, self.show_key);
         table.set_header(table_header);
         table.add_rows(table_content);
 
@@ -0,0 +1,202 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::models::messages::PolledMessage;
+use crate::utils::duration::IggyDuration;
+use crate::utils::text;
+use anyhow::Context;
+use async_trait::async_trait;
+use serde::Serialize;
+use tracing::{event, Level};
+
+pub enum TailMessagesOutput {
+    Raw,
+    Hex,
+    Base64,
+    Json,
+}
+
+#[derive(Serialize)]
+struct TailedMessage<'a> {
+    partition_id: u32,
+    #[serde(flatten)]
+    message: &'a PolledMessage,
+}
+
+pub struct TailMessagesCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: Option<u32>,
+    consumer: Consumer,
+    first: bool,
+    timestamp: Option<u64>,
+    batch_size: u32,
+    interval: IggyDuration,
+    limit: Option<u64>,
+    output: TailMessagesOutput,
+}
+
+impl TailMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: Option<u32>,
+        consumer: Identifier,
+        first: bool,
+        timestamp: Option<u64>,
+        batch_size: u32,
+        interval: IggyDuration,
+        limit: Option<u64>,
+        output: TailMessagesOutput,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer: Consumer::new(consumer),
+            first,
+            timestamp,
+            batch_size,
+            interval,
+            // Zero limit means that the messages are tailed until interrupted, the same as no limit.
+            limit: limit.filter(|limit| *limit > 0),
+            output,
+        }
+    }
+
+    fn format_message(&self, partition_id: u32, message: &PolledMessage) -> String {
+        match self.output {
+            TailMessagesOutput::Raw => String::from_utf8_lossy(&message.payload).to_string(),
+            TailMessagesOutput::Hex => message
+                .payload
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect(),
+            TailMessagesOutput::Base64 => text::as_base64(&message.payload),
+            TailMessagesOutput::Json => serde_json::to_string(&TailedMessage {
+                partition_id,
+                message,
+            })
+            .unwrap_or_default(),
+        }
+    }
+
+    fn initial_strategy(&self, current_offset: u64, messages_count: u64) -> PollingStrategy {
+        if self.first {
+            return PollingStrategy::first();
+        }
+
+        if let Some(timestamp) = self.timestamp {
+            return PollingStrategy::timestamp(timestamp);
+        }
+
+        // By default, only the messages appended after starting the command are printed.
+        match messages_count {
+            0 => PollingStrategy::offset(0),
+            _ => PollingStrategy::offset(current_offset + 1),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for TailMessagesCmd {
+    fn explain(&self) -> String {
+        match self.partition_id {
+            Some(partition_id) => format!(
+                "tail messages from topic ID: {} and stream with ID: {} (partition with ID: {})",
+                self.topic_id, self.stream_id, partition_id
+            ),
+            None => format!(
+                "tail messages from topic ID: {} and stream with ID: {} (all partitions)",
+                self.topic_id, self.stream_id
+            ),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let topic = client
+            .get_topic(&self.stream_id, &self.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+
+        let mut partitions = topic
+            .partitions
+            .iter()
+            .filter(|partition| match self.partition_id {
+                Some(partition_id) => partition.id == partition_id,
+                None => true,
+            })
+            .map(|partition| {
+                (
+                    partition.id,
+                    self.initial_strategy(partition.current_offset, partition.messages_count),
+                )
+            })
+            .collect::<Vec<_>>();
+        partitions.sort_by_key(|(partition_id, _)| *partition_id);
+
+        if partitions.is_empty() {
+            anyhow::bail!(
+                "Partition with ID: {} was not found in topic with ID: {} and stream with ID: {}",
+                self.partition_id.unwrap_or_default(),
+                self.topic_id,
+                self.stream_id
+            );
+        }
+
+        let mut tailed_messages = 0u64;
+        loop {
+            let mut polled_any = false;
+            for (partition_id, strategy) in partitions.iter_mut() {
+                let count = match self.limit {
+                    Some(limit) => (limit - tailed_messages).min(self.batch_size as u64) as u32,
+                    None => self.batch_size,
+                };
+                let polled_messages = client
+                    .poll_messages(
+                        &self.stream_id,
+                        &self.topic_id,
+                        Some(*partition_id),
+                        &self.consumer,
+                        strategy,
+                        count,
+                        false,
+                    )
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem polling messages from topic with ID: {} and stream with ID: {} (partition with ID: {})",
+                            self.topic_id, self.stream_id, partition_id
+                        )
+                    })?;
+
+                let Some(last_message) = polled_messages.messages.last() else {
+                    continue;
+                };
+
+                polled_any = true;
+                *strategy = PollingStrategy::offset(last_message.offset + 1);
+                for message in &polled_messages.messages {
+                    event!(target: PRINT_TARGET, Level::INFO, "{}", self.format_message(*partition_id, message));
+                }
+
+                tailed_messages += polled_messages.messages.len() as u64;
+                if self.limit.is_some_and(|limit| tailed_messages >= limit) {
+                    return Ok(());
+                }
+            }
+
+            if !polled_any {
+                tokio::time::sleep(self.interval.get_duration()).await;
+            }
+        }
+    }
+}
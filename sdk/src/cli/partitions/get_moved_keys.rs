@@ -0,0 +1,87 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetMovedKeysCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partitions_count: u32,
+    keys: Vec<String>,
+}
+
+impl GetMovedKeysCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partitions_count: u32,
+        keys: Vec<String>,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            partitions_count,
+            keys,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetMovedKeysCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get messages keys moved after adding {} partitions to topic with ID: {} and stream with ID: {}",
+            self.partitions_count, self.topic_id, self.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let topic = client
+            .get_topic(&self.stream_id, &self.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+
+        let new_partitions_count = topic.partitions_count + self.partitions_count;
+        let moved_keys = topic.partitioning_scheme.get_moved_keys(
+            &self.keys,
+            topic.partitions_count,
+            new_partitions_count,
+        );
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Adding {} partitions to topic with ID: {} and stream with ID: {} using {} partitioning scheme moves {} of {} messages keys",
+            self.partitions_count,
+            self.topic_id,
+            self.stream_id,
+            topic.partitioning_scheme,
+            moved_keys.len(),
+            self.keys.len(),
+        );
+
+        if moved_keys.is_empty() {
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+        table.set_header(vec!["Key", "From partition ID", "To partition ID"]);
+        for moved_key in moved_keys {
+            table.add_row(vec![
+                String::from_utf8_lossy(&moved_key.key).to_string(),
+                format!("{}", moved_key.from_partition_id),
+                format!("{}", moved_key.to_partition_id),
+            ]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetOffsetsForTimestampCmd {
+    get_offsets_for_timestamp: GetOffsetsForTimestamp,
+}
+
+impl GetOffsetsForTimestampCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        timestamp: u64,
+        partition_ids: Vec<u32>,
+    ) -> Self {
+        Self {
+            get_offsets_for_timestamp: GetOffsetsForTimestamp {
+                stream_id,
+                topic_id,
+                timestamp,
+                partition_ids,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetOffsetsForTimestampCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get offsets for timestamp: {} for topic with ID: {} and stream with ID: {}",
+            self.get_offsets_for_timestamp.timestamp,
+            self.get_offsets_for_timestamp.topic_id,
+            self.get_offsets_for_timestamp.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let offsets = client
+            .get_offsets_for_timestamp(
+                &self.get_offsets_for_timestamp.stream_id,
+                &self.get_offsets_for_timestamp.topic_id,
+                &self.get_offsets_for_timestamp.partition_ids,
+                self.get_offsets_for_timestamp.timestamp,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting offsets for timestamp: {} for topic with ID: {} and stream with ID: {}",
+                    self.get_offsets_for_timestamp.timestamp,
+                    self.get_offsets_for_timestamp.topic_id,
+                    self.get_offsets_for_timestamp.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec![
+            "Partition ID",
+            "First offset",
+            "Last offset",
+            "Offset",
+        ]);
+        for partition in &offsets {
+            table.add_row(vec![
+                format!("{}", partition.partition_id),
+                format_offset(partition.first_offset),
+                format_offset(partition.last_offset),
+                format_offset(partition.offset),
+            ]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
+
+fn format_offset(offset: Option<u64>) -> String {
+    match offset {
+        Some(offset) => format!("{offset}"),
+        None => String::from("-"),
+    }
+}
@@ -1,2 +1,5 @@
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod get_moved_keys;
+pub mod get_offsets_for_timestamp;
+pub mod truncate_partition;
@@ -0,0 +1,74 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::truncate_partition::TruncatePartition;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct TruncatePartitionCmd {
+    truncate_partition: TruncatePartition,
+}
+
+impl TruncatePartitionCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Self {
+        Self {
+            truncate_partition: TruncatePartition {
+                stream_id,
+                topic_id,
+                partition_id,
+                offset,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for TruncatePartitionCmd {
+    fn explain(&self) -> String {
+        format!(
+            "truncate partition with ID: {} before offset: {} for topic with ID: {} and stream with ID: {}",
+            self.truncate_partition.partition_id,
+            self.truncate_partition.offset,
+            self.truncate_partition.topic_id,
+            self.truncate_partition.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let truncation = client
+            .truncate_partition(
+                &self.truncate_partition.stream_id,
+                &self.truncate_partition.topic_id,
+                self.truncate_partition.partition_id,
+                self.truncate_partition.offset,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem truncating partition with ID: {} before offset: {} for topic with ID: {} and stream with ID: {}",
+                    self.truncate_partition.partition_id,
+                    self.truncate_partition.offset,
+                    self.truncate_partition.topic_id,
+                    self.truncate_partition.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Partition with ID: {} for topic with ID: {} and stream with ID: {} truncated, low watermark: {}, deleted {} segments and {} messages",
+            truncation.partition_id,
+            self.truncate_partition.topic_id,
+            self.truncate_partition.stream_id,
+            truncation.low_watermark,
+            truncation.deleted_segments,
+            truncation.deleted_messages
+        );
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
 use crate::streams::get_stream::GetStream;
+use crate::topics::get_topic::TopicLayoutVersion;
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -14,7 +15,10 @@ pub struct GetStreamCmd {
 impl GetStreamCmd {
     pub fn new(stream_id: Identifier) -> Self {
         Self {
-            get_stream: GetStream { stream_id },
+            get_stream: GetStream {
+                stream_id,
+                version: TopicLayoutVersion::default(),
+            },
         }
     }
 }
@@ -51,6 +55,10 @@ impl CliCommand for GetStreamCmd {
             "Stream topics count",
             format!("{}", stream.topics_count).as_str(),
         ]);
+        table.add_row(vec![
+            "Topic config overrides",
+            stream.topic_config_overrides.to_string().as_str(),
+        ]);
 
         event!(target: PRINT_TARGET, Level::INFO, "{table}");
 
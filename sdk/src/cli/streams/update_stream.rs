@@ -1,6 +1,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::streams::update_stream::UpdateStream;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -11,9 +12,17 @@ pub struct UpdateStreamCmd {
 }
 
 impl UpdateStreamCmd {
-    pub fn new(stream_id: Identifier, name: String) -> Self {
+    pub fn new(
+        stream_id: Identifier,
+        name: String,
+        topic_config_overrides: TopicConfigOverrides,
+    ) -> Self {
         UpdateStreamCmd {
-            update_stream: UpdateStream { stream_id, name },
+            update_stream: UpdateStream {
+                stream_id,
+                topic_config_overrides: Some(topic_config_overrides),
+                name,
+            },
         }
     }
 }
@@ -29,7 +38,13 @@ impl CliCommand for UpdateStreamCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .update_stream(&self.update_stream.stream_id, &self.update_stream.name)
+            .update_stream(
+                &self.update_stream.stream_id,
+                &self.update_stream.name,
+                self.update_stream
+                    .topic_config_overrides
+                    .unwrap_or_default(),
+            )
             .await
             .with_context(|| {
                 format!(
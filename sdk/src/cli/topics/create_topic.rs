@@ -2,8 +2,10 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::topics::create_topic::CreateTopic;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
 use crate::utils::expiry::IggyExpiry;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -28,6 +30,8 @@ impl CreateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: IggyByteSize,
         replication_factor: u8,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Self {
         Self {
             create_topic: CreateTopic {
@@ -39,6 +43,8 @@ impl CreateTopicCmd {
                 message_expiry: message_expiry.clone().into(),
                 max_topic_size: Some(max_topic_size),
                 replication_factor: Some(replication_factor),
+                durability,
+                config_overrides,
             },
             message_expiry,
             max_topic_size,
@@ -62,7 +68,7 @@ impl CliCommand for CreateTopicCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .create_topic(&self.create_topic.stream_id, &self.create_topic.name, self.create_topic.partitions_count, self.create_topic.compression_algorithm, self.create_topic.replication_factor, self.create_topic.topic_id, self.create_topic.message_expiry.into(), self.create_topic.max_topic_size)
+            .create_topic(&self.create_topic.stream_id, &self.create_topic.name, self.create_topic.partitions_count, self.create_topic.compression_algorithm, self.create_topic.replication_factor, self.create_topic.topic_id, self.create_topic.message_expiry.into(), self.create_topic.max_topic_size, self.create_topic.durability, self.create_topic.config_overrides)
             .await
             .with_context(|| {
                 format!(
@@ -1,7 +1,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
-use crate::topics::get_topic::GetTopic;
+use crate::topics::get_topic::{GetTopic, TopicLayoutVersion};
 use crate::utils::timestamp::IggyTimestamp;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -18,6 +18,7 @@ impl GetTopicCmd {
             get_topic: GetTopic {
                 stream_id,
                 topic_id,
+                version: TopicLayoutVersion::default(),
             },
         }
     }
@@ -75,6 +76,15 @@ impl CliCommand for GetTopicCmd {
             }
             .as_str(),
         ]);
+        table.add_row(vec!["Durability", topic.durability.to_string().as_str()]);
+        table.add_row(vec![
+            "Config overrides",
+            topic.config_overrides.to_string().as_str(),
+        ]);
+        table.add_row(vec![
+            "Partitioning scheme",
+            topic.partitioning_scheme.to_string().as_str(),
+        ]);
         table.add_row(vec![
             "Topic message count",
             format!("{}", topic.messages_count).as_str(),
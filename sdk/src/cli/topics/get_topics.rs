@@ -1,6 +1,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
+use crate::topics::get_topic::TopicLayoutVersion;
 use crate::topics::get_topics::GetTopics;
 use crate::utils::timestamp::IggyTimestamp;
 use anyhow::Context;
@@ -33,7 +34,10 @@ pub struct GetTopicsCmd {
 impl GetTopicsCmd {
     pub fn new(stream_id: Identifier, output: GetTopicsOutput) -> Self {
         Self {
-            get_topics: GetTopics { stream_id },
+            get_topics: GetTopics {
+                stream_id,
+                version: TopicLayoutVersion::default(),
+            },
             output,
         }
     }
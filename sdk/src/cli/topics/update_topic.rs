@@ -2,8 +2,10 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::topics::update_topic::UpdateTopic;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
 use crate::utils::expiry::IggyExpiry;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -18,6 +20,7 @@ pub struct UpdateTopicCmd {
 }
 
 impl UpdateTopicCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: Identifier,
@@ -26,6 +29,8 @@ impl UpdateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: IggyByteSize,
         replication_factor: u8,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Self {
         Self {
             update_topic: UpdateTopic {
@@ -36,6 +41,8 @@ impl UpdateTopicCmd {
                 message_expiry: message_expiry.clone().into(),
                 max_topic_size: Some(max_topic_size),
                 replication_factor: Some(replication_factor),
+                durability,
+                config_overrides: Some(config_overrides),
             },
             message_expiry,
             max_topic_size,
@@ -52,7 +59,7 @@ impl CliCommand for UpdateTopicCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry.clone(), Some(self.max_topic_size))
+            .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry.clone(), Some(self.max_topic_size), self.update_topic.durability, self.update_topic.config_overrides.unwrap_or_default())
             .await
             .with_context(|| {
                 format!(
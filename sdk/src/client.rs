@@ -1,24 +1,35 @@
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::consumer::Consumer;
+use crate::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::poll_messages::PollingStrategy;
 use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::appended_messages::AppendedMessages;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offsets_reset::ConsumerOffsetsReset;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
+use crate::models::partition_offsets::PartitionOffsets;
+use crate::models::partition_truncation::PartitionTruncation;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::Schema;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
 use async_trait::async_trait;
 use std::fmt::Debug;
 
@@ -36,6 +47,7 @@ pub trait Client:
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
+    + SchemaClient
     + Sync
     + Send
     + Debug
@@ -163,10 +175,15 @@ pub trait StreamClient {
     ///
     /// Authentication is required, and the permission to manage the streams.
     async fn create_stream(&self, name: &str, stream_id: Option<u32>) -> Result<(), IggyError>;
-    /// Update a stream by unique ID or name.
+    /// Update a stream by unique ID or name, including the default config overrides for its topics.
     ///
     /// Authentication is required, and the permission to manage the streams.
-    async fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError>;
+    async fn update_stream(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        topic_config_overrides: TopicConfigOverrides,
+    ) -> Result<(), IggyError>;
     /// Delete a stream by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the streams.
@@ -207,6 +224,8 @@ pub trait TopicClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError>;
     /// Update a topic by unique ID or name.
     ///
@@ -220,6 +239,8 @@ pub trait TopicClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError>;
     /// Delete a topic by unique ID or name.
     ///
@@ -264,6 +285,31 @@ pub trait PartitionClient {
         topic_id: &Identifier,
         partitions_count: u32,
     ) -> Result<(), IggyError>;
+    /// Get the offsets corresponding to the given timestamp (in microseconds) for the specified partitions (all of them if empty) of a topic by unique ID or name.
+    ///
+    /// For each partition, the first and the last offset are returned, along with the offset of the first message with the timestamp greater than or equal to the given one, if any.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_offsets_for_timestamp(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_ids: &[u32],
+        timestamp: u64,
+    ) -> Result<Vec<PartitionOffsets>, IggyError>;
+    /// Delete the messages before the given offset in the partition of a topic by unique ID or name.
+    ///
+    /// The offset becomes the new low watermark of the partition, so the earlier messages are no longer returned by any polling strategy,
+    /// and the segments containing only such messages are deleted. The low watermark never moves backward or beyond the next offset of the partition.
+    ///
+    /// Authentication is required, and the permission to manage the topic.
+    async fn truncate_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<PartitionTruncation, IggyError>;
 }
 
 /// This trait defines the methods to interact with the messaging module.
@@ -283,6 +329,21 @@ pub trait MessageClient {
         count: u32,
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError>;
+    /// Poll given amount of messages like `poll_messages`, but the partition ID of the consumer group is not ignored,
+    /// and the messages are polled only if the partition is assigned to the member.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn poll_assigned_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError>;
     /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to send the messages.
@@ -293,6 +354,30 @@ pub trait MessageClient {
         partitioning: &Partitioning,
         messages: &mut [Message],
     ) -> Result<(), IggyError>;
+    /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names,
+    /// and wait until they are durably stored on disk, according to the durability level of the topic.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_durable_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError>;
+    /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names,
+    /// and return the partition ID and offsets assigned to the appended messages.
+    /// If `wait_for_durability` is set, the response is returned once the messages are durably stored on disk.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        wait_for_durability: bool,
+    ) -> Result<AppendedMessages, IggyError>;
 }
 
 /// This trait defines the methods to interact with the consumer offset module.
@@ -319,6 +404,27 @@ pub trait ConsumerOffsetClient {
         topic_id: &Identifier,
         partition_id: Option<u32>,
     ) -> Result<ConsumerOffsetInfo, IggyError>;
+    /// Get the lag of a specific consumer or consumer group across all the partitions of the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_consumer_lag(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ConsumerLag, IggyError>;
+    /// Reset the offsets of a specific consumer or consumer group across all the partitions of the given stream and topic by unique IDs or names.
+    /// The new offsets are calculated using the provided strategy, and with `dry_run` enabled they are only returned, without being stored.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        strategy: OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerOffsetsReset, IggyError>;
 }
 
 /// This trait defines the methods to interact with the consumer group module.
@@ -379,3 +485,35 @@ pub trait ConsumerGroupClient {
         group_id: &Identifier,
     ) -> Result<(), IggyError>;
 }
+
+/// This trait defines the methods to interact with the schema registry module.
+#[async_trait]
+pub trait SchemaClient {
+    /// Get the schema by unique ID, as carried in the `iggy-schema-id` message header.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_schema(&self, schema_id: u32) -> Result<Schema, IggyError>;
+    /// Get all the versions of the schema registered for the topic by unique ID or name, ordered by version.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError>;
+    /// Register a new version of the schema for the topic by unique ID or name.
+    ///
+    /// The definition must be compatible with the latest version of the topic schema according to the provided compatibility rule.
+    /// If the validation is enabled, the payloads of the appended messages are validated against the latest schema (or the one referenced by the `iggy-schema-id` header).
+    ///
+    /// Authentication is required, and the permission to manage the topic.
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        validation_enabled: bool,
+        definition: &str,
+    ) -> Result<Schema, IggyError>;
+}
@@ -2,27 +2,41 @@ use crate::client::Client;
 use crate::client_error::ClientError;
 #[allow(deprecated)]
 use crate::clients::client::IggyClient;
+#[cfg(feature = "grpc")]
+use crate::grpc::client::GrpcClient;
+#[cfg(feature = "grpc")]
+use crate::grpc::config::GrpcClientConfig;
 use crate::http::client::HttpClient;
 use crate::http::config::HttpClientConfig;
 use crate::quic::client::QuicClient;
 use crate::quic::config::QuicClientConfig;
 use crate::tcp::client::TcpClient;
 use crate::tcp::config::TcpClientConfig;
+#[cfg(unix)]
+use crate::unix::client::UnixClient;
+#[cfg(unix)]
+use crate::unix::config::UnixClientConfig;
 use std::sync::Arc;
 
 const QUIC_TRANSPORT: &str = "quic";
 const HTTP_TRANSPORT: &str = "http";
 const TCP_TRANSPORT: &str = "tcp";
+#[cfg(feature = "grpc")]
+const GRPC_TRANSPORT: &str = "grpc";
+#[cfg(unix)]
+const UNIX_TRANSPORT: &str = "unix";
 
 /// Configuration for the `ClientProvider`.
 /// It consists of the following fields:
-/// - `transport`: the transport to use. Valid values are `quic`, `http` and `tcp`.
+/// - `transport`: the transport to use. Valid values are `quic`, `http`, `tcp`, `grpc` and `unix` (on Unix systems only).
 /// - `http`: the optional configuration for the HTTP transport.
 /// - `quic`: the optional configuration for the QUIC transport.
 /// - `tcp`: the optional configuration for the TCP transport.
+/// - `grpc`: the optional configuration for the gRPC transport (with the `grpc` feature).
+/// - `unix`: the optional configuration for the Unix domain socket transport.
 #[derive(Debug)]
 pub struct ClientProviderConfig {
-    /// The transport to use. Valid values are `quic`, `http` and `tcp`.
+    /// The transport to use. Valid values are `quic`, `http`, `tcp`, `grpc` (with the `grpc` feature) and `unix` (on Unix systems only).
     pub transport: String,
     /// The optional configuration for the HTTP transport.
     pub http: Option<Arc<HttpClientConfig>>,
@@ -30,6 +44,12 @@ pub struct ClientProviderConfig {
     pub quic: Option<Arc<QuicClientConfig>>,
     /// The optional configuration for the TCP transport.
     pub tcp: Option<Arc<TcpClientConfig>>,
+    /// The optional configuration for the gRPC transport.
+    #[cfg(feature = "grpc")]
+    pub grpc: Option<Arc<GrpcClientConfig>>,
+    /// The optional configuration for the Unix domain socket transport.
+    #[cfg(unix)]
+    pub unix: Option<Arc<UnixClientConfig>>,
 }
 
 impl Default for ClientProviderConfig {
@@ -39,6 +59,10 @@ impl Default for ClientProviderConfig {
             http: Some(Arc::new(HttpClientConfig::default())),
             quic: Some(Arc::new(QuicClientConfig::default())),
             tcp: Some(Arc::new(TcpClientConfig::default())),
+            #[cfg(feature = "grpc")]
+            grpc: Some(Arc::new(GrpcClientConfig::default())),
+            #[cfg(unix)]
+            unix: Some(Arc::new(UnixClientConfig::default())),
         }
     }
 }
@@ -52,6 +76,10 @@ impl ClientProviderConfig {
             http: None,
             quic: None,
             tcp: None,
+            #[cfg(feature = "grpc")]
+            grpc: None,
+            #[cfg(unix)]
+            unix: None,
         };
         match config.transport.as_str() {
             QUIC_TRANSPORT => {
@@ -76,6 +104,7 @@ impl ClientProviderConfig {
                 config.http = Some(Arc::new(HttpClientConfig {
                     api_url: args.http_api_url,
                     retries: args.http_retries,
+                    ..HttpClientConfig::default()
                 }));
             }
             TCP_TRANSPORT => {
@@ -87,6 +116,26 @@ impl ClientProviderConfig {
                     tls_domain: args.tcp_tls_domain,
                 }));
             }
+            #[cfg(feature = "grpc")]
+            GRPC_TRANSPORT => {
+                config.grpc = Some(Arc::new(GrpcClientConfig {
+                    server_address: args.grpc_server_address,
+                    reconnection_retries: args.grpc_reconnection_retries,
+                    reconnection_interval: args.grpc_reconnection_interval,
+                    tls_enabled: args.grpc_tls_enabled,
+                    tls_domain: args.grpc_tls_domain,
+                    tls_ca_file: args.grpc_tls_ca_file,
+                    ..GrpcClientConfig::default()
+                }));
+            }
+            #[cfg(unix)]
+            UNIX_TRANSPORT => {
+                config.unix = Some(Arc::new(UnixClientConfig {
+                    socket_path: args.unix_socket_path,
+                    reconnection_retries: args.unix_reconnection_retries,
+                    reconnection_interval: args.unix_reconnection_interval,
+                }));
+            }
             _ => return Err(ClientError::InvalidTransport(config.transport.clone())),
         }
 
@@ -140,6 +189,24 @@ pub async fn get_raw_client(
             };
             Ok(Box::new(client))
         }
+        #[cfg(feature = "grpc")]
+        GRPC_TRANSPORT => {
+            let grpc_config = config.grpc.as_ref().unwrap();
+            let client = GrpcClient::create(grpc_config.clone())?;
+            if establish_connection {
+                Client::connect(&client).await?
+            };
+            Ok(Box::new(client))
+        }
+        #[cfg(unix)]
+        UNIX_TRANSPORT => {
+            let unix_config = config.unix.as_ref().unwrap();
+            let client = UnixClient::create(unix_config.clone())?;
+            if establish_connection {
+                Client::connect(&client).await?
+            };
+            Ok(Box::new(client))
+        }
         _ => Err(ClientError::InvalidTransport(transport)),
     }
 }
@@ -1,6 +1,10 @@
 use crate::client::Client;
 use crate::clients::client::{IggyClient, IggyClientBackgroundConfig};
 use crate::error::IggyError;
+#[cfg(feature = "grpc")]
+use crate::grpc::client::GrpcClient;
+#[cfg(feature = "grpc")]
+use crate::grpc::config::GrpcClientConfigBuilder;
 use crate::http::client::HttpClient;
 use crate::http::config::HttpClientConfigBuilder;
 use crate::message_handler::MessageHandler;
@@ -9,6 +13,10 @@ use crate::quic::client::QuicClient;
 use crate::quic::config::QuicClientConfigBuilder;
 use crate::tcp::client::TcpClient;
 use crate::tcp::config::TcpClientConfigBuilder;
+#[cfg(unix)]
+use crate::unix::client::UnixClient;
+#[cfg(unix)]
+use crate::unix::config::UnixClientConfigBuilder;
 use crate::utils::crypto::Encryptor;
 use std::sync::Arc;
 use tracing::error;
@@ -90,10 +98,32 @@ impl IggyClientBuilder {
         }
     }
 
+    /// This method provides fluent API for the gRPC client configuration.
+    /// It returns the `GrpcClientBuilder` instance, which allows to configure the gRPC client with custom settings or using defaults.
+    /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    #[cfg(feature = "grpc")]
+    pub fn with_grpc(self) -> GrpcClientBuilder {
+        GrpcClientBuilder {
+            config: GrpcClientConfigBuilder::default(),
+            parent_builder: self,
+        }
+    }
+
+    /// This method provides fluent API for the Unix domain socket client configuration.
+    /// It returns the `UnixClientBuilder` instance, which allows to configure the Unix domain socket client with custom settings or using defaults.
+    /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    #[cfg(unix)]
+    pub fn with_unix(self) -> UnixClientBuilder {
+        UnixClientBuilder {
+            config: UnixClientConfigBuilder::default(),
+            parent_builder: self,
+        }
+    }
+
     /// Build the `IggyClient` instance.
     /// This method returns an error if the client is not provided.
     /// If the client is provided, it creates the `IggyClient` instance with the provided configuration.
-    /// To provide the client configuration, use the `with_tcp`, `with_quic` or `with_http` methods.
+    /// To provide the client configuration, use the `with_tcp`, `with_quic`, `with_http`, `with_grpc` or `with_unix` methods.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let Some(client) = self.client else {
             error!("Client is not provided");
@@ -224,3 +254,101 @@ impl HttpClientBuilder {
         Ok(client)
     }
 }
+
+#[cfg(feature = "grpc")]
+#[derive(Debug, Default)]
+pub struct GrpcClientBuilder {
+    config: GrpcClientConfigBuilder,
+    parent_builder: IggyClientBuilder,
+}
+
+#[cfg(feature = "grpc")]
+impl GrpcClientBuilder {
+    /// Sets the server address for the gRPC client.
+    pub fn with_server_address(mut self, server_address: String) -> Self {
+        self.config = self.config.with_server_address(server_address);
+        self
+    }
+
+    /// Sets the number of retries when connecting to the server.
+    pub fn with_reconnection_retries(mut self, reconnection_retries: u32) -> Self {
+        self.config = self.config.with_reconnection_retries(reconnection_retries);
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, reconnection_interval: u64) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_interval(reconnection_interval);
+        self
+    }
+
+    /// Sets the deadline in milliseconds of each request.
+    pub fn with_request_timeout(mut self, request_timeout: u64) -> Self {
+        self.config = self.config.with_request_timeout(request_timeout);
+        self
+    }
+
+    /// Sets whether to use TLS when connecting to the server.
+    pub fn with_tls_enabled(mut self, tls_enabled: bool) -> Self {
+        self.config = self.config.with_tls_enabled(tls_enabled);
+        self
+    }
+
+    /// Sets the domain to use for TLS when connecting to the server.
+    pub fn with_tls_domain(mut self, tls_domain: String) -> Self {
+        self.config = self.config.with_tls_domain(tls_domain);
+        self
+    }
+
+    /// Sets the path to the CA certificate used to verify the server.
+    pub fn with_tls_ca_file(mut self, tls_ca_file: String) -> Self {
+        self.config = self.config.with_tls_ca_file(tls_ca_file);
+        self
+    }
+
+    /// Builds the parent `IggyClient` with gRPC configuration.
+    pub fn build(self) -> Result<IggyClient, IggyError> {
+        let client = GrpcClient::create(Arc::new(self.config.build()))?;
+        let client = self.parent_builder.with_client(Box::new(client)).build()?;
+        Ok(client)
+    }
+}
+
+#[cfg(unix)]
+#[derive(Debug, Default)]
+pub struct UnixClientBuilder {
+    config: UnixClientConfigBuilder,
+    parent_builder: IggyClientBuilder,
+}
+
+#[cfg(unix)]
+impl UnixClientBuilder {
+    /// Sets the socket path for the Unix domain socket client.
+    pub fn with_socket_path(mut self, socket_path: String) -> Self {
+        self.config = self.config.with_socket_path(socket_path);
+        self
+    }
+
+    /// Sets the number of retries when connecting to the server.
+    pub fn with_reconnection_retries(mut self, reconnection_retries: u32) -> Self {
+        self.config = self.config.with_reconnection_retries(reconnection_retries);
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, reconnection_interval: u64) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_interval(reconnection_interval);
+        self
+    }
+
+    /// Builds the parent `IggyClient` with Unix domain socket configuration.
+    pub fn build(self) -> Result<IggyClient, IggyError> {
+        let client = UnixClient::create(Arc::new(self.config.build()))?;
+        let client = self.parent_builder.with_client(Box::new(client)).build()?;
+        Ok(client)
+    }
+}
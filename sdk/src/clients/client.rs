@@ -1,23 +1,33 @@
 use crate::client::{
     Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, UserClient,
+    PersonalAccessTokenClient, SchemaClient, StreamClient, SystemClient, TopicClient, UserClient,
 };
 use crate::consumer::Consumer;
+use crate::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::locking::IggySharedMut;
 use crate::locking::IggySharedMutFn;
 use crate::message_handler::MessageHandler;
-use crate::messages::send_messages::{Message, Partitioning, PartitioningKind, SendMessages};
+use crate::messages::send_messages::{
+    Message, Partitioning, PartitioningKind, SendMessages, SendMessagesVersion,
+};
+use crate::models::appended_messages::AppendedMessages;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offsets_reset::ConsumerOffsetsReset;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{PolledMessage, PolledMessages};
+use crate::models::partition_offsets::PartitionOffsets;
+use crate::models::partition_truncation::PartitionTruncation;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::Schema;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitioner::Partitioner;
 use crate::tcp::client::TcpClient;
@@ -40,8 +50,11 @@ use crate::messages::poll_messages::{PollingKind, PollingStrategy};
 use crate::models::permissions::Permissions;
 use crate::models::user_status::UserStatus;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
 
 // The default interval between sending the messages as batches in the background.
 pub const DEFAULT_SEND_MESSAGES_INTERVAL_MS: u64 = 100;
@@ -441,6 +454,8 @@ impl IggyClient {
                             value: key.value.clone(),
                         },
                         messages,
+                        wait_for_durability: false,
+                        version: SendMessagesVersion::default(),
                     };
 
                     if let Err(error) = client
@@ -640,11 +655,16 @@ impl StreamClient for IggyClient {
             .await
     }
 
-    async fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError> {
+    async fn update_stream(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        topic_config_overrides: TopicConfigOverrides,
+    ) -> Result<(), IggyError> {
         self.client
             .read()
             .await
-            .update_stream(stream_id, name)
+            .update_stream(stream_id, name, topic_config_overrides)
             .await
     }
 
@@ -685,6 +705,8 @@ impl TopicClient for IggyClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError> {
         self.client
             .read()
@@ -698,6 +720,8 @@ impl TopicClient for IggyClient {
                 topic_id,
                 message_expiry,
                 max_topic_size,
+                durability,
+                config_overrides,
             )
             .await
     }
@@ -711,6 +735,8 @@ impl TopicClient for IggyClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError> {
         self.client
             .read()
@@ -723,6 +749,8 @@ impl TopicClient for IggyClient {
                 replication_factor,
                 message_expiry,
                 max_topic_size,
+                durability,
+                config_overrides,
             )
             .await
     }
@@ -779,6 +807,34 @@ impl PartitionClient for IggyClient {
             .delete_partitions(stream_id, topic_id, partitions_count)
             .await
     }
+
+    async fn get_offsets_for_timestamp(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_ids: &[u32],
+        timestamp: u64,
+    ) -> Result<Vec<PartitionOffsets>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_offsets_for_timestamp(stream_id, topic_id, partition_ids, timestamp)
+            .await
+    }
+
+    async fn truncate_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<PartitionTruncation, IggyError> {
+        self.client
+            .read()
+            .await
+            .truncate_partition(stream_id, topic_id, partition_id, offset)
+            .await
+    }
 }
 
 #[async_trait]
@@ -812,6 +868,35 @@ impl MessageClient for IggyClient {
             .await
     }
 
+    async fn poll_assigned_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        if count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        self.client
+            .read()
+            .await
+            .poll_assigned_messages(
+                stream_id,
+                topic_id,
+                partition_id,
+                consumer,
+                strategy,
+                count,
+                auto_commit,
+            )
+            .await
+    }
+
     async fn send_messages(
         &self,
         stream_id: &Identifier,
@@ -829,6 +914,49 @@ impl MessageClient for IggyClient {
             .send_messages(stream_id, topic_id, partitioning, messages)
             .await
     }
+
+    async fn send_durable_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        if messages.is_empty() {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        self.client
+            .read()
+            .await
+            .send_durable_messages(stream_id, topic_id, partitioning, messages)
+            .await
+    }
+
+    async fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        wait_for_durability: bool,
+    ) -> Result<AppendedMessages, IggyError> {
+        if messages.is_empty() {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        self.client
+            .read()
+            .await
+            .send_messages_with_offsets(
+                stream_id,
+                topic_id,
+                partitioning,
+                messages,
+                wait_for_durability,
+            )
+            .await
+    }
 }
 
 #[async_trait]
@@ -861,6 +989,34 @@ impl ConsumerOffsetClient for IggyClient {
             .get_consumer_offset(consumer, stream_id, topic_id, partition_id)
             .await
     }
+
+    async fn get_consumer_lag(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ConsumerLag, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_consumer_lag(consumer, stream_id, topic_id)
+            .await
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        strategy: OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerOffsetsReset, IggyError> {
+        self.client
+            .read()
+            .await
+            .reset_consumer_offsets(consumer, stream_id, topic_id, strategy, dry_run)
+            .await
+    }
 }
 
 #[async_trait]
@@ -944,6 +1100,48 @@ impl ConsumerGroupClient for IggyClient {
     }
 }
 
+#[async_trait]
+impl SchemaClient for IggyClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Schema, IggyError> {
+        self.client.read().await.get_schema(schema_id).await
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError> {
+        self.client
+            .read()
+            .await
+            .get_schemas(stream_id, topic_id)
+            .await
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        validation_enabled: bool,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        self.client
+            .read()
+            .await
+            .register_schema(
+                stream_id,
+                topic_id,
+                schema_type,
+                compatibility,
+                validation_enabled,
+                definition,
+            )
+            .await
+    }
+}
+
 #[async_trait]
 impl AsyncDrop for IggyClient {
     async fn async_drop(&mut self) {
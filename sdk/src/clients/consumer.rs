@@ -0,0 +1,585 @@
+use crate::client::{ConsumerGroupClient, ConsumerOffsetClient, MessageClient, SystemClient};
+use crate::clients::client::IggyClient;
+use crate::consumer::Consumer;
+use crate::error::{IggyError, IggyErrorDiscriminants};
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::models::messages::PolledMessage;
+use crate::utils::duration::IggyDuration;
+use futures::Stream;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use tracing::{trace, warn};
+
+/// The strategy of committing the offsets of the consumed messages on the server.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CommitMode {
+    /// The offsets are committed only when `commit` or `store_offset` is called.
+    Manual,
+    /// The offsets of the consumed messages are committed periodically, at the given interval, and on shutdown.
+    Periodic(IggyDuration),
+    /// The offset of the consumed message is committed once the next message is requested, and on shutdown.
+    AfterEachMessage,
+}
+
+/// The configuration of the `IggyConsumer`.
+#[derive(Debug, Clone)]
+pub struct IggyConsumerConfig {
+    /// The maximum number of messages polled from the single partition at once.
+    pub batch_size: u32,
+    /// The delay before polling again, when none of the assigned partitions had the new messages.
+    pub poll_interval: IggyDuration,
+    /// The strategy of committing the offsets of the consumed messages.
+    pub commit_mode: CommitMode,
+    /// The interval of refreshing the partitions assigned to the consumer group member, to detect the rebalancing.
+    pub assignment_refresh_interval: IggyDuration,
+}
+
+impl Default for IggyConsumerConfig {
+    fn default() -> Self {
+        IggyConsumerConfig {
+            batch_size: 100,
+            poll_interval: IggyDuration::new(Duration::from_millis(100)),
+            commit_mode: CommitMode::Periodic(IggyDuration::new(Duration::from_secs(5))),
+            assignment_refresh_interval: IggyDuration::new(Duration::from_secs(5)),
+        }
+    }
+}
+
+/// The message received by the `IggyConsumer`.
+#[derive(Debug)]
+pub struct ReceivedMessage {
+    /// The identifier of the partition the message was polled from.
+    pub partition_id: u32,
+    /// The current offset of the partition at the time the message was polled.
+    pub current_offset: u64,
+    /// The polled message.
+    pub message: PolledMessage,
+}
+
+#[derive(Debug, Default)]
+struct ConsumerState {
+    member_id: Option<u32>,
+    partitions: Vec<u32>,
+    next_partition_index: usize,
+    empty_polls: usize,
+    paused: HashSet<u32>,
+    positions: HashMap<u32, u64>,
+    consumed: HashMap<u32, u64>,
+    committed: HashMap<u32, u64>,
+    buffer: VecDeque<ReceivedMessage>,
+    last_commit: Option<Instant>,
+    last_refresh: Option<Instant>,
+}
+
+impl ConsumerState {
+    /// Replaces the assigned partitions, dropping the state of the revoked ones. Returns the offsets of the messages
+    /// consumed from the revoked partitions, which haven't been committed yet.
+    fn assign_partitions(&mut self, mut partitions: Vec<u32>) -> Vec<(u32, u64)> {
+        partitions.sort_unstable();
+        if partitions == self.partitions {
+            return Vec::new();
+        }
+
+        trace!(
+            "Assigned partitions changed from {:?} to {partitions:?}",
+            self.partitions
+        );
+        let revoked_offsets = self
+            .get_uncommitted_offsets()
+            .into_iter()
+            .filter(|(partition_id, _)| !partitions.contains(partition_id))
+            .collect();
+        self.positions.retain(|id, _| partitions.contains(id));
+        self.consumed.retain(|id, _| partitions.contains(id));
+        self.committed.retain(|id, _| partitions.contains(id));
+        self.buffer
+            .retain(|message| partitions.contains(&message.partition_id));
+        self.partitions = partitions;
+        self.next_partition_index = 0;
+        self.empty_polls = 0;
+        revoked_offsets
+    }
+
+    fn next_partition(&mut self) -> Option<(u32, PollingStrategy)> {
+        let partitions = self
+            .partitions
+            .iter()
+            .filter(|id| !self.paused.contains(id))
+            .copied()
+            .collect::<Vec<_>>();
+        if partitions.is_empty() {
+            return None;
+        }
+
+        let partition_id = partitions[self.next_partition_index % partitions.len()];
+        self.next_partition_index = (self.next_partition_index + 1) % partitions.len();
+        let strategy = match self.positions.get(&partition_id) {
+            Some(offset) => PollingStrategy::offset(*offset),
+            None => PollingStrategy::next(),
+        };
+        Some((partition_id, strategy))
+    }
+
+    fn active_partitions_count(&self) -> usize {
+        self.partitions
+            .iter()
+            .filter(|id| !self.paused.contains(id))
+            .count()
+    }
+
+    fn take_message(&mut self) -> Option<ReceivedMessage> {
+        let index = self
+            .buffer
+            .iter()
+            .position(|message| !self.paused.contains(&message.partition_id))?;
+        let message = self.buffer.remove(index)?;
+        self.consumed
+            .insert(message.partition_id, message.message.offset);
+        Some(message)
+    }
+
+    fn get_uncommitted_offsets(&self) -> Vec<(u32, u64)> {
+        let mut offsets = self
+            .consumed
+            .iter()
+            .filter(|(partition_id, offset)| self.committed.get(partition_id) != Some(offset))
+            .map(|(partition_id, offset)| (*partition_id, *offset))
+            .collect::<Vec<_>>();
+        offsets.sort_unstable();
+        offsets
+    }
+}
+
+#[derive(Debug)]
+struct ConsumerContext {
+    client: Arc<IggyClient>,
+    consumer: Consumer,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    config: IggyConsumerConfig,
+    state: Mutex<ConsumerState>,
+}
+
+type NextMessageFuture = Pin<Box<dyn Future<Output = Result<ReceivedMessage, IggyError>> + Send>>;
+
+/// `IggyConsumer` consumes the messages from the topic as a member of the consumer group, exposing them as the async `Stream`.
+///
+/// The consumer joins the consumer group automatically when polled for the first time, and keeps track of the partitions
+/// assigned to it, which are refreshed periodically to detect the rebalancing. The assigned partitions are polled in the
+/// round-robin order, one batch at a time, so none of them is starved. Each partition can be paused and resumed, the messages
+/// already buffered for the paused partition are held until it's resumed.
+///
+/// The offsets of the consumed messages are committed according to the configured `CommitMode`, including the ones consumed
+/// from the partitions revoked by the rebalancing, once it's detected. Since the offsets are committed after the messages
+/// are returned, the delivery is at-least-once, and the messages can be redelivered after the rebalancing.
+/// The stream doesn't end on errors, so the caller can decide whether to continue consuming or to shut down the consumer,
+/// which commits the offsets of the consumed messages for the last time and leaves the consumer group.
+pub struct IggyConsumer {
+    context: Arc<ConsumerContext>,
+    next_message: Option<NextMessageFuture>,
+}
+
+impl Debug for IggyConsumer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IggyConsumer")
+            .field("context", &self.context)
+            .finish_non_exhaustive()
+    }
+}
+
+impl IggyConsumer {
+    /// Creates a new `IggyConsumer` for the given consumer group, stream and topic by unique IDs or names.
+    /// The consumer group must already exist, and the client must be authenticated.
+    pub fn new(
+        client: Arc<IggyClient>,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+        config: IggyConsumerConfig,
+    ) -> Self {
+        IggyConsumer {
+            context: Arc::new(ConsumerContext {
+                client,
+                consumer: Consumer::group(consumer_group_id.clone()),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                config,
+                state: Mutex::new(ConsumerState::default()),
+            }),
+            next_message: None,
+        }
+    }
+
+    /// Returns the configuration of the consumer.
+    pub fn config(&self) -> &IggyConsumerConfig {
+        &self.context.config
+    }
+
+    /// Joins the consumer group and fetches the assigned partitions. It's called automatically when the consumer is polled for the first time.
+    pub async fn init(&self) -> Result<(), IggyError> {
+        self.context.refresh_assignment(true).await
+    }
+
+    /// Returns the IDs of the partitions currently assigned to the consumer.
+    pub fn assigned_partitions(&self) -> Vec<u32> {
+        self.context.state.lock().unwrap().partitions.clone()
+    }
+
+    /// Pauses consuming the messages from the given partition, until it's resumed.
+    pub fn pause(&self, partition_id: u32) {
+        self.context
+            .state
+            .lock()
+            .unwrap()
+            .paused
+            .insert(partition_id);
+    }
+
+    /// Resumes consuming the messages from the given partition.
+    pub fn resume(&self, partition_id: u32) {
+        self.context
+            .state
+            .lock()
+            .unwrap()
+            .paused
+            .remove(&partition_id);
+    }
+
+    /// Returns the IDs of the paused partitions.
+    pub fn paused_partitions(&self) -> Vec<u32> {
+        let mut partitions = self
+            .context
+            .state
+            .lock()
+            .unwrap()
+            .paused
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        partitions.sort_unstable();
+        partitions
+    }
+
+    /// Commits the offsets of the messages consumed so far, which haven't been committed yet.
+    pub async fn commit(&self) -> Result<(), IggyError> {
+        self.context.commit().await
+    }
+
+    /// Stores the offset for the given partition on the server, regardless of the consumed messages.
+    pub async fn store_offset(&self, partition_id: u32, offset: u64) -> Result<(), IggyError> {
+        self.context.store_offset(partition_id, offset).await
+    }
+
+    /// Shuts down the consumer, committing the offsets of the consumed messages (unless the commit mode is manual) and leaving the consumer group.
+    pub async fn shutdown(mut self) -> Result<(), IggyError> {
+        self.next_message = None;
+        let context = &self.context;
+        let joined = context.state.lock().unwrap().member_id.is_some();
+        if !joined {
+            return Ok(());
+        }
+
+        if context.config.commit_mode != CommitMode::Manual {
+            context.commit().await?;
+        }
+        context
+            .client
+            .leave_consumer_group(&context.stream_id, &context.topic_id, &context.consumer.id)
+            .await
+    }
+}
+
+impl Stream for IggyConsumer {
+    type Item = Result<ReceivedMessage, IggyError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.next_message.is_none() {
+            let context = self.context.clone();
+            self.next_message = Some(Box::pin(context.next_message()));
+        }
+
+        let result = ready!(self.next_message.as_mut().unwrap().as_mut().poll(cx));
+        self.next_message = None;
+        Poll::Ready(Some(result))
+    }
+}
+
+impl ConsumerContext {
+    async fn next_message(self: Arc<Self>) -> Result<ReceivedMessage, IggyError> {
+        loop {
+            self.refresh_assignment(false).await?;
+            self.commit_if_due().await?;
+            if let Some(message) = self.state.lock().unwrap().take_message() {
+                return Ok(message);
+            }
+
+            if !self.fetch().await? {
+                sleep(self.config.poll_interval.get_duration()).await;
+            }
+        }
+    }
+
+    async fn refresh_assignment(&self, force: bool) -> Result<(), IggyError> {
+        let (member_id, refresh) = {
+            let state = self.state.lock().unwrap();
+            let refresh =
+                force || is_due(state.last_refresh, self.config.assignment_refresh_interval);
+            (state.member_id, refresh)
+        };
+        if !refresh {
+            return Ok(());
+        }
+
+        let member_id = match member_id {
+            Some(member_id) => member_id,
+            None => {
+                self.client
+                    .join_consumer_group(&self.stream_id, &self.topic_id, &self.consumer.id)
+                    .await?;
+                let member_id = self.client.get_me().await?.client_id;
+                self.state.lock().unwrap().member_id = Some(member_id);
+                member_id
+            }
+        };
+
+        let consumer_group = self
+            .client
+            .get_consumer_group(&self.stream_id, &self.topic_id, &self.consumer.id)
+            .await?;
+        let partitions = consumer_group
+            .members
+            .into_iter()
+            .find(|member| member.id == member_id)
+            .map(|member| member.partitions)
+            .unwrap_or_default();
+        let revoked_offsets = {
+            let mut state = self.state.lock().unwrap();
+            state.last_refresh = Some(Instant::now());
+            state.assign_partitions(partitions)
+        };
+        if self.config.commit_mode == CommitMode::Manual {
+            return Ok(());
+        }
+
+        for (partition_id, offset) in revoked_offsets {
+            self.store_server_offset(partition_id, offset).await?;
+        }
+        Ok(())
+    }
+
+    async fn fetch(&self) -> Result<bool, IggyError> {
+        let Some((partition_id, strategy)) = self.state.lock().unwrap().next_partition() else {
+            return Ok(false);
+        };
+
+        let polled_messages = match self
+            .client
+            .poll_assigned_messages(
+                &self.stream_id,
+                &self.topic_id,
+                Some(partition_id),
+                &self.consumer,
+                &strategy,
+                self.config.batch_size,
+                false,
+            )
+            .await
+        {
+            Ok(polled_messages) => polled_messages,
+            Err(IggyError::InvalidResponse(code, _, _))
+                if code == IggyErrorDiscriminants::ConsumerGroupPartitionNotAssigned as u32 =>
+            {
+                warn!(
+                    "Partition with ID: {partition_id} is no longer assigned to the consumer, refreshing the assignment."
+                );
+                self.state.lock().unwrap().last_refresh = None;
+                return Ok(true);
+            }
+            Err(error) => return Err(error),
+        };
+
+        let mut state = self.state.lock().unwrap();
+        if !state.partitions.contains(&partition_id) {
+            return Ok(true);
+        }
+
+        let Some(last_message) = polled_messages.messages.last() else {
+            state.empty_polls += 1;
+            if state.empty_polls >= state.active_partitions_count() {
+                state.empty_polls = 0;
+                return Ok(false);
+            }
+            return Ok(true);
+        };
+
+        state.empty_polls = 0;
+        state
+            .positions
+            .insert(partition_id, last_message.offset + 1);
+        let current_offset = polled_messages.current_offset;
+        state.buffer.extend(
+            polled_messages
+                .messages
+                .into_iter()
+                .map(|message| ReceivedMessage {
+                    partition_id,
+                    current_offset,
+                    message,
+                }),
+        );
+        Ok(true)
+    }
+
+    async fn commit_if_due(&self) -> Result<(), IggyError> {
+        match self.config.commit_mode {
+            CommitMode::Manual => Ok(()),
+            CommitMode::AfterEachMessage => self.commit().await,
+            CommitMode::Periodic(interval) => {
+                let last_commit = self.state.lock().unwrap().last_commit;
+                if is_due(last_commit, interval) {
+                    self.commit().await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn commit(&self) -> Result<(), IggyError> {
+        let offsets = self.state.lock().unwrap().get_uncommitted_offsets();
+        for (partition_id, offset) in offsets {
+            self.store_offset(partition_id, offset).await?;
+        }
+        self.state.lock().unwrap().last_commit = Some(Instant::now());
+        Ok(())
+    }
+
+    async fn store_offset(&self, partition_id: u32, offset: u64) -> Result<(), IggyError> {
+        self.store_server_offset(partition_id, offset).await?;
+        self.state
+            .lock()
+            .unwrap()
+            .committed
+            .insert(partition_id, offset);
+        Ok(())
+    }
+
+    async fn store_server_offset(&self, partition_id: u32, offset: u64) -> Result<(), IggyError> {
+        self.client
+            .store_consumer_offset(
+                &self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                Some(partition_id),
+                offset,
+            )
+            .await?;
+        trace!("Stored offset: {offset} for partition with ID: {partition_id}");
+        Ok(())
+    }
+}
+
+fn is_due(last_time: Option<Instant>, interval: IggyDuration) -> bool {
+    match last_time {
+        Some(last_time) => last_time.elapsed() >= interval.get_duration(),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::messages::MessageState;
+    use bytes::Bytes;
+
+    fn create_message(partition_id: u32, offset: u64) -> ReceivedMessage {
+        ReceivedMessage {
+            partition_id,
+            current_offset: offset,
+            message: PolledMessage::create(
+                offset,
+                MessageState::Available,
+                0,
+                offset as u128,
+                Bytes::from_static(b"test"),
+                0,
+                None,
+            ),
+        }
+    }
+
+    #[test]
+    fn partitions_should_be_polled_in_round_robin_order_skipping_paused_ones() {
+        let mut state = ConsumerState::default();
+        state.assign_partitions(vec![3, 1, 2]);
+        state.positions.insert(2, 10);
+        let partitions = (0..6)
+            .map(|_| state.next_partition().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            partitions.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![1, 2, 3, 1, 2, 3]
+        );
+        assert_eq!(partitions[0].1, PollingStrategy::next());
+        assert_eq!(partitions[1].1, PollingStrategy::offset(10));
+
+        state.paused.insert(2);
+        let partitions = (0..4)
+            .map(|_| state.next_partition().unwrap().0)
+            .collect::<Vec<_>>();
+        assert!(!partitions.contains(&2));
+        assert_eq!(state.active_partitions_count(), 2);
+
+        state.paused.extend([1, 3]);
+        assert!(state.next_partition().is_none());
+    }
+
+    #[test]
+    fn buffered_messages_of_paused_partitions_should_be_held() {
+        let mut state = ConsumerState::default();
+        state.assign_partitions(vec![1, 2]);
+        state.buffer.extend([
+            create_message(1, 0),
+            create_message(2, 0),
+            create_message(1, 1),
+        ]);
+        state.paused.insert(1);
+
+        assert_eq!(state.take_message().unwrap().partition_id, 2);
+        assert!(state.take_message().is_none());
+
+        state.paused.remove(&1);
+        assert_eq!(state.take_message().unwrap().message.offset, 0);
+        assert_eq!(state.take_message().unwrap().message.offset, 1);
+        assert_eq!(state.get_uncommitted_offsets(), vec![(1, 1), (2, 0)]);
+
+        state.committed.insert(1, 1);
+        assert_eq!(state.get_uncommitted_offsets(), vec![(2, 0)]);
+    }
+
+    #[test]
+    fn state_of_revoked_partitions_should_be_removed_returning_their_uncommitted_offsets() {
+        let mut state = ConsumerState::default();
+        state.assign_partitions(vec![1, 2]);
+        state.positions.extend([(1, 5), (2, 7)]);
+        state.consumed.extend([(1, 4), (2, 6)]);
+        state
+            .buffer
+            .extend([create_message(1, 5), create_message(2, 7)]);
+
+        state.committed.insert(2, 6);
+        assert_eq!(state.assign_partitions(vec![2, 3]), vec![(1, 4)]);
+        assert_eq!(state.partitions, vec![2, 3]);
+        assert_eq!(state.positions.get(&1), None);
+        assert_eq!(state.positions.get(&2), Some(&7));
+        assert!(state.get_uncommitted_offsets().is_empty());
+        assert_eq!(state.buffer.len(), 1);
+        assert_eq!(state.buffer[0].partition_id, 2);
+    }
+}
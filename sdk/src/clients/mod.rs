@@ -1,2 +1,6 @@
 pub mod builder;
 pub mod client;
+pub mod consumer;
+pub mod producer;
+pub mod typed_consumer;
+pub mod typed_producer;
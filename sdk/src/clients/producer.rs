@@ -0,0 +1,532 @@
+use crate::client::{MessageClient, StreamClient, TopicClient};
+use crate::clients::client::IggyClient;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{Message, Partitioning, PartitioningKind};
+use crate::utils::duration::IggyDuration;
+use crate::utils::partitioning_scheme::PartitioningScheme;
+use flume::{Receiver, Sender};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::{oneshot, OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, timeout_at, Instant};
+use tracing::{error, warn};
+use uuid::Uuid;
+
+/// The configuration of the `IggyProducer`.
+#[derive(Debug, Clone)]
+pub struct IggyProducerConfig {
+    /// The maximum size in bytes of the batch sent to the single partition. A single message exceeding it is sent alone.
+    pub batch_size_bytes: u32,
+    /// The maximum time the message waits for the batch to fill up before it's sent.
+    pub linger: IggyDuration,
+    /// The maximum size in bytes of the messages which are buffered or being sent. Once reached, `send` waits until some messages are delivered.
+    pub max_in_flight_bytes: u32,
+    /// The maximum number of messages queued for the single partition. Once reached, `send` waits until the partition's batch is taken.
+    pub max_queued_messages: u32,
+    /// The maximum number of retries of the batch which failed to be sent due to the retriable (connection) error.
+    pub max_retries: u32,
+    /// The initial delay before retrying the batch, doubled after each retry.
+    pub retry_backoff: IggyDuration,
+    /// The maximum delay before retrying the batch.
+    pub max_retry_backoff: IggyDuration,
+    /// Whether the delivery report is returned only once the messages are durably stored on disk.
+    pub wait_for_durability: bool,
+}
+
+impl Default for IggyProducerConfig {
+    fn default() -> Self {
+        IggyProducerConfig {
+            batch_size_bytes: 1024 * 1024,
+            linger: IggyDuration::new(Duration::from_millis(5)),
+            max_in_flight_bytes: 64 * 1024 * 1024,
+            max_queued_messages: 10_000,
+            max_retries: 3,
+            retry_backoff: IggyDuration::new(Duration::from_millis(100)),
+            max_retry_backoff: IggyDuration::new(Duration::from_secs(5)),
+            wait_for_durability: false,
+        }
+    }
+}
+
+/// The delivery report of the single message sent by the `IggyProducer`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeliveryReport {
+    /// The identifier of the partition the message was appended to.
+    pub partition_id: u32,
+    /// The offset assigned to the message, or `None` if the message was scheduled for the delayed delivery or ignored as a duplicate.
+    pub offset: Option<u64>,
+}
+
+/// The future resolved with the delivery report once the message is sent, or with the error if it couldn't be delivered.
+#[derive(Debug)]
+pub struct DeliveryFuture {
+    receiver: oneshot::Receiver<Result<DeliveryReport, IggyError>>,
+}
+
+impl Future for DeliveryFuture {
+    type Output = Result<DeliveryReport, IggyError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| result.unwrap_or(Err(IggyError::ProducerClosed)))
+    }
+}
+
+#[derive(Debug)]
+struct PendingMessage {
+    message: Message,
+    /// The partitioning the message is sent with, the messages key is kept so it's stored along with the message.
+    partitioning: Partitioning,
+    size: u32,
+    report: oneshot::Sender<Result<DeliveryReport, IggyError>>,
+    permit: OwnedSemaphorePermit,
+}
+
+#[derive(Debug)]
+struct ProducerContext {
+    client: Arc<IggyClient>,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    config: IggyProducerConfig,
+}
+
+/// Resolves the partition of the messages the same way the server does, using the partitions count and the partitioning
+/// scheme of the topic.
+#[derive(Debug)]
+struct PartitionResolver {
+    stream_id: u32,
+    topic_id: u32,
+    partitions_count: u32,
+    partitioning_scheme: PartitioningScheme,
+    next_partition: AtomicU32,
+}
+
+impl PartitionResolver {
+    fn new(
+        stream_id: u32,
+        topic_id: u32,
+        partitions_count: u32,
+        partitioning_scheme: PartitioningScheme,
+    ) -> Self {
+        PartitionResolver {
+            stream_id,
+            topic_id,
+            partitions_count,
+            partitioning_scheme,
+            next_partition: AtomicU32::new(0),
+        }
+    }
+
+    fn resolve(&self, partitioning: &Partitioning) -> Result<u32, IggyError> {
+        match partitioning.kind {
+            PartitioningKind::Balanced => {
+                Ok(self.next_partition.fetch_add(1, Ordering::Relaxed) % self.partitions_count + 1)
+            }
+            PartitioningKind::PartitionId => {
+                let partition_id = u32::from_le_bytes(
+                    partitioning.value[..]
+                        .try_into()
+                        .map_err(|_| IggyError::InvalidCommand)?,
+                );
+                if partition_id == 0 || partition_id > self.partitions_count {
+                    return Err(IggyError::PartitionNotFound(
+                        partition_id,
+                        self.topic_id,
+                        self.stream_id,
+                    ));
+                }
+                Ok(partition_id)
+            }
+            PartitioningKind::MessagesKey => Ok(self
+                .partitioning_scheme
+                .calculate_partition_id(&partitioning.value, self.partitions_count)),
+        }
+    }
+}
+
+/// The workers of the topic's partitions, resolved once the first message is sent.
+#[derive(Debug)]
+struct PartitionWorkers {
+    resolver: PartitionResolver,
+    /// The queue of the worker for each partition, ordered by the partition ID.
+    senders: Vec<Sender<PendingMessage>>,
+}
+
+/// `IggyProducer` sends the messages to the topic in the background, batching them per partition.
+///
+/// The partition of each message is resolved by the producer (the round-robin for the balanced partitioning, and the
+/// partitioning scheme of the topic for the messages key), using the partitions count fetched once the first message is sent,
+/// so the partitions added later are not used until the producer is recreated. Each partition has a single worker, which
+/// accumulates the messages until either the batch reaches the configured size in bytes, or the linger time elapses.
+/// The messages sent with the messages key are batched only with the ones having the same key, which is stored with them.
+/// The batches of the partition are sent one at a time, which preserves the ordering of the messages, and the ones which
+/// failed due to the connection errors are retried with the exponential backoff. The messages without the ID get the random
+/// one assigned, so the retried batches can be deduplicated by the server, if the deduplication is enabled for the topic.
+///
+/// The memory used by the buffered messages is bounded by `max_in_flight_bytes`, and the queue of each partition by
+/// `max_queued_messages`, once reached `send` waits until the previous messages are delivered. Each sent message returns
+/// the `DeliveryFuture` resolved with its partition ID and offset.
+#[derive(Debug)]
+pub struct IggyProducer {
+    context: Arc<ProducerContext>,
+    in_flight: Arc<Semaphore>,
+    workers: tokio::sync::Mutex<Option<Arc<PartitionWorkers>>>,
+    handles: Mutex<Vec<JoinHandle<()>>>,
+    closed: AtomicBool,
+}
+
+impl IggyProducer {
+    /// Creates a new `IggyProducer` for the given stream and topic by unique IDs or names.
+    pub fn new(
+        client: Arc<IggyClient>,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        config: IggyProducerConfig,
+    ) -> Self {
+        let in_flight = Arc::new(Semaphore::new(config.max_in_flight_bytes as usize));
+        IggyProducer {
+            context: Arc::new(ProducerContext {
+                client,
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                config,
+            }),
+            in_flight,
+            workers: tokio::sync::Mutex::new(None),
+            handles: Mutex::new(Vec::new()),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the configuration of the producer.
+    pub fn config(&self) -> &IggyProducerConfig {
+        &self.context.config
+    }
+
+    /// Enqueues the message to be sent using the provided partitioning, waiting if the in-flight bytes limit is reached,
+    /// or the queue of the partition is full. Returns the future resolved with the delivery report once the message is sent.
+    pub async fn send(
+        &self,
+        partitioning: &Partitioning,
+        mut message: Message,
+    ) -> Result<DeliveryFuture, IggyError> {
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(IggyError::ProducerClosed);
+        }
+
+        if message.id == 0 {
+            message.id = Uuid::new_v4().to_u128_le();
+        }
+
+        let workers = self.get_workers().await?;
+        let partition_id = workers.resolver.resolve(partitioning)?;
+        // The server resolves the same partition for the messages key, so it's sent as is to keep the key.
+        let partitioning = match partitioning.kind {
+            PartitioningKind::MessagesKey => partitioning.clone(),
+            _ => Partitioning::partition_id(partition_id),
+        };
+        let size = message.get_size_bytes();
+        let permit = self
+            .in_flight
+            .clone()
+            .acquire_many_owned(size.min(self.context.config.max_in_flight_bytes))
+            .await
+            .map_err(|_| IggyError::ProducerClosed)?;
+        let (sender, receiver) = oneshot::channel();
+        let pending_message = PendingMessage {
+            message,
+            partitioning,
+            size,
+            report: sender,
+            permit,
+        };
+        workers.senders[partition_id as usize - 1]
+            .send_async(pending_message)
+            .await
+            .map_err(|_| IggyError::ProducerClosed)?;
+        Ok(DeliveryFuture { receiver })
+    }
+
+    /// Waits until all the messages enqueued so far are delivered (either successfully or not).
+    pub async fn flush(&self) -> Result<(), IggyError> {
+        let _permit = self
+            .in_flight
+            .acquire_many(self.context.config.max_in_flight_bytes)
+            .await
+            .map_err(|_| IggyError::ProducerClosed)?;
+        Ok(())
+    }
+
+    /// Stops accepting the new messages, and waits until the already enqueued ones are delivered.
+    pub async fn close(&self) {
+        if self.closed.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        self.workers.lock().await.take();
+        let handles = std::mem::take(&mut *self.handles.lock().unwrap());
+        for handle in handles {
+            if let Err(error) = handle.await {
+                error!("Producer worker failed: {error}");
+            }
+        }
+    }
+
+    /// Returns the workers of the topic's partitions, spawning them once the topic is fetched for the first message.
+    async fn get_workers(&self) -> Result<Arc<PartitionWorkers>, IggyError> {
+        let mut workers = self.workers.lock().await;
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(IggyError::ProducerClosed);
+        }
+
+        if let Some(workers) = workers.as_ref() {
+            return Ok(workers.clone());
+        }
+
+        let context = &self.context;
+        let stream = context.client.get_stream(&context.stream_id).await?;
+        let topic = context
+            .client
+            .get_topic(&context.stream_id, &context.topic_id)
+            .await?;
+        if topic.partitions_count == 0 {
+            return Err(IggyError::NoPartitions(topic.id, stream.id));
+        }
+
+        let mut senders = Vec::with_capacity(topic.partitions_count as usize);
+        let mut handles = self.handles.lock().unwrap();
+        for _ in 0..topic.partitions_count {
+            let (sender, receiver) = flume::bounded(context.config.max_queued_messages as usize);
+            handles.push(tokio::spawn(run_worker(context.clone(), receiver)));
+            senders.push(sender);
+        }
+
+        let partition_workers = Arc::new(PartitionWorkers {
+            resolver: PartitionResolver::new(
+                stream.id,
+                topic.id,
+                topic.partitions_count,
+                topic.partitioning_scheme,
+            ),
+            senders,
+        });
+        *workers = Some(partition_workers.clone());
+        Ok(partition_workers)
+    }
+}
+
+async fn run_worker(context: Arc<ProducerContext>, receiver: Receiver<PendingMessage>) {
+    let batch_size_bytes = context.config.batch_size_bytes;
+    let mut next_message = None;
+    loop {
+        let first_message = match next_message.take() {
+            Some(message) => message,
+            None => match receiver.recv_async().await {
+                Ok(message) => message,
+                Err(_) => break,
+            },
+        };
+
+        // The batch is sent with a single partitioning, so the messages with the different keys are sent separately.
+        let partitioning = first_message.partitioning.clone();
+        let deadline = Instant::now() + context.config.linger.get_duration();
+        let mut batch_size = first_message.size;
+        let mut batch = vec![first_message];
+        while batch_size < batch_size_bytes {
+            match timeout_at(deadline, receiver.recv_async()).await {
+                Ok(Ok(message)) => {
+                    if batch_size + message.size > batch_size_bytes
+                        || message.partitioning != partitioning
+                    {
+                        next_message = Some(message);
+                        break;
+                    }
+                    batch_size += message.size;
+                    batch.push(message);
+                }
+                Ok(Err(_)) | Err(_) => break,
+            }
+        }
+
+        send_batch(&context, &partitioning, batch).await;
+    }
+}
+
+async fn send_batch(
+    context: &ProducerContext,
+    partitioning: &Partitioning,
+    batch: Vec<PendingMessage>,
+) {
+    let (mut messages, reports): (Vec<_>, Vec<_>) = batch
+        .into_iter()
+        .map(|pending_message| {
+            (
+                pending_message.message,
+                (pending_message.report, pending_message.permit),
+            )
+        })
+        .unzip();
+
+    let config = &context.config;
+    let mut retries = 0;
+    let mut backoff = config.retry_backoff.get_duration();
+    let result = loop {
+        match context
+            .client
+            .send_messages_with_offsets(
+                &context.stream_id,
+                &context.topic_id,
+                partitioning,
+                &mut messages,
+                config.wait_for_durability,
+            )
+            .await
+        {
+            Ok(appended_messages) => break Ok(appended_messages),
+            Err(error) if retries < config.max_retries && is_retriable(&error) => {
+                retries += 1;
+                warn!(
+                    "Failed to send {} messages, retrying ({retries}/{}) in {} ms: {error}",
+                    messages.len(),
+                    config.max_retries,
+                    backoff.as_millis()
+                );
+                sleep(backoff).await;
+                backoff = (backoff * 2).min(config.max_retry_backoff.get_duration());
+            }
+            Err(error) => break Err(error),
+        }
+    };
+
+    match result {
+        Ok(appended_messages) => {
+            for (index, (report, _permit)) in reports.into_iter().enumerate() {
+                let _ = report.send(Ok(DeliveryReport {
+                    partition_id: appended_messages.partition_id,
+                    offset: appended_messages.get_offset(index as u32),
+                }));
+            }
+        }
+        Err(error) => {
+            error!("Failed to send {} messages: {error}", messages.len());
+            let code = error.as_code();
+            let reason = error.to_string();
+            for (report, _permit) in reports {
+                let _ = report.send(Err(IggyError::CannotDeliverMessages(code, reason.clone())));
+            }
+        }
+    }
+}
+
+fn is_retriable(error: &IggyError) -> bool {
+    matches!(
+        error,
+        IggyError::NotConnected
+            | IggyError::ConnectionClosed
+            | IggyError::IoError(_)
+            | IggyError::WriteError(_)
+            | IggyError::ReadError(_)
+            | IggyError::ReadToEndError(_)
+            | IggyError::ConnectionError(_)
+            | IggyError::ClosedError(_)
+            | IggyError::RequestError(_)
+            | IggyError::EmptyResponse
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn create_producer(config: IggyProducerConfig) -> IggyProducer {
+        IggyProducer::new(
+            Arc::new(IggyClient::default()),
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(2).unwrap(),
+            config,
+        )
+    }
+
+    #[test]
+    fn only_connection_errors_should_be_retriable() {
+        assert!(is_retriable(&IggyError::NotConnected));
+        assert!(is_retriable(&IggyError::ConnectionClosed));
+        assert!(!is_retriable(&IggyError::Unauthenticated));
+        assert!(!is_retriable(&IggyError::InvalidMessagesCount));
+    }
+
+    #[test]
+    fn balanced_partitioning_should_use_partitions_in_turn() {
+        let resolver = PartitionResolver::new(1, 2, 3, PartitioningScheme::default());
+        let partition_ids = (0..6)
+            .map(|_| resolver.resolve(&Partitioning::balanced()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(partition_ids, vec![1, 2, 3, 1, 2, 3]);
+    }
+
+    #[test]
+    fn messages_key_should_be_resolved_using_partitioning_scheme() {
+        for partitioning_scheme in [PartitioningScheme::Modulo, PartitioningScheme::JumpHash] {
+            let resolver = PartitionResolver::new(1, 2, 10, partitioning_scheme);
+            for key in ["a", "b", "order-1", "order-2"] {
+                assert_eq!(
+                    resolver
+                        .resolve(&Partitioning::messages_key_str(key).unwrap())
+                        .unwrap(),
+                    partitioning_scheme.calculate_partition_id(key.as_bytes(), 10)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_partition_id_should_be_rejected() {
+        let resolver = PartitionResolver::new(1, 2, 3, PartitioningScheme::default());
+        assert_eq!(resolver.resolve(&Partitioning::partition_id(3)).unwrap(), 3);
+        assert!(matches!(
+            resolver.resolve(&Partitioning::partition_id(0)),
+            Err(IggyError::PartitionNotFound(0, 2, 1))
+        ));
+        assert!(matches!(
+            resolver.resolve(&Partitioning::partition_id(4)),
+            Err(IggyError::PartitionNotFound(4, 2, 1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn send_should_fail_when_topic_cannot_be_fetched() {
+        let producer = create_producer(IggyProducerConfig::default());
+        assert!(producer
+            .send(
+                &Partitioning::partition_id(1),
+                Message::from_str("test").unwrap(),
+            )
+            .await
+            .is_err());
+        producer.flush().await.unwrap();
+        producer.close().await;
+    }
+
+    #[tokio::test]
+    async fn closed_producer_should_not_accept_messages() {
+        let producer = create_producer(IggyProducerConfig::default());
+        producer.close().await;
+        assert!(matches!(
+            producer
+                .send(
+                    &Partitioning::balanced(),
+                    Message::from_str("test").unwrap()
+                )
+                .await,
+            Err(IggyError::ProducerClosed)
+        ));
+    }
+}
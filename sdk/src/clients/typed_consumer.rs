@@ -0,0 +1,297 @@
+use crate::client::{ConsumerOffsetClient, MessageClient};
+use crate::clients::client::IggyClient;
+use crate::codecs::{extract_content_type, Codec};
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::{PollingKind, PollingStrategy};
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::messages::PolledMessage;
+use crate::schemas::schema_id::extract_schema_id;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use tracing::warn;
+
+const DEFAULT_BATCH_SIZE: u32 = 100;
+
+/// The trait represents the logic responsible for handling the messages which couldn't be decoded by the `TypedConsumer`.
+/// Returning an error stops processing the polled batch and propagates the error to the caller, while returning `Ok` skips the message.
+pub trait DecodeErrorHandler: Send + Sync + Debug {
+    fn handle(&self, message: &PolledMessage, error: IggyError) -> Result<(), IggyError>;
+}
+
+/// The default `DecodeErrorHandler` which logs the error and skips the message.
+#[derive(Debug, Default)]
+pub struct SkipDecodeErrors;
+
+impl DecodeErrorHandler for SkipDecodeErrors {
+    fn handle(&self, message: &PolledMessage, error: IggyError) -> Result<(), IggyError> {
+        warn!(
+            "Skipping the message with ID: {} at offset: {} which couldn't be decoded: {error}",
+            message.id, message.offset
+        );
+        Ok(())
+    }
+}
+
+/// The `DecodeErrorHandler` which fails the whole poll when any of the messages couldn't be decoded.
+#[derive(Debug, Default)]
+pub struct FailOnDecodeErrors;
+
+impl DecodeErrorHandler for FailOnDecodeErrors {
+    fn handle(&self, _message: &PolledMessage, error: IggyError) -> Result<(), IggyError> {
+        Err(error)
+    }
+}
+
+/// The message polled by the `TypedConsumer`, with the payload decoded into the value of type `T`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedMessage<T> {
+    /// The identifier of the partition the message was polled from.
+    pub partition_id: u32,
+    /// The offset of the message.
+    pub offset: u64,
+    /// The timestamp of the message.
+    pub timestamp: u64,
+    /// The identifier of the message.
+    pub id: u128,
+    /// The ID of the schema the payload was encoded with, taken from the `iggy-schema-id` header.
+    pub schema_id: Option<u32>,
+    /// The optional headers of the message.
+    pub headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    /// The decoded payload of the message.
+    pub value: T,
+}
+
+/// `TypedConsumer` polls the messages from the topic and decodes their payloads into the values of type `T` with the provided codec.
+///
+/// The messages having the `iggy-content-type` header different from the codec content type are treated as the decode errors,
+/// which are routed to the configured `DecodeErrorHandler` (by default, they are logged and skipped).
+/// By default, the next available messages are polled with the auto commit enabled, and the partition is assigned by the server (consumer group).
+#[derive(Debug)]
+pub struct TypedConsumer<T, C: Codec<T>> {
+    client: Arc<IggyClient>,
+    consumer: Consumer,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: Option<u32>,
+    strategy: PollingStrategy,
+    batch_size: u32,
+    auto_commit: bool,
+    codec: C,
+    decode_error_handler: Box<dyn DecodeErrorHandler>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, C: Codec<T>> TypedConsumer<T, C> {
+    /// Creates a new `TypedConsumer` for the given consumer, stream and topic by unique IDs or names.
+    pub fn new(
+        client: Arc<IggyClient>,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        codec: C,
+    ) -> Self {
+        TypedConsumer {
+            client,
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id: None,
+            strategy: PollingStrategy::next(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            auto_commit: true,
+            codec,
+            decode_error_handler: Box::new(SkipDecodeErrors),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets the partition ID to poll the messages from. It's required for the single consumer, and ignored for the consumer group.
+    pub fn with_partition_id(mut self, partition_id: u32) -> Self {
+        self.partition_id = Some(partition_id);
+        self
+    }
+
+    /// Sets the polling strategy. In case of the offset strategy, the offset is advanced after each poll.
+    pub fn with_polling_strategy(mut self, strategy: PollingStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the maximum number of messages polled at once.
+    pub fn with_batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Sets whether the offset should be stored on the server when the messages are polled.
+    /// If disabled, the offset can be stored manually using the `store_offset` method.
+    pub fn with_auto_commit(mut self, auto_commit: bool) -> Self {
+        self.auto_commit = auto_commit;
+        self
+    }
+
+    /// Sets the custom handler of the messages which couldn't be decoded.
+    pub fn with_decode_error_handler(
+        mut self,
+        decode_error_handler: Box<dyn DecodeErrorHandler>,
+    ) -> Self {
+        self.decode_error_handler = decode_error_handler;
+        self
+    }
+
+    /// Returns the codec used to decode the values.
+    pub fn codec(&self) -> &C {
+        &self.codec
+    }
+
+    /// Polls the next batch of messages and decodes their payloads.
+    /// The messages which couldn't be decoded are routed to the decode error handler.
+    pub async fn poll(&mut self) -> Result<Vec<TypedMessage<T>>, IggyError> {
+        let polled_messages = self
+            .client
+            .poll_messages(
+                &self.stream_id,
+                &self.topic_id,
+                self.partition_id,
+                &self.consumer,
+                &self.strategy,
+                self.batch_size,
+                self.auto_commit,
+            )
+            .await?;
+
+        let partition_id = polled_messages.partition_id;
+        let mut messages = Vec::with_capacity(polled_messages.messages.len());
+        for message in polled_messages.messages {
+            if self.strategy.kind == PollingKind::Offset {
+                self.strategy.value = message.offset + 1;
+            }
+            match self.decode(partition_id, &message) {
+                Ok(message) => messages.push(message),
+                Err(error) => self.decode_error_handler.handle(&message, error)?,
+            }
+        }
+        Ok(messages)
+    }
+
+    /// Stores the offset of the processed message on the server, used when the auto commit is disabled.
+    pub async fn store_offset(&self, offset: u64) -> Result<(), IggyError> {
+        self.client
+            .store_consumer_offset(
+                &self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                self.partition_id,
+                offset,
+            )
+            .await
+    }
+
+    /// Decodes the payload of the polled message, validating its content type header (if present).
+    pub fn decode(
+        &self,
+        partition_id: u32,
+        message: &PolledMessage,
+    ) -> Result<TypedMessage<T>, IggyError> {
+        let expected_content_type = self.codec.content_type();
+        if let Some(content_type) = extract_content_type(&message.headers)? {
+            if content_type != expected_content_type {
+                return Err(IggyError::InvalidMessageContentType(
+                    content_type.to_string(),
+                    expected_content_type.to_string(),
+                ));
+            }
+        }
+
+        Ok(TypedMessage {
+            partition_id,
+            offset: message.offset,
+            timestamp: message.timestamp,
+            id: message.id,
+            schema_id: extract_schema_id(&message.headers)?,
+            headers: message.headers.clone(),
+            value: self.codec.decode(&message.payload)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codecs::inject_content_type;
+    use crate::codecs::json_codec::JsonCodec;
+    use crate::codecs::msgpack_codec::MSGPACK_CONTENT_TYPE;
+    use crate::models::messages::MessageState;
+    use bytes::Bytes;
+
+    fn create_consumer() -> TypedConsumer<Vec<u32>, JsonCodec> {
+        TypedConsumer::new(
+            Arc::new(IggyClient::default()),
+            &Consumer::default(),
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(2).unwrap(),
+            JsonCodec,
+        )
+    }
+
+    fn create_message(payload: &'static [u8], content_type: Option<&str>) -> PolledMessage {
+        let mut headers = None;
+        if let Some(content_type) = content_type {
+            inject_content_type(&mut headers, content_type).unwrap();
+        }
+        PolledMessage::create(
+            5,
+            MessageState::Available,
+            10,
+            1,
+            Bytes::from_static(payload),
+            0,
+            headers,
+        )
+    }
+
+    #[test]
+    fn message_should_be_decoded() {
+        let consumer = create_consumer();
+        let message = create_message(b"[1,2,3]", Some("application/json"));
+        let message = consumer.decode(3, &message).unwrap();
+        assert_eq!(message.partition_id, 3);
+        assert_eq!(message.offset, 5);
+        assert_eq!(message.value, vec![1, 2, 3]);
+        assert_eq!(message.schema_id, None);
+
+        let message = create_message(b"[4]", None);
+        assert_eq!(consumer.decode(3, &message).unwrap().value, vec![4]);
+    }
+
+    #[test]
+    fn message_with_different_content_type_should_not_be_decoded() {
+        let consumer = create_consumer();
+        let message = create_message(b"[1,2,3]", Some(MSGPACK_CONTENT_TYPE));
+        assert!(matches!(
+            consumer.decode(3, &message),
+            Err(IggyError::InvalidMessageContentType(_, _))
+        ));
+    }
+
+    #[test]
+    fn decode_errors_should_be_skipped_or_propagated_by_handlers() {
+        let message = create_message(b"invalid", None);
+        assert!(SkipDecodeErrors
+            .handle(
+                &message,
+                IggyError::CannotDecodeMessagePayload("test".into())
+            )
+            .is_ok());
+        assert!(FailOnDecodeErrors
+            .handle(
+                &message,
+                IggyError::CannotDecodeMessagePayload("test".into())
+            )
+            .is_err());
+    }
+}
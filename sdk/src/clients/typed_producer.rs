@@ -0,0 +1,126 @@
+use crate::client::MessageClient;
+use crate::clients::client::IggyClient;
+use crate::codecs::{inject_content_type, Codec};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::schemas::schema_id::inject_schema_id;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// `TypedProducer` sends the values of type `T` to the topic, encoding them into the message payloads with the provided codec.
+///
+/// The `iggy-content-type` header is set automatically for each message, as well as the `iggy-schema-id` one, if the schema ID is configured.
+/// By default, the messages are distributed across the partitions using the balanced partitioning.
+#[derive(Debug)]
+pub struct TypedProducer<T, C: Codec<T>> {
+    client: Arc<IggyClient>,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partitioning: Partitioning,
+    codec: C,
+    schema_id: Option<u32>,
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<T, C: Codec<T>> TypedProducer<T, C> {
+    /// Creates a new `TypedProducer` for the given stream and topic by unique IDs or names.
+    pub fn new(
+        client: Arc<IggyClient>,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        codec: C,
+    ) -> Self {
+        TypedProducer {
+            client,
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partitioning: Partitioning::balanced(),
+            codec,
+            schema_id: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets the default partitioning used when sending the messages.
+    pub fn with_partitioning(mut self, partitioning: Partitioning) -> Self {
+        self.partitioning = partitioning;
+        self
+    }
+
+    /// Sets the ID of the registered schema the values are encoded with, which will be stored in the `iggy-schema-id` header.
+    pub fn with_schema_id(mut self, schema_id: u32) -> Self {
+        self.schema_id = Some(schema_id);
+        self
+    }
+
+    /// Returns the codec used to encode the values.
+    pub fn codec(&self) -> &C {
+        &self.codec
+    }
+
+    /// Encodes the single value and sends it using the default partitioning.
+    pub async fn send_one(&self, value: &T) -> Result<(), IggyError> {
+        self.send_with_partitioning(std::slice::from_ref(value), &self.partitioning)
+            .await
+    }
+
+    /// Encodes the values and sends them as a single batch using the default partitioning.
+    pub async fn send(&self, values: &[T]) -> Result<(), IggyError> {
+        self.send_with_partitioning(values, &self.partitioning)
+            .await
+    }
+
+    /// Encodes the values and sends them as a single batch using the provided partitioning.
+    pub async fn send_with_partitioning(
+        &self,
+        values: &[T],
+        partitioning: &Partitioning,
+    ) -> Result<(), IggyError> {
+        let mut messages = values
+            .iter()
+            .map(|value| self.encode(value))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.client
+            .send_messages(&self.stream_id, &self.topic_id, partitioning, &mut messages)
+            .await
+    }
+
+    /// Encodes the value into the message with the content type and schema headers set.
+    pub fn encode(&self, value: &T) -> Result<Message, IggyError> {
+        let payload = self.codec.encode(value)?;
+        let mut headers = None;
+        inject_content_type(&mut headers, self.codec.content_type())?;
+        if let Some(schema_id) = self.schema_id {
+            inject_schema_id(&mut headers, schema_id)?;
+        }
+        Ok(Message::new(None, payload, headers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codecs::extract_content_type;
+    use crate::codecs::json_codec::{JsonCodec, JSON_CONTENT_TYPE};
+    use crate::schemas::schema_id::extract_schema_id;
+
+    #[test]
+    fn value_should_be_encoded_with_content_type_and_schema_headers() {
+        let producer = TypedProducer::<Vec<u32>, _>::new(
+            Arc::new(IggyClient::default()),
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(2).unwrap(),
+            JsonCodec,
+        )
+        .with_schema_id(3);
+
+        let message = producer.encode(&vec![1, 2, 3]).unwrap();
+        assert_eq!(message.payload.as_ref(), b"[1,2,3]");
+        assert_eq!(
+            extract_content_type(&message.headers).unwrap(),
+            Some(JSON_CONTENT_TYPE)
+        );
+        assert_eq!(extract_schema_id(&message.headers).unwrap(), Some(3));
+    }
+}
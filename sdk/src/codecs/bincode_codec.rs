@@ -0,0 +1,67 @@
+use crate::codecs::Codec;
+use crate::error::IggyError;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The content type of the payloads encoded by the `BincodeCodec`.
+pub const BINCODE_CONTENT_TYPE: &str = "application/x-bincode";
+
+/// `BincodeCodec` encodes the values implementing `Serialize` and `DeserializeOwned` traits using the compact bincode format.
+/// The format isn't self-describing, thus both the producer and the consumer must share the exact same type definition.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct BincodeCodec;
+
+impl<T: Serialize + DeserializeOwned> Codec<T> for BincodeCodec {
+    fn content_type(&self) -> &'static str {
+        BINCODE_CONTENT_TYPE
+    }
+
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError> {
+        bincode::serialize(value)
+            .map(Bytes::from)
+            .map_err(|error| IggyError::CannotEncodeMessagePayload(error.to_string()))
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError> {
+        bincode::deserialize(payload)
+            .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u64,
+        side: String,
+    }
+
+    #[test]
+    fn value_should_be_encoded_and_decoded() {
+        let order = Order {
+            id: 1,
+            side: "buy".to_string(),
+        };
+        let payload = BincodeCodec.encode(&order).unwrap();
+        let decoded: Order = BincodeCodec.decode(&payload).unwrap();
+        assert_eq!(decoded, order);
+    }
+
+    #[test]
+    fn truncated_payload_should_not_be_decoded() {
+        let order = Order {
+            id: 1,
+            side: "buy".to_string(),
+        };
+        let payload = BincodeCodec.encode(&order).unwrap();
+        let result: Result<Order, IggyError> = BincodeCodec.decode(&payload[..payload.len() - 1]);
+        assert!(matches!(
+            result,
+            Err(IggyError::CannotDecodeMessagePayload(_))
+        ));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::codecs::Codec;
+use crate::error::IggyError;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The content type of the payloads encoded by the `JsonCodec`.
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// `JsonCodec` encodes the values implementing `Serialize` and `DeserializeOwned` traits as JSON.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct JsonCodec;
+
+impl<T: Serialize + DeserializeOwned> Codec<T> for JsonCodec {
+    fn content_type(&self) -> &'static str {
+        JSON_CONTENT_TYPE
+    }
+
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError> {
+        serde_json::to_vec(value)
+            .map(Bytes::from)
+            .map_err(|error| IggyError::CannotEncodeMessagePayload(error.to_string()))
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError> {
+        serde_json::from_slice(payload)
+            .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u64,
+        side: String,
+    }
+
+    #[test]
+    fn value_should_be_encoded_and_decoded() {
+        let order = Order {
+            id: 1,
+            side: "buy".to_string(),
+        };
+        let payload = JsonCodec.encode(&order).unwrap();
+        assert_eq!(payload, Bytes::from(r#"{"id":1,"side":"buy"}"#));
+        let decoded: Order = JsonCodec.decode(&payload).unwrap();
+        assert_eq!(decoded, order);
+    }
+
+    #[test]
+    fn invalid_payload_should_not_be_decoded() {
+        let result: Result<Order, IggyError> = JsonCodec.decode(br#"{"id":"1"}"#);
+        assert!(matches!(
+            result,
+            Err(IggyError::CannotDecodeMessagePayload(_))
+        ));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+pub mod bincode_codec;
+pub mod json_codec;
+pub mod msgpack_codec;
+#[cfg(feature = "protobuf")]
+pub mod protobuf_codec;
+
+/// The name of the reserved message header holding the content type of the payload, set by the typed producer.
+pub const CONTENT_TYPE_HEADER: &str = "iggy-content-type";
+
+/// The trait represents the logic responsible for encoding the values into the message payloads and decoding them back.
+/// It is used by the `TypedProducer` and `TypedConsumer`, and can be implemented to support the custom serialization formats.
+pub trait Codec<T>: Send + Sync + Debug {
+    /// The content type of the encoded payload, stored in the `iggy-content-type` header.
+    fn content_type(&self) -> &'static str;
+    /// Encode the value into the message payload.
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError>;
+    /// Decode the message payload into the value.
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError>;
+}
+
+/// Inject the content type into the headers, replacing the existing one if present.
+pub fn inject_content_type(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    content_type: &str,
+) -> Result<(), IggyError> {
+    headers.get_or_insert_with(HashMap::new).insert(
+        HeaderKey::new(CONTENT_TYPE_HEADER)?,
+        HeaderValue::from_str(content_type)?,
+    );
+    Ok(())
+}
+
+/// Extract the content type from the headers, if the content type header is present.
+pub fn extract_content_type(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<&str>, IggyError> {
+    let Some(headers) = headers.as_ref() else {
+        return Ok(None);
+    };
+
+    match headers.get(&HeaderKey::new(CONTENT_TYPE_HEADER)?) {
+        Some(value) => Ok(Some(value.as_str()?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_type_should_be_injected_and_extracted_from_headers() {
+        let mut headers = None;
+        inject_content_type(&mut headers, "text/plain").unwrap();
+        inject_content_type(&mut headers, "application/json").unwrap();
+        assert_eq!(headers.as_ref().unwrap().len(), 1);
+        assert_eq!(
+            extract_content_type(&headers).unwrap(),
+            Some("application/json")
+        );
+        assert_eq!(extract_content_type(&None).unwrap(), None);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::codecs::Codec;
+use crate::error::IggyError;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The content type of the payloads encoded by the `MessagePackCodec`.
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+
+/// `MessagePackCodec` encodes the values implementing `Serialize` and `DeserializeOwned` traits as MessagePack.
+/// The structs are encoded as maps with the named fields, so that adding the optional fields doesn't break the existing consumers.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct MessagePackCodec;
+
+impl<T: Serialize + DeserializeOwned> Codec<T> for MessagePackCodec {
+    fn content_type(&self) -> &'static str {
+        MSGPACK_CONTENT_TYPE
+    }
+
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError> {
+        rmp_serde::to_vec_named(value)
+            .map(Bytes::from)
+            .map_err(|error| IggyError::CannotEncodeMessagePayload(error.to_string()))
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError> {
+        rmp_serde::from_slice(payload)
+            .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u64,
+        side: String,
+    }
+
+    #[test]
+    fn value_should_be_encoded_and_decoded() {
+        let order = Order {
+            id: 1,
+            side: "buy".to_string(),
+        };
+        let payload = MessagePackCodec.encode(&order).unwrap();
+        let decoded: Order = MessagePackCodec.decode(&payload).unwrap();
+        assert_eq!(decoded, order);
+    }
+
+    #[test]
+    fn invalid_payload_should_not_be_decoded() {
+        let result: Result<Order, IggyError> = MessagePackCodec.decode(&[0xc1]);
+        assert!(matches!(
+            result,
+            Err(IggyError::CannotDecodeMessagePayload(_))
+        ));
+    }
+}
@@ -0,0 +1,58 @@
+use crate::codecs::Codec;
+use crate::error::IggyError;
+use bytes::Bytes;
+
+/// The content type of the payloads encoded by the `ProtobufCodec`.
+pub const PROTOBUF_CONTENT_TYPE: &str = "application/x-protobuf";
+
+/// `ProtobufCodec` encodes the messages generated by `prost` (or deriving `prost::Message`) using the Protocol Buffers wire format.
+/// It is available with the `protobuf` feature (also enabled by the `grpc` one).
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ProtobufCodec;
+
+impl<T: prost::Message + Default> Codec<T> for ProtobufCodec {
+    fn content_type(&self) -> &'static str {
+        PROTOBUF_CONTENT_TYPE
+    }
+
+    fn encode(&self, value: &T) -> Result<Bytes, IggyError> {
+        Ok(Bytes::from(value.encode_to_vec()))
+    }
+
+    fn decode(&self, payload: &[u8]) -> Result<T, IggyError> {
+        T::decode(payload).map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Order {
+        #[prost(uint64, tag = "1")]
+        id: u64,
+        #[prost(string, tag = "2")]
+        side: String,
+    }
+
+    #[test]
+    fn value_should_be_encoded_and_decoded() {
+        let order = Order {
+            id: 1,
+            side: "buy".to_string(),
+        };
+        let payload = ProtobufCodec.encode(&order).unwrap();
+        let decoded: Order = ProtobufCodec.decode(&payload).unwrap();
+        assert_eq!(decoded, order);
+    }
+
+    #[test]
+    fn invalid_payload_should_not_be_decoded() {
+        let result: Result<Order, IggyError> = ProtobufCodec.decode(&[0x12, 0x05, b'b']);
+        assert!(matches!(
+            result,
+            Err(IggyError::CannotDecodeMessagePayload(_))
+        ));
+    }
+}
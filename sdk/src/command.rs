@@ -5,17 +5,24 @@ use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
-use crate::messages::poll_messages::PollMessages;
-use crate::messages::send_messages::SendMessages;
+use crate::messages::poll_messages::{PollMessages, PollMessagesVersion};
+use crate::messages::send_messages::{SendMessages, SendMessagesVersion};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::truncate_partition::TruncatePartition;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
@@ -29,7 +36,7 @@ use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
-use crate::topics::get_topic::GetTopic;
+use crate::topics::get_topic::{GetTopic, TopicLayoutVersion};
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
 use crate::topics::update_topic::UpdateTopic;
@@ -84,12 +91,26 @@ pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.login"
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE: u32 = 44;
 pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
+pub const POLL_MESSAGES_V2: &str = "message.poll.v2";
+pub const POLL_MESSAGES_V2_CODE: u32 = 105;
+pub const POLL_MESSAGES_V3: &str = "message.poll.v3";
+pub const POLL_MESSAGES_V3_CODE: u32 = 106;
 pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
+pub const SEND_DURABLE_MESSAGES: &str = "message.send_durable";
+pub const SEND_DURABLE_MESSAGES_CODE: u32 = 102;
+pub const SEND_MESSAGES_V2: &str = "message.send.v2";
+pub const SEND_MESSAGES_V2_CODE: u32 = 103;
+pub const SEND_DURABLE_MESSAGES_V2: &str = "message.send_durable.v2";
+pub const SEND_DURABLE_MESSAGES_V2_CODE: u32 = 104;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
 pub const STORE_CONSUMER_OFFSET_CODE: u32 = 121;
+pub const GET_CONSUMER_LAG: &str = "consumer_offset.lag";
+pub const GET_CONSUMER_LAG_CODE: u32 = 122;
+pub const RESET_CONSUMER_OFFSETS: &str = "consumer_offset.reset";
+pub const RESET_CONSUMER_OFFSETS_CODE: u32 = 123;
 pub const GET_STREAM: &str = "stream.get";
 pub const GET_STREAM_CODE: u32 = 200;
 pub const GET_STREAMS: &str = "stream.list";
@@ -102,6 +123,8 @@ pub const UPDATE_STREAM: &str = "stream.update";
 pub const UPDATE_STREAM_CODE: u32 = 204;
 pub const PURGE_STREAM: &str = "stream.purge";
 pub const PURGE_STREAM_CODE: u32 = 205;
+pub const GET_STREAM_V2: &str = "stream.get.v2";
+pub const GET_STREAM_V2_CODE: u32 = 206;
 pub const GET_TOPIC: &str = "topic.get";
 pub const GET_TOPIC_CODE: u32 = 300;
 pub const GET_TOPICS: &str = "topic.list";
@@ -114,10 +137,18 @@ pub const UPDATE_TOPIC: &str = "topic.update";
 pub const UPDATE_TOPIC_CODE: u32 = 304;
 pub const PURGE_TOPIC: &str = "topic.purge";
 pub const PURGE_TOPIC_CODE: u32 = 305;
+pub const GET_TOPIC_V2: &str = "topic.get.v2";
+pub const GET_TOPIC_V2_CODE: u32 = 306;
+pub const GET_TOPICS_V2: &str = "topic.list.v2";
+pub const GET_TOPICS_V2_CODE: u32 = 307;
 pub const CREATE_PARTITIONS: &str = "partition.create";
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
 pub const DELETE_PARTITIONS: &str = "partition.delete";
 pub const DELETE_PARTITIONS_CODE: u32 = 403;
+pub const GET_OFFSETS_FOR_TIMESTAMP: &str = "partition.offsets_for_timestamp";
+pub const GET_OFFSETS_FOR_TIMESTAMP_CODE: u32 = 404;
+pub const TRUNCATE_PARTITION: &str = "partition.truncate";
+pub const TRUNCATE_PARTITION_CODE: u32 = 405;
 pub const GET_CONSUMER_GROUP: &str = "consumer_group.get";
 pub const GET_CONSUMER_GROUP_CODE: u32 = 600;
 pub const GET_CONSUMER_GROUPS: &str = "consumer_group.list";
@@ -130,6 +161,12 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const GET_SCHEMA: &str = "schema.get";
+pub const GET_SCHEMA_CODE: u32 = 700;
+pub const GET_SCHEMAS: &str = "schema.list";
+pub const GET_SCHEMAS_CODE: u32 = 701;
+pub const REGISTER_SCHEMA: &str = "schema.register";
+pub const REGISTER_SCHEMA_CODE: u32 = 702;
 
 #[derive(Debug, PartialEq, EnumString)]
 pub enum Command {
@@ -155,6 +192,8 @@ pub enum Command {
     PollMessages(PollMessages),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
+    GetConsumerLag(GetConsumerLag),
+    ResetConsumerOffsets(ResetConsumerOffsets),
     GetStream(GetStream),
     GetStreams(GetStreams),
     CreateStream(CreateStream),
@@ -169,17 +208,79 @@ pub enum Command {
     PurgeTopic(PurgeTopic),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
+    GetOffsetsForTimestamp(GetOffsetsForTimestamp),
+    TruncatePartition(TruncatePartition),
     GetConsumerGroup(GetConsumerGroup),
     GetConsumerGroups(GetConsumerGroups),
     CreateConsumerGroup(CreateConsumerGroup),
     DeleteConsumerGroup(DeleteConsumerGroup),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    GetSchema(GetSchema),
+    GetSchemas(GetSchemas),
+    RegisterSchema(RegisterSchema),
 }
 
 /// A trait for all command payloads.
 pub trait CommandPayload: BytesSerializable + Display {}
 
+impl Command {
+    /// Get the code of the command.
+    pub fn code(&self) -> u32 {
+        match self {
+            Command::Ping(_) => PING_CODE,
+            Command::GetStats(_) => GET_STATS_CODE,
+            Command::GetMe(_) => GET_ME_CODE,
+            Command::GetClient(_) => GET_CLIENT_CODE,
+            Command::GetClients(_) => GET_CLIENTS_CODE,
+            Command::GetUser(_) => GET_USER_CODE,
+            Command::GetUsers(_) => GET_USERS_CODE,
+            Command::CreateUser(_) => CREATE_USER_CODE,
+            Command::DeleteUser(_) => DELETE_USER_CODE,
+            Command::UpdateUser(_) => UPDATE_USER_CODE,
+            Command::UpdatePermissions(_) => UPDATE_PERMISSIONS_CODE,
+            Command::ChangePassword(_) => CHANGE_PASSWORD_CODE,
+            Command::LoginUser(_) => LOGIN_USER_CODE,
+            Command::LogoutUser(_) => LOGOUT_USER_CODE,
+            Command::GetPersonalAccessTokens(_) => GET_PERSONAL_ACCESS_TOKENS_CODE,
+            Command::CreatePersonalAccessToken(_) => CREATE_PERSONAL_ACCESS_TOKEN_CODE,
+            Command::DeletePersonalAccessToken(_) => DELETE_PERSONAL_ACCESS_TOKEN_CODE,
+            Command::LoginWithPersonalAccessToken(_) => LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
+            Command::SendMessages(payload) => get_send_messages_code(payload),
+            Command::PollMessages(payload) => get_poll_messages_code(payload),
+            Command::StoreConsumerOffset(_) => STORE_CONSUMER_OFFSET_CODE,
+            Command::GetConsumerOffset(_) => GET_CONSUMER_OFFSET_CODE,
+            Command::GetConsumerLag(_) => GET_CONSUMER_LAG_CODE,
+            Command::ResetConsumerOffsets(_) => RESET_CONSUMER_OFFSETS_CODE,
+            Command::GetStream(payload) => get_stream_code(payload),
+            Command::GetStreams(_) => GET_STREAMS_CODE,
+            Command::CreateStream(_) => CREATE_STREAM_CODE,
+            Command::DeleteStream(_) => DELETE_STREAM_CODE,
+            Command::UpdateStream(_) => UPDATE_STREAM_CODE,
+            Command::PurgeStream(_) => PURGE_STREAM_CODE,
+            Command::GetTopic(payload) => get_topic_code(payload),
+            Command::GetTopics(payload) => get_topics_code(payload),
+            Command::CreateTopic(_) => CREATE_TOPIC_CODE,
+            Command::DeleteTopic(_) => DELETE_TOPIC_CODE,
+            Command::UpdateTopic(_) => UPDATE_TOPIC_CODE,
+            Command::PurgeTopic(_) => PURGE_TOPIC_CODE,
+            Command::CreatePartitions(_) => CREATE_PARTITIONS_CODE,
+            Command::DeletePartitions(_) => DELETE_PARTITIONS_CODE,
+            Command::GetOffsetsForTimestamp(_) => GET_OFFSETS_FOR_TIMESTAMP_CODE,
+            Command::TruncatePartition(_) => TRUNCATE_PARTITION_CODE,
+            Command::GetConsumerGroup(_) => GET_CONSUMER_GROUP_CODE,
+            Command::GetConsumerGroups(_) => GET_CONSUMER_GROUPS_CODE,
+            Command::CreateConsumerGroup(_) => CREATE_CONSUMER_GROUP_CODE,
+            Command::DeleteConsumerGroup(_) => DELETE_CONSUMER_GROUP_CODE,
+            Command::JoinConsumerGroup(_) => JOIN_CONSUMER_GROUP_CODE,
+            Command::LeaveConsumerGroup(_) => LEAVE_CONSUMER_GROUP_CODE,
+            Command::GetSchema(_) => GET_SCHEMA_CODE,
+            Command::GetSchemas(_) => GET_SCHEMAS_CODE,
+            Command::RegisterSchema(_) => REGISTER_SCHEMA_CODE,
+        }
+    }
+}
+
 impl BytesSerializable for Command {
     fn as_bytes(&self) -> Bytes {
         match self {
@@ -211,22 +312,30 @@ impl BytesSerializable for Command {
             Command::LoginWithPersonalAccessToken(payload) => {
                 as_bytes(LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE, payload.as_bytes())
             }
-            Command::SendMessages(payload) => as_bytes(SEND_MESSAGES_CODE, payload.as_bytes()),
-            Command::PollMessages(payload) => as_bytes(POLL_MESSAGES_CODE, payload.as_bytes()),
+            Command::SendMessages(payload) => {
+                as_bytes(get_send_messages_code(payload), payload.as_bytes())
+            }
+            Command::PollMessages(payload) => {
+                as_bytes(get_poll_messages_code(payload), payload.as_bytes())
+            }
             Command::StoreConsumerOffset(payload) => {
                 as_bytes(STORE_CONSUMER_OFFSET_CODE, payload.as_bytes())
             }
             Command::GetConsumerOffset(payload) => {
                 as_bytes(GET_CONSUMER_OFFSET_CODE, payload.as_bytes())
             }
-            Command::GetStream(payload) => as_bytes(GET_STREAM_CODE, payload.as_bytes()),
+            Command::GetConsumerLag(payload) => as_bytes(GET_CONSUMER_LAG_CODE, payload.as_bytes()),
+            Command::ResetConsumerOffsets(payload) => {
+                as_bytes(RESET_CONSUMER_OFFSETS_CODE, payload.as_bytes())
+            }
+            Command::GetStream(payload) => as_bytes(get_stream_code(payload), payload.as_bytes()),
             Command::GetStreams(payload) => as_bytes(GET_STREAMS_CODE, payload.as_bytes()),
             Command::CreateStream(payload) => as_bytes(CREATE_STREAM_CODE, payload.as_bytes()),
             Command::DeleteStream(payload) => as_bytes(DELETE_STREAM_CODE, payload.as_bytes()),
             Command::UpdateStream(payload) => as_bytes(UPDATE_STREAM_CODE, payload.as_bytes()),
             Command::PurgeStream(payload) => as_bytes(PURGE_STREAM_CODE, payload.as_bytes()),
-            Command::GetTopic(payload) => as_bytes(GET_TOPIC_CODE, payload.as_bytes()),
-            Command::GetTopics(payload) => as_bytes(GET_TOPICS_CODE, payload.as_bytes()),
+            Command::GetTopic(payload) => as_bytes(get_topic_code(payload), payload.as_bytes()),
+            Command::GetTopics(payload) => as_bytes(get_topics_code(payload), payload.as_bytes()),
             Command::CreateTopic(payload) => as_bytes(CREATE_TOPIC_CODE, payload.as_bytes()),
             Command::DeleteTopic(payload) => as_bytes(DELETE_TOPIC_CODE, payload.as_bytes()),
             Command::UpdateTopic(payload) => as_bytes(UPDATE_TOPIC_CODE, payload.as_bytes()),
@@ -237,6 +346,12 @@ impl BytesSerializable for Command {
             Command::DeletePartitions(payload) => {
                 as_bytes(DELETE_PARTITIONS_CODE, payload.as_bytes())
             }
+            Command::GetOffsetsForTimestamp(payload) => {
+                as_bytes(GET_OFFSETS_FOR_TIMESTAMP_CODE, payload.as_bytes())
+            }
+            Command::TruncatePartition(payload) => {
+                as_bytes(TRUNCATE_PARTITION_CODE, payload.as_bytes())
+            }
             Command::GetConsumerGroup(payload) => {
                 as_bytes(GET_CONSUMER_GROUP_CODE, payload.as_bytes())
             }
@@ -255,6 +370,9 @@ impl BytesSerializable for Command {
             Command::LeaveConsumerGroup(payload) => {
                 as_bytes(LEAVE_CONSUMER_GROUP_CODE, payload.as_bytes())
             }
+            Command::GetSchema(payload) => as_bytes(GET_SCHEMA_CODE, payload.as_bytes()),
+            Command::GetSchemas(payload) => as_bytes(GET_SCHEMAS_CODE, payload.as_bytes()),
+            Command::RegisterSchema(payload) => as_bytes(REGISTER_SCHEMA_CODE, payload.as_bytes()),
         }
     }
 
@@ -292,22 +410,68 @@ impl BytesSerializable for Command {
             LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => Ok(Command::LoginWithPersonalAccessToken(
                 LoginWithPersonalAccessToken::from_bytes(payload)?,
             )),
-            SEND_MESSAGES_CODE => Ok(Command::SendMessages(SendMessages::from_bytes(payload)?)),
-            POLL_MESSAGES_CODE => Ok(Command::PollMessages(PollMessages::from_bytes(payload)?)),
+            SEND_MESSAGES_CODE => Ok(Command::SendMessages(SendMessages::from_bytes_for_version(
+                payload,
+                SendMessagesVersion::V1,
+            )?)),
+            SEND_DURABLE_MESSAGES_CODE => {
+                let mut command =
+                    SendMessages::from_bytes_for_version(payload, SendMessagesVersion::V1)?;
+                command.wait_for_durability = true;
+                Ok(Command::SendMessages(command))
+            }
+            SEND_MESSAGES_V2_CODE => Ok(Command::SendMessages(SendMessages::from_bytes(payload)?)),
+            SEND_DURABLE_MESSAGES_V2_CODE => {
+                let mut command = SendMessages::from_bytes(payload)?;
+                command.wait_for_durability = true;
+                Ok(Command::SendMessages(command))
+            }
+            POLL_MESSAGES_CODE => {
+                let mut command = PollMessages::from_bytes(payload)?;
+                command.version = PollMessagesVersion::V1;
+                Ok(Command::PollMessages(command))
+            }
+            POLL_MESSAGES_V2_CODE => Ok(Command::PollMessages(PollMessages::from_bytes(payload)?)),
+            POLL_MESSAGES_V3_CODE => {
+                let mut command = PollMessages::from_bytes(payload)?;
+                command.version = PollMessagesVersion::V3;
+                Ok(Command::PollMessages(command))
+            }
             STORE_CONSUMER_OFFSET_CODE => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_bytes(payload)?,
             )),
             GET_CONSUMER_OFFSET_CODE => Ok(Command::GetConsumerOffset(
                 GetConsumerOffset::from_bytes(payload)?,
             )),
-            GET_STREAM_CODE => Ok(Command::GetStream(GetStream::from_bytes(payload)?)),
+            GET_CONSUMER_LAG_CODE => Ok(Command::GetConsumerLag(GetConsumerLag::from_bytes(
+                payload,
+            )?)),
+            RESET_CONSUMER_OFFSETS_CODE => Ok(Command::ResetConsumerOffsets(
+                ResetConsumerOffsets::from_bytes(payload)?,
+            )),
+            GET_STREAM_CODE => {
+                let mut command = GetStream::from_bytes(payload)?;
+                command.version = TopicLayoutVersion::V1;
+                Ok(Command::GetStream(command))
+            }
+            GET_STREAM_V2_CODE => Ok(Command::GetStream(GetStream::from_bytes(payload)?)),
             GET_STREAMS_CODE => Ok(Command::GetStreams(GetStreams::from_bytes(payload)?)),
             CREATE_STREAM_CODE => Ok(Command::CreateStream(CreateStream::from_bytes(payload)?)),
             DELETE_STREAM_CODE => Ok(Command::DeleteStream(DeleteStream::from_bytes(payload)?)),
             UPDATE_STREAM_CODE => Ok(Command::UpdateStream(UpdateStream::from_bytes(payload)?)),
             PURGE_STREAM_CODE => Ok(Command::PurgeStream(PurgeStream::from_bytes(payload)?)),
-            GET_TOPIC_CODE => Ok(Command::GetTopic(GetTopic::from_bytes(payload)?)),
-            GET_TOPICS_CODE => Ok(Command::GetTopics(GetTopics::from_bytes(payload)?)),
+            GET_TOPIC_CODE => {
+                let mut command = GetTopic::from_bytes(payload)?;
+                command.version = TopicLayoutVersion::V1;
+                Ok(Command::GetTopic(command))
+            }
+            GET_TOPIC_V2_CODE => Ok(Command::GetTopic(GetTopic::from_bytes(payload)?)),
+            GET_TOPICS_CODE => {
+                let mut command = GetTopics::from_bytes(payload)?;
+                command.version = TopicLayoutVersion::V1;
+                Ok(Command::GetTopics(command))
+            }
+            GET_TOPICS_V2_CODE => Ok(Command::GetTopics(GetTopics::from_bytes(payload)?)),
             CREATE_TOPIC_CODE => Ok(Command::CreateTopic(CreateTopic::from_bytes(payload)?)),
             DELETE_TOPIC_CODE => Ok(Command::DeleteTopic(DeleteTopic::from_bytes(payload)?)),
             UPDATE_TOPIC_CODE => Ok(Command::UpdateTopic(UpdateTopic::from_bytes(payload)?)),
@@ -318,6 +482,12 @@ impl BytesSerializable for Command {
             DELETE_PARTITIONS_CODE => Ok(Command::DeletePartitions(DeletePartitions::from_bytes(
                 payload,
             )?)),
+            GET_OFFSETS_FOR_TIMESTAMP_CODE => Ok(Command::GetOffsetsForTimestamp(
+                GetOffsetsForTimestamp::from_bytes(payload)?,
+            )),
+            TRUNCATE_PARTITION_CODE => Ok(Command::TruncatePartition(
+                TruncatePartition::from_bytes(payload)?,
+            )),
             GET_CONSUMER_GROUP_CODE => Ok(Command::GetConsumerGroup(GetConsumerGroup::from_bytes(
                 payload,
             )?)),
@@ -336,6 +506,11 @@ impl BytesSerializable for Command {
             LEAVE_CONSUMER_GROUP_CODE => Ok(Command::LeaveConsumerGroup(
                 LeaveConsumerGroup::from_bytes(payload)?,
             )),
+            GET_SCHEMA_CODE => Ok(Command::GetSchema(GetSchema::from_bytes(payload)?)),
+            GET_SCHEMAS_CODE => Ok(Command::GetSchemas(GetSchemas::from_bytes(payload)?)),
+            REGISTER_SCHEMA_CODE => Ok(Command::RegisterSchema(RegisterSchema::from_bytes(
+                payload,
+            )?)),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -348,6 +523,44 @@ fn as_bytes(command: u32, payload: Bytes) -> Bytes {
     bytes.freeze()
 }
 
+fn get_poll_messages_code(command: &PollMessages) -> u32 {
+    match command.version {
+        PollMessagesVersion::V1 => POLL_MESSAGES_CODE,
+        PollMessagesVersion::V2 => POLL_MESSAGES_V2_CODE,
+        PollMessagesVersion::V3 => POLL_MESSAGES_V3_CODE,
+    }
+}
+
+fn get_stream_code(command: &GetStream) -> u32 {
+    match command.version {
+        TopicLayoutVersion::V1 => GET_STREAM_CODE,
+        TopicLayoutVersion::V2 => GET_STREAM_V2_CODE,
+    }
+}
+
+fn get_topic_code(command: &GetTopic) -> u32 {
+    match command.version {
+        TopicLayoutVersion::V1 => GET_TOPIC_CODE,
+        TopicLayoutVersion::V2 => GET_TOPIC_V2_CODE,
+    }
+}
+
+fn get_topics_code(command: &GetTopics) -> u32 {
+    match command.version {
+        TopicLayoutVersion::V1 => GET_TOPICS_CODE,
+        TopicLayoutVersion::V2 => GET_TOPICS_V2_CODE,
+    }
+}
+
+fn get_send_messages_code(command: &SendMessages) -> u32 {
+    match (command.version, command.wait_for_durability) {
+        (SendMessagesVersion::V1, false) => SEND_MESSAGES_CODE,
+        (SendMessagesVersion::V1, true) => SEND_DURABLE_MESSAGES_CODE,
+        (SendMessagesVersion::V2, false) => SEND_MESSAGES_V2_CODE,
+        (SendMessagesVersion::V2, true) => SEND_DURABLE_MESSAGES_V2_CODE,
+    }
+}
+
 impl Display for Command {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -381,14 +594,32 @@ impl Display for Command {
             Command::LoginWithPersonalAccessToken(payload) => {
                 write!(formatter, "{LOGIN_WITH_PERSONAL_ACCESS_TOKEN}|{payload}")
             }
-            Command::GetStream(payload) => write!(formatter, "{GET_STREAM}|{payload}"),
+            Command::GetStream(payload) => {
+                let name = match payload.version {
+                    TopicLayoutVersion::V1 => GET_STREAM,
+                    TopicLayoutVersion::V2 => GET_STREAM_V2,
+                };
+                write!(formatter, "{name}|{payload}")
+            }
             Command::GetStreams(_) => write!(formatter, "{GET_STREAMS}"),
             Command::CreateStream(payload) => write!(formatter, "{CREATE_STREAM}|{payload}"),
             Command::DeleteStream(payload) => write!(formatter, "{DELETE_STREAM}|{payload}"),
             Command::UpdateStream(payload) => write!(formatter, "{UPDATE_STREAM}|{payload}"),
             Command::PurgeStream(payload) => write!(formatter, "{PURGE_STREAM}|{payload}"),
-            Command::GetTopic(payload) => write!(formatter, "{GET_TOPIC}|{payload}"),
-            Command::GetTopics(payload) => write!(formatter, "{GET_TOPICS}|{payload}"),
+            Command::GetTopic(payload) => {
+                let name = match payload.version {
+                    TopicLayoutVersion::V1 => GET_TOPIC,
+                    TopicLayoutVersion::V2 => GET_TOPIC_V2,
+                };
+                write!(formatter, "{name}|{payload}")
+            }
+            Command::GetTopics(payload) => {
+                let name = match payload.version {
+                    TopicLayoutVersion::V1 => GET_TOPICS,
+                    TopicLayoutVersion::V2 => GET_TOPICS_V2,
+                };
+                write!(formatter, "{name}|{payload}")
+            }
             Command::CreateTopic(payload) => write!(formatter, "{CREATE_TOPIC}|{payload}"),
             Command::DeleteTopic(payload) => write!(formatter, "{DELETE_TOPIC}|{payload}"),
             Command::UpdateTopic(payload) => write!(formatter, "{UPDATE_TOPIC}|{payload}"),
@@ -399,14 +630,41 @@ impl Display for Command {
             Command::DeletePartitions(payload) => {
                 write!(formatter, "{DELETE_PARTITIONS}|{payload}")
             }
-            Command::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
-            Command::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
+            Command::GetOffsetsForTimestamp(payload) => {
+                write!(formatter, "{GET_OFFSETS_FOR_TIMESTAMP}|{payload}")
+            }
+            Command::TruncatePartition(payload) => {
+                write!(formatter, "{TRUNCATE_PARTITION}|{payload}")
+            }
+            Command::PollMessages(payload) => {
+                let name = match payload.version {
+                    PollMessagesVersion::V1 => POLL_MESSAGES,
+                    PollMessagesVersion::V2 => POLL_MESSAGES_V2,
+                    PollMessagesVersion::V3 => POLL_MESSAGES_V3,
+                };
+                write!(formatter, "{name}|{payload}")
+            }
+            Command::SendMessages(payload) => {
+                let name = match (payload.version, payload.wait_for_durability) {
+                    (SendMessagesVersion::V1, false) => SEND_MESSAGES,
+                    (SendMessagesVersion::V1, true) => SEND_DURABLE_MESSAGES,
+                    (SendMessagesVersion::V2, false) => SEND_MESSAGES_V2,
+                    (SendMessagesVersion::V2, true) => SEND_DURABLE_MESSAGES_V2,
+                };
+                write!(formatter, "{name}|{payload}")
+            }
             Command::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
             }
             Command::GetConsumerOffset(payload) => {
                 write!(formatter, "{GET_CONSUMER_OFFSET}|{payload}")
             }
+            Command::GetConsumerLag(payload) => {
+                write!(formatter, "{GET_CONSUMER_LAG}|{payload}")
+            }
+            Command::ResetConsumerOffsets(payload) => {
+                write!(formatter, "{RESET_CONSUMER_OFFSETS}|{payload}")
+            }
             Command::GetConsumerGroup(payload) => {
                 write!(formatter, "{GET_CONSUMER_GROUP}|{payload}")
             }
@@ -425,6 +683,11 @@ impl Display for Command {
             Command::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            Command::GetSchema(payload) => write!(formatter, "{GET_SCHEMA}|{payload}"),
+            Command::GetSchemas(payload) => write!(formatter, "{GET_SCHEMAS}|{payload}"),
+            Command::RegisterSchema(payload) => {
+                write!(formatter, "{REGISTER_SCHEMA}|{payload}")
+            }
         }
     }
 }
@@ -432,6 +695,7 @@ impl Display for Command {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::messages::send_messages::Partitioning;
 
     #[test]
     fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
@@ -527,14 +791,61 @@ mod tests {
         );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::SendMessages(SendMessages::default()),
-            SEND_MESSAGES_CODE,
+            SEND_MESSAGES_V2_CODE,
+            &SendMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::SendMessages(SendMessages {
+                wait_for_durability: true,
+                ..SendMessages::default()
+            }),
+            SEND_DURABLE_MESSAGES_V2_CODE,
             &SendMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::SendMessages(SendMessages {
+                version: SendMessagesVersion::V1,
+                ..SendMessages::default()
+            }),
+            SEND_MESSAGES_CODE,
+            &SendMessages {
+                version: SendMessagesVersion::V1,
+                ..SendMessages::default()
+            },
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::SendMessages(SendMessages {
+                version: SendMessagesVersion::V1,
+                wait_for_durability: true,
+                ..SendMessages::default()
+            }),
+            SEND_DURABLE_MESSAGES_CODE,
+            &SendMessages {
+                version: SendMessagesVersion::V1,
+                ..SendMessages::default()
+            },
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::PollMessages(PollMessages::default()),
+            POLL_MESSAGES_V2_CODE,
+            &PollMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::PollMessages(PollMessages {
+                version: PollMessagesVersion::V1,
+                ..PollMessages::default()
+            }),
             POLL_MESSAGES_CODE,
             &PollMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::PollMessages(PollMessages {
+                version: PollMessagesVersion::V3,
+                ..PollMessages::default()
+            }),
+            POLL_MESSAGES_V3_CODE,
+            &PollMessages::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::StoreConsumerOffset(StoreConsumerOffset::default()),
             STORE_CONSUMER_OFFSET_CODE,
@@ -545,8 +856,26 @@ mod tests {
             GET_CONSUMER_OFFSET_CODE,
             &GetConsumerOffset::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetConsumerLag(GetConsumerLag::default()),
+            GET_CONSUMER_LAG_CODE,
+            &GetConsumerLag::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::ResetConsumerOffsets(ResetConsumerOffsets::default()),
+            RESET_CONSUMER_OFFSETS_CODE,
+            &ResetConsumerOffsets::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetStream(GetStream::default()),
+            GET_STREAM_V2_CODE,
+            &GetStream::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetStream(GetStream {
+                version: TopicLayoutVersion::V1,
+                ..GetStream::default()
+            }),
             GET_STREAM_CODE,
             &GetStream::default(),
         );
@@ -577,11 +906,27 @@ mod tests {
         );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetTopic(GetTopic::default()),
+            GET_TOPIC_V2_CODE,
+            &GetTopic::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetTopic(GetTopic {
+                version: TopicLayoutVersion::V1,
+                ..GetTopic::default()
+            }),
             GET_TOPIC_CODE,
             &GetTopic::default(),
         );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetTopics(GetTopics::default()),
+            GET_TOPICS_V2_CODE,
+            &GetTopics::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetTopics(GetTopics {
+                version: TopicLayoutVersion::V1,
+                ..GetTopics::default()
+            }),
             GET_TOPICS_CODE,
             &GetTopics::default(),
         );
@@ -615,6 +960,16 @@ mod tests {
             DELETE_PARTITIONS_CODE,
             &DeletePartitions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetOffsetsForTimestamp(GetOffsetsForTimestamp::default()),
+            GET_OFFSETS_FOR_TIMESTAMP_CODE,
+            &GetOffsetsForTimestamp::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::TruncatePartition(TruncatePartition::default()),
+            TRUNCATE_PARTITION_CODE,
+            &TruncatePartition::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetConsumerGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP_CODE,
@@ -645,6 +1000,51 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetSchema(GetSchema::default()),
+            GET_SCHEMA_CODE,
+            &GetSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetSchemas(GetSchemas::default()),
+            GET_SCHEMAS_CODE,
+            &GetSchemas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RegisterSchema(RegisterSchema::default()),
+            REGISTER_SCHEMA_CODE,
+            &RegisterSchema::default(),
+        );
+    }
+
+    #[test]
+    fn send_messages_in_original_layout_should_be_deserialized() {
+        // The layout sent by the clients released before the event timestamp was added to the messages.
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(SEND_MESSAGES_CODE);
+        bytes.put_slice(&[1, 4, 1, 0, 0, 0]); // stream ID
+        bytes.put_slice(&[1, 4, 2, 0, 0, 0]); // topic ID
+        bytes.put_slice(&[2, 4, 3, 0, 0, 0]); // partitioning
+        for (id, payload) in [(1u128, "hello 1"), (2u128, "hello 2")] {
+            bytes.put_u128_le(id);
+            bytes.put_u32_le(0); // headers length
+            bytes.put_u32_le(payload.len() as u32);
+            bytes.put_slice(payload.as_bytes());
+        }
+
+        let command = Command::from_bytes(bytes.freeze()).unwrap();
+        let Command::SendMessages(command) = command else {
+            panic!("Expected SendMessages command");
+        };
+        assert_eq!(command.version, SendMessagesVersion::V1);
+        assert!(!command.wait_for_durability);
+        assert_eq!(command.partitioning, Partitioning::partition_id(3));
+        assert_eq!(command.messages.len(), 2);
+        assert_eq!(command.messages[0].id, 1);
+        assert_eq!(command.messages[0].timestamp, 0);
+        assert_eq!(command.messages[0].payload, "hello 1");
+        assert_eq!(command.messages[1].id, 2);
+        assert_eq!(command.messages[1].payload, "hello 2");
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
@@ -666,6 +1066,7 @@ mod tests {
         bytes.put_u32_le(command_id);
         bytes.put_slice(&payload);
         assert_eq!(command.as_bytes(), bytes);
+        assert_eq!(command.code(), command_id);
     }
 
     fn assert_deserialized_from_bytes(
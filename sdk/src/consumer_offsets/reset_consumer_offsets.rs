@@ -0,0 +1,267 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `ResetConsumerOffsets` command that moves the position of a consumer or consumer group across all the partitions of the topic.
+/// It has additional payload:
+/// - `consumer` - the consumer for which the offsets are reset, either the regular consumer or the consumer group.
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `strategy` - the strategy used to calculate the new offsets.
+/// - `dry_run` - whether to only calculate the new offsets without storing them.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct ResetConsumerOffsets {
+    /// The consumer for which the offsets are reset, either the regular consumer or the consumer group.
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// The strategy used to calculate the new offsets.
+    pub strategy: OffsetResetStrategy,
+    /// Whether to only calculate the new offsets without storing them.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// `OffsetResetStrategy` specifies the message from which the consumer will continue consuming in each partition.
+/// It has the following kinds:
+/// - `Earliest` - the first message available in the partition.
+/// - `Latest` - the message following the last one in the partition, so only the new messages will be consumed.
+/// - `Timestamp` - the first message appended at or after the specified timestamp (in microseconds), based on the time indexes.
+/// - `Offset` - the message with the specified offset.
+/// - `Shift` - the message shifted by the specified number of messages (negative value moves backward) from the current position.
+///
+/// The resulting offsets are always limited to the range of the messages available in the partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum OffsetResetStrategy {
+    /// Continue from the first message available in the partition.
+    #[default]
+    Earliest,
+    /// Continue from the message following the last one in the partition.
+    Latest,
+    /// Continue from the first message appended at or after the specified timestamp (in microseconds).
+    Timestamp(u64),
+    /// Continue from the message with the specified offset.
+    Offset(u64),
+    /// Continue from the message shifted by the specified number of messages from the current position.
+    Shift(i64),
+}
+
+impl OffsetResetStrategy {
+    /// Returns code of the offset reset strategy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            OffsetResetStrategy::Earliest => 1,
+            OffsetResetStrategy::Latest => 2,
+            OffsetResetStrategy::Timestamp(_) => 3,
+            OffsetResetStrategy::Offset(_) => 4,
+            OffsetResetStrategy::Shift(_) => 5,
+        }
+    }
+
+    /// Returns the raw value of the offset reset strategy, 0 for the kinds without value.
+    fn as_value_bytes(&self) -> [u8; 8] {
+        match self {
+            OffsetResetStrategy::Earliest | OffsetResetStrategy::Latest => 0u64.to_le_bytes(),
+            OffsetResetStrategy::Timestamp(value) | OffsetResetStrategy::Offset(value) => {
+                value.to_le_bytes()
+            }
+            OffsetResetStrategy::Shift(value) => value.to_le_bytes(),
+        }
+    }
+
+    /// Returns offset reset strategy from the specified code and raw value.
+    pub fn from_code(code: u8, value: [u8; 8]) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(OffsetResetStrategy::Earliest),
+            2 => Ok(OffsetResetStrategy::Latest),
+            3 => Ok(OffsetResetStrategy::Timestamp(u64::from_le_bytes(value))),
+            4 => Ok(OffsetResetStrategy::Offset(u64::from_le_bytes(value))),
+            5 => Ok(OffsetResetStrategy::Shift(i64::from_le_bytes(value))),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for OffsetResetStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OffsetResetStrategy::Earliest => write!(f, "earliest"),
+            OffsetResetStrategy::Latest => write!(f, "latest"),
+            OffsetResetStrategy::Timestamp(value) => write!(f, "timestamp:{value}"),
+            OffsetResetStrategy::Offset(value) => write!(f, "offset:{value}"),
+            OffsetResetStrategy::Shift(value) => write!(f, "shift:{value}"),
+        }
+    }
+}
+
+impl CommandPayload for ResetConsumerOffsets {}
+
+impl Validatable<IggyError> for ResetConsumerOffsets {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for ResetConsumerOffsets {
+    fn as_bytes(&self) -> Bytes {
+        let consumer_bytes = self.consumer.as_bytes();
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            10 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&consumer_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.strategy.as_code());
+        bytes.put_slice(&self.strategy.as_value_bytes());
+        bytes.put_u8(self.dry_run as u8);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<ResetConsumerOffsets, IggyError> {
+        if bytes.len() < 20 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0])?;
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..))?;
+        position += 1 + consumer_id.get_size_bytes() as usize;
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() != position + 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let strategy = OffsetResetStrategy::from_code(
+            bytes[position],
+            bytes[position + 1..position + 9].try_into()?,
+        )?;
+        let dry_run = match bytes[position + 9] {
+            0 => false,
+            1 => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        let command = ResetConsumerOffsets {
+            consumer,
+            stream_id,
+            topic_id,
+            strategy,
+            dry_run,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for ResetConsumerOffsets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.consumer, self.stream_id, self.topic_id, self.strategy, self.dry_run
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = ResetConsumerOffsets {
+            consumer: Consumer::group(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            strategy: OffsetResetStrategy::Shift(-5),
+            dry_run: true,
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0]).unwrap();
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..)).unwrap();
+        position += 1 + consumer_id.get_size_bytes() as usize;
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let strategy = OffsetResetStrategy::from_code(
+            bytes[position],
+            bytes[position + 1..position + 9].try_into().unwrap(),
+        )
+        .unwrap();
+        let dry_run = bytes[position + 9] == 1;
+
+        assert!(!bytes.is_empty());
+        assert_eq!(consumer, command.consumer);
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(strategy, command.strategy);
+        assert_eq!(dry_run, command.dry_run);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let consumer = Consumer::new(Identifier::numeric(1).unwrap());
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let strategy = OffsetResetStrategy::Timestamp(1_000_000);
+
+        let consumer_bytes = consumer.as_bytes();
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            10 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&consumer_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(strategy.as_code());
+        bytes.put_u64_le(1_000_000);
+        bytes.put_u8(0);
+
+        let command = ResetConsumerOffsets::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(consumer, command.consumer);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.strategy, strategy);
+        assert!(!command.dry_run);
+    }
+
+    #[test]
+    fn strategy_should_be_serialized_as_kind_and_value() {
+        let json = serde_json::to_string(&OffsetResetStrategy::Shift(-3)).unwrap();
+        assert_eq!(json, r#"{"kind":"shift","value":-3}"#);
+
+        let strategy = serde_json::from_str::<OffsetResetStrategy>(r#"{"kind":"latest"}"#);
+        assert_eq!(strategy.unwrap(), OffsetResetStrategy::Latest);
+    }
+}
@@ -13,7 +13,7 @@ use std::fmt::Display;
 /// - `consumer` - the consumer that is storing the offset, either the regular consumer or the consumer group.
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
-/// - `partition_id` - partition ID on which the offset is stored. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
+/// - `partition_id` - partition ID on which the offset is stored. Has to be specified for the regular consumer. For consumer group it is optional, and the partition currently polled by the member is used if not specified.
 /// - `offset` - offset to store.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct StoreConsumerOffset {
@@ -26,7 +26,7 @@ pub struct StoreConsumerOffset {
     /// Unique topic ID (numeric or name).
     #[serde(skip)]
     pub topic_id: Identifier,
-    /// Partition ID on which the offset is stored. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
+    /// Partition ID on which the offset is stored. Has to be specified for the regular consumer. For consumer group it is optional, and the partition currently polled by the member is used if not specified.
     pub partition_id: Option<u32>,
     /// Offset to store.
     pub offset: u64,
@@ -142,6 +142,14 @@ pub enum IggyError {
     ReadToEndError(#[from] quinn::ReadToEndError) = 309,
     #[error("Closed error")]
     ClosedError(#[from] quinn::ClosedStream) = 310,
+    #[error("Unsupported content type: {0}")]
+    UnsupportedContentType(String) = 311,
+    #[error("Cannot deserialize messages: {0}")]
+    CannotDeserializeMessages(String) = 312,
+    #[error("gRPC status error, code: {0}, message: {1}")]
+    GrpcStatusError(i32, String) = 313,
+    #[error("Cannot serialize messages: {0}")]
+    CannotSerializeMessages(String) = 314,
     #[error("Cannot create streams directory, Path: {0}")]
     CannotCreateStreamsDirectory(String) = 1000,
     #[error("Cannot create stream with ID: {0} directory, Path: {1}")]
@@ -214,6 +222,10 @@ pub enum IggyError {
     CannotReadTopics(u32) = 2017,
     #[error("Invalid replication factor")]
     InvalidReplicationFactor = 2018,
+    #[error("Cannot change partitioning scheme of topic with ID: {0} for stream with ID: {1}")]
+    CannotChangePartitioningScheme(u32, u32) = 2019,
+    #[error("Cannot enforce fsync for topic with durability: none")]
+    FsyncEnforcedWithoutDurability = 2020,
     #[error("Cannot create partition with ID: {0} for stream with ID: {1} and topic with ID: {2}")]
     CannotCreatePartition(u32, u32, u32) = 3000,
     #[error(
@@ -298,6 +310,30 @@ pub enum IggyError {
     InvalidKeyValueLength = 4028,
     #[error("Command length error: {0}")]
     CommandLengthError(String) = 4029,
+    #[error("Cannot sync segment files for partition with ID: {0}")]
+    CannotSyncSegment(u32) = 4030,
+    #[error("Invalid trace context")]
+    InvalidTraceContext = 4031,
+    #[error(
+        "Invalid message timestamp: {0}, it exceeds the maximum allowed skew from the server time"
+    )]
+    InvalidMessageTimestamp(u64) = 4032,
+    #[error("Invalid message delivery schedule")]
+    InvalidMessageDeliverySchedule = 4033,
+    #[error("Cannot encode message payload: {0}")]
+    CannotEncodeMessagePayload(String) = 4034,
+    #[error("Cannot decode message payload: {0}")]
+    CannotDecodeMessagePayload(String) = 4035,
+    #[error("Invalid message content type: {0}, expected: {1}")]
+    InvalidMessageContentType(String, String) = 4036,
+    #[error("Cannot deliver messages, error code: {0}, reason: {1}")]
+    CannotDeliverMessages(u32, String) = 4037,
+    #[error("Producer is closed")]
+    ProducerClosed = 4038,
+    #[error(
+        "Invalid message delivery time: {0}, it exceeds the maximum allowed delay from the server time"
+    )]
+    TooDistantMessageDelivery(u64) = 4039,
     #[error("Invalid offset: {0}")]
     InvalidOffset(u64) = 4100,
     #[error("Failed to read consumers offsets for partition with ID: {0}")]
@@ -320,6 +356,8 @@ pub enum IggyError {
     CannotCreateConsumerGroupInfo(u32, u32, u32) = 5007,
     #[error("Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.")]
     CannotDeleteConsumerGroupInfo(u32, u32, u32) = 5008,
+    #[error("Partition with ID: {0} is not assigned to the consumer group member with ID: {1} for group with ID: {2}.")]
+    ConsumerGroupPartitionNotAssigned(u32, u32, u32) = 5009,
     #[error("Base offset is missing")]
     MissingBaseOffsetRetainedMessageBatch = 6000,
     #[error("Last offset delta is missing")]
@@ -340,6 +378,20 @@ pub enum IggyError {
     CannotReadMaxTimestamp = 7003,
     #[error("Cannot read batch payload")]
     CannotReadBatchPayload = 7004,
+    #[error("Schema with ID: {0} was not found.")]
+    SchemaNotFound(u32) = 8000,
+    #[error("Invalid schema definition: {0}")]
+    InvalidSchemaDefinition(String) = 8001,
+    #[error("Incompatible schema: {0}")]
+    IncompatibleSchema(String) = 8002,
+    #[error("Message payload does not match the schema with ID: {0}. {1}")]
+    InvalidSchemaPayload(u32, String) = 8003,
+    #[error(
+        "Schema with ID: {0} is not registered for topic with ID: {1} for stream with ID: {2}."
+    )]
+    SchemaNotRegisteredForTopic(u32, u32, u32) = 8004,
+    #[error("Invalid schema ID.")]
+    InvalidSchemaId = 8005,
 }
 
 impl IggyError {
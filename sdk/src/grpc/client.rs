@@ -0,0 +1,273 @@
+use crate::binary::ClientState;
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::grpc::config::GrpcClientConfig;
+use crate::grpc::proto::iggy_client::IggyClient as IggyServiceClient;
+use crate::grpc::proto::PollMessagesStreamRequest;
+use crate::grpc::ERROR_CODE_METADATA;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::{PollMessages, PollMessagesVersion, PollingStrategy};
+use crate::models::messages::PolledMessages;
+use crate::utils::duration::IggyDuration;
+use async_trait::async_trait;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+use tokio_stream::{Stream, StreamExt};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity};
+use tonic::{Request, Status};
+use tracing::{error, info};
+
+const NAME: &str = "Iggy";
+
+/// The stream of the polled messages returned by `GrpcClient::poll_messages_stream`.
+pub type PolledMessagesStream =
+    Pin<Box<dyn Stream<Item = Result<PolledMessages, IggyError>> + Send>>;
+
+/// gRPC client for interacting with the Iggy API.
+/// Each command is sent with its own typed RPC defined in `proto/iggy.proto`,
+/// and the session (e.g. the authenticated user) is bound to the underlying HTTP/2 connection.
+#[derive(Debug)]
+pub struct GrpcClient {
+    pub(crate) config: Arc<GrpcClientConfig>,
+    pub(crate) client: Mutex<Option<IggyServiceClient<Channel>>>,
+    pub(crate) state: Mutex<ClientState>,
+}
+
+impl Default for GrpcClient {
+    fn default() -> Self {
+        GrpcClient::create(Arc::new(GrpcClientConfig::default())).unwrap()
+    }
+}
+
+#[async_trait]
+impl Client for GrpcClient {
+    async fn connect(&self) -> Result<(), IggyError> {
+        GrpcClient::connect(self).await
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        GrpcClient::disconnect(self).await
+    }
+}
+
+impl GrpcClient {
+    /// Create a new gRPC client for the provided server address.
+    pub fn new(server_address: &str) -> Result<Self, IggyError> {
+        Self::create(Arc::new(GrpcClientConfig {
+            server_address: server_address.to_string(),
+            ..Default::default()
+        }))
+    }
+
+    /// Create a new gRPC client based on the provided configuration.
+    pub fn create(config: Arc<GrpcClientConfig>) -> Result<Self, IggyError> {
+        Ok(Self {
+            config,
+            client: Mutex::new(None),
+            state: Mutex::new(ClientState::Disconnected),
+        })
+    }
+
+    /// Continuously poll the messages using the server-streaming call, returning only the non-empty batches.
+    /// The offsets of the partition are tracked by the server, and the consumer group members always poll the next messages with the auto commit enabled.
+    /// When no messages are available, the server waits for the provided `interval` before polling again.
+    /// The messages are returned as they are stored, so the encrypted payloads must be decrypted by the caller.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn poll_messages_stream(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        interval: IggyDuration,
+    ) -> Result<PolledMessagesStream, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let poll = PollMessages {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            strategy: *strategy,
+            count,
+            auto_commit,
+            version: PollMessagesVersion::V2,
+        };
+        // The stream is long-lived, so the request timeout isn't applied to it.
+        let request = Request::new(PollMessagesStreamRequest {
+            poll: Some(poll.into()),
+            interval: interval.get_duration().as_millis() as u64,
+        });
+        let stream = self
+            .get_service_client()
+            .await?
+            .poll_messages_stream(request)
+            .await
+            .map_err(map_status)?
+            .into_inner();
+        Ok(Box::pin(stream.map(|response| {
+            let response = response.map_err(map_status)?;
+            PolledMessages::try_from(response)
+        })))
+    }
+
+    pub(crate) async fn get_state(&self) -> ClientState {
+        *self.state.lock().await
+    }
+
+    pub(crate) async fn set_state(&self, state: ClientState) {
+        *self.state.lock().await = state;
+    }
+
+    pub(crate) async fn fail_if_not_authenticated(&self) -> Result<(), IggyError> {
+        if self.get_state().await != ClientState::Authenticated {
+            return Err(IggyError::Unauthenticated);
+        }
+        Ok(())
+    }
+
+    /// Wraps the message into the request, applying the configured request timeout.
+    pub(crate) fn request<T>(&self, message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        if self.config.request_timeout > 0 {
+            request.set_timeout(Duration::from_millis(self.config.request_timeout));
+        }
+        request
+    }
+
+    pub(crate) async fn get_service_client(&self) -> Result<IggyServiceClient<Channel>, IggyError> {
+        if self.get_state().await == ClientState::Disconnected {
+            return Err(IggyError::NotConnected);
+        }
+
+        // The generated client is cheap to clone, as it shares the same channel.
+        match self.client.lock().await.as_ref() {
+            Some(client) => Ok(client.clone()),
+            None => {
+                error!("Cannot send data. Client is not connected.");
+                Err(IggyError::NotConnected)
+            }
+        }
+    }
+
+    fn create_endpoint(&self) -> Result<Endpoint, IggyError> {
+        let scheme = if self.config.tls_enabled {
+            "https"
+        } else {
+            "http"
+        };
+        let endpoint = Endpoint::from_shared(format!("{scheme}://{}", self.config.server_address))
+            .map_err(|_| IggyError::CannotParseUrl)?;
+        if !self.config.tls_enabled {
+            return Ok(endpoint);
+        }
+
+        let mut tls_config = ClientTlsConfig::new().domain_name(&self.config.tls_domain);
+        if !self.config.tls_ca_file.is_empty() {
+            let ca_certificate = std::fs::read(&self.config.tls_ca_file)?;
+            tls_config = tls_config.ca_certificate(Certificate::from_pem(ca_certificate));
+        }
+        if !self.config.tls_cert_file.is_empty() {
+            let certificate = std::fs::read(&self.config.tls_cert_file)?;
+            let key = std::fs::read(&self.config.tls_key_file)?;
+            tls_config = tls_config.identity(Identity::from_pem(certificate, key));
+        }
+
+        endpoint.tls_config(tls_config).map_err(|error| {
+            error!("Invalid gRPC client TLS configuration: {error}");
+            IggyError::InvalidConfiguration
+        })
+    }
+
+    async fn connect(&self) -> Result<(), IggyError> {
+        if self.get_state().await == ClientState::Connected {
+            return Ok(());
+        }
+
+        let endpoint = self.create_endpoint()?;
+        let mut retry_count = 0;
+        let channel;
+        loop {
+            info!(
+                "{} client is connecting to server: {}...",
+                NAME, self.config.server_address
+            );
+
+            let connection = endpoint.connect().await;
+            if let Err(error) = connection {
+                error!(
+                    "Failed to connect to server: {}, error: {error}",
+                    self.config.server_address
+                );
+                if retry_count < self.config.reconnection_retries {
+                    retry_count += 1;
+                    info!(
+                        "Retrying to connect to server ({}/{}): {} in: {} ms...",
+                        retry_count,
+                        self.config.reconnection_retries,
+                        self.config.server_address,
+                        self.config.reconnection_interval
+                    );
+                    sleep(Duration::from_millis(self.config.reconnection_interval)).await;
+                    continue;
+                }
+
+                return Err(IggyError::NotConnected);
+            }
+
+            channel = connection.unwrap();
+            break;
+        }
+
+        self.client
+            .lock()
+            .await
+            .replace(IggyServiceClient::new(channel));
+        self.set_state(ClientState::Connected).await;
+        info!(
+            "{} client has connected to server: {}",
+            NAME, self.config.server_address
+        );
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        if self.get_state().await == ClientState::Disconnected {
+            return Ok(());
+        }
+
+        info!("{} client is disconnecting from server...", NAME);
+        self.set_state(ClientState::Disconnected).await;
+        self.client.lock().await.take();
+        info!("{} client has disconnected from server.", NAME);
+        Ok(())
+    }
+}
+
+/// Maps the status returned by the server to the same error as returned by the TCP and QUIC clients,
+/// or to the `GrpcStatusError` if the status wasn't caused by the failed command (e.g. the deadline has been exceeded).
+pub(crate) fn map_status(status: Status) -> IggyError {
+    let code = status
+        .metadata()
+        .get(ERROR_CODE_METADATA)
+        .and_then(|code| code.to_str().ok())
+        .and_then(|code| code.parse::<u32>().ok());
+    let Some(code) = code else {
+        error!("Received an invalid gRPC response with status: {status}");
+        return IggyError::GrpcStatusError(status.code() as i32, status.message().to_string());
+    };
+
+    error!(
+        "Received an invalid response with status: {} ({}).",
+        code,
+        IggyError::from_code_as_string(code)
+    );
+    let message = status.message().to_string();
+    IggyError::InvalidResponse(code, message.len() as u32, message)
+}
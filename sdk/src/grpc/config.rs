@@ -0,0 +1,112 @@
+/// Configuration for the gRPC client.
+#[derive(Debug, Clone)]
+pub struct GrpcClientConfig {
+    /// The address of the Iggy server.
+    pub server_address: String,
+    /// The number of retries when connecting to the server.
+    pub reconnection_retries: u32,
+    /// The interval between retries when connecting to the server.
+    pub reconnection_interval: u64,
+    /// The deadline in milliseconds of each request (except the messages stream), `0` means no deadline.
+    pub request_timeout: u64,
+    /// Whether to use TLS when connecting to the server.
+    pub tls_enabled: bool,
+    /// The domain to use for TLS when connecting to the server.
+    pub tls_domain: String,
+    /// The path to the CA certificate used to verify the server (e.g. the self-signed one).
+    pub tls_ca_file: String,
+    /// The optional path to the client certificate used for the mutual TLS.
+    pub tls_cert_file: String,
+    /// The optional path to the client key used for the mutual TLS.
+    pub tls_key_file: String,
+}
+
+impl Default for GrpcClientConfig {
+    fn default() -> GrpcClientConfig {
+        GrpcClientConfig {
+            server_address: "127.0.0.1:8070".to_string(),
+            reconnection_retries: 3,
+            reconnection_interval: 1000,
+            request_timeout: 0,
+            tls_enabled: false,
+            tls_domain: "localhost".to_string(),
+            tls_ca_file: "".to_string(),
+            tls_cert_file: "".to_string(),
+            tls_key_file: "".to_string(),
+        }
+    }
+}
+
+/// Builder for the gRPC client configuration.
+/// Allows configuring the gRPC client with custom settings or using defaults:
+/// - `server_address`: Default is "127.0.0.1:8070"
+/// - `reconnection_retries`: Default is 3.
+/// - `reconnection_interval`: Default is 1000 ms.
+/// - `request_timeout`: Default is 0 (no deadline).
+/// - `tls_enabled`: Default is false.
+/// - `tls_domain`: Default is "localhost".
+/// - `tls_ca_file`, `tls_cert_file`, `tls_key_file`: Default is empty.
+#[derive(Debug, Default)]
+pub struct GrpcClientConfigBuilder {
+    config: GrpcClientConfig,
+}
+
+impl GrpcClientConfigBuilder {
+    pub fn new() -> Self {
+        GrpcClientConfigBuilder::default()
+    }
+
+    /// Sets the server address for the gRPC client.
+    pub fn with_server_address(mut self, server_address: String) -> Self {
+        self.config.server_address = server_address;
+        self
+    }
+
+    /// Sets the number of retries when connecting to the server.
+    pub fn with_reconnection_retries(mut self, reconnection_retries: u32) -> Self {
+        self.config.reconnection_retries = reconnection_retries;
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, reconnection_interval: u64) -> Self {
+        self.config.reconnection_interval = reconnection_interval;
+        self
+    }
+
+    /// Sets the deadline in milliseconds of each request.
+    pub fn with_request_timeout(mut self, request_timeout: u64) -> Self {
+        self.config.request_timeout = request_timeout;
+        self
+    }
+
+    /// Sets whether to use TLS when connecting to the server.
+    pub fn with_tls_enabled(mut self, tls_enabled: bool) -> Self {
+        self.config.tls_enabled = tls_enabled;
+        self
+    }
+
+    /// Sets the domain to use for TLS when connecting to the server.
+    pub fn with_tls_domain(mut self, tls_domain: String) -> Self {
+        self.config.tls_domain = tls_domain;
+        self
+    }
+
+    /// Sets the path to the CA certificate used to verify the server.
+    pub fn with_tls_ca_file(mut self, tls_ca_file: String) -> Self {
+        self.config.tls_ca_file = tls_ca_file;
+        self
+    }
+
+    /// Sets the paths to the client certificate and key used for the mutual TLS.
+    pub fn with_tls_identity(mut self, tls_cert_file: String, tls_key_file: String) -> Self {
+        self.config.tls_cert_file = tls_cert_file;
+        self.config.tls_key_file = tls_key_file;
+        self
+    }
+
+    /// Builds the gRPC client configuration.
+    pub fn build(self) -> GrpcClientConfig {
+        self.config
+    }
+}
@@ -0,0 +1,143 @@
+use crate::client::ConsumerGroupClient;
+use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::identifier::Identifier;
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use async_trait::async_trait;
+
+#[async_trait]
+impl ConsumerGroupClient for GrpcClient {
+    async fn get_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetConsumerGroup {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_consumer_group(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+
+    async fn get_consumer_groups(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<ConsumerGroup>, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetConsumerGroups {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_consumer_groups(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(response
+            .into_inner()
+            .consumer_groups
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn create_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        group_id: Option<u32>,
+    ) -> Result<ConsumerGroupDetails, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = CreateConsumerGroup {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id,
+            name: name.to_string(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .create_consumer_group(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+
+    async fn delete_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = DeleteConsumerGroup {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+        };
+        self.get_service_client()
+            .await?
+            .delete_consumer_group(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn join_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = JoinConsumerGroup {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+        };
+        self.get_service_client()
+            .await?
+            .join_consumer_group(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn leave_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = LeaveConsumerGroup {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            group_id: group_id.clone(),
+        };
+        self.get_service_client()
+            .await?
+            .leave_consumer_group(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use crate::client::ConsumerOffsetClient;
+use crate::consumer::Consumer;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::{OffsetResetStrategy, ResetConsumerOffsets};
+use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::identifier::Identifier;
+use crate::models::consumer_lag::ConsumerLag;
+use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offsets_reset::ConsumerOffsetsReset;
+use async_trait::async_trait;
+
+#[async_trait]
+impl ConsumerOffsetClient for GrpcClient {
+    async fn store_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = StoreConsumerOffset {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            offset,
+        };
+        self.get_service_client()
+            .await?
+            .store_consumer_offset(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn get_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<ConsumerOffsetInfo, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetConsumerOffset {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_consumer_offset(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(response.into_inner().into())
+    }
+
+    async fn get_consumer_lag(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ConsumerLag, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetConsumerLag {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_consumer_lag(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        strategy: OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerOffsetsReset, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = ResetConsumerOffsets {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            strategy,
+            dry_run,
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .reset_consumer_offsets(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+}
@@ -0,0 +1,1926 @@
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::{OffsetResetStrategy, ResetConsumerOffsets};
+use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use crate::error::IggyError;
+use crate::grpc::proto;
+use crate::identifier::{IdKind, Identifier};
+use crate::messages::poll_messages::{
+    PollMessages, PollMessagesVersion, PollingKind, PollingStrategy,
+};
+use crate::messages::send_messages::{
+    Message, Partitioning, PartitioningKind, SendMessages, SendMessagesVersion,
+};
+use crate::models::appended_messages::AppendedMessages;
+use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::consumer_lag::{ConsumerLag, PartitionLag};
+use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offsets_reset::{ConsumerOffsetsReset, PartitionOffsetReset};
+use crate::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use crate::models::identity_info::{IdentityInfo, IdentityTokens, TokenInfo};
+use crate::models::messages::{MessageState, PolledMessage, PolledMessages};
+use crate::models::partition::Partition;
+use crate::models::partition_offsets::PartitionOffsets;
+use crate::models::partition_truncation::PartitionTruncation;
+use crate::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
+use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::Schema;
+use crate::models::stats::Stats;
+use crate::models::stream::{Stream, StreamDetails};
+use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_config_overrides::TopicConfigOverrides;
+use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::models::user_status::UserStatus;
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::truncate_partition::TruncatePartition;
+use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::streams::create_stream::CreateStream;
+use crate::streams::delete_stream::DeleteStream;
+use crate::streams::get_stream::GetStream;
+use crate::streams::purge_stream::PurgeStream;
+use crate::streams::update_stream::UpdateStream;
+use crate::system::get_client::GetClient;
+use crate::topics::create_topic::CreateTopic;
+use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::get_topic::GetTopic;
+use crate::topics::get_topics::GetTopics;
+use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::update_topic::UpdateTopic;
+use crate::users::change_password::ChangePassword;
+use crate::users::create_user::CreateUser;
+use crate::users::delete_user::DeleteUser;
+use crate::users::get_user::GetUser;
+use crate::users::login_user::LoginUser;
+use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_user::UpdateUser;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
+use crate::utils::duration::IggyDuration;
+use crate::utils::partitioning_scheme::PartitioningScheme;
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
+use crate::utils::timestamp_type::TimestampType;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::time::Duration;
+
+// The conversions between the generated messages and the commands and models of the SDK, used by both the client and the server.
+// The enums are sent as the same codes as used by the binary protocol, the durations in microseconds and the sizes in bytes.
+// The malformed messages (e.g. missing the required field or having the unknown enum value) fail with `InvalidFormat`.
+
+/// Implements the conversions of the commands consisting only of the identifiers,
+/// optionally followed by the fields not sent over gRPC, which are set to their defaults.
+macro_rules! map_identifiers_command {
+    ($command:ident, $request:ident, $($field:ident),+ $(; $($default:ident),+)?) => {
+        impl From<$command> for proto::$request {
+            fn from(command: $command) -> Self {
+                Self {
+                    $($field: Some((&command.$field).into()),)+
+                }
+            }
+        }
+
+        impl TryFrom<proto::$request> for $command {
+            type Error = IggyError;
+
+            fn try_from(request: proto::$request) -> Result<Self, Self::Error> {
+                Ok(Self {
+                    $($field: map_identifier(request.$field)?,)+
+                    $($($default: Default::default(),)+)?
+                })
+            }
+        }
+    };
+}
+
+map_identifiers_command!(GetUser, GetUserRequest, user_id);
+map_identifiers_command!(DeleteUser, DeleteUserRequest, user_id);
+map_identifiers_command!(GetStream, GetStreamRequest, stream_id; version);
+map_identifiers_command!(DeleteStream, DeleteStreamRequest, stream_id);
+map_identifiers_command!(PurgeStream, PurgeStreamRequest, stream_id);
+map_identifiers_command!(GetTopic, GetTopicRequest, stream_id, topic_id; version);
+map_identifiers_command!(GetTopics, GetTopicsRequest, stream_id; version);
+map_identifiers_command!(DeleteTopic, DeleteTopicRequest, stream_id, topic_id);
+map_identifiers_command!(PurgeTopic, PurgeTopicRequest, stream_id, topic_id);
+map_identifiers_command!(
+    GetConsumerGroups,
+    GetConsumerGroupsRequest,
+    stream_id,
+    topic_id
+);
+map_identifiers_command!(GetSchemas, GetSchemasRequest, stream_id, topic_id);
+map_identifiers_command!(
+    GetConsumerGroup,
+    GetConsumerGroupRequest,
+    stream_id,
+    topic_id,
+    group_id
+);
+map_identifiers_command!(
+    DeleteConsumerGroup,
+    DeleteConsumerGroupRequest,
+    stream_id,
+    topic_id,
+    group_id
+);
+map_identifiers_command!(
+    JoinConsumerGroup,
+    JoinConsumerGroupRequest,
+    stream_id,
+    topic_id,
+    group_id
+);
+map_identifiers_command!(
+    LeaveConsumerGroup,
+    LeaveConsumerGroupRequest,
+    stream_id,
+    topic_id,
+    group_id
+);
+
+fn required<T>(value: Option<T>) -> Result<T, IggyError> {
+    value.ok_or(IggyError::InvalidFormat)
+}
+
+fn map_code(value: i32) -> Result<u8, IggyError> {
+    u8::try_from(value).map_err(|_| IggyError::InvalidFormat)
+}
+
+fn map_duration(micros: u64) -> IggyDuration {
+    IggyDuration::new(Duration::from_micros(micros))
+}
+
+fn map_replication_factor(value: u32) -> Result<u8, IggyError> {
+    u8::try_from(value).map_err(|_| IggyError::InvalidFormat)
+}
+
+fn map_identifier(identifier: Option<proto::Identifier>) -> Result<Identifier, IggyError> {
+    match required(required(identifier)?.kind)? {
+        proto::identifier::Kind::Numeric(id) => Identifier::numeric(id),
+        proto::identifier::Kind::Name(name) => Identifier::named(&name),
+    }
+}
+
+impl From<&Identifier> for proto::Identifier {
+    fn from(identifier: &Identifier) -> Self {
+        let kind = match identifier.kind {
+            IdKind::Numeric => {
+                proto::identifier::Kind::Numeric(identifier.get_u32_value().unwrap_or_default())
+            }
+            IdKind::String => proto::identifier::Kind::Name(identifier.as_string()),
+        };
+        Self { kind: Some(kind) }
+    }
+}
+
+fn map_consumer(consumer: Option<proto::Consumer>) -> Result<Consumer, IggyError> {
+    let consumer = required(consumer)?;
+    Ok(Consumer {
+        kind: ConsumerKind::from_code(map_code(consumer.kind)?)?,
+        id: map_identifier(consumer.id)?,
+    })
+}
+
+impl From<&Consumer> for proto::Consumer {
+    fn from(consumer: &Consumer) -> Self {
+        Self {
+            kind: consumer.kind.as_code() as i32,
+            id: Some((&consumer.id).into()),
+        }
+    }
+}
+
+fn map_message_id(id: &[u8]) -> Result<u128, IggyError> {
+    if id.is_empty() {
+        return Ok(0);
+    }
+
+    let id = <[u8; 16]>::try_from(id).map_err(|_| IggyError::InvalidFormat)?;
+    Ok(u128::from_le_bytes(id))
+}
+
+fn map_headers(
+    headers: HashMap<String, proto::HeaderValue>,
+) -> Result<Option<HashMap<HeaderKey, HeaderValue>>, IggyError> {
+    if headers.is_empty() {
+        return Ok(None);
+    }
+
+    let mut mapped_headers = HashMap::with_capacity(headers.len());
+    for (key, value) in headers {
+        let value = HeaderValue {
+            kind: HeaderKind::from_code(map_code(value.kind)?)?,
+            value: value.value,
+        };
+        mapped_headers.insert(HeaderKey::new(&key)?, value);
+    }
+    Ok(Some(mapped_headers))
+}
+
+fn map_proto_headers(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> HashMap<String, proto::HeaderValue> {
+    let Some(headers) = headers else {
+        return HashMap::new();
+    };
+
+    headers
+        .iter()
+        .map(|(key, value)| {
+            let value = proto::HeaderValue {
+                kind: value.kind.as_code() as i32,
+                value: value.value.clone(),
+            };
+            (key.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+// System
+
+impl From<GetClient> for proto::GetClientRequest {
+    fn from(command: GetClient) -> Self {
+        Self {
+            client_id: command.client_id,
+        }
+    }
+}
+
+impl From<proto::GetClientRequest> for GetClient {
+    fn from(request: proto::GetClientRequest) -> Self {
+        Self {
+            client_id: request.client_id,
+        }
+    }
+}
+
+impl From<Stats> for proto::Stats {
+    fn from(stats: Stats) -> Self {
+        Self {
+            process_id: stats.process_id,
+            cpu_usage: stats.cpu_usage,
+            total_cpu_usage: stats.total_cpu_usage,
+            memory_usage: stats.memory_usage.as_bytes_u64(),
+            total_memory: stats.total_memory.as_bytes_u64(),
+            available_memory: stats.available_memory.as_bytes_u64(),
+            run_time: stats.run_time.as_micros(),
+            start_time: stats.start_time.into(),
+            read_bytes: stats.read_bytes.as_bytes_u64(),
+            written_bytes: stats.written_bytes.as_bytes_u64(),
+            messages_size_bytes: stats.messages_size_bytes.as_bytes_u64(),
+            streams_count: stats.streams_count,
+            topics_count: stats.topics_count,
+            partitions_count: stats.partitions_count,
+            segments_count: stats.segments_count,
+            messages_count: stats.messages_count,
+            clients_count: stats.clients_count,
+            consumer_groups_count: stats.consumer_groups_count,
+            hostname: stats.hostname,
+            os_name: stats.os_name,
+            os_version: stats.os_version,
+            kernel_version: stats.kernel_version,
+        }
+    }
+}
+
+impl From<proto::Stats> for Stats {
+    fn from(stats: proto::Stats) -> Self {
+        Self {
+            process_id: stats.process_id,
+            cpu_usage: stats.cpu_usage,
+            total_cpu_usage: stats.total_cpu_usage,
+            memory_usage: stats.memory_usage.into(),
+            total_memory: stats.total_memory.into(),
+            available_memory: stats.available_memory.into(),
+            run_time: map_duration(stats.run_time),
+            start_time: stats.start_time.into(),
+            read_bytes: stats.read_bytes.into(),
+            written_bytes: stats.written_bytes.into(),
+            messages_size_bytes: stats.messages_size_bytes.into(),
+            streams_count: stats.streams_count,
+            topics_count: stats.topics_count,
+            partitions_count: stats.partitions_count,
+            segments_count: stats.segments_count,
+            messages_count: stats.messages_count,
+            clients_count: stats.clients_count,
+            consumer_groups_count: stats.consumer_groups_count,
+            hostname: stats.hostname,
+            os_name: stats.os_name,
+            os_version: stats.os_version,
+            kernel_version: stats.kernel_version,
+        }
+    }
+}
+
+impl From<ClientInfo> for proto::ClientInfo {
+    fn from(client: ClientInfo) -> Self {
+        Self {
+            client_id: client.client_id,
+            user_id: client.user_id,
+            address: client.address,
+            transport: client.transport,
+            consumer_groups_count: client.consumer_groups_count,
+        }
+    }
+}
+
+impl From<proto::ClientInfo> for ClientInfo {
+    fn from(client: proto::ClientInfo) -> Self {
+        Self {
+            client_id: client.client_id,
+            user_id: client.user_id,
+            address: client.address,
+            transport: client.transport,
+            consumer_groups_count: client.consumer_groups_count,
+        }
+    }
+}
+
+impl From<ClientInfoDetails> for proto::ClientInfoDetails {
+    fn from(client: ClientInfoDetails) -> Self {
+        Self {
+            client: Some(proto::ClientInfo {
+                client_id: client.client_id,
+                user_id: client.user_id,
+                address: client.address,
+                transport: client.transport,
+                consumer_groups_count: client.consumer_groups_count,
+            }),
+            consumer_groups: client
+                .consumer_groups
+                .into_iter()
+                .map(|consumer_group| proto::ConsumerGroupInfo {
+                    stream_id: consumer_group.stream_id,
+                    topic_id: consumer_group.topic_id,
+                    group_id: consumer_group.group_id,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::ClientInfoDetails> for ClientInfoDetails {
+    type Error = IggyError;
+
+    fn try_from(client: proto::ClientInfoDetails) -> Result<Self, Self::Error> {
+        let consumer_groups = client
+            .consumer_groups
+            .into_iter()
+            .map(|consumer_group| ConsumerGroupInfo {
+                stream_id: consumer_group.stream_id,
+                topic_id: consumer_group.topic_id,
+                group_id: consumer_group.group_id,
+            })
+            .collect();
+        let client = required(client.client)?;
+        Ok(Self {
+            client_id: client.client_id,
+            user_id: client.user_id,
+            address: client.address,
+            transport: client.transport,
+            consumer_groups_count: client.consumer_groups_count,
+            consumer_groups,
+        })
+    }
+}
+
+// Users
+
+impl From<GlobalPermissions> for proto::GlobalPermissions {
+    fn from(permissions: GlobalPermissions) -> Self {
+        Self {
+            manage_servers: permissions.manage_servers,
+            read_servers: permissions.read_servers,
+            manage_users: permissions.manage_users,
+            read_users: permissions.read_users,
+            manage_streams: permissions.manage_streams,
+            read_streams: permissions.read_streams,
+            manage_topics: permissions.manage_topics,
+            read_topics: permissions.read_topics,
+            poll_messages: permissions.poll_messages,
+            send_messages: permissions.send_messages,
+        }
+    }
+}
+
+impl From<proto::GlobalPermissions> for GlobalPermissions {
+    fn from(permissions: proto::GlobalPermissions) -> Self {
+        Self {
+            manage_servers: permissions.manage_servers,
+            read_servers: permissions.read_servers,
+            manage_users: permissions.manage_users,
+            read_users: permissions.read_users,
+            manage_streams: permissions.manage_streams,
+            read_streams: permissions.read_streams,
+            manage_topics: permissions.manage_topics,
+            read_topics: permissions.read_topics,
+            poll_messages: permissions.poll_messages,
+            send_messages: permissions.send_messages,
+        }
+    }
+}
+
+impl From<TopicPermissions> for proto::TopicPermissions {
+    fn from(permissions: TopicPermissions) -> Self {
+        Self {
+            manage_topic: permissions.manage_topic,
+            read_topic: permissions.read_topic,
+            poll_messages: permissions.poll_messages,
+            send_messages: permissions.send_messages,
+        }
+    }
+}
+
+impl From<proto::TopicPermissions> for TopicPermissions {
+    fn from(permissions: proto::TopicPermissions) -> Self {
+        Self {
+            manage_topic: permissions.manage_topic,
+            read_topic: permissions.read_topic,
+            poll_messages: permissions.poll_messages,
+            send_messages: permissions.send_messages,
+        }
+    }
+}
+
+impl From<StreamPermissions> for proto::StreamPermissions {
+    fn from(permissions: StreamPermissions) -> Self {
+        Self {
+            manage_stream: permissions.manage_stream,
+            read_stream: permissions.read_stream,
+            manage_topics: permissions.manage_topics,
+            read_topics: permissions.read_topics,
+            poll_messages: permissions.poll_messages,
+            send_messages: permissions.send_messages,
+            topics: permissions
+                .topics
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(topic_id, permissions)| (topic_id, permissions.into()))
+                .collect(),
+        }
+    }
+}
+
+impl From<proto::StreamPermissions> for StreamPermissions {
+    fn from(permissions: proto::StreamPermissions) -> Self {
+        let topics = (!permissions.topics.is_empty()).then(|| {
+            permissions
+                .topics
+                .into_iter()
+                .map(|(topic_id, permissions)| (topic_id, permissions.into()))
+                .collect()
+        });
+        Self {
+            manage_stream: permissions.manage_stream,
+            read_stream: permissions.read_stream,
+            manage_topics: permissions.manage_topics,
+            read_topics: permissions.read_topics,
+            poll_messages: permissions.poll_messages,
+            send_messages: permissions.send_messages,
+            topics,
+        }
+    }
+}
+
+impl From<Permissions> for proto::Permissions {
+    fn from(permissions: Permissions) -> Self {
+        Self {
+            global: Some(permissions.global.into()),
+            streams: permissions
+                .streams
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(stream_id, permissions)| (stream_id, permissions.into()))
+                .collect(),
+        }
+    }
+}
+
+impl From<proto::Permissions> for Permissions {
+    fn from(permissions: proto::Permissions) -> Self {
+        let streams = (!permissions.streams.is_empty()).then(|| {
+            permissions
+                .streams
+                .into_iter()
+                .map(|(stream_id, permissions)| (stream_id, permissions.into()))
+                .collect()
+        });
+        Self {
+            global: permissions.global.map(Into::into).unwrap_or_default(),
+            streams,
+        }
+    }
+}
+
+impl From<UserInfo> for proto::UserInfo {
+    fn from(user: UserInfo) -> Self {
+        Self {
+            id: user.id,
+            created_at: user.created_at,
+            status: user.status.as_code() as i32,
+            username: user.username,
+        }
+    }
+}
+
+impl TryFrom<proto::UserInfo> for UserInfo {
+    type Error = IggyError;
+
+    fn try_from(user: proto::UserInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: user.id,
+            created_at: user.created_at,
+            status: UserStatus::from_code(map_code(user.status)?)?,
+            username: user.username,
+        })
+    }
+}
+
+impl From<UserInfoDetails> for proto::UserInfoDetails {
+    fn from(user: UserInfoDetails) -> Self {
+        Self {
+            user: Some(proto::UserInfo {
+                id: user.id,
+                created_at: user.created_at,
+                status: user.status.as_code() as i32,
+                username: user.username,
+            }),
+            permissions: user.permissions.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<proto::UserInfoDetails> for UserInfoDetails {
+    type Error = IggyError;
+
+    fn try_from(user: proto::UserInfoDetails) -> Result<Self, Self::Error> {
+        let permissions = user.permissions.map(Into::into);
+        let user = UserInfo::try_from(required(user.user)?)?;
+        Ok(Self {
+            id: user.id,
+            created_at: user.created_at,
+            status: user.status,
+            username: user.username,
+            permissions,
+        })
+    }
+}
+
+impl From<CreateUser> for proto::CreateUserRequest {
+    fn from(command: CreateUser) -> Self {
+        Self {
+            username: command.username,
+            password: command.password,
+            status: command.status.as_code() as i32,
+            permissions: command.permissions.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<proto::CreateUserRequest> for CreateUser {
+    type Error = IggyError;
+
+    fn try_from(request: proto::CreateUserRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            username: request.username,
+            password: request.password,
+            status: UserStatus::from_code(map_code(request.status)?)?,
+            permissions: request.permissions.map(Into::into),
+        })
+    }
+}
+
+impl From<UpdateUser> for proto::UpdateUserRequest {
+    fn from(command: UpdateUser) -> Self {
+        Self {
+            user_id: Some((&command.user_id).into()),
+            username: command.username,
+            status: command.status.map(|status| status.as_code() as i32),
+        }
+    }
+}
+
+impl TryFrom<proto::UpdateUserRequest> for UpdateUser {
+    type Error = IggyError;
+
+    fn try_from(request: proto::UpdateUserRequest) -> Result<Self, Self::Error> {
+        let status = match request.status {
+            Some(status) => Some(UserStatus::from_code(map_code(status)?)?),
+            None => None,
+        };
+        Ok(Self {
+            user_id: map_identifier(request.user_id)?,
+            username: request.username,
+            status,
+        })
+    }
+}
+
+impl From<UpdatePermissions> for proto::UpdatePermissionsRequest {
+    fn from(command: UpdatePermissions) -> Self {
+        Self {
+            user_id: Some((&command.user_id).into()),
+            permissions: command.permissions.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<proto::UpdatePermissionsRequest> for UpdatePermissions {
+    type Error = IggyError;
+
+    fn try_from(request: proto::UpdatePermissionsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            user_id: map_identifier(request.user_id)?,
+            permissions: request.permissions.map(Into::into),
+        })
+    }
+}
+
+impl From<ChangePassword> for proto::ChangePasswordRequest {
+    fn from(command: ChangePassword) -> Self {
+        Self {
+            user_id: Some((&command.user_id).into()),
+            current_password: command.current_password,
+            new_password: command.new_password,
+        }
+    }
+}
+
+impl TryFrom<proto::ChangePasswordRequest> for ChangePassword {
+    type Error = IggyError;
+
+    fn try_from(request: proto::ChangePasswordRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            user_id: map_identifier(request.user_id)?,
+            current_password: request.current_password,
+            new_password: request.new_password,
+        })
+    }
+}
+
+impl From<LoginUser> for proto::LoginUserRequest {
+    fn from(command: LoginUser) -> Self {
+        Self {
+            username: command.username,
+            password: command.password,
+        }
+    }
+}
+
+impl From<proto::LoginUserRequest> for LoginUser {
+    fn from(request: proto::LoginUserRequest) -> Self {
+        Self {
+            username: request.username,
+            password: request.password,
+        }
+    }
+}
+
+impl From<IdentityInfo> for proto::IdentityInfo {
+    fn from(identity: IdentityInfo) -> Self {
+        Self {
+            user_id: identity.user_id,
+            tokens: identity.tokens.map(|tokens| proto::IdentityTokens {
+                access_token: Some(proto::TokenInfo {
+                    token: tokens.access_token.token,
+                    expiry: tokens.access_token.expiry,
+                }),
+                refresh_token: Some(proto::TokenInfo {
+                    token: tokens.refresh_token.token,
+                    expiry: tokens.refresh_token.expiry,
+                }),
+            }),
+        }
+    }
+}
+
+impl TryFrom<proto::IdentityInfo> for IdentityInfo {
+    type Error = IggyError;
+
+    fn try_from(identity: proto::IdentityInfo) -> Result<Self, Self::Error> {
+        let tokens = match identity.tokens {
+            Some(tokens) => {
+                let access_token = required(tokens.access_token)?;
+                let refresh_token = required(tokens.refresh_token)?;
+                Some(IdentityTokens {
+                    access_token: TokenInfo {
+                        token: access_token.token,
+                        expiry: access_token.expiry,
+                    },
+                    refresh_token: TokenInfo {
+                        token: refresh_token.token,
+                        expiry: refresh_token.expiry,
+                    },
+                })
+            }
+            None => None,
+        };
+        Ok(Self {
+            user_id: identity.user_id,
+            tokens,
+        })
+    }
+}
+
+// Personal access tokens
+
+impl From<PersonalAccessTokenInfo> for proto::PersonalAccessTokenInfo {
+    fn from(token: PersonalAccessTokenInfo) -> Self {
+        Self {
+            name: token.name,
+            expiry: token.expiry,
+        }
+    }
+}
+
+impl From<proto::PersonalAccessTokenInfo> for PersonalAccessTokenInfo {
+    fn from(token: proto::PersonalAccessTokenInfo) -> Self {
+        Self {
+            name: token.name,
+            expiry: token.expiry,
+        }
+    }
+}
+
+impl From<CreatePersonalAccessToken> for proto::CreatePersonalAccessTokenRequest {
+    fn from(command: CreatePersonalAccessToken) -> Self {
+        Self {
+            name: command.name,
+            expiry: command.expiry,
+        }
+    }
+}
+
+impl From<proto::CreatePersonalAccessTokenRequest> for CreatePersonalAccessToken {
+    fn from(request: proto::CreatePersonalAccessTokenRequest) -> Self {
+        Self {
+            name: request.name,
+            expiry: request.expiry,
+        }
+    }
+}
+
+impl From<proto::RawPersonalAccessToken> for RawPersonalAccessToken {
+    fn from(token: proto::RawPersonalAccessToken) -> Self {
+        Self { token: token.token }
+    }
+}
+
+impl From<DeletePersonalAccessToken> for proto::DeletePersonalAccessTokenRequest {
+    fn from(command: DeletePersonalAccessToken) -> Self {
+        Self { name: command.name }
+    }
+}
+
+impl From<proto::DeletePersonalAccessTokenRequest> for DeletePersonalAccessToken {
+    fn from(request: proto::DeletePersonalAccessTokenRequest) -> Self {
+        Self { name: request.name }
+    }
+}
+
+impl From<LoginWithPersonalAccessToken> for proto::LoginWithPersonalAccessTokenRequest {
+    fn from(command: LoginWithPersonalAccessToken) -> Self {
+        Self {
+            token: command.token,
+        }
+    }
+}
+
+impl From<proto::LoginWithPersonalAccessTokenRequest> for LoginWithPersonalAccessToken {
+    fn from(request: proto::LoginWithPersonalAccessTokenRequest) -> Self {
+        Self {
+            token: request.token,
+        }
+    }
+}
+
+// Streams
+
+impl From<TopicConfigOverrides> for proto::TopicConfigOverrides {
+    fn from(overrides: TopicConfigOverrides) -> Self {
+        Self {
+            segment_size: overrides.segment_size.map(|size| size.as_bytes_u64()),
+            enforce_fsync: overrides.enforce_fsync,
+            messages_required_to_save: overrides.messages_required_to_save,
+            validate_checksum: overrides.validate_checksum,
+            cache_indexes: overrides.cache_indexes,
+            cache_time_indexes: overrides.cache_time_indexes,
+            message_deduplication: overrides.message_deduplication,
+            message_deduplication_max_entries: overrides.message_deduplication_max_entries,
+            message_deduplication_expiry: overrides
+                .message_deduplication_expiry
+                .map(|expiry| expiry.as_micros()),
+            segment_max_age: overrides.segment_max_age.map(|age| age.as_micros()),
+            segment_max_messages: overrides.segment_max_messages,
+            timestamp_type: overrides
+                .timestamp_type
+                .map(|timestamp_type| timestamp_type.as_code() as i32),
+            max_timestamp_skew: overrides.max_timestamp_skew.map(|skew| skew.as_micros()),
+            partitioning_scheme: overrides
+                .partitioning_scheme
+                .map(|scheme| scheme.as_code() as i32),
+        }
+    }
+}
+
+impl TryFrom<proto::TopicConfigOverrides> for TopicConfigOverrides {
+    type Error = IggyError;
+
+    fn try_from(overrides: proto::TopicConfigOverrides) -> Result<Self, Self::Error> {
+        let timestamp_type = match overrides.timestamp_type {
+            Some(timestamp_type) => Some(TimestampType::from_code(map_code(timestamp_type)?)?),
+            None => None,
+        };
+        let partitioning_scheme = match overrides.partitioning_scheme {
+            Some(scheme) => Some(PartitioningScheme::from_code(map_code(scheme)?)?),
+            None => None,
+        };
+        Ok(Self {
+            segment_size: overrides.segment_size.map(IggyByteSize::from),
+            enforce_fsync: overrides.enforce_fsync,
+            messages_required_to_save: overrides.messages_required_to_save,
+            validate_checksum: overrides.validate_checksum,
+            cache_indexes: overrides.cache_indexes,
+            cache_time_indexes: overrides.cache_time_indexes,
+            message_deduplication: overrides.message_deduplication,
+            message_deduplication_max_entries: overrides.message_deduplication_max_entries,
+            message_deduplication_expiry: overrides.message_deduplication_expiry.map(map_duration),
+            segment_max_age: overrides.segment_max_age.map(map_duration),
+            segment_max_messages: overrides.segment_max_messages,
+            timestamp_type,
+            max_timestamp_skew: overrides.max_timestamp_skew.map(map_duration),
+            partitioning_scheme,
+        })
+    }
+}
+
+fn map_config_overrides(
+    overrides: Option<proto::TopicConfigOverrides>,
+) -> Result<TopicConfigOverrides, IggyError> {
+    match overrides {
+        Some(overrides) => overrides.try_into(),
+        None => Ok(TopicConfigOverrides::default()),
+    }
+}
+
+impl From<Stream> for proto::Stream {
+    fn from(stream: Stream) -> Self {
+        Self {
+            id: stream.id,
+            created_at: stream.created_at.into(),
+            name: stream.name,
+            size: stream.size.as_bytes_u64(),
+            messages_count: stream.messages_count,
+            topics_count: stream.topics_count,
+        }
+    }
+}
+
+impl From<proto::Stream> for Stream {
+    fn from(stream: proto::Stream) -> Self {
+        Self {
+            id: stream.id,
+            created_at: stream.created_at.into(),
+            name: stream.name,
+            size: stream.size.into(),
+            messages_count: stream.messages_count,
+            topics_count: stream.topics_count,
+        }
+    }
+}
+
+impl From<StreamDetails> for proto::StreamDetails {
+    fn from(stream: StreamDetails) -> Self {
+        Self {
+            stream: Some(proto::Stream {
+                id: stream.id,
+                created_at: stream.created_at.into(),
+                name: stream.name,
+                size: stream.size.as_bytes_u64(),
+                messages_count: stream.messages_count,
+                topics_count: stream.topics_count,
+            }),
+            topic_config_overrides: Some(stream.topic_config_overrides.into()),
+            topics: stream.topics.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::StreamDetails> for StreamDetails {
+    type Error = IggyError;
+
+    fn try_from(stream: proto::StreamDetails) -> Result<Self, Self::Error> {
+        let topic_config_overrides = map_config_overrides(stream.topic_config_overrides)?;
+        let topics = stream
+            .topics
+            .into_iter()
+            .map(Topic::try_from)
+            .collect::<Result<_, _>>()?;
+        let stream = Stream::from(required(stream.stream)?);
+        Ok(Self {
+            id: stream.id,
+            created_at: stream.created_at,
+            name: stream.name,
+            size: stream.size,
+            messages_count: stream.messages_count,
+            topics_count: stream.topics_count,
+            topic_config_overrides,
+            topics,
+        })
+    }
+}
+
+impl From<CreateStream> for proto::CreateStreamRequest {
+    fn from(command: CreateStream) -> Self {
+        Self {
+            stream_id: command.stream_id,
+            name: command.name,
+        }
+    }
+}
+
+impl From<proto::CreateStreamRequest> for CreateStream {
+    fn from(request: proto::CreateStreamRequest) -> Self {
+        Self {
+            stream_id: request.stream_id,
+            name: request.name,
+        }
+    }
+}
+
+impl From<UpdateStream> for proto::UpdateStreamRequest {
+    fn from(command: UpdateStream) -> Self {
+        Self {
+            stream_id: Some((&command.stream_id).into()),
+            name: command.name,
+            topic_config_overrides: command.topic_config_overrides.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<proto::UpdateStreamRequest> for UpdateStream {
+    type Error = IggyError;
+
+    fn try_from(request: proto::UpdateStreamRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_config_overrides: request
+                .topic_config_overrides
+                .map(TryInto::try_into)
+                .transpose()?,
+            name: request.name,
+        })
+    }
+}
+
+// Topics
+
+impl From<Topic> for proto::Topic {
+    fn from(topic: Topic) -> Self {
+        Self {
+            id: topic.id,
+            created_at: topic.created_at,
+            name: topic.name,
+            size: topic.size.as_bytes_u64(),
+            message_expiry: topic.message_expiry,
+            compression_algorithm: topic.compression_algorithm.as_code() as i32,
+            max_topic_size: topic.max_topic_size.map(|size| size.as_bytes_u64()),
+            replication_factor: topic.replication_factor as u32,
+            durability: topic.durability.as_code() as i32,
+            messages_count: topic.messages_count,
+            partitions_count: topic.partitions_count,
+        }
+    }
+}
+
+impl TryFrom<proto::Topic> for Topic {
+    type Error = IggyError;
+
+    fn try_from(topic: proto::Topic) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: topic.id,
+            created_at: topic.created_at,
+            name: topic.name,
+            size: topic.size.into(),
+            message_expiry: topic.message_expiry,
+            compression_algorithm: CompressionAlgorithm::from_code(map_code(
+                topic.compression_algorithm,
+            )?)?,
+            max_topic_size: topic.max_topic_size.map(IggyByteSize::from),
+            replication_factor: map_replication_factor(topic.replication_factor)?,
+            durability: Durability::from_code(map_code(topic.durability)?)?,
+            messages_count: topic.messages_count,
+            partitions_count: topic.partitions_count,
+        })
+    }
+}
+
+impl From<Partition> for proto::Partition {
+    fn from(partition: Partition) -> Self {
+        Self {
+            id: partition.id,
+            created_at: partition.created_at,
+            segments_count: partition.segments_count,
+            current_offset: partition.current_offset,
+            size: partition.size.as_bytes_u64(),
+            messages_count: partition.messages_count,
+        }
+    }
+}
+
+impl From<proto::Partition> for Partition {
+    fn from(partition: proto::Partition) -> Self {
+        Self {
+            id: partition.id,
+            created_at: partition.created_at,
+            segments_count: partition.segments_count,
+            current_offset: partition.current_offset,
+            size: partition.size.into(),
+            messages_count: partition.messages_count,
+        }
+    }
+}
+
+impl From<TopicDetails> for proto::TopicDetails {
+    fn from(topic: TopicDetails) -> Self {
+        Self {
+            topic: Some(proto::Topic {
+                id: topic.id,
+                created_at: topic.created_at,
+                name: topic.name,
+                size: topic.size.as_bytes_u64(),
+                message_expiry: topic.message_expiry,
+                compression_algorithm: topic.compression_algorithm.as_code() as i32,
+                max_topic_size: topic.max_topic_size.map(|size| size.as_bytes_u64()),
+                replication_factor: topic.replication_factor as u32,
+                durability: topic.durability.as_code() as i32,
+                messages_count: topic.messages_count,
+                partitions_count: topic.partitions_count,
+            }),
+            config_overrides: Some(topic.config_overrides.into()),
+            partitioning_scheme: topic.partitioning_scheme.as_code() as i32,
+            partitions: topic.partitions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::TopicDetails> for TopicDetails {
+    type Error = IggyError;
+
+    fn try_from(topic: proto::TopicDetails) -> Result<Self, Self::Error> {
+        let config_overrides = map_config_overrides(topic.config_overrides)?;
+        let partitioning_scheme =
+            PartitioningScheme::from_code(map_code(topic.partitioning_scheme)?)?;
+        let partitions = topic.partitions.into_iter().map(Into::into).collect();
+        let topic = Topic::try_from(required(topic.topic)?)?;
+        Ok(Self {
+            id: topic.id,
+            created_at: topic.created_at,
+            name: topic.name,
+            size: topic.size,
+            message_expiry: topic.message_expiry,
+            compression_algorithm: topic.compression_algorithm,
+            max_topic_size: topic.max_topic_size,
+            replication_factor: topic.replication_factor,
+            durability: topic.durability,
+            messages_count: topic.messages_count,
+            partitions_count: topic.partitions_count,
+            config_overrides,
+            partitioning_scheme,
+            partitions,
+        })
+    }
+}
+
+impl From<CreateTopic> for proto::CreateTopicRequest {
+    fn from(command: CreateTopic) -> Self {
+        Self {
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: command.topic_id,
+            partitions_count: command.partitions_count,
+            compression_algorithm: command.compression_algorithm.as_code() as i32,
+            message_expiry: command.message_expiry,
+            max_topic_size: command.max_topic_size.map(|size| size.as_bytes_u64()),
+            replication_factor: command.replication_factor.map(u32::from),
+            durability: command
+                .durability
+                .map(|durability| durability.as_code() as i32),
+            config_overrides: Some(command.config_overrides.into()),
+            name: command.name,
+        }
+    }
+}
+
+impl TryFrom<proto::CreateTopicRequest> for CreateTopic {
+    type Error = IggyError;
+
+    fn try_from(request: proto::CreateTopicRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: request.topic_id,
+            partitions_count: request.partitions_count,
+            compression_algorithm: CompressionAlgorithm::from_code(map_code(
+                request.compression_algorithm,
+            )?)?,
+            message_expiry: request.message_expiry,
+            max_topic_size: request.max_topic_size.map(IggyByteSize::from),
+            replication_factor: request
+                .replication_factor
+                .map(map_replication_factor)
+                .transpose()?,
+            durability: request
+                .durability
+                .map(|durability| Durability::from_code(map_code(durability)?))
+                .transpose()?,
+            config_overrides: map_config_overrides(request.config_overrides)?,
+            name: request.name,
+        })
+    }
+}
+
+impl From<UpdateTopic> for proto::UpdateTopicRequest {
+    fn from(command: UpdateTopic) -> Self {
+        Self {
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            compression_algorithm: command.compression_algorithm.as_code() as i32,
+            message_expiry: command.message_expiry,
+            max_topic_size: command.max_topic_size.map(|size| size.as_bytes_u64()),
+            replication_factor: command.replication_factor.map(u32::from),
+            durability: command
+                .durability
+                .map(|durability| durability.as_code() as i32),
+            config_overrides: command.config_overrides.map(Into::into),
+            name: command.name,
+        }
+    }
+}
+
+impl TryFrom<proto::UpdateTopicRequest> for UpdateTopic {
+    type Error = IggyError;
+
+    fn try_from(request: proto::UpdateTopicRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            compression_algorithm: CompressionAlgorithm::from_code(map_code(
+                request.compression_algorithm,
+            )?)?,
+            message_expiry: request.message_expiry,
+            max_topic_size: request.max_topic_size.map(IggyByteSize::from),
+            replication_factor: request
+                .replication_factor
+                .map(map_replication_factor)
+                .transpose()?,
+            durability: request
+                .durability
+                .map(|durability| Durability::from_code(map_code(durability)?))
+                .transpose()?,
+            config_overrides: request
+                .config_overrides
+                .map(TryInto::try_into)
+                .transpose()?,
+            name: request.name,
+        })
+    }
+}
+
+// Partitions
+
+impl From<CreatePartitions> for proto::CreatePartitionsRequest {
+    fn from(command: CreatePartitions) -> Self {
+        Self {
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            partitions_count: command.partitions_count,
+        }
+    }
+}
+
+impl TryFrom<proto::CreatePartitionsRequest> for CreatePartitions {
+    type Error = IggyError;
+
+    fn try_from(request: proto::CreatePartitionsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            partitions_count: request.partitions_count,
+        })
+    }
+}
+
+impl From<DeletePartitions> for proto::DeletePartitionsRequest {
+    fn from(command: DeletePartitions) -> Self {
+        Self {
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            partitions_count: command.partitions_count,
+        }
+    }
+}
+
+impl TryFrom<proto::DeletePartitionsRequest> for DeletePartitions {
+    type Error = IggyError;
+
+    fn try_from(request: proto::DeletePartitionsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            partitions_count: request.partitions_count,
+        })
+    }
+}
+
+impl From<GetOffsetsForTimestamp> for proto::GetOffsetsForTimestampRequest {
+    fn from(command: GetOffsetsForTimestamp) -> Self {
+        Self {
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            timestamp: command.timestamp,
+            partition_ids: command.partition_ids,
+        }
+    }
+}
+
+impl TryFrom<proto::GetOffsetsForTimestampRequest> for GetOffsetsForTimestamp {
+    type Error = IggyError;
+
+    fn try_from(request: proto::GetOffsetsForTimestampRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            timestamp: request.timestamp,
+            partition_ids: request.partition_ids,
+        })
+    }
+}
+
+impl From<PartitionOffsets> for proto::PartitionOffsets {
+    fn from(offsets: PartitionOffsets) -> Self {
+        Self {
+            partition_id: offsets.partition_id,
+            first_offset: offsets.first_offset,
+            last_offset: offsets.last_offset,
+            offset: offsets.offset,
+        }
+    }
+}
+
+impl From<proto::PartitionOffsets> for PartitionOffsets {
+    fn from(offsets: proto::PartitionOffsets) -> Self {
+        Self {
+            partition_id: offsets.partition_id,
+            first_offset: offsets.first_offset,
+            last_offset: offsets.last_offset,
+            offset: offsets.offset,
+        }
+    }
+}
+
+impl From<TruncatePartition> for proto::TruncatePartitionRequest {
+    fn from(command: TruncatePartition) -> Self {
+        Self {
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            partition_id: command.partition_id,
+            offset: command.offset,
+        }
+    }
+}
+
+impl TryFrom<proto::TruncatePartitionRequest> for TruncatePartition {
+    type Error = IggyError;
+
+    fn try_from(request: proto::TruncatePartitionRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            partition_id: request.partition_id,
+            offset: request.offset,
+        })
+    }
+}
+
+impl From<PartitionTruncation> for proto::PartitionTruncation {
+    fn from(truncation: PartitionTruncation) -> Self {
+        Self {
+            partition_id: truncation.partition_id,
+            low_watermark: truncation.low_watermark,
+            deleted_segments: truncation.deleted_segments,
+            deleted_messages: truncation.deleted_messages,
+        }
+    }
+}
+
+impl From<proto::PartitionTruncation> for PartitionTruncation {
+    fn from(truncation: proto::PartitionTruncation) -> Self {
+        Self {
+            partition_id: truncation.partition_id,
+            low_watermark: truncation.low_watermark,
+            deleted_segments: truncation.deleted_segments,
+            deleted_messages: truncation.deleted_messages,
+        }
+    }
+}
+
+// Messages
+
+impl From<PollMessages> for proto::PollMessagesRequest {
+    fn from(command: PollMessages) -> Self {
+        Self {
+            consumer: Some((&command.consumer).into()),
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            partition_id: command.partition_id,
+            strategy: Some(proto::PollingStrategy {
+                kind: command.strategy.kind.as_code() as i32,
+                value: command.strategy.value,
+            }),
+            count: command.count,
+            auto_commit: command.auto_commit,
+            assigned_partition_only: command.version == PollMessagesVersion::V3,
+        }
+    }
+}
+
+impl TryFrom<proto::PollMessagesRequest> for PollMessages {
+    type Error = IggyError;
+
+    fn try_from(request: proto::PollMessagesRequest) -> Result<Self, Self::Error> {
+        let strategy = required(request.strategy)?;
+        Ok(Self {
+            consumer: map_consumer(request.consumer)?,
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            partition_id: request.partition_id,
+            strategy: PollingStrategy {
+                kind: PollingKind::from_code(map_code(strategy.kind)?)?,
+                value: strategy.value,
+            },
+            count: request.count,
+            auto_commit: request.auto_commit,
+            version: if request.assigned_partition_only {
+                PollMessagesVersion::V3
+            } else {
+                PollMessagesVersion::V2
+            },
+        })
+    }
+}
+
+impl From<PolledMessages> for proto::PolledMessages {
+    fn from(polled_messages: PolledMessages) -> Self {
+        Self {
+            partition_id: polled_messages.partition_id,
+            current_offset: polled_messages.current_offset,
+            messages: polled_messages
+                .messages
+                .into_iter()
+                .map(|message| proto::PolledMessage {
+                    offset: message.offset,
+                    state: message.state.as_code() as i32,
+                    timestamp: message.timestamp,
+                    id: Bytes::copy_from_slice(&message.id.to_le_bytes()),
+                    checksum: message.checksum,
+                    headers: map_proto_headers(&message.headers),
+                    key: message.key,
+                    payload: message.payload,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::PolledMessages> for PolledMessages {
+    type Error = IggyError;
+
+    fn try_from(polled_messages: proto::PolledMessages) -> Result<Self, Self::Error> {
+        let mut messages = Vec::with_capacity(polled_messages.messages.len());
+        for message in polled_messages.messages {
+            messages.push(PolledMessage {
+                offset: message.offset,
+                state: MessageState::from_code(map_code(message.state)?)?,
+                timestamp: message.timestamp,
+                id: map_message_id(&message.id)?,
+                checksum: message.checksum,
+                headers: map_headers(message.headers)?,
+                key: message.key,
+                length: message.payload.len() as u32,
+                payload: message.payload,
+            });
+        }
+        Ok(Self {
+            partition_id: polled_messages.partition_id,
+            current_offset: polled_messages.current_offset,
+            messages,
+        })
+    }
+}
+
+impl From<&Partitioning> for proto::Partitioning {
+    fn from(partitioning: &Partitioning) -> Self {
+        Self {
+            kind: partitioning.kind.as_code() as i32,
+            value: Bytes::copy_from_slice(&partitioning.value),
+        }
+    }
+}
+
+impl From<&Message> for proto::Message {
+    fn from(message: &Message) -> Self {
+        Self {
+            id: Bytes::copy_from_slice(&message.id.to_le_bytes()),
+            timestamp: message.timestamp,
+            payload: message.payload.clone(),
+            headers: map_proto_headers(&message.headers),
+        }
+    }
+}
+
+impl TryFrom<proto::SendMessagesRequest> for SendMessages {
+    type Error = IggyError;
+
+    fn try_from(request: proto::SendMessagesRequest) -> Result<Self, Self::Error> {
+        let partitioning = required(request.partitioning)?;
+        let partitioning = Partitioning {
+            kind: PartitioningKind::from_code(map_code(partitioning.kind)?)?,
+            length: u8::try_from(partitioning.value.len()).map_err(|_| IggyError::InvalidFormat)?,
+            value: partitioning.value.to_vec(),
+        };
+        let mut messages = Vec::with_capacity(request.messages.len());
+        for message in request.messages {
+            messages.push(Message {
+                id: map_message_id(&message.id)?,
+                timestamp: message.timestamp,
+                length: message.payload.len() as u32,
+                payload: message.payload,
+                headers: map_headers(message.headers)?,
+            });
+        }
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            partitioning,
+            messages,
+            wait_for_durability: request.wait_for_durability,
+            version: SendMessagesVersion::V2,
+        })
+    }
+}
+
+impl From<AppendedMessages> for proto::AppendedMessages {
+    fn from(appended_messages: AppendedMessages) -> Self {
+        Self {
+            partition_id: appended_messages.partition_id,
+            base_offset: appended_messages.base_offset,
+            messages_count: appended_messages.messages_count,
+            skipped_indexes: appended_messages.skipped_indexes,
+        }
+    }
+}
+
+impl From<proto::AppendedMessages> for AppendedMessages {
+    fn from(appended_messages: proto::AppendedMessages) -> Self {
+        Self {
+            partition_id: appended_messages.partition_id,
+            base_offset: appended_messages.base_offset,
+            messages_count: appended_messages.messages_count,
+            skipped_indexes: appended_messages.skipped_indexes,
+        }
+    }
+}
+
+// Consumer offsets
+
+impl From<StoreConsumerOffset> for proto::StoreConsumerOffsetRequest {
+    fn from(command: StoreConsumerOffset) -> Self {
+        Self {
+            consumer: Some((&command.consumer).into()),
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            partition_id: command.partition_id,
+            offset: command.offset,
+        }
+    }
+}
+
+impl TryFrom<proto::StoreConsumerOffsetRequest> for StoreConsumerOffset {
+    type Error = IggyError;
+
+    fn try_from(request: proto::StoreConsumerOffsetRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            consumer: map_consumer(request.consumer)?,
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            partition_id: request.partition_id,
+            offset: request.offset,
+        })
+    }
+}
+
+impl From<GetConsumerOffset> for proto::GetConsumerOffsetRequest {
+    fn from(command: GetConsumerOffset) -> Self {
+        Self {
+            consumer: Some((&command.consumer).into()),
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            partition_id: command.partition_id,
+        }
+    }
+}
+
+impl TryFrom<proto::GetConsumerOffsetRequest> for GetConsumerOffset {
+    type Error = IggyError;
+
+    fn try_from(request: proto::GetConsumerOffsetRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            consumer: map_consumer(request.consumer)?,
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            partition_id: request.partition_id,
+        })
+    }
+}
+
+impl From<ConsumerOffsetInfo> for proto::ConsumerOffsetInfo {
+    fn from(offset: ConsumerOffsetInfo) -> Self {
+        Self {
+            partition_id: offset.partition_id,
+            current_offset: offset.current_offset,
+            stored_offset: offset.stored_offset,
+        }
+    }
+}
+
+impl From<proto::ConsumerOffsetInfo> for ConsumerOffsetInfo {
+    fn from(offset: proto::ConsumerOffsetInfo) -> Self {
+        Self {
+            partition_id: offset.partition_id,
+            current_offset: offset.current_offset,
+            stored_offset: offset.stored_offset,
+        }
+    }
+}
+
+impl From<GetConsumerLag> for proto::GetConsumerLagRequest {
+    fn from(command: GetConsumerLag) -> Self {
+        Self {
+            consumer: Some((&command.consumer).into()),
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+        }
+    }
+}
+
+impl TryFrom<proto::GetConsumerLagRequest> for GetConsumerLag {
+    type Error = IggyError;
+
+    fn try_from(request: proto::GetConsumerLagRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            consumer: map_consumer(request.consumer)?,
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+        })
+    }
+}
+
+impl From<ConsumerLag> for proto::ConsumerLag {
+    fn from(consumer_lag: ConsumerLag) -> Self {
+        Self {
+            kind: consumer_lag.kind.as_code() as i32,
+            consumer_id: consumer_lag.consumer_id,
+            total_lag: consumer_lag.total_lag,
+            partitions: consumer_lag
+                .partitions
+                .into_iter()
+                .map(|partition| proto::PartitionLag {
+                    partition_id: partition.partition_id,
+                    stored_offset: partition.stored_offset,
+                    end_offset: partition.end_offset,
+                    lag: partition.lag,
+                    estimated_lag_time: partition.estimated_lag_time,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::ConsumerLag> for ConsumerLag {
+    type Error = IggyError;
+
+    fn try_from(consumer_lag: proto::ConsumerLag) -> Result<Self, Self::Error> {
+        Ok(Self {
+            kind: ConsumerKind::from_code(map_code(consumer_lag.kind)?)?,
+            consumer_id: consumer_lag.consumer_id,
+            total_lag: consumer_lag.total_lag,
+            partitions: consumer_lag
+                .partitions
+                .into_iter()
+                .map(|partition| PartitionLag {
+                    partition_id: partition.partition_id,
+                    stored_offset: partition.stored_offset,
+                    end_offset: partition.end_offset,
+                    lag: partition.lag,
+                    estimated_lag_time: partition.estimated_lag_time,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl From<OffsetResetStrategy> for proto::OffsetResetStrategy {
+    fn from(strategy: OffsetResetStrategy) -> Self {
+        let kind = match strategy {
+            OffsetResetStrategy::Earliest => {
+                proto::offset_reset_strategy::Kind::Earliest(proto::Empty {})
+            }
+            OffsetResetStrategy::Latest => {
+                proto::offset_reset_strategy::Kind::Latest(proto::Empty {})
+            }
+            OffsetResetStrategy::Timestamp(timestamp) => {
+                proto::offset_reset_strategy::Kind::Timestamp(timestamp)
+            }
+            OffsetResetStrategy::Offset(offset) => {
+                proto::offset_reset_strategy::Kind::Offset(offset)
+            }
+            OffsetResetStrategy::Shift(shift) => proto::offset_reset_strategy::Kind::Shift(shift),
+        };
+        Self { kind: Some(kind) }
+    }
+}
+
+impl TryFrom<proto::OffsetResetStrategy> for OffsetResetStrategy {
+    type Error = IggyError;
+
+    fn try_from(strategy: proto::OffsetResetStrategy) -> Result<Self, Self::Error> {
+        let strategy = match required(strategy.kind)? {
+            proto::offset_reset_strategy::Kind::Earliest(_) => OffsetResetStrategy::Earliest,
+            proto::offset_reset_strategy::Kind::Latest(_) => OffsetResetStrategy::Latest,
+            proto::offset_reset_strategy::Kind::Timestamp(timestamp) => {
+                OffsetResetStrategy::Timestamp(timestamp)
+            }
+            proto::offset_reset_strategy::Kind::Offset(offset) => {
+                OffsetResetStrategy::Offset(offset)
+            }
+            proto::offset_reset_strategy::Kind::Shift(shift) => OffsetResetStrategy::Shift(shift),
+        };
+        Ok(strategy)
+    }
+}
+
+impl From<ResetConsumerOffsets> for proto::ResetConsumerOffsetsRequest {
+    fn from(command: ResetConsumerOffsets) -> Self {
+        Self {
+            consumer: Some((&command.consumer).into()),
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            strategy: Some(command.strategy.into()),
+            dry_run: command.dry_run,
+        }
+    }
+}
+
+impl TryFrom<proto::ResetConsumerOffsetsRequest> for ResetConsumerOffsets {
+    type Error = IggyError;
+
+    fn try_from(request: proto::ResetConsumerOffsetsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            consumer: map_consumer(request.consumer)?,
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            strategy: required(request.strategy)?.try_into()?,
+            dry_run: request.dry_run,
+        })
+    }
+}
+
+impl From<ConsumerOffsetsReset> for proto::ConsumerOffsetsReset {
+    fn from(reset: ConsumerOffsetsReset) -> Self {
+        Self {
+            kind: reset.kind.as_code() as i32,
+            consumer_id: reset.consumer_id,
+            dry_run: reset.dry_run,
+            partitions: reset
+                .partitions
+                .into_iter()
+                .map(|partition| proto::PartitionOffsetReset {
+                    partition_id: partition.partition_id,
+                    previous_offset: partition.previous_offset,
+                    new_offset: partition.new_offset,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::ConsumerOffsetsReset> for ConsumerOffsetsReset {
+    type Error = IggyError;
+
+    fn try_from(reset: proto::ConsumerOffsetsReset) -> Result<Self, Self::Error> {
+        Ok(Self {
+            kind: ConsumerKind::from_code(map_code(reset.kind)?)?,
+            consumer_id: reset.consumer_id,
+            dry_run: reset.dry_run,
+            partitions: reset
+                .partitions
+                .into_iter()
+                .map(|partition| PartitionOffsetReset {
+                    partition_id: partition.partition_id,
+                    previous_offset: partition.previous_offset,
+                    new_offset: partition.new_offset,
+                })
+                .collect(),
+        })
+    }
+}
+
+// Consumer groups
+
+impl From<ConsumerGroup> for proto::ConsumerGroup {
+    fn from(consumer_group: ConsumerGroup) -> Self {
+        Self {
+            id: consumer_group.id,
+            name: consumer_group.name,
+            partitions_count: consumer_group.partitions_count,
+            members_count: consumer_group.members_count,
+        }
+    }
+}
+
+impl From<proto::ConsumerGroup> for ConsumerGroup {
+    fn from(consumer_group: proto::ConsumerGroup) -> Self {
+        Self {
+            id: consumer_group.id,
+            name: consumer_group.name,
+            partitions_count: consumer_group.partitions_count,
+            members_count: consumer_group.members_count,
+        }
+    }
+}
+
+impl From<ConsumerGroupDetails> for proto::ConsumerGroupDetails {
+    fn from(consumer_group: ConsumerGroupDetails) -> Self {
+        Self {
+            consumer_group: Some(proto::ConsumerGroup {
+                id: consumer_group.id,
+                name: consumer_group.name,
+                partitions_count: consumer_group.partitions_count,
+                members_count: consumer_group.members_count,
+            }),
+            members: consumer_group
+                .members
+                .into_iter()
+                .map(|member| proto::ConsumerGroupMember {
+                    id: member.id,
+                    partitions: member.partitions,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::ConsumerGroupDetails> for ConsumerGroupDetails {
+    type Error = IggyError;
+
+    fn try_from(consumer_group: proto::ConsumerGroupDetails) -> Result<Self, Self::Error> {
+        let members = consumer_group
+            .members
+            .into_iter()
+            .map(|member| ConsumerGroupMember {
+                id: member.id,
+                partitions_count: member.partitions.len() as u32,
+                partitions: member.partitions,
+            })
+            .collect();
+        let consumer_group = required(consumer_group.consumer_group)?;
+        Ok(Self {
+            id: consumer_group.id,
+            name: consumer_group.name,
+            partitions_count: consumer_group.partitions_count,
+            members_count: consumer_group.members_count,
+            members,
+        })
+    }
+}
+
+impl From<CreateConsumerGroup> for proto::CreateConsumerGroupRequest {
+    fn from(command: CreateConsumerGroup) -> Self {
+        Self {
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            group_id: command.group_id,
+            name: command.name,
+        }
+    }
+}
+
+impl TryFrom<proto::CreateConsumerGroupRequest> for CreateConsumerGroup {
+    type Error = IggyError;
+
+    fn try_from(request: proto::CreateConsumerGroupRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            group_id: request.group_id,
+            name: request.name,
+        })
+    }
+}
+
+// Schemas
+
+impl From<GetSchema> for proto::GetSchemaRequest {
+    fn from(command: GetSchema) -> Self {
+        Self {
+            schema_id: command.schema_id,
+        }
+    }
+}
+
+impl From<proto::GetSchemaRequest> for GetSchema {
+    fn from(request: proto::GetSchemaRequest) -> Self {
+        Self {
+            schema_id: request.schema_id,
+        }
+    }
+}
+
+impl From<Schema> for proto::Schema {
+    fn from(schema: Schema) -> Self {
+        Self {
+            id: schema.id,
+            stream_id: schema.stream_id,
+            topic_id: schema.topic_id,
+            version: schema.version,
+            schema_type: schema.schema_type.as_code() as i32,
+            compatibility: schema.compatibility.as_code() as i32,
+            validation_enabled: schema.validation_enabled,
+            created_at: schema.created_at,
+            definition: schema.definition,
+        }
+    }
+}
+
+impl TryFrom<proto::Schema> for Schema {
+    type Error = IggyError;
+
+    fn try_from(schema: proto::Schema) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: schema.id,
+            stream_id: schema.stream_id,
+            topic_id: schema.topic_id,
+            version: schema.version,
+            schema_type: SchemaType::from_code(map_code(schema.schema_type)?)?,
+            compatibility: SchemaCompatibility::from_code(map_code(schema.compatibility)?)?,
+            validation_enabled: schema.validation_enabled,
+            created_at: schema.created_at,
+            definition: schema.definition,
+        })
+    }
+}
+
+impl From<RegisterSchema> for proto::RegisterSchemaRequest {
+    fn from(command: RegisterSchema) -> Self {
+        Self {
+            stream_id: Some((&command.stream_id).into()),
+            topic_id: Some((&command.topic_id).into()),
+            schema_type: command.schema_type.as_code() as i32,
+            compatibility: command.compatibility.as_code() as i32,
+            validation_enabled: command.validation_enabled,
+            definition: command.definition,
+        }
+    }
+}
+
+impl TryFrom<proto::RegisterSchemaRequest> for RegisterSchema {
+    type Error = IggyError;
+
+    fn try_from(request: proto::RegisterSchemaRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            stream_id: map_identifier(request.stream_id)?,
+            topic_id: map_identifier(request.topic_id)?,
+            schema_type: SchemaType::from_code(map_code(request.schema_type)?)?,
+            compatibility: SchemaCompatibility::from_code(map_code(request.compatibility)?)?,
+            validation_enabled: request.validation_enabled,
+            definition: request.definition,
+        })
+    }
+}
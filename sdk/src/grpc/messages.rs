@@ -0,0 +1,143 @@
+use crate::client::MessageClient;
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::grpc::proto;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::{PollMessages, PollMessagesVersion, PollingStrategy};
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::appended_messages::AppendedMessages;
+use crate::models::messages::PolledMessages;
+use async_trait::async_trait;
+
+#[async_trait]
+impl MessageClient for GrpcClient {
+    async fn poll_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            PollMessagesVersion::V2,
+        )
+        .await
+    }
+
+    async fn poll_assigned_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+            PollMessagesVersion::V3,
+        )
+        .await
+    }
+
+    async fn send_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.send_messages_with_offsets(stream_id, topic_id, partitioning, messages, false)
+            .await?;
+        Ok(())
+    }
+
+    async fn send_durable_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.send_messages_with_offsets(stream_id, topic_id, partitioning, messages, true)
+            .await?;
+        Ok(())
+    }
+
+    async fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        wait_for_durability: bool,
+    ) -> Result<AppendedMessages, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = proto::SendMessagesRequest {
+            stream_id: Some(stream_id.into()),
+            topic_id: Some(topic_id.into()),
+            partitioning: Some(partitioning.into()),
+            messages: messages.iter().map(Into::into).collect(),
+            wait_for_durability,
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .send_messages(self.request(request))
+            .await
+            .map_err(map_status)?;
+        Ok(response.into_inner().into())
+    }
+}
+
+impl GrpcClient {
+    #[allow(clippy::too_many_arguments)]
+    async fn poll(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        version: PollMessagesVersion,
+    ) -> Result<PolledMessages, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = PollMessages {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            strategy: *strategy,
+            count,
+            auto_commit,
+            version,
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .poll_messages(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+}
@@ -0,0 +1,22 @@
+pub mod client;
+pub mod config;
+mod consumer_groups;
+mod consumer_offsets;
+pub(crate) mod mapper;
+mod messages;
+mod partitions;
+mod personal_access_tokens;
+mod schemas;
+mod streams;
+mod system;
+mod topics;
+mod users;
+
+/// The generated gRPC service definitions, shared by the client and the server.
+#[allow(clippy::all, missing_docs)]
+pub mod proto {
+    tonic::include_proto!("iggy");
+}
+
+/// The name of the status metadata holding the code of the `IggyError` returned by the server.
+pub const ERROR_CODE_METADATA: &str = "iggy-error-code";
@@ -0,0 +1,105 @@
+use crate::client::PartitionClient;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::identifier::Identifier;
+use crate::models::partition_offsets::PartitionOffsets;
+use crate::models::partition_truncation::PartitionTruncation;
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::truncate_partition::TruncatePartition;
+use async_trait::async_trait;
+
+#[async_trait]
+impl PartitionClient for GrpcClient {
+    async fn create_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = CreatePartitions {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partitions_count,
+        };
+        self.get_service_client()
+            .await?
+            .create_partitions(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn delete_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = DeletePartitions {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partitions_count,
+        };
+        self.get_service_client()
+            .await?
+            .delete_partitions(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn get_offsets_for_timestamp(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_ids: &[u32],
+        timestamp: u64,
+    ) -> Result<Vec<PartitionOffsets>, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetOffsetsForTimestamp {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            timestamp,
+            partition_ids: partition_ids.to_vec(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_offsets_for_timestamp(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(response
+            .into_inner()
+            .partitions
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn truncate_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<PartitionTruncation, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = TruncatePartition {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+            offset,
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .truncate_partition(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(response.into_inner().into())
+    }
+}
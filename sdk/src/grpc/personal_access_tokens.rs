@@ -0,0 +1,80 @@
+use crate::binary::ClientState;
+use crate::client::PersonalAccessTokenClient;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::grpc::proto;
+use crate::models::identity_info::IdentityInfo;
+use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use async_trait::async_trait;
+
+#[async_trait]
+impl PersonalAccessTokenClient for GrpcClient {
+    async fn get_personal_access_tokens(&self) -> Result<Vec<PersonalAccessTokenInfo>, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let response = self
+            .get_service_client()
+            .await?
+            .get_personal_access_tokens(self.request(proto::Empty {}))
+            .await
+            .map_err(map_status)?;
+        Ok(response
+            .into_inner()
+            .personal_access_tokens
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn create_personal_access_token(
+        &self,
+        name: &str,
+        expiry: PersonalAccessTokenExpiry,
+    ) -> Result<RawPersonalAccessToken, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = CreatePersonalAccessToken {
+            name: name.to_string(),
+            expiry: expiry.into(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .create_personal_access_token(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(response.into_inner().into())
+    }
+
+    async fn delete_personal_access_token(&self, name: &str) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = DeletePersonalAccessToken {
+            name: name.to_string(),
+        };
+        self.get_service_client()
+            .await?
+            .delete_personal_access_token(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn login_with_personal_access_token(
+        &self,
+        token: &str,
+    ) -> Result<IdentityInfo, IggyError> {
+        let request = LoginWithPersonalAccessToken {
+            token: token.to_string(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .login_with_personal_access_token(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        self.set_state(ClientState::Authenticated).await;
+        response.into_inner().try_into()
+    }
+}
@@ -0,0 +1,76 @@
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::identifier::Identifier;
+use crate::models::schema::Schema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
+use async_trait::async_trait;
+
+#[async_trait]
+impl SchemaClient for GrpcClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Schema, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let response = self
+            .get_service_client()
+            .await?
+            .get_schema(self.request(GetSchema { schema_id }.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetSchemas {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_schemas(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response
+            .into_inner()
+            .schemas
+            .into_iter()
+            .map(Schema::try_from)
+            .collect()
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        validation_enabled: bool,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = RegisterSchema {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            schema_type,
+            compatibility,
+            validation_enabled,
+            definition: definition.to_string(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .register_schema(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+}
@@ -0,0 +1,108 @@
+use crate::client::StreamClient;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::grpc::proto;
+use crate::identifier::Identifier;
+use crate::models::stream::{Stream, StreamDetails};
+use crate::models::topic_config_overrides::TopicConfigOverrides;
+use crate::streams::create_stream::CreateStream;
+use crate::streams::delete_stream::DeleteStream;
+use crate::streams::get_stream::GetStream;
+use crate::streams::purge_stream::PurgeStream;
+use crate::streams::update_stream::UpdateStream;
+use crate::topics::get_topic::TopicLayoutVersion;
+use async_trait::async_trait;
+
+#[async_trait]
+impl StreamClient for GrpcClient {
+    async fn get_stream(&self, stream_id: &Identifier) -> Result<StreamDetails, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetStream {
+            stream_id: stream_id.clone(),
+            version: TopicLayoutVersion::V2,
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_stream(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+
+    async fn get_streams(&self) -> Result<Vec<Stream>, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let response = self
+            .get_service_client()
+            .await?
+            .get_streams(self.request(proto::Empty {}))
+            .await
+            .map_err(map_status)?;
+        Ok(response
+            .into_inner()
+            .streams
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn create_stream(&self, name: &str, stream_id: Option<u32>) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = CreateStream {
+            stream_id,
+            name: name.to_string(),
+        };
+        self.get_service_client()
+            .await?
+            .create_stream(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn update_stream(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        topic_config_overrides: TopicConfigOverrides,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = UpdateStream {
+            stream_id: stream_id.clone(),
+            topic_config_overrides: Some(topic_config_overrides),
+            name: name.to_string(),
+        };
+        self.get_service_client()
+            .await?
+            .update_stream(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn delete_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = DeleteStream {
+            stream_id: stream_id.clone(),
+        };
+        self.get_service_client()
+            .await?
+            .delete_stream(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn purge_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = PurgeStream {
+            stream_id: stream_id.clone(),
+        };
+        self.get_service_client()
+            .await?
+            .purge_stream(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::client::SystemClient;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::grpc::proto;
+use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::stats::Stats;
+use crate::system::get_client::GetClient;
+use async_trait::async_trait;
+
+#[async_trait]
+impl SystemClient for GrpcClient {
+    async fn get_stats(&self) -> Result<Stats, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let response = self
+            .get_service_client()
+            .await?
+            .get_stats(self.request(proto::Empty {}))
+            .await
+            .map_err(map_status)?;
+        Ok(response.into_inner().into())
+    }
+
+    async fn get_me(&self) -> Result<ClientInfoDetails, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let response = self
+            .get_service_client()
+            .await?
+            .get_me(self.request(proto::Empty {}))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+
+    async fn get_client(&self, client_id: u32) -> Result<ClientInfoDetails, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let response = self
+            .get_service_client()
+            .await?
+            .get_client(self.request(GetClient { client_id }.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+
+    async fn get_clients(&self) -> Result<Vec<ClientInfo>, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let response = self
+            .get_service_client()
+            .await?
+            .get_clients(self.request(proto::Empty {}))
+            .await
+            .map_err(map_status)?;
+        Ok(response
+            .into_inner()
+            .clients
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    async fn ping(&self) -> Result<(), IggyError> {
+        self.get_service_client()
+            .await?
+            .ping(self.request(proto::Empty {}))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+use crate::client::TopicClient;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::identifier::Identifier;
+use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_config_overrides::TopicConfigOverrides;
+use crate::topics::create_topic::CreateTopic;
+use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::get_topic::{GetTopic, TopicLayoutVersion};
+use crate::topics::get_topics::GetTopics;
+use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::update_topic::UpdateTopic;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
+use crate::utils::expiry::IggyExpiry;
+use async_trait::async_trait;
+
+#[async_trait]
+impl TopicClient for GrpcClient {
+    async fn get_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<TopicDetails, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetTopic {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            version: TopicLayoutVersion::V2,
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_topic(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+
+    async fn get_topics(&self, stream_id: &Identifier) -> Result<Vec<Topic>, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetTopics {
+            stream_id: stream_id.clone(),
+            version: TopicLayoutVersion::V2,
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_topics(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response
+            .into_inner()
+            .topics
+            .into_iter()
+            .map(Topic::try_from)
+            .collect()
+    }
+
+    async fn create_topic(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        topic_id: Option<u32>,
+        message_expiry: IggyExpiry,
+        max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = CreateTopic {
+            stream_id: stream_id.clone(),
+            topic_id,
+            partitions_count,
+            compression_algorithm,
+            message_expiry: message_expiry.into(),
+            max_topic_size,
+            replication_factor,
+            durability,
+            config_overrides,
+            name: name.to_string(),
+        };
+        self.get_service_client()
+            .await?
+            .create_topic(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn update_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        name: &str,
+        compression_algorithm: CompressionAlgorithm,
+        replication_factor: Option<u8>,
+        message_expiry: IggyExpiry,
+        max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = UpdateTopic {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            compression_algorithm,
+            message_expiry: message_expiry.into(),
+            max_topic_size,
+            replication_factor,
+            durability,
+            config_overrides: Some(config_overrides),
+            name: name.to_string(),
+        };
+        self.get_service_client()
+            .await?
+            .update_topic(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn delete_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = DeleteTopic {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+        };
+        self.get_service_client()
+            .await?
+            .delete_topic(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn purge_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = PurgeTopic {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+        };
+        self.get_service_client()
+            .await?
+            .purge_topic(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+}
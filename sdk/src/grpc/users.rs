@@ -0,0 +1,170 @@
+use crate::binary::ClientState;
+use crate::client::UserClient;
+use crate::error::IggyError;
+use crate::grpc::client::{map_status, GrpcClient};
+use crate::grpc::proto;
+use crate::identifier::Identifier;
+use crate::models::identity_info::IdentityInfo;
+use crate::models::permissions::Permissions;
+use crate::models::user_info::{UserInfo, UserInfoDetails};
+use crate::models::user_status::UserStatus;
+use crate::users::change_password::ChangePassword;
+use crate::users::create_user::CreateUser;
+use crate::users::delete_user::DeleteUser;
+use crate::users::get_user::GetUser;
+use crate::users::login_user::LoginUser;
+use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_user::UpdateUser;
+use async_trait::async_trait;
+
+#[async_trait]
+impl UserClient for GrpcClient {
+    async fn get_user(&self, user_id: &Identifier) -> Result<UserInfoDetails, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = GetUser {
+            user_id: user_id.clone(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .get_user(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        response.into_inner().try_into()
+    }
+
+    async fn get_users(&self) -> Result<Vec<UserInfo>, IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let response = self
+            .get_service_client()
+            .await?
+            .get_users(self.request(proto::Empty {}))
+            .await
+            .map_err(map_status)?;
+        response
+            .into_inner()
+            .users
+            .into_iter()
+            .map(UserInfo::try_from)
+            .collect()
+    }
+
+    async fn create_user(
+        &self,
+        username: &str,
+        password: &str,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = CreateUser {
+            username: username.to_string(),
+            password: password.to_string(),
+            status,
+            permissions,
+        };
+        self.get_service_client()
+            .await?
+            .create_user(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn delete_user(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = DeleteUser {
+            user_id: user_id.clone(),
+        };
+        self.get_service_client()
+            .await?
+            .delete_user(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn update_user(
+        &self,
+        user_id: &Identifier,
+        username: Option<&str>,
+        status: Option<UserStatus>,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = UpdateUser {
+            user_id: user_id.clone(),
+            username: username.map(|username| username.to_string()),
+            status,
+        };
+        self.get_service_client()
+            .await?
+            .update_user(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn update_permissions(
+        &self,
+        user_id: &Identifier,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = UpdatePermissions {
+            user_id: user_id.clone(),
+            permissions,
+        };
+        self.get_service_client()
+            .await?
+            .update_permissions(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn change_password(
+        &self,
+        user_id: &Identifier,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        let request = ChangePassword {
+            user_id: user_id.clone(),
+            current_password: current_password.to_string(),
+            new_password: new_password.to_string(),
+        };
+        self.get_service_client()
+            .await?
+            .change_password(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        Ok(())
+    }
+
+    async fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError> {
+        let request = LoginUser {
+            username: username.to_string(),
+            password: password.to_string(),
+        };
+        let response = self
+            .get_service_client()
+            .await?
+            .login_user(self.request(request.into()))
+            .await
+            .map_err(map_status)?;
+        self.set_state(ClientState::Authenticated).await;
+        response.into_inner().try_into()
+    }
+
+    async fn logout_user(&self) -> Result<(), IggyError> {
+        self.fail_if_not_authenticated().await?;
+        self.get_service_client()
+            .await?
+            .logout_user(self.request(proto::Empty {}))
+            .await
+            .map_err(map_status)?;
+        self.set_state(ClientState::Connected).await;
+        Ok(())
+    }
+}
@@ -1,10 +1,13 @@
 use crate::client::Client;
 use crate::error::IggyError;
 use crate::http::config::HttpClientConfig;
+use crate::http::messages_format::MessagesFormat;
 use crate::http::HttpTransport;
 use crate::locking::{IggySharedMut, IggySharedMutFn};
 use crate::models::identity_info::IdentityInfo;
 use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
 use reqwest::{Response, Url};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
@@ -28,6 +31,7 @@ pub struct HttpClient {
     /// The URL of the Iggy API.
     pub api_url: Url,
     client: ClientWithMiddleware,
+    pub(crate) messages_format: MessagesFormat,
     access_token: IggySharedMut<String>,
     refresh_token: IggySharedMut<String>,
 }
@@ -94,6 +98,27 @@ impl HttpTransport for HttpClient {
         Self::handle_response(response).await
     }
 
+    /// Invoke HTTP GET request to the Iggy API with query parameters, accepting the response of the provided content type.
+    async fn get_with_query_accepting<T: Serialize + Sync + ?Sized>(
+        &self,
+        path: &str,
+        query: &T,
+        accept: &str,
+    ) -> Result<Response, IggyError> {
+        let url = self.get_url(path)?;
+        self.fail_if_not_authenticated(path).await?;
+        let token = self.access_token.read().await;
+        let response = self
+            .client
+            .get(url)
+            .bearer_auth(token.deref())
+            .header(ACCEPT, accept)
+            .query(query)
+            .send()
+            .await?;
+        Self::handle_response(response).await
+    }
+
     /// Invoke HTTP POST request to the Iggy API.
     async fn post<T: Serialize + Sync + ?Sized>(
         &self,
@@ -113,6 +138,27 @@ impl HttpTransport for HttpClient {
         Self::handle_response(response).await
     }
 
+    /// Invoke HTTP POST request to the Iggy API with the raw payload of the provided content type.
+    async fn post_bytes(
+        &self,
+        path: &str,
+        content_type: &str,
+        payload: Bytes,
+    ) -> Result<Response, IggyError> {
+        let url = self.get_url(path)?;
+        self.fail_if_not_authenticated(path).await?;
+        let token = self.access_token.read().await;
+        let response = self
+            .client
+            .post(url)
+            .bearer_auth(token.deref())
+            .header(CONTENT_TYPE, content_type)
+            .body(payload)
+            .send()
+            .await?;
+        Self::handle_response(response).await
+    }
+
     /// Invoke HTTP PUT request to the Iggy API.
     async fn put<T: Serialize + Sync + ?Sized>(
         &self,
@@ -259,6 +305,7 @@ impl HttpClient {
         Ok(Self {
             api_url,
             client,
+            messages_format: config.messages_format,
             access_token: IggySharedMut::new("".to_string()),
             refresh_token: IggySharedMut::new("".to_string()),
         })
@@ -1,3 +1,5 @@
+use crate::http::messages_format::MessagesFormat;
+
 /// Configuration for the HTTP client.
 #[derive(Debug, Clone)]
 pub struct HttpClientConfig {
@@ -5,6 +7,8 @@ pub struct HttpClientConfig {
     pub api_url: String,
     /// The number of retries to perform on transient errors.
     pub retries: u32,
+    /// The format of the messages sent and polled using the HTTP API.
+    pub messages_format: MessagesFormat,
 }
 
 impl Default for HttpClientConfig {
@@ -12,6 +16,7 @@ impl Default for HttpClientConfig {
         HttpClientConfig {
             api_url: "http://127.0.0.1:3000".to_string(),
             retries: 3,
+            messages_format: MessagesFormat::default(),
         }
     }
 }
@@ -20,6 +25,7 @@ impl Default for HttpClientConfig {
 /// Allows configuring the HTTP client with custom settings or using defaults:
 /// - `api_url`: Default is "http://127.0.0.1:3000"
 /// - `retries`: Default is 3.
+/// - `messages_format`: Default is `Json`.
 #[derive(Debug, Default)]
 pub struct HttpClientConfigBuilder {
     config: HttpClientConfig,
@@ -43,6 +49,12 @@ impl HttpClientConfigBuilder {
         self
     }
 
+    /// Sets the format of the messages sent and polled using the HTTP client.
+    pub fn with_messages_format(mut self, messages_format: MessagesFormat) -> Self {
+        self.config.messages_format = messages_format;
+        self
+    }
+
     /// Builds the `HttpClientConfig` instance.
     pub fn build(self) -> HttpClientConfig {
         self.config
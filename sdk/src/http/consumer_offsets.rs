@@ -1,12 +1,16 @@
 use crate::client::ConsumerOffsetClient;
-use crate::consumer::Consumer;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::{OffsetResetStrategy, ResetConsumerOffsets};
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offsets_reset::ConsumerOffsetsReset;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -54,8 +58,87 @@ impl ConsumerOffsetClient for HttpClient {
         let offset = response.json().await?;
         Ok(offset)
     }
+
+    async fn get_consumer_lag(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ConsumerLag, IggyError> {
+        let stream_id = stream_id.as_cow_str();
+        let topic_id = topic_id.as_cow_str();
+        let response = match consumer.kind {
+            ConsumerKind::Consumer => {
+                self.get_with_query(
+                    &get_lag_path(&stream_id, &topic_id),
+                    &GetConsumerLag {
+                        consumer: consumer.clone(),
+                        ..Default::default()
+                    },
+                )
+                .await?
+            }
+            ConsumerKind::ConsumerGroup => {
+                self.get(&get_group_lag_path(
+                    &stream_id,
+                    &topic_id,
+                    &consumer.id.as_cow_str(),
+                ))
+                .await?
+            }
+        };
+        let lag = response.json().await?;
+        Ok(lag)
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        strategy: OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerOffsetsReset, IggyError> {
+        let stream_id = stream_id.as_cow_str();
+        let topic_id = topic_id.as_cow_str();
+        let path = match consumer.kind {
+            ConsumerKind::Consumer => get_reset_path(&stream_id, &topic_id),
+            ConsumerKind::ConsumerGroup => {
+                get_group_reset_path(&stream_id, &topic_id, &consumer.id.as_cow_str())
+            }
+        };
+        let response = self
+            .post(
+                &path,
+                &ResetConsumerOffsets {
+                    consumer: consumer.clone(),
+                    strategy,
+                    dry_run,
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let consumer_offsets_reset = response.json().await?;
+        Ok(consumer_offsets_reset)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/consumer-offsets")
 }
+
+fn get_lag_path(stream_id: &str, topic_id: &str) -> String {
+    format!("{}/lag", get_path(stream_id, topic_id))
+}
+
+fn get_group_lag_path(stream_id: &str, topic_id: &str, group_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}/lag")
+}
+
+fn get_reset_path(stream_id: &str, topic_id: &str) -> String {
+    format!("{}/reset", get_path(stream_id, topic_id))
+}
+
+fn get_group_reset_path(stream_id: &str, topic_id: &str, group_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}/reset-offsets")
+}
@@ -1,13 +1,23 @@
+use crate::binary::mapper;
 use crate::client::MessageClient;
 use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
+use crate::http::messages_format::{
+    MessagesFormat, BINARY_CONTENT_TYPE, CURRENT_OFFSET_HEADER, MSGPACK_CONTENT_TYPE,
+    PARTITION_ID_HEADER,
+};
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
-use crate::messages::poll_messages::{PollMessages, PollingStrategy};
-use crate::messages::send_messages::{Message, Partitioning, SendMessages};
-use crate::models::messages::PolledMessages;
+use crate::messages::poll_messages::{PollMessages, PollMessagesVersion, PollingStrategy};
+use crate::messages::send_messages;
+use crate::messages::send_messages::{Message, Partitioning, SendMessages, SendMessagesVersion};
+use crate::models::appended_messages::AppendedMessages;
+use crate::models::messages::{PolledMessage, PolledMessages};
 use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use reqwest::Response;
+use std::str::FromStr;
 
 #[async_trait]
 impl MessageClient for HttpClient {
@@ -22,7 +32,7 @@ impl MessageClient for HttpClient {
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError> {
         let response = self
-            .get_with_query(
+            .get_with_query_accepting(
                 &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
                 &PollMessages {
                     stream_id: stream_id.clone(),
@@ -32,11 +42,41 @@ impl MessageClient for HttpClient {
                     strategy: *strategy,
                     count,
                     auto_commit,
+                    version: PollMessagesVersion::default(),
                 },
+                self.messages_format.as_content_type(),
             )
             .await?;
-        let messages = response.json().await?;
-        Ok(messages)
+        match self.messages_format {
+            MessagesFormat::Json => Ok(response.json().await?),
+            MessagesFormat::Binary => mapper::map_polled_messages(response.bytes().await?),
+            MessagesFormat::MessagePack => rmp_serde::from_slice(&response.bytes().await?)
+                .map_err(|error| IggyError::CannotDeserializeMessages(error.to_string())),
+            MessagesFormat::Ndjson => map_ndjson_polled_messages(response).await,
+        }
+    }
+
+    async fn poll_assigned_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        // The HTTP API polls the messages only as the regular consumer, which always uses the provided partition ID.
+        self.poll_messages(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit,
+        )
+        .await
     }
 
     async fn send_messages(
@@ -46,18 +86,134 @@ impl MessageClient for HttpClient {
         partitioning: &Partitioning,
         messages: &mut [Message],
     ) -> Result<(), IggyError> {
-        self.post(
-            &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
-            &SendMessages {
-                stream_id: stream_id.clone(),
-                topic_id: topic_id.clone(),
-                partitioning: partitioning.clone(),
-                messages: messages.to_vec(),
-            },
-        )
-        .await?;
+        self.send(stream_id, topic_id, partitioning, messages, false)
+            .await?;
         Ok(())
     }
+
+    async fn send_durable_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.send(stream_id, topic_id, partitioning, messages, true)
+            .await?;
+        Ok(())
+    }
+
+    async fn send_messages_with_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [Message],
+        wait_for_durability: bool,
+    ) -> Result<AppendedMessages, IggyError> {
+        let response = self
+            .send(
+                stream_id,
+                topic_id,
+                partitioning,
+                messages,
+                wait_for_durability,
+            )
+            .await?;
+        Ok(response.json().await?)
+    }
+}
+
+impl HttpClient {
+    async fn send(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &[Message],
+        wait_for_durability: bool,
+    ) -> Result<Response, IggyError> {
+        let path = get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str());
+        match self.messages_format {
+            MessagesFormat::Binary => {
+                let payload = send_messages::as_bytes(stream_id, topic_id, partitioning, messages);
+                let path = format!("{path}?wait_for_durability={wait_for_durability}");
+                self.post_bytes(&path, BINARY_CONTENT_TYPE, payload).await
+            }
+            MessagesFormat::MessagePack => {
+                let payload = rmp_serde::to_vec_named(&SendMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partitioning: partitioning.clone(),
+                    messages: messages.to_vec(),
+                    wait_for_durability,
+                    version: SendMessagesVersion::default(),
+                })
+                .map_err(|error| IggyError::CannotSerializeMessages(error.to_string()))?;
+                self.post_bytes(&path, MSGPACK_CONTENT_TYPE, Bytes::from(payload))
+                    .await
+            }
+            MessagesFormat::Json | MessagesFormat::Ndjson => {
+                self.post(
+                    &path,
+                    &SendMessages {
+                        stream_id: stream_id.clone(),
+                        topic_id: topic_id.clone(),
+                        partitioning: partitioning.clone(),
+                        messages: messages.to_vec(),
+                        wait_for_durability,
+                        version: SendMessagesVersion::default(),
+                    },
+                )
+                .await
+            }
+        }
+    }
+}
+
+async fn map_ndjson_polled_messages(mut response: Response) -> Result<PolledMessages, IggyError> {
+    let partition_id = get_header_value(&response, PARTITION_ID_HEADER)?;
+    let current_offset = get_header_value(&response, CURRENT_OFFSET_HEADER)?;
+    let mut messages = Vec::new();
+    let mut buffer = BytesMut::new();
+    while let Some(chunk) = response.chunk().await? {
+        buffer.extend_from_slice(&chunk);
+        while let Some(position) = buffer.iter().position(|byte| *byte == b'\n') {
+            let line = buffer.split_to(position + 1);
+            map_ndjson_polled_message(&line[..position], &mut messages)?;
+        }
+    }
+    map_ndjson_polled_message(&buffer, &mut messages)?;
+
+    Ok(PolledMessages {
+        partition_id,
+        current_offset,
+        messages,
+    })
+}
+
+fn map_ndjson_polled_message(
+    line: &[u8],
+    messages: &mut Vec<PolledMessage>,
+) -> Result<(), IggyError> {
+    if line.iter().all(u8::is_ascii_whitespace) {
+        return Ok(());
+    }
+
+    let mut message: PolledMessage = serde_json::from_slice(line)
+        .map_err(|error| IggyError::CannotDeserializeMessages(error.to_string()))?;
+    message.length = message.payload.len() as u32;
+    messages.push(message);
+    Ok(())
+}
+
+fn get_header_value<T: FromStr>(response: &Response, name: &str) -> Result<T, IggyError> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| IggyError::CannotDeserializeMessages(format!("missing {name} header")))
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
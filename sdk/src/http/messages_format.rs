@@ -0,0 +1,141 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The content type of the JSON messages, the payloads are encoded as base64 strings.
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+/// The content type of the native binary messages, the same format as used by the TCP and QUIC transports.
+pub const BINARY_CONTENT_TYPE: &str = "application/octet-stream";
+/// The content type of the MessagePack messages, the payloads are encoded as raw bytes.
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+/// The content type of the newline delimited JSON messages, streamed as one message per line.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// The name of the response header holding the partition ID of the messages polled in the `Ndjson` format.
+pub const PARTITION_ID_HEADER: &str = "iggy-partition-id";
+/// The name of the response header holding the current offset of the partition for the messages polled in the `Ndjson` format.
+pub const CURRENT_OFFSET_HEADER: &str = "iggy-current-offset";
+
+/// The format of the messages sent and polled using the HTTP API, negotiated via the `Content-Type` and `Accept` headers.
+/// - `Json` - the default format, the whole batch as a single JSON document.
+/// - `Binary` - the native binary format, the same as used by the TCP and QUIC transports.
+/// - `MessagePack` - the MessagePack format, with the same structure as the JSON one.
+/// - `Ndjson` - the chunked newline delimited JSON, one polled message per line, only available for polling (sending uses JSON).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessagesFormat {
+    /// The whole batch as a single JSON document.
+    #[default]
+    Json,
+    /// The native binary format, the same as used by the TCP and QUIC transports.
+    Binary,
+    /// The MessagePack format, with the same structure as the JSON one.
+    MessagePack,
+    /// The chunked newline delimited JSON, one polled message per line.
+    Ndjson,
+}
+
+impl MessagesFormat {
+    /// Returns the content type of the format.
+    pub fn as_content_type(&self) -> &'static str {
+        match self {
+            MessagesFormat::Json => JSON_CONTENT_TYPE,
+            MessagesFormat::Binary => BINARY_CONTENT_TYPE,
+            MessagesFormat::MessagePack => MSGPACK_CONTENT_TYPE,
+            MessagesFormat::Ndjson => NDJSON_CONTENT_TYPE,
+        }
+    }
+
+    /// Resolves the format from the value of the `Content-Type` or `Accept` header, the parameters such as `charset` are ignored.
+    pub fn from_content_type(content_type: &str) -> Result<Self, IggyError> {
+        let media_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        match media_type.as_str() {
+            "" | "*/*" | "application/*" | JSON_CONTENT_TYPE => Ok(MessagesFormat::Json),
+            BINARY_CONTENT_TYPE => Ok(MessagesFormat::Binary),
+            MSGPACK_CONTENT_TYPE | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Ok(MessagesFormat::MessagePack)
+            }
+            NDJSON_CONTENT_TYPE | "application/jsonl" => Ok(MessagesFormat::Ndjson),
+            _ => Err(IggyError::UnsupportedContentType(content_type.to_string())),
+        }
+    }
+
+    /// Resolves the preferred format from the value of the `Accept` header, which might contain multiple media types.
+    /// The first supported media type is used, falling back to `Json` if none of them is supported.
+    pub fn from_accept(accept: &str) -> Self {
+        accept
+            .split(',')
+            .find_map(|media_type| Self::from_content_type(media_type).ok())
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for MessagesFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(MessagesFormat::Json),
+            "binary" => Ok(MessagesFormat::Binary),
+            "message_pack" | "msgpack" => Ok(MessagesFormat::MessagePack),
+            "ndjson" => Ok(MessagesFormat::Ndjson),
+            _ => Err(format!("Unknown messages format: {}", s)),
+        }
+    }
+}
+
+impl Display for MessagesFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessagesFormat::Json => write!(f, "json"),
+            MessagesFormat::Binary => write!(f, "binary"),
+            MessagesFormat::MessagePack => write!(f, "message_pack"),
+            MessagesFormat::Ndjson => write!(f, "ndjson"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_should_be_resolved_from_content_type() {
+        assert_eq!(
+            MessagesFormat::from_content_type("application/json; charset=utf-8").unwrap(),
+            MessagesFormat::Json
+        );
+        assert_eq!(
+            MessagesFormat::from_content_type("application/octet-stream").unwrap(),
+            MessagesFormat::Binary
+        );
+        assert_eq!(
+            MessagesFormat::from_content_type("application/x-msgpack").unwrap(),
+            MessagesFormat::MessagePack
+        );
+        assert_eq!(
+            MessagesFormat::from_content_type("Application/X-NDJSON").unwrap(),
+            MessagesFormat::Ndjson
+        );
+        assert!(MessagesFormat::from_content_type("text/plain").is_err());
+    }
+
+    #[test]
+    fn format_should_be_resolved_from_accept_header() {
+        assert_eq!(
+            MessagesFormat::from_accept("text/html, application/msgpack;q=0.9, */*;q=0.8"),
+            MessagesFormat::MessagePack
+        );
+        assert_eq!(MessagesFormat::from_accept("*/*"), MessagesFormat::Json);
+        assert_eq!(
+            MessagesFormat::from_accept("text/html"),
+            MessagesFormat::Json
+        );
+    }
+}
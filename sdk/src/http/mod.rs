@@ -1,6 +1,7 @@
 use crate::error::IggyError;
 use crate::models::identity_info::IdentityInfo;
 use async_trait::async_trait;
+use bytes::Bytes;
 use reqwest::{Response, Url};
 use serde::Serialize;
 
@@ -10,8 +11,10 @@ pub mod config;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod messages;
+pub mod messages_format;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod streams;
 pub mod system;
 pub mod topics;
@@ -32,6 +35,14 @@ pub trait HttpTransport {
         query: &T,
     ) -> Result<Response, IggyError>;
 
+    /// Invoke HTTP GET request to the Iggy API with query parameters, accepting the response of the provided content type.
+    async fn get_with_query_accepting<T: Serialize + Sync + ?Sized>(
+        &self,
+        path: &str,
+        query: &T,
+        accept: &str,
+    ) -> Result<Response, IggyError>;
+
     /// Invoke HTTP POST request to the Iggy API.
     async fn post<T: Serialize + Sync + ?Sized>(
         &self,
@@ -39,6 +50,14 @@ pub trait HttpTransport {
         payload: &T,
     ) -> Result<Response, IggyError>;
 
+    /// Invoke HTTP POST request to the Iggy API with the raw payload of the provided content type.
+    async fn post_bytes(
+        &self,
+        path: &str,
+        content_type: &str,
+        payload: Bytes,
+    ) -> Result<Response, IggyError>;
+
     /// Invoke HTTP PUT request to the Iggy API.
     async fn put<T: Serialize + Sync + ?Sized>(
         &self,
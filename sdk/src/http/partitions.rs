@@ -3,8 +3,12 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::models::partition_offsets::PartitionOffsets;
+use crate::models::partition_truncation::PartitionTruncation;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use crate::partitions::truncate_partition::TruncatePartition;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -44,8 +48,64 @@ impl PartitionClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn get_offsets_for_timestamp(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_ids: &[u32],
+        timestamp: u64,
+    ) -> Result<Vec<PartitionOffsets>, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_offsets_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &GetOffsetsForTimestamp {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    timestamp,
+                    partition_ids: partition_ids.to_vec(),
+                },
+            )
+            .await?;
+        let offsets = response.json().await?;
+        Ok(offsets)
+    }
+
+    async fn truncate_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<PartitionTruncation, IggyError> {
+        let response = self
+            .post(
+                &get_truncate_path(
+                    &stream_id.as_cow_str(),
+                    &topic_id.as_cow_str(),
+                    partition_id,
+                ),
+                &TruncatePartition {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    offset,
+                },
+            )
+            .await?;
+        let truncation = response.json().await?;
+        Ok(truncation)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/partitions")
 }
+
+fn get_offsets_path(stream_id: &str, topic_id: &str) -> String {
+    format!("{}/offsets", get_path(stream_id, topic_id))
+}
+
+fn get_truncate_path(stream_id: &str, topic_id: &str, partition_id: u32) -> String {
+    format!("{}/{partition_id}/truncate", get_path(stream_id, topic_id))
+}
@@ -0,0 +1,61 @@
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::HttpTransport;
+use crate::identifier::Identifier;
+use crate::models::schema::Schema;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
+use async_trait::async_trait;
+
+#[async_trait]
+impl SchemaClient for HttpClient {
+    async fn get_schema(&self, schema_id: u32) -> Result<Schema, IggyError> {
+        let response = self.get(&format!("schemas/{schema_id}")).await?;
+        let schema = response.json().await?;
+        Ok(schema)
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError> {
+        let response = self
+            .get(&get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()))
+            .await?;
+        let schemas = response.json().await?;
+        Ok(schemas)
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        validation_enabled: bool,
+        definition: &str,
+    ) -> Result<Schema, IggyError> {
+        let response = self
+            .post(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &RegisterSchema {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    schema_type,
+                    compatibility,
+                    validation_enabled,
+                    definition: definition.to_string(),
+                },
+            )
+            .await?;
+        let schema = response.json().await?;
+        Ok(schema)
+    }
+}
+
+fn get_path(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/schemas")
+}
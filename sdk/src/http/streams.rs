@@ -4,6 +4,7 @@ use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
 use crate::models::stream::{Stream, StreamDetails};
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::update_stream::UpdateStream;
 use async_trait::async_trait;
@@ -36,11 +37,17 @@ impl StreamClient for HttpClient {
         Ok(())
     }
 
-    async fn update_stream(&self, stream_id: &Identifier, name: &str) -> Result<(), IggyError> {
+    async fn update_stream(
+        &self,
+        stream_id: &Identifier,
+        name: &str,
+        topic_config_overrides: TopicConfigOverrides,
+    ) -> Result<(), IggyError> {
         self.put(
             &get_details_path(&stream_id.as_cow_str()),
             &UpdateStream {
                 stream_id: stream_id.clone(),
+                topic_config_overrides: Some(topic_config_overrides),
                 name: name.to_string(),
             },
         )
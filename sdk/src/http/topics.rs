@@ -5,9 +5,11 @@ use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::update_topic::UpdateTopic;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
 use crate::utils::expiry::IggyExpiry;
 use async_trait::async_trait;
 
@@ -44,6 +46,8 @@ impl TopicClient for HttpClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError> {
         self.post(
             &get_path(&stream_id.as_cow_str()),
@@ -56,6 +60,8 @@ impl TopicClient for HttpClient {
                 topic_id,
                 message_expiry: message_expiry.into(),
                 max_topic_size,
+                durability,
+                config_overrides,
             },
         )
         .await?;
@@ -71,6 +77,8 @@ impl TopicClient for HttpClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: Option<IggyByteSize>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError> {
         self.put(
             &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
@@ -82,6 +90,8 @@ impl TopicClient for HttpClient {
                 replication_factor,
                 message_expiry: message_expiry.into(),
                 max_topic_size,
+                durability,
+                config_overrides: Some(config_overrides),
             },
         )
         .await?;
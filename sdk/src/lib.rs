@@ -11,12 +11,15 @@ pub mod client_error;
 pub mod client_provider;
 #[allow(deprecated)]
 pub mod clients;
+pub mod codecs;
 pub mod command;
 pub mod compression;
 pub mod consumer;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod error;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod http;
 pub mod identifier;
 pub mod locking;
@@ -27,10 +30,13 @@ pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod quic;
+pub mod schemas;
 pub mod streams;
 pub mod system;
 pub mod tcp;
 pub mod topics;
+#[cfg(unix)]
+pub mod unix;
 pub mod users;
 pub mod utils;
 pub mod validatable;
@@ -0,0 +1,141 @@
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::utils::duration::IggyDuration;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// The name of the message header holding the timestamp (in microseconds) at which the message should be delivered.
+pub const DELIVER_AT_HEADER: &str = "iggy-deliver-at";
+
+/// The name of the message header holding the delay (in microseconds) after which the message should be delivered.
+pub const DELAY_HEADER: &str = "iggy-delay";
+
+/// `DeliverySchedule` defines when the message sent by the producer becomes visible to the consumers.
+/// Until then, the message is held by the server and it's not returned by `poll_messages`.
+/// It's propagated through the message headers and has the following variants:
+/// - `At`: the message is delivered at the given timestamp (in microseconds).
+/// - `After`: the message is delivered after the given delay, counted from the time it was received by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliverySchedule {
+    /// Deliver the message at the given timestamp (in microseconds).
+    At(u64),
+    /// Deliver the message after the given delay, counted from the time it was received by the server.
+    After(IggyDuration),
+}
+
+impl DeliverySchedule {
+    /// Returns the timestamp (in microseconds) at which the message should be delivered, relative to the provided current time.
+    pub fn get_deliver_at(&self, now: u64) -> u64 {
+        match self {
+            DeliverySchedule::At(timestamp) => *timestamp,
+            DeliverySchedule::After(delay) => now.saturating_add(delay.as_micros()),
+        }
+    }
+
+    /// Inject the delivery schedule into the headers, replacing the existing schedule headers if present.
+    pub fn inject(
+        &self,
+        headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<(), IggyError> {
+        let deliver_at_key = HeaderKey::new(DELIVER_AT_HEADER)?;
+        let delay_key = HeaderKey::new(DELAY_HEADER)?;
+        let headers = headers.get_or_insert_with(HashMap::new);
+        headers.remove(&deliver_at_key);
+        headers.remove(&delay_key);
+        match self {
+            DeliverySchedule::At(timestamp) => {
+                headers.insert(deliver_at_key, HeaderValue::from_uint64(*timestamp)?)
+            }
+            DeliverySchedule::After(delay) => {
+                headers.insert(delay_key, HeaderValue::from_uint64(delay.as_micros())?)
+            }
+        };
+        Ok(())
+    }
+
+    /// Extract the delivery schedule from the headers, if any of the schedule headers is present.
+    /// The `iggy-deliver-at` header takes precedence over the `iggy-delay` one.
+    pub fn extract(
+        headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<Option<Self>, IggyError> {
+        let Some(headers) = headers.as_ref() else {
+            return Ok(None);
+        };
+
+        if let Some(value) = headers.get(&HeaderKey::new(DELIVER_AT_HEADER)?) {
+            let timestamp = value
+                .as_uint64()
+                .map_err(|_| IggyError::InvalidMessageDeliverySchedule)?;
+            return Ok(Some(DeliverySchedule::At(timestamp)));
+        }
+
+        if let Some(value) = headers.get(&HeaderKey::new(DELAY_HEADER)?) {
+            let delay = value
+                .as_uint64()
+                .map_err(|_| IggyError::InvalidMessageDeliverySchedule)?;
+            return Ok(Some(DeliverySchedule::After(IggyDuration::new(
+                Duration::from_micros(delay),
+            ))));
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn deliver_at_should_be_resolved_for_both_variants() {
+        let now = 1_000_000;
+        assert_eq!(
+            DeliverySchedule::At(5_000_000).get_deliver_at(now),
+            5_000_000
+        );
+        assert_eq!(
+            DeliverySchedule::After(IggyDuration::from_str("2s").unwrap()).get_deliver_at(now),
+            3_000_000
+        );
+    }
+
+    #[test]
+    fn delivery_schedule_should_be_injected_and_extracted_from_headers() {
+        let mut headers = None;
+        let delay = DeliverySchedule::After(IggyDuration::from_str("10s").unwrap());
+        delay.inject(&mut headers).unwrap();
+        assert_eq!(DeliverySchedule::extract(&headers).unwrap(), Some(delay));
+
+        let deliver_at = DeliverySchedule::At(123_456_789);
+        deliver_at.inject(&mut headers).unwrap();
+        assert_eq!(headers.as_ref().unwrap().len(), 1);
+        assert_eq!(
+            DeliverySchedule::extract(&headers).unwrap(),
+            Some(deliver_at)
+        );
+    }
+
+    #[test]
+    fn invalid_delivery_schedule_header_should_not_be_extracted() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderKey::new(DELIVER_AT_HEADER).unwrap(),
+            HeaderValue::from_str("tomorrow").unwrap(),
+        );
+
+        assert!(matches!(
+            DeliverySchedule::extract(&Some(headers)),
+            Err(IggyError::InvalidMessageDeliverySchedule)
+        ));
+    }
+
+    #[test]
+    fn missing_headers_should_not_have_delivery_schedule() {
+        assert_eq!(DeliverySchedule::extract(&None).unwrap(), None);
+        assert_eq!(
+            DeliverySchedule::extract(&Some(HashMap::new())).unwrap(),
+            None
+        );
+    }
+}
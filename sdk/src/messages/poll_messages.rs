@@ -15,10 +15,11 @@ use std::str::FromStr;
 /// - `consumer` - consumer which will poll messages. Either regular consumer or consumer group.
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
-/// - `partition_id` - partition ID from which messages will be polled. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
+/// - `partition_id` - partition ID from which messages will be polled. Has to be specified for the regular consumer. For consumer group it is ignored and the partition is chosen by the server, unless polled with `V3`, which requires the partition to be assigned to the member if specified.
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `version` - version of the command, which determines the binary layout of the polled messages, determined by the command code.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -30,7 +31,7 @@ pub struct PollMessages {
     /// Unique topic ID (numeric or name).
     #[serde(skip)]
     pub topic_id: Identifier,
-    /// Partition ID from which messages will be polled. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
+    /// Partition ID from which messages will be polled. Has to be specified for the regular consumer. For consumer group it is ignored and the partition is chosen by the server, unless polled with `V3`, which requires the partition to be assigned to the member if specified.
     #[serde(default = "default_partition_id")]
     pub partition_id: Option<u32>,
     /// Polling strategy which specifies from where to start polling messages.
@@ -42,6 +43,25 @@ pub struct PollMessages {
     #[serde(default)]
     /// Whether to commit offset on the server automatically after polling the messages.
     pub auto_commit: bool,
+    /// Version of the command, which determines the binary layout of the polled messages, determined by the command code.
+    #[serde(skip)]
+    pub version: PollMessagesVersion,
+}
+
+/// `PollMessagesVersion` is the version of `PollMessages` command, which determines the binary layout of the returned messages.
+/// It has the following versions:
+/// - `V1` - the original layout: offset, state, timestamp, ID, checksum, headers and payload.
+/// - `V2` - the layout extended with the messages key before the payload.
+/// - `V3` - the `V2` layout, and the partition ID of the consumer group has to be assigned to the member instead of being ignored.
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub enum PollMessagesVersion {
+    /// The original layout: offset, state, timestamp, ID, checksum, headers and payload.
+    V1,
+    /// The layout extended with the messages key before the payload.
+    #[default]
+    V2,
+    /// The `V2` layout, and the partition ID of the consumer group has to be assigned to the member instead of being ignored.
+    V3,
 }
 
 /// `PollingStrategy` specifies from where to start polling messages.
@@ -91,6 +111,7 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            version: PollMessagesVersion::default(),
         }
     }
 }
@@ -285,6 +306,7 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            version: PollMessagesVersion::default(),
         };
         command.validate()?;
         Ok(command)
@@ -394,6 +416,7 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            version: PollMessagesVersion::default(),
         };
 
         let bytes = command.as_bytes();
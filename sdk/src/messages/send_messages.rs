@@ -2,13 +2,16 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::messages::delivery_schedule::DeliverySchedule;
+use crate::messages::trace_context::TraceContext;
 use crate::messages::{MAX_HEADERS_SIZE, MAX_PAYLOAD_SIZE};
 use crate::models::header;
 use crate::models::header::{HeaderKey, HeaderValue};
+use crate::utils::base64_or_bytes::Base64OrBytes;
+use crate::utils::duration::IggyDuration;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
-use serde_with::base64::Base64;
 use serde_with::serde_as;
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -23,6 +26,8 @@ const EMPTY_KEY_VALUE: Vec<u8> = vec![];
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitioning` - to which partition the messages should be sent - either provided by the client or calculated by the server.
 /// - `messages` - collection of messages to be sent.
+/// - `wait_for_durability` - whether the messages should be acknowledged only once they are durably stored on disk.
+/// - `version` - version of the binary layout of the messages, determined by the command code.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct SendMessages {
     /// Unique stream ID (numeric or name).
@@ -35,6 +40,25 @@ pub struct SendMessages {
     pub partitioning: Partitioning,
     /// Collection of messages to be sent.
     pub messages: Vec<Message>,
+    /// Whether the messages should be acknowledged only once they are durably stored on disk.
+    #[serde(default)]
+    pub wait_for_durability: bool,
+    /// Version of the binary layout of the messages, determined by the command code.
+    #[serde(skip)]
+    pub version: SendMessagesVersion,
+}
+
+/// `SendMessagesVersion` is the version of the binary layout of the messages sent by `SendMessages` command.
+/// It has the following versions:
+/// - `V1` - the original layout: ID, headers and payload, answered with the empty response.
+/// - `V2` - the layout extended with the event timestamp after the ID, answered with the appended messages (partition ID and offsets).
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub enum SendMessagesVersion {
+    /// The original layout: ID, headers and payload.
+    V1,
+    /// The layout extended with the event timestamp after the ID.
+    #[default]
+    V2,
 }
 
 /// `Partitioning` is used to specify to which partition the messages should be sent.
@@ -50,13 +74,15 @@ pub struct Partitioning {
     #[serde(skip)]
     /// The length of the value payload.
     pub length: u8,
-    #[serde_as(as = "Base64")]
+    #[serde_as(as = "Base64OrBytes")]
     /// The binary value payload.
     pub value: Vec<u8>,
 }
 
 /// The single message to be sent. It has the following payload:
 /// - `id` - unique message ID, if not specified by the client (has value = 0), it will be generated by the server.
+/// - `timestamp` - optional event timestamp in microseconds, if not specified by the client (has value = 0), the append time is used.
+///   It's stored only if the topic uses the `CreateTime` timestamp type.
 /// - `length` - length of the payload.
 /// - `payload` - binary message payload.
 /// - `headers` - optional collection of headers.
@@ -66,10 +92,13 @@ pub struct Message {
     /// Unique message ID, if not specified by the client (has value = 0), it will be generated by the server.
     #[serde(default = "default_message_id")]
     pub id: u128,
+    /// Optional event timestamp in microseconds, if not specified by the client (has value = 0), the append time is used.
+    #[serde(default)]
+    pub timestamp: u64,
     #[serde(skip)]
     /// Length of the payload.
     pub length: u32,
-    #[serde_as(as = "Base64")]
+    #[serde_as(as = "Base64OrBytes")]
     /// Binary message payload.
     pub payload: Bytes,
     /// Optional collection of headers.
@@ -100,6 +129,8 @@ impl Default for SendMessages {
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
             messages: vec![Message::default()],
+            wait_for_durability: false,
+            version: SendMessagesVersion::default(),
         }
     }
 }
@@ -191,6 +222,16 @@ impl Partitioning {
     }
 }
 
+impl SendMessages {
+    /// Set the W3C trace context in the `traceparent` header of all the messages.
+    pub fn set_trace_context(&mut self, trace_context: &TraceContext) -> Result<(), IggyError> {
+        for message in &mut self.messages {
+            message.set_trace_context(trace_context)?;
+        }
+        Ok(())
+    }
+}
+
 impl CommandPayload for SendMessages {}
 
 impl Validatable<IggyError> for SendMessages {
@@ -261,6 +302,7 @@ impl Message {
     ) -> Self {
         Message {
             id: id.unwrap_or(0),
+            timestamp: 0,
             #[allow(clippy::cast_possible_truncation)]
             length: payload.len() as u32,
             payload,
@@ -268,10 +310,129 @@ impl Message {
         }
     }
 
+    /// Set the W3C trace context in the `traceparent` header, so that the message can be traced by the consumer.
+    pub fn set_trace_context(&mut self, trace_context: &TraceContext) -> Result<(), IggyError> {
+        trace_context.inject(&mut self.headers)
+    }
+
+    /// Get the W3C trace context from the `traceparent` header, if present.
+    pub fn get_trace_context(&self) -> Option<TraceContext> {
+        TraceContext::extract(&self.headers)
+    }
+
+    /// Set the timestamp (in microseconds) in the `iggy-deliver-at` header, so that the message is hidden from the consumers until then.
+    pub fn set_deliver_at(&mut self, deliver_at: u64) -> Result<(), IggyError> {
+        DeliverySchedule::At(deliver_at).inject(&mut self.headers)
+    }
+
+    /// Set the delay in the `iggy-delay` header, so that the message is hidden from the consumers until it elapses.
+    pub fn set_delay(&mut self, delay: IggyDuration) -> Result<(), IggyError> {
+        DeliverySchedule::After(delay).inject(&mut self.headers)
+    }
+
+    /// Get the delivery schedule from the `iggy-deliver-at` or `iggy-delay` header, if present.
+    pub fn get_delivery_schedule(&self) -> Result<Option<DeliverySchedule>, IggyError> {
+        DeliverySchedule::extract(&self.headers)
+    }
+
+    /// Set the event timestamp of the message in microseconds.
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
     /// Get the size of the message in bytes.
     pub fn get_size_bytes(&self) -> u32 {
-        // ID + Length + Payload + Headers
-        16 + 4 + self.payload.len() as u32 + header::get_headers_size_bytes(&self.headers)
+        self.get_size_bytes_for_version(SendMessagesVersion::V2)
+    }
+
+    /// Get the size of the message in bytes using the binary layout of the provided version.
+    pub fn get_size_bytes_for_version(&self, version: SendMessagesVersion) -> u32 {
+        // ID + Timestamp (since V2) + Length + Payload + Headers
+        let timestamp_size = match version {
+            SendMessagesVersion::V1 => 0,
+            SendMessagesVersion::V2 => 8,
+        };
+        16 + timestamp_size
+            + 4
+            + self.payload.len() as u32
+            + header::get_headers_size_bytes(&self.headers)
+    }
+
+    /// Serialize the message using the binary layout of the provided version.
+    pub fn as_bytes_for_version(&self, version: SendMessagesVersion) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(self.get_size_bytes_for_version(version) as usize);
+        bytes.put_u128_le(self.id);
+        if version == SendMessagesVersion::V2 {
+            bytes.put_u64_le(self.timestamp);
+        }
+        if let Some(headers) = &self.headers {
+            let headers_bytes = headers.as_bytes();
+            bytes.put_u32_le(headers_bytes.len() as u32);
+            bytes.put_slice(&headers_bytes);
+        } else {
+            bytes.put_u32_le(0);
+        }
+        bytes.put_u32_le(self.length);
+        bytes.put_slice(&self.payload);
+        bytes.freeze()
+    }
+
+    /// Deserialize the message using the binary layout of the provided version, the `V1` messages have no event timestamp.
+    pub fn from_bytes_for_version(
+        bytes: Bytes,
+        version: SendMessagesVersion,
+    ) -> Result<Self, IggyError> {
+        let (timestamp, position) = match version {
+            SendMessagesVersion::V1 => (0, 16),
+            SendMessagesVersion::V2 => {
+                if bytes.len() < 24 {
+                    return Err(IggyError::InvalidCommand);
+                }
+                (u64::from_le_bytes(bytes[16..24].try_into()?), 24)
+            }
+        };
+        if bytes.len() < position + 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut id = u128::from_le_bytes(bytes[..16].try_into()?);
+        if id == 0 {
+            id = Uuid::new_v4().to_u128_le();
+        }
+        let headers_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+        let position = position + 4;
+        if bytes.len() < position + headers_length + 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let headers = if headers_length > 0 {
+            Some(HashMap::from_bytes(
+                bytes.slice(position..position + headers_length),
+            )?)
+        } else {
+            None
+        };
+
+        let position = position + headers_length;
+        let payload_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        if payload_length == 0 {
+            return Err(IggyError::EmptyMessagePayload);
+        }
+
+        let position = position + 4;
+        if bytes.len() < position + payload_length as usize {
+            return Err(IggyError::InvalidMessagePayloadLength);
+        }
+
+        let payload = bytes.slice(position..position + payload_length as usize);
+        Ok(Message {
+            id,
+            timestamp,
+            length: payload_length,
+            payload,
+            headers,
+        })
     }
 }
 
@@ -280,6 +441,7 @@ impl Default for Message {
         let payload = Bytes::from("hello world");
         Message {
             id: 1,
+            timestamp: 0,
             length: payload.len() as u32,
             payload,
             headers: None,
@@ -339,58 +501,11 @@ impl BytesSerializable for Partitioning {
 
 impl BytesSerializable for Message {
     fn as_bytes(&self) -> Bytes {
-        let mut bytes = BytesMut::with_capacity(self.get_size_bytes() as usize);
-        bytes.put_u128_le(self.id);
-        if let Some(headers) = &self.headers {
-            let headers_bytes = headers.as_bytes();
-            bytes.put_u32_le(headers_bytes.len() as u32);
-            bytes.put_slice(&headers_bytes);
-        } else {
-            bytes.put_u32_le(0);
-        }
-        bytes.put_u32_le(self.length);
-        bytes.put_slice(&self.payload);
-        bytes.freeze()
+        self.as_bytes_for_version(SendMessagesVersion::V2)
     }
 
     fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
-        if bytes.len() < 24 {
-            return Err(IggyError::InvalidCommand);
-        }
-
-        let mut id = u128::from_le_bytes(bytes[..16].try_into()?);
-        if id == 0 {
-            id = Uuid::new_v4().to_u128_le();
-        }
-        let headers_length = u32::from_le_bytes(bytes[16..20].try_into()?);
-        let headers = if headers_length > 0 {
-            Some(HashMap::from_bytes(
-                bytes.slice(20..20 + headers_length as usize),
-            )?)
-        } else {
-            None
-        };
-
-        let payload_length = u32::from_le_bytes(
-            bytes[20 + headers_length as usize..24 + headers_length as usize].try_into()?,
-        );
-        if payload_length == 0 {
-            return Err(IggyError::EmptyMessagePayload);
-        }
-
-        let payload = bytes.slice(
-            24 + headers_length as usize..24 + headers_length as usize + payload_length as usize,
-        );
-        if payload.len() != payload_length as usize {
-            return Err(IggyError::InvalidMessagePayloadLength);
-        }
-
-        Ok(Message {
-            id,
-            length: payload_length,
-            payload,
-            headers,
-        })
+        Self::from_bytes_for_version(bytes, SendMessagesVersion::V2)
     }
 }
 
@@ -401,7 +516,26 @@ pub(crate) fn as_bytes(
     partitioning: &Partitioning,
     messages: &[Message],
 ) -> Bytes {
-    let messages_size = messages.iter().map(Message::get_size_bytes).sum::<u32>();
+    as_bytes_for_version(
+        stream_id,
+        topic_id,
+        partitioning,
+        messages,
+        SendMessagesVersion::V2,
+    )
+}
+
+fn as_bytes_for_version(
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    partitioning: &Partitioning,
+    messages: &[Message],
+    version: SendMessagesVersion,
+) -> Bytes {
+    let messages_size = messages
+        .iter()
+        .map(|message| message.get_size_bytes_for_version(version))
+        .sum::<u32>();
     let key_bytes = partitioning.as_bytes();
     let stream_id_bytes = stream_id.as_bytes();
     let topic_id_bytes = topic_id.as_bytes();
@@ -412,7 +546,7 @@ pub(crate) fn as_bytes(
     bytes.put_slice(&topic_id_bytes);
     bytes.put_slice(&key_bytes);
     for message in messages {
-        bytes.put_slice(&message.as_bytes());
+        bytes.put_slice(&message.as_bytes_for_version(version));
     }
 
     bytes.freeze()
@@ -430,6 +564,7 @@ impl FromStr for Message {
 
         Ok(Message {
             id,
+            timestamp: 0,
             length,
             payload,
             headers: None,
@@ -437,17 +572,12 @@ impl FromStr for Message {
     }
 }
 
-impl BytesSerializable for SendMessages {
-    fn as_bytes(&self) -> Bytes {
-        as_bytes(
-            &self.stream_id,
-            &self.topic_id,
-            &self.partitioning,
-            &self.messages,
-        )
-    }
-
-    fn from_bytes(bytes: Bytes) -> Result<SendMessages, IggyError> {
+impl SendMessages {
+    /// Deserialize the command with the messages in the binary layout of the provided version.
+    pub fn from_bytes_for_version(
+        bytes: Bytes,
+        version: SendMessagesVersion,
+    ) -> Result<SendMessages, IggyError> {
         if bytes.len() < 11 {
             return Err(IggyError::InvalidCommand);
         }
@@ -463,8 +593,9 @@ impl BytesSerializable for SendMessages {
         position = 0;
         let mut messages = Vec::new();
         while position < messages_payloads.len() {
-            let message = Message::from_bytes(messages_payloads.slice(position..))?;
-            position += message.get_size_bytes() as usize;
+            let message =
+                Message::from_bytes_for_version(messages_payloads.slice(position..), version)?;
+            position += message.get_size_bytes_for_version(version) as usize;
             messages.push(message);
         }
 
@@ -473,12 +604,30 @@ impl BytesSerializable for SendMessages {
             topic_id,
             partitioning: key,
             messages,
+            wait_for_durability: false,
+            version,
         };
         command.validate()?;
         Ok(command)
     }
 }
 
+impl BytesSerializable for SendMessages {
+    fn as_bytes(&self) -> Bytes {
+        as_bytes_for_version(
+            &self.stream_id,
+            &self.topic_id,
+            &self.partitioning,
+            &self.messages,
+            self.version,
+        )
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SendMessages, IggyError> {
+        Self::from_bytes_for_version(bytes, SendMessagesVersion::V2)
+    }
+}
+
 impl Display for SendMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -538,6 +687,8 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
             messages,
+            wait_for_durability: false,
+            version: SendMessagesVersion::V2,
         };
 
         let bytes = command.as_bytes();
@@ -616,6 +767,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn message_timestamp_should_be_serialized_and_deserialized() {
+        let message = Message::new(Some(1), "hello".into(), None).with_timestamp(1_000_000);
+
+        let bytes = message.as_bytes();
+        let deserialized_message = Message::from_bytes(bytes.clone()).unwrap();
+
+        assert_eq!(bytes.len(), message.get_size_bytes() as usize);
+        assert_eq!(deserialized_message.id, message.id);
+        assert_eq!(deserialized_message.timestamp, 1_000_000);
+        assert_eq!(deserialized_message.payload, message.payload);
+    }
+
+    #[test]
+    fn v1_messages_should_be_serialized_and_deserialized_without_timestamp() {
+        let message = Message::new(Some(1), "hello".into(), None).with_timestamp(1_000_000);
+
+        let bytes = message.as_bytes_for_version(SendMessagesVersion::V1);
+        let deserialized_message =
+            Message::from_bytes_for_version(bytes.clone(), SendMessagesVersion::V1).unwrap();
+
+        assert_eq!(bytes.len(), message.get_size_bytes() as usize - 8);
+        assert_eq!(deserialized_message.id, message.id);
+        assert_eq!(deserialized_message.timestamp, 0);
+        assert_eq!(deserialized_message.payload, message.payload);
+    }
+
     #[test]
     fn key_of_type_balanced_should_have_empty_value() {
         let key = Partitioning::balanced();
@@ -0,0 +1,180 @@
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The name of the message header holding the W3C trace context.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+const VERSION: u8 = 0;
+const SAMPLED_FLAG: u8 = 1;
+
+/// `TraceContext` represents the W3C trace context (`traceparent`), which can be propagated through the message headers,
+/// so that the message can be traced from the producer through the server to the consumer.
+/// It has the following fields:
+/// - `trace_id`: the ID of the whole trace, shared by all the spans.
+/// - `span_id`: the ID of the parent span, e.g. the one which has sent the message.
+/// - `sampled`: whether the trace is sampled (recorded) or not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceContext {
+    /// The ID of the whole trace, shared by all the spans.
+    pub trace_id: u128,
+    /// The ID of the parent span.
+    pub span_id: u64,
+    /// Whether the trace is sampled (recorded) or not.
+    pub sampled: bool,
+}
+
+impl TraceContext {
+    /// Create a new trace context with the randomly generated trace ID and span ID.
+    pub fn new() -> Self {
+        TraceContext {
+            trace_id: uuid::Uuid::new_v4().as_u128(),
+            span_id: uuid::Uuid::new_v4().as_u64_pair().0,
+            sampled: true,
+        }
+    }
+
+    /// Create a child trace context, sharing the same trace ID and having a new span ID.
+    pub fn child(&self) -> Self {
+        TraceContext {
+            trace_id: self.trace_id,
+            span_id: uuid::Uuid::new_v4().as_u64_pair().0,
+            sampled: self.sampled,
+        }
+    }
+
+    /// Inject the trace context into the headers, replacing the existing `traceparent` header if present.
+    pub fn inject(
+        &self,
+        headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<(), IggyError> {
+        let key = HeaderKey::new(TRACEPARENT_HEADER)?;
+        let value = HeaderValue::from_str(&self.to_string())?;
+        headers.get_or_insert_with(HashMap::new).insert(key, value);
+        Ok(())
+    }
+
+    /// Extract the trace context from the headers, if the valid `traceparent` header is present.
+    pub fn extract(headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> Option<Self> {
+        let headers = headers.as_ref()?;
+        let key = HeaderKey::new(TRACEPARENT_HEADER).ok()?;
+        let value = headers.get(&key)?.as_str().ok()?;
+        TraceContext::from_str(value).ok()
+    }
+}
+
+impl Default for TraceContext {
+    fn default() -> Self {
+        TraceContext::new()
+    }
+}
+
+impl FromStr for TraceContext {
+    type Err = IggyError;
+
+    /// Parse the `traceparent` value in the format: `{version}-{trace_id}-{span_id}-{flags}`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.trim().split('-').collect::<Vec<&str>>();
+        if parts.len() != 4
+            || parts[0].len() != 2
+            || parts[1].len() != 32
+            || parts[2].len() != 16
+            || parts[3].len() != 2
+        {
+            return Err(IggyError::InvalidTraceContext);
+        }
+
+        let version =
+            u8::from_str_radix(parts[0], 16).map_err(|_| IggyError::InvalidTraceContext)?;
+        // The version 0xff is forbidden by the specification.
+        if version == 0xff {
+            return Err(IggyError::InvalidTraceContext);
+        }
+
+        let trace_id =
+            u128::from_str_radix(parts[1], 16).map_err(|_| IggyError::InvalidTraceContext)?;
+        let span_id =
+            u64::from_str_radix(parts[2], 16).map_err(|_| IggyError::InvalidTraceContext)?;
+        let flags = u8::from_str_radix(parts[3], 16).map_err(|_| IggyError::InvalidTraceContext)?;
+        if trace_id == 0 || span_id == 0 {
+            return Err(IggyError::InvalidTraceContext);
+        }
+
+        Ok(TraceContext {
+            trace_id,
+            span_id,
+            sampled: flags & SAMPLED_FLAG == SAMPLED_FLAG,
+        })
+    }
+}
+
+impl Display for TraceContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let flags = if self.sampled { SAMPLED_FLAG } else { 0 };
+        write!(
+            f,
+            "{:02x}-{:032x}-{:016x}-{:02x}",
+            VERSION, self.trace_id, self.span_id, flags
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_context_should_be_formatted_as_traceparent() {
+        let trace_context = TraceContext {
+            trace_id: 0x4bf92f3577b34da6a3ce929d0e0e4736,
+            span_id: 0x00f067aa0ba902b7,
+            sampled: true,
+        };
+
+        assert_eq!(
+            trace_context.to_string(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+    }
+
+    #[test]
+    fn traceparent_should_be_parsed() {
+        let trace_context =
+            TraceContext::from_str("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00")
+                .unwrap();
+
+        assert_eq!(trace_context.trace_id, 0x4bf92f3577b34da6a3ce929d0e0e4736);
+        assert_eq!(trace_context.span_id, 0x00f067aa0ba902b7);
+        assert!(!trace_context.sampled);
+    }
+
+    #[test]
+    fn invalid_traceparent_should_not_be_parsed() {
+        let inputs = [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e47zz-00f067aa0ba902b7-01",
+        ];
+
+        for input in inputs {
+            assert!(TraceContext::from_str(input).is_err());
+        }
+    }
+
+    #[test]
+    fn trace_context_should_be_injected_and_extracted_from_headers() {
+        let trace_context = TraceContext::new();
+        let mut headers = None;
+
+        trace_context.inject(&mut headers).unwrap();
+        let extracted = TraceContext::extract(&headers).unwrap();
+
+        assert_eq!(extracted, trace_context);
+        assert_eq!(trace_context.child().trace_id, trace_context.trace_id);
+    }
+}
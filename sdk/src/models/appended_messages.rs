@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+/// `AppendedMessages` represents the result of sending the batch of messages to the partition.
+/// The appended messages get the consecutive offsets starting from the base offset, in the order they were sent.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition the messages were appended to.
+/// - `base_offset`: the offset assigned to the first appended message.
+/// - `messages_count`: the number of the appended messages.
+/// - `skipped_indexes`: the indexes of the sent messages which were not appended (yet), either scheduled for the delayed delivery, or ignored as duplicates.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AppendedMessages {
+    /// The unique identifier of the partition the messages were appended to.
+    pub partition_id: u32,
+    /// The offset assigned to the first appended message.
+    pub base_offset: u64,
+    /// The number of the appended messages.
+    pub messages_count: u32,
+    /// The indexes of the sent messages which were not appended (yet), sorted in the ascending order.
+    #[serde(default)]
+    pub skipped_indexes: Vec<u32>,
+}
+
+impl AppendedMessages {
+    /// Returns the offset assigned to the sent message at the given index, or `None` if the message was not appended.
+    pub fn get_offset(&self, index: u32) -> Option<u64> {
+        let skipped_before = match self.skipped_indexes.binary_search(&index) {
+            Ok(_) => return None,
+            Err(skipped_before) => skipped_before as u32,
+        };
+        let appended_index = index - skipped_before;
+        if appended_index >= self.messages_count {
+            return None;
+        }
+
+        Some(self.base_offset + appended_index as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_should_be_assigned_to_appended_messages_only() {
+        let appended_messages = AppendedMessages {
+            partition_id: 1,
+            base_offset: 10,
+            messages_count: 3,
+            skipped_indexes: vec![1, 3],
+        };
+        assert_eq!(appended_messages.get_offset(0), Some(10));
+        assert_eq!(appended_messages.get_offset(1), None);
+        assert_eq!(appended_messages.get_offset(2), Some(11));
+        assert_eq!(appended_messages.get_offset(3), None);
+        assert_eq!(appended_messages.get_offset(4), Some(12));
+        assert_eq!(appended_messages.get_offset(5), None);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::consumer::ConsumerKind;
+use serde::{Deserialize, Serialize};
+
+/// `ConsumerLag` represents how far the consumer or consumer group is behind the end of the topic.
+/// It consists of the following fields:
+/// - `kind`: the kind of the consumer, either the regular consumer or the consumer group.
+/// - `consumer_id`: the unique identifier of the consumer or consumer group.
+/// - `total_lag`: the sum of the lags across all the partitions of the topic.
+/// - `partitions`: the lag for each partition of the topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ConsumerLag {
+    /// The kind of the consumer, either the regular consumer or the consumer group.
+    pub kind: ConsumerKind,
+    /// The unique identifier of the consumer or consumer group.
+    pub consumer_id: u32,
+    /// The sum of the lags (number of messages) across all the partitions of the topic.
+    pub total_lag: u64,
+    /// The lag for each partition of the topic.
+    pub partitions: Vec<PartitionLag>,
+}
+
+/// `PartitionLag` represents the lag of the consumer or consumer group in a single partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `stored_offset`: the offset stored by the consumer in the partition, if any.
+/// - `end_offset`: the offset following the last message in the partition (exclusive).
+/// - `lag`: the number of messages which were not consumed yet.
+/// - `estimated_lag_time`: the estimated time (in microseconds) between the first not consumed message and the last message.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PartitionLag {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset stored by the consumer in the partition, if any.
+    pub stored_offset: Option<u64>,
+    /// The offset following the last message in the partition (exclusive).
+    pub end_offset: u64,
+    /// The number of messages which were not consumed yet.
+    pub lag: u64,
+    /// The estimated time (in microseconds) between the first not consumed message and the last message, based on the time indexes.
+    pub estimated_lag_time: u64,
+}
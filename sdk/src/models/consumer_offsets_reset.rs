@@ -0,0 +1,36 @@
+use crate::consumer::ConsumerKind;
+use serde::{Deserialize, Serialize};
+
+/// `ConsumerOffsetsReset` represents the result of resetting the offsets of the consumer or consumer group.
+/// It consists of the following fields:
+/// - `kind`: the kind of the consumer, either the regular consumer or the consumer group.
+/// - `consumer_id`: the unique identifier of the consumer or consumer group.
+/// - `dry_run`: whether the new offsets were only calculated, without being stored.
+/// - `partitions`: the previous and new offset for each partition of the topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ConsumerOffsetsReset {
+    /// The kind of the consumer, either the regular consumer or the consumer group.
+    pub kind: ConsumerKind,
+    /// The unique identifier of the consumer or consumer group.
+    pub consumer_id: u32,
+    /// Whether the new offsets were only calculated, without being stored.
+    pub dry_run: bool,
+    /// The previous and new offset for each partition of the topic.
+    pub partitions: Vec<PartitionOffsetReset>,
+}
+
+/// `PartitionOffsetReset` represents the offset reset of the consumer or consumer group in a single partition.
+/// The offsets are the stored ones (the last consumed message), so the consumption continues from the following offset.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `previous_offset`: the offset stored before the reset, if any.
+/// - `new_offset`: the offset stored after the reset, if any (no offset means consuming from the first message).
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PartitionOffsetReset {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset stored before the reset, if any.
+    pub previous_offset: Option<u64>,
+    /// The offset stored after the reset, if any (no offset means consuming from the first message).
+    pub new_offset: Option<u64>,
+}
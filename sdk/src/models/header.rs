@@ -1,8 +1,8 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::error::IggyError;
+use crate::utils::base64_or_bytes::Base64OrBytes;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
-use serde_with::base64::Base64;
 use serde_with::serde_as;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
@@ -63,7 +63,7 @@ pub struct HeaderValue {
     /// The kind of the header value.
     pub kind: HeaderKind,
     /// The binary value of the header payload.
-    #[serde_as(as = "Base64")]
+    #[serde_as(as = "Base64OrBytes")]
     pub value: Bytes,
 }
 
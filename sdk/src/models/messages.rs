@@ -1,10 +1,13 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::error::IggyError;
+use crate::messages::delivery_schedule::DeliverySchedule;
+use crate::messages::poll_messages::PollMessagesVersion;
+use crate::messages::trace_context::TraceContext;
 use crate::models::header;
 use crate::models::header::{HeaderKey, HeaderValue};
+use crate::utils::base64_or_bytes::Base64OrBytes;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
-use serde_with::base64::Base64;
 use serde_with::serde_as;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
@@ -35,6 +38,7 @@ pub struct PolledMessages {
 /// - `id`: the identifier of the message.
 /// - `checksum`: the checksum of the message, can be used to verify the integrity of the message.
 /// - `headers`: the optional headers of the message.
+/// - `key`: the optional messages key used to route the message to the partition.
 /// - `length`: the length of the payload.
 /// - `payload`: the binary payload of the message.
 #[serde_as]
@@ -52,11 +56,15 @@ pub struct PolledMessage {
     pub checksum: u32,
     /// The optional headers of the message.
     pub headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    /// The optional messages key used to route the message to the partition.
+    #[serde_as(as = "Option<Base64OrBytes>")]
+    #[serde(default)]
+    pub key: Option<Bytes>,
     /// The length of the payload.
     #[serde(skip)]
     pub length: u32,
     /// The binary payload of the message.
-    #[serde_as(as = "Base64")]
+    #[serde_as(as = "Base64OrBytes")]
     pub payload: Bytes,
 }
 
@@ -155,17 +163,60 @@ impl PolledMessage {
             length: payload.len() as u32,
             payload,
             headers,
+            key: None,
+        }
+    }
+
+    /// Sets the messages key used to route the message to the partition.
+    pub fn with_key(mut self, key: Option<Bytes>) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Returns the W3C trace context propagated by the producer in the `traceparent` header, if present.
+    pub fn get_trace_context(&self) -> Option<TraceContext> {
+        TraceContext::extract(&self.headers)
+    }
+
+    /// Returns the timestamp (in microseconds) at which the delayed or scheduled message was delivered, if present.
+    pub fn get_deliver_at(&self) -> Option<u64> {
+        match DeliverySchedule::extract(&self.headers) {
+            Ok(Some(DeliverySchedule::At(deliver_at))) => Some(deliver_at),
+            _ => None,
         }
     }
 
     /// Returns the size of the message in bytes.
     pub fn get_size_bytes(&self) -> u32 {
-        // Offset + State + Timestamp + ID + Checksum + Length + Payload + Headers
-        8 + 1 + 8 + 16 + 4 + 4 + self.length + header::get_headers_size_bytes(&self.headers)
+        self.get_size_bytes_for_version(PollMessagesVersion::V2)
+    }
+
+    /// Returns the size of the message in bytes using the binary layout of the provided version.
+    pub fn get_size_bytes_for_version(&self, version: PollMessagesVersion) -> u32 {
+        // Offset + State + Timestamp + ID + Checksum + Length + Payload + Headers + Key (since V2)
+        let key_size = match version {
+            PollMessagesVersion::V1 => 0,
+            PollMessagesVersion::V2 | PollMessagesVersion::V3 => {
+                1 + self.key.as_ref().map_or(0, |key| key.len() as u32)
+            }
+        };
+        8 + 1
+            + 8
+            + 16
+            + 4
+            + 4
+            + self.length
+            + header::get_headers_size_bytes(&self.headers)
+            + key_size
     }
 
     /// Extends the provided bytes with the message.
     pub fn extend(&self, bytes: &mut BytesMut) {
+        self.extend_for_version(bytes, PollMessagesVersion::V2);
+    }
+
+    /// Extends the provided bytes with the message using the binary layout of the provided version.
+    pub fn extend_for_version(&self, bytes: &mut BytesMut, version: PollMessagesVersion) {
         bytes.put_u64_le(self.offset);
         bytes.put_u8(self.state.as_code());
         bytes.put_u64_le(self.timestamp);
@@ -179,7 +230,75 @@ impl PolledMessage {
         } else {
             bytes.put_u32_le(0u32);
         }
+        if version != PollMessagesVersion::V1 {
+            if let Some(key) = &self.key {
+                #[allow(clippy::cast_possible_truncation)]
+                bytes.put_u8(key.len() as u8);
+                bytes.put_slice(key);
+            } else {
+                bytes.put_u8(0);
+            }
+        }
         bytes.put_u32_le(self.length);
         bytes.put_slice(&self.payload);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v1_message_should_be_extended_in_original_layout_without_key() {
+        let message = PolledMessage::create(
+            5,
+            MessageState::Available,
+            1_000_000,
+            1,
+            Bytes::from("hello"),
+            123,
+            None,
+        )
+        .with_key(Some(Bytes::from("key")));
+
+        let mut bytes = BytesMut::new();
+        message.extend_for_version(&mut bytes, PollMessagesVersion::V1);
+
+        // The layout read by the clients released before the messages key was returned on poll.
+        let mut expected_bytes = BytesMut::new();
+        expected_bytes.put_u64_le(5);
+        expected_bytes.put_u8(MessageState::Available.as_code());
+        expected_bytes.put_u64_le(1_000_000);
+        expected_bytes.put_u128_le(1);
+        expected_bytes.put_u32_le(123);
+        expected_bytes.put_u32_le(0);
+        expected_bytes.put_u32_le(5);
+        expected_bytes.put_slice(b"hello");
+        assert_eq!(bytes, expected_bytes);
+        assert_eq!(
+            message.get_size_bytes_for_version(PollMessagesVersion::V1) as usize,
+            bytes.len()
+        );
+    }
+
+    #[test]
+    fn v2_message_should_be_extended_with_key() {
+        let message = PolledMessage::create(
+            5,
+            MessageState::Available,
+            1_000_000,
+            1,
+            Bytes::from("hello"),
+            123,
+            None,
+        )
+        .with_key(Some(Bytes::from("key")));
+
+        let mut bytes = BytesMut::new();
+        message.extend(&mut bytes);
+
+        assert_eq!(message.get_size_bytes() as usize, bytes.len());
+        assert_eq!(bytes[41], 3);
+        assert_eq!(&bytes[42..45], b"key");
+    }
+}
@@ -1,14 +1,21 @@
+pub mod appended_messages;
 pub mod client_info;
 pub mod consumer_group;
+pub mod consumer_lag;
 pub mod consumer_offset_info;
+pub mod consumer_offsets_reset;
 pub mod header;
 pub mod identity_info;
 pub mod messages;
 pub mod partition;
+pub mod partition_offsets;
+pub mod partition_truncation;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod schema;
 pub mod stats;
 pub mod stream;
 pub mod topic;
+pub mod topic_config_overrides;
 pub mod user_info;
 pub mod user_status;
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// `PartitionOffsets` represents the offsets of the partition resolved for the given timestamp.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `first_offset`: the offset of the first message available in the partition, if any.
+/// - `last_offset`: the offset of the last message in the partition, if any.
+/// - `offset`: the offset of the first message with the timestamp greater than or equal to the requested one, if any.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PartitionOffsets {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset of the first message available in the partition, if any.
+    pub first_offset: Option<u64>,
+    /// The offset of the last message in the partition, if any.
+    pub last_offset: Option<u64>,
+    /// The offset of the first message with the timestamp greater than or equal to the requested one, if any.
+    pub offset: Option<u64>,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// `PartitionTruncation` represents the result of deleting the messages before the given offset in a partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `low_watermark`: the offset of the first message available in the partition after the truncation.
+/// - `deleted_segments`: the number of segments deleted, as they contained only the truncated messages.
+/// - `deleted_messages`: the number of messages removed together with the deleted segments.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PartitionTruncation {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset of the first message available in the partition after the truncation.
+    pub low_watermark: u64,
+    /// The number of segments deleted, as they contained only the truncated messages.
+    pub deleted_segments: u32,
+    /// The number of messages removed together with the deleted segments.
+    pub deleted_messages: u64,
+}
@@ -0,0 +1,36 @@
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
+use serde::{Deserialize, Serialize};
+
+/// `Schema` represents the versioned schema registered for the topic.
+/// It consists of the following fields:
+/// - `id`: the unique identifier of the schema, carried in the `iggy-schema-id` message header.
+/// - `stream_id`: the unique identifier of the stream.
+/// - `topic_id`: the unique identifier of the topic.
+/// - `version`: the version of the schema within the topic, starting from 1.
+/// - `schema_type`: the format of the schema.
+/// - `compatibility`: the compatibility rule enforced against the previous version.
+/// - `validation_enabled`: whether the payloads of the appended messages are validated against the schema.
+/// - `created_at`: the timestamp when the schema was registered.
+/// - `definition`: the schema definition.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Schema {
+    /// The unique identifier of the schema.
+    pub id: u32,
+    /// The unique identifier of the stream.
+    pub stream_id: u32,
+    /// The unique identifier of the topic.
+    pub topic_id: u32,
+    /// The version of the schema within the topic, starting from 1.
+    pub version: u32,
+    /// The format of the schema.
+    pub schema_type: SchemaType,
+    /// The compatibility rule enforced against the previous version.
+    pub compatibility: SchemaCompatibility,
+    /// Whether the payloads of the appended messages are validated against the schema.
+    pub validation_enabled: bool,
+    /// The timestamp when the schema was registered.
+    pub created_at: u64,
+    /// The schema definition.
+    pub definition: String,
+}
@@ -1,3 +1,4 @@
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::utils::byte_size::IggyByteSize;
 use crate::{models::topic::Topic, utils::timestamp::IggyTimestamp};
 use serde::{Deserialize, Serialize};
@@ -34,6 +35,7 @@ pub struct Stream {
 /// - `size_bytes`: the total size of the stream in bytes.
 /// - `messages_count`: the total number of messages in the stream.
 /// - `topics_count`: the total number of topics in the stream.
+/// - `topic_config_overrides`: the default settings overriding the server configuration for the topics in the stream.
 /// - `topics`: the list of topics in the stream.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StreamDetails {
@@ -49,6 +51,9 @@ pub struct StreamDetails {
     pub messages_count: u64,
     /// The total number of topics in the stream.
     pub topics_count: u32,
+    /// The default settings overriding the server configuration for the topics in the stream.
+    #[serde(default)]
+    pub topic_config_overrides: TopicConfigOverrides,
     /// The collection of topics in the stream.
     pub topics: Vec<Topic>,
 }
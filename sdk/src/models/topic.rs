@@ -1,4 +1,7 @@
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::models::topic_config_overrides::TopicConfigOverrides;
+use crate::utils::durability::Durability;
+use crate::utils::partitioning_scheme::PartitioningScheme;
 use crate::{models::partition::Partition, utils::byte_size::IggyByteSize};
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +14,7 @@ use serde::{Deserialize, Serialize};
 /// - `message_expiry`: the optional expiry of the messages in the topic in seconds.
 /// - `max_topic_size`: the optional maximum size of the topic in bytes.
 /// - `replication_factor`: replication factor for the topic.
+/// - `durability`: durability level of the topic.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
@@ -32,6 +36,8 @@ pub struct Topic {
     pub max_topic_size: Option<IggyByteSize>,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// Durability level of the topic.
+    pub durability: Durability,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
@@ -47,8 +53,11 @@ pub struct Topic {
 /// - `message_expiry`: the optional expiry of the messages in the topic in seconds.
 /// - `max_topic_size`: the optional maximum size of the topic.
 /// - `replication_factor`: replication factor for the topic.
+/// - `durability`: durability level of the topic.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
+/// - `config_overrides`: the settings overriding the server configuration, set for the topic.
+/// - `partitioning_scheme`: the scheme mapping the messages key to the partition.
 /// - `partitions`: the collection of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TopicDetails {
@@ -69,10 +78,18 @@ pub struct TopicDetails {
     pub max_topic_size: Option<IggyByteSize>,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// Durability level of the topic.
+    pub durability: Durability,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
     pub partitions_count: u32,
+    /// The settings overriding the server configuration, set for the topic.
+    #[serde(default)]
+    pub config_overrides: TopicConfigOverrides,
+    /// The scheme mapping the messages key to the partition.
+    #[serde(default)]
+    pub partitioning_scheme: PartitioningScheme,
     /// The collection of partitions in the topic.
     pub partitions: Vec<Partition>,
 }
@@ -0,0 +1,378 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use crate::utils::partitioning_scheme::PartitioningScheme;
+use crate::utils::timestamp_type::TimestampType;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The maximum size of the segment, same as the limit of the server configuration.
+pub const MAX_SEGMENT_SIZE_BYTES: u64 = 1000 * 1000 * 1000;
+
+/// `TopicConfigOverrides` represents the settings of the topic, which override the server configuration.
+/// Each setting is optional, if `None` then the value is inherited from the stream defaults (if any) or the server configuration.
+/// It consists of the following fields:
+/// - `segment_size`: the maximum size of the segment, after which a new one is created.
+/// - `enforce_fsync`: whether every write to the segment files is followed by fsync, it can't be disabled when the server configuration enforces it, nor enabled for the topic with durability `none`.
+/// - `messages_required_to_save`: the number of buffered messages, which triggers writing them to disk.
+/// - `validate_checksum`: whether the checksums of the messages are validated when loading the segments.
+/// - `cache_indexes`: whether the indexes of the segments are kept in memory.
+/// - `cache_time_indexes`: whether the time indexes of the segments are kept in memory.
+/// - `message_deduplication`: whether the messages with the already seen IDs are dropped.
+/// - `message_deduplication_max_entries`: the maximum number of message IDs tracked by the deduplication, 0 means unlimited.
+/// - `message_deduplication_expiry`: the expiry of the message IDs tracked by the deduplication, 0 means unlimited.
+/// - `segment_max_age`: the maximum age of the segment, after which a new one is created, 0 means unlimited.
+/// - `segment_max_messages`: the maximum number of messages in the segment, after which a new one is created, 0 means unlimited.
+/// - `timestamp_type`: the type of the timestamp stored with the messages and used by the time index.
+/// - `max_timestamp_skew`: the maximum difference between the event timestamp set by the producer and the server time, 0 means unlimited.
+/// - `partitioning_scheme`: the scheme mapping the messages key to the partition, it can be only set when the topic is created.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct TopicConfigOverrides {
+    /// The maximum size of the segment, after which a new one is created.
+    #[serde(default)]
+    pub segment_size: Option<IggyByteSize>,
+    /// Whether every write to the segment files is followed by fsync, it can't be disabled when the server configuration enforces it, nor enabled for the topic with durability `none`.
+    #[serde(default)]
+    pub enforce_fsync: Option<bool>,
+    /// The number of buffered messages, which triggers writing them to disk.
+    #[serde(default)]
+    pub messages_required_to_save: Option<u32>,
+    /// Whether the checksums of the messages are validated when loading the segments.
+    #[serde(default)]
+    pub validate_checksum: Option<bool>,
+    /// Whether the indexes of the segments are kept in memory.
+    #[serde(default)]
+    pub cache_indexes: Option<bool>,
+    /// Whether the time indexes of the segments are kept in memory.
+    #[serde(default)]
+    pub cache_time_indexes: Option<bool>,
+    /// Whether the messages with the already seen IDs are dropped.
+    #[serde(default)]
+    pub message_deduplication: Option<bool>,
+    /// The maximum number of message IDs tracked by the deduplication, 0 means unlimited.
+    #[serde(default)]
+    pub message_deduplication_max_entries: Option<u64>,
+    /// The expiry of the message IDs tracked by the deduplication, 0 means unlimited.
+    #[serde(default)]
+    pub message_deduplication_expiry: Option<IggyDuration>,
+    /// The maximum age of the segment, after which a new one is created, 0 means unlimited.
+    #[serde(default)]
+    pub segment_max_age: Option<IggyDuration>,
+    /// The maximum number of messages in the segment, after which a new one is created, 0 means unlimited.
+    #[serde(default)]
+    pub segment_max_messages: Option<u64>,
+    /// The type of the timestamp stored with the messages and used by the time index.
+    #[serde(default)]
+    pub timestamp_type: Option<TimestampType>,
+    /// The maximum difference between the event timestamp set by the producer and the server time, 0 means unlimited.
+    #[serde(default)]
+    pub max_timestamp_skew: Option<IggyDuration>,
+    /// The scheme mapping the messages key to the partition, it can be only set when the topic is created.
+    #[serde(default)]
+    pub partitioning_scheme: Option<PartitioningScheme>,
+}
+
+impl TopicConfigOverrides {
+    /// The size of the overrides serialized as bytes.
+    pub const SIZE_BYTES: usize = 64;
+
+    /// Returns `true` if none of the settings is overridden.
+    pub fn is_empty(&self) -> bool {
+        *self == TopicConfigOverrides::default()
+    }
+
+    /// Returns the overrides with the settings set in `self`, and the remaining ones taken from `defaults`.
+    pub fn or(&self, defaults: &TopicConfigOverrides) -> TopicConfigOverrides {
+        TopicConfigOverrides {
+            segment_size: self.segment_size.or(defaults.segment_size),
+            enforce_fsync: self.enforce_fsync.or(defaults.enforce_fsync),
+            messages_required_to_save: self
+                .messages_required_to_save
+                .or(defaults.messages_required_to_save),
+            validate_checksum: self.validate_checksum.or(defaults.validate_checksum),
+            cache_indexes: self.cache_indexes.or(defaults.cache_indexes),
+            cache_time_indexes: self.cache_time_indexes.or(defaults.cache_time_indexes),
+            message_deduplication: self
+                .message_deduplication
+                .or(defaults.message_deduplication),
+            message_deduplication_max_entries: self
+                .message_deduplication_max_entries
+                .or(defaults.message_deduplication_max_entries),
+            message_deduplication_expiry: self
+                .message_deduplication_expiry
+                .or(defaults.message_deduplication_expiry),
+            segment_max_age: self.segment_max_age.or(defaults.segment_max_age),
+            segment_max_messages: self.segment_max_messages.or(defaults.segment_max_messages),
+            timestamp_type: self.timestamp_type.or(defaults.timestamp_type),
+            max_timestamp_skew: self.max_timestamp_skew.or(defaults.max_timestamp_skew),
+            partitioning_scheme: self.partitioning_scheme.or(defaults.partitioning_scheme),
+        }
+    }
+
+    fn put_bool(bytes: &mut BytesMut, value: Option<bool>) {
+        match value {
+            Some(value) => bytes.put_u8(if value { 2 } else { 1 }),
+            None => bytes.put_u8(0),
+        }
+    }
+
+    fn read_bool(code: u8) -> Result<Option<bool>, IggyError> {
+        match code {
+            0 => Ok(None),
+            1 => Ok(Some(false)),
+            2 => Ok(Some(true)),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    fn put_u64(bytes: &mut BytesMut, value: Option<u64>) {
+        match value {
+            Some(value) => {
+                bytes.put_u8(1);
+                bytes.put_u64_le(value);
+            }
+            None => {
+                bytes.put_u8(0);
+                bytes.put_u64_le(0);
+            }
+        }
+    }
+
+    fn read_u64(bytes: &[u8]) -> Result<Option<u64>, IggyError> {
+        let value = u64::from_le_bytes(bytes[1..9].try_into()?);
+        match bytes[0] {
+            0 => Ok(None),
+            1 => Ok(Some(value)),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Validatable<IggyError> for TopicConfigOverrides {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(segment_size) = self.segment_size {
+            let segment_size = segment_size.as_bytes_u64();
+            if segment_size == 0 || segment_size > MAX_SEGMENT_SIZE_BYTES {
+                return Err(IggyError::InvalidSegmentSize(segment_size));
+            }
+        }
+
+        if self.messages_required_to_save == Some(0) {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for TopicConfigOverrides {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(Self::SIZE_BYTES);
+        match self.segment_size {
+            Some(segment_size) => bytes.put_u64_le(segment_size.as_bytes_u64()),
+            None => bytes.put_u64_le(0),
+        }
+        Self::put_bool(&mut bytes, self.enforce_fsync);
+        bytes.put_u32_le(self.messages_required_to_save.unwrap_or(0));
+        Self::put_bool(&mut bytes, self.validate_checksum);
+        Self::put_bool(&mut bytes, self.cache_indexes);
+        Self::put_bool(&mut bytes, self.cache_time_indexes);
+        Self::put_bool(&mut bytes, self.message_deduplication);
+        Self::put_u64(&mut bytes, self.message_deduplication_max_entries);
+        Self::put_u64(
+            &mut bytes,
+            self.message_deduplication_expiry
+                .map(|expiry| expiry.into()),
+        );
+        Self::put_u64(
+            &mut bytes,
+            self.segment_max_age.map(|max_age| max_age.into()),
+        );
+        Self::put_u64(&mut bytes, self.segment_max_messages);
+        match self.timestamp_type {
+            Some(timestamp_type) => bytes.put_u8(timestamp_type.as_code()),
+            None => bytes.put_u8(0),
+        }
+        Self::put_u64(
+            &mut bytes,
+            self.max_timestamp_skew.map(|max_skew| max_skew.into()),
+        );
+        match self.partitioning_scheme {
+            Some(partitioning_scheme) => bytes.put_u8(partitioning_scheme.as_code()),
+            None => bytes.put_u8(0),
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<TopicConfigOverrides, IggyError> {
+        if bytes.len() < Self::SIZE_BYTES {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let segment_size = match u64::from_le_bytes(bytes[0..8].try_into()?) {
+            0 => None,
+            size => Some(IggyByteSize::from(size)),
+        };
+        let messages_required_to_save = match u32::from_le_bytes(bytes[9..13].try_into()?) {
+            0 => None,
+            count => Some(count),
+        };
+        let overrides = TopicConfigOverrides {
+            segment_size,
+            enforce_fsync: Self::read_bool(bytes[8])?,
+            messages_required_to_save,
+            validate_checksum: Self::read_bool(bytes[13])?,
+            cache_indexes: Self::read_bool(bytes[14])?,
+            cache_time_indexes: Self::read_bool(bytes[15])?,
+            message_deduplication: Self::read_bool(bytes[16])?,
+            message_deduplication_max_entries: Self::read_u64(&bytes[17..26])?,
+            message_deduplication_expiry: Self::read_u64(&bytes[26..35])?.map(IggyDuration::from),
+            segment_max_age: Self::read_u64(&bytes[35..44])?.map(IggyDuration::from),
+            segment_max_messages: Self::read_u64(&bytes[44..53])?,
+            timestamp_type: match bytes[53] {
+                0 => None,
+                code => Some(TimestampType::from_code(code)?),
+            },
+            max_timestamp_skew: Self::read_u64(&bytes[54..63])?.map(IggyDuration::from),
+            partitioning_scheme: match bytes[63] {
+                0 => None,
+                code => Some(PartitioningScheme::from_code(code)?),
+            },
+        };
+        Ok(overrides)
+    }
+}
+
+impl Display for TopicConfigOverrides {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut settings = Vec::new();
+        if let Some(segment_size) = self.segment_size {
+            settings.push(format!("segment_size: {segment_size}"));
+        }
+        if let Some(enforce_fsync) = self.enforce_fsync {
+            settings.push(format!("enforce_fsync: {enforce_fsync}"));
+        }
+        if let Some(messages_required_to_save) = self.messages_required_to_save {
+            settings.push(format!(
+                "messages_required_to_save: {messages_required_to_save}"
+            ));
+        }
+        if let Some(validate_checksum) = self.validate_checksum {
+            settings.push(format!("validate_checksum: {validate_checksum}"));
+        }
+        if let Some(cache_indexes) = self.cache_indexes {
+            settings.push(format!("cache_indexes: {cache_indexes}"));
+        }
+        if let Some(cache_time_indexes) = self.cache_time_indexes {
+            settings.push(format!("cache_time_indexes: {cache_time_indexes}"));
+        }
+        if let Some(message_deduplication) = self.message_deduplication {
+            settings.push(format!("message_deduplication: {message_deduplication}"));
+        }
+        if let Some(max_entries) = self.message_deduplication_max_entries {
+            settings.push(format!("message_deduplication_max_entries: {max_entries}"));
+        }
+        if let Some(expiry) = self.message_deduplication_expiry {
+            settings.push(format!(
+                "message_deduplication_expiry: {}",
+                expiry.as_human_time_string()
+            ));
+        }
+
+        if let Some(max_age) = self.segment_max_age {
+            settings.push(format!(
+                "segment_max_age: {}",
+                max_age.as_human_time_string()
+            ));
+        }
+        if let Some(max_messages) = self.segment_max_messages {
+            settings.push(format!("segment_max_messages: {max_messages}"));
+        }
+        if let Some(timestamp_type) = self.timestamp_type {
+            settings.push(format!("timestamp_type: {timestamp_type}"));
+        }
+        if let Some(max_skew) = self.max_timestamp_skew {
+            settings.push(format!(
+                "max_timestamp_skew: {}",
+                max_skew.as_human_time_string()
+            ));
+        }
+        if let Some(partitioning_scheme) = self.partitioning_scheme {
+            settings.push(format!("partitioning_scheme: {partitioning_scheme}"));
+        }
+
+        if settings.is_empty() {
+            write!(f, "default")
+        } else {
+            write!(f, "{}", settings.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let overrides = TopicConfigOverrides {
+            segment_size: Some(IggyByteSize::from(1000)),
+            enforce_fsync: Some(true),
+            messages_required_to_save: None,
+            validate_checksum: Some(false),
+            cache_indexes: None,
+            cache_time_indexes: Some(false),
+            message_deduplication: Some(true),
+            message_deduplication_max_entries: Some(0),
+            message_deduplication_expiry: Some(IggyDuration::from(60)),
+            segment_max_age: Some(IggyDuration::from(3600)),
+            segment_max_messages: None,
+            timestamp_type: Some(TimestampType::CreateTime),
+            max_timestamp_skew: Some(IggyDuration::from(3600)),
+            partitioning_scheme: Some(PartitioningScheme::JumpHash),
+        };
+
+        let bytes = overrides.as_bytes();
+        assert_eq!(bytes.len(), TopicConfigOverrides::SIZE_BYTES);
+
+        let deserialized = TopicConfigOverrides::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, overrides);
+    }
+
+    #[test]
+    fn empty_overrides_should_be_serialized_as_zeros() {
+        let bytes = TopicConfigOverrides::default().as_bytes();
+        assert!(bytes.iter().all(|byte| *byte == 0));
+        assert!(TopicConfigOverrides::from_bytes(bytes).unwrap().is_empty());
+    }
+
+    #[test]
+    fn missing_settings_should_be_taken_from_defaults() {
+        let overrides = TopicConfigOverrides {
+            segment_size: Some(IggyByteSize::from(1000)),
+            ..Default::default()
+        };
+        let defaults = TopicConfigOverrides {
+            segment_size: Some(IggyByteSize::from(2000)),
+            enforce_fsync: Some(true),
+            ..Default::default()
+        };
+
+        let merged = overrides.or(&defaults);
+        assert_eq!(merged.segment_size, Some(IggyByteSize::from(1000)));
+        assert_eq!(merged.enforce_fsync, Some(true));
+        assert_eq!(merged.cache_indexes, None);
+    }
+
+    #[test]
+    fn zero_segment_size_should_be_invalid() {
+        let overrides = TopicConfigOverrides {
+            segment_size: Some(IggyByteSize::from(0)),
+            ..Default::default()
+        };
+        assert!(overrides.validate().is_err());
+    }
+}
@@ -1,7 +1,9 @@
 use crate::error::IggyError;
 use crate::identifier::Identifier;
-use crate::messages::send_messages::{Message, Partitioning};
+use crate::messages::send_messages::{Message, Partitioning, PartitioningKind};
+use crate::utils::partitioning_scheme::PartitioningScheme;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 /// The trait represent the logic responsible for calculating the partition ID and is used by the `IggyClient`.
 /// This might be especially useful when the partition ID is not constant and might be calculated based on the stream ID, topic ID and other parameters.
@@ -14,3 +16,89 @@ pub trait Partitioner: Send + Sync + Debug {
         messages: &[Message],
     ) -> Result<u32, IggyError>;
 }
+
+/// The partitioner mapping the messages key to the partition on the client side, using the same scheme as the server,
+/// which makes it possible to know the target partition before sending the messages.
+/// The `Balanced` partitioning is mapped to the partitions using the round-robin and the `PartitionId` one is used as is.
+#[derive(Debug)]
+pub struct MessagesKeyPartitioner {
+    partitioning_scheme: PartitioningScheme,
+    partitions_count: u32,
+    current_partition_id: AtomicU32,
+}
+
+impl MessagesKeyPartitioner {
+    /// Create a new partitioner for the topic having the given partitioning scheme and partitions count.
+    pub fn new(partitioning_scheme: PartitioningScheme, partitions_count: u32) -> Self {
+        Self {
+            partitioning_scheme,
+            partitions_count,
+            current_partition_id: AtomicU32::new(0),
+        }
+    }
+}
+
+impl Partitioner for MessagesKeyPartitioner {
+    fn calculate_partition_id(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        partitioning: &Partitioning,
+        _messages: &[Message],
+    ) -> Result<u32, IggyError> {
+        match partitioning.kind {
+            PartitioningKind::PartitionId => Ok(u32::from_le_bytes(
+                partitioning.value[..partitioning.length as usize].try_into()?,
+            )),
+            PartitioningKind::MessagesKey => Ok(self
+                .partitioning_scheme
+                .calculate_partition_id(&partitioning.value, self.partitions_count)),
+            PartitioningKind::Balanced => {
+                let partition_id = self.current_partition_id.fetch_add(1, Ordering::SeqCst);
+                Ok(partition_id
+                    .checked_rem(self.partitions_count)
+                    .map_or(0, |partition_id| partition_id + 1))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_key_should_be_mapped_using_partitioning_scheme() {
+        let partitioner = MessagesKeyPartitioner::new(PartitioningScheme::JumpHash, 10);
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(1).unwrap();
+        let partitioning = Partitioning::messages_key_str("user-1").unwrap();
+
+        let partition_id = partitioner
+            .calculate_partition_id(&stream_id, &topic_id, &partitioning, &[])
+            .unwrap();
+
+        assert_eq!(
+            partition_id,
+            PartitioningScheme::JumpHash.calculate_partition_id(b"user-1", 10)
+        );
+    }
+
+    #[test]
+    fn balanced_partitioning_should_use_round_robin() {
+        let partitioner = MessagesKeyPartitioner::new(PartitioningScheme::Modulo, 3);
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(1).unwrap();
+        let partitioning = Partitioning::balanced();
+
+        let partition_ids = (0..4)
+            .map(|_| {
+                partitioner
+                    .calculate_partition_id(&stream_id, &topic_id, &partitioning, &[])
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(partition_ids, vec![1, 2, 3, 1]);
+    }
+}
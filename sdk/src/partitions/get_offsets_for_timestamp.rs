@@ -0,0 +1,196 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::partitions::MAX_PARTITIONS_COUNT;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::formats::CommaSeparator;
+use serde_with::{serde_as, StringWithSeparator};
+use std::fmt::Display;
+
+/// `GetOffsetsForTimestamp` command is used to resolve the offsets corresponding to the given timestamp, without polling the messages.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `timestamp` - the timestamp (in microseconds) for which the offsets are resolved.
+/// - `partition_ids` - the partitions for which the offsets are resolved, all the partitions of the topic if empty.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetOffsetsForTimestamp {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// The timestamp (in microseconds) for which the offsets are resolved.
+    pub timestamp: u64,
+    /// The partitions for which the offsets are resolved, all the partitions of the topic if empty.
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, u32>")]
+    #[serde(default)]
+    pub partition_ids: Vec<u32>,
+}
+
+impl CommandPayload for GetOffsetsForTimestamp {}
+
+impl Validatable<IggyError> for GetOffsetsForTimestamp {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.partition_ids.len() > MAX_PARTITIONS_COUNT as usize {
+            return Err(IggyError::TooManyPartitions);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetOffsetsForTimestamp {
+    fn as_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            12 + 4 * self.partition_ids.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u64_le(self.timestamp);
+        bytes.put_u32_le(self.partition_ids.len() as u32);
+        for partition_id in &self.partition_ids {
+            bytes.put_u32_le(*partition_id);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetOffsetsForTimestamp, IggyError> {
+        if bytes.len() < 18 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() < position + 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let timestamp = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+        let partitions_count =
+            u32::from_le_bytes(bytes[position + 8..position + 12].try_into()?) as usize;
+        position += 12;
+        if bytes.len() != position + 4 * partitions_count {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_ids = bytes[position..]
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        let command = GetOffsetsForTimestamp {
+            stream_id,
+            topic_id,
+            timestamp,
+            partition_ids,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetOffsetsForTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.timestamp,
+            self.partition_ids
+                .iter()
+                .map(|partition_id| partition_id.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetOffsetsForTimestamp {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            timestamp: 1_000_000,
+            partition_ids: vec![1, 3],
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let timestamp = u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+        let partitions_count =
+            u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
+        let first_partition_id =
+            u32::from_le_bytes(bytes[position + 12..position + 16].try_into().unwrap());
+        let second_partition_id =
+            u32::from_le_bytes(bytes[position + 16..position + 20].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(timestamp, command.timestamp);
+        assert_eq!(partitions_count, 2);
+        assert_eq!(
+            vec![first_partition_id, second_partition_id],
+            command.partition_ids
+        );
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let timestamp = 1_000_000u64;
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(16 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u64_le(timestamp);
+        bytes.put_u32_le(1);
+        bytes.put_u32_le(2);
+        let command = GetOffsetsForTimestamp::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.timestamp, timestamp);
+        assert_eq!(command.partition_ids, vec![2]);
+    }
+
+    #[test]
+    fn partition_ids_should_be_serialized_as_comma_separated_query() {
+        let command = GetOffsetsForTimestamp {
+            timestamp: 10,
+            partition_ids: vec![1, 2],
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&command).unwrap();
+        assert_eq!(json, r#"{"timestamp":10,"partition_ids":"1,2"}"#);
+
+        let command = serde_json::from_str::<GetOffsetsForTimestamp>(
+            r#"{"timestamp":10,"partition_ids":""}"#,
+        )
+        .unwrap();
+        assert!(command.partition_ids.is_empty());
+    }
+}
@@ -1,4 +1,6 @@
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod get_offsets_for_timestamp;
+pub mod truncate_partition;
 
 const MAX_PARTITIONS_COUNT: u32 = 1000;
@@ -0,0 +1,767 @@
+use serde_json::{Map, Number, Value};
+use std::collections::{HashMap, HashSet};
+
+const MAX_DEPTH: usize = 128;
+
+/// The Avro schema of the topic, validating the payloads which are Avro binary encoded datums (without the container file header).
+#[derive(Debug)]
+pub struct AvroSchema {
+    root: AvroType,
+    names: HashMap<String, AvroType>,
+}
+
+#[derive(Debug, Clone)]
+enum AvroType {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record {
+        name: String,
+        fields: Vec<AvroField>,
+    },
+    Enum {
+        name: String,
+        symbols: Vec<String>,
+        default: Option<String>,
+    },
+    Array(Box<AvroType>),
+    Map(Box<AvroType>),
+    Union(Vec<AvroType>),
+    Fixed {
+        name: String,
+        size: usize,
+    },
+    Reference(String),
+}
+
+#[derive(Debug, Clone)]
+struct AvroField {
+    name: String,
+    schema: AvroType,
+    has_default: bool,
+}
+
+impl AvroSchema {
+    /// Parse the Avro schema definition (JSON).
+    pub fn parse(definition: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(definition).map_err(|error| error.to_string())?;
+        let mut names = HashMap::new();
+        let root = parse_type(&value, None, &mut names)?;
+        let schema = AvroSchema { root, names };
+        schema.verify_references(&schema.root)?;
+        Ok(schema)
+    }
+
+    /// Decode the Avro binary encoded payload into the JSON value, the payload must be fully consumed.
+    pub fn decode(&self, payload: &[u8]) -> Result<Value, String> {
+        let mut reader = AvroReader {
+            payload,
+            position: 0,
+        };
+        let value = self.decode_type(&self.root, &mut reader, 0)?;
+        if reader.position != payload.len() {
+            return Err(format!(
+                "{} trailing bytes after the datum",
+                payload.len() - reader.position
+            ));
+        }
+
+        Ok(value)
+    }
+
+    /// Check if the data written with the `writer` schema can be read using this (reader) schema, following the Avro schema resolution rules.
+    pub fn can_read(&self, writer: &AvroSchema) -> Result<(), String> {
+        let mut visited = HashSet::new();
+        self.can_read_type(&self.root, writer, &writer.root, "$", &mut visited)
+    }
+
+    fn resolve<'a>(&'a self, avro_type: &'a AvroType) -> &'a AvroType {
+        match avro_type {
+            AvroType::Reference(name) => match self.names.get(name) {
+                Some(AvroType::Reference(_)) | None => avro_type,
+                Some(named_type) => named_type,
+            },
+            _ => avro_type,
+        }
+    }
+
+    fn verify_references(&self, avro_type: &AvroType) -> Result<(), String> {
+        match avro_type {
+            AvroType::Reference(name) if !self.names.contains_key(name) => {
+                Err(format!("unknown type: {name}"))
+            }
+            AvroType::Record { fields, .. } => fields
+                .iter()
+                .try_for_each(|field| self.verify_references(&field.schema)),
+            AvroType::Array(items) | AvroType::Map(items) => self.verify_references(items),
+            AvroType::Union(branches) => branches
+                .iter()
+                .try_for_each(|branch| self.verify_references(branch)),
+            _ => Ok(()),
+        }
+    }
+
+    fn decode_type(
+        &self,
+        avro_type: &AvroType,
+        reader: &mut AvroReader,
+        depth: usize,
+    ) -> Result<Value, String> {
+        if depth > MAX_DEPTH {
+            return Err("maximum nesting depth exceeded".to_string());
+        }
+
+        let value = match self.resolve(avro_type) {
+            AvroType::Null => Value::Null,
+            AvroType::Boolean => match reader.read_byte()? {
+                0 => Value::Bool(false),
+                1 => Value::Bool(true),
+                value => return Err(format!("invalid boolean value: {value}")),
+            },
+            AvroType::Int => Value::from(reader.read_int()?),
+            AvroType::Long => Value::from(reader.read_long()?),
+            AvroType::Float => {
+                let value = f32::from_le_bytes(reader.read_bytes(4)?.try_into().unwrap());
+                Number::from_f64(value as f64).map_or(Value::Null, Value::Number)
+            }
+            AvroType::Double => {
+                let value = f64::from_le_bytes(reader.read_bytes(8)?.try_into().unwrap());
+                Number::from_f64(value).map_or(Value::Null, Value::Number)
+            }
+            AvroType::Bytes => {
+                let length = reader.read_length()?;
+                Value::String(
+                    reader
+                        .read_bytes(length)?
+                        .iter()
+                        .map(|b| *b as char)
+                        .collect(),
+                )
+            }
+            AvroType::String => {
+                let length = reader.read_length()?;
+                let value = std::str::from_utf8(reader.read_bytes(length)?)
+                    .map_err(|error| error.to_string())?;
+                Value::String(value.to_string())
+            }
+            AvroType::Record { fields, .. } => {
+                let mut object = Map::with_capacity(fields.len());
+                for field in fields {
+                    let value = self.decode_type(&field.schema, reader, depth + 1)?;
+                    object.insert(field.name.clone(), value);
+                }
+                Value::Object(object)
+            }
+            AvroType::Enum { name, symbols, .. } => {
+                let index = reader.read_int()?;
+                let symbol = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| symbols.get(index))
+                    .ok_or_else(|| format!("invalid symbol index: {index} for enum: {name}"))?;
+                Value::String(symbol.clone())
+            }
+            AvroType::Array(items) => {
+                let mut values = Vec::new();
+                while let Some(count) = reader.read_block_count()? {
+                    for _ in 0..count {
+                        values.push(self.decode_type(items, reader, depth + 1)?);
+                    }
+                }
+                Value::Array(values)
+            }
+            AvroType::Map(values) => {
+                let mut object = Map::new();
+                while let Some(count) = reader.read_block_count()? {
+                    for _ in 0..count {
+                        let length = reader.read_length()?;
+                        let key = std::str::from_utf8(reader.read_bytes(length)?)
+                            .map_err(|error| error.to_string())?
+                            .to_string();
+                        let value = self.decode_type(values, reader, depth + 1)?;
+                        object.insert(key, value);
+                    }
+                }
+                Value::Object(object)
+            }
+            AvroType::Union(branches) => {
+                let index = reader.read_long()?;
+                let branch = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| branches.get(index))
+                    .ok_or_else(|| format!("invalid union branch index: {index}"))?;
+                self.decode_type(branch, reader, depth + 1)?
+            }
+            AvroType::Fixed { size, .. } => Value::String(
+                reader
+                    .read_bytes(*size)?
+                    .iter()
+                    .map(|b| *b as char)
+                    .collect(),
+            ),
+            AvroType::Reference(name) => return Err(format!("unknown type: {name}")),
+        };
+        Ok(value)
+    }
+
+    fn can_read_type(
+        &self,
+        reader_type: &AvroType,
+        writer: &AvroSchema,
+        writer_type: &AvroType,
+        path: &str,
+        visited: &mut HashSet<(String, String)>,
+    ) -> Result<(), String> {
+        let reader_type = self.resolve(reader_type);
+        let writer_type = writer.resolve(writer_type);
+
+        if let AvroType::Union(writer_branches) = writer_type {
+            return writer_branches.iter().try_for_each(|writer_branch| {
+                self.can_read_type(reader_type, writer, writer_branch, path, visited)
+            });
+        }
+
+        if let AvroType::Union(reader_branches) = reader_type {
+            let readable = reader_branches.iter().any(|reader_branch| {
+                self.can_read_type(
+                    reader_branch,
+                    writer,
+                    writer_type,
+                    path,
+                    &mut visited.clone(),
+                )
+                .is_ok()
+            });
+            if readable {
+                return Ok(());
+            }
+            return Err(format!(
+                "{path}: {} is not present in the reader union",
+                type_name(writer_type)
+            ));
+        }
+
+        match (reader_type, writer_type) {
+            (AvroType::Null, AvroType::Null)
+            | (AvroType::Boolean, AvroType::Boolean)
+            | (AvroType::Int, AvroType::Int)
+            | (AvroType::Long, AvroType::Int | AvroType::Long)
+            | (AvroType::Float, AvroType::Int | AvroType::Long | AvroType::Float)
+            | (
+                AvroType::Double,
+                AvroType::Int | AvroType::Long | AvroType::Float | AvroType::Double,
+            )
+            | (AvroType::Bytes | AvroType::String, AvroType::Bytes | AvroType::String) => Ok(()),
+            (
+                AvroType::Record {
+                    name: reader_name,
+                    fields: reader_fields,
+                },
+                AvroType::Record {
+                    name: writer_name,
+                    fields: writer_fields,
+                },
+            ) => {
+                if short_name(reader_name) != short_name(writer_name) {
+                    return Err(format!(
+                        "{path}: record name changed from {writer_name} to {reader_name}"
+                    ));
+                }
+
+                if !visited.insert((reader_name.clone(), writer_name.clone())) {
+                    return Ok(());
+                }
+
+                for reader_field in reader_fields {
+                    let writer_field = writer_fields
+                        .iter()
+                        .find(|writer_field| writer_field.name == reader_field.name);
+                    match writer_field {
+                        Some(writer_field) => self.can_read_type(
+                            &reader_field.schema,
+                            writer,
+                            &writer_field.schema,
+                            &format!("{path}.{}", reader_field.name),
+                            visited,
+                        )?,
+                        None if reader_field.has_default => {}
+                        None => {
+                            return Err(format!(
+                                "{path}.{}: field is missing and has no default value",
+                                reader_field.name
+                            ))
+                        }
+                    }
+                }
+                Ok(())
+            }
+            (
+                AvroType::Enum {
+                    name: reader_name,
+                    symbols: reader_symbols,
+                    default,
+                },
+                AvroType::Enum {
+                    name: writer_name,
+                    symbols: writer_symbols,
+                    ..
+                },
+            ) => {
+                if short_name(reader_name) != short_name(writer_name) {
+                    return Err(format!(
+                        "{path}: enum name changed from {writer_name} to {reader_name}"
+                    ));
+                }
+
+                if default.is_none() {
+                    if let Some(symbol) = writer_symbols
+                        .iter()
+                        .find(|symbol| !reader_symbols.contains(symbol))
+                    {
+                        return Err(format!(
+                            "{path}: enum symbol {symbol} is missing and there is no default"
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            (AvroType::Array(reader_items), AvroType::Array(writer_items)) => self.can_read_type(
+                reader_items,
+                writer,
+                writer_items,
+                &format!("{path}[]"),
+                visited,
+            ),
+            (AvroType::Map(reader_values), AvroType::Map(writer_values)) => self.can_read_type(
+                reader_values,
+                writer,
+                writer_values,
+                &format!("{path}{{}}"),
+                visited,
+            ),
+            (
+                AvroType::Fixed {
+                    name: reader_name,
+                    size: reader_size,
+                },
+                AvroType::Fixed {
+                    name: writer_name,
+                    size: writer_size,
+                },
+            ) if short_name(reader_name) == short_name(writer_name)
+                && reader_size == writer_size =>
+            {
+                Ok(())
+            }
+            _ => Err(format!(
+                "{path}: {} cannot be read as {}",
+                type_name(writer_type),
+                type_name(reader_type)
+            )),
+        }
+    }
+}
+
+fn parse_type(
+    value: &Value,
+    namespace: Option<&str>,
+    names: &mut HashMap<String, AvroType>,
+) -> Result<AvroType, String> {
+    match value {
+        Value::String(name) => Ok(parse_named_or_primitive(name, namespace)),
+        Value::Array(branches) => {
+            if branches.is_empty() {
+                return Err("union must have at least one branch".to_string());
+            }
+            let branches = branches
+                .iter()
+                .map(|branch| parse_type(branch, namespace, names))
+                .collect::<Result<Vec<_>, _>>()?;
+            if branches
+                .iter()
+                .any(|branch| matches!(branch, AvroType::Union(_)))
+            {
+                return Err("union cannot directly contain another union".to_string());
+            }
+            Ok(AvroType::Union(branches))
+        }
+        Value::Object(object) => {
+            let type_value = object
+                .get("type")
+                .ok_or_else(|| "missing 'type' attribute".to_string())?;
+            let Some(type_name) = type_value.as_str() else {
+                return parse_type(type_value, namespace, names);
+            };
+            match type_name {
+                "record" | "error" => {
+                    let (name, namespace) = get_full_name(object, namespace)?;
+                    register_name(names, name.clone(), AvroType::Reference(name.clone()))?;
+                    let fields = object
+                        .get("fields")
+                        .and_then(Value::as_array)
+                        .ok_or_else(|| format!("record {name} must have 'fields' array"))?;
+                    let mut parsed_fields = Vec::with_capacity(fields.len());
+                    for field in fields {
+                        let field_name = field
+                            .get("name")
+                            .and_then(Value::as_str)
+                            .ok_or_else(|| format!("field of record {name} must have a name"))?;
+                        if parsed_fields
+                            .iter()
+                            .any(|field: &AvroField| field.name == field_name)
+                        {
+                            return Err(format!("duplicate field {field_name} in record {name}"));
+                        }
+                        let field_type = field.get("type").ok_or_else(|| {
+                            format!("field {field_name} of record {name} must have a type")
+                        })?;
+                        parsed_fields.push(AvroField {
+                            name: field_name.to_string(),
+                            schema: parse_type(field_type, namespace.as_deref(), names)?,
+                            has_default: field.get("default").is_some(),
+                        });
+                    }
+                    let record = AvroType::Record {
+                        name: name.clone(),
+                        fields: parsed_fields,
+                    };
+                    names.insert(name, record.clone());
+                    Ok(record)
+                }
+                "enum" => {
+                    let (name, _) = get_full_name(object, namespace)?;
+                    let symbols = object
+                        .get("symbols")
+                        .and_then(Value::as_array)
+                        .ok_or_else(|| format!("enum {name} must have 'symbols' array"))?
+                        .iter()
+                        .map(|symbol| {
+                            symbol
+                                .as_str()
+                                .map(|symbol| symbol.to_string())
+                                .ok_or_else(|| format!("symbols of enum {name} must be strings"))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let default = object
+                        .get("default")
+                        .and_then(Value::as_str)
+                        .map(|symbol| symbol.to_string());
+                    let enum_type = AvroType::Enum {
+                        name: name.clone(),
+                        symbols,
+                        default,
+                    };
+                    register_name(names, name, enum_type.clone())?;
+                    Ok(enum_type)
+                }
+                "fixed" => {
+                    let (name, _) = get_full_name(object, namespace)?;
+                    let size = object
+                        .get("size")
+                        .and_then(Value::as_u64)
+                        .ok_or_else(|| format!("fixed {name} must have a 'size'"))?;
+                    let fixed = AvroType::Fixed {
+                        name: name.clone(),
+                        size: size as usize,
+                    };
+                    register_name(names, name, fixed.clone())?;
+                    Ok(fixed)
+                }
+                "array" => {
+                    let items = object
+                        .get("items")
+                        .ok_or_else(|| "array must have 'items'".to_string())?;
+                    Ok(AvroType::Array(Box::new(parse_type(
+                        items, namespace, names,
+                    )?)))
+                }
+                "map" => {
+                    let values = object
+                        .get("values")
+                        .ok_or_else(|| "map must have 'values'".to_string())?;
+                    Ok(AvroType::Map(Box::new(parse_type(
+                        values, namespace, names,
+                    )?)))
+                }
+                name => Ok(parse_named_or_primitive(name, namespace)),
+            }
+        }
+        _ => Err(format!("invalid type: {value}")),
+    }
+}
+
+fn parse_named_or_primitive(name: &str, namespace: Option<&str>) -> AvroType {
+    match name {
+        "null" => AvroType::Null,
+        "boolean" => AvroType::Boolean,
+        "int" => AvroType::Int,
+        "long" => AvroType::Long,
+        "float" => AvroType::Float,
+        "double" => AvroType::Double,
+        "bytes" => AvroType::Bytes,
+        "string" => AvroType::String,
+        name => AvroType::Reference(qualify(name, namespace)),
+    }
+}
+
+fn get_full_name(
+    object: &Map<String, Value>,
+    namespace: Option<&str>,
+) -> Result<(String, Option<String>), String> {
+    let name = object
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| "named type must have a 'name'".to_string())?;
+    let namespace = object
+        .get("namespace")
+        .and_then(Value::as_str)
+        .or(namespace)
+        .filter(|namespace| !namespace.is_empty());
+    let full_name = qualify(name, namespace);
+    let namespace = full_name
+        .rsplit_once('.')
+        .map(|(namespace, _)| namespace.to_string());
+    Ok((full_name, namespace))
+}
+
+fn qualify(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) if !name.contains('.') => format!("{namespace}.{name}"),
+        _ => name.to_string(),
+    }
+}
+
+fn register_name(
+    names: &mut HashMap<String, AvroType>,
+    name: String,
+    avro_type: AvroType,
+) -> Result<(), String> {
+    if names.contains_key(&name) {
+        return Err(format!("duplicate type name: {name}"));
+    }
+
+    names.insert(name, avro_type);
+    Ok(())
+}
+
+fn short_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn type_name(avro_type: &AvroType) -> String {
+    match avro_type {
+        AvroType::Null => "null".to_string(),
+        AvroType::Boolean => "boolean".to_string(),
+        AvroType::Int => "int".to_string(),
+        AvroType::Long => "long".to_string(),
+        AvroType::Float => "float".to_string(),
+        AvroType::Double => "double".to_string(),
+        AvroType::Bytes => "bytes".to_string(),
+        AvroType::String => "string".to_string(),
+        AvroType::Record { name, .. } => format!("record {name}"),
+        AvroType::Enum { name, .. } => format!("enum {name}"),
+        AvroType::Array(_) => "array".to_string(),
+        AvroType::Map(_) => "map".to_string(),
+        AvroType::Union(_) => "union".to_string(),
+        AvroType::Fixed { name, .. } => format!("fixed {name}"),
+        AvroType::Reference(name) => name.clone(),
+    }
+}
+
+struct AvroReader<'a> {
+    payload: &'a [u8],
+    position: usize,
+}
+
+impl<'a> AvroReader<'a> {
+    fn read_byte(&mut self) -> Result<u8, String> {
+        let byte = *self
+            .payload
+            .get(self.position)
+            .ok_or_else(|| "unexpected end of payload".to_string())?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], String> {
+        if self.payload.len() - self.position < length {
+            return Err("unexpected end of payload".to_string());
+        }
+
+        let bytes = &self.payload[self.position..self.position + length];
+        self.position += length;
+        Ok(bytes)
+    }
+
+    fn read_long(&mut self) -> Result<i64, String> {
+        let mut value = 0u64;
+        for shift in (0..70).step_by(7) {
+            let byte = self.read_byte()?;
+            if shift == 63 && byte > 1 {
+                return Err("varint overflows 64 bits".to_string());
+            }
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+        Err("varint overflows 64 bits".to_string())
+    }
+
+    fn read_int(&mut self) -> Result<i32, String> {
+        let value = self.read_long()?;
+        i32::try_from(value).map_err(|_| format!("int value out of range: {value}"))
+    }
+
+    fn read_length(&mut self) -> Result<usize, String> {
+        let length = self.read_long()?;
+        usize::try_from(length).map_err(|_| format!("invalid length: {length}"))
+    }
+
+    fn read_block_count(&mut self) -> Result<Option<u64>, String> {
+        let count = self.read_long()?;
+        if count == 0 {
+            return Ok(None);
+        }
+
+        if count < 0 {
+            self.read_long()?;
+        }
+        Ok(Some(count.unsigned_abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "namespace": "iggy.test",
+        "fields": [
+            { "name": "id", "type": "long" },
+            { "name": "name", "type": "string" },
+            { "name": "email", "type": ["null", "string"], "default": null },
+            { "name": "tags", "type": { "type": "array", "items": "string" } },
+            { "name": "status", "type": { "type": "enum", "name": "Status", "symbols": ["ACTIVE", "BLOCKED"] } }
+        ]
+    }"#;
+
+    fn encode_long(value: i64, bytes: &mut Vec<u8>) {
+        let mut value = ((value << 1) ^ (value >> 63)) as u64;
+        while value > 0x7f {
+            bytes.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        bytes.push(value as u8);
+    }
+
+    fn encode_string(value: &str, bytes: &mut Vec<u8>) {
+        encode_long(value.len() as i64, bytes);
+        bytes.extend_from_slice(value.as_bytes());
+    }
+
+    fn encode_user() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        encode_long(-150, &mut bytes);
+        encode_string("iggy", &mut bytes);
+        encode_long(1, &mut bytes);
+        encode_string("iggy@iggy.rs", &mut bytes);
+        encode_long(2, &mut bytes);
+        encode_string("a", &mut bytes);
+        encode_string("b", &mut bytes);
+        encode_long(0, &mut bytes);
+        encode_long(1, &mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn valid_datum_should_be_decoded() {
+        let schema = AvroSchema::parse(USER_SCHEMA).unwrap();
+        let value = schema.decode(&encode_user()).unwrap();
+        assert_eq!(value["id"], -150);
+        assert_eq!(value["name"], "iggy");
+        assert_eq!(value["email"], "iggy@iggy.rs");
+        assert_eq!(value["tags"], serde_json::json!(["a", "b"]));
+        assert_eq!(value["status"], "BLOCKED");
+    }
+
+    #[test]
+    fn invalid_datum_should_be_rejected() {
+        let schema = AvroSchema::parse(USER_SCHEMA).unwrap();
+        let bytes = encode_user();
+        assert!(schema.decode(&bytes[..bytes.len() - 1]).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(schema.decode(&trailing).is_err());
+        let mut invalid_symbol = bytes;
+        *invalid_symbol.last_mut().unwrap() = 8;
+        assert!(schema.decode(&invalid_symbol).is_err());
+    }
+
+    #[test]
+    fn invalid_definition_should_be_rejected() {
+        assert!(AvroSchema::parse(r#""unknown""#).is_err());
+        assert!(AvroSchema::parse(r#"{"type": "record", "name": "A"}"#).is_err());
+        assert!(AvroSchema::parse(r#"[]"#).is_err());
+    }
+
+    #[test]
+    fn recursive_schema_should_be_supported() {
+        let schema = AvroSchema::parse(
+            r#"{
+                "type": "record",
+                "name": "Node",
+                "fields": [
+                    { "name": "value", "type": "int" },
+                    { "name": "next", "type": ["null", "Node"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let mut bytes = Vec::new();
+        encode_long(1, &mut bytes);
+        encode_long(1, &mut bytes);
+        encode_long(2, &mut bytes);
+        encode_long(0, &mut bytes);
+        let value = schema.decode(&bytes).unwrap();
+        assert_eq!(value["next"]["value"], 2);
+        assert!(schema.can_read(&schema).is_ok());
+    }
+
+    #[test]
+    fn adding_field_with_default_should_be_backward_compatible() {
+        let old = AvroSchema::parse(USER_SCHEMA).unwrap();
+        let new = AvroSchema::parse(
+            r#"{
+                "type": "record",
+                "name": "User",
+                "namespace": "iggy.test",
+                "fields": [
+                    { "name": "id", "type": "long" },
+                    { "name": "name", "type": "string" },
+                    { "name": "email", "type": ["null", "string"], "default": null },
+                    { "name": "tags", "type": { "type": "array", "items": "string" } },
+                    { "name": "status", "type": { "type": "enum", "name": "Status", "symbols": ["ACTIVE", "BLOCKED", "DELETED"] } },
+                    { "name": "age", "type": "int", "default": 0 }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(new.can_read(&old).is_ok());
+        assert!(old.can_read(&new).is_err());
+    }
+
+    #[test]
+    fn type_promotion_should_be_respected() {
+        let int = AvroSchema::parse(r#""int""#).unwrap();
+        let double = AvroSchema::parse(r#""double""#).unwrap();
+        assert!(double.can_read(&int).is_ok());
+        assert!(int.can_read(&double).is_err());
+    }
+}
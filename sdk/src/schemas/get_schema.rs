@@ -0,0 +1,94 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetSchema` command is used to retrieve the schema by unique ID.
+/// It has additional payload:
+/// - `schema_id` - unique ID (numeric) of the schema, as carried in the `iggy-schema-id` message header.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetSchema {
+    /// Unique ID (numeric) of the schema.
+    pub schema_id: u32,
+}
+
+impl CommandPayload for GetSchema {}
+
+impl Default for GetSchema {
+    fn default() -> Self {
+        GetSchema { schema_id: 1 }
+    }
+}
+
+impl Validatable<IggyError> for GetSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.schema_id == 0 {
+            return Err(IggyError::InvalidSchemaId);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetSchema {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.schema_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetSchema, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let schema_id = u32::from_le_bytes(bytes.as_ref().try_into()?);
+        let command = GetSchema { schema_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.schema_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetSchema { schema_id: 1 };
+
+        let bytes = command.as_bytes();
+        let schema_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(schema_id, command.schema_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let schema_id = 1u32;
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(schema_id);
+        let command = GetSchema::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.schema_id, schema_id);
+    }
+
+    #[test]
+    fn zero_schema_id_should_be_rejected() {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(0);
+        assert!(GetSchema::from_bytes(bytes.freeze()).is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use jsonschema::JSONSchema;
+use serde_json::{Map, Value};
+
+/// The JSON Schema of the topic, validating the payloads which are JSON documents.
+#[derive(Debug)]
+pub struct JsonSchema {
+    value: Value,
+    compiled: JSONSchema,
+}
+
+impl JsonSchema {
+    /// Parse and compile the JSON Schema definition.
+    pub fn parse(definition: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(definition).map_err(|error| error.to_string())?;
+        if !value.is_object() && !value.is_boolean() {
+            return Err("JSON Schema must be an object or a boolean".to_string());
+        }
+
+        let compiled = JSONSchema::compile(&value).map_err(|error| error.to_string())?;
+        Ok(JsonSchema { value, compiled })
+    }
+
+    /// Decode the payload as a JSON document and validate it against the schema.
+    pub fn decode(&self, payload: &[u8]) -> Result<Value, String> {
+        let document: Value = serde_json::from_slice(payload).map_err(|error| error.to_string())?;
+        if let Err(errors) = self.compiled.validate(&document) {
+            let errors = errors
+                .map(|error| format!("{} at '{}'", error, error.instance_path))
+                .collect::<Vec<String>>()
+                .join(", ");
+            return Err(errors);
+        }
+
+        Ok(document)
+    }
+
+    /// Check if the documents valid against the `writer` schema are also valid against this (reader) schema.
+    /// The check is structural and covers the types, required and additional properties, items and enumerations.
+    pub fn can_read(&self, writer: &JsonSchema) -> Result<(), String> {
+        is_subset(&writer.value, &self.value, "#")
+    }
+}
+
+fn is_subset(writer: &Value, reader: &Value, path: &str) -> Result<(), String> {
+    if is_any(reader) || writer == &Value::Bool(false) {
+        return Ok(());
+    }
+
+    if reader == &Value::Bool(false) {
+        return Err(format!("{path}: reader schema does not accept any value"));
+    }
+
+    let empty = Map::new();
+    let writer = writer.as_object().unwrap_or(&empty);
+    let reader = reader.as_object().unwrap_or(&empty);
+
+    if let Some(reader_types) = get_types(reader) {
+        let Some(writer_types) = get_types(writer) else {
+            return Err(format!(
+                "{path}: type is restricted to {} but was not restricted",
+                reader_types.join(", ")
+            ));
+        };
+        for writer_type in &writer_types {
+            let accepted = reader_types.iter().any(|reader_type| {
+                reader_type == writer_type || (reader_type == "number" && writer_type == "integer")
+            });
+            if !accepted {
+                return Err(format!(
+                    "{path}: type '{writer_type}' is not accepted by {}",
+                    reader_types.join(", ")
+                ));
+            }
+        }
+    }
+
+    if let Some(reader_values) = get_enum(reader) {
+        let Some(writer_values) = get_enum(writer) else {
+            return Err(format!(
+                "{path}: values are restricted by enum but were not restricted"
+            ));
+        };
+        if let Some(value) = writer_values
+            .iter()
+            .find(|value| !reader_values.contains(value))
+        {
+            return Err(format!("{path}: enum value {value} was removed"));
+        }
+    }
+
+    let writer_required = get_required(writer);
+    for required in get_required(reader) {
+        if !writer_required.contains(&required) {
+            return Err(format!(
+                "{path}: property '{required}' is required but was optional"
+            ));
+        }
+    }
+
+    let writer_properties = writer.get("properties").and_then(Value::as_object);
+    let reader_properties = reader.get("properties").and_then(Value::as_object);
+    if let Some(reader_properties) = reader_properties {
+        for (name, reader_property) in reader_properties {
+            if let Some(writer_property) = writer_properties.and_then(|p| p.get(name)) {
+                is_subset(
+                    writer_property,
+                    reader_property,
+                    &format!("{path}/properties/{name}"),
+                )?;
+            }
+        }
+    }
+
+    if let Some(writer_properties) = writer_properties {
+        let reader_additional = reader.get("additionalProperties");
+        for (name, writer_property) in writer_properties {
+            if reader_properties.is_some_and(|p| p.contains_key(name)) {
+                continue;
+            }
+
+            if let Some(reader_additional) = reader_additional {
+                is_subset(
+                    writer_property,
+                    reader_additional,
+                    &format!("{path}/properties/{name}"),
+                )?;
+            }
+        }
+    }
+
+    if let (Some(writer_items), Some(reader_items)) = (writer.get("items"), reader.get("items")) {
+        if writer_items.is_object() && reader_items.is_object() {
+            is_subset(writer_items, reader_items, &format!("{path}/items"))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn is_any(schema: &Value) -> bool {
+    match schema {
+        Value::Bool(value) => *value,
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
+
+fn get_types(schema: &Map<String, Value>) -> Option<Vec<String>> {
+    match schema.get("type")? {
+        Value::String(value) => Some(vec![value.clone()]),
+        Value::Array(values) => Some(
+            values
+                .iter()
+                .filter_map(|value| value.as_str().map(|value| value.to_string()))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn get_enum(schema: &Map<String, Value>) -> Option<Vec<Value>> {
+    if let Some(value) = schema.get("const") {
+        return Some(vec![value.clone()]);
+    }
+
+    schema.get("enum")?.as_array().cloned()
+}
+
+fn get_required(schema: &Map<String, Value>) -> Vec<String> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|value| value.as_str().map(|value| value.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER_SCHEMA: &str = r#"{
+        "type": "object",
+        "properties": {
+            "id": { "type": "integer" },
+            "status": { "enum": ["new", "paid"] }
+        },
+        "required": ["id"]
+    }"#;
+
+    #[test]
+    fn valid_document_should_be_decoded() {
+        let schema = JsonSchema::parse(ORDER_SCHEMA).unwrap();
+        let document = schema.decode(br#"{"id":1,"status":"paid"}"#).unwrap();
+        assert_eq!(document["id"], 1);
+    }
+
+    #[test]
+    fn invalid_document_should_be_rejected() {
+        let schema = JsonSchema::parse(ORDER_SCHEMA).unwrap();
+        assert!(schema.decode(br#"{"status":"paid"}"#).is_err());
+        assert!(schema.decode(br#"{"id":"1"}"#).is_err());
+        assert!(schema.decode(b"not json").is_err());
+    }
+
+    #[test]
+    fn invalid_definition_should_be_rejected() {
+        assert!(JsonSchema::parse("{").is_err());
+        assert!(JsonSchema::parse("1").is_err());
+        assert!(JsonSchema::parse(r#"{"type": 5}"#).is_err());
+    }
+
+    #[test]
+    fn adding_optional_property_should_be_compatible() {
+        let old = JsonSchema::parse(ORDER_SCHEMA).unwrap();
+        let new = JsonSchema::parse(
+            r#"{
+                "type": "object",
+                "properties": {
+                    "id": { "type": "number" },
+                    "status": { "enum": ["new", "paid", "shipped"] },
+                    "note": { "type": "string" }
+                },
+                "required": ["id"]
+            }"#,
+        )
+        .unwrap();
+        assert!(new.can_read(&old).is_ok());
+        assert!(old.can_read(&new).is_err());
+    }
+
+    #[test]
+    fn adding_required_property_should_not_be_backward_compatible() {
+        let old = JsonSchema::parse(ORDER_SCHEMA).unwrap();
+        let new = JsonSchema::parse(
+            r#"{
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer" },
+                    "customer": { "type": "string" }
+                },
+                "required": ["id", "customer"]
+            }"#,
+        )
+        .unwrap();
+        assert!(new.can_read(&old).is_err());
+        assert!(old.can_read(&new).is_ok());
+    }
+}
@@ -0,0 +1,11 @@
+pub mod avro;
+pub mod get_schema;
+pub mod get_schemas;
+pub mod json;
+pub mod protobuf;
+pub mod register_schema;
+pub mod schema_decoder;
+pub mod schema_definition;
+pub mod schema_id;
+
+const MAX_SCHEMA_DEFINITION_LENGTH: usize = 1024 * 1024;
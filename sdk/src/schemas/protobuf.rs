@@ -0,0 +1,475 @@
+use base64::engine::general_purpose;
+use base64::Engine;
+use protobuf::descriptor::field_descriptor_proto::{Label, Type};
+use protobuf::reflect::{
+    FieldDescriptor, FileDescriptor, MessageDescriptor, MessageRef, ReflectFieldRef,
+    ReflectValueRef, RuntimeFieldType, RuntimeType,
+};
+use protobuf::MessageDyn;
+use protobuf::UnknownValueRef;
+use serde_json::{Map, Number, Value};
+use std::collections::HashSet;
+use std::fs;
+
+/// The name of the file the definition is parsed as, the imports are relative to it.
+const DEFINITION_FILE: &str = "schema.proto";
+/// The file declaring the Iggy options, which can be imported by the definition.
+const OPTIONS_FILE: &str = "iggy/options.proto";
+const OPTIONS_DEFINITION: &str = r#"
+syntax = "proto3";
+package iggy;
+import "google/protobuf/descriptor.proto";
+extend google.protobuf.FileOptions {
+  // The name of the message type of the payloads.
+  string message_type = 50000;
+}
+"#;
+const MESSAGE_TYPE_OPTION: u32 = 50000;
+
+/// The Protobuf schema of the topic, validating the payloads which are encoded messages of its message type.
+/// The definition can import the Google well-known types (e.g. `google/protobuf/timestamp.proto`) and `iggy/options.proto`,
+/// which allows naming the message type with `option (iggy.message_type) = "Order";`, required if the definition declares
+/// more than one top level message.
+#[derive(Debug)]
+pub struct ProtobufSchema {
+    message: MessageDescriptor,
+}
+
+impl ProtobufSchema {
+    /// Parse the `.proto` definition, resolving its imports and the message type of the payloads.
+    pub fn parse(definition: &str) -> Result<Self, String> {
+        let file = parse_file(definition)?;
+        let message = match get_message_type_option(&file)? {
+            Some(name) => {
+                let name = name.strip_prefix('.').unwrap_or(&name);
+                file.message_by_full_name(&format!(".{name}"))
+                    .or_else(|| file.message_by_package_relative_name(name))
+                    .ok_or_else(|| format!("unknown message type: {name}"))?
+            }
+            None => {
+                let mut messages = file.messages();
+                match (messages.next(), messages.next()) {
+                    (Some(message), None) => message,
+                    (None, _) => return Err("definition must declare a message".to_string()),
+                    (Some(_), Some(_)) => {
+                        return Err(
+                            "definition declares multiple messages, the message type must be named with option (iggy.message_type)"
+                                .to_string(),
+                        )
+                    }
+                }
+            }
+        };
+        Ok(ProtobufSchema { message })
+    }
+
+    /// Decode the Protobuf encoded payload of the message type into the JSON value.
+    pub fn decode(&self, payload: &[u8]) -> Result<Value, String> {
+        let message = self
+            .message
+            .parse_from_bytes(payload)
+            .map_err(|error| error.to_string())?;
+        message
+            .check_initialized_dyn()
+            .map_err(|error| error.to_string())?;
+        Ok(message_to_json(&*message))
+    }
+
+    /// Check if the messages encoded with the `writer` schema can be read using this (reader) schema.
+    /// The fields are matched by their numbers and must have wire compatible types and labels.
+    pub fn can_read(&self, writer: &ProtobufSchema) -> Result<(), String> {
+        let mut visited = HashSet::new();
+        can_read_message(&self.message, &writer.message, &mut visited)
+    }
+}
+
+/// Parses the definition along with its imports, the well-known types are bundled with the parser.
+fn parse_file(definition: &str) -> Result<FileDescriptor, String> {
+    let directory = tempfile::tempdir().map_err(|error| error.to_string())?;
+    let definition_path = directory.path().join(DEFINITION_FILE);
+    let options_path = directory.path().join(OPTIONS_FILE);
+    fs::write(&definition_path, definition).map_err(|error| error.to_string())?;
+    if let Some(options_directory) = options_path.parent() {
+        fs::create_dir_all(options_directory).map_err(|error| error.to_string())?;
+    }
+    fs::write(&options_path, OPTIONS_DEFINITION).map_err(|error| error.to_string())?;
+
+    let file_descriptors = protobuf_parse::Parser::new()
+        .pure()
+        .include(directory.path())
+        .input(&definition_path)
+        .parse_and_typecheck()
+        // The temporary directory is not a part of the error, as it's meaningless for the schema author.
+        .map_err(|error| {
+            format!("{error:#}").replace(&format!("{}", directory.path().display()), "")
+        })?
+        .file_descriptors;
+    FileDescriptor::new_dynamic_fds(file_descriptors, &[])
+        .map_err(|error| error.to_string())?
+        .into_iter()
+        .find(|file| file.proto().name() == DEFINITION_FILE)
+        .ok_or_else(|| "definition cannot be parsed".to_string())
+}
+
+fn get_message_type_option(file: &FileDescriptor) -> Result<Option<String>, String> {
+    let Some(options) = file.proto().options.as_ref() else {
+        return Ok(None);
+    };
+    match options
+        .special_fields
+        .unknown_fields()
+        .get(MESSAGE_TYPE_OPTION)
+    {
+        Some(UnknownValueRef::LengthDelimited(name)) => std::str::from_utf8(name)
+            .map(|name| Some(name.to_string()))
+            .map_err(|error| error.to_string()),
+        Some(_) => Err("invalid message type option".to_string()),
+        None => Ok(None),
+    }
+}
+
+fn message_to_json(message: &dyn MessageDyn) -> Value {
+    let mut object = Map::new();
+    for field in message.descriptor_dyn().fields() {
+        let value = match field.get_reflect(message) {
+            ReflectFieldRef::Optional(value) => match value.value() {
+                Some(value) => value_to_json(value),
+                None => continue,
+            },
+            ReflectFieldRef::Repeated(values) => {
+                if values.is_empty() {
+                    continue;
+                }
+                Value::Array(values.into_iter().map(value_to_json).collect())
+            }
+            ReflectFieldRef::Map(entries) => {
+                if entries.is_empty() {
+                    continue;
+                }
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| {
+                            let key = match value_to_json(key) {
+                                Value::String(key) => key,
+                                key => key.to_string(),
+                            };
+                            (key, value_to_json(value))
+                        })
+                        .collect(),
+                )
+            }
+        };
+        object.insert(field.name().to_string(), value);
+    }
+    Value::Object(object)
+}
+
+fn value_to_json(value: ReflectValueRef) -> Value {
+    match value {
+        ReflectValueRef::U32(value) => Value::from(value),
+        ReflectValueRef::U64(value) => Value::from(value),
+        ReflectValueRef::I32(value) => Value::from(value),
+        ReflectValueRef::I64(value) => Value::from(value),
+        ReflectValueRef::F32(value) => {
+            Number::from_f64(value as f64).map_or(Value::Null, Value::Number)
+        }
+        ReflectValueRef::F64(value) => Number::from_f64(value).map_or(Value::Null, Value::Number),
+        ReflectValueRef::Bool(value) => Value::Bool(value),
+        ReflectValueRef::String(value) => Value::String(value.to_string()),
+        ReflectValueRef::Bytes(value) => Value::String(general_purpose::STANDARD.encode(value)),
+        ReflectValueRef::Enum(descriptor, value) => match descriptor.value_by_number(value) {
+            Some(symbol) => Value::String(symbol.name().to_string()),
+            None => Value::from(value),
+        },
+        ReflectValueRef::Message(message) => message_to_json(&*MessageRef::clone(&message)),
+    }
+}
+
+fn can_read_message(
+    reader: &MessageDescriptor,
+    writer: &MessageDescriptor,
+    visited: &mut HashSet<(String, String)>,
+) -> Result<(), String> {
+    if !visited.insert((
+        reader.full_name().to_string(),
+        writer.full_name().to_string(),
+    )) {
+        return Ok(());
+    }
+
+    for reader_field in reader.fields() {
+        let path = format!("{}.{}", reader.name(), reader_field.name());
+        let Some(writer_field) = writer.field_by_number(reader_field.number() as u32) else {
+            if reader_field.is_required() {
+                return Err(format!(
+                    "{path}: required field {} is missing",
+                    reader_field.number()
+                ));
+            }
+            continue;
+        };
+
+        if reader_field.is_required() && !writer_field.is_required() {
+            return Err(format!("{path}: field is required but was optional"));
+        }
+
+        if is_repeated(&reader_field) != is_repeated(&writer_field) {
+            return Err(format!(
+                "{path}: field changed between repeated and singular"
+            ));
+        }
+
+        let reader_group = compatibility_group(&reader_field);
+        let writer_group = compatibility_group(&writer_field);
+        if reader_group != writer_group {
+            return Err(format!(
+                "{path}: type changed from {writer_group} to {reader_group}"
+            ));
+        }
+
+        // The map fields are encoded as the repeated entry messages, so they're compared as such.
+        if let (Some(reader_message), Some(writer_message)) = (
+            get_message_type(&reader_field),
+            get_message_type(&writer_field),
+        ) {
+            can_read_message(&reader_message, &writer_message, visited)?;
+        }
+    }
+    Ok(())
+}
+
+fn is_repeated(field: &FieldDescriptor) -> bool {
+    field.proto().label() == Label::LABEL_REPEATED
+}
+
+fn get_message_type(field: &FieldDescriptor) -> Option<MessageDescriptor> {
+    match field.runtime_field_type() {
+        RuntimeFieldType::Singular(RuntimeType::Message(message))
+        | RuntimeFieldType::Repeated(RuntimeType::Message(message)) => Some(message),
+        RuntimeFieldType::Map(_, _) => {
+            let type_name = field.proto().type_name().strip_prefix('.')?;
+            field
+                .containing_message()
+                .nested_messages()
+                .find(|message| message.full_name() == type_name)
+        }
+        _ => None,
+    }
+}
+
+/// The types within the same group share the wire representation and can be read as each other.
+fn compatibility_group(field: &FieldDescriptor) -> &'static str {
+    match field.proto().type_() {
+        Type::TYPE_INT32
+        | Type::TYPE_INT64
+        | Type::TYPE_UINT32
+        | Type::TYPE_UINT64
+        | Type::TYPE_BOOL
+        | Type::TYPE_ENUM => "varint",
+        Type::TYPE_SINT32 | Type::TYPE_SINT64 => "zigzag",
+        Type::TYPE_FIXED32 | Type::TYPE_SFIXED32 => "fixed32",
+        Type::TYPE_FIXED64 | Type::TYPE_SFIXED64 => "fixed64",
+        Type::TYPE_FLOAT => "float",
+        Type::TYPE_DOUBLE => "double",
+        Type::TYPE_STRING | Type::TYPE_BYTES => "bytes",
+        Type::TYPE_MESSAGE if field.is_map() => "map",
+        Type::TYPE_MESSAGE => "message",
+        Type::TYPE_GROUP => "group",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER_PROTO: &str = r#"
+        syntax = "proto3";
+        package shop;
+
+        // The order placed by the customer.
+        message Order {
+            uint64 id = 1;
+            string customer = 2;
+            repeated Item items = 3;
+            Status status = 4;
+            map<string, int32> attributes = 5;
+            repeated sint32 deltas = 6 [packed = true];
+
+            message Item {
+                string sku = 1;
+                double price = 2;
+            }
+        }
+
+        enum Status {
+            NEW = 0;
+            PAID = 1;
+        }
+    "#;
+
+    fn encode_varint(mut value: u64, bytes: &mut Vec<u8>) {
+        while value > 0x7f {
+            bytes.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        bytes.push(value as u8);
+    }
+
+    fn encode_length_delimited(number: u64, value: &[u8], bytes: &mut Vec<u8>) {
+        encode_varint(number << 3 | 2, bytes);
+        encode_varint(value.len() as u64, bytes);
+        bytes.extend_from_slice(value);
+    }
+
+    fn encode_order() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        encode_varint(1 << 3, &mut bytes);
+        encode_varint(300, &mut bytes);
+        encode_length_delimited(2, b"iggy", &mut bytes);
+        let mut item = Vec::new();
+        encode_length_delimited(1, b"sku-1", &mut item);
+        item.push(2 << 3 | 1);
+        item.extend_from_slice(&9.5f64.to_le_bytes());
+        encode_length_delimited(3, &item, &mut bytes);
+        encode_varint(4 << 3, &mut bytes);
+        encode_varint(1, &mut bytes);
+        let mut entry = Vec::new();
+        encode_length_delimited(1, b"priority", &mut entry);
+        entry.push(2 << 3);
+        encode_varint(7, &mut entry);
+        encode_length_delimited(5, &entry, &mut bytes);
+        encode_length_delimited(6, &[3, 4], &mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn valid_message_should_be_decoded() {
+        let schema = ProtobufSchema::parse(ORDER_PROTO).unwrap();
+        let value = schema.decode(&encode_order()).unwrap();
+        assert_eq!(value["id"], 300);
+        assert_eq!(value["customer"], "iggy");
+        assert_eq!(value["items"][0]["sku"], "sku-1");
+        assert_eq!(value["items"][0]["price"], 9.5);
+        assert_eq!(value["status"], "PAID");
+        assert_eq!(value["attributes"]["priority"], 7);
+        assert_eq!(value["deltas"], serde_json::json!([-2, 2]));
+    }
+
+    #[test]
+    fn invalid_message_should_be_rejected() {
+        let schema = ProtobufSchema::parse(ORDER_PROTO).unwrap();
+        let mut truncated = Vec::new();
+        encode_length_delimited(2, b"iggy", &mut truncated);
+        assert!(schema.decode(&truncated[..truncated.len() - 1]).is_err());
+        let mut invalid_wire_type = Vec::new();
+        encode_length_delimited(1, b"300", &mut invalid_wire_type);
+        assert!(schema.decode(&invalid_wire_type).is_err());
+    }
+
+    #[test]
+    fn invalid_definition_should_be_rejected() {
+        assert!(ProtobufSchema::parse("syntax = \"proto3\";").is_err());
+        assert!(ProtobufSchema::parse("message A { Unknown b = 1; }").is_err());
+        assert!(ProtobufSchema::parse("message A { int32 b = 1; int32 c = 1; }").is_err());
+        assert!(ProtobufSchema::parse("message A { int32 b = 1; ").is_err());
+        assert!(ProtobufSchema::parse("import \"shop/order.proto\"; message A {}").is_err());
+    }
+
+    #[test]
+    fn required_fields_should_be_checked() {
+        let schema =
+            ProtobufSchema::parse("syntax = \"proto2\"; message A { required int32 b = 1; }")
+                .unwrap();
+        assert!(schema.decode(&[]).is_err());
+        assert!(schema.decode(&[8, 1]).is_ok());
+    }
+
+    #[test]
+    fn well_known_types_should_be_imported() {
+        let schema = ProtobufSchema::parse(
+            r#"
+            syntax = "proto3";
+            import "google/protobuf/timestamp.proto";
+            message Event {
+                google.protobuf.Timestamp created_at = 1;
+            }
+            "#,
+        )
+        .unwrap();
+        let mut timestamp = Vec::new();
+        encode_varint(1 << 3, &mut timestamp);
+        encode_varint(1_700_000_000, &mut timestamp);
+        let mut bytes = Vec::new();
+        encode_length_delimited(1, &timestamp, &mut bytes);
+
+        let value = schema.decode(&bytes).unwrap();
+        assert_eq!(value["created_at"]["seconds"], 1_700_000_000);
+    }
+
+    #[test]
+    fn message_type_should_be_named_by_option() {
+        let definition = r#"
+            syntax = "proto3";
+            package shop;
+            import "iggy/options.proto";
+            option (iggy.message_type) = "shop.Order";
+            message Customer {
+                string name = 1;
+            }
+            message Order {
+                uint64 id = 1;
+                Customer customer = 2;
+            }
+        "#;
+        let schema = ProtobufSchema::parse(definition).unwrap();
+        assert_eq!(schema.message.full_name(), "shop.Order");
+        assert!(ProtobufSchema::parse(&definition.replace("shop.Order", "Order")).is_ok());
+        assert!(ProtobufSchema::parse(&definition.replace("shop.Order", "Unknown")).is_err());
+        assert!(ProtobufSchema::parse(
+            &definition.replace("option (iggy.message_type) = \"shop.Order\";", "")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn adding_and_removing_fields_should_be_compatible() {
+        let old = ProtobufSchema::parse(ORDER_PROTO).unwrap();
+        let new = ProtobufSchema::parse(
+            r#"
+            syntax = "proto3";
+            message Order {
+                int64 id = 1;
+                bytes customer = 2;
+                string note = 7;
+            }
+            "#,
+        )
+        .unwrap();
+        assert!(new.can_read(&old).is_ok());
+        assert!(old.can_read(&new).is_ok());
+    }
+
+    #[test]
+    fn changing_field_type_should_be_incompatible() {
+        let old = ProtobufSchema::parse(ORDER_PROTO).unwrap();
+        let new =
+            ProtobufSchema::parse("syntax = \"proto3\"; message Order { string id = 1; }").unwrap();
+        assert!(new.can_read(&old).is_err());
+        assert!(old.can_read(&new).is_err());
+
+        let new = ProtobufSchema::parse(
+            r#"
+            syntax = "proto3";
+            message Order {
+                repeated Item items = 3;
+                message Item {
+                    int32 sku = 1;
+                }
+            }
+            "#,
+        )
+        .unwrap();
+        assert!(new.can_read(&old).is_err());
+    }
+}
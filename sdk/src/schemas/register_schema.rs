@@ -0,0 +1,211 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::schemas::MAX_SCHEMA_DEFINITION_LENGTH;
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `RegisterSchema` command is used to register a new version of the schema for the topic.
+/// The definition is checked against the latest version of the topic schema using the provided compatibility rule.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `schema_type` - the format of the schema (JSON Schema, Avro or Protobuf).
+/// - `compatibility` - the compatibility rule enforced against the previous version, `backward` by default.
+/// - `validation_enabled` - whether the payloads of the appended messages are validated against the schema.
+/// - `definition` - the schema definition, must not be empty and at most 1 MB long.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RegisterSchema {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// The format of the schema.
+    pub schema_type: SchemaType,
+    /// The compatibility rule enforced against the previous version.
+    #[serde(default)]
+    pub compatibility: SchemaCompatibility,
+    /// Whether the payloads of the appended messages are validated against the schema.
+    #[serde(default)]
+    pub validation_enabled: bool,
+    /// The schema definition.
+    pub definition: String,
+}
+
+impl CommandPayload for RegisterSchema {}
+
+impl Default for RegisterSchema {
+    fn default() -> Self {
+        RegisterSchema {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            schema_type: SchemaType::Json,
+            compatibility: SchemaCompatibility::default(),
+            validation_enabled: false,
+            definition: r#"{"type":"object"}"#.to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for RegisterSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.definition.trim().is_empty() {
+            return Err(IggyError::InvalidSchemaDefinition(
+                "definition cannot be empty".to_string(),
+            ));
+        }
+
+        if self.definition.len() > MAX_SCHEMA_DEFINITION_LENGTH {
+            return Err(IggyError::InvalidSchemaDefinition(format!(
+                "definition cannot be longer than {MAX_SCHEMA_DEFINITION_LENGTH} bytes"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RegisterSchema {
+    fn as_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            7 + stream_id_bytes.len() + topic_id_bytes.len() + self.definition.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.schema_type.as_code());
+        bytes.put_u8(self.compatibility.as_code());
+        bytes.put_u8(if self.validation_enabled { 1 } else { 0 });
+        bytes.put_u32_le(self.definition.len() as u32);
+        bytes.put_slice(self.definition.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RegisterSchema, IggyError> {
+        if bytes.len() < 14 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() < position + 7 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let schema_type = SchemaType::from_code(bytes[position])?;
+        let compatibility = SchemaCompatibility::from_code(bytes[position + 1])?;
+        let validation_enabled = match bytes[position + 2] {
+            0 => false,
+            1 => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        let definition_length =
+            u32::from_le_bytes(bytes[position + 3..position + 7].try_into()?) as usize;
+        position += 7;
+        if bytes.len() != position + definition_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let definition = from_utf8(&bytes[position..])?.to_string();
+        let command = RegisterSchema {
+            stream_id,
+            topic_id,
+            schema_type,
+            compatibility,
+            validation_enabled,
+            definition,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RegisterSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.schema_type,
+            self.compatibility,
+            self.validation_enabled,
+            self.definition.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RegisterSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            schema_type: SchemaType::Avro,
+            compatibility: SchemaCompatibility::Full,
+            validation_enabled: true,
+            definition: r#""string""#.to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let schema_type = SchemaType::from_code(bytes[position]).unwrap();
+        let compatibility = SchemaCompatibility::from_code(bytes[position + 1]).unwrap();
+        let validation_enabled = bytes[position + 2] == 1;
+        let definition_length =
+            u32::from_le_bytes(bytes[position + 3..position + 7].try_into().unwrap()) as usize;
+        let definition = from_utf8(&bytes[position + 7..]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(schema_type, command.schema_type);
+        assert_eq!(compatibility, command.compatibility);
+        assert_eq!(validation_enabled, command.validation_enabled);
+        assert_eq!(definition_length, command.definition.len());
+        assert_eq!(definition, command.definition);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = RegisterSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("orders").unwrap(),
+            schema_type: SchemaType::Protobuf,
+            compatibility: SchemaCompatibility::Forward,
+            validation_enabled: false,
+            definition: "message Order { uint64 id = 1; }".to_string(),
+        };
+
+        let deserialized = RegisterSchema::from_bytes(command.as_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn empty_definition_should_be_rejected() {
+        let command = RegisterSchema {
+            definition: " ".to_string(),
+            ..Default::default()
+        };
+        assert!(RegisterSchema::from_bytes(command.as_bytes()).is_err());
+    }
+}
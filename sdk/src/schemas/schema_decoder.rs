@@ -0,0 +1,62 @@
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::models::messages::PolledMessage;
+use crate::schemas::schema_definition::SchemaDefinition;
+use crate::schemas::schema_id::extract_schema_id;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// `SchemaDecoder` decodes the payloads of the polled messages using the schema referenced by the `iggy-schema-id` header.
+/// The schemas are fetched from the registry on the first use and cached, as the registered schema versions are immutable.
+#[derive(Debug, Default)]
+pub struct SchemaDecoder {
+    schemas: RwLock<HashMap<u32, Arc<SchemaDefinition>>>,
+}
+
+impl SchemaDecoder {
+    /// Create a new schema decoder with the empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode the payload of the message into the JSON value, or return `None` if the message has no schema header.
+    pub async fn decode<C: SchemaClient + Sync + ?Sized>(
+        &self,
+        client: &C,
+        message: &PolledMessage,
+    ) -> Result<Option<Value>, IggyError> {
+        let Some(schema_id) = extract_schema_id(&message.headers)? else {
+            return Ok(None);
+        };
+
+        let schema = self.get_schema(client, schema_id).await?;
+        schema
+            .decode(&message.payload)
+            .map(Some)
+            .map_err(|error| IggyError::InvalidSchemaPayload(schema_id, error))
+    }
+
+    /// Get the parsed schema by unique ID, fetching it from the registry if it's not cached yet.
+    pub async fn get_schema<C: SchemaClient + Sync + ?Sized>(
+        &self,
+        client: &C,
+        schema_id: u32,
+    ) -> Result<Arc<SchemaDefinition>, IggyError> {
+        if let Some(schema) = self.schemas.read().await.get(&schema_id) {
+            return Ok(schema.clone());
+        }
+
+        let schema = client.get_schema(schema_id).await?;
+        let definition = Arc::new(SchemaDefinition::parse(
+            schema.schema_type,
+            &schema.definition,
+        )?);
+        self.schemas
+            .write()
+            .await
+            .insert(schema_id, definition.clone());
+        Ok(definition)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::error::IggyError;
+use crate::schemas::avro::AvroSchema;
+use crate::schemas::json::JsonSchema;
+use crate::schemas::protobuf::ProtobufSchema;
+use crate::utils::schema_compatibility::SchemaCompatibility;
+use crate::utils::schema_type::SchemaType;
+use serde_json::Value;
+
+/// The parsed schema definition, used to validate and decode the payloads and to check the compatibility between the schema versions.
+#[derive(Debug)]
+pub enum SchemaDefinition {
+    /// JSON Schema.
+    Json(JsonSchema),
+    /// Apache Avro schema.
+    Avro(AvroSchema),
+    /// Protocol Buffers definition.
+    Protobuf(ProtobufSchema),
+}
+
+impl SchemaDefinition {
+    /// Parse the definition of the given schema type.
+    pub fn parse(schema_type: SchemaType, definition: &str) -> Result<Self, IggyError> {
+        let schema = match schema_type {
+            SchemaType::Json => JsonSchema::parse(definition).map(SchemaDefinition::Json),
+            SchemaType::Avro => AvroSchema::parse(definition).map(SchemaDefinition::Avro),
+            SchemaType::Protobuf => {
+                ProtobufSchema::parse(definition).map(SchemaDefinition::Protobuf)
+            }
+        };
+        schema.map_err(IggyError::InvalidSchemaDefinition)
+    }
+
+    /// Get the type of the schema.
+    pub fn schema_type(&self) -> SchemaType {
+        match self {
+            SchemaDefinition::Json(_) => SchemaType::Json,
+            SchemaDefinition::Avro(_) => SchemaType::Avro,
+            SchemaDefinition::Protobuf(_) => SchemaType::Protobuf,
+        }
+    }
+
+    /// Validate the payload against the schema and decode it into the JSON value.
+    pub fn decode(&self, payload: &[u8]) -> Result<Value, String> {
+        match self {
+            SchemaDefinition::Json(schema) => schema.decode(payload),
+            SchemaDefinition::Avro(schema) => schema.decode(payload),
+            SchemaDefinition::Protobuf(schema) => schema.decode(payload),
+        }
+    }
+
+    /// Check if this schema can replace the `previous` one using the given compatibility rule.
+    pub fn check_compatibility(
+        &self,
+        previous: &SchemaDefinition,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        if compatibility == SchemaCompatibility::None {
+            return Ok(());
+        }
+
+        if compatibility.is_backward() {
+            self.can_read(previous).map_err(|error| {
+                IggyError::IncompatibleSchema(format!("not backward compatible, {error}"))
+            })?;
+        }
+
+        if compatibility.is_forward() {
+            previous.can_read(self).map_err(|error| {
+                IggyError::IncompatibleSchema(format!("not forward compatible, {error}"))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn can_read(&self, writer: &SchemaDefinition) -> Result<(), String> {
+        match (self, writer) {
+            (SchemaDefinition::Json(reader), SchemaDefinition::Json(writer)) => {
+                reader.can_read(writer)
+            }
+            (SchemaDefinition::Avro(reader), SchemaDefinition::Avro(writer)) => {
+                reader.can_read(writer)
+            }
+            (SchemaDefinition::Protobuf(reader), SchemaDefinition::Protobuf(writer)) => {
+                reader.can_read(writer)
+            }
+            _ => Err(format!(
+                "schema type changed from {} to {}",
+                writer.schema_type(),
+                self.schema_type()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compatibility_should_be_checked_according_to_the_rule() {
+        let previous = SchemaDefinition::parse(
+            SchemaType::Json,
+            r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#,
+        )
+        .unwrap();
+        let next = SchemaDefinition::parse(
+            SchemaType::Json,
+            r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}},"required":["id","name"]}"#,
+        )
+        .unwrap();
+
+        assert!(next
+            .check_compatibility(&previous, SchemaCompatibility::None)
+            .is_ok());
+        assert!(next
+            .check_compatibility(&previous, SchemaCompatibility::Forward)
+            .is_ok());
+        assert!(matches!(
+            next.check_compatibility(&previous, SchemaCompatibility::Backward),
+            Err(IggyError::IncompatibleSchema(_))
+        ));
+        assert!(next
+            .check_compatibility(&previous, SchemaCompatibility::Full)
+            .is_err());
+    }
+
+    #[test]
+    fn changing_schema_type_should_be_incompatible() {
+        let previous = SchemaDefinition::parse(SchemaType::Json, r#"{"type":"string"}"#).unwrap();
+        let next = SchemaDefinition::parse(SchemaType::Avro, r#""string""#).unwrap();
+        assert!(next
+            .check_compatibility(&previous, SchemaCompatibility::Backward)
+            .is_err());
+        assert!(next
+            .check_compatibility(&previous, SchemaCompatibility::None)
+            .is_ok());
+    }
+}
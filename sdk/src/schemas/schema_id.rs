@@ -0,0 +1,67 @@
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+
+/// The name of the reserved message header holding the ID of the schema the payload was encoded with.
+/// If the producer doesn't set it, the server stamps the ID of the latest schema registered for the topic.
+pub const SCHEMA_ID_HEADER: &str = "iggy-schema-id";
+
+/// Inject the schema ID into the headers, replacing the existing one if present.
+pub fn inject_schema_id(
+    headers: &mut Option<HashMap<HeaderKey, HeaderValue>>,
+    schema_id: u32,
+) -> Result<(), IggyError> {
+    headers.get_or_insert_with(HashMap::new).insert(
+        HeaderKey::new(SCHEMA_ID_HEADER)?,
+        HeaderValue::from_uint32(schema_id)?,
+    );
+    Ok(())
+}
+
+/// Extract the schema ID from the headers, if the schema header is present.
+pub fn extract_schema_id(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<u32>, IggyError> {
+    let Some(headers) = headers.as_ref() else {
+        return Ok(None);
+    };
+
+    let Some(value) = headers.get(&HeaderKey::new(SCHEMA_ID_HEADER)?) else {
+        return Ok(None);
+    };
+
+    match value.as_uint32() {
+        Ok(schema_id) if schema_id > 0 => Ok(Some(schema_id)),
+        _ => Err(IggyError::InvalidSchemaId),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn schema_id_should_be_injected_and_extracted_from_headers() {
+        let mut headers = None;
+        inject_schema_id(&mut headers, 5).unwrap();
+        inject_schema_id(&mut headers, 7).unwrap();
+        assert_eq!(headers.as_ref().unwrap().len(), 1);
+        assert_eq!(extract_schema_id(&headers).unwrap(), Some(7));
+        assert_eq!(extract_schema_id(&None).unwrap(), None);
+    }
+
+    #[test]
+    fn invalid_schema_id_header_should_not_be_extracted() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderKey::new(SCHEMA_ID_HEADER).unwrap(),
+            HeaderValue::from_str("orders-v1").unwrap(),
+        );
+
+        assert!(matches!(
+            extract_schema_id(&Some(headers)),
+            Err(IggyError::InvalidSchemaId)
+        ));
+    }
+}
@@ -2,6 +2,7 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::topics::get_topic::TopicLayoutVersion;
 use crate::validatable::Validatable;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,9 @@ pub struct GetStream {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
     pub stream_id: Identifier,
+    /// Version of the binary layout of the returned stream and its topics, determined by the command code.
+    #[serde(skip)]
+    pub version: TopicLayoutVersion,
 }
 
 impl CommandPayload for GetStream {}
@@ -36,7 +40,10 @@ impl BytesSerializable for GetStream {
         }
 
         let stream_id = Identifier::from_bytes(bytes)?;
-        let command = GetStream { stream_id };
+        let command = GetStream {
+            stream_id,
+            version: TopicLayoutVersion::default(),
+        };
         command.validate()?;
         Ok(command)
     }
@@ -56,6 +63,7 @@ mod tests {
     fn should_be_serialized_as_bytes() {
         let command = GetStream {
             stream_id: Identifier::numeric(1).unwrap(),
+            version: TopicLayoutVersion::default(),
         };
 
         let bytes = command.as_bytes();
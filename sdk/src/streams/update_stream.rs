@@ -2,7 +2,9 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::topic_config_overrides::TopicConfigOverrides;
 use crate::streams::MAX_NAME_LENGTH;
+use crate::topics::topic_options::TopicOptions;
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -14,11 +16,17 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `name` - unique stream name (string), max length is 255 characters.
+/// - `topic_config_overrides` - optional default settings overriding the server configuration for all the topics in the stream,
+///   each topic can override them further. If `None` then the current ones are kept.
+///   They're sent in the versioned topic options block after the name (without the durability), so the clients which don't send it keep the current settings.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct UpdateStream {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
     pub stream_id: Identifier,
+    /// Optional default settings overriding the server configuration for all the topics in the stream, if `None` then the current ones are kept.
+    #[serde(default)]
+    pub topic_config_overrides: Option<TopicConfigOverrides>,
     /// Unique stream name (string), max length is 255 characters.
     pub name: String,
 }
@@ -29,6 +37,7 @@ impl Default for UpdateStream {
     fn default() -> Self {
         UpdateStream {
             stream_id: Identifier::default(),
+            topic_config_overrides: None,
             name: "stream".to_string(),
         }
     }
@@ -44,6 +53,10 @@ impl Validatable<IggyError> for UpdateStream {
             return Err(IggyError::InvalidStreamName);
         }
 
+        if let Some(topic_config_overrides) = &self.topic_config_overrides {
+            topic_config_overrides.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -51,11 +64,20 @@ impl Validatable<IggyError> for UpdateStream {
 impl BytesSerializable for UpdateStream {
     fn as_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.as_bytes();
-        let mut bytes = BytesMut::with_capacity(1 + stream_id_bytes.len() + self.name.len());
+        let options_bytes = TopicOptions {
+            durability: None,
+            config_overrides: self.topic_config_overrides,
+            partitioning_scheme: None,
+        }
+        .as_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            1 + stream_id_bytes.len() + self.name.len() + options_bytes.len(),
+        );
         bytes.put_slice(&stream_id_bytes);
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        bytes.put_slice(&options_bytes);
         bytes.freeze()
     }
 
@@ -67,14 +89,29 @@ impl BytesSerializable for UpdateStream {
         let mut position = 0;
         let stream_id = Identifier::from_bytes(bytes.clone())?;
         position += stream_id.get_size_bytes() as usize;
-        let name_length = bytes[position];
-        let name =
-            from_utf8(&bytes[position + 1..position + 1 + name_length as usize])?.to_string();
-        if name.len() != name_length as usize {
+        if bytes.len() <= position {
+            return Err(IggyError::InvalidCommand);
+        }
+        let name_length = bytes[position] as usize;
+        position += 1;
+        if bytes.len() < position + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+        let name = from_utf8(&bytes[position..position + name_length])?.to_string();
+        position += name_length;
+        let options = TopicOptions::from_bytes(bytes.slice(position..))?;
+        // The durability is set per topic, so the streams don't have the default one,
+        // and the partitioning scheme is set with the config overrides.
+        if options.durability.is_some() || options.partitioning_scheme.is_some() {
             return Err(IggyError::InvalidCommand);
         }
+        let topic_config_overrides = options.config_overrides;
 
-        let command = UpdateStream { stream_id, name };
+        let command = UpdateStream {
+            stream_id,
+            topic_config_overrides,
+            name,
+        };
         command.validate()?;
         Ok(command)
     }
@@ -82,18 +119,32 @@ impl BytesSerializable for UpdateStream {
 
 impl Display for UpdateStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id, self.name)
+        let topic_config_overrides = match &self.topic_config_overrides {
+            Some(topic_config_overrides) => topic_config_overrides.to_string(),
+            None => String::from("current"),
+        };
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id, topic_config_overrides, self.name
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::byte_size::IggyByteSize;
+    use crate::utils::durability::Durability;
 
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = UpdateStream {
             stream_id: Identifier::numeric(1).unwrap(),
+            topic_config_overrides: Some(TopicConfigOverrides {
+                segment_size: Some(IggyByteSize::from(1000)),
+                ..Default::default()
+            }),
             name: "test".to_string(),
         };
 
@@ -105,16 +156,24 @@ mod tests {
         let name = from_utf8(&bytes[position + 1..position + 1 + name_length as usize])
             .unwrap()
             .to_string();
+        position += 1 + name_length as usize;
+        let options = TopicOptions::from_bytes(bytes.slice(position..)).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(name, command.name);
+        assert_eq!(options.durability, None);
+        assert_eq!(options.config_overrides, command.topic_config_overrides);
     }
 
     #[test]
     fn should_be_deserialized_from_bytes() {
         let stream_id = Identifier::numeric(1).unwrap();
         let name = "test".to_string();
+        let topic_config_overrides = TopicConfigOverrides {
+            enforce_fsync: Some(false),
+            ..Default::default()
+        };
 
         let stream_id_bytes = stream_id.as_bytes();
         let mut bytes = BytesMut::with_capacity(1 + stream_id_bytes.len() + name.len());
@@ -122,11 +181,56 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.put_slice(name.as_bytes());
+        bytes.put_slice(
+            &TopicOptions {
+                durability: None,
+                config_overrides: Some(topic_config_overrides),
+                partitioning_scheme: None,
+            }
+            .as_bytes(),
+        );
         let command = UpdateStream::from_bytes(bytes.freeze());
         assert!(command.is_ok());
 
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_config_overrides, Some(topic_config_overrides));
+        assert_eq!(command.name, name);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_options() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let name = "test".to_string();
+        let stream_id_bytes = stream_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(1 + stream_id_bytes.len() + name.len());
+        bytes.put_slice(&stream_id_bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+
+        let command = UpdateStream::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.name, name);
+        assert_eq!(command.topic_config_overrides, None);
+    }
+
+    #[test]
+    fn durability_should_be_rejected() {
+        let command = UpdateStream::default();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&command.stream_id.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(command.name.len() as u8);
+        bytes.put_slice(command.name.as_bytes());
+        bytes.put_slice(
+            &TopicOptions {
+                durability: Some(Durability::EveryBatch),
+                config_overrides: None,
+                partitioning_scheme: None,
+            }
+            .as_bytes(),
+        );
+        assert!(UpdateStream::from_bytes(bytes.freeze()).is_err());
     }
 }
@@ -3,8 +3,11 @@ use crate::command::CommandPayload;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::topic_config_overrides::TopicConfigOverrides;
+use crate::topics::topic_options::TopicOptions;
 use crate::topics::{MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -22,6 +25,11 @@ use std::str::from_utf8;
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters. The name will be always converted to lowercase and all whitespaces will be replaced with dots.
+/// - `durability` - optional durability level of the topic, if `None` then the server default is used.
+/// - `config_overrides` - settings overriding the server configuration (and the stream defaults) for this topic.
+///
+/// The `durability` and `config_overrides` are sent in the versioned options block after the name,
+/// so the clients which don't send it get the server defaults.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateTopic {
     /// Unique stream ID (numeric or name).
@@ -39,6 +47,12 @@ pub struct CreateTopic {
     pub max_topic_size: Option<IggyByteSize>,
     /// Replication factor for the topic.
     pub replication_factor: Option<u8>,
+    /// Optional durability level of the topic, if `None` then the server default is used.
+    #[serde(default)]
+    pub durability: Option<Durability>,
+    /// Settings overriding the server configuration (and the stream defaults) for this topic.
+    #[serde(default)]
+    pub config_overrides: TopicConfigOverrides,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
 }
@@ -55,6 +69,8 @@ impl Default for CreateTopic {
             message_expiry: None,
             max_topic_size: None,
             replication_factor: None,
+            durability: None,
+            config_overrides: TopicConfigOverrides::default(),
             name: "topic".to_string(),
         }
     }
@@ -86,6 +102,13 @@ impl Validatable<IggyError> for CreateTopic {
             }
         }
 
+        self.config_overrides.validate()?;
+        if self.durability == Some(Durability::None)
+            && self.config_overrides.enforce_fsync == Some(true)
+        {
+            return Err(IggyError::FsyncEnforcedWithoutDurability);
+        }
+
         Ok(())
     }
 }
@@ -93,7 +116,15 @@ impl Validatable<IggyError> for CreateTopic {
 impl BytesSerializable for CreateTopic {
     fn as_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.as_bytes();
-        let mut bytes = BytesMut::with_capacity(23 + stream_id_bytes.len() + self.name.len());
+        let options_bytes = TopicOptions {
+            durability: self.durability,
+            config_overrides: Some(self.config_overrides),
+            partitioning_scheme: None,
+        }
+        .as_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            23 + stream_id_bytes.len() + self.name.len() + options_bytes.len(),
+        );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_u32_le(self.topic_id.unwrap_or(0));
         bytes.put_u32_le(self.partitions_count);
@@ -113,6 +144,7 @@ impl BytesSerializable for CreateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        bytes.put_slice(&options_bytes);
         bytes.freeze()
     }
 
@@ -141,10 +173,20 @@ impl BytesSerializable for CreateTopic {
             0 => None,
             factor => Some(factor),
         };
-        let name_length = bytes[position + 22];
-        let name =
-            from_utf8(&bytes[position + 23..(position + 23 + name_length as usize)])?.to_string();
-        if name.len() != name_length as usize {
+        position += 22;
+        if bytes.len() <= position {
+            return Err(IggyError::InvalidCommand);
+        }
+        let name_length = bytes[position] as usize;
+        position += 1;
+        if bytes.len() < position + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+        let name = from_utf8(&bytes[position..position + name_length])?.to_string();
+        position += name_length;
+        let options = TopicOptions::from_bytes(bytes.slice(position..))?;
+        // The scheme is set with the config overrides, the one in the options is only returned by the server.
+        if options.partitioning_scheme.is_some() {
             return Err(IggyError::InvalidCommand);
         }
         let command = CreateTopic {
@@ -155,6 +197,8 @@ impl BytesSerializable for CreateTopic {
             message_expiry,
             max_topic_size,
             replication_factor,
+            durability: options.durability,
+            config_overrides: options.config_overrides.unwrap_or_default(),
             name,
         };
         command.validate()?;
@@ -168,15 +212,21 @@ impl Display for CreateTopic {
             Some(max_topic_size) => max_topic_size.to_string(),
             None => "unlimited".to_string(),
         };
+        let durability = match self.durability {
+            Some(durability) => durability.to_string(),
+            None => "default".to_string(),
+        };
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id.unwrap_or(0),
             self.partitions_count,
             self.message_expiry.unwrap_or(0),
             max_topic_size,
             self.replication_factor.unwrap_or(0),
+            durability,
+            self.config_overrides,
             self.name
         )
     }
@@ -185,6 +235,7 @@ impl Display for CreateTopic {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::partitioning_scheme::PartitioningScheme;
     use bytes::BufMut;
 
     #[test]
@@ -197,6 +248,12 @@ mod tests {
             compression_algorithm: CompressionAlgorithm::None,
             max_topic_size: Some(IggyByteSize::from(100)),
             replication_factor: Some(1),
+            durability: Some(Durability::GroupCommit),
+            config_overrides: TopicConfigOverrides {
+                segment_size: Some(IggyByteSize::from(1000)),
+                enforce_fsync: Some(true),
+                ..Default::default()
+            },
             name: "test".to_string(),
         };
         let bytes = command.as_bytes();
@@ -218,10 +275,13 @@ mod tests {
                 size => Some(IggyByteSize::from(size)),
             };
         let replication_factor = bytes[position + 21];
-        let name_length = bytes[position + 22];
-        let name = from_utf8(&bytes[position + 23..(position + 23 + name_length as usize)])
+        position += 22;
+        let name_length = bytes[position];
+        let name = from_utf8(&bytes[position + 1..(position + 1 + name_length as usize)])
             .unwrap()
             .to_string();
+        position += 1 + name_length as usize;
+        let options = TopicOptions::from_bytes(bytes.slice(position..)).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -231,6 +291,8 @@ mod tests {
         assert_eq!(message_expiry, command.message_expiry);
         assert_eq!(max_topic_size, command.max_topic_size);
         assert_eq!(replication_factor, command.replication_factor.unwrap());
+        assert_eq!(options.durability, command.durability);
+        assert_eq!(options.config_overrides, Some(command.config_overrides));
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
     }
@@ -245,8 +307,14 @@ mod tests {
         let message_expiry = 10;
         let max_topic_size = IggyByteSize::from(100);
         let replication_factor = 1;
+        let durability = Durability::EveryBatch;
+        let config_overrides = TopicConfigOverrides {
+            cache_indexes: Some(false),
+            message_deduplication: Some(true),
+            ..Default::default()
+        };
         let stream_id_bytes = stream_id.as_bytes();
-        let mut bytes = BytesMut::with_capacity(14 + stream_id_bytes.len() + name.len());
+        let mut bytes = BytesMut::with_capacity(15 + stream_id_bytes.len() + name.len());
         bytes.put_slice(&stream_id_bytes);
         bytes.put_u32_le(topic_id);
         bytes.put_u32_le(partitions_count);
@@ -257,6 +325,14 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.put_slice(name.as_bytes());
+        bytes.put_slice(
+            &TopicOptions {
+                durability: Some(durability),
+                config_overrides: Some(config_overrides),
+                partitioning_scheme: None,
+            }
+            .as_bytes(),
+        );
 
         let command = CreateTopic::from_bytes(bytes.freeze());
         assert!(command.is_ok());
@@ -270,6 +346,67 @@ mod tests {
         assert_eq!(command.message_expiry, Some(message_expiry));
         assert_eq!(command.max_topic_size, Some(max_topic_size));
         assert_eq!(command.replication_factor.unwrap(), replication_factor);
+        assert_eq!(command.durability, Some(durability));
+        assert_eq!(command.config_overrides, config_overrides);
         assert_eq!(command.partitions_count, partitions_count);
     }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_options() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let name = "test".to_string();
+        let stream_id_bytes = stream_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(23 + stream_id_bytes.len() + name.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(3);
+        bytes.put_u8(CompressionAlgorithm::None.as_code());
+        bytes.put_u32_le(0);
+        bytes.put_u64_le(0);
+        bytes.put_u8(1);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+
+        let command = CreateTopic::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, Some(2));
+        assert_eq!(command.partitions_count, 3);
+        assert_eq!(command.name, name);
+        assert_eq!(command.durability, None);
+        assert!(command.config_overrides.is_empty());
+    }
+
+    #[test]
+    fn should_not_be_valid_when_fsync_is_enforced_without_durability() {
+        let mut command = CreateTopic {
+            durability: Some(Durability::None),
+            config_overrides: TopicConfigOverrides {
+                enforce_fsync: Some(true),
+                ..Default::default()
+            },
+            name: "test".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::FsyncEnforcedWithoutDurability)
+        ));
+
+        command.durability = Some(Durability::EveryBatch);
+        assert!(command.validate().is_ok());
+    }
+
+    #[test]
+    fn partitioning_scheme_of_options_should_be_rejected() {
+        let command = CreateTopic {
+            name: "test".to_string(),
+            ..Default::default()
+        };
+        let mut bytes = BytesMut::from(&command.as_bytes()[..]);
+        // The partitioning scheme is the last field of the options block.
+        let last = bytes.len() - 1;
+        bytes[last] = PartitioningScheme::JumpHash.as_code();
+        assert!(CreateTopic::from_bytes(bytes.freeze()).is_err());
+    }
 }
@@ -19,6 +19,25 @@ pub struct GetTopic {
     /// Unique topic ID (numeric or name).
     #[serde(skip)]
     pub topic_id: Identifier,
+    /// Version of the binary layout of the returned topic, determined by the command code.
+    #[serde(skip)]
+    pub version: TopicLayoutVersion,
+}
+
+/// `TopicLayoutVersion` is the version of the binary layout of the topics returned by `GetTopic`, `GetTopics`
+/// and `GetStream` commands.
+/// It has the following versions:
+/// - `V1` - the original layout: ID, created at, partitions count, message expiry, compression algorithm, max topic size,
+///   replication factor, size, messages count and name.
+/// - `V2` - the layout extended with the topic options block (see `TopicOptions`) after the name of each topic,
+///   and after the name of the stream returned by `GetStream`.
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+pub enum TopicLayoutVersion {
+    /// The original layout.
+    V1,
+    /// The layout extended with the topic options block.
+    #[default]
+    V2,
 }
 
 impl CommandPayload for GetTopic {}
@@ -51,6 +70,7 @@ impl BytesSerializable for GetTopic {
         let command = GetTopic {
             stream_id,
             topic_id,
+            version: TopicLayoutVersion::default(),
         };
         command.validate()?;
         Ok(command)
@@ -74,6 +94,7 @@ mod tests {
         let command = GetTopic {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
+            version: TopicLayoutVersion::default(),
         };
 
         let bytes = command.as_bytes();
@@ -5,5 +5,7 @@ pub mod get_topics;
 pub mod purge_topic;
 pub mod update_topic;
 
+pub mod topic_options;
+
 const MAX_NAME_LENGTH: usize = 255;
 const MAX_PARTITIONS_COUNT: u32 = 1000;
@@ -0,0 +1,247 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::models::topic_config_overrides::TopicConfigOverrides;
+use crate::utils::durability::Durability;
+use crate::utils::partitioning_scheme::PartitioningScheme;
+use bytes::{BufMut, Bytes, BytesMut};
+
+/// `TopicOptions` are the optional settings of the topic, sent after the name by the `CreateTopic`, `UpdateTopic`
+/// and `UpdateStream` (as the defaults of its topics) commands, and returned after the name of each topic
+/// (and of the stream) by the V2 `GetTopic`, `GetTopics` and `GetStream` commands.
+///
+/// They're serialized as the block prefixed with its length and version:
+/// - `length` - length of the block excluding this field (u32).
+/// - `version` - version of the block, which determines the fields it contains (u8).
+/// - `durability` - durability level code, 0 if not set (u8, since version 1).
+/// - `config_overrides` - settings overriding the server configuration (64 bytes, since version 2).
+/// - `partitioning_scheme` - partitioning scheme code of the topic, 0 if not set (u8, since version 3),
+///   only returned by the server, as the commands set the scheme with the config overrides.
+///
+/// The fields missing from the older versions (or the whole block) are `None`, so the server defaults are used when creating
+/// and the current settings are kept when updating. The fields added by the newer versions are skipped by the servers which
+/// don't know them yet.
+#[derive(Debug, Default, PartialEq)]
+pub struct TopicOptions {
+    /// Durability level of the topic.
+    pub durability: Option<Durability>,
+    /// Settings overriding the server configuration.
+    pub config_overrides: Option<TopicConfigOverrides>,
+    /// Partitioning scheme of the topic.
+    pub partitioning_scheme: Option<PartitioningScheme>,
+}
+
+impl TopicOptions {
+    pub const VERSION: u8 = 3;
+    const VERSION_1_SIZE_BYTES: u32 = 2;
+    const VERSION_2_SIZE_BYTES: u32 =
+        Self::VERSION_1_SIZE_BYTES + TopicConfigOverrides::SIZE_BYTES as u32;
+    const VERSION_3_SIZE_BYTES: u32 = Self::VERSION_2_SIZE_BYTES + 1;
+
+    /// Writes the options block, which is omitted if none of the options is set.
+    pub fn as_bytes(&self) -> Bytes {
+        if self.durability.is_none()
+            && self.config_overrides.is_none()
+            && self.partitioning_scheme.is_none()
+        {
+            return Bytes::new();
+        }
+
+        self.as_block_bytes()
+    }
+
+    /// Writes the options block even if none of the options is set, as the responses always contain it.
+    pub fn as_block_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4 + Self::VERSION_3_SIZE_BYTES as usize);
+        bytes.put_u32_le(Self::VERSION_3_SIZE_BYTES);
+        bytes.put_u8(Self::VERSION);
+        match self.durability {
+            Some(durability) => bytes.put_u8(durability.as_code()),
+            None => bytes.put_u8(0),
+        }
+        bytes.put_slice(&self.config_overrides.unwrap_or_default().as_bytes());
+        match self.partitioning_scheme {
+            Some(partitioning_scheme) => bytes.put_u8(partitioning_scheme.as_code()),
+            None => bytes.put_u8(0),
+        }
+        bytes.freeze()
+    }
+
+    /// Reads the options from the bytes remaining after the name, which are empty if the client didn't send them.
+    pub fn from_bytes(bytes: Bytes) -> Result<TopicOptions, IggyError> {
+        if bytes.is_empty() {
+            return Ok(TopicOptions::default());
+        }
+
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let length = u32::from_le_bytes(bytes[..4].try_into()?);
+        if length < Self::VERSION_1_SIZE_BYTES || bytes.len() != 4 + length as usize {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let version = bytes[4];
+        if version == 0
+            || (version >= 2 && length < Self::VERSION_2_SIZE_BYTES)
+            || (version >= 3 && length < Self::VERSION_3_SIZE_BYTES)
+        {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let durability = match bytes[5] {
+            0 => None,
+            code => Some(Durability::from_code(code)?),
+        };
+        let config_overrides = match version {
+            1 => None,
+            _ => Some(TopicConfigOverrides::from_bytes(
+                bytes.slice(6..6 + TopicConfigOverrides::SIZE_BYTES),
+            )?),
+        };
+        let partitioning_scheme = match version {
+            1 | 2 => None,
+            _ => match bytes[6 + TopicConfigOverrides::SIZE_BYTES] {
+                0 => None,
+                code => Some(PartitioningScheme::from_code(code)?),
+            },
+        };
+        Ok(TopicOptions {
+            durability,
+            config_overrides,
+            partitioning_scheme,
+        })
+    }
+
+    /// Reads the options block starting at the position of the response, returning the options and the size of the block.
+    pub fn from_block_bytes(
+        bytes: &Bytes,
+        position: usize,
+    ) -> Result<(TopicOptions, usize), IggyError> {
+        if bytes.len() < position + 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+        if bytes.len() < position + 4 + length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let options = Self::from_bytes(bytes.slice(position..position + 4 + length))?;
+        Ok((options, 4 + length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::byte_size::IggyByteSize;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let options = TopicOptions {
+            durability: Some(Durability::GroupCommit),
+            config_overrides: Some(TopicConfigOverrides {
+                segment_size: Some(IggyByteSize::from(1000)),
+                enforce_fsync: Some(true),
+                ..Default::default()
+            }),
+            partitioning_scheme: Some(PartitioningScheme::JumpHash),
+        };
+        let bytes = options.as_bytes();
+        assert_eq!(TopicOptions::from_bytes(bytes).unwrap(), options);
+    }
+
+    #[test]
+    fn block_without_options_should_be_omitted() {
+        assert!(TopicOptions::default().as_bytes().is_empty());
+    }
+
+    #[test]
+    fn block_should_be_read_from_the_middle_of_response() {
+        let options = TopicOptions {
+            durability: Some(Durability::EveryBatch),
+            config_overrides: None,
+            partitioning_scheme: None,
+        };
+        let mut bytes = BytesMut::new();
+        bytes.put_u64_le(1);
+        bytes.put_slice(&options.as_block_bytes());
+        bytes.put_u64_le(2);
+        let bytes = bytes.freeze();
+        let (read_options, read_bytes) = TopicOptions::from_block_bytes(&bytes, 8).unwrap();
+        assert_eq!(read_options.durability, options.durability);
+        assert_eq!(
+            read_options.config_overrides,
+            Some(TopicConfigOverrides::default())
+        );
+        assert_eq!(read_bytes, bytes.len() - 16);
+    }
+
+    #[test]
+    fn missing_block_should_be_deserialized_as_defaults() {
+        let options = TopicOptions::from_bytes(Bytes::new()).unwrap();
+        assert_eq!(options, TopicOptions::default());
+    }
+
+    #[test]
+    fn version_1_block_should_be_deserialized_without_config_overrides() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(TopicOptions::VERSION_1_SIZE_BYTES);
+        bytes.put_u8(1);
+        bytes.put_u8(Durability::EveryBatch.as_code());
+        let options = TopicOptions::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(options.durability, Some(Durability::EveryBatch));
+        assert_eq!(options.config_overrides, None);
+        assert_eq!(options.partitioning_scheme, None);
+    }
+
+    #[test]
+    fn version_2_block_should_be_deserialized_without_partitioning_scheme() {
+        let config_overrides = TopicConfigOverrides {
+            cache_indexes: Some(false),
+            ..Default::default()
+        };
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(TopicOptions::VERSION_2_SIZE_BYTES);
+        bytes.put_u8(2);
+        bytes.put_u8(Durability::EveryBatch.as_code());
+        bytes.put_slice(&config_overrides.as_bytes());
+        let options = TopicOptions::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(options.durability, Some(Durability::EveryBatch));
+        assert_eq!(options.config_overrides, Some(config_overrides));
+        assert_eq!(options.partitioning_scheme, None);
+    }
+
+    #[test]
+    fn unknown_fields_of_newer_version_should_be_skipped() {
+        let config_overrides = TopicConfigOverrides {
+            cache_indexes: Some(false),
+            ..Default::default()
+        };
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(TopicOptions::VERSION_3_SIZE_BYTES + 4);
+        bytes.put_u8(TopicOptions::VERSION + 1);
+        bytes.put_u8(Durability::EveryBatch.as_code());
+        bytes.put_slice(&config_overrides.as_bytes());
+        bytes.put_u8(PartitioningScheme::JumpHash.as_code());
+        bytes.put_u32_le(100);
+        let options = TopicOptions::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(options.durability, Some(Durability::EveryBatch));
+        assert_eq!(options.config_overrides, Some(config_overrides));
+        assert_eq!(
+            options.partitioning_scheme,
+            Some(PartitioningScheme::JumpHash)
+        );
+    }
+
+    #[test]
+    fn truncated_block_should_be_rejected() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(TopicOptions::VERSION_2_SIZE_BYTES);
+        bytes.put_u8(TopicOptions::VERSION);
+        bytes.put_u8(0);
+        bytes.put_slice(&TopicConfigOverrides::default().as_bytes());
+        assert!(TopicOptions::from_bytes(bytes.freeze()).is_err());
+    }
+}
@@ -3,8 +3,11 @@ use crate::command::CommandPayload;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::topic_config_overrides::TopicConfigOverrides;
+use crate::topics::topic_options::TopicOptions;
 use crate::topics::MAX_NAME_LENGTH;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::durability::Durability;
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -21,6 +24,12 @@ use std::str::from_utf8;
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `durability` - optional durability level of the topic, if `None` then the current one is kept.
+/// - `config_overrides` - optional settings overriding the server configuration (and the stream defaults) for this topic, if `None` then the current ones are kept.
+///   Otherwise they replace the previous overrides, so the settings set to `None` are inherited again.
+///
+/// The `durability` and `config_overrides` are sent in the versioned options block after the name,
+/// so the clients which don't send it keep the current settings.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct UpdateTopic {
     /// Unique stream ID (numeric or name).
@@ -38,6 +47,12 @@ pub struct UpdateTopic {
     pub max_topic_size: Option<IggyByteSize>,
     /// Replication factor for the topic.
     pub replication_factor: Option<u8>,
+    /// Optional durability level of the topic, if `None` then the current one is kept.
+    #[serde(default)]
+    pub durability: Option<Durability>,
+    /// Optional settings overriding the server configuration (and the stream defaults) for this topic, if `None` then the current ones are kept.
+    #[serde(default)]
+    pub config_overrides: Option<TopicConfigOverrides>,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
 }
@@ -53,6 +68,8 @@ impl Default for UpdateTopic {
             message_expiry: None,
             max_topic_size: None,
             replication_factor: None,
+            durability: None,
+            config_overrides: None,
             name: "topic".to_string(),
         }
     }
@@ -74,6 +91,15 @@ impl Validatable<IggyError> for UpdateTopic {
             }
         }
 
+        if let Some(config_overrides) = &self.config_overrides {
+            config_overrides.validate()?;
+            if self.durability == Some(Durability::None)
+                && config_overrides.enforce_fsync == Some(true)
+            {
+                return Err(IggyError::FsyncEnforcedWithoutDurability);
+            }
+        }
+
         Ok(())
     }
 }
@@ -82,8 +108,17 @@ impl BytesSerializable for UpdateTopic {
     fn as_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
+        let options_bytes = TopicOptions {
+            durability: self.durability,
+            config_overrides: self.config_overrides,
+            partitioning_scheme: None,
+        }
+        .as_bytes();
         let mut bytes = BytesMut::with_capacity(
-            14 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+            14 + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + self.name.len()
+                + options_bytes.len(),
         );
         bytes.put_slice(&stream_id_bytes.clone());
         bytes.put_slice(&topic_id_bytes.clone());
@@ -103,6 +138,7 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        bytes.put_slice(&options_bytes);
         bytes.freeze()
     }
 
@@ -131,10 +167,20 @@ impl BytesSerializable for UpdateTopic {
             0 => None,
             factor => Some(factor),
         };
-        let name_length = bytes[position + 13];
-        let name =
-            from_utf8(&bytes[position + 14..(position + 14 + name_length as usize)])?.to_string();
-        if name.len() != name_length as usize {
+        position += 13;
+        if bytes.len() <= position {
+            return Err(IggyError::InvalidCommand);
+        }
+        let name_length = bytes[position] as usize;
+        position += 1;
+        if bytes.len() < position + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+        let name = from_utf8(&bytes[position..position + name_length])?.to_string();
+        position += name_length;
+        let options = TopicOptions::from_bytes(bytes.slice(position..))?;
+        // The scheme is set with the config overrides, the one in the options is only returned by the server.
+        if options.partitioning_scheme.is_some() {
             return Err(IggyError::InvalidCommand);
         }
         let command = UpdateTopic {
@@ -144,6 +190,8 @@ impl BytesSerializable for UpdateTopic {
             message_expiry,
             max_topic_size,
             replication_factor,
+            durability: options.durability,
+            config_overrides: options.config_overrides,
             name,
         };
         command.validate()?;
@@ -157,14 +205,24 @@ impl Display for UpdateTopic {
             Some(max_topic_size) => max_topic_size.to_string(),
             None => String::from("unlimited"),
         };
+        let durability = match self.durability {
+            Some(durability) => durability.to_string(),
+            None => String::from("default"),
+        };
+        let config_overrides = match &self.config_overrides {
+            Some(config_overrides) => config_overrides.to_string(),
+            None => String::from("current"),
+        };
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.message_expiry.unwrap_or(0),
             max_topic_size,
             self.replication_factor.unwrap_or(0),
+            durability,
+            config_overrides,
             self.name,
         )
     }
@@ -184,6 +242,12 @@ mod tests {
             message_expiry: Some(10),
             max_topic_size: Some(IggyByteSize::from(100)),
             replication_factor: Some(1),
+            durability: Some(Durability::Interval),
+            config_overrides: Some(TopicConfigOverrides {
+                messages_required_to_save: Some(10),
+                validate_checksum: Some(true),
+                ..Default::default()
+            }),
             name: "test".to_string(),
         };
 
@@ -206,10 +270,13 @@ mod tests {
                 size => Some(IggyByteSize::from(size)),
             };
         let replication_factor = bytes[position + 12];
-        let name_length = bytes[position + 13];
-        let name = from_utf8(&bytes[position + 14..position + 14 + name_length as usize])
+        position += 13;
+        let name_length = bytes[position];
+        let name = from_utf8(&bytes[position + 1..position + 1 + name_length as usize])
             .unwrap()
             .to_string();
+        position += 1 + name_length as usize;
+        let options = TopicOptions::from_bytes(bytes.slice(position..)).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -218,6 +285,8 @@ mod tests {
         assert_eq!(message_expiry, command.message_expiry);
         assert_eq!(max_topic_size, command.max_topic_size);
         assert_eq!(replication_factor, command.replication_factor.unwrap());
+        assert_eq!(options.durability, command.durability);
+        assert_eq!(options.config_overrides, command.config_overrides);
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
     }
@@ -231,6 +300,12 @@ mod tests {
         let message_expiry = 10;
         let max_topic_size = IggyByteSize::from(100);
         let replication_factor = 1;
+        let durability = Durability::GroupCommit;
+        let config_overrides = TopicConfigOverrides {
+            cache_time_indexes: Some(true),
+            message_deduplication_max_entries: Some(1000),
+            ..Default::default()
+        };
 
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
@@ -242,10 +317,17 @@ mod tests {
         bytes.put_u32_le(message_expiry);
         bytes.put_u64_le(max_topic_size.as_bytes_u64());
         bytes.put_u8(replication_factor);
-
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.put_slice(name.as_bytes());
+        bytes.put_slice(
+            &TopicOptions {
+                durability: Some(durability),
+                config_overrides: Some(config_overrides),
+                partitioning_scheme: None,
+            }
+            .as_bytes(),
+        );
 
         let command = UpdateTopic::from_bytes(bytes.freeze());
         assert!(command.is_ok());
@@ -254,7 +336,37 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.message_expiry, Some(message_expiry));
+        assert_eq!(command.durability, Some(durability));
+        assert_eq!(command.config_overrides, Some(config_overrides));
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_options() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let name = "test".to_string();
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes =
+            BytesMut::with_capacity(14 + stream_id_bytes.len() + topic_id_bytes.len() + name.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(CompressionAlgorithm::None.as_code());
+        bytes.put_u32_le(10);
+        bytes.put_u64_le(0);
+        bytes.put_u8(1);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+
+        let command = UpdateTopic::from_bytes(bytes.freeze()).unwrap();
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.message_expiry, Some(10));
+        assert_eq!(command.name, name);
+        assert_eq!(command.durability, None);
+        assert_eq!(command.config_overrides, None);
     }
 }
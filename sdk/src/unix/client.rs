@@ -0,0 +1,259 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{BinaryTransport, ClientState};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::Client;
+use crate::command::GET_ME_CODE;
+use crate::error::IggyError;
+use crate::system::get_me::GetMe;
+use crate::unix::config::UnixClientConfig;
+use async_trait::async_trait;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::UnixStream;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+use tracing::{error, info, trace};
+
+const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
+const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+const NAME: &str = "Iggy";
+
+/// Unix domain socket client for interacting with the Iggy API.
+/// It speaks the same binary protocol as the TCP client, but connects to the server
+/// running on the same machine via the socket path, without going through the network stack.
+#[derive(Debug)]
+pub struct UnixClient {
+    pub(crate) stream: Mutex<Option<UnixConnectionStream>>,
+    pub(crate) config: Arc<UnixClientConfig>,
+    pub(crate) state: Mutex<ClientState>,
+}
+
+#[derive(Debug)]
+pub(crate) struct UnixConnectionStream {
+    reader: BufReader<OwnedReadHalf>,
+    writer: BufWriter<OwnedWriteHalf>,
+}
+
+impl UnixConnectionStream {
+    fn new(stream: UnixStream) -> Self {
+        let (reader, writer) = stream.into_split();
+        Self {
+            reader: BufReader::new(reader),
+            writer: BufWriter::new(writer),
+        }
+    }
+
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, IggyError> {
+        Ok(self.reader.read_exact(buf).await?)
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<(), IggyError> {
+        Ok(self.writer.write_all(buf).await?)
+    }
+
+    async fn flush(&mut self) -> Result<(), IggyError> {
+        Ok(self.writer.flush().await?)
+    }
+}
+
+impl Default for UnixClient {
+    fn default() -> Self {
+        UnixClient::create(Arc::new(UnixClientConfig::default())).unwrap()
+    }
+}
+
+#[async_trait]
+impl Client for UnixClient {
+    async fn connect(&self) -> Result<(), IggyError> {
+        UnixClient::connect(self).await
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        UnixClient::disconnect(self).await
+    }
+}
+
+#[async_trait]
+impl BinaryTransport for UnixClient {
+    async fn get_state(&self) -> ClientState {
+        *self.state.lock().await
+    }
+
+    async fn set_state(&self, state: ClientState) {
+        *self.state.lock().await = state;
+    }
+
+    async fn send_with_response(&self, command: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        if self.get_state().await == ClientState::Disconnected {
+            return Err(IggyError::NotConnected);
+        }
+
+        let mut stream = self.stream.lock().await;
+        if let Some(stream) = stream.as_mut() {
+            let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
+            trace!("Sending a Unix domain socket request...");
+            stream.write(&(payload_length as u32).to_le_bytes()).await?;
+            stream.write(&command.to_le_bytes()).await?;
+            stream.write(&payload).await?;
+            stream.flush().await?;
+            trace!("Sent a Unix domain socket request, waiting for a response...");
+
+            let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
+            let read_bytes = stream.read(&mut response_buffer).await?;
+            if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
+                error!("Received an invalid or empty response.");
+                return Err(IggyError::EmptyResponse);
+            }
+
+            let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
+            let length = u32::from_le_bytes(response_buffer[4..].try_into().unwrap());
+            return Self::handle_response(status, length, stream).await;
+        }
+
+        error!("Cannot send data. Client is not connected.");
+        Err(IggyError::NotConnected)
+    }
+}
+
+impl BinaryClient for UnixClient {}
+
+impl UnixClient {
+    /// Create a new Unix domain socket client for the provided socket path.
+    pub fn new(socket_path: &str) -> Result<Self, IggyError> {
+        Self::create(Arc::new(UnixClientConfig {
+            socket_path: socket_path.to_string(),
+            ..Default::default()
+        }))
+    }
+
+    /// Create a new Unix domain socket client based on the provided configuration.
+    pub fn create(config: Arc<UnixClientConfig>) -> Result<Self, IggyError> {
+        Ok(Self {
+            config,
+            stream: Mutex::new(None),
+            state: Mutex::new(ClientState::Disconnected),
+        })
+    }
+
+    async fn handle_response(
+        status: u32,
+        length: u32,
+        stream: &mut UnixConnectionStream,
+    ) -> Result<Bytes, IggyError> {
+        if status != 0 {
+            // The unauthenticated response is expected when checking the peer authentication.
+            if status == IggyError::Unauthenticated.as_code() {
+                tracing::debug!("Received an unauthenticated response.");
+            } else {
+                error!(
+                    "Received an invalid response with status: {} ({}).",
+                    status,
+                    IggyError::from_code_as_string(status),
+                );
+            }
+
+            let mut error_details_buffer = BytesMut::with_capacity(length as usize);
+            error_details_buffer.put_bytes(0, length as usize);
+            stream.read(&mut error_details_buffer).await?;
+
+            let string_length = error_details_buffer.get_u32_le();
+            let error_message = String::from_utf8_lossy(&error_details_buffer);
+
+            return Err(IggyError::InvalidResponse(
+                status,
+                string_length,
+                error_message.to_string(),
+            ));
+        }
+
+        trace!("Status: OK. Response length: {}", length);
+        if length <= 1 {
+            return Ok(Bytes::new());
+        }
+
+        let mut response_buffer = BytesMut::with_capacity(length as usize);
+        response_buffer.put_bytes(0, length as usize);
+        stream.read(&mut response_buffer).await?;
+        Ok(response_buffer.freeze())
+    }
+
+    async fn connect(&self) -> Result<(), IggyError> {
+        if self.get_state().await == ClientState::Connected {
+            return Ok(());
+        }
+
+        let mut retry_count = 0;
+        let stream = loop {
+            info!(
+                "{} client is connecting to server: {}...",
+                NAME, self.config.socket_path
+            );
+
+            match UnixStream::connect(&self.config.socket_path).await {
+                Ok(stream) => break stream,
+                Err(error) => {
+                    error!(
+                        "Failed to connect to server: {}, error: {error}",
+                        self.config.socket_path
+                    );
+                    if retry_count < self.config.reconnection_retries {
+                        retry_count += 1;
+                        info!(
+                            "Retrying to connect to server ({}/{}): {} in: {} ms...",
+                            retry_count,
+                            self.config.reconnection_retries,
+                            self.config.socket_path,
+                            self.config.reconnection_interval
+                        );
+                        sleep(Duration::from_millis(self.config.reconnection_interval)).await;
+                        continue;
+                    }
+
+                    return Err(IggyError::NotConnected);
+                }
+            }
+        };
+
+        self.stream
+            .lock()
+            .await
+            .replace(UnixConnectionStream::new(stream));
+        self.set_state(ClientState::Connected).await;
+
+        info!(
+            "{} client has connected to server: {}",
+            NAME, self.config.socket_path
+        );
+
+        // The server might have already authenticated the connection based on the peer credentials,
+        // in which case the client doesn't have to log in.
+        if self
+            .send_with_response(GET_ME_CODE, GetMe {}.as_bytes())
+            .await
+            .is_ok()
+        {
+            info!(
+                "{} client has been authenticated by the peer credentials.",
+                NAME
+            );
+            self.set_state(ClientState::Authenticated).await;
+        }
+
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> Result<(), IggyError> {
+        if self.get_state().await == ClientState::Disconnected {
+            return Ok(());
+        }
+
+        info!("{} client is disconnecting from server...", NAME);
+        self.set_state(ClientState::Disconnected).await;
+        self.stream.lock().await.take();
+        info!("{} client has disconnected from server.", NAME);
+        Ok(())
+    }
+}
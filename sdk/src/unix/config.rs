@@ -0,0 +1,59 @@
+/// Configuration for the Unix domain socket client.
+#[derive(Debug, Clone)]
+pub struct UnixClientConfig {
+    /// The path of the Unix domain socket the Iggy server is listening on.
+    pub socket_path: String,
+    /// The number of retries when connecting to the server.
+    pub reconnection_retries: u32,
+    /// The interval between retries when connecting to the server.
+    pub reconnection_interval: u64,
+}
+
+impl Default for UnixClientConfig {
+    fn default() -> UnixClientConfig {
+        UnixClientConfig {
+            socket_path: "/tmp/iggy.sock".to_string(),
+            reconnection_retries: 3,
+            reconnection_interval: 1000,
+        }
+    }
+}
+
+/// Builder for the Unix domain socket client configuration.
+/// Allows configuring the Unix domain socket client with custom settings or using defaults:
+/// - `socket_path`: Default is "/tmp/iggy.sock"
+/// - `reconnection_retries`: Default is 3.
+/// - `reconnection_interval`: Default is 1000 ms.
+#[derive(Debug, Default)]
+pub struct UnixClientConfigBuilder {
+    config: UnixClientConfig,
+}
+
+impl UnixClientConfigBuilder {
+    pub fn new() -> Self {
+        UnixClientConfigBuilder::default()
+    }
+
+    /// Sets the socket path for the Unix domain socket client.
+    pub fn with_socket_path(mut self, socket_path: String) -> Self {
+        self.config.socket_path = socket_path;
+        self
+    }
+
+    /// Sets the number of retries when connecting to the server.
+    pub fn with_reconnection_retries(mut self, reconnection_retries: u32) -> Self {
+        self.config.reconnection_retries = reconnection_retries;
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, reconnection_interval: u64) -> Self {
+        self.config.reconnection_interval = reconnection_interval;
+        self
+    }
+
+    /// Builds the Unix domain socket client configuration.
+    pub fn build(self) -> UnixClientConfig {
+        self.config
+    }
+}
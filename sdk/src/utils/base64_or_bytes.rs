@@ -0,0 +1,71 @@
+use serde::{Deserializer, Serializer};
+use serde_with::base64::Base64;
+use serde_with::{DeserializeAs, SerializeAs};
+
+/// Serializes the binary value as the base64 string for the human-readable formats such as JSON,
+/// and as the raw bytes for the binary formats such as MessagePack, so that the payloads aren't inflated.
+pub struct Base64OrBytes;
+
+impl<T: AsRef<[u8]>> SerializeAs<T> for Base64OrBytes {
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            return <Base64 as SerializeAs<T>>::serialize_as(source, serializer);
+        }
+
+        serializer.serialize_bytes(source.as_ref())
+    }
+}
+
+impl<'de, T: From<Vec<u8>>> DeserializeAs<'de, T> for Base64OrBytes {
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes: Vec<u8> = if deserializer.is_human_readable() {
+            <Base64 as DeserializeAs<'de, Vec<u8>>>::deserialize_as(deserializer)?
+        } else {
+            <serde_with::Bytes as DeserializeAs<'de, Vec<u8>>>::deserialize_as(deserializer)?
+        };
+        Ok(T::from(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use serde::{Deserialize, Serialize};
+    use serde_with::serde_as;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Payload {
+        #[serde_as(as = "Base64OrBytes")]
+        value: Bytes,
+    }
+
+    #[test]
+    fn value_should_be_serialized_as_base64_for_json() {
+        let payload = Payload {
+            value: Bytes::from("hello"),
+        };
+
+        let json = serde_json::to_string(&payload).unwrap();
+        assert_eq!(json, r#"{"value":"aGVsbG8="}"#);
+        assert_eq!(serde_json::from_str::<Payload>(&json).unwrap(), payload);
+    }
+
+    #[test]
+    fn value_should_be_serialized_as_raw_bytes_for_msgpack() {
+        let payload = Payload {
+            value: Bytes::from("hello"),
+        };
+
+        let msgpack = rmp_serde::to_vec_named(&payload).unwrap();
+        assert!(msgpack.windows(5).any(|window| window == b"hello"));
+        assert_eq!(rmp_serde::from_slice::<Payload>(&msgpack).unwrap(), payload);
+    }
+}
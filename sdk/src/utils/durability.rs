@@ -0,0 +1,116 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Durability level of the topic, which controls when the appended messages are flushed (fsynced) to disk.
+/// - `None` - messages are written to disk when the buffered messages threshold is reached, but the data is never explicitly fsynced.
+/// - `Interval` - as above, and additionally the message saver fsyncs all the buffered data on its interval.
+/// - `GroupCommit` - every batch is written to disk immediately, and the fsyncs are coalesced across concurrent producers within the configured time window.
+/// - `EveryBatch` - every batch is written to disk and fsynced immediately.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Durability {
+    /// The data is never explicitly fsynced.
+    #[default]
+    None,
+    /// The data is fsynced by the message saver on its interval.
+    Interval,
+    /// The fsyncs are coalesced across the concurrent producers within the time window.
+    GroupCommit,
+    /// Every batch is fsynced immediately.
+    EveryBatch,
+}
+
+impl Durability {
+    /// Get the code of the durability level.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            Durability::None => 1,
+            Durability::Interval => 2,
+            Durability::GroupCommit => 3,
+            Durability::EveryBatch => 4,
+        }
+    }
+
+    /// Get the durability level from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(Durability::None),
+            2 => Ok(Durability::Interval),
+            3 => Ok(Durability::GroupCommit),
+            4 => Ok(Durability::EveryBatch),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    /// Returns `true` if every batch has to be written to disk as soon as it's appended.
+    pub fn requires_immediate_write(&self) -> bool {
+        matches!(self, Durability::GroupCommit | Durability::EveryBatch)
+    }
+}
+
+impl FromStr for Durability {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "none" => Ok(Durability::None),
+            "interval" => Ok(Durability::Interval),
+            "group_commit" => Ok(Durability::GroupCommit),
+            "every_batch" => Ok(Durability::EveryBatch),
+            _ => Err(format!("Unknown durability level: {}", s)),
+        }
+    }
+}
+
+impl Display for Durability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Durability::None => write!(f, "none"),
+            Durability::Interval => write!(f, "interval"),
+            Durability::GroupCommit => write!(f, "group_commit"),
+            Durability::EveryBatch => write!(f, "every_batch"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(Durability::from_str("none").unwrap(), Durability::None);
+        assert_eq!(
+            Durability::from_str("Interval").unwrap(),
+            Durability::Interval
+        );
+        assert_eq!(
+            Durability::from_str("group_commit").unwrap(),
+            Durability::GroupCommit
+        );
+        assert_eq!(
+            Durability::from_str("every-batch").unwrap(),
+            Durability::EveryBatch
+        );
+        assert!(Durability::from_str("always").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_to_and_from_code() {
+        for durability in [
+            Durability::None,
+            Durability::Interval,
+            Durability::GroupCommit,
+            Durability::EveryBatch,
+        ] {
+            assert_eq!(
+                Durability::from_code(durability.as_code()).unwrap(),
+                durability
+            );
+        }
+        assert!(Durability::from_code(0).is_err());
+        assert!(Durability::from_code(5).is_err());
+    }
+}
@@ -1,8 +1,14 @@
+pub mod base64_or_bytes;
 pub mod byte_size;
 pub mod checksum;
 pub mod crypto;
+pub mod durability;
 pub mod duration;
 pub mod expiry;
+pub mod partitioning_scheme;
 pub mod personal_access_token_expiry;
+pub mod schema_compatibility;
+pub mod schema_type;
 pub mod text;
 pub mod timestamp;
+pub mod timestamp_type;
@@ -0,0 +1,187 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use xxhash_rust::xxh32::xxh32;
+
+/// Scheme used to map the messages key to the partition of the topic, when the messages are sent using the `MessagesKey` partitioning.
+/// - `Modulo` - the hash of the key modulo the partitions count, adding partitions remaps most of the keys.
+/// - `JumpHash` - the jump consistent hash of the key, adding partitions moves only the keys assigned to the new partitions.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitioningScheme {
+    /// The hash of the key modulo the partitions count.
+    #[default]
+    Modulo,
+    /// The jump consistent hash of the key.
+    JumpHash,
+}
+
+/// The messages key, which is assigned to the different partition after changing the partitions count.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct KeyMove {
+    /// The messages key.
+    pub key: Vec<u8>,
+    /// The partition ID assigned to the key before changing the partitions count.
+    pub from_partition_id: u32,
+    /// The partition ID assigned to the key after changing the partitions count.
+    pub to_partition_id: u32,
+}
+
+impl PartitioningScheme {
+    /// Get the code of the partitioning scheme.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PartitioningScheme::Modulo => 1,
+            PartitioningScheme::JumpHash => 2,
+        }
+    }
+
+    /// Get the partitioning scheme from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PartitioningScheme::Modulo),
+            2 => Ok(PartitioningScheme::JumpHash),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    /// Calculate the partition ID (starting from 1) for the provided messages key, or 0 if there are no partitions.
+    pub fn calculate_partition_id(&self, messages_key: &[u8], partitions_count: u32) -> u32 {
+        if partitions_count == 0 {
+            return 0;
+        }
+
+        let hash = xxh32(messages_key, 0);
+        match self {
+            PartitioningScheme::Modulo => match hash % partitions_count {
+                0 => partitions_count,
+                partition_id => partition_id,
+            },
+            PartitioningScheme::JumpHash => jump_hash(hash as u64, partitions_count) + 1,
+        }
+    }
+
+    /// Returns the keys, which are assigned to the different partitions after changing the partitions count.
+    pub fn get_moved_keys<K: AsRef<[u8]>>(
+        &self,
+        keys: &[K],
+        partitions_count: u32,
+        new_partitions_count: u32,
+    ) -> Vec<KeyMove> {
+        keys.iter()
+            .filter_map(|key| {
+                let key = key.as_ref();
+                let from_partition_id = self.calculate_partition_id(key, partitions_count);
+                let to_partition_id = self.calculate_partition_id(key, new_partitions_count);
+                if from_partition_id == to_partition_id {
+                    return None;
+                }
+
+                Some(KeyMove {
+                    key: key.to_vec(),
+                    from_partition_id,
+                    to_partition_id,
+                })
+            })
+            .collect()
+    }
+}
+
+// Jump consistent hash by Lamping and Veach, returns the bucket in range [0, buckets).
+fn jump_hash(mut key: u64, buckets: u32) -> u32 {
+    let mut bucket: i64 = -1;
+    let mut next: i64 = 0;
+    while next < buckets as i64 {
+        bucket = next;
+        key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+        next = ((bucket + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+    bucket as u32
+}
+
+impl FromStr for PartitioningScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "modulo" => Ok(PartitioningScheme::Modulo),
+            "jump_hash" => Ok(PartitioningScheme::JumpHash),
+            _ => Err(format!("Unknown partitioning scheme: {}", s)),
+        }
+    }
+}
+
+impl Display for PartitioningScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartitioningScheme::Modulo => write!(f, "modulo"),
+            PartitioningScheme::JumpHash => write!(f, "jump_hash"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_keys() -> Vec<String> {
+        (0..1000).map(|id| format!("key-{id}")).collect()
+    }
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            PartitioningScheme::from_str("modulo").unwrap(),
+            PartitioningScheme::Modulo
+        );
+        assert_eq!(
+            PartitioningScheme::from_str("Jump-Hash").unwrap(),
+            PartitioningScheme::JumpHash
+        );
+        assert!(PartitioningScheme::from_str("ring").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_to_and_from_code() {
+        for scheme in [PartitioningScheme::Modulo, PartitioningScheme::JumpHash] {
+            assert_eq!(
+                PartitioningScheme::from_code(scheme.as_code()).unwrap(),
+                scheme
+            );
+        }
+        assert!(PartitioningScheme::from_code(0).is_err());
+    }
+
+    #[test]
+    fn partition_id_should_be_in_range_of_partitions() {
+        for scheme in [PartitioningScheme::Modulo, PartitioningScheme::JumpHash] {
+            assert_eq!(scheme.calculate_partition_id(b"key", 0), 0);
+            for key in get_keys() {
+                let partition_id = scheme.calculate_partition_id(key.as_bytes(), 7);
+                assert!((1..=7).contains(&partition_id));
+            }
+        }
+    }
+
+    #[test]
+    fn jump_hash_should_only_move_keys_to_new_partitions() {
+        let keys = get_keys();
+        let moved_keys = PartitioningScheme::JumpHash.get_moved_keys(&keys, 4, 5);
+
+        assert!(!moved_keys.is_empty());
+        // Roughly 1/5 of the keys should be moved, all of them to the new partition.
+        assert!(moved_keys.len() < keys.len() / 3);
+        for moved_key in moved_keys {
+            assert_eq!(moved_key.to_partition_id, 5);
+        }
+    }
+
+    #[test]
+    fn modulo_should_move_most_keys_when_partitions_are_added() {
+        let keys = get_keys();
+        let moved_keys = PartitioningScheme::Modulo.get_moved_keys(&keys, 4, 5);
+
+        assert!(moved_keys.len() > keys.len() / 2);
+    }
+}
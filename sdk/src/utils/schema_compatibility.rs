@@ -0,0 +1,121 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Compatibility rule enforced when registering a new version of the topic schema.
+/// - `None` - any schema can be registered.
+/// - `Backward` - consumers using the new schema can read the data produced with the previous one.
+/// - `Forward` - consumers using the previous schema can read the data produced with the new one.
+/// - `Full` - both backward and forward compatible.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaCompatibility {
+    /// No compatibility checks.
+    None,
+    /// The new schema can read the data written with the previous one.
+    #[default]
+    Backward,
+    /// The previous schema can read the data written with the new one.
+    Forward,
+    /// Backward and forward compatible.
+    Full,
+}
+
+impl SchemaCompatibility {
+    /// Get the code of the schema compatibility.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaCompatibility::None => 1,
+            SchemaCompatibility::Backward => 2,
+            SchemaCompatibility::Forward => 3,
+            SchemaCompatibility::Full => 4,
+        }
+    }
+
+    /// Get the schema compatibility from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaCompatibility::None),
+            2 => Ok(SchemaCompatibility::Backward),
+            3 => Ok(SchemaCompatibility::Forward),
+            4 => Ok(SchemaCompatibility::Full),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    /// Whether the new schema must be able to read the data written with the previous one.
+    pub fn is_backward(&self) -> bool {
+        matches!(
+            self,
+            SchemaCompatibility::Backward | SchemaCompatibility::Full
+        )
+    }
+
+    /// Whether the previous schema must be able to read the data written with the new one.
+    pub fn is_forward(&self) -> bool {
+        matches!(
+            self,
+            SchemaCompatibility::Forward | SchemaCompatibility::Full
+        )
+    }
+}
+
+impl FromStr for SchemaCompatibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(SchemaCompatibility::None),
+            "backward" => Ok(SchemaCompatibility::Backward),
+            "forward" => Ok(SchemaCompatibility::Forward),
+            "full" => Ok(SchemaCompatibility::Full),
+            _ => Err(format!("Unknown schema compatibility: {}", s)),
+        }
+    }
+}
+
+impl Display for SchemaCompatibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaCompatibility::None => write!(f, "none"),
+            SchemaCompatibility::Backward => write!(f, "backward"),
+            SchemaCompatibility::Forward => write!(f, "forward"),
+            SchemaCompatibility::Full => write!(f, "full"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            SchemaCompatibility::from_str("Backward").unwrap(),
+            SchemaCompatibility::Backward
+        );
+        assert_eq!(
+            SchemaCompatibility::from_str("full").unwrap(),
+            SchemaCompatibility::Full
+        );
+        assert!(SchemaCompatibility::from_str("transitive").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_to_and_from_code() {
+        for compatibility in [
+            SchemaCompatibility::None,
+            SchemaCompatibility::Backward,
+            SchemaCompatibility::Forward,
+            SchemaCompatibility::Full,
+        ] {
+            assert_eq!(
+                SchemaCompatibility::from_code(compatibility.as_code()).unwrap(),
+                compatibility
+            );
+        }
+        assert!(SchemaCompatibility::from_code(5).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Format of the schema registered for the topic.
+/// - `Json` - the payload is a JSON document validated against a JSON Schema.
+/// - `Avro` - the payload is an Avro binary encoded datum of the record described by the Avro schema.
+/// - `Protobuf` - the payload is a Protobuf encoded message of the type declared (or named with `option (iggy.message_type)`) by the `.proto` definition.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaType {
+    /// JSON Schema.
+    #[default]
+    Json,
+    /// Apache Avro schema.
+    Avro,
+    /// Protocol Buffers definition.
+    Protobuf,
+}
+
+impl SchemaType {
+    /// Get the code of the schema type.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaType::Json => 1,
+            SchemaType::Avro => 2,
+            SchemaType::Protobuf => 3,
+        }
+    }
+
+    /// Get the schema type from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaType::Json),
+            2 => Ok(SchemaType::Avro),
+            3 => Ok(SchemaType::Protobuf),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for SchemaType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(SchemaType::Json),
+            "avro" => Ok(SchemaType::Avro),
+            "protobuf" | "proto" => Ok(SchemaType::Protobuf),
+            _ => Err(format!("Unknown schema type: {}", s)),
+        }
+    }
+}
+
+impl Display for SchemaType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaType::Json => write!(f, "json"),
+            SchemaType::Avro => write!(f, "avro"),
+            SchemaType::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(SchemaType::from_str("json").unwrap(), SchemaType::Json);
+        assert_eq!(SchemaType::from_str("Avro").unwrap(), SchemaType::Avro);
+        assert_eq!(SchemaType::from_str("proto").unwrap(), SchemaType::Protobuf);
+        assert!(SchemaType::from_str("thrift").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_to_and_from_code() {
+        for schema_type in [SchemaType::Json, SchemaType::Avro, SchemaType::Protobuf] {
+            assert_eq!(
+                SchemaType::from_code(schema_type.as_code()).unwrap(),
+                schema_type
+            );
+        }
+        assert!(SchemaType::from_code(0).is_err());
+    }
+}
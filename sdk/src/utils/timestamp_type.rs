@@ -0,0 +1,86 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Type of the timestamp stored with the messages of the topic, which is also used by the time index and the polling by timestamp.
+/// - `LogAppendTime` - the time at which the message has been appended to the partition, any timestamp set by the producer is ignored.
+/// - `CreateTime` - the event timestamp set by the producer, or the append time if the producer didn't set it.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampType {
+    /// The time at which the message has been appended to the partition.
+    #[default]
+    LogAppendTime,
+    /// The event timestamp set by the producer.
+    CreateTime,
+}
+
+impl TimestampType {
+    /// Get the code of the timestamp type.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            TimestampType::LogAppendTime => 1,
+            TimestampType::CreateTime => 2,
+        }
+    }
+
+    /// Get the timestamp type from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(TimestampType::LogAppendTime),
+            2 => Ok(TimestampType::CreateTime),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for TimestampType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "log_append_time" => Ok(TimestampType::LogAppendTime),
+            "create_time" => Ok(TimestampType::CreateTime),
+            _ => Err(format!("Unknown timestamp type: {}", s)),
+        }
+    }
+}
+
+impl Display for TimestampType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimestampType::LogAppendTime => write!(f, "log_append_time"),
+            TimestampType::CreateTime => write!(f, "create_time"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            TimestampType::from_str("log_append_time").unwrap(),
+            TimestampType::LogAppendTime
+        );
+        assert_eq!(
+            TimestampType::from_str("Create-Time").unwrap(),
+            TimestampType::CreateTime
+        );
+        assert!(TimestampType::from_str("event_time").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_to_and_from_code() {
+        for timestamp_type in [TimestampType::LogAppendTime, TimestampType::CreateTime] {
+            assert_eq!(
+                TimestampType::from_code(timestamp_type.as_code()).unwrap(),
+                timestamp_type
+            );
+        }
+        assert!(TimestampType::from_code(0).is_err());
+    }
+}
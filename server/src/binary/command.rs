@@ -9,6 +9,9 @@ use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
 };
+use crate::binary::handlers::schemas::{
+    get_schema_handler, get_schemas_handler, register_schema_handler,
+};
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
@@ -18,19 +21,50 @@ use crate::binary::handlers::users::{
     update_user_handler,
 };
 use crate::binary::sender::Sender;
+use crate::log::telemetry;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::diagnostics::metrics::StreamingMetrics;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use iggy::command::Command;
 use iggy::error::IggyError;
-use tracing::{debug, error};
+use std::time::Instant;
+use tracing::{debug, error, info_span, Instrument};
 
 pub async fn handle(
     command: Command,
     sender: &mut dyn Sender,
     session: &Session,
     system: SharedSystem,
+    transport: Transport,
 ) -> Result<(), IggyError> {
-    match try_handle(command, sender, session, &system).await {
+    let command_code = command.code();
+    let span = info_span!(
+        "command",
+        code = command_code,
+        transport = %transport,
+        client_id = session.client_id
+    );
+    if let Command::SendMessages(payload) = &command {
+        if let Some(trace_context) = payload
+            .messages
+            .first()
+            .and_then(|message| message.get_trace_context())
+        {
+            telemetry::set_parent(&span, &trace_context);
+        }
+    }
+
+    let started_at = Instant::now();
+    let result = try_handle(command, sender, session, &system)
+        .instrument(span)
+        .await;
+    StreamingMetrics::get().record_request_duration(
+        &transport.to_string(),
+        &command_code.to_string(),
+        started_at.elapsed(),
+    );
+    match result {
         Ok(_) => {
             debug!("Command was handled successfully, session: {session}.");
             Ok(())
@@ -113,6 +147,12 @@ async fn try_handle(
         Command::StoreConsumerOffset(command) => {
             store_consumer_offset_handler::handle(&command, sender, session, system).await
         }
+        Command::GetConsumerLag(command) => {
+            get_consumer_lag_handler::handle(&command, sender, session, system).await
+        }
+        Command::ResetConsumerOffsets(command) => {
+            reset_consumer_offsets_handler::handle(&command, sender, session, system).await
+        }
         Command::GetStream(command) => {
             get_stream_handler::handle(&command, sender, session, system).await
         }
@@ -155,6 +195,12 @@ async fn try_handle(
         Command::DeletePartitions(command) => {
             delete_partitions_handler::handle(&command, sender, session, system).await
         }
+        Command::GetOffsetsForTimestamp(command) => {
+            get_offsets_for_timestamp_handler::handle(&command, sender, session, system).await
+        }
+        Command::TruncatePartition(command) => {
+            truncate_partition_handler::handle(&command, sender, session, system).await
+        }
         Command::GetConsumerGroup(command) => {
             get_consumer_group_handler::handle(&command, sender, session, system).await
         }
@@ -173,5 +219,14 @@ async fn try_handle(
         Command::LeaveConsumerGroup(command) => {
             leave_consumer_group_handler::handle(&command, sender, session, system).await
         }
+        Command::GetSchema(command) => {
+            get_schema_handler::handle(&command, sender, session, system).await
+        }
+        Command::GetSchemas(command) => {
+            get_schemas_handler::handle(&command, sender, session, system).await
+        }
+        Command::RegisterSchema(command) => {
+            register_schema_handler::handle(&command, sender, session, system).await
+        }
     }
 }
@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetConsumerLag,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let consumer_lag = system
+        .get_consumer_lag(
+            session,
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+        )
+        .await?;
+    let consumer_lag = mapper::map_consumer_lag(&consumer_lag);
+    sender.send_ok_response(&consumer_lag).await?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: &ResetConsumerOffsets,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let consumer_offsets_reset = system
+        .reset_consumer_offsets(
+            session,
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.strategy,
+            command.dry_run,
+        )
+        .await?;
+    let consumer_offsets_reset = mapper::map_consumer_offsets_reset(&consumer_offsets_reset);
+    sender.send_ok_response(&consumer_offsets_reset).await?;
+    Ok(())
+}
@@ -16,8 +16,7 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
-    let consumer =
-        PollingConsumer::from_consumer(&command.consumer, session.client_id, command.partition_id);
+    let consumer = PollingConsumer::from_poll_messages(command, session.client_id);
     let system = system.read();
     let messages = system
         .poll_messages(
@@ -28,7 +27,10 @@ pub async fn handle(
             PollingArgs::new(command.strategy, command.count, command.auto_commit),
         )
         .await?;
-    let messages = mapper::map_polled_messages(&messages);
+    system
+        .record_polled_messages(&command.stream_id, &command.topic_id, &messages)
+        .await;
+    let messages = mapper::map_polled_messages(&messages, command.version);
     sender.send_ok_response(&messages).await?;
     Ok(())
 }
@@ -1,9 +1,10 @@
+use crate::binary::mapper;
 use crate::binary::sender::Sender;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use iggy::error::IggyError;
-use iggy::messages::send_messages::SendMessages;
+use iggy::messages::send_messages::{SendMessages, SendMessagesVersion};
 use tracing::debug;
 
 pub async fn handle(
@@ -13,14 +14,35 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
-    let system = system.read();
+    let version = command.version;
     let stream_id = command.stream_id;
     let topic_id = command.topic_id;
     let partitioning = command.partitioning;
     let messages = command.messages;
-    system
-        .append_messages(session, stream_id, topic_id, partitioning, messages)
-        .await?;
-    sender.send_empty_ok_response().await?;
+    let (appended_messages, durability_waiter) = {
+        let system = system.read();
+        system
+            .append_messages(
+                session,
+                stream_id,
+                topic_id,
+                partitioning,
+                messages,
+                command.wait_for_durability,
+            )
+            .await?
+    };
+    // Awaiting the durability without holding the system lock, so the group commit can proceed.
+    if let Some(durability_waiter) = durability_waiter {
+        durability_waiter.wait().await?;
+    }
+    // The clients using the original layout (V1) expect the empty response.
+    if version == SendMessagesVersion::V1 {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
+
+    let appended_messages = mapper::map_appended_messages(&appended_messages);
+    sender.send_ok_response(&appended_messages).await?;
     Ok(())
 }
@@ -0,0 +1,30 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetOffsetsForTimestamp,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let offsets = system
+        .get_offsets_for_timestamp(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            &command.partition_ids,
+            command.timestamp,
+        )
+        .await?;
+    let offsets = mapper::map_partition_offsets(&offsets);
+    sender.send_ok_response(&offsets).await?;
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::partitions::truncate_partition::TruncatePartition;
+use tracing::debug;
+
+pub async fn handle(
+    command: &TruncatePartition,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let truncation = system
+        .truncate_partition(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+        )
+        .await?;
+    let truncation = mapper::map_partition_truncation(&truncation);
+    sender.send_ok_response(&truncation).await?;
+    Ok(())
+}
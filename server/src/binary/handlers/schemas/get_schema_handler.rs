@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::schemas::get_schema::GetSchema;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetSchema,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let schema = system.get_schema(session, command.schema_id)?;
+    let schema = mapper::map_schema(schema);
+    sender.send_ok_response(&schema).await?;
+    Ok(())
+}
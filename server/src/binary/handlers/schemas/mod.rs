@@ -0,0 +1,3 @@
+pub mod get_schema_handler;
+pub mod get_schemas_handler;
+pub mod register_schema_handler;
@@ -16,7 +16,7 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let system = system.read();
     let stream = system.find_stream(session, &command.stream_id)?;
-    let stream = mapper::map_stream(stream).await;
+    let stream = mapper::map_stream(stream, command.version).await;
     sender.send_ok_response(&stream).await?;
     Ok(())
 }
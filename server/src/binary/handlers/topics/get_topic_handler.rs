@@ -16,7 +16,7 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let system = system.read();
     let topic = system.find_topic(session, &command.stream_id, &command.topic_id)?;
-    let topic = mapper::map_topic(topic).await;
+    let topic = mapper::map_topic(topic, command.version).await;
     sender.send_ok_response(&topic).await?;
     Ok(())
 }
@@ -16,7 +16,7 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let system = system.read();
     let topics = system.find_topics(session, &command.stream_id)?;
-    let topics = mapper::map_topics(&topics).await;
+    let topics = mapper::map_topics(&topics, command.version).await;
     sender.send_ok_response(&topics).await?;
     Ok(())
 }
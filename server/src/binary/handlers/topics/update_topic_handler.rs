@@ -24,6 +24,8 @@ pub async fn handle(
             command.compression_algorithm,
             command.max_topic_size,
             command.replication_factor,
+            command.durability,
+            command.config_overrides,
         )
         .await?;
     sender.send_empty_ok_response().await?;
@@ -8,10 +8,19 @@ use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::messages::poll_messages::PollMessagesVersion;
+use iggy::models::appended_messages::AppendedMessages;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offsets_reset::ConsumerOffsetsReset;
 use iggy::models::messages::PolledMessages;
+use iggy::models::partition_offsets::PartitionOffsets;
+use iggy::models::partition_truncation::PartitionTruncation;
+use iggy::models::schema::Schema;
 use iggy::models::stats::Stats;
 use iggy::models::user_info::UserId;
+use iggy::topics::get_topic::TopicLayoutVersion;
+use iggy::topics::topic_options::TopicOptions;
 use tokio::sync::RwLock;
 
 pub fn map_stats(stats: &Stats) -> Bytes {
@@ -53,6 +62,74 @@ pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_consumer_lag(consumer_lag: &ConsumerLag) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(13 + 37 * consumer_lag.partitions.len());
+    bytes.put_u8(consumer_lag.kind.as_code());
+    bytes.put_u32_le(consumer_lag.consumer_id);
+    bytes.put_u64_le(consumer_lag.total_lag);
+    for partition in &consumer_lag.partitions {
+        bytes.put_u32_le(partition.partition_id);
+        bytes.put_u8(partition.stored_offset.is_some() as u8);
+        bytes.put_u64_le(partition.stored_offset.unwrap_or_default());
+        bytes.put_u64_le(partition.end_offset);
+        bytes.put_u64_le(partition.lag);
+        bytes.put_u64_le(partition.estimated_lag_time);
+    }
+    bytes.freeze()
+}
+
+pub fn map_appended_messages(appended_messages: &AppendedMessages) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(20 + 4 * appended_messages.skipped_indexes.len());
+    bytes.put_u32_le(appended_messages.partition_id);
+    bytes.put_u64_le(appended_messages.base_offset);
+    bytes.put_u32_le(appended_messages.messages_count);
+    bytes.put_u32_le(appended_messages.skipped_indexes.len() as u32);
+    for index in &appended_messages.skipped_indexes {
+        bytes.put_u32_le(*index);
+    }
+    bytes.freeze()
+}
+
+pub fn map_consumer_offsets_reset(consumer_offsets_reset: &ConsumerOffsetsReset) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(6 + 22 * consumer_offsets_reset.partitions.len());
+    bytes.put_u8(consumer_offsets_reset.kind.as_code());
+    bytes.put_u32_le(consumer_offsets_reset.consumer_id);
+    bytes.put_u8(consumer_offsets_reset.dry_run as u8);
+    for partition in &consumer_offsets_reset.partitions {
+        bytes.put_u32_le(partition.partition_id);
+        bytes.put_u8(partition.previous_offset.is_some() as u8);
+        bytes.put_u64_le(partition.previous_offset.unwrap_or_default());
+        bytes.put_u8(partition.new_offset.is_some() as u8);
+        bytes.put_u64_le(partition.new_offset.unwrap_or_default());
+    }
+    bytes.freeze()
+}
+
+pub fn map_partition_offsets(offsets: &[PartitionOffsets]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(31 * offsets.len());
+    for partition in offsets {
+        bytes.put_u32_le(partition.partition_id);
+        for offset in [
+            partition.first_offset,
+            partition.last_offset,
+            partition.offset,
+        ] {
+            bytes.put_u8(offset.is_some() as u8);
+            bytes.put_u64_le(offset.unwrap_or_default());
+        }
+    }
+    bytes.freeze()
+}
+
+pub fn map_partition_truncation(truncation: &PartitionTruncation) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(24);
+    bytes.put_u32_le(truncation.partition_id);
+    bytes.put_u64_le(truncation.low_watermark);
+    bytes.put_u32_le(truncation.deleted_segments);
+    bytes.put_u64_le(truncation.deleted_messages);
+    bytes.freeze()
+}
+
 pub async fn map_client(client: &Client) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_client(client, &mut bytes);
@@ -117,12 +194,29 @@ pub fn map_personal_access_tokens(personal_access_tokens: &[PersonalAccessToken]
     bytes.freeze()
 }
 
-pub fn map_polled_messages(polled_messages: &PolledMessages) -> Bytes {
+pub fn map_schema(schema: &Schema) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(31 + schema.definition.len());
+    extend_schema(schema, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_schemas(schemas: &[&Schema]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for schema in schemas {
+        extend_schema(schema, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_polled_messages(
+    polled_messages: &PolledMessages,
+    version: PollMessagesVersion,
+) -> Bytes {
     let messages_count = polled_messages.messages.len() as u32;
     let messages_size = polled_messages
         .messages
         .iter()
-        .map(|message| message.get_size_bytes())
+        .map(|message| message.get_size_bytes_for_version(version))
         .sum::<u32>();
 
     let mut bytes = BytesMut::with_capacity(20 + messages_size as usize);
@@ -130,17 +224,25 @@ pub fn map_polled_messages(polled_messages: &PolledMessages) -> Bytes {
     bytes.put_u64_le(polled_messages.current_offset);
     bytes.put_u32_le(messages_count);
     for message in polled_messages.messages.iter() {
-        message.extend(&mut bytes);
+        message.extend_for_version(&mut bytes, version);
     }
 
     bytes.freeze()
 }
 
-pub async fn map_stream(stream: &Stream) -> Bytes {
+pub async fn map_stream(stream: &Stream, version: TopicLayoutVersion) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_stream(stream, &mut bytes).await;
+    if version == TopicLayoutVersion::V2 {
+        let options = TopicOptions {
+            durability: None,
+            config_overrides: Some(stream.topic_config_overrides),
+            partitioning_scheme: None,
+        };
+        bytes.put_slice(&options.as_block_bytes());
+    }
     for topic in stream.get_topics() {
-        extend_topic(topic, &mut bytes).await;
+        extend_topic(topic, &mut bytes, version).await;
     }
     bytes.freeze()
 }
@@ -153,17 +255,17 @@ pub async fn map_streams(streams: &[&Stream]) -> Bytes {
     bytes.freeze()
 }
 
-pub async fn map_topics(topics: &[&Topic]) -> Bytes {
+pub async fn map_topics(topics: &[&Topic], version: TopicLayoutVersion) -> Bytes {
     let mut bytes = BytesMut::new();
     for topic in topics {
-        extend_topic(topic, &mut bytes).await;
+        extend_topic(topic, &mut bytes, version).await;
     }
     bytes.freeze()
 }
 
-pub async fn map_topic(topic: &Topic) -> Bytes {
+pub async fn map_topic(topic: &Topic, version: TopicLayoutVersion) -> Bytes {
     let mut bytes = BytesMut::new();
-    extend_topic(topic, &mut bytes).await;
+    extend_topic(topic, &mut bytes, version).await;
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
         extend_partition(&partition, &mut bytes);
@@ -206,7 +308,7 @@ async fn extend_stream(stream: &Stream, bytes: &mut BytesMut) {
     bytes.put_slice(stream.name.as_bytes());
 }
 
-async fn extend_topic(topic: &Topic, bytes: &mut BytesMut) {
+async fn extend_topic(topic: &Topic, bytes: &mut BytesMut, version: TopicLayoutVersion) {
     bytes.put_u32_le(topic.topic_id);
     bytes.put_u64_le(topic.created_at);
     bytes.put_u32_le(topic.get_partitions().len() as u32);
@@ -224,6 +326,14 @@ async fn extend_topic(topic: &Topic, bytes: &mut BytesMut) {
     bytes.put_u64_le(topic.get_messages_count());
     bytes.put_u8(topic.name.len() as u8);
     bytes.put_slice(topic.name.as_bytes());
+    if version == TopicLayoutVersion::V2 {
+        let options = TopicOptions {
+            durability: Some(topic.durability),
+            config_overrides: Some(topic.config_overrides),
+            partitioning_scheme: Some(topic.partitioning_scheme),
+        };
+        bytes.put_slice(&options.as_block_bytes());
+    }
 }
 
 fn extend_partition(partition: &Partition, bytes: &mut BytesMut) {
@@ -249,6 +359,11 @@ fn extend_client(client: &Client, bytes: &mut BytesMut) {
     let transport: u8 = match client.transport {
         Transport::Tcp => 1,
         Transport::Quic => 2,
+        Transport::Http => 3,
+        Transport::Grpc => 4,
+        Transport::Kafka => 5,
+        Transport::Mqtt => 6,
+        Transport::Unix => 7,
     };
     bytes.put_u8(transport);
     let address = client.address.to_string();
@@ -265,6 +380,19 @@ fn extend_user(user: &User, bytes: &mut BytesMut) {
     bytes.put_slice(user.username.as_bytes());
 }
 
+fn extend_schema(schema: &Schema, bytes: &mut BytesMut) {
+    bytes.put_u32_le(schema.id);
+    bytes.put_u32_le(schema.stream_id);
+    bytes.put_u32_le(schema.topic_id);
+    bytes.put_u32_le(schema.version);
+    bytes.put_u8(schema.schema_type.as_code());
+    bytes.put_u8(schema.compatibility.as_code());
+    bytes.put_u8(if schema.validation_enabled { 1 } else { 0 });
+    bytes.put_u64_le(schema.created_at);
+    bytes.put_u32_le(schema.definition.len() as u32);
+    bytes.put_slice(schema.definition.as_bytes());
+}
+
 fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut BytesMut) {
     bytes.put_u8(personal_access_token.name.len() as u8);
     bytes.put_slice(personal_access_token.name.as_bytes());
@@ -70,6 +70,13 @@ impl ServerCommand<CleanMessagesCommand> for CleanMessagesExecutor {
         for stream in streams {
             let topics = stream.get_topics();
             for topic in topics {
+                if let Err(error) = topic.close_aged_segments(now).await {
+                    error!(
+                        "Failed to close the aged segments for stream ID: {}, topic ID: {}. Error: {}",
+                        topic.stream_id, topic.topic_id, error
+                    );
+                }
+
                 let deleted_segments = delete_expired_segments(topic, now).await;
                 if let Ok(Some(deleted_segments)) = deleted_segments {
                     info!(
@@ -0,0 +1,118 @@
+use crate::streaming::systems::system::SharedSystem;
+use crate::{channels::server_command::ServerCommand, configs::server::MessageSchedulerConfig};
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info};
+
+/// The maximum number of the scheduled messages loaded at once, the remaining ones are delivered in the next rounds.
+const MAX_MESSAGES_PER_DELIVERY: u32 = 10_000;
+
+pub struct MessagesScheduler {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<DeliverScheduledMessagesCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DeliverScheduledMessagesCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct DeliverScheduledMessagesExecutor;
+
+impl MessagesScheduler {
+    pub fn new(
+        config: &MessageSchedulerConfig,
+        sender: Sender<DeliverScheduledMessagesCommand>,
+    ) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Message scheduler is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Message scheduler is enabled, due messages will be delivered every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(DeliverScheduledMessagesCommand)
+                    .unwrap_or_else(|err| {
+                        error!(
+                            "Failed to send DeliverScheduledMessagesCommand. Error: {}",
+                            err
+                        );
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<DeliverScheduledMessagesCommand> for DeliverScheduledMessagesExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: DeliverScheduledMessagesCommand) {
+        let system = system.read();
+        let mut delivered_messages_count = 0;
+        loop {
+            match system
+                .deliver_scheduled_messages(MAX_MESSAGES_PER_DELIVERY)
+                .await
+            {
+                Ok(count) => {
+                    delivered_messages_count += count;
+                    if count < MAX_MESSAGES_PER_DELIVERY as u64 {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    error!("Failed to deliver the scheduled messages. Error: {}", err);
+                    break;
+                }
+            }
+        }
+
+        if delivered_messages_count > 0 {
+            debug!("Delivered {delivered_messages_count} scheduled message(s).");
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<DeliverScheduledMessagesCommand>,
+    ) {
+        let messages_scheduler = MessagesScheduler::new(&config.message_scheduler, sender);
+        messages_scheduler.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<DeliverScheduledMessagesCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Messages scheduler receiver stopped.");
+        });
+    }
+}
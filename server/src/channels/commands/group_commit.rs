@@ -0,0 +1,103 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::configs::system::PartitionConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::{Receiver, Sender};
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{error, info, trace, warn};
+
+pub struct GroupCommitter {
+    window: IggyDuration,
+    sender: Sender<GroupCommitCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GroupCommitCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct GroupCommitExecutor;
+
+impl GroupCommitter {
+    pub fn new(config: &PartitionConfig, sender: Sender<GroupCommitCommand>) -> Self {
+        Self {
+            window: config.group_commit_window,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        let window = self.window;
+        let sender = self.sender.clone();
+        info!(
+            "Group committer is started, written messages of group commit topics will be synced every: {:?}.",
+            window
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(window.get_duration());
+            interval_timer.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+            loop {
+                interval_timer.tick().await;
+                // Skip the tick if the previous sync is still in progress, it will cover the new writes anyway.
+                if !sender.is_empty() {
+                    continue;
+                }
+
+                sender.send(GroupCommitCommand).unwrap_or_else(|e| {
+                    error!("Failed to send GroupCommitCommand. Error: {e}",);
+                });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<GroupCommitCommand> for GroupCommitExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: GroupCommitCommand) {
+        // Waiting for the system lock would block the worker thread on every tick, so the sync is skipped
+        // while the lock is held for writing, the next one covers the written messages anyway.
+        let Some(system) = system.try_read() else {
+            trace!("System is locked for writing, skipping the sync of the written messages.");
+            return;
+        };
+        let synced_partitions_count = system.sync_group_commit_messages().await;
+
+        match synced_partitions_count {
+            Ok(n) => {
+                if n > 0 {
+                    trace!("Synced the written messages of {n} partitions.");
+                }
+            }
+            Err(e) => {
+                error!("Couldn't sync the written messages. Error: {e}");
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<GroupCommitCommand>,
+    ) {
+        let group_committer = GroupCommitter::new(&config.system.partition, sender);
+        group_committer.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<GroupCommitCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            warn!("Server command handler stopped receiving commands.");
+        });
+    }
+}
@@ -1,4 +1,6 @@
 pub mod clean_messages;
 pub mod clean_personal_access_tokens;
+pub mod deliver_scheduled_messages;
+pub mod group_commit;
 pub mod print_sysinfo;
 pub mod save_messages;
@@ -1,5 +1,7 @@
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone, Copy)]
 pub enum BinarySchema {
     RetainedMessageSchema,
     RetainedMessageBatchSchema,
+    RetainedMessageBatchWithKeySchema,
 }
@@ -1,5 +1,6 @@
 use crate::compat::samplers::message_sampler::MessageSampler;
 use crate::compat::samplers::retained_batch_sampler::RetainedMessageBatchSampler;
+use crate::compat::samplers::retained_batch_with_key_sampler::RetainedMessageBatchWithKeySampler;
 use crate::compat::schema_sampler::BinarySchemaSampler;
 use crate::streaming::sizeable::Sizeable;
 use bytes::{BufMut, BytesMut};
@@ -83,6 +84,11 @@ impl MessageFormatConverter {
         // Always append new schemas to beginning of vec
         MessageFormatConverter {
             samplers: vec![
+                Box::new(RetainedMessageBatchWithKeySampler::new(
+                    segment_start_offset,
+                    log_path.clone(),
+                    index_path.clone(),
+                )),
                 Box::new(RetainedMessageBatchSampler::new(
                     segment_start_offset,
                     log_path.clone(),
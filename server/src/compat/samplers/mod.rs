@@ -1,2 +1,3 @@
 pub(crate) mod message_sampler;
 pub(crate) mod retained_batch_sampler;
+pub(crate) mod retained_batch_with_key_sampler;
@@ -0,0 +1,123 @@
+use crate::compat::binary_schema::BinarySchema;
+use crate::compat::schema_sampler::BinarySchemaSampler;
+use crate::compat::snapshots::retained_batch_snapshot::RetainedMessageBatchSnapshot;
+use crate::server_error::ServerError;
+use crate::streaming::utils::file;
+use async_trait::async_trait;
+use bytes::{BufMut, Bytes};
+use iggy::utils::checksum;
+use tokio::io::AsyncReadExt;
+
+pub struct RetainedMessageBatchWithKeySampler {
+    pub segment_start_offset: u64,
+    pub log_path: String,
+    pub index_path: String,
+}
+
+impl RetainedMessageBatchWithKeySampler {
+    pub fn new(
+        segment_start_offset: u64,
+        log_path: String,
+        index_path: String,
+    ) -> RetainedMessageBatchWithKeySampler {
+        RetainedMessageBatchWithKeySampler {
+            segment_start_offset,
+            log_path,
+            index_path,
+        }
+    }
+
+    // The batch layout didn't change, so the first message is parsed using the layout with the messages key,
+    // and its checksum is verified against the payload, which is located right after the key.
+    fn validate_first_message(
+        &self,
+        batch: &RetainedMessageBatchSnapshot,
+    ) -> Result<(), ServerError> {
+        let invalid_field = |field: &str| {
+            ServerError::InvalidMessageFieldFormatConversionSampling(format!(
+                "Invalid {field} bytes for message with key"
+            ))
+        };
+        let length = u32::from_le_bytes(
+            batch
+                .bytes
+                .get(0..4)
+                .ok_or_else(|| invalid_field("length"))?
+                .try_into()?,
+        );
+        let message = batch
+            .bytes
+            .get(4..4 + length as usize)
+            .ok_or_else(|| invalid_field("message"))?;
+        let offset = u64::from_le_bytes(
+            message
+                .get(0..8)
+                .ok_or_else(|| invalid_field("offset"))?
+                .try_into()?,
+        );
+        if offset != self.segment_start_offset {
+            return Err(ServerError::InvalidMessageOffsetFormatConversion);
+        }
+
+        let checksum = u32::from_le_bytes(
+            message
+                .get(33..37)
+                .ok_or_else(|| invalid_field("checksum"))?
+                .try_into()?,
+        );
+        let headers_length = u32::from_le_bytes(
+            message
+                .get(37..41)
+                .ok_or_else(|| invalid_field("headers_length"))?
+                .try_into()?,
+        );
+        let key_position = 41 + headers_length as usize;
+        let key_length = *message
+            .get(key_position)
+            .ok_or_else(|| invalid_field("key_length"))? as usize;
+        let payload = message
+            .get(key_position + 1 + key_length..)
+            .ok_or_else(|| invalid_field("payload"))?;
+        if checksum::calculate(payload) != checksum {
+            return Err(ServerError::InvalidMessageChecksumFormatConversion);
+        }
+
+        Ok(())
+    }
+}
+
+unsafe impl Send for RetainedMessageBatchWithKeySampler {}
+unsafe impl Sync for RetainedMessageBatchWithKeySampler {}
+
+#[async_trait]
+impl BinarySchemaSampler for RetainedMessageBatchWithKeySampler {
+    async fn try_sample(&self) -> Result<BinarySchema, ServerError> {
+        let mut index_file = file::open(&self.index_path).await?;
+        let mut log_file = file::open(&self.log_path).await?;
+        let log_file_size = log_file.metadata().await?.len();
+
+        if log_file_size == 0 {
+            return Ok(BinarySchema::RetainedMessageBatchWithKeySchema);
+        }
+
+        let _ = index_file.read_u32_le().await?;
+        let _ = index_file.read_u32_le().await?;
+        let second_index_offset = index_file.read_u32_le().await;
+        let second_end_position = index_file.read_u32_le().await;
+
+        let mut buffer = Vec::new();
+        if second_index_offset.is_err() && second_end_position.is_err() {
+            let _ = log_file.read_to_end(&mut buffer).await?;
+        } else {
+            let buffer_size = second_end_position.unwrap() as usize;
+            buffer.put_bytes(0, buffer_size);
+            let _ = log_file.read_exact(&mut buffer).await?;
+        }
+        let batch = RetainedMessageBatchSnapshot::try_from(Bytes::from(buffer))?;
+        if batch.base_offset != self.segment_start_offset {
+            return Err(ServerError::InvalidBatchBaseOffsetFormatConversion);
+        }
+        self.validate_first_message(&batch)?;
+        Ok(BinarySchema::RetainedMessageBatchWithKeySchema)
+    }
+}
@@ -64,6 +64,8 @@ impl Extendable for MessageSnapshot {
         } else {
             bytes.put_u32_le(0u32);
         }
+        // The messages key didn't exist in this schema.
+        bytes.put_u8(0);
         bytes.put_slice(&payload);
     }
 }
@@ -71,7 +73,7 @@ impl Extendable for MessageSnapshot {
 impl Sizeable for MessageSnapshot {
     fn get_size_bytes(&self) -> u32 {
         let headers_size = header::get_headers_size_bytes(&self.headers);
-        41 + headers_size + self.payload.len() as u32
+        42 + headers_size + self.payload.len() as u32
     }
 }
 
@@ -34,6 +34,49 @@ impl RetainedMessageBatchSnapshot {
         self.base_offset + self.last_offset_delta as u64
     }
 
+    /// Rewrites the messages of the batch stored without the messages key, so that each of them has an empty key.
+    pub fn try_with_empty_messages_keys(self) -> Result<RetainedMessageBatchSnapshot, IggyError> {
+        let messages_count = self.last_offset_delta as usize + 1;
+        let mut bytes = BytesMut::with_capacity(self.bytes.len() + messages_count);
+        let mut position = 0;
+        while position < self.bytes.len() {
+            let length = u32::from_le_bytes(
+                self.bytes
+                    .get(position..position + 4)
+                    .ok_or(IggyError::InvalidFormat)?
+                    .try_into()?,
+            );
+            let message = self
+                .bytes
+                .get(position + 4..position + 4 + length as usize)
+                .ok_or(IggyError::InvalidFormat)?;
+            let headers_length = u32::from_le_bytes(
+                message
+                    .get(37..41)
+                    .ok_or(IggyError::InvalidFormat)?
+                    .try_into()?,
+            );
+            let key_position = 41 + headers_length as usize;
+            if key_position > message.len() {
+                return Err(IggyError::InvalidFormat);
+            }
+
+            bytes.put_u32_le(length + 1);
+            bytes.put_slice(&message[..key_position]);
+            bytes.put_u8(0);
+            bytes.put_slice(&message[key_position..]);
+            position += 4 + length as usize;
+        }
+
+        Ok(RetainedMessageBatchSnapshot::new(
+            self.base_offset,
+            self.last_offset_delta,
+            self.max_timestamp,
+            bytes.len() as u32,
+            bytes.freeze(),
+        ))
+    }
+
     pub fn try_from_messages(
         messages: Vec<MessageSnapshot>,
     ) -> Result<RetainedMessageBatchSnapshot, IggyError> {
@@ -121,7 +164,7 @@ impl TryFrom<Bytes> for RetainedMessageBatchSnapshot {
         );
         let bytes = Bytes::from(
             value
-                .get(24..length as usize)
+                .get(24..24 + length as usize)
                 .ok_or_else(|| {
                     ServerError::CannotReadMessageBatchFormatConversion(
                         "Failed to read batch payload".to_owned(),
@@ -138,3 +181,39 @@ impl TryFrom<Bytes> for RetainedMessageBatchSnapshot {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::models::messages::RetainedMessage;
+    use iggy::models::messages::MessageState;
+    use iggy::utils::checksum;
+
+    #[test]
+    fn messages_stored_without_key_should_be_converted_to_messages_with_empty_key() {
+        let payload = Bytes::from("hello");
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(41 + payload.len() as u32);
+        bytes.put_u64_le(5);
+        bytes.put_u8(MessageState::Available.as_code());
+        bytes.put_u64_le(100);
+        bytes.put_u128_le(1);
+        bytes.put_u32_le(checksum::calculate(&payload));
+        bytes.put_u32_le(0);
+        bytes.put_slice(&payload);
+        let batch =
+            RetainedMessageBatchSnapshot::new(5, 0, 100, bytes.len() as u32, bytes.freeze());
+
+        let batch = batch.try_with_empty_messages_keys().unwrap();
+
+        let length = u32::from_le_bytes(batch.bytes[..4].try_into().unwrap());
+        assert_eq!(batch.length, 4 + length);
+        let message = RetainedMessage::try_from_bytes(batch.bytes.slice(4..)).unwrap();
+        assert_eq!(message.offset, 5);
+        assert_eq!(message.timestamp, 100);
+        assert_eq!(message.id, 1);
+        assert_eq!(message.checksum, checksum::calculate(&payload));
+        assert!(message.key.is_none());
+        assert_eq!(message.payload, payload);
+    }
+}
@@ -1,7 +1,14 @@
+use crate::compat::message_stream::MessageStream;
+use crate::compat::snapshots::retained_batch_snapshot::RetainedMessageBatchSnapshot;
+use async_stream::try_stream;
+use bytes::{BufMut, BytesMut};
+use futures::Stream;
+use iggy::error::IggyError;
 use tokio::fs::File;
-use tokio::io::BufWriter;
+use tokio::io::{AsyncReadExt, BufReader, BufWriter};
 
 const BUF_WRITER_CAPACITY_BYTES: usize = 512 * 1000;
+const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
 
 pub struct RetainedBatchWriter {
     pub log_writer: BufWriter<File>,
@@ -18,3 +25,49 @@ impl RetainedBatchWriter {
         }
     }
 }
+
+pub struct RetainedBatchStream {
+    pub reader: BufReader<File>,
+    read_length: u64,
+    read_bytes: u64,
+}
+
+impl RetainedBatchStream {
+    pub fn new(file: File, read_length: u64) -> RetainedBatchStream {
+        RetainedBatchStream {
+            reader: BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file),
+            read_bytes: 0,
+            read_length,
+        }
+    }
+}
+
+impl MessageStream for RetainedBatchStream {
+    type Item = Result<RetainedMessageBatchSnapshot, IggyError>;
+
+    fn into_stream(mut self) -> impl Stream<Item = Self::Item> {
+        try_stream! {
+            while self.read_bytes < self.read_length {
+                let base_offset = self.reader.read_u64_le().await?;
+                let length = self.reader.read_u32_le().await?;
+                let last_offset_delta = self.reader.read_u32_le().await?;
+                let max_timestamp = self.reader.read_u64_le().await?;
+                self.read_bytes += 24;
+
+                let mut bytes = BytesMut::with_capacity(length as usize);
+                bytes.put_bytes(0, length as usize);
+                self.reader.read_exact(&mut bytes).await?;
+                self.read_bytes += length as u64;
+
+                let batch = RetainedMessageBatchSnapshot::new(
+                    base_offset,
+                    last_offset_delta,
+                    max_timestamp,
+                    length,
+                    bytes.freeze(),
+                );
+                yield batch;
+            }
+        }
+    }
+}
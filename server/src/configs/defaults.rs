@@ -1,10 +1,13 @@
+use crate::configs::grpc::{GrpcConfig, GrpcTlsConfig};
 use crate::configs::http::{
-    HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
+    HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpLiveConfig, HttpMetricsConfig, HttpTlsConfig,
 };
+use crate::configs::kafka::KafkaConfig;
+use crate::configs::mqtt::MqttConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    MessageCleanerConfig, MessageSaverConfig, PersonalAccessTokenCleanerConfig,
-    PersonalAccessTokenConfig, ServerConfig,
+    MessageCleanerConfig, MessageSaverConfig, MessageSchedulerConfig,
+    PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig, ServerConfig, TelemetryConfig,
 };
 use crate::configs::system::{
     BackupConfig, CacheConfig, CompatibilityConfig, CompressionConfig, DatabaseConfig,
@@ -12,6 +15,7 @@ use crate::configs::system::{
     RetentionPolicyConfig, RuntimeConfig, SegmentConfig, StreamConfig, SystemConfig, TopicConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use crate::configs::unix::UnixConfig;
 use std::sync::Arc;
 
 static_toml::static_toml! {
@@ -24,11 +28,17 @@ impl Default for ServerConfig {
         ServerConfig {
             message_cleaner: MessageCleanerConfig::default(),
             message_saver: MessageSaverConfig::default(),
+            message_scheduler: MessageSchedulerConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
+            telemetry: TelemetryConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
             http: HttpConfig::default(),
+            grpc: GrpcConfig::default(),
+            kafka: KafkaConfig::default(),
+            mqtt: MqttConfig::default(),
+            unix: UnixConfig::default(),
         }
     }
 }
@@ -64,6 +74,64 @@ impl Default for QuicCertificateConfig {
     }
 }
 
+impl Default for GrpcConfig {
+    fn default() -> GrpcConfig {
+        GrpcConfig {
+            enabled: SERVER_CONFIG.grpc.enabled,
+            address: SERVER_CONFIG.grpc.address.parse().unwrap(),
+            tls: GrpcTlsConfig::default(),
+        }
+    }
+}
+
+impl Default for KafkaConfig {
+    fn default() -> KafkaConfig {
+        KafkaConfig {
+            enabled: SERVER_CONFIG.kafka.enabled,
+            address: SERVER_CONFIG.kafka.address.parse().unwrap(),
+            stream: SERVER_CONFIG.kafka.stream.parse().unwrap(),
+            username: SERVER_CONFIG.kafka.username.parse().unwrap(),
+            password: SERVER_CONFIG.kafka.password.parse().unwrap(),
+            initial_rebalance_delay: SERVER_CONFIG.kafka.initial_rebalance_delay.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for MqttConfig {
+    fn default() -> MqttConfig {
+        MqttConfig {
+            enabled: SERVER_CONFIG.mqtt.enabled,
+            address: SERVER_CONFIG.mqtt.address.parse().unwrap(),
+            max_packet_size: SERVER_CONFIG.mqtt.max_packet_size.parse().unwrap(),
+            poll_interval: SERVER_CONFIG.mqtt.poll_interval.parse().unwrap(),
+            max_session_expiry: SERVER_CONFIG.mqtt.max_session_expiry.parse().unwrap(),
+            resend_interval: SERVER_CONFIG.mqtt.resend_interval.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for UnixConfig {
+    fn default() -> UnixConfig {
+        UnixConfig {
+            enabled: SERVER_CONFIG.unix.enabled,
+            path: SERVER_CONFIG.unix.path.parse().unwrap(),
+            permissions: SERVER_CONFIG.unix.permissions as u32,
+            peer_users: SERVER_CONFIG.unix.peer_users.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for GrpcTlsConfig {
+    fn default() -> GrpcTlsConfig {
+        GrpcTlsConfig {
+            enabled: SERVER_CONFIG.grpc.tls.enabled,
+            cert_file: SERVER_CONFIG.grpc.tls.cert_file.parse().unwrap(),
+            key_file: SERVER_CONFIG.grpc.tls.key_file.parse().unwrap(),
+            ca_file: SERVER_CONFIG.grpc.tls.ca_file.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for TcpConfig {
     fn default() -> TcpConfig {
         TcpConfig {
@@ -93,6 +161,7 @@ impl Default for HttpConfig {
             cors: HttpCorsConfig::default(),
             jwt: HttpJwtConfig::default(),
             metrics: HttpMetricsConfig::default(),
+            live: HttpLiveConfig::default(),
             tls: HttpTlsConfig::default(),
         }
     }
@@ -176,6 +245,15 @@ impl Default for HttpMetricsConfig {
     }
 }
 
+impl Default for HttpLiveConfig {
+    fn default() -> HttpLiveConfig {
+        HttpLiveConfig {
+            enabled: SERVER_CONFIG.http.live.enabled,
+            poll_interval: SERVER_CONFIG.http.live.poll_interval.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for HttpTlsConfig {
     fn default() -> HttpTlsConfig {
         HttpTlsConfig {
@@ -205,6 +283,16 @@ impl Default for MessageSaverConfig {
     }
 }
 
+impl Default for MessageSchedulerConfig {
+    fn default() -> MessageSchedulerConfig {
+        MessageSchedulerConfig {
+            enabled: SERVER_CONFIG.message_scheduler.enabled,
+            interval: SERVER_CONFIG.message_scheduler.interval.parse().unwrap(),
+            max_delay: SERVER_CONFIG.message_scheduler.max_delay.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for PersonalAccessTokenConfig {
     fn default() -> PersonalAccessTokenConfig {
         PersonalAccessTokenConfig {
@@ -214,6 +302,16 @@ impl Default for PersonalAccessTokenConfig {
     }
 }
 
+impl Default for TelemetryConfig {
+    fn default() -> TelemetryConfig {
+        TelemetryConfig {
+            enabled: SERVER_CONFIG.telemetry.enabled,
+            service_name: SERVER_CONFIG.telemetry.service_name.parse().unwrap(),
+            endpoint: SERVER_CONFIG.telemetry.endpoint.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for PersonalAccessTokenCleanerConfig {
     fn default() -> PersonalAccessTokenCleanerConfig {
         PersonalAccessTokenCleanerConfig {
@@ -308,7 +406,10 @@ impl Default for LoggingConfig {
             path: SERVER_CONFIG.system.logging.path.parse().unwrap(),
             level: SERVER_CONFIG.system.logging.level.parse().unwrap(),
             max_size: SERVER_CONFIG.system.logging.max_size.parse().unwrap(),
+            max_total_size: SERVER_CONFIG.system.logging.max_total_size.parse().unwrap(),
             retention: SERVER_CONFIG.system.logging.retention.parse().unwrap(),
+            compress_rotated: SERVER_CONFIG.system.logging.compress_rotated,
+            format: SERVER_CONFIG.system.logging.format.parse().unwrap(),
             sysinfo_print_interval: SERVER_CONFIG
                 .system
                 .logging
@@ -368,6 +469,12 @@ impl Default for TopicConfig {
     fn default() -> TopicConfig {
         TopicConfig {
             path: SERVER_CONFIG.system.topic.path.parse().unwrap(),
+            partitioning_scheme: SERVER_CONFIG
+                .system
+                .topic
+                .partitioning_scheme
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -380,6 +487,25 @@ impl Default for PartitionConfig {
                 as u32,
             enforce_fsync: SERVER_CONFIG.system.partition.enforce_fsync,
             validate_checksum: SERVER_CONFIG.system.partition.validate_checksum,
+            durability: SERVER_CONFIG.system.partition.durability.parse().unwrap(),
+            group_commit_window: SERVER_CONFIG
+                .system
+                .partition
+                .group_commit_window
+                .parse()
+                .unwrap(),
+            timestamp_type: SERVER_CONFIG
+                .system
+                .partition
+                .timestamp_type
+                .parse()
+                .unwrap(),
+            max_timestamp_skew: SERVER_CONFIG
+                .system
+                .partition
+                .max_timestamp_skew
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -390,6 +516,8 @@ impl Default for SegmentConfig {
             size: SERVER_CONFIG.system.segment.size.parse().unwrap(),
             cache_indexes: SERVER_CONFIG.system.segment.cache_indexes,
             cache_time_indexes: SERVER_CONFIG.system.segment.cache_time_indexes,
+            max_age: SERVER_CONFIG.system.segment.max_age.parse().unwrap(),
+            max_messages: SERVER_CONFIG.system.segment.max_messages as u64,
         }
     }
 }
@@ -1,15 +1,24 @@
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::system::MessageDeduplicationConfig;
 use crate::configs::{
-    http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
+    grpc::{GrpcConfig, GrpcTlsConfig},
+    http::{
+        HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpLiveConfig, HttpMetricsConfig, HttpTlsConfig,
+    },
+    kafka::KafkaConfig,
+    mqtt::MqttConfig,
     resource_quota::MemoryResourceQuota,
-    server::{MessageCleanerConfig, MessageSaverConfig, ServerConfig},
+    server::{
+        MessageCleanerConfig, MessageSaverConfig, MessageSchedulerConfig, ServerConfig,
+        TelemetryConfig,
+    },
     system::{
-        CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LoggingConfig,
+        CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LogFormat, LoggingConfig,
         PartitionConfig, RetentionPolicyConfig, SegmentConfig, StreamConfig, SystemConfig,
         TopicConfig,
     },
     tcp::{TcpConfig, TcpTlsConfig},
+    unix::UnixConfig,
 };
 use std::fmt::{Display, Formatter};
 
@@ -17,8 +26,8 @@ impl Display for HttpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, max_request_size: {}, cors: {}, jwt: {}, metrics: {}, tls: {} }}",
-            self.enabled, self.address, self.max_request_size, self.cors, self.jwt, self.metrics, self.tls
+            "{{ enabled: {}, address: {}, max_request_size: {}, cors: {}, jwt: {}, metrics: {}, live: {}, tls: {} }}",
+            self.enabled, self.address, self.max_request_size, self.cors, self.jwt, self.metrics, self.live, self.tls
         )
     }
 }
@@ -53,6 +62,16 @@ impl Display for HttpMetricsConfig {
     }
 }
 
+impl Display for HttpLiveConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, poll_interval: {} }}",
+            self.enabled, self.poll_interval
+        )
+    }
+}
+
 impl Display for HttpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -115,8 +134,19 @@ impl Display for ServerConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ message_cleaner: {}, message_saver: {}, system: {}, quic: {}, tcp: {}, http: {} }}",
-            self.message_cleaner, self.message_saver, self.system, self.quic, self.tcp, self.http
+            "{{ message_cleaner: {}, message_saver: {}, message_scheduler: {}, telemetry: {}, system: {}, quic: {}, tcp: {}, http: {}, grpc: {}, kafka: {}, mqtt: {}, unix: {} }}",
+            self.message_cleaner,
+            self.message_saver,
+            self.message_scheduler,
+            self.telemetry,
+            self.system,
+            self.quic,
+            self.tcp,
+            self.http,
+            self.grpc,
+            self.kafka,
+            self.mqtt,
+            self.unix
         )
     }
 }
@@ -141,6 +171,26 @@ impl Display for MessageSaverConfig {
     }
 }
 
+impl Display for MessageSchedulerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, max_delay: {} }}",
+            self.enabled, self.interval, self.max_delay
+        )
+    }
+}
+
+impl Display for TelemetryConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, service_name: {}, endpoint: {} }}",
+            self.enabled, self.service_name, self.endpoint
+        )
+    }
+}
+
 impl Display for DatabaseConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ path: {} }}", self.path)
@@ -178,7 +228,11 @@ impl Display for StreamConfig {
 
 impl Display for TopicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ path: {} }}", self.path)
+        write!(
+            f,
+            "{{ path: {}, partitioning_scheme: {} }}",
+            self.path, self.partitioning_scheme
+        )
     }
 }
 
@@ -186,11 +240,15 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {} }}",
+          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {}, durability: {}, group_commit_window: {}, timestamp_type: {}, max_timestamp_skew: {} }}",
           self.path,
           self.messages_required_to_save,
           self.enforce_fsync,
-          self.validate_checksum
+          self.validate_checksum,
+          self.durability,
+          self.group_commit_window,
+          self.timestamp_type,
+          self.max_timestamp_skew
       )
     }
 }
@@ -209,8 +267,8 @@ impl Display for SegmentConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ size_bytes: {}, cache_indexes: {}, cache_time_indexes: {} }}",
-            self.size, self.cache_indexes, self.cache_time_indexes
+            "{{ size_bytes: {}, cache_indexes: {}, cache_time_indexes: {}, max_age: {}, max_messages: {} }}",
+            self.size, self.cache_indexes, self.cache_time_indexes, self.max_age, self.max_messages
         )
     }
 }
@@ -219,15 +277,27 @@ impl Display for LoggingConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, level: {}, max_size: {}, retention: {} }}",
+            "{{ path: {}, level: {}, max_size: {}, max_total_size: {}, retention: {}, compress_rotated: {}, format: {} }}",
             self.path,
             self.level,
             self.max_size.as_human_string_with_zero_as_unlimited(),
-            self.retention
+            self.max_total_size.as_human_string_with_zero_as_unlimited(),
+            self.retention,
+            self.compress_rotated,
+            self.format
         )
     }
 }
 
+impl Display for LogFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogFormat::Text => write!(f, "text"),
+            LogFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
 impl Display for TcpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -238,6 +308,61 @@ impl Display for TcpConfig {
     }
 }
 
+impl Display for GrpcConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, tls: {} }}",
+            self.enabled, self.address, self.tls
+        )
+    }
+}
+
+impl Display for KafkaConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, stream: {}, username: {}, initial_rebalance_delay: {} }}",
+            self.enabled, self.address, self.stream, self.username, self.initial_rebalance_delay
+        )
+    }
+}
+
+impl Display for MqttConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, max_packet_size: {}, poll_interval: {}, max_session_expiry: {}, resend_interval: {} }}",
+            self.enabled,
+            self.address,
+            self.max_packet_size,
+            self.poll_interval,
+            self.max_session_expiry,
+            self.resend_interval
+        )
+    }
+}
+
+impl Display for UnixConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, path: {}, permissions: {}, peer_users: {} }}",
+            self.enabled, self.path, self.permissions, self.peer_users
+        )
+    }
+}
+
+impl Display for GrpcTlsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, cert_file: {}, key_file: {}, ca_file: {} }}",
+            self.enabled, self.cert_file, self.key_file, self.ca_file
+        )
+    }
+}
+
 impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GrpcConfig {
+    pub enabled: bool,
+    pub address: String,
+    pub tls: GrpcTlsConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GrpcTlsConfig {
+    pub enabled: bool,
+    pub cert_file: String,
+    pub key_file: String,
+    pub ca_file: String,
+}
@@ -14,6 +14,7 @@ pub struct HttpConfig {
     pub cors: HttpCorsConfig,
     pub jwt: HttpJwtConfig,
     pub metrics: HttpMetricsConfig,
+    pub live: HttpLiveConfig,
     pub tls: HttpTlsConfig,
 }
 
@@ -55,6 +56,14 @@ pub struct HttpMetricsConfig {
     pub endpoint: String,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpLiveConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub poll_interval: IggyDuration,
+}
+
 #[derive(Debug)]
 pub enum JwtSecret {
     Default(String),
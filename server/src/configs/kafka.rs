@@ -0,0 +1,16 @@
+use iggy::utils::duration::IggyDuration;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct KafkaConfig {
+    pub enabled: bool,
+    pub address: String,
+    pub stream: String,
+    pub username: String,
+    pub password: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub initial_rebalance_delay: IggyDuration,
+}
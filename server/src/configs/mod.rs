@@ -1,9 +1,13 @@
 pub mod server;
 pub mod system;
 
+pub mod grpc;
 pub mod http;
+pub mod kafka;
+pub mod mqtt;
 pub mod quic;
 pub mod tcp;
+pub mod unix;
 
 pub mod config_provider;
 pub mod defaults;
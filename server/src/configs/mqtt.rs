@@ -0,0 +1,19 @@
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MqttConfig {
+    pub enabled: bool,
+    pub address: String,
+    pub max_packet_size: IggyByteSize,
+    #[serde_as(as = "DisplayFromStr")]
+    pub poll_interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_session_expiry: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub resend_interval: IggyDuration,
+}
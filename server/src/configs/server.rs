@@ -1,8 +1,12 @@
 use crate::configs::config_provider::ConfigProvider;
+use crate::configs::grpc::GrpcConfig;
 use crate::configs::http::HttpConfig;
+use crate::configs::kafka::KafkaConfig;
+use crate::configs::mqtt::MqttConfig;
 use crate::configs::quic::QuicConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
+use crate::configs::unix::UnixConfig;
 use crate::server_error::ServerError;
 use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
@@ -15,11 +19,17 @@ use std::sync::Arc;
 pub struct ServerConfig {
     pub message_cleaner: MessageCleanerConfig,
     pub message_saver: MessageSaverConfig,
+    pub message_scheduler: MessageSchedulerConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
+    pub telemetry: TelemetryConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
     pub http: HttpConfig,
+    pub grpc: GrpcConfig,
+    pub kafka: KafkaConfig,
+    pub mqtt: MqttConfig,
+    pub unix: UnixConfig,
 }
 
 #[serde_as]
@@ -39,6 +49,16 @@ pub struct MessageSaverConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MessageSchedulerConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_delay: IggyDuration,
+}
+
 #[derive(Debug, Deserialize, Serialize, Copy, Clone)]
 pub struct PersonalAccessTokenConfig {
     pub max_tokens_per_user: u32,
@@ -53,6 +73,13 @@ pub struct PersonalAccessTokenCleanerConfig {
     pub interval: IggyDuration,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TelemetryConfig {
+    pub enabled: bool,
+    pub service_name: String,
+    pub endpoint: String,
+}
+
 impl ServerConfig {
     pub async fn load(config_provider: &dyn ConfigProvider) -> Result<ServerConfig, ServerError> {
         let server_config = config_provider.load_config().await?;
@@ -1,13 +1,18 @@
 use crate::configs::resource_quota::MemoryResourceQuota;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::durability::Durability;
+use iggy::utils::partitioning_scheme::PartitioningScheme;
+use iggy::utils::timestamp_type::TimestampType;
 use iggy::{
     compression::compression_algorithm::CompressionAlgorithm, utils::duration::IggyDuration,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::str::FromStr;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SystemConfig {
     pub path: String,
     pub backup: BackupConfig,
@@ -25,46 +30,69 @@ pub struct SystemConfig {
     pub message_deduplication: MessageDeduplicationConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DatabaseConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BackupConfig {
     pub path: String,
     pub compatibility: CompatibilityConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CompatibilityConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RuntimeConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CompressionConfig {
     pub allow_override: bool,
     pub default_algorithm: CompressionAlgorithm,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LoggingConfig {
     pub path: String,
     pub level: String,
     pub max_size: IggyByteSize,
+    pub max_total_size: IggyByteSize,
     #[serde_as(as = "DisplayFromStr")]
     pub retention: IggyDuration,
+    pub compress_rotated: bool,
+    pub format: LogFormat,
     #[serde_as(as = "DisplayFromStr")]
     pub sysinfo_print_interval: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!("Invalid log format: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CacheConfig {
     pub enabled: bool,
     pub size: MemoryResourceQuota,
@@ -78,32 +106,40 @@ pub struct RetentionPolicyConfig {
     pub max_topic_size: IggyByteSize,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EncryptionConfig {
     pub enabled: bool,
     pub key: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct StreamConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TopicConfig {
     pub path: String,
+    pub partitioning_scheme: PartitioningScheme,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PartitionConfig {
     pub path: String,
     pub messages_required_to_save: u32,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    pub durability: Durability,
+    #[serde_as(as = "DisplayFromStr")]
+    pub group_commit_window: IggyDuration,
+    pub timestamp_type: TimestampType,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_timestamp_skew: IggyDuration,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct MessageDeduplicationConfig {
     pub enabled: bool,
     pub max_entries: u64,
@@ -111,14 +147,66 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SegmentConfig {
     pub size: IggyByteSize,
     pub cache_indexes: bool,
     pub cache_time_indexes: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_age: IggyDuration,
+    pub max_messages: u64,
 }
 
 impl SystemConfig {
+    /// Returns the copy of the configuration with the settings overridden for the topic.
+    pub fn with_topic_config_overrides(&self, overrides: &TopicConfigOverrides) -> SystemConfig {
+        let mut config = self.clone();
+        if let Some(segment_size) = overrides.segment_size {
+            config.segment.size = segment_size;
+        }
+        if let Some(segment_max_age) = overrides.segment_max_age {
+            config.segment.max_age = segment_max_age;
+        }
+        if let Some(segment_max_messages) = overrides.segment_max_messages {
+            config.segment.max_messages = segment_max_messages;
+        }
+        if let Some(timestamp_type) = overrides.timestamp_type {
+            config.partition.timestamp_type = timestamp_type;
+        }
+        if let Some(max_timestamp_skew) = overrides.max_timestamp_skew {
+            config.partition.max_timestamp_skew = max_timestamp_skew;
+        }
+        if let Some(partitioning_scheme) = overrides.partitioning_scheme {
+            config.topic.partitioning_scheme = partitioning_scheme;
+        }
+        if let Some(cache_indexes) = overrides.cache_indexes {
+            config.segment.cache_indexes = cache_indexes;
+        }
+        if let Some(cache_time_indexes) = overrides.cache_time_indexes {
+            config.segment.cache_time_indexes = cache_time_indexes;
+        }
+        if let Some(enforce_fsync) = overrides.enforce_fsync {
+            config.partition.enforce_fsync = enforce_fsync;
+        }
+        if let Some(messages_required_to_save) = overrides.messages_required_to_save {
+            config.partition.messages_required_to_save = messages_required_to_save;
+        }
+        if let Some(validate_checksum) = overrides.validate_checksum {
+            config.partition.validate_checksum = validate_checksum;
+        }
+        if let Some(enabled) = overrides.message_deduplication {
+            config.message_deduplication.enabled = enabled;
+        }
+        if let Some(max_entries) = overrides.message_deduplication_max_entries {
+            config.message_deduplication.max_entries = max_entries;
+        }
+        if let Some(expiry) = overrides.message_deduplication_expiry {
+            config.message_deduplication.expiry = expiry;
+        }
+        config
+    }
+
     pub fn get_system_path(&self) -> String {
         self.path.to_string()
     }
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UnixConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub permissions: u32,
+    pub peer_users: String,
+}
+
+impl UnixConfig {
+    /// Returns the file mode of the socket, as the permissions are written in the octal digits like for `chmod`
+    /// (e.g. 660 is `0o660`), or `None` if they're invalid.
+    pub fn get_permissions(&self) -> Option<u32> {
+        u32::from_str_radix(&self.permissions.to_string(), 8)
+            .ok()
+            .filter(|mode| *mode <= 0o777)
+    }
+
+    /// Returns the usernames of the Iggy users mapped by the peer UIDs, parsed from the comma-separated
+    /// `uid=username` pairs (e.g. "1000=producer,1001=consumer"), or `None` if they're invalid.
+    pub fn get_peer_users(&self) -> Option<HashMap<u32, String>> {
+        let mut peer_users = HashMap::new();
+        for pair in self
+            .peer_users
+            .split(',')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+        {
+            let (uid, username) = pair.split_once('=')?;
+            let username = username.trim();
+            if username.is_empty() {
+                return None;
+            }
+            peer_users.insert(uid.trim().parse().ok()?, username.to_string());
+        }
+        Some(peer_users)
+    }
+}
@@ -1,12 +1,17 @@
 extern crate sysinfo;
 
-use super::server::{MessageCleanerConfig, MessageSaverConfig};
+use super::server::{MessageCleanerConfig, MessageSaverConfig, MessageSchedulerConfig};
 use super::system::CompressionConfig;
+use crate::configs::kafka::KafkaConfig;
 use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, RetentionPolicyConfig, SegmentConfig};
+use crate::configs::system::{
+    CacheConfig, LoggingConfig, PartitionConfig, RetentionPolicyConfig, SegmentConfig,
+};
+use crate::configs::unix::UnixConfig;
 use crate::server_error::ServerError;
 use crate::streaming::segments::segment;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::validatable::Validatable;
 use sysinfo::System;
@@ -14,11 +19,16 @@ use tracing::{error, info, warn};
 
 impl Validatable<ServerError> for ServerConfig {
     fn validate(&self) -> Result<(), ServerError> {
+        self.system.logging.validate()?;
+        self.system.partition.validate()?;
         self.system.segment.validate()?;
         self.system.cache.validate()?;
         self.system.retention_policy.validate()?;
         self.system.compression.validate()?;
         self.personal_access_token.validate()?;
+        self.message_scheduler.validate()?;
+        self.kafka.validate()?;
+        self.unix.validate()?;
 
         Ok(())
     }
@@ -101,6 +111,34 @@ impl Validatable<ServerError> for SegmentConfig {
     }
 }
 
+impl Validatable<ServerError> for LoggingConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        let max_size = self.max_size.as_bytes_u64();
+        let max_total_size = self.max_total_size.as_bytes_u64();
+        if max_size > 0 && max_total_size > 0 && max_total_size < max_size {
+            error!(
+                "Logging configuration -> max total size: {} cannot be lower than max size: {}.",
+                self.max_total_size, self.max_size
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for PartitionConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        // Any topic can opt into the group commit durability, so the window is required regardless of the default one.
+        if self.group_commit_window.get_duration().is_zero() {
+            error!("Partition configuration -> group commit window cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for MessageSaverConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.enabled && self.interval.is_zero() {
@@ -123,6 +161,17 @@ impl Validatable<ServerError> for MessageCleanerConfig {
     }
 }
 
+impl Validatable<ServerError> for MessageSchedulerConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.interval.is_zero() {
+            error!("Message scheduler interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for PersonalAccessTokenConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.max_tokens_per_user == 0 {
@@ -140,3 +189,50 @@ impl Validatable<ServerError> for PersonalAccessTokenConfig {
         Ok(())
     }
 }
+
+impl Validatable<ServerError> for KafkaConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        // Otherwise, any client reaching the listener would act as the root user without providing any credentials.
+        if self.username == DEFAULT_ROOT_USERNAME && self.password == DEFAULT_ROOT_PASSWORD {
+            error!("Kafka listener cannot use the default root credentials, configure the dedicated user or leave them empty to require the SASL authentication.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for UnixConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.path.is_empty() {
+            error!("Unix domain socket path cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.get_permissions().is_none() {
+            error!(
+                "Unix domain socket permissions: {} are invalid, they must be the file mode in the octal digits (e.g. 660).",
+                self.permissions
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.get_peer_users().is_none() {
+            error!(
+                "Unix domain socket peer users: {} are invalid, they must be the comma-separated uid=username pairs.",
+                self.peer_users
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
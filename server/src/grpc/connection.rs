@@ -0,0 +1,88 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::TcpStream;
+use tonic::transport::server::Connected;
+use tracing::info;
+
+/// The TCP connection of the gRPC client, registered as the client of the system for its whole lifetime,
+/// so that all the requests sent over the same HTTP/2 connection share the same session.
+pub struct GrpcConnection {
+    stream: TcpStream,
+    session: Arc<Session>,
+    system: SharedSystem,
+}
+
+/// The connection info attached to each request, which provides the session of the client.
+#[derive(Debug, Clone)]
+pub struct GrpcConnectInfo {
+    pub session: Arc<Session>,
+}
+
+impl GrpcConnection {
+    pub fn new(stream: TcpStream, session: Session, system: SharedSystem) -> Self {
+        Self {
+            stream,
+            session: Arc::new(session),
+            system,
+        }
+    }
+
+    fn address(&self) -> SocketAddr {
+        self.session.ip_address
+    }
+}
+
+impl Connected for GrpcConnection {
+    type ConnectInfo = GrpcConnectInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        GrpcConnectInfo {
+            session: self.session.clone(),
+        }
+    }
+}
+
+impl AsyncRead for GrpcConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for GrpcConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.stream).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_shutdown(cx)
+    }
+}
+
+impl Drop for GrpcConnection {
+    fn drop(&mut self) {
+        let address = self.address();
+        let system = self.system.clone();
+        info!("gRPC connection has been closed: {address}");
+        tokio::spawn(async move {
+            system.read().delete_client(&address).await;
+        });
+    }
+}
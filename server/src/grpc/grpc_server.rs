@@ -0,0 +1,76 @@
+use crate::configs::grpc::{GrpcConfig, GrpcTlsConfig};
+use crate::grpc::connection::GrpcConnection;
+use crate::grpc::service::IggyService;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use async_stream::stream;
+use iggy::grpc::proto::iggy_server::IggyServer;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tonic::transport::{Certificate, Identity, Server, ServerTlsConfig};
+use tracing::{error, info};
+
+/// Starts the gRPC server.
+/// Returns the address the server is listening on.
+pub async fn start(config: GrpcConfig, system: SharedSystem) -> SocketAddr {
+    let server_name = if config.tls.enabled {
+        "Iggy gRPC TLS"
+    } else {
+        "Iggy gRPC"
+    };
+    info!("Initializing {server_name} server...");
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .expect("Unable to start gRPC server.");
+    let address = listener
+        .local_addr()
+        .expect("Failed to get local address for gRPC listener");
+
+    let mut server = Server::builder();
+    if config.tls.enabled {
+        server = server
+            .tls_config(load_tls_config(&config.tls))
+            .expect("Invalid gRPC TLS configuration.");
+    }
+
+    let service = IggyServer::new(IggyService::new(system.clone()));
+    let connections = stream! {
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    info!("Accepted new gRPC connection: {address}");
+                    let client_id = system.read().add_client(&address, Transport::Grpc).await;
+                    let session = Session::from_client_id(client_id, address);
+                    yield Ok::<_, std::io::Error>(GrpcConnection::new(stream, session, system.clone()));
+                }
+                Err(error) => error!("Unable to accept gRPC connection, error: {error}"),
+            }
+        }
+    };
+
+    tokio::spawn(async move {
+        if let Err(error) = server
+            .add_service(service)
+            .serve_with_incoming(connections)
+            .await
+        {
+            error!("gRPC server has failed, error: {error}");
+        }
+    });
+    info!("{server_name} server has started on: {:?}", address);
+    address
+}
+
+fn load_tls_config(config: &GrpcTlsConfig) -> ServerTlsConfig {
+    let certificate = std::fs::read(&config.cert_file).expect("Failed to read gRPC certificate.");
+    let key = std::fs::read(&config.key_file).expect("Failed to read gRPC key.");
+    let tls_config = ServerTlsConfig::new().identity(Identity::from_pem(certificate, key));
+    if config.ca_file.is_empty() {
+        return tls_config;
+    }
+
+    let ca_certificate =
+        std::fs::read(&config.ca_file).expect("Failed to read gRPC CA certificate.");
+    tls_config.client_ca_root(Certificate::from_pem(ca_certificate))
+}
@@ -0,0 +1,3 @@
+mod connection;
+pub mod grpc_server;
+mod service;
@@ -0,0 +1,1219 @@
+use crate::grpc::connection::GrpcConnectInfo;
+use crate::http::mapper;
+use crate::log::telemetry;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::diagnostics::metrics::StreamingMetrics;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use async_stream::stream;
+use futures::{Future, Stream};
+use iggy::command::*;
+use iggy::consumer::ConsumerKind;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::error::IggyError;
+use iggy::grpc::proto;
+use iggy::grpc::proto::iggy_server::Iggy;
+use iggy::grpc::ERROR_CODE_METADATA;
+use iggy::locking::IggySharedMutFn;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::SendMessages;
+use iggy::models::identity_info::IdentityInfo;
+use iggy::partitions::create_partitions::CreatePartitions;
+use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use iggy::partitions::truncate_partition::TruncatePartition;
+use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::streams::purge_stream::PurgeStream;
+use iggy::streams::update_stream::UpdateStream;
+use iggy::system::get_client::GetClient;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::topics::get_topics::GetTopics;
+use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::update_topic::UpdateTopic;
+use iggy::users::change_password::ChangePassword;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use iggy::users::login_user::LoginUser;
+use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_user::UpdateUser;
+use iggy::validatable::Validatable;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tonic::metadata::MetadataValue;
+use tonic::transport::server::TlsConnectInfo;
+use tonic::{Code, Request, Response, Status};
+use tracing::{debug, error, info_span, Instrument, Span};
+
+const MIN_POLL_INTERVAL_MS: u64 = 10;
+
+pub type PollMessagesStream =
+    Pin<Box<dyn Stream<Item = Result<proto::PolledMessages, Status>> + Send>>;
+
+/// Handles the typed RPCs by calling the system the same way as the TCP and QUIC command handlers,
+/// and mapping the results to the messages defined in `proto/iggy.proto`.
+pub struct IggyService {
+    system: SharedSystem,
+}
+
+impl IggyService {
+    pub fn new(system: SharedSystem) -> Self {
+        Self { system }
+    }
+}
+
+#[tonic::async_trait]
+impl Iggy for IggyService {
+    async fn ping(&self, request: Request<proto::Empty>) -> Result<Response<proto::Empty>, Status> {
+        let (session, _) = into_parts(request)?;
+        handle(PING_CODE, &session, async { Ok(proto::Empty {}) }).await
+    }
+
+    async fn get_stats(
+        &self,
+        request: Request<proto::Empty>,
+    ) -> Result<Response<proto::Stats>, Status> {
+        let (session, _) = into_parts(request)?;
+        handle(GET_STATS_CODE, &session, async {
+            let system = self.system.read();
+            let stats = system.get_stats(&session).await?;
+            Ok(stats.into())
+        })
+        .await
+    }
+
+    async fn get_me(
+        &self,
+        request: Request<proto::Empty>,
+    ) -> Result<Response<proto::ClientInfoDetails>, Status> {
+        let (session, _) = into_parts(request)?;
+        handle(GET_ME_CODE, &session, async {
+            let system = self.system.read();
+            let client = system.get_client(&session, session.client_id).await?;
+            let client = client.read().await;
+            Ok(mapper::map_client(&client).await.into())
+        })
+        .await
+    }
+
+    async fn get_client(
+        &self,
+        request: Request<proto::GetClientRequest>,
+    ) -> Result<Response<proto::ClientInfoDetails>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_CLIENT_CODE, &session, async {
+            let command = GetClient::from(request);
+            command.validate()?;
+            let system = self.system.read();
+            let client = system.get_client(&session, command.client_id).await?;
+            let client = client.read().await;
+            Ok(mapper::map_client(&client).await.into())
+        })
+        .await
+    }
+
+    async fn get_clients(
+        &self,
+        request: Request<proto::Empty>,
+    ) -> Result<Response<proto::GetClientsResponse>, Status> {
+        let (session, _) = into_parts(request)?;
+        handle(GET_CLIENTS_CODE, &session, async {
+            let system = self.system.read();
+            let clients = system.get_clients(&session).await?;
+            let clients = mapper::map_clients(&clients).await;
+            Ok(proto::GetClientsResponse {
+                clients: clients.into_iter().map(Into::into).collect(),
+            })
+        })
+        .await
+    }
+
+    async fn get_user(
+        &self,
+        request: Request<proto::GetUserRequest>,
+    ) -> Result<Response<proto::UserInfoDetails>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_USER_CODE, &session, async {
+            let command = GetUser::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let user = system.find_user(&session, &command.user_id).await?;
+            Ok(mapper::map_user(&user).into())
+        })
+        .await
+    }
+
+    async fn get_users(
+        &self,
+        request: Request<proto::Empty>,
+    ) -> Result<Response<proto::GetUsersResponse>, Status> {
+        let (session, _) = into_parts(request)?;
+        handle(GET_USERS_CODE, &session, async {
+            let system = self.system.read();
+            let users = system.get_users(&session).await?;
+            Ok(proto::GetUsersResponse {
+                users: mapper::map_users(&users)
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            })
+        })
+        .await
+    }
+
+    async fn create_user(
+        &self,
+        request: Request<proto::CreateUserRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(CREATE_USER_CODE, &session, async {
+            let command = CreateUser::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .create_user(
+                    &session,
+                    &command.username,
+                    &command.password,
+                    command.status,
+                    command.permissions,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn delete_user(
+        &self,
+        request: Request<proto::DeleteUserRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(DELETE_USER_CODE, &session, async {
+            let command = DeleteUser::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system.delete_user(&session, &command.user_id).await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn update_user(
+        &self,
+        request: Request<proto::UpdateUserRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(UPDATE_USER_CODE, &session, async {
+            let command = UpdateUser::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            system
+                .update_user(&session, &command.user_id, command.username, command.status)
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn update_permissions(
+        &self,
+        request: Request<proto::UpdatePermissionsRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(UPDATE_PERMISSIONS_CODE, &session, async {
+            let command = UpdatePermissions::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .update_permissions(&session, &command.user_id, command.permissions)
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn change_password(
+        &self,
+        request: Request<proto::ChangePasswordRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(CHANGE_PASSWORD_CODE, &session, async {
+            let command = ChangePassword::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            system
+                .change_password(
+                    &session,
+                    &command.user_id,
+                    &command.current_password,
+                    &command.new_password,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn login_user(
+        &self,
+        request: Request<proto::LoginUserRequest>,
+    ) -> Result<Response<proto::IdentityInfo>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(LOGIN_USER_CODE, &session, async {
+            let command = LoginUser::from(request);
+            command.validate()?;
+            let system = self.system.read();
+            let user = system
+                .login_user(&command.username, &command.password, Some(&session))
+                .await?;
+            Ok(map_identity_info(user.id))
+        })
+        .await
+    }
+
+    async fn logout_user(
+        &self,
+        request: Request<proto::Empty>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, _) = into_parts(request)?;
+        handle(LOGOUT_USER_CODE, &session, async {
+            let system = self.system.read();
+            system.logout_user(&session).await?;
+            session.clear_user_id();
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn get_personal_access_tokens(
+        &self,
+        request: Request<proto::Empty>,
+    ) -> Result<Response<proto::GetPersonalAccessTokensResponse>, Status> {
+        let (session, _) = into_parts(request)?;
+        handle(GET_PERSONAL_ACCESS_TOKENS_CODE, &session, async {
+            let system = self.system.read();
+            let personal_access_tokens = system.get_personal_access_tokens(&session).await?;
+            Ok(proto::GetPersonalAccessTokensResponse {
+                personal_access_tokens: mapper::map_personal_access_tokens(&personal_access_tokens)
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            })
+        })
+        .await
+    }
+
+    async fn create_personal_access_token(
+        &self,
+        request: Request<proto::CreatePersonalAccessTokenRequest>,
+    ) -> Result<Response<proto::RawPersonalAccessToken>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(CREATE_PERSONAL_ACCESS_TOKEN_CODE, &session, async {
+            let command = CreatePersonalAccessToken::from(request);
+            command.validate()?;
+            let system = self.system.read();
+            let token = system
+                .create_personal_access_token(&session, &command.name, command.expiry)
+                .await?;
+            Ok(proto::RawPersonalAccessToken { token })
+        })
+        .await
+    }
+
+    async fn delete_personal_access_token(
+        &self,
+        request: Request<proto::DeletePersonalAccessTokenRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(DELETE_PERSONAL_ACCESS_TOKEN_CODE, &session, async {
+            let command = DeletePersonalAccessToken::from(request);
+            command.validate()?;
+            let system = self.system.read();
+            system
+                .delete_personal_access_token(&session, &command.name)
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn login_with_personal_access_token(
+        &self,
+        request: Request<proto::LoginWithPersonalAccessTokenRequest>,
+    ) -> Result<Response<proto::IdentityInfo>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE, &session, async {
+            let command = LoginWithPersonalAccessToken::from(request);
+            command.validate()?;
+            let system = self.system.read();
+            let user = system
+                .login_with_personal_access_token(&command.token, Some(&session))
+                .await?;
+            Ok(map_identity_info(user.id))
+        })
+        .await
+    }
+
+    async fn get_stream(
+        &self,
+        request: Request<proto::GetStreamRequest>,
+    ) -> Result<Response<proto::StreamDetails>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_STREAM_CODE, &session, async {
+            let command = GetStream::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let stream = system.find_stream(&session, &command.stream_id)?;
+            Ok(mapper::map_stream(stream).await.into())
+        })
+        .await
+    }
+
+    async fn get_streams(
+        &self,
+        request: Request<proto::Empty>,
+    ) -> Result<Response<proto::GetStreamsResponse>, Status> {
+        let (session, _) = into_parts(request)?;
+        handle(GET_STREAMS_CODE, &session, async {
+            let system = self.system.read();
+            let streams = system.find_streams(&session)?;
+            let streams = mapper::map_streams(&streams).await;
+            Ok(proto::GetStreamsResponse {
+                streams: streams.into_iter().map(Into::into).collect(),
+            })
+        })
+        .await
+    }
+
+    async fn create_stream(
+        &self,
+        request: Request<proto::CreateStreamRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(CREATE_STREAM_CODE, &session, async {
+            let command = CreateStream::from(request);
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .create_stream(&session, command.stream_id, &command.name)
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn update_stream(
+        &self,
+        request: Request<proto::UpdateStreamRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(UPDATE_STREAM_CODE, &session, async {
+            let command = UpdateStream::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .update_stream(
+                    &session,
+                    &command.stream_id,
+                    &command.name,
+                    command.topic_config_overrides,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn delete_stream(
+        &self,
+        request: Request<proto::DeleteStreamRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(DELETE_STREAM_CODE, &session, async {
+            let command = DeleteStream::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system.delete_stream(&session, &command.stream_id).await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn purge_stream(
+        &self,
+        request: Request<proto::PurgeStreamRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(PURGE_STREAM_CODE, &session, async {
+            let command = PurgeStream::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            system.purge_stream(&session, &command.stream_id).await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn get_topic(
+        &self,
+        request: Request<proto::GetTopicRequest>,
+    ) -> Result<Response<proto::TopicDetails>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_TOPIC_CODE, &session, async {
+            let command = GetTopic::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let topic = system.find_topic(&session, &command.stream_id, &command.topic_id)?;
+            Ok(mapper::map_topic(topic).await.into())
+        })
+        .await
+    }
+
+    async fn get_topics(
+        &self,
+        request: Request<proto::GetTopicsRequest>,
+    ) -> Result<Response<proto::GetTopicsResponse>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_TOPICS_CODE, &session, async {
+            let command = GetTopics::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let topics = system.find_topics(&session, &command.stream_id)?;
+            let topics = mapper::map_topics(&topics).await;
+            Ok(proto::GetTopicsResponse {
+                topics: topics.into_iter().map(Into::into).collect(),
+            })
+        })
+        .await
+    }
+
+    async fn create_topic(
+        &self,
+        request: Request<proto::CreateTopicRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(CREATE_TOPIC_CODE, &session, async {
+            let command = CreateTopic::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .create_topic(
+                    &session,
+                    &command.stream_id,
+                    command.topic_id,
+                    &command.name,
+                    command.partitions_count,
+                    command.message_expiry,
+                    command.compression_algorithm,
+                    command.max_topic_size,
+                    command.replication_factor,
+                    command.durability,
+                    command.config_overrides,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn update_topic(
+        &self,
+        request: Request<proto::UpdateTopicRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(UPDATE_TOPIC_CODE, &session, async {
+            let command = UpdateTopic::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .update_topic(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.name,
+                    command.message_expiry,
+                    command.compression_algorithm,
+                    command.max_topic_size,
+                    command.replication_factor,
+                    command.durability,
+                    command.config_overrides,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn delete_topic(
+        &self,
+        request: Request<proto::DeleteTopicRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(DELETE_TOPIC_CODE, &session, async {
+            let command = DeleteTopic::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .delete_topic(&session, &command.stream_id, &command.topic_id)
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn purge_topic(
+        &self,
+        request: Request<proto::PurgeTopicRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(PURGE_TOPIC_CODE, &session, async {
+            let command = PurgeTopic::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            system
+                .purge_topic(&session, &command.stream_id, &command.topic_id)
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn create_partitions(
+        &self,
+        request: Request<proto::CreatePartitionsRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(CREATE_PARTITIONS_CODE, &session, async {
+            let command = CreatePartitions::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .create_partitions(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partitions_count,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn delete_partitions(
+        &self,
+        request: Request<proto::DeletePartitionsRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(DELETE_PARTITIONS_CODE, &session, async {
+            let command = DeletePartitions::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .delete_partitions(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partitions_count,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn get_offsets_for_timestamp(
+        &self,
+        request: Request<proto::GetOffsetsForTimestampRequest>,
+    ) -> Result<Response<proto::GetOffsetsForTimestampResponse>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_OFFSETS_FOR_TIMESTAMP_CODE, &session, async {
+            let command = GetOffsetsForTimestamp::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let offsets = system
+                .get_offsets_for_timestamp(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.partition_ids,
+                    command.timestamp,
+                )
+                .await?;
+            Ok(proto::GetOffsetsForTimestampResponse {
+                partitions: offsets.into_iter().map(Into::into).collect(),
+            })
+        })
+        .await
+    }
+
+    async fn truncate_partition(
+        &self,
+        request: Request<proto::TruncatePartitionRequest>,
+    ) -> Result<Response<proto::PartitionTruncation>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(TRUNCATE_PARTITION_CODE, &session, async {
+            let command = TruncatePartition::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let truncation = system
+                .truncate_partition(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.partition_id,
+                    command.offset,
+                )
+                .await?;
+            Ok(truncation.into())
+        })
+        .await
+    }
+
+    async fn poll_messages(
+        &self,
+        request: Request<proto::PollMessagesRequest>,
+    ) -> Result<Response<proto::PolledMessages>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(POLL_MESSAGES_V2_CODE, &session, async {
+            let command = PollMessages::try_from(request)?;
+            command.validate()?;
+            let consumer = PollingConsumer::from_poll_messages(&command, session.client_id);
+            let system = self.system.read();
+            let messages = system
+                .poll_messages(
+                    &session,
+                    consumer,
+                    &command.stream_id,
+                    &command.topic_id,
+                    PollingArgs::new(command.strategy, command.count, command.auto_commit),
+                )
+                .await?;
+            system
+                .record_polled_messages(&command.stream_id, &command.topic_id, &messages)
+                .await;
+            Ok(messages.into())
+        })
+        .await
+    }
+
+    async fn send_messages(
+        &self,
+        request: Request<proto::SendMessagesRequest>,
+    ) -> Result<Response<proto::AppendedMessages>, Status> {
+        let (session, request) = into_parts(request)?;
+        let command = SendMessages::try_from(request).map_err(map_error)?;
+        let code = if command.wait_for_durability {
+            SEND_DURABLE_MESSAGES_V2_CODE
+        } else {
+            SEND_MESSAGES_V2_CODE
+        };
+        let span = create_span(code, &session);
+        if let Some(trace_context) = command
+            .messages
+            .first()
+            .and_then(|message| message.get_trace_context())
+        {
+            telemetry::set_parent(&span, &trace_context);
+        }
+
+        handle_in_span(code, &session, span, async {
+            command.validate()?;
+            let (appended_messages, durability_waiter) = {
+                let system = self.system.read();
+                system
+                    .append_messages(
+                        &session,
+                        command.stream_id,
+                        command.topic_id,
+                        command.partitioning,
+                        command.messages,
+                        command.wait_for_durability,
+                    )
+                    .await?
+            };
+            // Awaiting the durability without holding the system lock, so the group commit can proceed.
+            if let Some(durability_waiter) = durability_waiter {
+                durability_waiter.wait().await?;
+            }
+            Ok(appended_messages.into())
+        })
+        .await
+    }
+
+    type PollMessagesStreamStream = PollMessagesStream;
+
+    async fn poll_messages_stream(
+        &self,
+        request: Request<proto::PollMessagesStreamRequest>,
+    ) -> Result<Response<Self::PollMessagesStreamStream>, Status> {
+        let (session, request) = into_parts(request)?;
+        let poll = request
+            .poll
+            .ok_or_else(|| map_error(IggyError::InvalidFormat))?;
+        let command = PollMessages::try_from(poll).map_err(map_error)?;
+        command.validate().map_err(map_error)?;
+        if command.count == 0 {
+            return Err(map_error(IggyError::InvalidMessagesCount));
+        }
+
+        let is_consumer_group = command.consumer.kind == ConsumerKind::ConsumerGroup;
+        let consumer = PollingConsumer::from_poll_messages(&command, session.client_id);
+        // The consumer group members always poll the next messages, which must be committed to move forward.
+        let (mut strategy, auto_commit) = if is_consumer_group {
+            (PollingStrategy::next(), true)
+        } else {
+            (command.strategy, command.auto_commit)
+        };
+        let interval = Duration::from_millis(request.interval.max(MIN_POLL_INTERVAL_MS));
+        let system = self.system.clone();
+        let messages = stream! {
+            loop {
+                let polled_messages = system
+                    .read()
+                    .poll_messages(
+                        &session,
+                        consumer,
+                        &command.stream_id,
+                        &command.topic_id,
+                        PollingArgs::new(strategy, command.count, auto_commit),
+                    )
+                    .await;
+                let polled_messages = match polled_messages {
+                    Ok(polled_messages) => polled_messages,
+                    Err(error) => {
+                        yield Err(map_error(error));
+                        break;
+                    }
+                };
+
+                let Some(last_message) = polled_messages.messages.last() else {
+                    tokio::time::sleep(interval).await;
+                    continue;
+                };
+
+                if !is_consumer_group {
+                    strategy = PollingStrategy::offset(last_message.offset + 1);
+                }
+                system
+                    .read()
+                    .record_polled_messages(&command.stream_id, &command.topic_id, &polled_messages)
+                    .await;
+                yield Ok(polled_messages.into());
+            }
+        };
+        Ok(Response::new(Box::pin(messages)))
+    }
+
+    async fn store_consumer_offset(
+        &self,
+        request: Request<proto::StoreConsumerOffsetRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(STORE_CONSUMER_OFFSET_CODE, &session, async {
+            let command = StoreConsumerOffset::try_from(request)?;
+            command.validate()?;
+            let consumer = PollingConsumer::from_consumer(
+                &command.consumer,
+                session.client_id,
+                command.partition_id,
+            );
+            let system = self.system.read();
+            system
+                .store_consumer_offset(
+                    &session,
+                    consumer,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.offset,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn get_consumer_offset(
+        &self,
+        request: Request<proto::GetConsumerOffsetRequest>,
+    ) -> Result<Response<proto::ConsumerOffsetInfo>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_CONSUMER_OFFSET_CODE, &session, async {
+            let command = GetConsumerOffset::try_from(request)?;
+            command.validate()?;
+            let consumer = PollingConsumer::from_consumer(
+                &command.consumer,
+                session.client_id,
+                command.partition_id,
+            );
+            let system = self.system.read();
+            let offset = system
+                .get_consumer_offset(&session, consumer, &command.stream_id, &command.topic_id)
+                .await?;
+            Ok(offset.into())
+        })
+        .await
+    }
+
+    async fn get_consumer_lag(
+        &self,
+        request: Request<proto::GetConsumerLagRequest>,
+    ) -> Result<Response<proto::ConsumerLag>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_CONSUMER_LAG_CODE, &session, async {
+            let command = GetConsumerLag::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let consumer_lag = system
+                .get_consumer_lag(
+                    &session,
+                    &command.consumer,
+                    &command.stream_id,
+                    &command.topic_id,
+                )
+                .await?;
+            Ok(consumer_lag.into())
+        })
+        .await
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        request: Request<proto::ResetConsumerOffsetsRequest>,
+    ) -> Result<Response<proto::ConsumerOffsetsReset>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(RESET_CONSUMER_OFFSETS_CODE, &session, async {
+            let command = ResetConsumerOffsets::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let consumer_offsets_reset = system
+                .reset_consumer_offsets(
+                    &session,
+                    &command.consumer,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.strategy,
+                    command.dry_run,
+                )
+                .await?;
+            Ok(consumer_offsets_reset.into())
+        })
+        .await
+    }
+
+    async fn get_consumer_group(
+        &self,
+        request: Request<proto::GetConsumerGroupRequest>,
+    ) -> Result<Response<proto::ConsumerGroupDetails>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_CONSUMER_GROUP_CODE, &session, async {
+            let command = GetConsumerGroup::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let consumer_group = system.get_consumer_group(
+                &session,
+                &command.stream_id,
+                &command.topic_id,
+                &command.group_id,
+            )?;
+            let consumer_group = consumer_group.read().await;
+            Ok(mapper::map_consumer_group(&consumer_group).await.into())
+        })
+        .await
+    }
+
+    async fn get_consumer_groups(
+        &self,
+        request: Request<proto::GetConsumerGroupsRequest>,
+    ) -> Result<Response<proto::GetConsumerGroupsResponse>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_CONSUMER_GROUPS_CODE, &session, async {
+            let command = GetConsumerGroups::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let consumer_groups =
+                system.get_consumer_groups(&session, &command.stream_id, &command.topic_id)?;
+            let consumer_groups = mapper::map_consumer_groups(&consumer_groups).await;
+            Ok(proto::GetConsumerGroupsResponse {
+                consumer_groups: consumer_groups.into_iter().map(Into::into).collect(),
+            })
+        })
+        .await
+    }
+
+    async fn create_consumer_group(
+        &self,
+        request: Request<proto::CreateConsumerGroupRequest>,
+    ) -> Result<Response<proto::ConsumerGroupDetails>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(CREATE_CONSUMER_GROUP_CODE, &session, async {
+            let command = CreateConsumerGroup::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            let consumer_group = system
+                .create_consumer_group(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.group_id,
+                    &command.name,
+                )
+                .await?;
+            let consumer_group = consumer_group.read().await;
+            Ok(mapper::map_consumer_group(&consumer_group).await.into())
+        })
+        .await
+    }
+
+    async fn delete_consumer_group(
+        &self,
+        request: Request<proto::DeleteConsumerGroupRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(DELETE_CONSUMER_GROUP_CODE, &session, async {
+            let command = DeleteConsumerGroup::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            system
+                .delete_consumer_group(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.group_id,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn join_consumer_group(
+        &self,
+        request: Request<proto::JoinConsumerGroupRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(JOIN_CONSUMER_GROUP_CODE, &session, async {
+            let command = JoinConsumerGroup::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            system
+                .join_consumer_group(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.group_id,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn leave_consumer_group(
+        &self,
+        request: Request<proto::LeaveConsumerGroupRequest>,
+    ) -> Result<Response<proto::Empty>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(LEAVE_CONSUMER_GROUP_CODE, &session, async {
+            let command = LeaveConsumerGroup::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            system
+                .leave_consumer_group(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    &command.group_id,
+                )
+                .await?;
+            Ok(proto::Empty {})
+        })
+        .await
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<proto::GetSchemaRequest>,
+    ) -> Result<Response<proto::Schema>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_SCHEMA_CODE, &session, async {
+            let command = GetSchema::from(request);
+            command.validate()?;
+            let system = self.system.read();
+            let schema = system.get_schema(&session, command.schema_id)?;
+            Ok(schema.clone().into())
+        })
+        .await
+    }
+
+    async fn get_schemas(
+        &self,
+        request: Request<proto::GetSchemasRequest>,
+    ) -> Result<Response<proto::GetSchemasResponse>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(GET_SCHEMAS_CODE, &session, async {
+            let command = GetSchemas::try_from(request)?;
+            command.validate()?;
+            let system = self.system.read();
+            let schemas = system.get_schemas(&session, &command.stream_id, &command.topic_id)?;
+            Ok(proto::GetSchemasResponse {
+                schemas: schemas
+                    .into_iter()
+                    .map(|schema| schema.clone().into())
+                    .collect(),
+            })
+        })
+        .await
+    }
+
+    async fn register_schema(
+        &self,
+        request: Request<proto::RegisterSchemaRequest>,
+    ) -> Result<Response<proto::Schema>, Status> {
+        let (session, request) = into_parts(request)?;
+        handle(REGISTER_SCHEMA_CODE, &session, async {
+            let command = RegisterSchema::try_from(request)?;
+            command.validate()?;
+            let mut system = self.system.write();
+            let schema = system
+                .register_schema(
+                    &session,
+                    &command.stream_id,
+                    &command.topic_id,
+                    command.schema_type,
+                    command.compatibility,
+                    command.validation_enabled,
+                    command.definition,
+                )
+                .await?;
+            Ok(schema.into())
+        })
+        .await
+    }
+}
+
+/// Handles the request within the span of the command, recording its duration the same way as the binary command handlers.
+async fn handle<T>(
+    code: u32,
+    session: &Session,
+    handler: impl Future<Output = Result<T, IggyError>>,
+) -> Result<Response<T>, Status> {
+    handle_in_span(code, session, create_span(code, session), handler).await
+}
+
+async fn handle_in_span<T>(
+    code: u32,
+    session: &Session,
+    span: Span,
+    handler: impl Future<Output = Result<T, IggyError>>,
+) -> Result<Response<T>, Status> {
+    let started_at = Instant::now();
+    let result = handler.instrument(span).await;
+    StreamingMetrics::get().record_request_duration(
+        &Transport::Grpc.to_string(),
+        &code.to_string(),
+        started_at.elapsed(),
+    );
+    match result {
+        Ok(response) => {
+            debug!("Command was handled successfully, session: {session}.");
+            Ok(Response::new(response))
+        }
+        Err(error) => {
+            error!("Command was not handled successfully, session: {session}, error: {error}");
+            Err(map_error(error))
+        }
+    }
+}
+
+fn create_span(code: u32, session: &Session) -> Span {
+    info_span!(
+        "command",
+        code = code,
+        transport = %Transport::Grpc,
+        client_id = session.client_id
+    )
+}
+
+#[allow(clippy::result_large_err)]
+fn into_parts<T>(request: Request<T>) -> Result<(Arc<Session>, T), Status> {
+    let session = get_session(&request)
+        .ok_or_else(|| Status::internal("Missing the gRPC connection info."))?;
+    Ok((session, request.into_inner()))
+}
+
+fn get_session<T>(request: &Request<T>) -> Option<Arc<Session>> {
+    let extensions = request.extensions();
+    extensions
+        .get::<GrpcConnectInfo>()
+        .or_else(|| {
+            extensions
+                .get::<TlsConnectInfo<GrpcConnectInfo>>()
+                .map(|info| info.get_ref())
+        })
+        .map(|info| info.session.clone())
+}
+
+fn map_identity_info(user_id: u32) -> proto::IdentityInfo {
+    IdentityInfo {
+        user_id,
+        tokens: None,
+    }
+    .into()
+}
+
+/// Maps the error to the status, including the error code as the metadata, so that the client can restore the original error.
+fn map_error(error: IggyError) -> Status {
+    let code = match error {
+        IggyError::StreamIdNotFound(_)
+        | IggyError::TopicIdNotFound(_, _)
+        | IggyError::PartitionNotFound(_, _, _)
+        | IggyError::SegmentNotFound
+        | IggyError::ClientNotFound(_)
+        | IggyError::ConsumerGroupIdNotFound(_, _)
+        | IggyError::ConsumerGroupNameNotFound(_, _)
+        | IggyError::ConsumerGroupMemberNotFound(_, _, _)
+        | IggyError::ResourceNotFound(_) => Code::NotFound,
+        IggyError::Unauthenticated => Code::Unauthenticated,
+        IggyError::Unauthorized => Code::PermissionDenied,
+        IggyError::StreamIdAlreadyExists(_)
+        | IggyError::StreamNameAlreadyExists(_)
+        | IggyError::TopicIdAlreadyExists(_, _)
+        | IggyError::TopicNameAlreadyExists(_, _)
+        | IggyError::ConsumerGroupIdAlreadyExists(_, _)
+        | IggyError::ConsumerGroupNameAlreadyExists(_, _)
+        | IggyError::UserAlreadyExists => Code::AlreadyExists,
+        IggyError::IoError(_) | IggyError::WriteError(_) | IggyError::CannotLoadResource(_) => {
+            Code::Internal
+        }
+        _ => Code::InvalidArgument,
+    };
+    let mut status = Status::new(code, error.to_string());
+    status
+        .metadata_mut()
+        .insert(ERROR_CODE_METADATA, MetadataValue::from(error.as_code()));
+    status
+}
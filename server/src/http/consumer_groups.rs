@@ -5,11 +5,15 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use iggy::consumer::Consumer;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use iggy::identifier::Identifier;
 use iggy::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use iggy::models::consumer_lag::ConsumerLag;
+use iggy::models::consumer_offsets_reset::ConsumerOffsetsReset;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -23,6 +27,14 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/consumer-groups/:group_id",
             get(get_consumer_group).delete(delete_consumer_group),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/consumer-groups/:group_id/lag",
+            get(get_consumer_group_lag),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/consumer-groups/:group_id/reset-offsets",
+            post(reset_consumer_group_offsets),
+        )
         .with_state(state)
 }
 
@@ -106,3 +118,47 @@ async fn delete_consumer_group(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn get_consumer_group_lag(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, group_id)): Path<(String, String, String)>,
+) -> Result<Json<ConsumerLag>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let group_id = Identifier::from_str_value(&group_id)?;
+    let system = state.system.read();
+    let consumer_lag = system
+        .get_consumer_lag(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &Consumer::group(group_id),
+            &stream_id,
+            &topic_id,
+        )
+        .await?;
+    Ok(Json(consumer_lag))
+}
+
+async fn reset_consumer_group_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, group_id)): Path<(String, String, String)>,
+    Json(mut command): Json<ResetConsumerOffsets>,
+) -> Result<Json<ConsumerOffsetsReset>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.consumer = Consumer::group(Identifier::from_str_value(&group_id)?);
+    command.validate()?;
+    let system = state.system.read();
+    let consumer_offsets_reset = system
+        .reset_consumer_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.strategy,
+            command.dry_run,
+        )
+        .await?;
+    Ok(Json(consumer_offsets_reset))
+}
@@ -5,12 +5,17 @@ use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use iggy::consumer::ConsumerKind;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offsets_reset::ConsumerOffsetsReset;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -20,6 +25,14 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/consumer-offsets",
             get(get_consumer_offset).put(store_consumer_offset),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/consumer-offsets/lag",
+            get(get_consumer_lag),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/consumer-offsets/reset",
+            post(reset_consumer_offsets),
+        )
         .with_state(state)
 }
 
@@ -69,3 +82,49 @@ async fn store_consumer_offset(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn get_consumer_lag(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<GetConsumerLag>,
+) -> Result<Json<ConsumerLag>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.consumer.kind = ConsumerKind::Consumer;
+    query.validate()?;
+    let system = state.system.read();
+    let consumer_lag = system
+        .get_consumer_lag(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.consumer,
+            &query.stream_id,
+            &query.topic_id,
+        )
+        .await?;
+    Ok(Json(consumer_lag))
+}
+
+async fn reset_consumer_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<ResetConsumerOffsets>,
+) -> Result<Json<ConsumerOffsetsReset>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.consumer.kind = ConsumerKind::Consumer;
+    command.validate()?;
+    let system = state.system.read();
+    let consumer_offsets_reset = system
+        .reset_consumer_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.strategy,
+            command.dry_run,
+        )
+        .await?;
+    Ok(Json(consumer_offsets_reset))
+}
@@ -1,4 +1,5 @@
 use crate::http::shared::RequestDetails;
+use crate::log::telemetry;
 use crate::streaming::utils::random_id;
 use axum::body::Body;
 use axum::{
@@ -7,9 +8,11 @@ use axum::{
     middleware::Next,
     response::Response,
 };
+use iggy::messages::trace_context::{TraceContext, TRACEPARENT_HEADER};
 use std::net::SocketAddr;
+use std::str::FromStr;
 use tokio::time::Instant;
-use tracing::{debug, error};
+use tracing::{debug, error, info_span, Instrument};
 
 pub async fn request_diagnostics(
     ConnectInfo(ip_address): ConnectInfo<SocketAddr>,
@@ -31,8 +34,22 @@ pub async fn request_diagnostics(
         request_id,
         ip_address,
     });
+    let span = info_span!(
+        "http_request",
+        method = %request.method(),
+        path = request.uri().path(),
+        request_id = %request_id
+    );
+    if let Some(trace_context) = request
+        .headers()
+        .get(TRACEPARENT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| TraceContext::from_str(value).ok())
+    {
+        telemetry::set_parent(&span, &trace_context);
+    }
     let now = Instant::now();
-    let result = Ok(next.run(request).await);
+    let result = Ok(next.run(request).instrument(span).await);
     if let Ok(response) = &result {
         let status = response.status();
         if status >= StatusCode::BAD_REQUEST {
@@ -36,13 +36,16 @@ impl IntoResponse for CustomError {
                     IggyError::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::CannotLoadResource(_) => StatusCode::NOT_FOUND,
                     IggyError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::SchemaNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::WriteError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::CannotParseInt(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::CannotParseSlice(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::CannotParseUtf8(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                    IggyError::CannotSerializeMessages(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::Unauthenticated => StatusCode::UNAUTHORIZED,
                     IggyError::Unauthorized => StatusCode::FORBIDDEN,
+                    IggyError::UnsupportedContentType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
                     _ => StatusCode::BAD_REQUEST,
                 };
                 (status_code, Json(ErrorResponse::from_error(error)))
@@ -64,6 +67,7 @@ impl ErrorResponse {
                 IggyError::PartitionNotFound(_, _, _) => Some("partition_id".to_string()),
                 IggyError::SegmentNotFound => Some("segment_id".to_string()),
                 IggyError::ClientNotFound(_) => Some("client_id".to_string()),
+                IggyError::SchemaNotFound(_) => Some("schema_id".to_string()),
                 IggyError::InvalidStreamName => Some("name".to_string()),
                 IggyError::StreamNameAlreadyExists(_) => Some("name".to_string()),
                 IggyError::InvalidTopicName => Some("name".to_string()),
@@ -36,12 +36,17 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(consumer_groups::router(app_state.clone()))
         .merge(consumer_offsets::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
+        .merge(schemas::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
         .layer(DefaultBodyLimit::max(
             config.max_request_size.as_bytes_u64() as usize,
         ))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
+    if config.live.enabled {
+        app = app.merge(live::router(app_state.clone(), &config));
+    }
+
     if config.cors.enabled {
         app = app.layer(configure_cors(config.cors));
     }
@@ -7,10 +7,11 @@ use axum::{
     middleware::Next,
     response::Response,
 };
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 const AUTHORIZATION: &str = "authorization";
-const BEARER: &str = "Bearer ";
+pub(crate) const BEARER: &str = "Bearer ";
 const UNAUTHORIZED: StatusCode = StatusCode::UNAUTHORIZED;
 
 const UNAUTHORIZED_PATHS: &[&str] = &[
@@ -43,6 +44,18 @@ pub async fn jwt_auth(
     }
 
     let jwt_token = &bearer[BEARER.len()..];
+    let request_details = request.extensions().get::<RequestDetails>().unwrap();
+    let identity = authenticate(&state, jwt_token, request_details.ip_address).await?;
+    request.extensions_mut().insert(identity);
+    Ok(next.run(request).await)
+}
+
+/// Decodes the identity from the provided JWT, failing if the token is invalid or has been revoked.
+pub async fn authenticate(
+    state: &AppState,
+    jwt_token: &str,
+    ip_address: SocketAddr,
+) -> Result<Identity, StatusCode> {
     let token_header = jsonwebtoken::decode_header(jwt_token).map_err(|_| UNAUTHORIZED)?;
     let jwt_claims = state
         .jwt_manager
@@ -56,13 +69,10 @@ pub async fn jwt_auth(
         return Err(StatusCode::UNAUTHORIZED);
     }
 
-    let request_details = request.extensions().get::<RequestDetails>().unwrap();
-    let identity = Identity {
+    Ok(Identity {
         token_id: jwt_claims.claims.jti,
         token_expiry: jwt_claims.claims.exp,
         user_id: jwt_claims.claims.sub,
-        ip_address: request_details.ip_address,
-    };
-    request.extensions_mut().insert(identity);
-    Ok(next.run(request).await)
+        ip_address,
+    })
 }
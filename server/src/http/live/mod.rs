@@ -0,0 +1,70 @@
+mod sse;
+mod subscription;
+mod websocket;
+
+use crate::configs::http::HttpConfig;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::jwt::middleware::BEARER;
+use crate::http::shared::AppState;
+use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::get;
+use axum::Router;
+use iggy::utils::duration::IggyDuration;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+pub struct LiveState {
+    pub app: Arc<AppState>,
+    pub poll_interval: IggyDuration,
+    pub max_frame_size: u64,
+}
+
+/// The live messages endpoints aren't covered by the JWT middleware, as the WebSocket clients can also authenticate
+/// with the first message sent after the upgrade, so the router must be merged after the middleware layer.
+pub fn router(state: Arc<AppState>, config: &HttpConfig) -> Router {
+    let state = Arc::new(LiveState {
+        app: state,
+        poll_interval: config.live.poll_interval,
+        max_frame_size: config.max_request_size.as_bytes_u64(),
+    });
+    Router::new()
+        .route(
+            "/streams/:stream_id/topics/:topic_id/messages/sse",
+            get(sse::subscribe),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/messages/ws",
+            get(websocket::connect),
+        )
+        .with_state(state)
+}
+
+/// Returns the JWT passed in the `Authorization` header.
+/// The tokens aren't accepted as the query parameters, as they would end up in the access logs and the browser history.
+fn get_bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix(BEARER))
+}
+
+/// Authenticates the user with the personal access token, for the clients which can't obtain the JWT upfront.
+async fn authenticate_personal_access_token(
+    state: &AppState,
+    personal_access_token: &str,
+    ip_address: SocketAddr,
+) -> Result<Identity, StatusCode> {
+    let user = state
+        .system
+        .read()
+        .login_with_personal_access_token(personal_access_token, None)
+        .await
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+    Ok(Identity {
+        token_id: String::new(),
+        token_expiry: 0,
+        user_id: user.id,
+        ip_address,
+    })
+}
@@ -0,0 +1,85 @@
+use crate::http::error::{CustomError, ErrorResponse};
+use crate::http::jwt::middleware::authenticate;
+use crate::http::live::subscription::{MessagesSubscription, SubscriptionQuery};
+use crate::http::live::{get_bearer_token, LiveState};
+use crate::http::shared::RequestDetails;
+use async_stream::stream;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::Extension;
+use iggy::identifier::Identifier;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+const LAST_EVENT_ID: &str = "last-event-id";
+const MESSAGE_EVENT: &str = "message";
+const ERROR_EVENT: &str = "error";
+const SUBSCRIPTION_BUFFER: usize = 16;
+
+/// Streams the messages as the `message` events with the offset as their ID,
+/// so that the reconnecting clients resume from the message after the `Last-Event-ID` one.
+/// The subscription is closed once the client disconnects, as the events receiver is dropped along with the response.
+pub async fn subscribe(
+    State(state): State<Arc<LiveState>>,
+    Extension(request_details): Extension<RequestDetails>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    headers: HeaderMap,
+    Query(query): Query<SubscriptionQuery>,
+) -> Result<Response, CustomError> {
+    let Some(jwt_token) = get_bearer_token(&headers) else {
+        return Ok(StatusCode::UNAUTHORIZED.into_response());
+    };
+    let identity = match authenticate(&state.app, jwt_token, request_details.ip_address).await {
+        Ok(identity) => identity,
+        Err(status_code) => return Ok(status_code.into_response()),
+    };
+
+    let last_offset = headers
+        .get(LAST_EVENT_ID)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    let subscription = MessagesSubscription::create(
+        state.app.system.clone(),
+        &identity,
+        Identifier::from_str_value(&stream_id)?,
+        Identifier::from_str_value(&topic_id)?,
+        &query,
+        last_offset,
+    )
+    .await?;
+
+    let (sender, mut receiver) = mpsc::channel(SUBSCRIPTION_BUFFER);
+    tokio::spawn(subscription.run(sender, state.poll_interval));
+    let events = stream! {
+        while let Some(polled_messages) = receiver.recv().await {
+            match polled_messages {
+                Ok(polled_messages) => {
+                    for message in polled_messages.messages {
+                        let event = Event::default()
+                            .event(MESSAGE_EVENT)
+                            .id(message.offset.to_string())
+                            .json_data(&message);
+                        if let Ok(event) = event {
+                            yield Ok::<Event, Infallible>(event);
+                        }
+                    }
+                }
+                Err(error) => {
+                    if let Ok(event) = Event::default()
+                        .event(ERROR_EVENT)
+                        .json_data(ErrorResponse::from_error(error))
+                    {
+                        yield Ok(event);
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response())
+}
@@ -0,0 +1,190 @@
+use crate::http::jwt::json_web_token::Identity;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
+use iggy::models::messages::PolledMessages;
+use iggy::utils::duration::IggyDuration;
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+use tokio::sync::mpsc;
+
+const DEFAULT_COUNT: u32 = 100;
+
+/// The query parameters of the live messages endpoints.
+/// When `consumer_group_id` is provided, the subscription joins the consumer group as a separate member,
+/// otherwise the messages are consumed from the `partition_id` by the `consumer_id`.
+#[serde_as]
+#[derive(Debug, Default, Deserialize)]
+pub struct SubscriptionQuery {
+    pub consumer_id: Option<String>,
+    pub consumer_group_id: Option<String>,
+    pub partition_id: Option<u32>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub kind: Option<PollingKind>,
+    pub value: Option<u64>,
+    pub count: Option<u32>,
+    pub auto_commit: Option<bool>,
+    pub subscribe: Option<bool>,
+}
+
+/// Continuously polls the new messages for the live messages endpoints.
+/// The consumer group members are registered as the HTTP clients, which are removed once the subscription is closed.
+pub struct MessagesSubscription {
+    system: SharedSystem,
+    session: Session,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    consumer: PollingConsumer,
+    strategy: PollingStrategy,
+    count: u32,
+    auto_commit: bool,
+    is_consumer_group: bool,
+}
+
+impl MessagesSubscription {
+    pub async fn create(
+        system: SharedSystem,
+        identity: &Identity,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        query: &SubscriptionQuery,
+        last_offset: Option<u64>,
+    ) -> Result<Self, IggyError> {
+        let count = query.count.unwrap_or(DEFAULT_COUNT);
+        if count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        if let Some(consumer_group_id) = &query.consumer_group_id {
+            let consumer_group_id = Identifier::from_str_value(consumer_group_id)?;
+            let session;
+            {
+                let system = system.read();
+                let client_id = system
+                    .add_client(&identity.ip_address, Transport::Http)
+                    .await;
+                session = Session::new(client_id, identity.user_id, identity.ip_address);
+                let joined = system
+                    .join_consumer_group(&session, &stream_id, &topic_id, &consumer_group_id)
+                    .await;
+                if let Err(error) = joined {
+                    system.delete_client(&identity.ip_address).await;
+                    return Err(error);
+                }
+            }
+
+            let consumer = PollingConsumer::ConsumerGroup(
+                PollingConsumer::resolve_consumer_id(&consumer_group_id),
+                session.client_id,
+                None,
+            );
+            return Ok(Self {
+                system,
+                session,
+                stream_id,
+                topic_id,
+                consumer,
+                strategy: PollingStrategy::next(),
+                count,
+                auto_commit: true,
+                is_consumer_group: true,
+            });
+        }
+
+        let consumer_id = match &query.consumer_id {
+            Some(consumer_id) => Identifier::from_str_value(consumer_id)?,
+            None => Identifier::numeric(1)?,
+        };
+        let partition_id = query.partition_id.unwrap_or(1);
+        let consumer = PollingConsumer::Consumer(
+            PollingConsumer::resolve_consumer_id(&consumer_id),
+            partition_id,
+        );
+        let strategy = match (last_offset, query.kind) {
+            (Some(last_offset), _) => PollingStrategy::offset(last_offset + 1),
+            (None, Some(kind)) => PollingStrategy {
+                kind,
+                value: query.value.unwrap_or_default(),
+            },
+            (None, None) => PollingStrategy::next(),
+        };
+        Ok(Self {
+            system,
+            session: Session::stateless(identity.user_id, identity.ip_address),
+            stream_id,
+            topic_id,
+            consumer,
+            strategy,
+            count,
+            auto_commit: query.auto_commit.unwrap_or_default(),
+            is_consumer_group: false,
+        })
+    }
+
+    /// Pushes the new messages to the channel, checking for them every `interval`, until the channel is closed
+    /// or the polling fails (the error is pushed as the last item), and then closes the subscription.
+    /// The polling itself is never cancelled, so that the auto committed messages aren't lost in between.
+    pub async fn run(
+        mut self,
+        sender: mpsc::Sender<Result<PolledMessages, IggyError>>,
+        interval: IggyDuration,
+    ) {
+        loop {
+            match self.poll().await {
+                Ok(polled_messages) if polled_messages.messages.is_empty() => {
+                    tokio::select! {
+                        _ = tokio::time::sleep(interval.get_duration()) => {}
+                        _ = sender.closed() => break,
+                    }
+                }
+                Ok(polled_messages) => {
+                    if sender.send(Ok(polled_messages)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(error) => {
+                    let _ = sender.send(Err(error)).await;
+                    break;
+                }
+            }
+        }
+        self.close().await;
+    }
+
+    async fn poll(&mut self) -> Result<PolledMessages, IggyError> {
+        let polled_messages = self
+            .system
+            .read()
+            .poll_messages(
+                &self.session,
+                self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                PollingArgs::new(self.strategy, self.count, self.auto_commit),
+            )
+            .await?;
+        // The consumer group offsets are committed on the server, the partition one is tracked here.
+        if !self.is_consumer_group {
+            if let Some(message) = polled_messages.messages.last() {
+                self.strategy = PollingStrategy::offset(message.offset + 1);
+            }
+        }
+        Ok(polled_messages)
+    }
+
+    /// Leaves the consumer group by removing the client registered for the subscription.
+    pub async fn close(self) {
+        if self.is_consumer_group {
+            self.system
+                .read()
+                .delete_client(&self.session.ip_address)
+                .await;
+        }
+    }
+}
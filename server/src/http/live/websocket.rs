@@ -0,0 +1,252 @@
+use crate::http::error::{CustomError, ErrorResponse};
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::jwt::middleware::authenticate;
+use crate::http::live::subscription::{MessagesSubscription, SubscriptionQuery};
+use crate::http::live::{authenticate_personal_access_token, get_bearer_token, LiveState};
+use crate::http::messages::append_messages;
+use crate::http::shared::RequestDetails;
+use axum::body::Bytes;
+use axum::extract::ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::response::{IntoResponse, Response};
+use axum::Extension;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::SendMessages;
+use iggy::models::messages::PolledMessages;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+const AUTHENTICATION_TIMEOUT: Duration = Duration::from_secs(10);
+const SUBSCRIPTION_BUFFER: usize = 16;
+
+/// The messages sent by the server as the JSON text messages, tagged with the `type` field.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    Authenticated,
+    Messages(PolledMessages),
+    Sent { count: usize },
+    Error(ErrorResponse),
+}
+
+/// The first message expected from the clients which didn't pass the JWT in the `Authorization` header
+/// (e.g. the browsers, which can't set it for WebSockets), containing either the JWT or the personal access token.
+#[derive(Debug, Deserialize)]
+struct Credentials {
+    access_token: Option<String>,
+    personal_access_token: Option<String>,
+}
+
+/// Upgrades the connection to the WebSocket, which can be used to both send and subscribe to the messages.
+/// The messages are sent as the JSON text messages or the binary messages (the same format as used by the TCP transport),
+/// and the polled ones are pushed as the JSON text messages, unless the `subscribe` parameter is set to `false`.
+pub async fn connect(
+    State(state): State<Arc<LiveState>>,
+    Extension(request_details): Extension<RequestDetails>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Query(query): Query<SubscriptionQuery>,
+    headers: HeaderMap,
+    websocket: WebSocketUpgrade,
+) -> Result<Response, CustomError> {
+    let identity = match get_bearer_token(&headers) {
+        Some(jwt_token) => {
+            match authenticate(&state.app, jwt_token, request_details.ip_address).await {
+                Ok(identity) => Some(identity),
+                Err(status_code) => return Ok(status_code.into_response()),
+            }
+        }
+        None => None,
+    };
+
+    let max_message_size = state.max_frame_size as usize;
+    let connection = Connection {
+        state,
+        ip_address: request_details.ip_address,
+        stream_id: Identifier::from_str_value(&stream_id)?,
+        topic_id: Identifier::from_str_value(&topic_id)?,
+    };
+    Ok(websocket
+        .max_message_size(max_message_size)
+        .max_frame_size(max_message_size)
+        .on_upgrade(move |socket| connection.handle(socket, identity, query)))
+}
+
+struct Connection {
+    state: Arc<LiveState>,
+    ip_address: SocketAddr,
+    stream_id: Identifier,
+    topic_id: Identifier,
+}
+
+impl Connection {
+    async fn handle(
+        self,
+        mut socket: WebSocket,
+        identity: Option<Identity>,
+        query: SubscriptionQuery,
+    ) {
+        let identity = match identity {
+            Some(identity) => identity,
+            None => match self.authenticate(&mut socket).await {
+                Ok(identity) => {
+                    if send(&mut socket, &ServerMessage::Authenticated)
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                    identity
+                }
+                Err(error) => {
+                    close(socket, error).await;
+                    return;
+                }
+            },
+        };
+
+        let subscription = if query.subscribe.unwrap_or(true) {
+            let subscription = MessagesSubscription::create(
+                self.state.app.system.clone(),
+                &identity,
+                self.stream_id.clone(),
+                self.topic_id.clone(),
+                &query,
+                None,
+            )
+            .await;
+            match subscription {
+                Ok(subscription) => Some(subscription),
+                Err(error) => {
+                    close(socket, error).await;
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+
+        // The subscription is closed by its task once the receiver is dropped, when the connection ends.
+        let (sender, mut receiver) = mpsc::channel(SUBSCRIPTION_BUFFER);
+        let poll_interval = self.state.poll_interval;
+        let subscription_task =
+            subscription.map(|subscription| tokio::spawn(subscription.run(sender, poll_interval)));
+
+        loop {
+            let reply = tokio::select! {
+                message = socket.recv() => match message {
+                    Some(Ok(Message::Text(text))) => {
+                        let command = serde_json::from_str::<SendMessages>(&text)
+                            .map_err(|error| IggyError::CannotDeserializeMessages(error.to_string()));
+                        self.send_messages(&identity, command).await
+                    }
+                    Some(Ok(Message::Binary(bytes))) => {
+                        let command = SendMessages::from_bytes(Bytes::from(bytes));
+                        self.send_messages(&identity, command).await
+                    }
+                    // The pings are answered and the close handshake is completed by the WebSocket itself.
+                    Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Close(_))) => continue,
+                    Some(Err(error)) => {
+                        debug!("Failed to read WebSocket message, error: {error}");
+                        break;
+                    }
+                    None => break,
+                },
+                Some(polled_messages) = receiver.recv() => match polled_messages {
+                    Ok(polled_messages) => ServerMessage::Messages(polled_messages),
+                    Err(error) => ServerMessage::Error(ErrorResponse::from_error(error)),
+                },
+            };
+
+            if send(&mut socket, &reply).await.is_err() {
+                break;
+            }
+        }
+
+        drop(receiver);
+        if let Some(subscription_task) = subscription_task {
+            let _ = subscription_task.await;
+        }
+    }
+
+    async fn authenticate(&self, socket: &mut WebSocket) -> Result<Identity, IggyError> {
+        let message = tokio::time::timeout(AUTHENTICATION_TIMEOUT, socket.recv())
+            .await
+            .map_err(|_| IggyError::Unauthenticated)?;
+        let Some(Ok(Message::Text(text))) = message else {
+            return Err(IggyError::Unauthenticated);
+        };
+
+        let credentials =
+            serde_json::from_str::<Credentials>(&text).map_err(|_| IggyError::Unauthenticated)?;
+        let identity = match (credentials.access_token, credentials.personal_access_token) {
+            (Some(jwt_token), _) => {
+                authenticate(&self.state.app, &jwt_token, self.ip_address).await
+            }
+            (None, Some(personal_access_token)) => {
+                authenticate_personal_access_token(
+                    &self.state.app,
+                    &personal_access_token,
+                    self.ip_address,
+                )
+                .await
+            }
+            (None, None) => return Err(IggyError::Unauthenticated),
+        };
+        identity.map_err(|_| IggyError::Unauthenticated)
+    }
+
+    async fn send_messages(
+        &self,
+        identity: &Identity,
+        command: Result<SendMessages, IggyError>,
+    ) -> ServerMessage {
+        let result = match command {
+            Ok(mut command) => {
+                command.stream_id = self.stream_id.clone();
+                command.topic_id = self.topic_id.clone();
+                let count = command.messages.len();
+                let wait_for_durability = command.wait_for_durability;
+                append_messages(
+                    &self.state.app.system,
+                    identity,
+                    command,
+                    wait_for_durability,
+                )
+                .await
+                .map(|_| count)
+            }
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(count) => ServerMessage::Sent { count },
+            Err(error) => ServerMessage::Error(ErrorResponse::from_error(error)),
+        }
+    }
+}
+
+async fn send(socket: &mut WebSocket, message: &ServerMessage) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(message).unwrap_or_default();
+    socket.send(Message::Text(text)).await
+}
+
+/// Sends the error and closes the connection with the policy violation code.
+async fn close(mut socket: WebSocket, error: IggyError) {
+    let message = ServerMessage::Error(ErrorResponse::from_error(error));
+    if send(&mut socket, &message).await.is_err() {
+        return;
+    }
+
+    let close_frame = CloseFrame {
+        code: close_code::POLICY,
+        reason: "".into(),
+    };
+    let _ = socket.send(Message::Close(Some(close_frame))).await;
+}
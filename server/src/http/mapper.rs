@@ -25,6 +25,7 @@ pub async fn map_stream(stream: &Stream) -> StreamDetails {
         topics_count: topics.len() as u32,
         size: stream.get_size(),
         messages_count: stream.get_messages_count(),
+        topic_config_overrides: stream.topic_config_overrides,
         topics,
     };
     stream_details.topics.sort_by(|a, b| a.id.cmp(&b.id));
@@ -63,6 +64,7 @@ pub async fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
             compression_algorithm: topic.compression_algorithm,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
+            durability: topic.durability,
         };
         topics_data.push(topic);
     }
@@ -83,6 +85,9 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         compression_algorithm: topic.compression_algorithm,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        durability: topic.durability,
+        config_overrides: topic.config_overrides,
+        partitioning_scheme: topic.partitioning_scheme,
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
@@ -1,21 +1,40 @@
+use crate::binary::mapper;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::shared::AppState;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
 use crate::streaming::utils::random_id;
+use axum::body::{Body, Bytes};
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
+use axum::http::header::{ACCEPT, CONTENT_TYPE};
+use axum::http::{HeaderMap, HeaderName, StatusCode};
+use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use axum::{Extension, Json, Router};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::http::messages_format::{MessagesFormat, CURRENT_OFFSET_HEADER, PARTITION_ID_HEADER};
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollMessages;
+use iggy::locking::IggySharedMutFn;
+use iggy::messages::poll_messages::{
+    PollMessages, PollMessagesVersion, PollingKind, PollingStrategy,
+};
 use iggy::messages::send_messages::SendMessages;
-use iggy::models::messages::PolledMessages;
+use iggy::models::appended_messages::AppendedMessages;
+use iggy::models::messages::{PolledMessage, PolledMessages};
 use iggy::validatable::Validatable;
+use serde::Deserialize;
 use std::sync::Arc;
 
+#[derive(Debug, Default, Deserialize)]
+struct SendMessagesQuery {
+    #[serde(default)]
+    wait_for_durability: bool,
+}
+
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
         .route(
@@ -29,15 +48,25 @@ async fn poll_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
+    headers: HeaderMap,
     mut query: Query<PollMessages>,
-) -> Result<Json<PolledMessages>, CustomError> {
+) -> Result<Response, CustomError> {
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
 
+    let format = headers
+        .get(ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .map(MessagesFormat::from_accept)
+        .unwrap_or_default();
     let partition_id = query.partition_id.unwrap_or(0);
     let consumer_id = PollingConsumer::resolve_consumer_id(&query.consumer.id);
     let consumer = PollingConsumer::Consumer(consumer_id, partition_id);
+    if format == MessagesFormat::Ndjson {
+        return poll_ndjson_messages(state.system.clone(), identity, consumer, query.0).await;
+    }
+
     let system = state.system.read();
     let polled_messages = system
         .poll_messages(
@@ -48,17 +77,42 @@ async fn poll_messages(
             PollingArgs::new(query.strategy, query.count, query.auto_commit),
         )
         .await?;
-    Ok(Json(polled_messages))
+    system
+        .record_polled_messages(&query.stream_id, &query.topic_id, &polled_messages)
+        .await;
+    map_polled_messages(polled_messages, format)
 }
 
 async fn send_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
-    Json(mut command): Json<SendMessages>,
-) -> Result<StatusCode, CustomError> {
+    Query(query): Query<SendMessagesQuery>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<(StatusCode, Json<AppendedMessages>), CustomError> {
+    let format = match headers.get(CONTENT_TYPE) {
+        Some(content_type) => {
+            MessagesFormat::from_content_type(content_type.to_str().unwrap_or_default())?
+        }
+        None => MessagesFormat::Json,
+    };
+    let mut command = map_send_messages(body, format)?;
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
+    let wait_for_durability = command.wait_for_durability || query.wait_for_durability;
+    let appended_messages =
+        append_messages(&state.system, &identity, command, wait_for_durability).await?;
+    Ok((StatusCode::CREATED, Json(appended_messages)))
+}
+
+/// Appends the messages sent to the stream and topic of the command, shared by the regular and the live messages endpoints.
+pub(crate) async fn append_messages(
+    system: &SharedSystem,
+    identity: &Identity,
+    mut command: SendMessages,
+    wait_for_durability: bool,
+) -> Result<AppendedMessages, IggyError> {
     command.partitioning.length = command.partitioning.value.len() as u8;
     command.messages.iter_mut().for_each(|msg| {
         if msg.id == 0 {
@@ -71,15 +125,202 @@ async fn send_messages(
     let stream_id = command.stream_id;
     let topic_id = command.topic_id;
     let partitioning = command.partitioning;
-    let system = state.system.read();
-    system
-        .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            stream_id,
-            topic_id,
-            partitioning,
-            messages,
+    let (appended_messages, durability_waiter) = {
+        let system = system.read();
+        system
+            .append_messages(
+                &Session::stateless(identity.user_id, identity.ip_address),
+                stream_id,
+                topic_id,
+                partitioning,
+                messages,
+                wait_for_durability,
+            )
+            .await?
+    };
+    if let Some(durability_waiter) = durability_waiter {
+        durability_waiter.wait().await?;
+    }
+    Ok(appended_messages)
+}
+
+fn map_send_messages(body: Bytes, format: MessagesFormat) -> Result<SendMessages, CustomError> {
+    let command = match format {
+        MessagesFormat::Json => serde_json::from_slice(&body)
+            .map_err(|error| IggyError::CannotDeserializeMessages(error.to_string()))?,
+        MessagesFormat::MessagePack => rmp_serde::from_slice(&body)
+            .map_err(|error| IggyError::CannotDeserializeMessages(error.to_string()))?,
+        // The binary batch is the same as sent over TCP, the stream and topic IDs are overridden by the path.
+        MessagesFormat::Binary => SendMessages::from_bytes(body)?,
+        MessagesFormat::Ndjson => {
+            return Err(
+                IggyError::UnsupportedContentType(format.as_content_type().to_string()).into(),
+            )
+        }
+    };
+    Ok(command)
+}
+
+fn map_polled_messages(
+    polled_messages: PolledMessages,
+    format: MessagesFormat,
+) -> Result<Response, CustomError> {
+    let content_type = [(CONTENT_TYPE, format.as_content_type())];
+    let response = match format {
+        MessagesFormat::Json => Json(polled_messages).into_response(),
+        MessagesFormat::Binary => (
+            content_type,
+            mapper::map_polled_messages(&polled_messages, PollMessagesVersion::V2),
         )
-        .await?;
-    Ok(StatusCode::CREATED)
+            .into_response(),
+        MessagesFormat::MessagePack => {
+            let bytes = rmp_serde::to_vec_named(&polled_messages)
+                .map_err(|error| IggyError::CannotSerializeMessages(error.to_string()))?;
+            (content_type, bytes).into_response()
+        }
+        MessagesFormat::Ndjson => {
+            return Err(
+                IggyError::UnsupportedContentType(format.as_content_type().to_string()).into(),
+            )
+        }
+    };
+    Ok(response)
+}
+
+/// Polls the messages in batches while the chunked NDJSON response is being written, so that the large polls are
+/// neither loaded nor serialized at once. Only the first batch uses the requested strategy (the `last` one is resolved
+/// to the offset upfront), the next ones continue from the offset following the last polled message.
+async fn poll_ndjson_messages(
+    system: SharedSystem,
+    identity: Identity,
+    consumer: PollingConsumer,
+    query: PollMessages,
+) -> Result<Response, CustomError> {
+    let mut strategy = query.strategy;
+    if strategy.kind == PollingKind::Last {
+        strategy = resolve_last_messages_strategy(&system, &query).await?;
+    }
+
+    let poller = NdjsonPoller {
+        system,
+        identity,
+        consumer,
+        stream_id: query.stream_id,
+        topic_id: query.topic_id,
+        auto_commit: query.auto_commit,
+    };
+    let count = query.count.min(NDJSON_POLL_BATCH_SIZE);
+    let polled_messages = poller.poll(strategy, count).await?;
+    let metadata = [
+        (
+            HeaderName::from_static(PARTITION_ID_HEADER),
+            polled_messages.partition_id.to_string(),
+        ),
+        (
+            HeaderName::from_static(CURRENT_OFFSET_HEADER),
+            polled_messages.current_offset.to_string(),
+        ),
+    ];
+    let next_batch = get_next_ndjson_batch(&polled_messages, count, query.count - count);
+    let state = (Some(polled_messages.messages), next_batch);
+    let lines = futures::stream::unfold(state, move |(messages, next_batch)| {
+        let poller = poller.clone();
+        async move {
+            if let Some(messages) = messages {
+                return Some((map_ndjson_lines(&messages), (None, next_batch)));
+            }
+
+            let (offset, remaining) = next_batch?;
+            let count = remaining.min(NDJSON_POLL_BATCH_SIZE);
+            match poller.poll(PollingStrategy::offset(offset), count).await {
+                Ok(polled_messages) => {
+                    let next_batch =
+                        get_next_ndjson_batch(&polled_messages, count, remaining - count);
+                    Some((
+                        map_ndjson_lines(&polled_messages.messages),
+                        (None, next_batch),
+                    ))
+                }
+                // The error interrupts the response, so that the client doesn't take it as the end of the messages.
+                Err(error) => Some((Err(error), (None, None))),
+            }
+        }
+    });
+    let content_type = [(CONTENT_TYPE, MessagesFormat::Ndjson.as_content_type())];
+    Ok((content_type, metadata, Body::from_stream(lines)).into_response())
+}
+
+/// The maximum number of messages polled at once while streaming the NDJSON response.
+const NDJSON_POLL_BATCH_SIZE: u32 = 100;
+
+#[derive(Clone)]
+struct NdjsonPoller {
+    system: SharedSystem,
+    identity: Identity,
+    consumer: PollingConsumer,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    auto_commit: bool,
+}
+
+impl NdjsonPoller {
+    async fn poll(
+        &self,
+        strategy: PollingStrategy,
+        count: u32,
+    ) -> Result<PolledMessages, IggyError> {
+        let system = self.system.read();
+        let polled_messages = system
+            .poll_messages(
+                &Session::stateless(self.identity.user_id, self.identity.ip_address),
+                self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                PollingArgs::new(strategy, count, self.auto_commit),
+            )
+            .await?;
+        system
+            .record_polled_messages(&self.stream_id, &self.topic_id, &polled_messages)
+            .await;
+        Ok(polled_messages)
+    }
+}
+
+/// Returns the offset and the remaining count of the next batch, unless the polled one has reached the end of the partition.
+fn get_next_ndjson_batch(
+    polled_messages: &PolledMessages,
+    count: u32,
+    remaining: u32,
+) -> Option<(u64, u32)> {
+    if remaining == 0 || (polled_messages.messages.len() as u32) < count {
+        return None;
+    }
+
+    let last_message = polled_messages.messages.last()?;
+    Some((last_message.offset + 1, remaining))
+}
+
+fn map_ndjson_lines(messages: &[PolledMessage]) -> Result<Vec<u8>, IggyError> {
+    let mut lines = Vec::new();
+    for message in messages {
+        serde_json::to_writer(&mut lines, message)
+            .map_err(|error| IggyError::CannotSerializeMessages(error.to_string()))?;
+        lines.push(b'\n');
+    }
+    Ok(lines)
+}
+
+/// Resolves the `last` strategy to the offset of the first of the last `count` messages in the partition.
+async fn resolve_last_messages_strategy(
+    system: &SharedSystem,
+    query: &PollMessages,
+) -> Result<PollingStrategy, IggyError> {
+    let system = system.read();
+    let partition = system
+        .get_stream(&query.stream_id)?
+        .get_topic(&query.topic_id)?
+        .get_partition(query.partition_id.unwrap_or(0))?;
+    let current_offset = partition.read().await.current_offset;
+    let count = (query.count as u64).min(current_offset + 1);
+    Ok(PollingStrategy::offset(current_offset + 1 - count))
 }
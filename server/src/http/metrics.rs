@@ -1,5 +1,7 @@
 use crate::http::shared::AppState;
+use crate::streaming::diagnostics::metrics::StreamingMetrics;
 use axum::body::Body;
+use axum::extract::MatchedPath;
 use axum::{
     extract::State,
     http::{Request, StatusCode},
@@ -7,6 +9,7 @@ use axum::{
     response::Response,
 };
 use std::sync::Arc;
+use std::time::Instant;
 
 pub async fn metrics(
     State(state): State<Arc<AppState>>,
@@ -14,5 +17,14 @@ pub async fn metrics(
     next: Next,
 ) -> Result<Response, StatusCode> {
     state.system.read().metrics.increment_http_requests();
-    Ok(next.run(request).await)
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str())
+        .unwrap_or("unknown");
+    let command = format!("{} {}", request.method(), path);
+    let started_at = Instant::now();
+    let response = next.run(request).await;
+    StreamingMetrics::get().record_request_duration("HTTP", &command, started_at.elapsed());
+    Ok(response)
 }
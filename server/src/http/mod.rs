@@ -4,11 +4,13 @@ pub mod diagnostics;
 pub mod error;
 pub mod http_server;
 pub mod jwt;
-mod mapper;
+pub mod live;
+pub(crate) mod mapper;
 pub mod messages;
 pub mod metrics;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 mod shared;
 pub mod streams;
 pub mod system;
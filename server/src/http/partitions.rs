@@ -4,11 +4,15 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
+use iggy::models::partition_offsets::PartitionOffsets;
+use iggy::models::partition_truncation::PartitionTruncation;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_offsets_for_timestamp::GetOffsetsForTimestamp;
+use iggy::partitions::truncate_partition::TruncatePartition;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -18,6 +22,14 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/partitions",
             post(create_partitions).delete(delete_partitions),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/offsets",
+            get(get_offsets_for_timestamp),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/:partition_id/truncate",
+            post(truncate_partition),
+        )
         .with_state(state)
 }
 
@@ -62,3 +74,48 @@ async fn delete_partitions(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn get_offsets_for_timestamp(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<GetOffsetsForTimestamp>,
+) -> Result<Json<Vec<PartitionOffsets>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+    let system = state.system.read();
+    let offsets = system
+        .get_offsets_for_timestamp(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.stream_id,
+            &query.topic_id,
+            &query.partition_ids,
+            query.timestamp,
+        )
+        .await?;
+    Ok(Json(offsets))
+}
+
+async fn truncate_partition(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+    Json(mut command): Json<TruncatePartition>,
+) -> Result<Json<PartitionTruncation>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.partition_id = partition_id;
+    command.validate()?;
+    let system = state.system.read();
+    let truncation = system
+        .truncate_partition(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+        )
+        .await?;
+    Ok(Json(truncation))
+}
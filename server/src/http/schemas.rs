@@ -0,0 +1,75 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use iggy::identifier::Identifier;
+use iggy::models::schema::Schema;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route(
+            "/streams/:stream_id/topics/:topic_id/schemas",
+            get(get_schemas).post(register_schema),
+        )
+        .route("/schemas/:schema_id", get(get_schema))
+        .with_state(state)
+}
+
+async fn get_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(schema_id): Path<u32>,
+) -> Result<Json<Schema>, CustomError> {
+    let system = state.system.read();
+    let schema = system.get_schema(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        schema_id,
+    )?;
+    Ok(Json(schema.clone()))
+}
+
+async fn get_schemas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<Vec<Schema>>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read();
+    let schemas = system.get_schemas(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        &stream_id,
+        &topic_id,
+    )?;
+    Ok(Json(schemas.into_iter().cloned().collect()))
+}
+
+async fn register_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<RegisterSchema>,
+) -> Result<Json<Schema>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let mut system = state.system.write();
+    let schema = system
+        .register_schema(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.schema_type,
+            command.compatibility,
+            command.validation_enabled,
+            command.definition,
+        )
+        .await?;
+    Ok(Json(schema))
+}
@@ -82,6 +82,7 @@ async fn update_stream(
             &Session::stateless(identity.user_id, identity.ip_address),
             &command.stream_id,
             &command.name,
+            command.topic_config_overrides,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
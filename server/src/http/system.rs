@@ -31,7 +31,7 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
 
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read();
-    Ok(system.metrics.get_formatted_output())
+    Ok(system.get_formatted_metrics().await)
 }
 
 async fn get_stats(
@@ -83,6 +83,8 @@ async fn create_topic(
             command.compression_algorithm,
             command.max_topic_size,
             command.replication_factor,
+            command.durability,
+            command.config_overrides,
         )
         .await?;
     Ok(StatusCode::CREATED)
@@ -108,6 +110,8 @@ async fn update_topic(
             command.compression_algorithm,
             command.max_topic_size,
             command.replication_factor,
+            command.durability,
+            command.config_overrides,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
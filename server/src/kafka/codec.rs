@@ -0,0 +1,315 @@
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy::error::IggyError;
+
+/// Reads the primitive types of the Kafka protocol (big-endian integers, length-prefixed strings,
+/// bytes and arrays, as well as the zigzag-encoded varints used by the record batches).
+#[derive(Debug)]
+pub struct Decoder {
+    buffer: Bytes,
+}
+
+impl Decoder {
+    pub fn new(buffer: Bytes) -> Self {
+        Self { buffer }
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.buffer.remaining()
+    }
+
+    fn ensure(&self, length: usize) -> Result<(), IggyError> {
+        if self.buffer.remaining() < length {
+            return Err(IggyError::InvalidFormat);
+        }
+        Ok(())
+    }
+
+    pub fn i8(&mut self) -> Result<i8, IggyError> {
+        self.ensure(1)?;
+        Ok(self.buffer.get_i8())
+    }
+
+    pub fn bool(&mut self) -> Result<bool, IggyError> {
+        Ok(self.i8()? != 0)
+    }
+
+    pub fn i16(&mut self) -> Result<i16, IggyError> {
+        self.ensure(2)?;
+        Ok(self.buffer.get_i16())
+    }
+
+    pub fn i32(&mut self) -> Result<i32, IggyError> {
+        self.ensure(4)?;
+        Ok(self.buffer.get_i32())
+    }
+
+    pub fn i64(&mut self) -> Result<i64, IggyError> {
+        self.ensure(8)?;
+        Ok(self.buffer.get_i64())
+    }
+
+    pub fn u32(&mut self) -> Result<u32, IggyError> {
+        self.ensure(4)?;
+        Ok(self.buffer.get_u32())
+    }
+
+    pub fn raw(&mut self, length: usize) -> Result<Bytes, IggyError> {
+        self.ensure(length)?;
+        Ok(self.buffer.split_to(length))
+    }
+
+    pub fn string(&mut self) -> Result<String, IggyError> {
+        self.nullable_string()?.ok_or(IggyError::InvalidFormat)
+    }
+
+    pub fn nullable_string(&mut self) -> Result<Option<String>, IggyError> {
+        let length = self.i16()?;
+        if length < 0 {
+            return Ok(None);
+        }
+        let value = self.raw(length as usize)?;
+        Ok(Some(std::str::from_utf8(&value)?.to_string()))
+    }
+
+    pub fn bytes(&mut self) -> Result<Bytes, IggyError> {
+        self.nullable_bytes()?.ok_or(IggyError::InvalidFormat)
+    }
+
+    pub fn nullable_bytes(&mut self) -> Result<Option<Bytes>, IggyError> {
+        let length = self.i32()?;
+        if length < 0 {
+            return Ok(None);
+        }
+        self.raw(length as usize).map(Some)
+    }
+
+    pub fn array<T>(
+        &mut self,
+        read: impl FnMut(&mut Decoder) -> Result<T, IggyError>,
+    ) -> Result<Vec<T>, IggyError> {
+        Ok(self.nullable_array(read)?.unwrap_or_default())
+    }
+
+    pub fn nullable_array<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Decoder) -> Result<T, IggyError>,
+    ) -> Result<Option<Vec<T>>, IggyError> {
+        let length = self.i32()?;
+        if length < 0 {
+            return Ok(None);
+        }
+        // Every element takes at least a single byte, which protects from the bogus lengths.
+        self.ensure(length as usize)?;
+        let mut items = Vec::with_capacity(length as usize);
+        for _ in 0..length {
+            items.push(read(self)?);
+        }
+        Ok(Some(items))
+    }
+
+    pub fn varint(&mut self) -> Result<i32, IggyError> {
+        let value = self.unsigned_varint(5)?;
+        Ok(((value >> 1) as i32) ^ -((value & 1) as i32))
+    }
+
+    pub fn varlong(&mut self) -> Result<i64, IggyError> {
+        let value = self.unsigned_varint(10)?;
+        Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+    }
+
+    pub fn varint_bytes(&mut self) -> Result<Option<Bytes>, IggyError> {
+        let length = self.varint()?;
+        if length < 0 {
+            return Ok(None);
+        }
+        self.raw(length as usize).map(Some)
+    }
+
+    fn unsigned_varint(&mut self, max_bytes: usize) -> Result<u64, IggyError> {
+        let mut value = 0u64;
+        for index in 0..max_bytes {
+            let byte = self.i8()? as u8;
+            value |= ((byte & 0x7f) as u64) << (7 * index);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(IggyError::InvalidFormat)
+    }
+}
+
+/// Writes the primitive types of the Kafka protocol, the counterpart of `Decoder`.
+#[derive(Debug, Default)]
+pub struct Encoder {
+    buffer: BytesMut,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    pub fn freeze(self) -> Bytes {
+        self.buffer.freeze()
+    }
+
+    pub fn i8(&mut self, value: i8) -> &mut Self {
+        self.buffer.put_i8(value);
+        self
+    }
+
+    pub fn bool(&mut self, value: bool) -> &mut Self {
+        self.i8(value as i8)
+    }
+
+    pub fn i16(&mut self, value: i16) -> &mut Self {
+        self.buffer.put_i16(value);
+        self
+    }
+
+    pub fn i32(&mut self, value: i32) -> &mut Self {
+        self.buffer.put_i32(value);
+        self
+    }
+
+    pub fn i64(&mut self, value: i64) -> &mut Self {
+        self.buffer.put_i64(value);
+        self
+    }
+
+    pub fn u32(&mut self, value: u32) -> &mut Self {
+        self.buffer.put_u32(value);
+        self
+    }
+
+    pub fn raw(&mut self, value: &[u8]) -> &mut Self {
+        self.buffer.put_slice(value);
+        self
+    }
+
+    pub fn string(&mut self, value: &str) -> &mut Self {
+        self.i16(value.len() as i16).raw(value.as_bytes())
+    }
+
+    pub fn nullable_string(&mut self, value: Option<&str>) -> &mut Self {
+        match value {
+            Some(value) => self.string(value),
+            None => self.i16(-1),
+        }
+    }
+
+    pub fn bytes(&mut self, value: &[u8]) -> &mut Self {
+        self.i32(value.len() as i32).raw(value)
+    }
+
+    pub fn nullable_bytes(&mut self, value: Option<&[u8]>) -> &mut Self {
+        match value {
+            Some(value) => self.bytes(value),
+            None => self.i32(-1),
+        }
+    }
+
+    pub fn array<T>(&mut self, items: &[T], mut write: impl FnMut(&mut Encoder, &T)) -> &mut Self {
+        self.i32(items.len() as i32);
+        for item in items {
+            write(self, item);
+        }
+        self
+    }
+
+    pub fn varint(&mut self, value: i32) -> &mut Self {
+        self.unsigned_varint(((value << 1) ^ (value >> 31)) as u32 as u64)
+    }
+
+    pub fn varlong(&mut self, value: i64) -> &mut Self {
+        self.unsigned_varint(((value << 1) ^ (value >> 63)) as u64)
+    }
+
+    pub fn varint_bytes(&mut self, value: Option<&[u8]>) -> &mut Self {
+        match value {
+            Some(value) => self.varint(value.len() as i32).raw(value),
+            None => self.varint(-1),
+        }
+    }
+
+    fn unsigned_varint(&mut self, mut value: u64) -> &mut Self {
+        while value >= 0x80 {
+            self.buffer.put_u8((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        self.buffer.put_u8(value as u8);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primitives_should_be_encoded_and_decoded() {
+        let mut encoder = Encoder::new();
+        encoder
+            .i8(-1)
+            .i16(300)
+            .i32(-70000)
+            .i64(1 << 40)
+            .string("topic")
+            .nullable_string(None)
+            .bytes(b"value")
+            .nullable_bytes(None)
+            .array(&[1, 2, 3], |encoder, item| {
+                encoder.i32(*item);
+            });
+
+        let mut decoder = Decoder::new(encoder.freeze());
+        assert_eq!(decoder.i8().unwrap(), -1);
+        assert_eq!(decoder.i16().unwrap(), 300);
+        assert_eq!(decoder.i32().unwrap(), -70000);
+        assert_eq!(decoder.i64().unwrap(), 1 << 40);
+        assert_eq!(decoder.string().unwrap(), "topic");
+        assert_eq!(decoder.nullable_string().unwrap(), None);
+        assert_eq!(decoder.bytes().unwrap().as_ref(), b"value");
+        assert_eq!(decoder.nullable_bytes().unwrap(), None);
+        assert_eq!(decoder.array(|d| d.i32()).unwrap(), vec![1, 2, 3]);
+        assert_eq!(decoder.remaining(), 0);
+    }
+
+    #[test]
+    fn varints_should_be_zigzag_encoded() {
+        let values = [0, -1, 1, -64, 64, i32::MIN, i32::MAX];
+        let mut encoder = Encoder::new();
+        for value in values {
+            encoder.varint(value);
+        }
+        encoder.varlong(i64::MIN).varlong(-2);
+
+        let mut decoder = Decoder::new(encoder.freeze());
+        for value in values {
+            assert_eq!(decoder.varint().unwrap(), value);
+        }
+        assert_eq!(decoder.varlong().unwrap(), i64::MIN);
+        assert_eq!(decoder.varlong().unwrap(), -2);
+    }
+
+    #[test]
+    fn single_byte_varints_should_match_the_kafka_encoding() {
+        let mut encoder = Encoder::new();
+        encoder.varint(-1).varint(1).varint(63);
+        assert_eq!(encoder.freeze().as_ref(), &[1, 2, 126]);
+    }
+
+    #[test]
+    fn truncated_input_should_fail() {
+        let mut decoder = Decoder::new(Bytes::from_static(&[0, 5, b'a']));
+        assert!(decoder.string().is_err());
+    }
+}
@@ -0,0 +1,478 @@
+use crate::kafka::protocol::error_codes;
+use bytes::Bytes;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, Mutex};
+use tokio::time::Instant;
+use tracing::info;
+use uuid::Uuid;
+
+/// Coordinates the Kafka consumer groups: the members join the group, the leader computes
+/// the partition assignment and distributes it with the sync, and the heartbeats keep
+/// the members alive. The state is kept in memory only, the committed offsets are stored
+/// as the regular Iggy consumer offsets.
+#[derive(Debug)]
+pub(crate) struct GroupCoordinator {
+    initial_rebalance_delay: Duration,
+    groups: Mutex<HashMap<String, Arc<Group>>>,
+}
+
+#[derive(Debug)]
+struct Group {
+    name: String,
+    state: Mutex<GroupState>,
+    // Bumped on every transition, so that the pending join and sync requests can re-check the state.
+    changes: watch::Sender<u64>,
+}
+
+#[derive(Debug, Default)]
+struct GroupState {
+    phase: Phase,
+    generation: i32,
+    protocol_type: Option<String>,
+    protocol: Option<String>,
+    leader: Option<String>,
+    members: HashMap<String, Member>,
+    pending_members: HashSet<String>,
+    assignments: HashMap<String, Bytes>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum Phase {
+    #[default]
+    Empty,
+    PreparingRebalance {
+        deadline: Instant,
+        // The initial rebalance of the empty group always waits until the deadline for the other members.
+        initial: bool,
+    },
+    CompletingRebalance,
+    Stable,
+}
+
+#[derive(Debug)]
+struct Member {
+    session_timeout: Duration,
+    rebalance_timeout: Duration,
+    protocols: Vec<(String, Bytes)>,
+    last_heartbeat: Instant,
+    joined: bool,
+}
+
+pub(crate) struct JoinRequest {
+    pub group_id: String,
+    pub member_id: String,
+    pub client_id: String,
+    pub session_timeout: Duration,
+    pub rebalance_timeout: Duration,
+    pub protocol_type: String,
+    pub protocols: Vec<(String, Bytes)>,
+    pub require_known_member_id: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct JoinResponse {
+    pub error_code: i16,
+    pub generation: i32,
+    pub protocol: String,
+    pub leader: String,
+    pub member_id: String,
+    pub members: Vec<(String, Bytes)>,
+}
+
+impl JoinResponse {
+    fn error(error_code: i16, member_id: String) -> Self {
+        Self {
+            error_code,
+            generation: -1,
+            member_id,
+            ..Default::default()
+        }
+    }
+}
+
+impl GroupCoordinator {
+    pub fn new(initial_rebalance_delay: Duration) -> Self {
+        Self {
+            initial_rebalance_delay,
+            groups: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn get_group(&self, group_id: &str) -> Arc<Group> {
+        let mut groups = self.groups.lock().await;
+        groups
+            .entry(group_id.to_string())
+            .or_insert_with(|| {
+                Arc::new(Group {
+                    name: group_id.to_string(),
+                    state: Mutex::new(GroupState::default()),
+                    changes: watch::channel(0).0,
+                })
+            })
+            .clone()
+    }
+
+    pub async fn join(&self, request: JoinRequest) -> JoinResponse {
+        let group = self.get_group(&request.group_id).await;
+        let member_id = {
+            let mut state = group.state.lock().await;
+            let now = Instant::now();
+            if group.expire_members(&mut state, now) {
+                group.notify();
+            }
+
+            if let Some(protocol_type) = &state.protocol_type {
+                if !state.members.is_empty() && protocol_type != &request.protocol_type {
+                    return JoinResponse::error(
+                        error_codes::INCONSISTENT_GROUP_PROTOCOL,
+                        request.member_id,
+                    );
+                }
+            }
+            if !state.members.is_empty() {
+                let supported = state.members.values().all(|member| {
+                    member
+                        .protocols
+                        .iter()
+                        .any(|(name, _)| request.protocols.iter().any(|(other, _)| other == name))
+                });
+                if !supported {
+                    return JoinResponse::error(
+                        error_codes::INCONSISTENT_GROUP_PROTOCOL,
+                        request.member_id,
+                    );
+                }
+            }
+
+            let member_id = if request.member_id.is_empty() {
+                let member_id = format!("{}-{}", request.client_id, Uuid::new_v4());
+                if request.require_known_member_id {
+                    state.pending_members.insert(member_id.clone());
+                    return JoinResponse::error(error_codes::MEMBER_ID_REQUIRED, member_id);
+                }
+                member_id
+            } else if state.members.contains_key(&request.member_id)
+                || state.pending_members.remove(&request.member_id)
+            {
+                request.member_id
+            } else {
+                return JoinResponse::error(error_codes::UNKNOWN_MEMBER_ID, request.member_id);
+            };
+
+            state.protocol_type = Some(request.protocol_type);
+            state.members.insert(
+                member_id.clone(),
+                Member {
+                    session_timeout: request.session_timeout,
+                    rebalance_timeout: request.rebalance_timeout,
+                    protocols: request.protocols,
+                    last_heartbeat: now,
+                    joined: true,
+                },
+            );
+            match state.phase {
+                Phase::PreparingRebalance { .. } => {}
+                Phase::Empty => {
+                    let delay = self.initial_rebalance_delay.min(request.rebalance_timeout);
+                    state.phase = Phase::PreparingRebalance {
+                        deadline: now + delay,
+                        initial: true,
+                    };
+                }
+                Phase::CompletingRebalance | Phase::Stable => {
+                    group.prepare_rebalance(&mut state, now)
+                }
+            }
+            group.notify();
+            member_id
+        };
+
+        let mut changes = group.changes.subscribe();
+        loop {
+            let deadline = {
+                let mut state = group.state.lock().await;
+                changes.borrow_and_update();
+                let now = Instant::now();
+                if !state.members.contains_key(&member_id) {
+                    return JoinResponse::error(error_codes::UNKNOWN_MEMBER_ID, member_id);
+                }
+                match state.phase {
+                    Phase::PreparingRebalance { deadline, initial } => {
+                        let all_joined = state.members.values().all(|member| member.joined);
+                        if (all_joined && !initial) || now >= deadline {
+                            group.complete_join(&mut state);
+                            group.notify();
+                            return Self::join_response(&state, member_id);
+                        }
+                        deadline
+                    }
+                    _ => return Self::join_response(&state, member_id),
+                }
+            };
+            let _ = tokio::time::timeout_at(deadline, changes.changed()).await;
+        }
+    }
+
+    fn join_response(state: &GroupState, member_id: String) -> JoinResponse {
+        let leader = state.leader.clone().unwrap_or_default();
+        let protocol = state.protocol.clone().unwrap_or_default();
+        let members = if leader == member_id {
+            state
+                .members
+                .iter()
+                .map(|(id, member)| {
+                    let metadata = member
+                        .protocols
+                        .iter()
+                        .find(|(name, _)| name == &protocol)
+                        .map(|(_, metadata)| metadata.clone())
+                        .unwrap_or_default();
+                    (id.clone(), metadata)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        JoinResponse {
+            error_code: error_codes::NONE,
+            generation: state.generation,
+            protocol,
+            leader,
+            member_id,
+            members,
+        }
+    }
+
+    pub async fn sync(
+        &self,
+        group_id: &str,
+        generation: i32,
+        member_id: &str,
+        assignments: Vec<(String, Bytes)>,
+    ) -> (i16, Bytes) {
+        let group = self.get_group(group_id).await;
+        let mut changes = group.changes.subscribe();
+        let mut timeout = None;
+        loop {
+            let deadline = {
+                let mut state = group.state.lock().await;
+                changes.borrow_and_update();
+                let now = Instant::now();
+                if group.expire_members(&mut state, now) {
+                    group.notify();
+                }
+                let Some(member) = state.members.get(member_id) else {
+                    return (error_codes::UNKNOWN_MEMBER_ID, Bytes::new());
+                };
+                if state.generation != generation {
+                    return (error_codes::ILLEGAL_GENERATION, Bytes::new());
+                }
+                match state.phase {
+                    Phase::Empty | Phase::PreparingRebalance { .. } => {
+                        return (error_codes::REBALANCE_IN_PROGRESS, Bytes::new());
+                    }
+                    Phase::Stable => {
+                        let assignment = state.assignments.get(member_id).cloned();
+                        return (error_codes::NONE, assignment.unwrap_or_default());
+                    }
+                    Phase::CompletingRebalance => {
+                        if state.leader.as_deref() == Some(member_id) {
+                            state.assignments = assignments.into_iter().collect();
+                            state.phase = Phase::Stable;
+                            info!(
+                                "Kafka consumer group: {} is stable, generation: {}, members: {}.",
+                                group.name,
+                                state.generation,
+                                state.members.len()
+                            );
+                            group.notify();
+                            let assignment = state.assignments.get(member_id).cloned();
+                            return (error_codes::NONE, assignment.unwrap_or_default());
+                        }
+                        *timeout.get_or_insert(now + member.rebalance_timeout)
+                    }
+                }
+            };
+            if tokio::time::timeout_at(deadline, changes.changed())
+                .await
+                .is_err()
+            {
+                return (error_codes::REBALANCE_IN_PROGRESS, Bytes::new());
+            }
+        }
+    }
+
+    pub async fn heartbeat(&self, group_id: &str, generation: i32, member_id: &str) -> i16 {
+        let group = self.get_group(group_id).await;
+        let mut state = group.state.lock().await;
+        let now = Instant::now();
+        if group.expire_members(&mut state, now) {
+            group.notify();
+        }
+        let phase = state.phase;
+        let current_generation = state.generation;
+        let Some(member) = state.members.get_mut(member_id) else {
+            return error_codes::UNKNOWN_MEMBER_ID;
+        };
+        member.last_heartbeat = now;
+        if matches!(phase, Phase::PreparingRebalance { .. }) {
+            return error_codes::REBALANCE_IN_PROGRESS;
+        }
+        if current_generation != generation {
+            return error_codes::ILLEGAL_GENERATION;
+        }
+        error_codes::NONE
+    }
+
+    pub async fn leave(&self, group_id: &str, member_id: &str) -> i16 {
+        let group = self.get_group(group_id).await;
+        let mut state = group.state.lock().await;
+        if state.members.remove(member_id).is_none() {
+            return error_codes::UNKNOWN_MEMBER_ID;
+        }
+        info!(
+            "Member: {member_id} has left Kafka consumer group: {}.",
+            group.name
+        );
+        group.on_members_removed(&mut state, Instant::now());
+        group.notify();
+        error_codes::NONE
+    }
+
+    /// Validates the generation of the offset commit, the generation `-1` is used by
+    /// the consumers committing the offsets without the group membership.
+    pub async fn validate_commit(&self, group_id: &str, generation: i32, member_id: &str) -> i16 {
+        if generation < 0 {
+            return error_codes::NONE;
+        }
+        let group = self.get_group(group_id).await;
+        let mut state = group.state.lock().await;
+        let now = Instant::now();
+        if group.expire_members(&mut state, now) {
+            group.notify();
+        }
+        let Some(member) = state.members.get_mut(member_id) else {
+            return error_codes::UNKNOWN_MEMBER_ID;
+        };
+        member.last_heartbeat = now;
+        if state.generation != generation {
+            return error_codes::ILLEGAL_GENERATION;
+        }
+        if matches!(state.phase, Phase::PreparingRebalance { .. }) {
+            return error_codes::REBALANCE_IN_PROGRESS;
+        }
+        error_codes::NONE
+    }
+}
+
+impl Group {
+    fn notify(&self) {
+        self.changes.send_modify(|changes| *changes += 1);
+    }
+
+    fn prepare_rebalance(&self, state: &mut GroupState, now: Instant) {
+        let rebalance_timeout = state
+            .members
+            .values()
+            .map(|member| member.rebalance_timeout)
+            .max()
+            .unwrap_or_default();
+        state.phase = Phase::PreparingRebalance {
+            deadline: now + rebalance_timeout,
+            initial: false,
+        };
+        info!(
+            "Preparing rebalance of Kafka consumer group: {}, generation: {}.",
+            self.name, state.generation
+        );
+    }
+
+    fn complete_join(&self, state: &mut GroupState) {
+        state.members.retain(|_, member| member.joined);
+        let now = Instant::now();
+        for member in state.members.values_mut() {
+            member.joined = false;
+            member.last_heartbeat = now;
+        }
+        state.generation += 1;
+        state.assignments.clear();
+        if state.members.is_empty() {
+            state.phase = Phase::Empty;
+            state.leader = None;
+            state.protocol = None;
+            return;
+        }
+
+        if !state
+            .leader
+            .as_ref()
+            .is_some_and(|leader| state.members.contains_key(leader))
+        {
+            state.leader = state.members.keys().min().cloned();
+        }
+        let leader = &state.members[state.leader.as_ref().unwrap()];
+        state.protocol = leader
+            .protocols
+            .iter()
+            .map(|(name, _)| name)
+            .find(|name| {
+                state
+                    .members
+                    .values()
+                    .all(|member| member.protocols.iter().any(|(other, _)| &other == name))
+            })
+            .cloned();
+        state.phase = Phase::CompletingRebalance;
+        info!(
+            "Kafka consumer group: {} has completed the join, generation: {}, members: {}.",
+            self.name,
+            state.generation,
+            state.members.len()
+        );
+    }
+
+    /// Removes the members which didn't send the heartbeat within the session timeout,
+    /// returns `true` if any member has been removed.
+    fn expire_members(&self, state: &mut GroupState, now: Instant) -> bool {
+        let expired = state
+            .members
+            .iter()
+            .filter(|(_, member)| {
+                !member.joined && now > member.last_heartbeat + member.session_timeout
+            })
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+        if expired.is_empty() {
+            return false;
+        }
+        for member_id in expired {
+            info!(
+                "Member: {member_id} of Kafka consumer group: {} has expired.",
+                self.name
+            );
+            state.members.remove(&member_id);
+        }
+        self.on_members_removed(state, now);
+        true
+    }
+
+    fn on_members_removed(&self, state: &mut GroupState, now: Instant) {
+        if state.members.is_empty() {
+            state.phase = Phase::Empty;
+            state.leader = None;
+            state.assignments.clear();
+            return;
+        }
+        match state.phase {
+            Phase::Stable | Phase::CompletingRebalance => {
+                for member in state.members.values_mut() {
+                    member.joined = false;
+                }
+                self.prepare_rebalance(state, now);
+            }
+            Phase::Empty | Phase::PreparingRebalance { .. } => {}
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::kafka::codec::Encoder;
+use crate::kafka::protocol::{self, error_codes};
+use bytes::Bytes;
+
+pub(crate) fn handle(version: i16) -> Bytes {
+    let mut encoder = Encoder::new();
+    // The unsupported version is answered with the v0 response, so that the client can pick the supported one.
+    let supported = protocol::is_supported(protocol::API_VERSIONS, version);
+    encoder
+        .i16(if supported {
+            error_codes::NONE
+        } else {
+            error_codes::UNSUPPORTED_VERSION
+        })
+        .array(&protocol::SUPPORTED_APIS, |encoder, (key, min, max)| {
+            encoder.i16(*key).i16(*min).i16(*max);
+        });
+    if supported && version >= 1 {
+        encoder.i32(0);
+    }
+    encoder.freeze()
+}
+
+/// The response to the request with the unsupported API key or version, consisting of the error code only.
+pub(crate) fn unsupported_version() -> Bytes {
+    let mut encoder = Encoder::new();
+    encoder.i16(error_codes::UNSUPPORTED_VERSION);
+    encoder.freeze()
+}
@@ -0,0 +1,221 @@
+use crate::kafka::codec::{Decoder, Encoder};
+use crate::kafka::handlers::{get_watermarks, partition_id, KafkaContext};
+use crate::kafka::protocol::{self, error_codes};
+use crate::kafka::records::{self, Record, RecordsCompression};
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::systems::messages::PollingArgs;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::models::messages::PolledMessage;
+use std::time::Duration;
+use tokio::time::Instant;
+
+const MESSAGES_COUNT: u32 = 1000;
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+// Record attributes, deltas and lengths of the key, value and headers.
+const RECORD_OVERHEAD: usize = 24;
+
+struct PartitionRequest {
+    partition_index: i32,
+    fetch_offset: i64,
+    max_bytes: i32,
+}
+
+struct PartitionResponse {
+    partition_index: i32,
+    error_code: i16,
+    high_watermark: i64,
+    log_start_offset: i64,
+    records: Bytes,
+}
+
+pub(crate) async fn handle(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Bytes, IggyError> {
+    decoder.i32()?;
+    let max_wait = Duration::from_millis(decoder.i32()?.max(0) as u64);
+    let min_bytes = decoder.i32()?.max(1) as usize;
+    let max_bytes = decoder.i32()?.max(1) as usize;
+    decoder.i8()?;
+    if version >= 7 {
+        decoder.i32()?;
+        decoder.i32()?;
+    }
+    let topics = decoder.array(|decoder| {
+        let topic = decoder.string()?;
+        let partitions = decoder.array(|decoder| {
+            let partition_index = decoder.i32()?;
+            if version >= 9 {
+                decoder.i32()?;
+            }
+            let fetch_offset = decoder.i64()?;
+            if version >= 5 {
+                decoder.i64()?;
+            }
+            let max_bytes = decoder.i32()?;
+            Ok(PartitionRequest {
+                partition_index,
+                fetch_offset,
+                max_bytes,
+            })
+        })?;
+        Ok((topic, partitions))
+    })?;
+
+    // The fetch is repeated until at least the minimal amount of bytes is available or the wait time elapses.
+    let deadline = Instant::now() + max_wait;
+    let responses = loop {
+        let mut remaining_bytes = max_bytes;
+        let mut responses = Vec::with_capacity(topics.len());
+        for (topic, partitions) in &topics {
+            let mut partition_responses = Vec::with_capacity(partitions.len());
+            for partition in partitions {
+                let response = fetch(context, topic, partition, remaining_bytes).await;
+                remaining_bytes = remaining_bytes.saturating_sub(response.records.len());
+                partition_responses.push(response);
+            }
+            responses.push((topic, partition_responses));
+        }
+
+        let fetched_bytes = max_bytes - remaining_bytes;
+        let has_errors = responses
+            .iter()
+            .flat_map(|(_, partitions)| partitions)
+            .any(|partition| partition.error_code != error_codes::NONE);
+        if fetched_bytes >= min_bytes || has_errors || Instant::now() >= deadline {
+            break responses;
+        }
+        tokio::time::sleep_until(deadline.min(Instant::now() + POLL_INTERVAL)).await;
+    };
+
+    let mut encoder = Encoder::new();
+    encoder.i32(0);
+    if version >= 7 {
+        encoder.i16(error_codes::NONE).i32(0);
+    }
+    encoder.array(&responses, |encoder, (topic, partitions)| {
+        encoder.string(topic);
+        encoder.array(partitions, |encoder, partition| {
+            encoder
+                .i32(partition.partition_index)
+                .i16(partition.error_code)
+                .i64(partition.high_watermark)
+                .i64(partition.high_watermark);
+            if version >= 5 {
+                encoder.i64(partition.log_start_offset);
+            }
+            encoder.i32(-1);
+            if version >= 11 {
+                encoder.i32(-1);
+            }
+            encoder.bytes(&partition.records);
+        });
+    });
+    Ok(encoder.freeze())
+}
+
+async fn fetch(
+    context: &KafkaContext,
+    topic: &str,
+    partition: &PartitionRequest,
+    remaining_bytes: usize,
+) -> PartitionResponse {
+    let mut response = PartitionResponse {
+        partition_index: partition.partition_index,
+        error_code: error_codes::NONE,
+        high_watermark: -1,
+        log_start_offset: -1,
+        records: Bytes::new(),
+    };
+    let (high_watermark, log_start_offset) =
+        match get_watermarks(context, topic, partition.partition_index).await {
+            Ok(watermarks) => watermarks,
+            Err(error) => {
+                response.error_code = protocol::map_error(&error);
+                return response;
+            }
+        };
+    response.high_watermark = high_watermark;
+    response.log_start_offset = log_start_offset;
+    if partition.fetch_offset < log_start_offset || partition.fetch_offset > high_watermark {
+        response.error_code = error_codes::OFFSET_OUT_OF_RANGE;
+        return response;
+    }
+    if partition.fetch_offset == high_watermark || remaining_bytes == 0 {
+        return response;
+    }
+
+    let max_bytes = (partition.max_bytes.max(1) as usize).min(remaining_bytes);
+    match poll(context, topic, partition, max_bytes).await {
+        Ok(records) => response.records = records,
+        Err(error) => response.error_code = protocol::map_error(&error),
+    }
+    response
+}
+
+async fn poll(
+    context: &KafkaContext,
+    topic: &str,
+    partition: &PartitionRequest,
+    max_bytes: usize,
+) -> Result<Bytes, IggyError> {
+    let partition_id = partition_id(partition.partition_index)?;
+    let polled_messages = {
+        let system = context.system.read();
+        system
+            .poll_messages(
+                &context.session,
+                PollingConsumer::Consumer(0, partition_id),
+                &context.stream_id,
+                &Identifier::named(topic)?,
+                PollingArgs::new(
+                    PollingStrategy::offset(partition.fetch_offset as u64),
+                    MESSAGES_COUNT,
+                    false,
+                ),
+            )
+            .await?
+    };
+
+    // At least a single record is always returned, even if it exceeds the limit, so that the consumer can make progress.
+    let mut size = 0;
+    let mut batch = Vec::new();
+    for message in polled_messages.messages {
+        let record = map_message(message);
+        size += RECORD_OVERHEAD
+            + record.value.as_ref().map_or(0, |value| value.len())
+            + record.key.as_ref().map_or(0, |key| key.len())
+            + record
+                .headers
+                .iter()
+                .map(|(key, value)| key.len() + value.as_ref().map_or(0, |value| value.len()))
+                .sum::<usize>();
+        if size > max_bytes && !batch.is_empty() {
+            break;
+        }
+        batch.push(record);
+    }
+    Ok(records::encode(&batch, RecordsCompression::None))
+}
+
+/// Maps the Iggy message onto the Kafka record, the key is the messages key,
+/// and the timestamp is converted from microseconds to milliseconds.
+fn map_message(message: PolledMessage) -> Record {
+    let headers = message
+        .headers
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(key, value)| (key.as_str().to_string(), Some(value.value)))
+        .collect();
+    Record {
+        offset: message.offset as i64,
+        timestamp: (message.timestamp / 1000) as i64,
+        key: message.key,
+        value: Some(message.payload),
+        headers,
+    }
+}
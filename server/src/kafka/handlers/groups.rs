@@ -0,0 +1,165 @@
+use crate::kafka::codec::{Decoder, Encoder};
+use crate::kafka::coordinator::{JoinRequest, JoinResponse};
+use crate::kafka::handlers::{KafkaContext, NODE_ID};
+use crate::kafka::protocol::error_codes;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use std::time::Duration;
+
+/// The listener is the coordinator of all the consumer groups.
+pub(crate) fn handle_find_coordinator(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Bytes, IggyError> {
+    decoder.string()?;
+    if version >= 1 {
+        decoder.i8()?;
+    }
+
+    let mut encoder = Encoder::new();
+    if version >= 1 {
+        encoder.i32(0);
+    }
+    encoder.i16(error_codes::NONE);
+    if version >= 1 {
+        encoder.nullable_string(None);
+    }
+    encoder
+        .i32(NODE_ID)
+        .string(&context.local_address.ip().to_string())
+        .i32(context.local_address.port() as i32);
+    Ok(encoder.freeze())
+}
+
+pub(crate) async fn handle_join(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+    client_id: &str,
+) -> Result<Bytes, IggyError> {
+    let group_id = decoder.string()?;
+    let session_timeout = Duration::from_millis(decoder.i32()?.max(0) as u64);
+    let rebalance_timeout = if version >= 1 {
+        Duration::from_millis(decoder.i32()?.max(0) as u64)
+    } else {
+        session_timeout
+    };
+    let member_id = decoder.string()?;
+    if version >= 5 {
+        decoder.nullable_string()?;
+    }
+    let protocol_type = decoder.string()?;
+    let protocols = decoder.array(|decoder| Ok((decoder.string()?, decoder.bytes()?)))?;
+
+    let response = if group_id.is_empty() {
+        JoinResponse {
+            error_code: error_codes::INVALID_GROUP_ID,
+            generation: -1,
+            member_id,
+            ..Default::default()
+        }
+    } else {
+        context
+            .coordinator
+            .join(JoinRequest {
+                group_id,
+                member_id,
+                client_id: client_id.to_string(),
+                session_timeout,
+                rebalance_timeout,
+                protocol_type,
+                protocols,
+                require_known_member_id: version >= 4,
+            })
+            .await
+    };
+
+    let mut encoder = Encoder::new();
+    if version >= 2 {
+        encoder.i32(0);
+    }
+    encoder
+        .i16(response.error_code)
+        .i32(response.generation)
+        .string(&response.protocol)
+        .string(&response.leader)
+        .string(&response.member_id)
+        .array(&response.members, |encoder, (member_id, metadata)| {
+            encoder.string(member_id);
+            if version >= 5 {
+                encoder.nullable_string(None);
+            }
+            encoder.bytes(metadata);
+        });
+    Ok(encoder.freeze())
+}
+
+pub(crate) async fn handle_sync(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Bytes, IggyError> {
+    let group_id = decoder.string()?;
+    let generation = decoder.i32()?;
+    let member_id = decoder.string()?;
+    if version >= 3 {
+        decoder.nullable_string()?;
+    }
+    let assignments = decoder.array(|decoder| Ok((decoder.string()?, decoder.bytes()?)))?;
+
+    let (error_code, assignment) = context
+        .coordinator
+        .sync(&group_id, generation, &member_id, assignments)
+        .await;
+
+    let mut encoder = Encoder::new();
+    if version >= 1 {
+        encoder.i32(0);
+    }
+    encoder.i16(error_code).bytes(&assignment);
+    Ok(encoder.freeze())
+}
+
+pub(crate) async fn handle_heartbeat(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Bytes, IggyError> {
+    let group_id = decoder.string()?;
+    let generation = decoder.i32()?;
+    let member_id = decoder.string()?;
+    if version >= 3 {
+        decoder.nullable_string()?;
+    }
+
+    let error_code = context
+        .coordinator
+        .heartbeat(&group_id, generation, &member_id)
+        .await;
+
+    let mut encoder = Encoder::new();
+    if version >= 1 {
+        encoder.i32(0);
+    }
+    encoder.i16(error_code);
+    Ok(encoder.freeze())
+}
+
+pub(crate) async fn handle_leave(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Bytes, IggyError> {
+    let group_id = decoder.string()?;
+    let member_id = decoder.string()?;
+
+    let error_code = context.coordinator.leave(&group_id, &member_id).await;
+
+    let mut encoder = Encoder::new();
+    if version >= 1 {
+        encoder.i32(0);
+    }
+    encoder.i16(error_code);
+    Ok(encoder.freeze())
+}
@@ -0,0 +1,116 @@
+use crate::kafka::codec::{Decoder, Encoder};
+use crate::kafka::handlers::{get_watermarks, partition_id, KafkaContext};
+use crate::kafka::protocol::{self, error_codes};
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::systems::messages::PollingArgs;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+
+const LATEST_TIMESTAMP: i64 = -1;
+const EARLIEST_TIMESTAMP: i64 = -2;
+
+struct PartitionResponse {
+    partition_index: i32,
+    error_code: i16,
+    timestamp: i64,
+    offset: i64,
+}
+
+pub(crate) async fn handle(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Bytes, IggyError> {
+    decoder.i32()?;
+    if version >= 2 {
+        decoder.i8()?;
+    }
+    let topics = decoder.array(|decoder| {
+        let name = decoder.string()?;
+        let partitions = decoder.array(|decoder| {
+            let partition_index = decoder.i32()?;
+            if version >= 4 {
+                decoder.i32()?;
+            }
+            Ok((partition_index, decoder.i64()?))
+        })?;
+        Ok((name, partitions))
+    })?;
+
+    let mut responses = Vec::with_capacity(topics.len());
+    for (topic, partitions) in topics {
+        let mut partition_responses = Vec::with_capacity(partitions.len());
+        for (partition_index, timestamp) in partitions {
+            let (error_code, timestamp, offset) =
+                match list_offset(context, &topic, partition_index, timestamp).await {
+                    Ok((timestamp, offset)) => (error_codes::NONE, timestamp, offset),
+                    Err(error) => (protocol::map_error(&error), -1, -1),
+                };
+            partition_responses.push(PartitionResponse {
+                partition_index,
+                error_code,
+                timestamp,
+                offset,
+            });
+        }
+        responses.push((topic, partition_responses));
+    }
+
+    let mut encoder = Encoder::new();
+    if version >= 2 {
+        encoder.i32(0);
+    }
+    encoder.array(&responses, |encoder, (topic, partitions)| {
+        encoder.string(topic);
+        encoder.array(partitions, |encoder, partition| {
+            encoder
+                .i32(partition.partition_index)
+                .i16(partition.error_code)
+                .i64(partition.timestamp)
+                .i64(partition.offset);
+            if version >= 4 {
+                encoder.i32(-1);
+            }
+        });
+    });
+    Ok(encoder.freeze())
+}
+
+/// Returns the timestamp and offset of the first message with the timestamp equal or greater than the requested one,
+/// the special timestamps `-1` and `-2` stand for the latest (high watermark) and the earliest offsets.
+async fn list_offset(
+    context: &KafkaContext,
+    topic: &str,
+    partition_index: i32,
+    timestamp: i64,
+) -> Result<(i64, i64), IggyError> {
+    let (high_watermark, log_start_offset) =
+        get_watermarks(context, topic, partition_index).await?;
+    match timestamp {
+        LATEST_TIMESTAMP => return Ok((-1, high_watermark)),
+        EARLIEST_TIMESTAMP => return Ok((-1, log_start_offset)),
+        _ => {}
+    }
+
+    let system = context.system.read();
+    let polled_messages = system
+        .poll_messages(
+            &context.session,
+            PollingConsumer::Consumer(0, partition_id(partition_index)?),
+            &context.stream_id,
+            &Identifier::named(topic)?,
+            PollingArgs::new(
+                PollingStrategy::timestamp(timestamp.max(0) as u64 * 1000),
+                1,
+                false,
+            ),
+        )
+        .await?;
+    Ok(polled_messages
+        .messages
+        .first()
+        .map(|message| ((message.timestamp / 1000) as i64, message.offset as i64))
+        .unwrap_or((-1, -1)))
+}
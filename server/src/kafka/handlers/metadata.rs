@@ -0,0 +1,90 @@
+use crate::kafka::codec::{Decoder, Encoder};
+use crate::kafka::handlers::{KafkaContext, NODE_ID};
+use crate::kafka::protocol::{self, error_codes};
+use bytes::Bytes;
+use iggy::error::IggyError;
+
+pub(crate) async fn handle(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Bytes, IggyError> {
+    // In v0 the empty array means all the topics, since v1 it's the null array.
+    let requested = match decoder.nullable_array(|decoder| decoder.string())? {
+        Some(topics) if version == 0 && topics.is_empty() => None,
+        topics => topics,
+    };
+
+    // The requested topics which cannot be listed are reported with the error, e.g. the missing permissions.
+    let (existing, missing_error_code) = {
+        let system = context.system.read();
+        match system.find_topics(&context.session, &context.stream_id) {
+            Ok(topics) => (
+                topics
+                    .iter()
+                    .map(|topic| (topic.name.clone(), topic.get_partitions_count()))
+                    .collect::<Vec<_>>(),
+                error_codes::UNKNOWN_TOPIC_OR_PARTITION,
+            ),
+            Err(error) => (Vec::new(), protocol::map_error(&error)),
+        }
+    };
+    let topics = match requested {
+        Some(requested) => requested
+            .into_iter()
+            .map(|name| {
+                let partitions = existing
+                    .iter()
+                    .find(|(topic, _)| topic == &name)
+                    .map(|(_, partitions)| *partitions);
+                (name, partitions)
+            })
+            .collect::<Vec<_>>(),
+        None => existing
+            .into_iter()
+            .map(|(name, partitions)| (name, Some(partitions)))
+            .collect(),
+    };
+
+    let mut encoder = Encoder::new();
+    if version >= 3 {
+        encoder.i32(0);
+    }
+    encoder.i32(1).i32(NODE_ID);
+    encoder
+        .string(&context.local_address.ip().to_string())
+        .i32(context.local_address.port() as i32);
+    if version >= 1 {
+        encoder.nullable_string(None);
+    }
+    if version >= 2 {
+        encoder.nullable_string(Some("iggy"));
+    }
+    if version >= 1 {
+        encoder.i32(NODE_ID);
+    }
+    encoder.array(&topics, |encoder, (name, partitions)| {
+        encoder.i16(match partitions {
+            Some(_) => error_codes::NONE,
+            None => missing_error_code,
+        });
+        encoder.string(name);
+        if version >= 1 {
+            encoder.bool(false);
+        }
+        let partitions = (0..partitions.unwrap_or_default() as i32).collect::<Vec<_>>();
+        encoder.array(&partitions, |encoder, partition_index| {
+            encoder
+                .i16(error_codes::NONE)
+                .i32(*partition_index)
+                .i32(NODE_ID)
+                .array(&[NODE_ID], |encoder, node| {
+                    encoder.i32(*node);
+                })
+                .array(&[NODE_ID], |encoder, node| {
+                    encoder.i32(*node);
+                });
+        });
+    });
+    Ok(encoder.freeze())
+}
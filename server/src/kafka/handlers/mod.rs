@@ -0,0 +1,121 @@
+mod api_versions;
+mod fetch;
+mod groups;
+mod list_offsets;
+mod metadata;
+mod offsets;
+mod produce;
+mod sasl;
+
+use crate::kafka::codec::Decoder;
+use crate::kafka::coordinator::GroupCoordinator;
+use crate::kafka::protocol::{self, RequestHeader};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::warn;
+
+pub(crate) use sasl::SaslState;
+
+/// The listener is advertised as the only broker of the cluster, being the leader of all the partitions.
+pub(crate) const NODE_ID: i32 = 1;
+
+pub(crate) struct KafkaContext {
+    pub system: SharedSystem,
+    pub session: Session,
+    pub stream_id: Identifier,
+    pub coordinator: Arc<GroupCoordinator>,
+    pub local_address: SocketAddr,
+    pub sasl_state: SaslState,
+}
+
+/// Handles the request and returns the response body (without the header),
+/// or `None` if the request doesn't expect any response.
+pub(crate) async fn handle(
+    header: &RequestHeader,
+    decoder: &mut Decoder,
+    context: &mut KafkaContext,
+) -> Result<Option<Bytes>, IggyError> {
+    let version = header.api_version;
+    if header.api_key == protocol::API_VERSIONS {
+        return Ok(Some(api_versions::handle(version)));
+    }
+    if !protocol::is_supported(header.api_key, version) {
+        warn!(
+            "Unsupported Kafka API key: {}, version: {version}, client: {:?}.",
+            header.api_key, header.client_id
+        );
+        // The layout of the response of the unsupported version is unknown, so only the error code is returned.
+        return Ok(Some(api_versions::unsupported_version()));
+    }
+    match header.api_key {
+        protocol::SASL_HANDSHAKE => return sasl::handle_handshake(decoder, context).map(Some),
+        protocol::SASL_AUTHENTICATE => {
+            return sasl::handle_authenticate(version, decoder, context)
+                .await
+                .map(Some)
+        }
+        _ => {}
+    }
+    // Like the Kafka broker with the SASL listener, the unauthenticated connection is closed.
+    if !context.session.is_authenticated() {
+        warn!(
+            "Unauthenticated Kafka request, API key: {}, client: {:?}.",
+            header.api_key, header.client_id
+        );
+        return Err(IggyError::Unauthenticated);
+    }
+
+    let context = &*context;
+    let client_id = header.client_id.as_deref().unwrap_or_default();
+    let response = match header.api_key {
+        protocol::PRODUCE => return produce::handle(version, decoder, context).await,
+        protocol::FETCH => fetch::handle(version, decoder, context).await?,
+        protocol::LIST_OFFSETS => list_offsets::handle(version, decoder, context).await?,
+        protocol::METADATA => metadata::handle(version, decoder, context).await?,
+        protocol::OFFSET_COMMIT => offsets::handle_commit(version, decoder, context).await?,
+        protocol::OFFSET_FETCH => offsets::handle_fetch(version, decoder, context).await?,
+        protocol::FIND_COORDINATOR => groups::handle_find_coordinator(version, decoder, context)?,
+        protocol::JOIN_GROUP => groups::handle_join(version, decoder, context, client_id).await?,
+        protocol::SYNC_GROUP => groups::handle_sync(version, decoder, context).await?,
+        protocol::HEARTBEAT => groups::handle_heartbeat(version, decoder, context).await?,
+        protocol::LEAVE_GROUP => groups::handle_leave(version, decoder, context).await?,
+        _ => return Ok(Some(api_versions::unsupported_version())),
+    };
+    Ok(Some(response))
+}
+
+/// Maps the Kafka partition index (starting from 0) onto the Iggy partition ID (starting from 1).
+fn partition_id(partition_index: i32) -> Result<u32, IggyError> {
+    if partition_index < 0 {
+        return Err(IggyError::InvalidIdentifier);
+    }
+    Ok(partition_index as u32 + 1)
+}
+
+/// Returns the high watermark (the offset of the next message) and the log start offset of the partition.
+async fn get_watermarks(
+    context: &KafkaContext,
+    topic: &str,
+    partition_index: i32,
+) -> Result<(i64, i64), IggyError> {
+    let system = context.system.read();
+    let topic = system.find_topic(
+        &context.session,
+        &context.stream_id,
+        &Identifier::named(topic)?,
+    )?;
+    let partition = topic.get_partition(partition_id(partition_index)?)?;
+    let partition = partition.read().await;
+    let high_watermark = if partition.should_increment_offset {
+        partition.current_offset as i64 + 1
+    } else {
+        0
+    };
+    Ok((high_watermark, partition.low_watermark as i64))
+}
@@ -0,0 +1,219 @@
+use crate::kafka::codec::{Decoder, Encoder};
+use crate::kafka::handlers::{partition_id, KafkaContext};
+use crate::kafka::protocol::{self, error_codes};
+use crate::streaming::polling_consumer::PollingConsumer;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use tracing::debug;
+
+/// The offsets of the Kafka consumer group are stored as the offsets of the Iggy consumer
+/// with the same name. Kafka commits the offset of the next message to consume, while Iggy
+/// stores the offset of the last consumed one, hence the values are shifted by one.
+fn consumer_id(group_id: &str) -> Result<u32, IggyError> {
+    Ok(PollingConsumer::resolve_consumer_id(&Identifier::named(
+        group_id,
+    )?))
+}
+
+pub(crate) async fn handle_commit(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Bytes, IggyError> {
+    let group_id = decoder.string()?;
+    let generation = decoder.i32()?;
+    let member_id = decoder.string()?;
+    if version >= 7 {
+        decoder.nullable_string()?;
+    }
+    if version <= 4 {
+        decoder.i64()?;
+    }
+    let topics = decoder.array(|decoder| {
+        let name = decoder.string()?;
+        let partitions = decoder.array(|decoder| {
+            let partition_index = decoder.i32()?;
+            let offset = decoder.i64()?;
+            if version >= 6 {
+                decoder.i32()?;
+            }
+            decoder.nullable_string()?;
+            Ok((partition_index, offset))
+        })?;
+        Ok((name, partitions))
+    })?;
+
+    let group_error = match consumer_id(&group_id) {
+        Ok(_) => {
+            context
+                .coordinator
+                .validate_commit(&group_id, generation, &member_id)
+                .await
+        }
+        Err(_) => error_codes::INVALID_GROUP_ID,
+    };
+    let mut responses = Vec::with_capacity(topics.len());
+    for (topic, partitions) in topics {
+        let mut partition_responses = Vec::with_capacity(partitions.len());
+        for (partition_index, offset) in partitions {
+            let error_code = if group_error != error_codes::NONE {
+                group_error
+            } else {
+                match commit(context, &group_id, &topic, partition_index, offset).await {
+                    Ok(()) => error_codes::NONE,
+                    Err(error) => {
+                        debug!("Cannot commit Kafka offset for group: {group_id}, topic: {topic}, partition: {partition_index}, error: {error}");
+                        protocol::map_error(&error)
+                    }
+                }
+            };
+            partition_responses.push((partition_index, error_code));
+        }
+        responses.push((topic, partition_responses));
+    }
+
+    let mut encoder = Encoder::new();
+    if version >= 3 {
+        encoder.i32(0);
+    }
+    encoder.array(&responses, |encoder, (topic, partitions)| {
+        encoder.string(topic);
+        encoder.array(partitions, |encoder, (partition_index, error_code)| {
+            encoder.i32(*partition_index).i16(*error_code);
+        });
+    });
+    Ok(encoder.freeze())
+}
+
+async fn commit(
+    context: &KafkaContext,
+    group_id: &str,
+    topic: &str,
+    partition_index: i32,
+    offset: i64,
+) -> Result<(), IggyError> {
+    // Committing the offset 0 means that nothing has been consumed yet, which is the default state.
+    if offset <= 0 {
+        return Ok(());
+    }
+    let system = context.system.read();
+    system
+        .store_consumer_offset(
+            &context.session,
+            PollingConsumer::Consumer(consumer_id(group_id)?, partition_id(partition_index)?),
+            &context.stream_id,
+            &Identifier::named(topic)?,
+            offset as u64 - 1,
+        )
+        .await
+}
+
+pub(crate) async fn handle_fetch(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Bytes, IggyError> {
+    let group_id = decoder.string()?;
+    let requested = decoder.nullable_array(|decoder| {
+        let name = decoder.string()?;
+        let partitions = decoder.array(|decoder| decoder.i32())?;
+        Ok((name, partitions))
+    })?;
+
+    let (error_code, responses) = match consumer_id(&group_id) {
+        Ok(consumer_id) => {
+            // The null topics request all the committed offsets of the group.
+            let committed_only = requested.is_none();
+            let topics = requested.unwrap_or_else(|| all_partitions(context));
+            let mut responses = Vec::with_capacity(topics.len());
+            for (topic, partitions) in topics {
+                let mut partition_responses = Vec::with_capacity(partitions.len());
+                for partition_index in partitions {
+                    let response =
+                        match get_committed_offset(context, consumer_id, &topic, partition_index)
+                            .await
+                        {
+                            Ok(offset) => (partition_index, offset, error_codes::NONE),
+                            Err(error) => (partition_index, -1, protocol::map_error(&error)),
+                        };
+                    if committed_only && response.1 < 0 {
+                        continue;
+                    }
+                    partition_responses.push(response);
+                }
+                if committed_only && partition_responses.is_empty() {
+                    continue;
+                }
+                responses.push((topic, partition_responses));
+            }
+            (error_codes::NONE, responses)
+        }
+        Err(_) => (error_codes::INVALID_GROUP_ID, Vec::new()),
+    };
+
+    let mut encoder = Encoder::new();
+    if version >= 3 {
+        encoder.i32(0);
+    }
+    encoder.array(&responses, |encoder, (topic, partitions)| {
+        encoder.string(topic);
+        encoder.array(
+            partitions,
+            |encoder, (partition_index, offset, error_code)| {
+                encoder.i32(*partition_index).i64(*offset);
+                if version >= 5 {
+                    encoder.i32(-1);
+                }
+                encoder.nullable_string(Some("")).i16(*error_code);
+            },
+        );
+    });
+    if version >= 2 {
+        encoder.i16(error_code);
+    }
+    Ok(encoder.freeze())
+}
+
+fn all_partitions(context: &KafkaContext) -> Vec<(String, Vec<i32>)> {
+    let system = context.system.read();
+    match system.find_topics(&context.session, &context.stream_id) {
+        Ok(topics) => topics
+            .iter()
+            .map(|topic| {
+                let partitions = (0..topic.get_partitions_count() as i32).collect();
+                (topic.name.clone(), partitions)
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Returns the committed offset (the offset of the next message to consume) or `-1` if there's none.
+async fn get_committed_offset(
+    context: &KafkaContext,
+    consumer_id: u32,
+    topic: &str,
+    partition_index: i32,
+) -> Result<i64, IggyError> {
+    let system = context.system.read();
+    let topic = system.find_topic(
+        &context.session,
+        &context.stream_id,
+        &Identifier::named(topic)?,
+    )?;
+    system.permissioner.get_consumer_offset(
+        context.session.get_user_id(),
+        topic.stream_id,
+        topic.topic_id,
+    )?;
+    let partition = topic.get_partition(partition_id(partition_index)?)?;
+    let partition = partition.read().await;
+    let offset = partition
+        .consumer_offsets
+        .get(&consumer_id)
+        .map(|offset| offset.offset as i64 + 1)
+        .unwrap_or(-1);
+    Ok(offset)
+}
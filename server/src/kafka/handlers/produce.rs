@@ -0,0 +1,203 @@
+use crate::kafka::codec::{Decoder, Encoder};
+use crate::kafka::handlers::{get_watermarks, partition_id, KafkaContext};
+use crate::kafka::protocol::{self, error_codes};
+use crate::kafka::records::{self, Record, RecordsError};
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+use tracing::{debug, warn};
+
+const ACKS_NONE: i16 = 0;
+const ACKS_ALL: i16 = -1;
+// The messages key is stored with its length encoded on a single byte.
+const MAX_KEY_LENGTH: usize = 255;
+
+struct PartitionResponse {
+    partition_index: i32,
+    error_code: i16,
+    base_offset: i64,
+    log_start_offset: i64,
+}
+
+pub(crate) async fn handle(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &KafkaContext,
+) -> Result<Option<Bytes>, IggyError> {
+    decoder.nullable_string()?;
+    let acks = decoder.i16()?;
+    decoder.i32()?;
+    let topics = decoder.array(|decoder| {
+        let name = decoder.string()?;
+        let partitions =
+            decoder.array(|decoder| Ok((decoder.i32()?, decoder.nullable_bytes()?)))?;
+        Ok((name, partitions))
+    })?;
+
+    let mut responses = Vec::with_capacity(topics.len());
+    for (topic, partitions) in topics {
+        let mut partition_responses = Vec::with_capacity(partitions.len());
+        for (partition_index, records) in partitions {
+            let response = produce(
+                context,
+                &topic,
+                partition_index,
+                records.unwrap_or_default(),
+                acks == ACKS_ALL,
+            )
+            .await;
+            partition_responses.push(response);
+        }
+        responses.push((topic, partition_responses));
+    }
+
+    if acks == ACKS_NONE {
+        return Ok(None);
+    }
+
+    let mut encoder = Encoder::new();
+    encoder.array(&responses, |encoder, (topic, partitions)| {
+        encoder.string(topic);
+        encoder.array(partitions, |encoder, partition| {
+            encoder
+                .i32(partition.partition_index)
+                .i16(partition.error_code)
+                .i64(partition.base_offset)
+                .i64(-1);
+            if version >= 5 {
+                encoder.i64(partition.log_start_offset);
+            }
+        });
+    });
+    encoder.i32(0);
+    Ok(Some(encoder.freeze()))
+}
+
+async fn produce(
+    context: &KafkaContext,
+    topic: &str,
+    partition_index: i32,
+    records: Bytes,
+    wait_for_durability: bool,
+) -> PartitionResponse {
+    let mut response = PartitionResponse {
+        partition_index,
+        error_code: error_codes::NONE,
+        base_offset: -1,
+        log_start_offset: -1,
+    };
+    let records = match records::decode(records) {
+        Ok(records) => records,
+        Err(error) => {
+            warn!("Invalid Kafka records for topic: {topic}, partition: {partition_index}, error: {error:?}");
+            response.error_code = match error {
+                RecordsError::Malformed | RecordsError::CorruptMessage => {
+                    error_codes::CORRUPT_MESSAGE
+                }
+                RecordsError::UnsupportedMagic => error_codes::UNSUPPORTED_FOR_MESSAGE_FORMAT,
+                RecordsError::UnsupportedCompression => error_codes::UNSUPPORTED_COMPRESSION_TYPE,
+            };
+            return response;
+        }
+    };
+    let messages = match records.into_iter().map(map_record).collect() {
+        Ok(messages) => messages,
+        Err(error) => {
+            warn!("Invalid Kafka record for topic: {topic}, partition: {partition_index}, error: {error}");
+            response.error_code = error_codes::INVALID_RECORD;
+            return response;
+        }
+    };
+
+    match append(
+        context,
+        topic,
+        partition_index,
+        messages,
+        wait_for_durability,
+    )
+    .await
+    {
+        Ok((base_offset, log_start_offset)) => {
+            response.base_offset = base_offset;
+            response.log_start_offset = log_start_offset;
+        }
+        Err(error) => {
+            debug!("Cannot append Kafka records to topic: {topic}, partition: {partition_index}, error: {error}");
+            response.error_code = protocol::map_error(&error);
+        }
+    }
+    response
+}
+
+/// Appends the records with the same key in a row as the single batch, so that the key is kept with each message.
+async fn append(
+    context: &KafkaContext,
+    topic: &str,
+    partition_index: i32,
+    messages: Vec<(Option<Bytes>, Message)>,
+    wait_for_durability: bool,
+) -> Result<(i64, i64), IggyError> {
+    let count = messages.len() as i64;
+    let mut batches: Vec<(Option<Bytes>, Vec<Message>)> = Vec::new();
+    for (key, message) in messages {
+        match batches.last_mut() {
+            Some((batch_key, batch)) if *batch_key == key => batch.push(message),
+            _ => batches.push((key, vec![message])),
+        }
+    }
+
+    let topic_id = Identifier::named(topic)?;
+    let partition_id = partition_id(partition_index)?;
+    for (key, messages) in batches {
+        let (_, durability_waiter) = {
+            let system = context.system.read();
+            system
+                .append_keyed_messages(
+                    &context.session,
+                    context.stream_id.clone(),
+                    topic_id.clone(),
+                    Partitioning::partition_id(partition_id),
+                    key,
+                    messages,
+                    wait_for_durability,
+                )
+                .await?
+        };
+        if let Some(durability_waiter) = durability_waiter {
+            durability_waiter.wait().await?;
+        }
+    }
+    let (high_watermark, log_start_offset) =
+        get_watermarks(context, topic, partition_index).await?;
+    Ok((high_watermark - count, log_start_offset))
+}
+
+/// Maps the Kafka record onto the Iggy message along with its key,
+/// and the timestamp is converted from milliseconds to microseconds.
+fn map_record(record: Record) -> Result<(Option<Bytes>, Message), IggyError> {
+    let mut headers = HashMap::new();
+    for (key, value) in record.headers {
+        // Iggy doesn't support the empty header values, so such headers are skipped.
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            headers.insert(HeaderKey::new(&key)?, HeaderValue::from_raw(&value)?);
+        }
+    }
+    let key = record.key.filter(|key| !key.is_empty());
+    if key.as_ref().is_some_and(|key| key.len() > MAX_KEY_LENGTH) {
+        return Err(IggyError::InvalidKeyValueLength);
+    }
+    let headers = if headers.is_empty() {
+        None
+    } else {
+        Some(headers)
+    };
+    let message = Message::new(None, record.value.unwrap_or_default(), headers);
+    if record.timestamp > 0 {
+        return Ok((key, message.with_timestamp(record.timestamp as u64 * 1000)));
+    }
+    Ok((key, message))
+}
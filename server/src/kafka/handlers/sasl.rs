@@ -0,0 +1,113 @@
+use crate::kafka::codec::{Decoder, Encoder};
+use crate::kafka::handlers::KafkaContext;
+use crate::kafka::protocol::error_codes;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use tracing::warn;
+
+const PLAIN_MECHANISM: &str = "PLAIN";
+
+/// State of the SASL authentication of the Kafka connection.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) enum SaslState {
+    /// The mechanism hasn't been negotiated yet.
+    #[default]
+    Initial,
+    /// The PLAIN mechanism has been negotiated, the credentials are expected next.
+    Handshaken,
+    /// The connection is authenticated as the Iggy user.
+    Authenticated,
+    /// The authentication has failed, the connection is closed once the response is sent.
+    Failed,
+}
+
+pub(crate) fn handle_handshake(
+    decoder: &mut Decoder,
+    context: &mut KafkaContext,
+) -> Result<Bytes, IggyError> {
+    let mechanism = decoder.string()?;
+    let error_code = if context.sasl_state != SaslState::Initial {
+        error_codes::ILLEGAL_SASL_STATE
+    } else if mechanism != PLAIN_MECHANISM {
+        warn!("Unsupported Kafka SASL mechanism: {mechanism}.");
+        error_codes::UNSUPPORTED_SASL_MECHANISM
+    } else {
+        context.sasl_state = SaslState::Handshaken;
+        error_codes::NONE
+    };
+
+    let mut encoder = Encoder::new();
+    encoder
+        .i16(error_code)
+        .array(&[PLAIN_MECHANISM], |encoder, mechanism| {
+            encoder.string(mechanism);
+        });
+    Ok(encoder.freeze())
+}
+
+/// Logs in the Iggy user with the SASL/PLAIN credentials, so that all the requests
+/// sent over the connection are authorized with the permissions of this user.
+pub(crate) async fn handle_authenticate(
+    version: i16,
+    decoder: &mut Decoder,
+    context: &mut KafkaContext,
+) -> Result<Bytes, IggyError> {
+    let auth_bytes = decoder.bytes()?;
+    let (error_code, error_message) = if context.sasl_state != SaslState::Handshaken {
+        (
+            error_codes::ILLEGAL_SASL_STATE,
+            Some("The SASL handshake must precede the authentication."),
+        )
+    } else {
+        match authenticate(&auth_bytes, context).await {
+            Ok(()) => {
+                context.sasl_state = SaslState::Authenticated;
+                (error_codes::NONE, None)
+            }
+            Err(error) => {
+                warn!(
+                    "Kafka SASL authentication of client: {} has failed, error: {error}",
+                    context.session.ip_address
+                );
+                context.sasl_state = SaslState::Failed;
+                (
+                    error_codes::SASL_AUTHENTICATION_FAILED,
+                    Some("Authentication failed: invalid credentials."),
+                )
+            }
+        }
+    };
+
+    let mut encoder = Encoder::new();
+    encoder
+        .i16(error_code)
+        .nullable_string(error_message)
+        .bytes(&[]);
+    if version >= 1 {
+        // The session doesn't expire, so there's no need for the re-authentication.
+        encoder.i64(0);
+    }
+    Ok(encoder.freeze())
+}
+
+/// The PLAIN message consists of the authorization identity, username and password separated with the NUL characters.
+async fn authenticate(auth_bytes: &[u8], context: &KafkaContext) -> Result<(), IggyError> {
+    let mut parts = auth_bytes.split(|byte| *byte == 0);
+    let (Some(_), Some(username), Some(password), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(IggyError::InvalidCredentials);
+    };
+    let (Ok(username), Ok(password)) =
+        (std::str::from_utf8(username), std::str::from_utf8(password))
+    else {
+        return Err(IggyError::InvalidCredentials);
+    };
+
+    context
+        .system
+        .read()
+        .login_user(username, password, Some(&context.session))
+        .await?;
+    Ok(())
+}
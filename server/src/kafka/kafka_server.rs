@@ -0,0 +1,132 @@
+use crate::configs::kafka::KafkaConfig;
+use crate::kafka::codec::{Decoder, Encoder};
+use crate::kafka::coordinator::GroupCoordinator;
+use crate::kafka::handlers::{self, KafkaContext, SaslState};
+use crate::kafka::protocol::RequestHeader;
+use crate::server_error::ServerError;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::{Bytes, BytesMut};
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, error, info};
+
+const MAX_REQUEST_SIZE: usize = 100 * 1024 * 1024;
+
+/// Starts the Kafka wire-protocol compatibility listener.
+/// Returns the address the listener is bound to.
+pub async fn start(config: KafkaConfig, system: SharedSystem) -> SocketAddr {
+    info!("Initializing Iggy Kafka listener...");
+    let stream_id =
+        Identifier::named(&config.stream).expect("Invalid stream name for Kafka listener.");
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .expect("Unable to start Kafka listener.");
+    let address = listener
+        .local_addr()
+        .expect("Failed to get local address for Kafka listener");
+    let config = Arc::new(config);
+    let coordinator = Arc::new(GroupCoordinator::new(
+        config.initial_rebalance_delay.get_duration(),
+    ));
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    info!("Accepted new Kafka connection: {address}");
+                    let system = system.clone();
+                    let config = config.clone();
+                    let stream_id = stream_id.clone();
+                    let coordinator = coordinator.clone();
+                    tokio::spawn(async move {
+                        let client_id = system.read().add_client(&address, Transport::Kafka).await;
+                        let mut context = KafkaContext {
+                            session: Session::from_client_id(client_id, address),
+                            local_address: stream.local_addr().unwrap_or(address),
+                            system: system.clone(),
+                            stream_id,
+                            coordinator,
+                            sasl_state: SaslState::default(),
+                        };
+                        if let Err(error) = handle_connection(stream, &mut context, &config).await {
+                            match error {
+                                ServerError::IoError(error)
+                                    if error.kind() == std::io::ErrorKind::UnexpectedEof =>
+                                {
+                                    info!("Kafka connection: {address} has been closed.")
+                                }
+                                error => error!("Kafka connection: {address} has failed: {error}"),
+                            }
+                        }
+                        system.read().delete_client(&address).await;
+                    });
+                }
+                Err(error) => error!("Unable to accept Kafka connection, error: {error}"),
+            }
+        }
+    });
+    info!("Iggy Kafka listener has started on: {:?}", address);
+    address
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    context: &mut KafkaContext,
+    config: &KafkaConfig,
+) -> Result<(), ServerError> {
+    // The clients not using SASL act on behalf of the configured user (if any), the SASL ones log in as their own user.
+    if !config.username.is_empty() {
+        context
+            .system
+            .read()
+            .login_user(&config.username, &config.password, Some(&context.session))
+            .await?;
+    }
+
+    loop {
+        let length = stream.read_i32().await?;
+        if length < 0 || length as usize > MAX_REQUEST_SIZE {
+            return Err(ServerError::from(IggyError::InvalidCommand));
+        }
+        let mut request = BytesMut::zeroed(length as usize);
+        stream.read_exact(&mut request).await?;
+
+        let mut decoder = Decoder::new(request.freeze());
+        let header = RequestHeader::decode(&mut decoder)?;
+        debug!(
+            "Received a Kafka request, API key: {}, version: {}, correlation ID: {}",
+            header.api_key, header.api_version, header.correlation_id
+        );
+        let Some(body) = handlers::handle(&header, &mut decoder, context).await? else {
+            continue;
+        };
+        write_response(&mut stream, header.correlation_id, body).await?;
+        if context.sasl_state == SaslState::Failed {
+            info!(
+                "Closing Kafka connection: {} after the failed authentication.",
+                context.session.ip_address
+            );
+            return Ok(());
+        }
+    }
+}
+
+async fn write_response(
+    stream: &mut TcpStream,
+    correlation_id: i32,
+    body: Bytes,
+) -> Result<(), ServerError> {
+    let mut encoder = Encoder::new();
+    encoder
+        .i32(body.len() as i32 + 4)
+        .i32(correlation_id)
+        .raw(&body);
+    stream.write_all(&encoder.freeze()).await?;
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod codec;
+mod coordinator;
+mod handlers;
+pub mod kafka_server;
+pub mod protocol;
+pub mod records;
@@ -0,0 +1,109 @@
+use crate::kafka::codec::Decoder;
+use iggy::error::IggyError;
+
+pub const PRODUCE: i16 = 0;
+pub const FETCH: i16 = 1;
+pub const LIST_OFFSETS: i16 = 2;
+pub const METADATA: i16 = 3;
+pub const OFFSET_COMMIT: i16 = 8;
+pub const OFFSET_FETCH: i16 = 9;
+pub const FIND_COORDINATOR: i16 = 10;
+pub const JOIN_GROUP: i16 = 11;
+pub const HEARTBEAT: i16 = 12;
+pub const LEAVE_GROUP: i16 = 13;
+pub const SYNC_GROUP: i16 = 14;
+pub const SASL_HANDSHAKE: i16 = 17;
+pub const API_VERSIONS: i16 = 18;
+pub const SASL_AUTHENTICATE: i16 = 36;
+
+/// Supported APIs with their minimal and maximal versions. Only the versions
+/// preceding the flexible (tagged fields) encoding are implemented, and the SASL
+/// handshake v0 (followed by the unframed SASL tokens) isn't supported.
+pub const SUPPORTED_APIS: [(i16, i16, i16); 14] = [
+    (PRODUCE, 3, 7),
+    (FETCH, 4, 11),
+    (LIST_OFFSETS, 1, 5),
+    (METADATA, 0, 4),
+    (OFFSET_COMMIT, 2, 7),
+    (OFFSET_FETCH, 1, 5),
+    (FIND_COORDINATOR, 0, 2),
+    (JOIN_GROUP, 0, 5),
+    (HEARTBEAT, 0, 3),
+    (LEAVE_GROUP, 0, 2),
+    (SYNC_GROUP, 0, 3),
+    (SASL_HANDSHAKE, 1, 1),
+    (API_VERSIONS, 0, 2),
+    (SASL_AUTHENTICATE, 0, 1),
+];
+
+pub fn is_supported(api_key: i16, api_version: i16) -> bool {
+    SUPPORTED_APIS
+        .iter()
+        .any(|(key, min, max)| *key == api_key && (*min..=*max).contains(&api_version))
+}
+
+pub mod error_codes {
+    pub const UNKNOWN_SERVER_ERROR: i16 = -1;
+    pub const NONE: i16 = 0;
+    pub const OFFSET_OUT_OF_RANGE: i16 = 1;
+    pub const CORRUPT_MESSAGE: i16 = 2;
+    pub const UNKNOWN_TOPIC_OR_PARTITION: i16 = 3;
+    pub const ILLEGAL_GENERATION: i16 = 22;
+    pub const INCONSISTENT_GROUP_PROTOCOL: i16 = 23;
+    pub const INVALID_GROUP_ID: i16 = 24;
+    pub const UNKNOWN_MEMBER_ID: i16 = 25;
+    pub const REBALANCE_IN_PROGRESS: i16 = 27;
+    pub const TOPIC_AUTHORIZATION_FAILED: i16 = 29;
+    pub const UNSUPPORTED_SASL_MECHANISM: i16 = 33;
+    pub const ILLEGAL_SASL_STATE: i16 = 34;
+    pub const UNSUPPORTED_VERSION: i16 = 35;
+    pub const INVALID_REQUEST: i16 = 42;
+    pub const UNSUPPORTED_FOR_MESSAGE_FORMAT: i16 = 43;
+    pub const UNSUPPORTED_COMPRESSION_TYPE: i16 = 76;
+    pub const SASL_AUTHENTICATION_FAILED: i16 = 58;
+    pub const MEMBER_ID_REQUIRED: i16 = 79;
+    pub const INVALID_RECORD: i16 = 87;
+}
+
+/// Maps the Iggy error onto the closest Kafka error code.
+pub fn map_error(error: &IggyError) -> i16 {
+    match error {
+        IggyError::StreamIdNotFound(_)
+        | IggyError::StreamNameNotFound(_)
+        | IggyError::TopicIdNotFound(_, _)
+        | IggyError::TopicNameNotFound(_, _)
+        | IggyError::PartitionNotFound(_, _, _)
+        | IggyError::NoPartitions(_, _)
+        | IggyError::InvalidIdentifier => error_codes::UNKNOWN_TOPIC_OR_PARTITION,
+        IggyError::Unauthenticated | IggyError::Unauthorized => {
+            error_codes::TOPIC_AUTHORIZATION_FAILED
+        }
+        _ => error_codes::UNKNOWN_SERVER_ERROR,
+    }
+}
+
+/// Request header v1, shared by all the non-flexible API versions.
+#[derive(Debug)]
+pub struct RequestHeader {
+    pub api_key: i16,
+    pub api_version: i16,
+    pub correlation_id: i32,
+    pub client_id: Option<String>,
+}
+
+impl RequestHeader {
+    pub fn decode(decoder: &mut Decoder) -> Result<Self, IggyError> {
+        let api_key = decoder.i16()?;
+        let api_version = decoder.i16()?;
+        let correlation_id = decoder.i32()?;
+        // The flexible versions use the header v2 with the tagged fields,
+        // the client ID is still read, as it precedes them.
+        let client_id = decoder.nullable_string()?;
+        Ok(Self {
+            api_key,
+            api_version,
+            correlation_id,
+            client_id,
+        })
+    }
+}
@@ -0,0 +1,286 @@
+use crate::kafka::codec::{Decoder, Encoder};
+use bytes::Bytes;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use iggy::error::IggyError;
+use std::io::{Read, Write};
+
+const MAGIC: i8 = 2;
+const COMPRESSION_MASK: i16 = 0x07;
+const CONTROL_FLAG: i16 = 0x20;
+// Base offset + batch length.
+const BATCH_OVERHEAD: usize = 12;
+// Partition leader epoch + magic + CRC.
+const CRC_OFFSET: usize = 9;
+
+/// Compression codec of the record batch, only `none` and `gzip` are supported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecordsCompression {
+    None,
+    Gzip,
+}
+
+/// Single record of the Kafka record batch (magic v2) with the absolute offset and timestamp.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub offset: i64,
+    pub timestamp: i64,
+    pub key: Option<Bytes>,
+    pub value: Option<Bytes>,
+    pub headers: Vec<(String, Option<Bytes>)>,
+}
+
+#[derive(Debug)]
+pub enum RecordsError {
+    Malformed,
+    CorruptMessage,
+    UnsupportedMagic,
+    UnsupportedCompression,
+}
+
+impl From<IggyError> for RecordsError {
+    fn from(_: IggyError) -> Self {
+        RecordsError::Malformed
+    }
+}
+
+/// Decodes all the record batches contained in the `records` field of the produce request,
+/// verifying their checksums and skipping the control batches.
+pub fn decode(records: Bytes) -> Result<Vec<Record>, RecordsError> {
+    let mut decoder = Decoder::new(records);
+    let mut decoded = Vec::new();
+    while decoder.remaining() > 0 {
+        let base_offset = decoder.i64()?;
+        let length = decoder.i32()?;
+        if length < CRC_OFFSET as i32 {
+            return Err(RecordsError::Malformed);
+        }
+        let mut batch = Decoder::new(decoder.raw(length as usize)?);
+        batch.i32()?;
+        if batch.i8()? != MAGIC {
+            return Err(RecordsError::UnsupportedMagic);
+        }
+        let crc = batch.u32()?;
+        let checksummed = batch.raw(batch.remaining())?;
+        if crc32c(&checksummed) != crc {
+            return Err(RecordsError::CorruptMessage);
+        }
+
+        let mut batch = Decoder::new(checksummed);
+        let attributes = batch.i16()?;
+        batch.i32()?;
+        let base_timestamp = batch.i64()?;
+        // Max timestamp, producer ID, producer epoch, base sequence.
+        batch.raw(8 + 8 + 2 + 4)?;
+        let count = batch.i32()?;
+        if attributes & CONTROL_FLAG != 0 {
+            continue;
+        }
+
+        let payload = batch.raw(batch.remaining())?;
+        let mut batch = match attributes & COMPRESSION_MASK {
+            0 => Decoder::new(payload),
+            1 => {
+                let mut uncompressed = Vec::new();
+                GzDecoder::new(payload.as_ref())
+                    .read_to_end(&mut uncompressed)
+                    .map_err(|_| RecordsError::CorruptMessage)?;
+                Decoder::new(Bytes::from(uncompressed))
+            }
+            _ => return Err(RecordsError::UnsupportedCompression),
+        };
+
+        for _ in 0..count {
+            decoded.push(decode_record(&mut batch, base_offset, base_timestamp)?);
+        }
+    }
+    Ok(decoded)
+}
+
+fn decode_record(
+    batch: &mut Decoder,
+    base_offset: i64,
+    base_timestamp: i64,
+) -> Result<Record, RecordsError> {
+    let length = batch.varint()?;
+    if length < 0 {
+        return Err(RecordsError::Malformed);
+    }
+    let mut record = Decoder::new(batch.raw(length as usize)?);
+    record.i8()?;
+    let timestamp_delta = record.varlong()?;
+    let offset_delta = record.varint()?;
+    let key = record.varint_bytes()?;
+    let value = record.varint_bytes()?;
+    let headers_count = record.varint()?;
+    let mut headers = Vec::with_capacity(headers_count.max(0) as usize);
+    for _ in 0..headers_count {
+        let header_key = record.varint_bytes()?.ok_or(RecordsError::Malformed)?;
+        let header_key = std::str::from_utf8(&header_key)
+            .map_err(|_| RecordsError::Malformed)?
+            .to_string();
+        headers.push((header_key, record.varint_bytes()?));
+    }
+    Ok(Record {
+        offset: base_offset + offset_delta as i64,
+        timestamp: base_timestamp + timestamp_delta,
+        key,
+        value,
+        headers,
+    })
+}
+
+/// Encodes the records into a single record batch, the first record determines the base offset and timestamp.
+pub fn encode(records: &[Record], compression: RecordsCompression) -> Bytes {
+    let mut encoder = Encoder::new();
+    if records.is_empty() {
+        return encoder.freeze();
+    }
+
+    let base_offset = records[0].offset;
+    let base_timestamp = records[0].timestamp;
+    let max_timestamp = records.iter().map(|record| record.timestamp).max().unwrap();
+    let mut payload = Encoder::new();
+    for record in records {
+        let mut body = Encoder::new();
+        body.i8(0)
+            .varlong(record.timestamp - base_timestamp)
+            .varint((record.offset - base_offset) as i32)
+            .varint_bytes(record.key.as_deref())
+            .varint_bytes(record.value.as_deref())
+            .varint(record.headers.len() as i32);
+        for (key, value) in &record.headers {
+            body.varint_bytes(Some(key.as_bytes()))
+                .varint_bytes(value.as_deref());
+        }
+        let body = body.freeze();
+        payload.varint(body.len() as i32).raw(&body);
+    }
+    let payload = match compression {
+        RecordsCompression::None => payload.freeze(),
+        RecordsCompression::Gzip => {
+            let mut gzip = GzEncoder::new(Vec::new(), Compression::default());
+            gzip.write_all(&payload.freeze())
+                .and_then(|_| gzip.finish())
+                .map(Bytes::from)
+                .expect("Writing to the in-memory buffer cannot fail.")
+        }
+    };
+
+    let mut checksummed = Encoder::new();
+    checksummed
+        .i16(match compression {
+            RecordsCompression::None => 0,
+            RecordsCompression::Gzip => 1,
+        })
+        .i32((records[records.len() - 1].offset - base_offset) as i32)
+        .i64(base_timestamp)
+        .i64(max_timestamp)
+        .i64(-1)
+        .i16(-1)
+        .i32(-1)
+        .i32(records.len() as i32)
+        .raw(&payload);
+    let checksummed = checksummed.freeze();
+
+    encoder
+        .i64(base_offset)
+        .i32((CRC_OFFSET + checksummed.len()) as i32)
+        .i32(0)
+        .i8(MAGIC)
+        .u32(crc32c(&checksummed))
+        .raw(&checksummed);
+    debug_assert_eq!(
+        encoder.len(),
+        BATCH_OVERHEAD + CRC_OFFSET + checksummed.len()
+    );
+    encoder.freeze()
+}
+
+const CRC32C_TABLE: [u32; 256] = crc32c_table();
+
+const fn crc32c_table() -> [u32; 256] {
+    // Reversed Castagnoli polynomial.
+    const POLYNOMIAL: u32 = 0x82f6_3b78;
+    let mut table = [0u32; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+}
+
+/// CRC-32C (Castagnoli) checksum used by the record batches.
+pub fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc = CRC32C_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32c_should_match_the_check_value() {
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+    }
+
+    #[test]
+    fn records_should_be_encoded_and_decoded() {
+        for compression in [RecordsCompression::None, RecordsCompression::Gzip] {
+            let records = vec![
+                Record {
+                    offset: 10,
+                    timestamp: 1_000,
+                    key: Some(Bytes::from_static(b"key")),
+                    value: Some(Bytes::from_static(b"value-1")),
+                    headers: vec![("header".to_string(), Some(Bytes::from_static(b"1")))],
+                },
+                Record {
+                    offset: 11,
+                    timestamp: 1_005,
+                    key: None,
+                    value: None,
+                    headers: vec![],
+                },
+            ];
+
+            let decoded = decode(encode(&records, compression)).unwrap();
+            assert_eq!(decoded, records);
+        }
+    }
+
+    #[test]
+    fn corrupted_batch_should_be_rejected() {
+        let records = vec![Record {
+            offset: 0,
+            timestamp: 0,
+            key: None,
+            value: Some(Bytes::from_static(b"value")),
+            headers: vec![],
+        }];
+        let mut batch = encode(&records, RecordsCompression::None).to_vec();
+        let last = batch.len() - 1;
+        batch[last] ^= 0xff;
+
+        assert!(matches!(
+            decode(Bytes::from(batch)),
+            Err(RecordsError::CorruptMessage)
+        ));
+    }
+}
@@ -13,9 +13,15 @@ pub mod binary;
 pub mod channels;
 pub(crate) mod compat;
 pub mod configs;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod http;
+pub mod kafka;
 pub mod log;
+pub mod mqtt;
 pub mod quic;
 pub mod server_error;
 pub mod streaming;
 pub mod tcp;
+#[cfg(unix)]
+pub mod unix;
@@ -1,5 +1,9 @@
-use crate::configs::system::LoggingConfig;
+use crate::configs::server::TelemetryConfig;
+use crate::configs::system::{LogFormat, LoggingConfig};
+use crate::log::rolling_file::RollingFileWriter;
+use crate::log::telemetry;
 use crate::server_error::ServerError;
+use opentelemetry_sdk::trace::TracerProvider;
 use std::io::{self, Write};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -7,11 +11,11 @@ use std::sync::{Arc, Mutex};
 use tracing::{event, info, trace, Level};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{
-    filter::LevelFilter, fmt, fmt::format::Format, fmt::MakeWriter, prelude::*, reload,
-    reload::Handle, Layer, Registry,
+    filter::LevelFilter, fmt, fmt::format::Format, fmt::MakeWriter, layer::Identity, prelude::*,
+    reload, reload::Handle, Layer, Registry,
 };
 
-const IGGY_LOG_FILE_PREFIX: &str = "iggy-server.log";
+const IGGY_LOG_FILE_NAME: &str = "iggy-server.log";
 
 // Writer that does nothing
 struct NullWriter;
@@ -84,8 +88,12 @@ pub struct Logging {
     file_guard: Option<WorkerGuard>,
     file_reload_handle: Option<ReloadHandle>,
 
+    telemetry_reload_handle: Option<ReloadHandle>,
+    tracer_provider: Option<TracerProvider>,
+
     filtering_stdout_reload_handle: Option<ReloadHandle>,
     filtering_file_reload_handle: Option<ReloadHandle>,
+    filtering_telemetry_reload_handle: Option<ReloadHandle>,
 
     early_logs_buffer: Arc<Mutex<Vec<String>>>,
 }
@@ -97,8 +105,11 @@ impl Logging {
             stdout_reload_handle: None,
             file_guard: None,
             file_reload_handle: None,
+            telemetry_reload_handle: None,
+            tracer_provider: None,
             filtering_stdout_reload_handle: None,
             filtering_file_reload_handle: None,
+            filtering_telemetry_reload_handle: None,
             early_logs_buffer: Arc::new(Mutex::new(vec![])),
         }
     }
@@ -108,6 +119,7 @@ impl Logging {
         // First layer is filtering based on severity
         // Second layer will just consume drain log entries and has first layer as a dependency
         // Third layer will write to a safe buffer and has first layer as a dependency
+        // Fourth layer will export the spans to the OpenTelemetry collector, if enabled
         // All layers will be replaced during late_init
         let mut layers = vec![];
 
@@ -120,6 +132,10 @@ impl Logging {
             reload::Layer::new(filtering_level.boxed());
         self.filtering_file_reload_handle = Some(filtering_file_reload_handle);
 
+        let (filtering_telemetry_layer, filtering_telemetry_reload_handle) =
+            reload::Layer::new(filtering_level.boxed());
+        self.filtering_telemetry_reload_handle = Some(filtering_telemetry_reload_handle);
+
         let stdout_layer = fmt::Layer::default()
             .event_format(Self::get_log_format())
             .with_writer(|| NullWriter);
@@ -136,6 +152,11 @@ impl Logging {
         self.file_reload_handle = Some(file_layer_reload_handle);
         layers.push(file_layer.and_then(filtering_file_layer));
 
+        let (telemetry_layer, telemetry_layer_reload_handle) =
+            reload::Layer::new(Identity::new().boxed());
+        self.telemetry_reload_handle = Some(telemetry_layer_reload_handle);
+        layers.push(telemetry_layer.and_then(filtering_telemetry_layer));
+
         let subscriber = tracing_subscriber::registry().with(layers);
 
         tracing::subscriber::set_global_default(subscriber)
@@ -162,6 +183,7 @@ impl Logging {
         &mut self,
         base_directory: String,
         config: &LoggingConfig,
+        telemetry_config: &TelemetryConfig,
     ) -> Result<(), ServerError> {
         // Write to stdout and file at the same time.
         // Use the non_blocking appender to avoid blocking the threads.
@@ -184,12 +206,21 @@ impl Logging {
             .modify(|layer| *layer = filtering_level.boxed())
             .expect("Failed to modify file filtering layer");
 
+        self.filtering_telemetry_reload_handle
+            .as_ref()
+            .ok_or(ServerError::FilterReloadFailure)?
+            .modify(|layer| *layer = filtering_level.boxed())
+            .expect("Failed to modify telemetry filtering layer");
+
         // Initialize non-blocking stdout layer
         let (_, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
-        let stdout_layer = fmt::Layer::default()
-            .with_ansi(true)
-            .event_format(Self::get_log_format())
-            .boxed();
+        let stdout_layer = match config.format {
+            LogFormat::Text => fmt::Layer::default()
+                .with_ansi(true)
+                .event_format(Self::get_log_format())
+                .boxed(),
+            LogFormat::Json => fmt::Layer::default().json().with_thread_ids(true).boxed(),
+        };
         self.stdout_guard = Some(stdout_guard);
 
         self.stdout_reload_handle
@@ -204,18 +235,26 @@ impl Logging {
         let base_directory = PathBuf::from(base_directory);
         let logs_subdirectory = PathBuf::from(config.path.clone());
         let logs_path = base_directory.join(logs_subdirectory.clone());
-        let file_appender =
-            tracing_appender::rolling::hourly(logs_path.clone(), IGGY_LOG_FILE_PREFIX);
+        let file_appender = RollingFileWriter::new(&logs_path, IGGY_LOG_FILE_NAME, config)
+            .map_err(|error| ServerError::CannotInitializeLogging(error.to_string()))?;
         let (mut non_blocking_file, file_guard) = tracing_appender::non_blocking(file_appender);
 
         self.dump_to_file(&mut non_blocking_file);
 
-        let file_layer = fmt::layer()
-            .event_format(Self::get_log_format())
-            .with_target(true)
-            .with_writer(non_blocking_file)
-            .with_ansi(false)
-            .boxed();
+        let file_layer = match config.format {
+            LogFormat::Text => fmt::layer()
+                .event_format(Self::get_log_format())
+                .with_target(true)
+                .with_writer(non_blocking_file)
+                .with_ansi(false)
+                .boxed(),
+            LogFormat::Json => fmt::layer()
+                .json()
+                .with_thread_ids(true)
+                .with_target(true)
+                .with_writer(non_blocking_file)
+                .boxed(),
+        };
 
         self.file_guard = Some(file_guard);
         self.file_reload_handle
@@ -223,11 +262,30 @@ impl Logging {
             .ok_or(ServerError::FileReloadFailure)?
             .modify(|layer| *layer = file_layer)
             .expect("Failed to modify file layer");
+
+        if telemetry_config.enabled {
+            let tracer_provider = telemetry::init_tracer_provider(telemetry_config)?;
+            let telemetry_layer = telemetry::create_layer(&tracer_provider).boxed();
+            self.tracer_provider = Some(tracer_provider);
+            self.telemetry_reload_handle
+                .as_ref()
+                .ok_or(ServerError::TelemetryReloadFailure)?
+                .modify(|layer| *layer = telemetry_layer)
+                .expect("Failed to modify telemetry layer");
+            info!(
+                "Telemetry initialized, spans will be exported to: {}.",
+                telemetry_config.endpoint
+            );
+        }
+
         let level = filtering_level.to_string();
 
         let print = format!(
-            "Logging initialized, logs will be stored at: {:?}. Logs will be rotated hourly. Log level is: {}.",
-            logs_path, level
+            "Logging initialized, logs will be stored at: {:?}. Logs will be rotated when the file reaches: {}, rotated files will be retained for: {}. Log level is: {}.",
+            logs_path,
+            config.max_size.as_human_string_with_zero_as_unlimited(),
+            config.retention,
+            level
         );
 
         match filtering_level {
@@ -274,10 +332,6 @@ impl Logging {
     fn get_log_format() -> Format {
         Format::default().with_thread_ids(true)
     }
-
-    fn _install_log_rotation_handler(&self) {
-        todo!("Implement log rotation handler based on size and retention time");
-    }
 }
 
 impl Default for Logging {
@@ -285,3 +339,14 @@ impl Default for Logging {
         Self::new()
     }
 }
+
+impl Drop for Logging {
+    fn drop(&mut self) {
+        // Make sure that the remaining spans are exported during graceful shutdown.
+        if let Some(tracer_provider) = self.tracer_provider.take() {
+            if let Err(error) = tracer_provider.shutdown() {
+                eprintln!("Failed to shutdown the tracer provider: {error}");
+            }
+        }
+    }
+}
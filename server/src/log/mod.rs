@@ -1,5 +1,8 @@
 #[cfg(not(feature = "tokio-console"))]
 pub mod logger;
+#[cfg(not(feature = "tokio-console"))]
+pub mod rolling_file;
+pub mod telemetry;
 
 #[cfg(feature = "tokio-console")]
 pub mod tokio_console;
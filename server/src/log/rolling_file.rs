@@ -0,0 +1,259 @@
+use crate::configs::system::LoggingConfig;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+const ROTATED_FILE_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S%.6f";
+
+/// File writer, which rolls the log file once it reaches the maximum size.
+/// The rotated files are suffixed with the rotation timestamp and optionally compressed (gzip).
+/// The rotated files older than the retention are deleted, and the oldest ones are also deleted
+/// when the total size of the logs exceeds the configured limit. Zero value disables the given limit.
+#[derive(Debug)]
+pub struct RollingFileWriter {
+    directory: PathBuf,
+    file_name: String,
+    max_file_size: u64,
+    max_total_size: u64,
+    retention: Duration,
+    compress: bool,
+    file: File,
+    file_size: u64,
+    last_cleanup: Instant,
+}
+
+impl RollingFileWriter {
+    pub fn new(directory: &Path, file_name: &str, config: &LoggingConfig) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        let file = Self::open_file(&directory.join(file_name))?;
+        let file_size = file.metadata()?.len();
+        let mut writer = Self {
+            directory: directory.to_path_buf(),
+            file_name: file_name.to_string(),
+            max_file_size: config.max_size.as_bytes_u64(),
+            max_total_size: config.max_total_size.as_bytes_u64(),
+            retention: config.retention.get_duration(),
+            compress: config.compress_rotated,
+            file,
+            file_size,
+            last_cleanup: Instant::now(),
+        };
+        writer.cleanup()?;
+        Ok(writer)
+    }
+
+    fn open_file(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let file_path = self.directory.join(&self.file_name);
+        let timestamp = IggyTimestamp::now().to_utc_string(ROTATED_FILE_TIMESTAMP_FORMAT);
+        let rotated_path = self
+            .directory
+            .join(format!("{}.{}", self.file_name, timestamp));
+        fs::rename(&file_path, &rotated_path)?;
+        self.file = Self::open_file(&file_path)?;
+        self.file_size = 0;
+
+        if self.compress {
+            Self::compress_file(&rotated_path)?;
+        }
+
+        self.cleanup()
+    }
+
+    fn compress_file(path: &Path) -> io::Result<()> {
+        let mut compressed_path = path.as_os_str().to_owned();
+        compressed_path.push(".gz");
+        let mut source = File::open(path)?;
+        let mut encoder = GzEncoder::new(File::create(compressed_path)?, Compression::default());
+        io::copy(&mut source, &mut encoder)?;
+        encoder.finish()?.sync_all()?;
+        fs::remove_file(path)
+    }
+
+    /// Deletes the rotated files, which are older than the retention,
+    /// and then the oldest ones until the total size of the logs fits the limit.
+    fn cleanup(&mut self) -> io::Result<()> {
+        self.last_cleanup = Instant::now();
+        let prefix = format!("{}.", self.file_name);
+        let mut rotated_files = Vec::new();
+        for entry in fs::read_dir(&self.directory)? {
+            let entry = entry?;
+            if !entry.file_name().to_string_lossy().starts_with(&prefix) {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            rotated_files.push((entry.path(), metadata.modified()?, metadata.len()));
+        }
+        // The rotated files are suffixed with the timestamp, so sorting by name puts the oldest first.
+        rotated_files.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+        if !self.retention.is_zero() {
+            let now = SystemTime::now();
+            rotated_files.retain(|(path, modified, _)| {
+                let expired = now
+                    .duration_since(*modified)
+                    .is_ok_and(|age| age > self.retention);
+                if expired {
+                    Self::remove_file(path);
+                }
+                !expired
+            });
+        }
+
+        if self.max_total_size > 0 {
+            // Leave enough space for the current file to grow up to its maximum size.
+            let max_rotated_size = self
+                .max_total_size
+                .saturating_sub(self.file_size.max(self.max_file_size));
+            let mut rotated_size = rotated_files.iter().map(|(_, _, size)| size).sum::<u64>();
+            for (path, _, size) in &rotated_files {
+                if rotated_size <= max_rotated_size {
+                    break;
+                }
+                Self::remove_file(path);
+                rotated_size -= size;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn remove_file(path: &Path) {
+        // The logger can't be used here, as it would write to this very file.
+        if let Err(error) = fs::remove_file(path) {
+            eprintln!("Failed to delete the log file: {path:?}, error: {error}");
+        }
+    }
+}
+
+impl Write for RollingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.max_file_size > 0
+            && self.file_size > 0
+            && self.file_size + buf.len() as u64 > self.max_file_size
+        {
+            if let Err(error) = self.rotate() {
+                eprintln!(
+                    "Failed to rotate the log file: {}, error: {error}",
+                    self.file_name
+                );
+            }
+        } else if self.last_cleanup.elapsed() >= CLEANUP_INTERVAL {
+            if let Err(error) = self.cleanup() {
+                eprintln!("Failed to clean up the log files, error: {error}");
+            }
+        }
+
+        self.file.write_all(buf)?;
+        self.file_size += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::byte_size::IggyByteSize;
+    use iggy::utils::duration::IggyDuration;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    const FILE_NAME: &str = "iggy-server.log";
+
+    struct TestDirectory(PathBuf);
+
+    impl TestDirectory {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("iggy-logs-{}", Uuid::new_v4())))
+        }
+
+        fn rotated_files(&self) -> Vec<String> {
+            let mut files = fs::read_dir(&self.0)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+                .filter(|name| name != FILE_NAME)
+                .collect::<Vec<_>>();
+            files.sort();
+            files
+        }
+    }
+
+    impl Drop for TestDirectory {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn config(max_size: &str, max_total_size: &str, compress_rotated: bool) -> LoggingConfig {
+        LoggingConfig {
+            max_size: IggyByteSize::from_str(max_size).unwrap(),
+            max_total_size: IggyByteSize::from_str(max_total_size).unwrap(),
+            retention: IggyDuration::from_str("1 h").unwrap(),
+            compress_rotated,
+            ..LoggingConfig::default()
+        }
+    }
+
+    #[test]
+    fn file_should_be_rotated_once_it_reaches_max_size() {
+        let directory = TestDirectory::new();
+        let mut writer =
+            RollingFileWriter::new(&directory.0, FILE_NAME, &config("100 B", "0", false)).unwrap();
+        writer.write_all(&[b'a'; 60]).unwrap();
+        assert!(directory.rotated_files().is_empty());
+
+        writer.write_all(&[b'b'; 60]).unwrap();
+        let rotated_files = directory.rotated_files();
+        assert_eq!(rotated_files.len(), 1);
+        assert_eq!(
+            fs::read(directory.0.join(&rotated_files[0])).unwrap(),
+            vec![b'a'; 60]
+        );
+        assert_eq!(
+            fs::read(directory.0.join(FILE_NAME)).unwrap(),
+            vec![b'b'; 60]
+        );
+    }
+
+    #[test]
+    fn rotated_file_should_be_compressed() {
+        let directory = TestDirectory::new();
+        let mut writer =
+            RollingFileWriter::new(&directory.0, FILE_NAME, &config("100 B", "0", true)).unwrap();
+        writer.write_all(&[b'a'; 60]).unwrap();
+        writer.write_all(&[b'b'; 60]).unwrap();
+        let rotated_files = directory.rotated_files();
+        assert_eq!(rotated_files.len(), 1);
+        assert!(rotated_files[0].ends_with(".gz"));
+    }
+
+    #[test]
+    fn oldest_rotated_files_should_be_deleted_when_total_size_is_exceeded() {
+        let directory = TestDirectory::new();
+        let mut writer =
+            RollingFileWriter::new(&directory.0, FILE_NAME, &config("100 B", "250 B", false))
+                .unwrap();
+        for _ in 0..5 {
+            writer.write_all(&[b'a'; 80]).unwrap();
+        }
+
+        // Only the most recent rotated file fits the limit, along with the current file of max size.
+        assert_eq!(directory.rotated_files().len(), 1);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::configs::server::TelemetryConfig;
+use crate::server_error::ServerError;
+use iggy::messages::trace_context::TraceContext;
+use opentelemetry::trace::{
+    SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState, TracerProvider as _,
+};
+use opentelemetry::{Context, KeyValue};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::trace::TracerProvider;
+use opentelemetry_sdk::{runtime, Resource};
+use tracing::{Span, Subscriber};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+const TRACER_NAME: &str = "iggy-server";
+
+/// Creates the tracer provider exporting the spans in batches to the OTLP/HTTP collector.
+pub fn init_tracer_provider(config: &TelemetryConfig) -> Result<TracerProvider, ServerError> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(&config.endpoint)
+        .build()
+        .map_err(|error| ServerError::CannotInitializeTelemetry(error.to_string()))?;
+
+    Ok(TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_resource(Resource::new(vec![KeyValue::new(
+            "service.name",
+            config.service_name.clone(),
+        )]))
+        .build())
+}
+
+/// Creates the tracing layer, which converts the spans into the OpenTelemetry ones.
+pub fn create_layer<S>(tracer_provider: &TracerProvider) -> impl Layer<S>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer(TRACER_NAME))
+}
+
+/// Sets the remote parent of the span, e.g. the producer span propagated in the `traceparent` header.
+pub fn set_parent(span: &Span, trace_context: &TraceContext) {
+    let trace_flags = if trace_context.sampled {
+        TraceFlags::SAMPLED
+    } else {
+        TraceFlags::default()
+    };
+    let span_context = SpanContext::new(
+        TraceId::from_bytes(trace_context.trace_id.to_be_bytes()),
+        SpanId::from_bytes(trace_context.span_id.to_be_bytes()),
+        trace_flags,
+        true,
+        TraceState::default(),
+    );
+    span.set_parent(Context::new().with_remote_span_context(span_context));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::post;
+    use axum::Router;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tracing::info_span;
+    use tracing_subscriber::prelude::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn spans_should_be_exported_to_collector() {
+        let exported_requests = Arc::new(AtomicUsize::new(0));
+        let counter = exported_requests.clone();
+        let collector = Router::new().route(
+            "/v1/traces",
+            post(move || async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, collector).await });
+
+        let config = TelemetryConfig {
+            enabled: true,
+            service_name: "iggy-test".to_string(),
+            endpoint: format!("http://{address}/v1/traces"),
+        };
+        let tracer_provider = init_tracer_provider(&config).unwrap();
+        let subscriber = tracing_subscriber::registry().with(create_layer(&tracer_provider));
+        tracing::subscriber::with_default(subscriber, || {
+            let span = info_span!("command", code = 101);
+            set_parent(&span, &TraceContext::new());
+            span.in_scope(|| {});
+        });
+
+        let results = tokio::task::spawn_blocking(move || tracer_provider.force_flush())
+            .await
+            .unwrap();
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert!(exported_requests.load(Ordering::SeqCst) > 0);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::configs::server::TelemetryConfig;
 use crate::configs::system::LoggingConfig;
 use crate::server_error::ServerError;
 use tracing_subscriber::prelude::*;
@@ -22,6 +23,7 @@ impl Logging {
         &mut self,
         _base_directory: String,
         _config: &LoggingConfig,
+        _telemetry_config: &TelemetryConfig,
     ) -> Result<(), ServerError> {
         Ok(())
     }
@@ -4,20 +4,28 @@ use figlet_rs::FIGfont;
 use server::args::Args;
 use server::channels::commands::clean_messages::CleanMessagesExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::deliver_scheduled_messages::DeliverScheduledMessagesExecutor;
+use server::channels::commands::group_commit::GroupCommitExecutor;
 use server::channels::commands::print_sysinfo::SysInfoPrintExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
 use server::channels::handler::ServerCommandHandler;
 use server::configs::config_provider;
 use server::configs::server::ServerConfig;
+#[cfg(feature = "grpc")]
+use server::grpc::grpc_server;
 use server::http::http_server;
+use server::kafka::kafka_server;
 #[cfg(not(feature = "tokio-console"))]
 use server::log::logger::Logging;
 #[cfg(feature = "tokio-console")]
 use server::log::tokio_console::Logging;
+use server::mqtt::mqtt_server;
 use server::quic::quic_server;
 use server::server_error::ServerError;
 use server::streaming::systems::system::{SharedSystem, System};
 use server::tcp::tcp_server;
+#[cfg(unix)]
+use server::unix::unix_server;
 use tokio::time::Instant;
 use tracing::info;
 
@@ -38,18 +46,25 @@ async fn main() -> Result<(), ServerError> {
     let config_provider = config_provider::resolve(&args.config_provider)?;
     let config = ServerConfig::load(config_provider.as_ref()).await?;
 
-    logging.late_init(config.system.get_system_path(), &config.system.logging)?;
+    logging.late_init(
+        config.system.get_system_path(),
+        &config.system.logging,
+        &config.telemetry,
+    )?;
 
     let system = SharedSystem::new(System::new(
         config.system.clone(),
         None,
         config.personal_access_token,
+        config.message_scheduler.clone(),
     ));
 
     let _command_handler = ServerCommandHandler::new(system.clone(), &config)
         .install_handler(SaveMessagesExecutor)
+        .install_handler(GroupCommitExecutor)
         .install_handler(CleanMessagesExecutor)
         .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(DeliverScheduledMessagesExecutor)
         .install_handler(SysInfoPrintExecutor);
 
     // Workaround to ensure that the statistics are initialized before the server
@@ -84,6 +99,32 @@ async fn main() -> Result<(), ServerError> {
         current_config.tcp.address = tcp_addr.to_string();
     }
 
+    #[cfg(feature = "grpc")]
+    if config.grpc.enabled {
+        let grpc_addr = grpc_server::start(config.grpc, system.clone()).await;
+        current_config.grpc.address = grpc_addr.to_string();
+    }
+
+    #[cfg(not(feature = "grpc"))]
+    if config.grpc.enabled {
+        tracing::warn!("gRPC server is enabled in the config, but the server was built without the `grpc` feature.");
+    }
+
+    if config.kafka.enabled {
+        let kafka_addr = kafka_server::start(config.kafka, system.clone()).await;
+        current_config.kafka.address = kafka_addr.to_string();
+    }
+
+    if config.mqtt.enabled {
+        let mqtt_addr = mqtt_server::start(config.mqtt, system.clone()).await;
+        current_config.mqtt.address = mqtt_addr.to_string();
+    }
+
+    #[cfg(unix)]
+    if config.unix.enabled {
+        unix_server::start(config.unix, system.clone()).await;
+    }
+
     let runtime_path = current_config.system.get_runtime_path();
     let current_config_path = format!("{}/current_config.toml", runtime_path);
     let current_config_content =
@@ -0,0 +1,819 @@
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy::error::IggyError;
+
+pub const PROTOCOL_NAME: &str = "MQTT";
+pub const PROTOCOL_V311: u8 = 4;
+pub const PROTOCOL_V5: u8 = 5;
+
+const CONNECT: u8 = 1;
+const CONNACK: u8 = 2;
+const PUBLISH: u8 = 3;
+const PUBACK: u8 = 4;
+const PUBREC: u8 = 5;
+const PUBREL: u8 = 6;
+const PUBCOMP: u8 = 7;
+const SUBSCRIBE: u8 = 8;
+const SUBACK: u8 = 9;
+const UNSUBSCRIBE: u8 = 10;
+const UNSUBACK: u8 = 11;
+const PINGREQ: u8 = 12;
+const PINGRESP: u8 = 13;
+const DISCONNECT: u8 = 14;
+
+/// The largest value of the variable byte integer (4 bytes).
+pub const MAX_VARIABLE_INTEGER: u32 = 268_435_455;
+
+/// The return codes of the MQTT 3.1.1 CONNACK and SUBACK packets.
+pub mod return_codes {
+    pub const ACCEPTED: u8 = 0x00;
+    pub const UNACCEPTABLE_PROTOCOL_VERSION: u8 = 0x01;
+    pub const BAD_USERNAME_OR_PASSWORD: u8 = 0x04;
+    pub const NOT_AUTHORIZED: u8 = 0x05;
+    pub const SUBSCRIPTION_FAILURE: u8 = 0x80;
+}
+
+/// The reason codes of the MQTT 5 packets.
+pub mod reason_codes {
+    pub const SUCCESS: u8 = 0x00;
+    pub const NO_SUBSCRIPTION_EXISTED: u8 = 0x11;
+    pub const UNSPECIFIED_ERROR: u8 = 0x80;
+    pub const MALFORMED_PACKET: u8 = 0x81;
+    pub const PROTOCOL_ERROR: u8 = 0x82;
+    pub const UNSUPPORTED_PROTOCOL_VERSION: u8 = 0x84;
+    pub const BAD_USERNAME_OR_PASSWORD: u8 = 0x86;
+    pub const NOT_AUTHORIZED: u8 = 0x87;
+    pub const KEEP_ALIVE_TIMEOUT: u8 = 0x8D;
+    pub const TOPIC_FILTER_INVALID: u8 = 0x8F;
+    pub const TOPIC_NAME_INVALID: u8 = 0x90;
+    pub const PACKET_TOO_LARGE: u8 = 0x95;
+    pub const QOS_NOT_SUPPORTED: u8 = 0x9B;
+    pub const SHARED_SUBSCRIPTIONS_NOT_SUPPORTED: u8 = 0x9E;
+}
+
+/// The identifiers of the MQTT 5 properties used by the gateway, the other ones are decoded and ignored.
+pub mod property_ids {
+    pub const SESSION_EXPIRY_INTERVAL: u8 = 0x11;
+    pub const ASSIGNED_CLIENT_IDENTIFIER: u8 = 0x12;
+    pub const RECEIVE_MAXIMUM: u8 = 0x21;
+    pub const TOPIC_ALIAS: u8 = 0x23;
+    pub const MAXIMUM_QOS: u8 = 0x24;
+    pub const RETAIN_AVAILABLE: u8 = 0x25;
+    pub const USER_PROPERTY: u8 = 0x26;
+    pub const MAXIMUM_PACKET_SIZE: u8 = 0x27;
+    pub const SUBSCRIPTION_IDENTIFIER_AVAILABLE: u8 = 0x29;
+    pub const SHARED_SUBSCRIPTION_AVAILABLE: u8 = 0x2A;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    Byte(u8),
+    TwoByteInteger(u16),
+    FourByteInteger(u32),
+    VariableByteInteger(u32),
+    String(String),
+    Binary(Bytes),
+    StringPair(String, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub id: u8,
+    pub value: PropertyValue,
+}
+
+impl Property {
+    pub fn new(id: u8, value: PropertyValue) -> Self {
+        Self { id, value }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Will {
+    pub topic: String,
+    pub payload: Bytes,
+    pub qos: u8,
+    pub retain: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Connect {
+    pub protocol_version: u8,
+    pub clean_session: bool,
+    pub keep_alive: u16,
+    pub client_id: String,
+    pub will: Option<Will>,
+    pub username: Option<String>,
+    pub password: Option<Bytes>,
+    pub properties: Vec<Property>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnAck {
+    pub session_present: bool,
+    pub code: u8,
+    pub properties: Vec<Property>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Publish {
+    pub dup: bool,
+    pub qos: u8,
+    pub retain: bool,
+    pub topic: String,
+    pub packet_id: Option<u16>,
+    pub properties: Vec<Property>,
+    pub payload: Bytes,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Subscribe {
+    pub packet_id: u16,
+    pub properties: Vec<Property>,
+    /// The topic filters and their subscription options, the lowest two bits being the maximum QoS.
+    pub filters: Vec<(String, u8)>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SubAck {
+    pub packet_id: u16,
+    pub properties: Vec<Property>,
+    pub codes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Unsubscribe {
+    pub packet_id: u16,
+    pub properties: Vec<Property>,
+    pub filters: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnsubAck {
+    pub packet_id: u16,
+    pub properties: Vec<Property>,
+    /// The reason codes are present in MQTT 5 only.
+    pub codes: Vec<u8>,
+}
+
+/// The MQTT control packet. The QoS 2 flow packets (PUBREC, PUBREL and PUBCOMP) are decoded
+/// only to be rejected, as the gateway supports QoS 0 and 1.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Packet {
+    Connect(Connect),
+    ConnAck(ConnAck),
+    Publish(Publish),
+    PubAck { packet_id: u16, code: u8 },
+    PubRec { packet_id: u16 },
+    PubRel { packet_id: u16 },
+    PubComp { packet_id: u16 },
+    Subscribe(Subscribe),
+    SubAck(SubAck),
+    Unsubscribe(Unsubscribe),
+    UnsubAck(UnsubAck),
+    PingReq,
+    PingResp,
+    Disconnect { code: u8 },
+}
+
+impl Packet {
+    /// Decodes the packet from the first byte of the fixed header and the remaining bytes.
+    /// The protocol version is the one negotiated by the CONNECT packet, which carries its own.
+    pub fn decode(first_byte: u8, body: Bytes, protocol_version: u8) -> Result<Self, IggyError> {
+        let packet_type = first_byte >> 4;
+        let flags = first_byte & 0x0F;
+        let v5 = protocol_version == PROTOCOL_V5;
+        let mut decoder = Decoder::new(body);
+        let packet = match packet_type {
+            CONNECT => Packet::Connect(decode_connect(&mut decoder)?),
+            CONNACK => {
+                let session_present = decoder.u8()? & 0x01 == 1;
+                let code = decoder.u8()?;
+                let properties = decoder.properties_if(v5)?;
+                Packet::ConnAck(ConnAck {
+                    session_present,
+                    code,
+                    properties,
+                })
+            }
+            PUBLISH => {
+                let qos = (flags >> 1) & 0x03;
+                if qos > 2 {
+                    return Err(IggyError::InvalidFormat);
+                }
+                let topic = decoder.string()?;
+                let packet_id = if qos > 0 { Some(decoder.u16()?) } else { None };
+                let properties = decoder.properties_if(v5)?;
+                Packet::Publish(Publish {
+                    dup: flags & 0x08 != 0,
+                    qos,
+                    retain: flags & 0x01 != 0,
+                    topic,
+                    packet_id,
+                    properties,
+                    payload: decoder.rest(),
+                })
+            }
+            PUBACK => {
+                let packet_id = decoder.u16()?;
+                // The reason code and properties can be omitted if the reason code is success.
+                let code = if decoder.remaining() > 0 {
+                    decoder.u8()?
+                } else {
+                    reason_codes::SUCCESS
+                };
+                Packet::PubAck { packet_id, code }
+            }
+            PUBREC => Packet::PubRec {
+                packet_id: decoder.u16()?,
+            },
+            PUBREL => Packet::PubRel {
+                packet_id: decoder.u16()?,
+            },
+            PUBCOMP => Packet::PubComp {
+                packet_id: decoder.u16()?,
+            },
+            SUBSCRIBE => {
+                if flags != 0x02 {
+                    return Err(IggyError::InvalidFormat);
+                }
+                let packet_id = decoder.u16()?;
+                let properties = decoder.properties_if(v5)?;
+                let mut filters = Vec::new();
+                while decoder.remaining() > 0 {
+                    filters.push((decoder.string()?, decoder.u8()?));
+                }
+                if filters.is_empty() {
+                    return Err(IggyError::InvalidFormat);
+                }
+                Packet::Subscribe(Subscribe {
+                    packet_id,
+                    properties,
+                    filters,
+                })
+            }
+            SUBACK => {
+                let packet_id = decoder.u16()?;
+                let properties = decoder.properties_if(v5)?;
+                Packet::SubAck(SubAck {
+                    packet_id,
+                    properties,
+                    codes: decoder.rest().to_vec(),
+                })
+            }
+            UNSUBSCRIBE => {
+                if flags != 0x02 {
+                    return Err(IggyError::InvalidFormat);
+                }
+                let packet_id = decoder.u16()?;
+                let properties = decoder.properties_if(v5)?;
+                let mut filters = Vec::new();
+                while decoder.remaining() > 0 {
+                    filters.push(decoder.string()?);
+                }
+                if filters.is_empty() {
+                    return Err(IggyError::InvalidFormat);
+                }
+                Packet::Unsubscribe(Unsubscribe {
+                    packet_id,
+                    properties,
+                    filters,
+                })
+            }
+            UNSUBACK => {
+                let packet_id = decoder.u16()?;
+                let properties = decoder.properties_if(v5)?;
+                Packet::UnsubAck(UnsubAck {
+                    packet_id,
+                    properties,
+                    codes: decoder.rest().to_vec(),
+                })
+            }
+            PINGREQ => Packet::PingReq,
+            PINGRESP => Packet::PingResp,
+            DISCONNECT => {
+                let code = if v5 && decoder.remaining() > 0 {
+                    decoder.u8()?
+                } else {
+                    reason_codes::SUCCESS
+                };
+                Packet::Disconnect { code }
+            }
+            _ => return Err(IggyError::InvalidFormat),
+        };
+        Ok(packet)
+    }
+
+    /// Encodes the packet including its fixed header.
+    pub fn encode(&self, protocol_version: u8) -> Bytes {
+        let v5 = protocol_version == PROTOCOL_V5;
+        let mut body = Encoder::new();
+        let first_byte = match self {
+            Packet::Connect(connect) => {
+                encode_connect(&mut body, connect);
+                CONNECT << 4
+            }
+            Packet::ConnAck(connack) => {
+                body.u8(connack.session_present as u8).u8(connack.code);
+                if v5 {
+                    body.properties(&connack.properties);
+                }
+                CONNACK << 4
+            }
+            Packet::Publish(publish) => {
+                body.string(&publish.topic);
+                if let Some(packet_id) = publish.packet_id {
+                    body.u16(packet_id);
+                }
+                if v5 {
+                    body.properties(&publish.properties);
+                }
+                body.raw(&publish.payload);
+                (PUBLISH << 4)
+                    | ((publish.dup as u8) << 3)
+                    | ((publish.qos & 0x03) << 1)
+                    | publish.retain as u8
+            }
+            Packet::PubAck { packet_id, code } => {
+                body.u16(*packet_id);
+                if v5 && *code != reason_codes::SUCCESS {
+                    body.u8(*code);
+                }
+                PUBACK << 4
+            }
+            Packet::PubRec { packet_id } => {
+                body.u16(*packet_id);
+                PUBREC << 4
+            }
+            Packet::PubRel { packet_id } => {
+                body.u16(*packet_id);
+                (PUBREL << 4) | 0x02
+            }
+            Packet::PubComp { packet_id } => {
+                body.u16(*packet_id);
+                PUBCOMP << 4
+            }
+            Packet::Subscribe(subscribe) => {
+                body.u16(subscribe.packet_id);
+                if v5 {
+                    body.properties(&subscribe.properties);
+                }
+                for (filter, options) in &subscribe.filters {
+                    body.string(filter).u8(*options);
+                }
+                (SUBSCRIBE << 4) | 0x02
+            }
+            Packet::SubAck(suback) => {
+                body.u16(suback.packet_id);
+                if v5 {
+                    body.properties(&suback.properties);
+                }
+                body.raw(&suback.codes);
+                SUBACK << 4
+            }
+            Packet::Unsubscribe(unsubscribe) => {
+                body.u16(unsubscribe.packet_id);
+                if v5 {
+                    body.properties(&unsubscribe.properties);
+                }
+                for filter in &unsubscribe.filters {
+                    body.string(filter);
+                }
+                (UNSUBSCRIBE << 4) | 0x02
+            }
+            Packet::UnsubAck(unsuback) => {
+                body.u16(unsuback.packet_id);
+                if v5 {
+                    body.properties(&unsuback.properties).raw(&unsuback.codes);
+                }
+                UNSUBACK << 4
+            }
+            Packet::PingReq => PINGREQ << 4,
+            Packet::PingResp => PINGRESP << 4,
+            Packet::Disconnect { code } => {
+                if v5 && *code != reason_codes::SUCCESS {
+                    body.u8(*code);
+                }
+                DISCONNECT << 4
+            }
+        };
+
+        let body = body.freeze();
+        let mut packet = Encoder::new();
+        packet
+            .u8(first_byte)
+            .variable_integer(body.len() as u32)
+            .raw(&body);
+        packet.freeze()
+    }
+}
+
+fn decode_connect(decoder: &mut Decoder) -> Result<Connect, IggyError> {
+    let protocol_name = decoder.string()?;
+    let protocol_version = decoder.u8()?;
+    if protocol_name != PROTOCOL_NAME {
+        // MQTT 3.1 uses the "MQIsdp" name, the version check rejects it below.
+        return Ok(Connect {
+            protocol_version: 0,
+            ..Default::default()
+        });
+    }
+    if protocol_version != PROTOCOL_V311 && protocol_version != PROTOCOL_V5 {
+        return Ok(Connect {
+            protocol_version,
+            ..Default::default()
+        });
+    }
+    let v5 = protocol_version == PROTOCOL_V5;
+    let flags = decoder.u8()?;
+    if flags & 0x01 != 0 {
+        return Err(IggyError::InvalidFormat);
+    }
+    let keep_alive = decoder.u16()?;
+    let properties = decoder.properties_if(v5)?;
+    let client_id = decoder.string()?;
+    let will = if flags & 0x04 != 0 {
+        // The will properties are not supported, so they are skipped.
+        decoder.properties_if(v5)?;
+        Some(Will {
+            topic: decoder.string()?,
+            payload: decoder.binary()?,
+            qos: (flags >> 3) & 0x03,
+            retain: flags & 0x20 != 0,
+        })
+    } else {
+        None
+    };
+    let username = if flags & 0x80 != 0 {
+        Some(decoder.string()?)
+    } else {
+        None
+    };
+    let password = if flags & 0x40 != 0 {
+        Some(decoder.binary()?)
+    } else {
+        None
+    };
+    Ok(Connect {
+        protocol_version,
+        clean_session: flags & 0x02 != 0,
+        keep_alive,
+        client_id,
+        will,
+        username,
+        password,
+        properties,
+    })
+}
+
+fn encode_connect(encoder: &mut Encoder, connect: &Connect) {
+    let v5 = connect.protocol_version == PROTOCOL_V5;
+    let mut flags = (connect.clean_session as u8) << 1;
+    if let Some(will) = &connect.will {
+        flags |= 0x04 | ((will.qos & 0x03) << 3) | ((will.retain as u8) << 5);
+    }
+    if connect.username.is_some() {
+        flags |= 0x80;
+    }
+    if connect.password.is_some() {
+        flags |= 0x40;
+    }
+    encoder
+        .string(PROTOCOL_NAME)
+        .u8(connect.protocol_version)
+        .u8(flags)
+        .u16(connect.keep_alive);
+    if v5 {
+        encoder.properties(&connect.properties);
+    }
+    encoder.string(&connect.client_id);
+    if let Some(will) = &connect.will {
+        if v5 {
+            encoder.properties(&[]);
+        }
+        encoder.string(&will.topic).binary(&will.payload);
+    }
+    if let Some(username) = &connect.username {
+        encoder.string(username);
+    }
+    if let Some(password) = &connect.password {
+        encoder.binary(password);
+    }
+}
+
+/// Decodes the variable byte integer used by the remaining length of the fixed header,
+/// returns `None` if more bytes are needed.
+pub fn decode_variable_integer(bytes: &[u8]) -> Result<Option<(u32, usize)>, IggyError> {
+    let mut value = 0u32;
+    for (index, byte) in bytes.iter().enumerate() {
+        if index == 4 {
+            return Err(IggyError::InvalidFormat);
+        }
+        value |= ((byte & 0x7F) as u32) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Ok(Some((value, index + 1)));
+        }
+    }
+    if bytes.len() >= 4 {
+        return Err(IggyError::InvalidFormat);
+    }
+    Ok(None)
+}
+
+/// Reads the primitive types of the MQTT protocol (big-endian integers, length-prefixed UTF-8 strings
+/// and binary data, variable byte integers and the MQTT 5 properties).
+#[derive(Debug)]
+struct Decoder {
+    buffer: Bytes,
+}
+
+impl Decoder {
+    fn new(buffer: Bytes) -> Self {
+        Self { buffer }
+    }
+
+    fn remaining(&self) -> usize {
+        self.buffer.remaining()
+    }
+
+    fn ensure(&self, length: usize) -> Result<(), IggyError> {
+        if self.buffer.remaining() < length {
+            return Err(IggyError::InvalidFormat);
+        }
+        Ok(())
+    }
+
+    fn u8(&mut self) -> Result<u8, IggyError> {
+        self.ensure(1)?;
+        Ok(self.buffer.get_u8())
+    }
+
+    fn u16(&mut self) -> Result<u16, IggyError> {
+        self.ensure(2)?;
+        Ok(self.buffer.get_u16())
+    }
+
+    fn u32(&mut self) -> Result<u32, IggyError> {
+        self.ensure(4)?;
+        Ok(self.buffer.get_u32())
+    }
+
+    fn variable_integer(&mut self) -> Result<u32, IggyError> {
+        match decode_variable_integer(&self.buffer)? {
+            Some((value, length)) => {
+                self.buffer.advance(length);
+                Ok(value)
+            }
+            None => Err(IggyError::InvalidFormat),
+        }
+    }
+
+    fn binary(&mut self) -> Result<Bytes, IggyError> {
+        let length = self.u16()? as usize;
+        self.ensure(length)?;
+        Ok(self.buffer.split_to(length))
+    }
+
+    fn string(&mut self) -> Result<String, IggyError> {
+        let value = self.binary()?;
+        Ok(std::str::from_utf8(&value)?.to_string())
+    }
+
+    fn rest(&mut self) -> Bytes {
+        self.buffer.split_to(self.buffer.remaining())
+    }
+
+    fn properties_if(&mut self, v5: bool) -> Result<Vec<Property>, IggyError> {
+        if !v5 {
+            return Ok(Vec::new());
+        }
+        let length = self.variable_integer()? as usize;
+        self.ensure(length)?;
+        let mut decoder = Decoder::new(self.buffer.split_to(length));
+        let mut properties = Vec::new();
+        while decoder.remaining() > 0 {
+            let id = decoder.variable_integer()?;
+            let value = match id {
+                0x01 | 0x17 | 0x19 | 0x24 | 0x25 | 0x28 | 0x29 | 0x2A => {
+                    PropertyValue::Byte(decoder.u8()?)
+                }
+                0x13 | 0x21 | 0x22 | 0x23 => PropertyValue::TwoByteInteger(decoder.u16()?),
+                0x02 | 0x11 | 0x18 | 0x27 => PropertyValue::FourByteInteger(decoder.u32()?),
+                0x0B => PropertyValue::VariableByteInteger(decoder.variable_integer()?),
+                0x03 | 0x08 | 0x12 | 0x15 | 0x1A | 0x1C | 0x1F => {
+                    PropertyValue::String(decoder.string()?)
+                }
+                0x09 | 0x16 => PropertyValue::Binary(decoder.binary()?),
+                0x26 => PropertyValue::StringPair(decoder.string()?, decoder.string()?),
+                _ => return Err(IggyError::InvalidFormat),
+            };
+            properties.push(Property::new(id as u8, value));
+        }
+        Ok(properties)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Encoder {
+    buffer: BytesMut,
+}
+
+impl Encoder {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn u8(&mut self, value: u8) -> &mut Self {
+        self.buffer.put_u8(value);
+        self
+    }
+
+    fn u16(&mut self, value: u16) -> &mut Self {
+        self.buffer.put_u16(value);
+        self
+    }
+
+    fn u32(&mut self, value: u32) -> &mut Self {
+        self.buffer.put_u32(value);
+        self
+    }
+
+    fn variable_integer(&mut self, mut value: u32) -> &mut Self {
+        loop {
+            let mut byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value > 0 {
+                byte |= 0x80;
+            }
+            self.buffer.put_u8(byte);
+            if value == 0 {
+                return self;
+            }
+        }
+    }
+
+    fn raw(&mut self, value: &[u8]) -> &mut Self {
+        self.buffer.put_slice(value);
+        self
+    }
+
+    fn binary(&mut self, value: &[u8]) -> &mut Self {
+        self.u16(value.len() as u16).raw(value)
+    }
+
+    fn string(&mut self, value: &str) -> &mut Self {
+        self.binary(value.as_bytes())
+    }
+
+    fn properties(&mut self, properties: &[Property]) -> &mut Self {
+        let mut encoder = Encoder::new();
+        for property in properties {
+            encoder.variable_integer(property.id as u32);
+            match &property.value {
+                PropertyValue::Byte(value) => encoder.u8(*value),
+                PropertyValue::TwoByteInteger(value) => encoder.u16(*value),
+                PropertyValue::FourByteInteger(value) => encoder.u32(*value),
+                PropertyValue::VariableByteInteger(value) => encoder.variable_integer(*value),
+                PropertyValue::String(value) => encoder.string(value),
+                PropertyValue::Binary(value) => encoder.binary(value),
+                PropertyValue::StringPair(key, value) => encoder.string(key).string(value),
+            };
+        }
+        let properties = encoder.freeze();
+        self.variable_integer(properties.len() as u32)
+            .raw(&properties)
+    }
+
+    fn freeze(self) -> Bytes {
+        self.buffer.freeze()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(packet: Packet, protocol_version: u8) {
+        let bytes = packet.encode(protocol_version);
+        let (length, length_size) = decode_variable_integer(&bytes[1..]).unwrap().unwrap();
+        let body = bytes.slice(1 + length_size..);
+        assert_eq!(length as usize, body.len());
+        let decoded = Packet::decode(bytes[0], body, protocol_version).unwrap();
+        assert_eq!(decoded, packet);
+    }
+
+    #[test]
+    fn variable_integer_should_be_encoded_and_decoded() {
+        for value in [0, 127, 128, 16_383, 16_384, 2_097_151, MAX_VARIABLE_INTEGER] {
+            let mut encoder = Encoder::new();
+            encoder.variable_integer(value);
+            let bytes = encoder.freeze();
+            assert_eq!(
+                decode_variable_integer(&bytes).unwrap(),
+                Some((value, bytes.len()))
+            );
+        }
+        assert_eq!(decode_variable_integer(&[0x80, 0x80]).unwrap(), None);
+        assert!(decode_variable_integer(&[0x80, 0x80, 0x80, 0x80, 0x01]).is_err());
+    }
+
+    #[test]
+    fn connect_should_be_encoded_and_decoded() {
+        for protocol_version in [PROTOCOL_V311, PROTOCOL_V5] {
+            let properties = if protocol_version == PROTOCOL_V5 {
+                vec![Property::new(
+                    property_ids::MAXIMUM_PACKET_SIZE,
+                    PropertyValue::FourByteInteger(1024),
+                )]
+            } else {
+                Vec::new()
+            };
+            roundtrip(
+                Packet::Connect(Connect {
+                    protocol_version,
+                    clean_session: true,
+                    keep_alive: 30,
+                    client_id: "device-1".to_string(),
+                    will: Some(Will {
+                        topic: "devices/status".to_string(),
+                        payload: Bytes::from_static(b"offline"),
+                        qos: 1,
+                        retain: false,
+                    }),
+                    username: Some("iggy".to_string()),
+                    password: Some(Bytes::from_static(b"secret")),
+                    properties,
+                }),
+                protocol_version,
+            );
+        }
+    }
+
+    #[test]
+    fn publish_and_subscriptions_should_be_encoded_and_decoded() {
+        for protocol_version in [PROTOCOL_V311, PROTOCOL_V5] {
+            let properties = if protocol_version == PROTOCOL_V5 {
+                vec![Property::new(
+                    property_ids::USER_PROPERTY,
+                    PropertyValue::StringPair("key".to_string(), "value".to_string()),
+                )]
+            } else {
+                Vec::new()
+            };
+            roundtrip(
+                Packet::Publish(Publish {
+                    dup: false,
+                    qos: 1,
+                    retain: false,
+                    topic: "factory/sensors/device-1".to_string(),
+                    packet_id: Some(7),
+                    properties: properties.clone(),
+                    payload: Bytes::from_static(b"21.5"),
+                }),
+                protocol_version,
+            );
+            roundtrip(
+                Packet::Subscribe(Subscribe {
+                    packet_id: 1,
+                    properties: properties.clone(),
+                    filters: vec![
+                        ("factory/sensors/+".to_string(), 1),
+                        ("factory/#".to_string(), 0),
+                    ],
+                }),
+                protocol_version,
+            );
+            roundtrip(
+                Packet::SubAck(SubAck {
+                    packet_id: 1,
+                    properties: Vec::new(),
+                    codes: vec![1, return_codes::SUBSCRIPTION_FAILURE],
+                }),
+                protocol_version,
+            );
+            roundtrip(
+                Packet::PubAck {
+                    packet_id: 7,
+                    code: reason_codes::SUCCESS,
+                },
+                protocol_version,
+            );
+            roundtrip(Packet::PingReq, protocol_version);
+        }
+    }
+
+    #[test]
+    fn connect_with_unsupported_protocol_version_should_be_decoded() {
+        let mut encoder = Encoder::new();
+        encoder
+            .string("MQIsdp")
+            .u8(3)
+            .u8(0x02)
+            .u16(60)
+            .string("device");
+        let packet = Packet::decode(CONNECT << 4, encoder.freeze(), 0).unwrap();
+        let Packet::Connect(connect) = packet else {
+            panic!("Expected the CONNECT packet.");
+        };
+        assert_eq!(connect.protocol_version, 0);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod codec;
+pub mod mqtt_server;
+mod subscriptions;
+mod topics;
+
+pub use topics::TOPIC_HEADER;
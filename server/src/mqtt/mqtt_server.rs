@@ -0,0 +1,672 @@
+use crate::configs::mqtt::MqttConfig;
+use crate::mqtt::codec::{
+    self, property_ids, reason_codes, return_codes, ConnAck, Connect, Packet, Property,
+    PropertyValue, Publish, SubAck, Subscribe, UnsubAck, Unsubscribe, PROTOCOL_V311, PROTOCOL_V5,
+};
+use crate::mqtt::subscriptions::SessionState;
+use crate::mqtt::topics::{TopicFilter, TopicName, TOPIC_HEADER};
+use crate::server_error::ServerError;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::{Bytes, BytesMut};
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::timeout;
+use tracing::{debug, error, info, warn};
+
+/// The time the client has to send the CONNECT packet after opening the connection.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// The number of the outgoing packets buffered before the delivery of the messages is paused.
+const OUTGOING_PACKETS_BUFFER: usize = 1000;
+/// The maximum number of the QoS 1 messages sent to the client without the acknowledgement,
+/// unless the MQTT 5 client requests the lower receive maximum.
+const MAX_IN_FLIGHT_MESSAGES: u16 = 100;
+const MAX_QOS: u8 = 1;
+
+/// The sessions of the disconnected clients, by the client ID. The expired ones are removed
+/// when the clients connect.
+type Sessions = Arc<Mutex<HashMap<String, StoredSession>>>;
+
+struct StoredSession {
+    state: Arc<Mutex<SessionState>>,
+    /// The session can be resumed only by the same user, as the messages in flight have been polled with its permissions.
+    user_id: u32,
+    expires_at: Instant,
+}
+
+/// Starts the MQTT gateway.
+/// Returns the address the gateway is bound to.
+pub async fn start(config: MqttConfig, system: SharedSystem) -> SocketAddr {
+    info!("Initializing Iggy MQTT gateway...");
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .expect("Unable to start MQTT gateway.");
+    let address = listener
+        .local_addr()
+        .expect("Failed to get local address for MQTT gateway");
+    let config = Arc::new(config);
+    let sessions = Sessions::default();
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    info!("Accepted new MQTT connection: {address}");
+                    let system = system.clone();
+                    let config = config.clone();
+                    let sessions = sessions.clone();
+                    tokio::spawn(async move {
+                        let client_id = system.read().add_client(&address, Transport::Mqtt).await;
+                        let session = Arc::new(Session::from_client_id(client_id, address));
+                        if let Err(error) =
+                            handle_connection(stream, system.clone(), session, config, sessions)
+                                .await
+                        {
+                            match error {
+                                ServerError::IoError(error)
+                                    if error.kind() == std::io::ErrorKind::UnexpectedEof =>
+                                {
+                                    info!("MQTT connection: {address} has been closed.")
+                                }
+                                error => error!("MQTT connection: {address} has failed: {error}"),
+                            }
+                        }
+                        system.read().delete_client(&address).await;
+                    });
+                }
+                Err(error) => error!("Unable to accept MQTT connection, error: {error}"),
+            }
+        }
+    });
+    info!("Iggy MQTT gateway has started on: {:?}", address);
+    address
+}
+
+struct Connection {
+    system: SharedSystem,
+    session: Arc<Session>,
+    protocol_version: u8,
+    sender: mpsc::Sender<Bytes>,
+    state: Arc<Mutex<SessionState>>,
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    system: SharedSystem,
+    session: Arc<Session>,
+    config: Arc<MqttConfig>,
+    sessions: Sessions,
+) -> Result<(), ServerError> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let max_packet_size = config.max_packet_size.as_bytes_u64() as usize;
+
+    let Ok(header) = timeout(CONNECT_TIMEOUT, read_fixed_header(&mut reader)).await else {
+        warn!("MQTT client: {} hasn't connected in time.", session);
+        return Ok(());
+    };
+    let (first_byte, length) = header?;
+    if length > max_packet_size {
+        return Err(ServerError::from(IggyError::InvalidCommand));
+    }
+    let Packet::Connect(connect) =
+        Packet::decode(first_byte, read_body(&mut reader, length).await?, 0)?
+    else {
+        return Err(ServerError::from(IggyError::InvalidCommand));
+    };
+
+    let protocol_version = connect.protocol_version;
+    if protocol_version != PROTOCOL_V311 && protocol_version != PROTOCOL_V5 {
+        warn!("Unsupported MQTT protocol version: {protocol_version}, client: {session}.");
+        let connack = Packet::ConnAck(ConnAck {
+            code: return_codes::UNACCEPTABLE_PROTOCOL_VERSION,
+            ..Default::default()
+        });
+        writer.write_all(&connack.encode(PROTOCOL_V311)).await?;
+        return Ok(());
+    }
+
+    let v5 = protocol_version == PROTOCOL_V5;
+    let code = match authenticate(&system, &session, &connect).await {
+        Ok(()) => return_codes::ACCEPTED,
+        Err(error) => {
+            warn!("MQTT client: {session} cannot be authenticated, error: {error}");
+            match (error, v5) {
+                (IggyError::Unauthenticated, true) => reason_codes::NOT_AUTHORIZED,
+                (IggyError::Unauthenticated, false) => return_codes::NOT_AUTHORIZED,
+                (_, true) => reason_codes::BAD_USERNAME_OR_PASSWORD,
+                (_, false) => return_codes::BAD_USERNAME_OR_PASSWORD,
+            }
+        }
+    };
+    let (state, session_present) = if code == return_codes::ACCEPTED {
+        take_session(&sessions, &session, &connect).await
+    } else {
+        (Arc::default(), false)
+    };
+    let connack = Packet::ConnAck(ConnAck {
+        session_present,
+        code,
+        properties: connack_properties(&connect, &config),
+    });
+    writer.write_all(&connack.encode(protocol_version)).await?;
+    if code != return_codes::ACCEPTED {
+        return Ok(());
+    }
+    info!(
+        "MQTT client: {}, ID: {} has connected using MQTT {}.",
+        session,
+        connect.client_id,
+        if v5 { "5" } else { "3.1.1" }
+    );
+
+    let (sender, mut receiver) = mpsc::channel::<Bytes>(OUTGOING_PACKETS_BUFFER);
+    let writer_task = tokio::spawn(async move {
+        while let Some(packet) = receiver.recv().await {
+            if writer.write_all(&packet).await.is_err() {
+                break;
+            }
+        }
+    });
+    let connection = Connection {
+        system,
+        session,
+        protocol_version,
+        sender,
+        state,
+    };
+    // MQTT 5 doesn't allow resending the messages other than after reconnecting.
+    let resend_interval = (!v5).then(|| config.resend_interval.get_duration());
+    let delivery_task = tokio::spawn(deliver(
+        connection.system.clone(),
+        connection.session.clone(),
+        connection.state.clone(),
+        connection.sender.clone(),
+        protocol_version,
+        get_client_max_packet_size(&connect),
+        get_max_in_flight(&connect),
+        config.poll_interval.get_duration(),
+        resend_interval,
+    ));
+
+    let result = connection
+        .handle_packets(&mut reader, connect.keep_alive, max_packet_size)
+        .await;
+    delivery_task.abort();
+    let _ = delivery_task.await;
+    let session_expiry = get_session_expiry(&connect, &config);
+    if !session_expiry.is_zero() {
+        sessions.lock().await.insert(
+            connect.client_id.clone(),
+            StoredSession {
+                state: connection.state.clone(),
+                user_id: connection.session.get_user_id(),
+                expires_at: Instant::now() + session_expiry,
+            },
+        );
+    }
+    drop(connection);
+    let _ = writer_task.await;
+    result
+}
+
+/// Returns the state of the session resumed by the client, unless it requests the clean one,
+/// along with the flag indicating if the session is present.
+async fn take_session(
+    sessions: &Sessions,
+    session: &Session,
+    connect: &Connect,
+) -> (Arc<Mutex<SessionState>>, bool) {
+    let mut sessions = sessions.lock().await;
+    let now = Instant::now();
+    sessions.retain(|_, stored_session| stored_session.expires_at > now);
+    match sessions.remove(&connect.client_id) {
+        Some(stored_session)
+            if !connect.clean_session && stored_session.user_id == session.get_user_id() =>
+        {
+            (stored_session.state, true)
+        }
+        _ => (Arc::default(), false),
+    }
+}
+
+/// Returns the time the session is kept for after the client disconnects: the session expiry interval
+/// requested by the MQTT 5 client, or the maximum one if the MQTT 3.1.1 client hasn't requested the clean session.
+fn get_session_expiry(connect: &Connect, config: &MqttConfig) -> Duration {
+    if connect.client_id.is_empty() {
+        return Duration::ZERO;
+    }
+    let max_session_expiry = config.max_session_expiry.get_duration();
+    if connect.protocol_version != PROTOCOL_V5 {
+        return if connect.clean_session {
+            Duration::ZERO
+        } else {
+            max_session_expiry
+        };
+    }
+    match find_property(connect, property_ids::SESSION_EXPIRY_INTERVAL) {
+        Some(PropertyValue::FourByteInteger(expiry)) => {
+            Duration::from_secs(*expiry as u64).min(max_session_expiry)
+        }
+        _ => Duration::ZERO,
+    }
+}
+
+async fn authenticate(
+    system: &SharedSystem,
+    session: &Session,
+    connect: &Connect,
+) -> Result<(), IggyError> {
+    let password = match &connect.password {
+        Some(password) => Some(std::str::from_utf8(password)?),
+        None => None,
+    };
+    let system = system.read();
+    match (connect.username.as_deref(), password) {
+        // The personal access token is passed as the password with the empty username.
+        (None | Some(""), Some(token)) => {
+            system
+                .login_with_personal_access_token(token, Some(session))
+                .await?;
+        }
+        (Some(username), Some(password)) => {
+            system.login_user(username, password, Some(session)).await?;
+        }
+        _ => return Err(IggyError::Unauthenticated),
+    }
+    Ok(())
+}
+
+/// The MQTT 5 clients are informed about the features not supported by the gateway.
+fn connack_properties(connect: &Connect, config: &MqttConfig) -> Vec<Property> {
+    if connect.protocol_version != PROTOCOL_V5 {
+        return Vec::new();
+    }
+    let mut properties = vec![
+        Property::new(property_ids::MAXIMUM_QOS, PropertyValue::Byte(MAX_QOS)),
+        Property::new(property_ids::RETAIN_AVAILABLE, PropertyValue::Byte(0)),
+        Property::new(
+            property_ids::SUBSCRIPTION_IDENTIFIER_AVAILABLE,
+            PropertyValue::Byte(0),
+        ),
+        Property::new(
+            property_ids::SHARED_SUBSCRIPTION_AVAILABLE,
+            PropertyValue::Byte(0),
+        ),
+        Property::new(
+            property_ids::MAXIMUM_PACKET_SIZE,
+            PropertyValue::FourByteInteger(
+                config
+                    .max_packet_size
+                    .as_bytes_u64()
+                    .min(codec::MAX_VARIABLE_INTEGER as u64) as u32,
+            ),
+        ),
+    ];
+    if connect.client_id.is_empty() {
+        properties.push(Property::new(
+            property_ids::ASSIGNED_CLIENT_IDENTIFIER,
+            PropertyValue::String(format!("iggy-{}", uuid::Uuid::new_v4())),
+        ));
+    }
+    // The client is informed if its session is kept for the shorter time than requested.
+    let max_session_expiry = config
+        .max_session_expiry
+        .get_duration()
+        .as_secs()
+        .min(u32::MAX as u64) as u32;
+    if let Some(PropertyValue::FourByteInteger(expiry)) =
+        find_property(connect, property_ids::SESSION_EXPIRY_INTERVAL)
+    {
+        if *expiry > max_session_expiry {
+            properties.push(Property::new(
+                property_ids::SESSION_EXPIRY_INTERVAL,
+                PropertyValue::FourByteInteger(max_session_expiry),
+            ));
+        }
+    }
+    properties
+}
+
+fn find_property(connect: &Connect, id: u8) -> Option<&PropertyValue> {
+    connect
+        .properties
+        .iter()
+        .find(|property| property.id == id)
+        .map(|property| &property.value)
+}
+
+fn get_client_max_packet_size(connect: &Connect) -> usize {
+    match find_property(connect, property_ids::MAXIMUM_PACKET_SIZE) {
+        Some(PropertyValue::FourByteInteger(value)) => *value as usize,
+        _ => usize::MAX,
+    }
+}
+
+fn get_max_in_flight(connect: &Connect) -> usize {
+    match find_property(connect, property_ids::RECEIVE_MAXIMUM) {
+        Some(PropertyValue::TwoByteInteger(value)) => (*value).min(MAX_IN_FLIGHT_MESSAGES) as usize,
+        _ => MAX_IN_FLIGHT_MESSAGES as usize,
+    }
+}
+
+impl Connection {
+    async fn handle_packets<R: AsyncRead + Unpin>(
+        &self,
+        reader: &mut R,
+        keep_alive: u16,
+        max_packet_size: usize,
+    ) -> Result<(), ServerError> {
+        // The client is disconnected if there's no packet within one and a half times the keep alive.
+        let keep_alive = (keep_alive > 0).then(|| Duration::from_millis(keep_alive as u64 * 1500));
+        loop {
+            let (first_byte, length) = match keep_alive {
+                Some(keep_alive) => match timeout(keep_alive, read_fixed_header(reader)).await {
+                    Ok(header) => header?,
+                    Err(_) => {
+                        info!("MQTT client: {} keep alive has expired.", self.session);
+                        return self.disconnect(reason_codes::KEEP_ALIVE_TIMEOUT).await;
+                    }
+                },
+                None => read_fixed_header(reader).await?,
+            };
+            if length > max_packet_size {
+                warn!(
+                    "MQTT client: {} has sent too large packet: {length} bytes.",
+                    self.session
+                );
+                return self.disconnect(reason_codes::PACKET_TOO_LARGE).await;
+            }
+            let body = read_body(reader, length).await?;
+            let packet = match Packet::decode(first_byte, body, self.protocol_version) {
+                Ok(packet) => packet,
+                Err(error) => {
+                    warn!(
+                        "MQTT client: {} has sent malformed packet, error: {error}",
+                        self.session
+                    );
+                    return self.disconnect(reason_codes::MALFORMED_PACKET).await;
+                }
+            };
+
+            match packet {
+                Packet::Publish(publish) => {
+                    if !self.handle_publish(publish).await? {
+                        return Ok(());
+                    }
+                }
+                Packet::Subscribe(subscribe) => self.handle_subscribe(subscribe).await?,
+                Packet::Unsubscribe(unsubscribe) => self.handle_unsubscribe(unsubscribe).await?,
+                Packet::PingReq => self.send(Packet::PingResp).await?,
+                Packet::PubAck { packet_id, .. } => {
+                    if !self.state.lock().await.acknowledge(packet_id) {
+                        debug!(
+                            "MQTT client: {} has acknowledged unknown packet ID: {packet_id}.",
+                            self.session
+                        );
+                    }
+                }
+                Packet::Disconnect { .. } => {
+                    debug!("MQTT client: {} has disconnected.", self.session);
+                    return Ok(());
+                }
+                packet => {
+                    warn!(
+                        "MQTT client: {} has sent unexpected packet: {packet:?}",
+                        self.session
+                    );
+                    return self.disconnect(reason_codes::PROTOCOL_ERROR).await;
+                }
+            }
+        }
+    }
+
+    /// Appends the published message, returns `false` if the connection has to be closed.
+    async fn handle_publish(&self, publish: Publish) -> Result<bool, ServerError> {
+        if publish.qos > MAX_QOS {
+            warn!("MQTT client: {} has published with QoS 2.", self.session);
+            self.disconnect(reason_codes::QOS_NOT_SUPPORTED).await?;
+            return Ok(false);
+        }
+
+        let code = match self.append(&publish).await {
+            Ok(()) => reason_codes::SUCCESS,
+            Err(error) => {
+                warn!(
+                    "Cannot append MQTT message from client: {} to topic: {}, error: {error}",
+                    self.session, publish.topic
+                );
+                match error {
+                    IggyError::InvalidTopicName => reason_codes::TOPIC_NAME_INVALID,
+                    IggyError::StreamNameNotFound(_) | IggyError::TopicNameNotFound(_, _) => {
+                        reason_codes::TOPIC_NAME_INVALID
+                    }
+                    IggyError::Unauthorized | IggyError::Unauthenticated => {
+                        reason_codes::NOT_AUTHORIZED
+                    }
+                    _ => reason_codes::UNSPECIFIED_ERROR,
+                }
+            }
+        };
+
+        match publish.packet_id {
+            // Only MQTT 5 can acknowledge the failure, otherwise the connection has to be closed.
+            Some(packet_id) if code == reason_codes::SUCCESS || self.is_v5() => {
+                self.send(Packet::PubAck { packet_id, code }).await?;
+                Ok(true)
+            }
+            _ if code == reason_codes::SUCCESS => Ok(true),
+            _ => {
+                self.disconnect(code).await?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Appends the message to the topic of the stream its MQTT topic maps onto, the messages
+    /// with the same MQTT topic are kept in order by using it as the messages key.
+    async fn append(&self, publish: &Publish) -> Result<(), IggyError> {
+        let Some(name) = TopicName::parse(&publish.topic) else {
+            return Err(IggyError::InvalidTopicName);
+        };
+        let headers = HashMap::from([(
+            HeaderKey::new(TOPIC_HEADER)?,
+            HeaderValue::from_str(&publish.topic)?,
+        )]);
+        let message = Message::new(None, publish.payload.clone(), Some(headers));
+        let system = self.system.read();
+        system
+            .append_messages(
+                &self.session,
+                Identifier::named(&name.stream)?,
+                Identifier::named(&name.topic)?,
+                Partitioning::messages_key_str(&publish.topic)?,
+                vec![message],
+                false,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn handle_subscribe(&self, subscribe: Subscribe) -> Result<(), ServerError> {
+        let mut codes = Vec::with_capacity(subscribe.filters.len());
+        for (filter, options) in subscribe.filters {
+            let qos = (options & 0x03).min(MAX_QOS);
+            let Some(topic_filter) = TopicFilter::parse(&filter) else {
+                warn!(
+                    "MQTT client: {} has sent invalid topic filter: {filter}",
+                    self.session
+                );
+                codes.push(self.subscription_failure(if filter.starts_with("$share/") {
+                    reason_codes::SHARED_SUBSCRIPTIONS_NOT_SUPPORTED
+                } else {
+                    reason_codes::TOPIC_FILTER_INVALID
+                }));
+                continue;
+            };
+
+            let result = {
+                let mut state = self.state.lock().await;
+                let system = self.system.read();
+                state
+                    .subscribe(&system, &self.session, topic_filter, qos)
+                    .await
+            };
+            match result {
+                Ok(()) => {
+                    codes.push(qos);
+                    debug!(
+                        "MQTT client: {} has subscribed to: {filter} with QoS {qos}.",
+                        self.session
+                    );
+                }
+                Err(error) => {
+                    warn!(
+                        "MQTT client: {} cannot subscribe to: {filter}, error: {error}",
+                        self.session
+                    );
+                    codes.push(self.subscription_failure(match error {
+                        IggyError::Unauthorized | IggyError::Unauthenticated => {
+                            reason_codes::NOT_AUTHORIZED
+                        }
+                        _ => reason_codes::TOPIC_FILTER_INVALID,
+                    }));
+                }
+            }
+        }
+        self.send(Packet::SubAck(SubAck {
+            packet_id: subscribe.packet_id,
+            properties: Vec::new(),
+            codes,
+        }))
+        .await
+    }
+
+    async fn handle_unsubscribe(&self, unsubscribe: Unsubscribe) -> Result<(), ServerError> {
+        let mut codes = Vec::with_capacity(unsubscribe.filters.len());
+        {
+            let mut state = self.state.lock().await;
+            for filter in &unsubscribe.filters {
+                codes.push(if state.unsubscribe(filter) {
+                    reason_codes::SUCCESS
+                } else {
+                    reason_codes::NO_SUBSCRIPTION_EXISTED
+                });
+            }
+        }
+        self.send(Packet::UnsubAck(UnsubAck {
+            packet_id: unsubscribe.packet_id,
+            properties: Vec::new(),
+            codes,
+        }))
+        .await
+    }
+
+    fn is_v5(&self) -> bool {
+        self.protocol_version == PROTOCOL_V5
+    }
+
+    fn subscription_failure(&self, reason_code: u8) -> u8 {
+        if self.is_v5() {
+            reason_code
+        } else {
+            return_codes::SUBSCRIPTION_FAILURE
+        }
+    }
+
+    async fn send(&self, packet: Packet) -> Result<(), ServerError> {
+        self.sender
+            .send(packet.encode(self.protocol_version))
+            .await
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::BrokenPipe))?;
+        Ok(())
+    }
+
+    /// MQTT 5 clients are informed about the reason of closing the connection.
+    async fn disconnect(&self, reason_code: u8) -> Result<(), ServerError> {
+        if self.is_v5() {
+            self.send(Packet::Disconnect { code: reason_code }).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Periodically polls the subscribed topics and sends the matching messages to the client. The QoS 1 messages
+/// are kept in flight until acknowledged, and sent again with the DUP flag once the client reconnects,
+/// or after the resend interval (if any) has elapsed.
+#[allow(clippy::too_many_arguments)]
+async fn deliver(
+    system: SharedSystem,
+    session: Arc<Session>,
+    state: Arc<Mutex<SessionState>>,
+    sender: mpsc::Sender<Bytes>,
+    protocol_version: u8,
+    max_packet_size: usize,
+    max_in_flight: usize,
+    poll_interval: Duration,
+    resend_interval: Option<Duration>,
+) {
+    let mut publishes = state.lock().await.resend(Duration::ZERO);
+    loop {
+        for publish in publishes {
+            let packet_id = publish.packet_id;
+            let packet = Packet::Publish(publish).encode(protocol_version);
+            if packet.len() > max_packet_size {
+                warn!("MQTT message exceeding the maximum packet size of client: {session} has been skipped.");
+                if let Some(packet_id) = packet_id {
+                    state.lock().await.acknowledge(packet_id);
+                }
+                continue;
+            }
+            if sender.send(packet).await.is_err() {
+                return;
+            }
+        }
+
+        tokio::time::sleep(poll_interval).await;
+        publishes = {
+            let mut state = state.lock().await;
+            let mut publishes = match resend_interval {
+                Some(resend_interval) => state.resend(resend_interval),
+                None => Vec::new(),
+            };
+            let system = system.read();
+            publishes.extend(state.poll(&system, &session, max_in_flight).await);
+            publishes
+        };
+    }
+}
+
+/// Reads the fixed header returning the first byte and the remaining length of the packet.
+async fn read_fixed_header<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<(u8, usize), ServerError> {
+    let first_byte = reader.read_u8().await?;
+    let mut length = Vec::with_capacity(4);
+    loop {
+        length.push(reader.read_u8().await?);
+        if let Some((length, _)) = codec::decode_variable_integer(&length)? {
+            return Ok((first_byte, length as usize));
+        }
+    }
+}
+
+async fn read_body<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    length: usize,
+) -> Result<Bytes, ServerError> {
+    let mut body = BytesMut::zeroed(length);
+    reader.read_exact(&mut body).await?;
+    Ok(body.freeze())
+}
@@ -0,0 +1,344 @@
+use crate::mqtt::codec::Publish;
+use crate::mqtt::topics::{TopicFilter, TOPIC_HEADER};
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::locking::IggySharedMutFn;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::PolledMessage;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// The maximum number of messages polled from the partition at once.
+const POLL_BATCH_SIZE: u32 = 100;
+
+/// The stream, topic and partition ID.
+type PartitionKey = (u32, u32, u32);
+
+#[derive(Debug)]
+pub(crate) struct Subscription {
+    pub filter: TopicFilter,
+    pub qos: u8,
+}
+
+/// The QoS 1 message sent to the client, which hasn't been acknowledged yet.
+#[derive(Debug)]
+struct InFlightMessage {
+    publish: Publish,
+    sent_at: Instant,
+}
+
+/// The partition of the topic matching the subscription.
+struct MatchingPartition<'a> {
+    key: PartitionKey,
+    stream: &'a Stream,
+    topic: &'a Topic,
+}
+
+/// The state of the MQTT session: the subscriptions, the offsets of the next messages to deliver and the QoS 1
+/// messages awaiting the acknowledgement. The state of the client which hasn't requested the clean session
+/// outlives its connection, so that the delivery resumes where it has stopped once the client reconnects.
+///
+/// As the MQTT topics are kept in the header, the subscriptions poll the whole partitions of the matching
+/// Iggy topics and filter out the messages which don't match. Each partition is polled once for all
+/// the subscriptions, and only if it has the new messages.
+#[derive(Debug, Default)]
+pub(crate) struct SessionState {
+    subscriptions: Vec<Subscription>,
+    /// The offset of the next message to deliver for each partition.
+    offsets: HashMap<PartitionKey, u64>,
+    /// The messages awaiting the acknowledgement (PUBACK) in the order of sending.
+    in_flight: VecDeque<InFlightMessage>,
+    last_packet_id: u16,
+}
+
+impl SessionState {
+    /// Adds the subscription, replacing the existing one with the same filter. The delivery starts at the end
+    /// of the matching partitions, unless they're already delivered for the other subscriptions.
+    /// Fails if the stream or topic doesn't exist, or the user can't poll the messages.
+    pub async fn subscribe(
+        &mut self,
+        system: &System,
+        session: &Session,
+        filter: TopicFilter,
+        qos: u8,
+    ) -> Result<(), IggyError> {
+        let partitions = resolve_partitions(system, session, &filter)?;
+        let mut delivered_partitions = HashSet::new();
+        for subscription in &self.subscriptions {
+            if let Ok(partitions) = resolve_partitions(system, session, &subscription.filter) {
+                delivered_partitions.extend(partitions.into_iter().map(|partition| partition.key));
+            }
+        }
+
+        for partition in partitions {
+            if delivered_partitions.contains(&partition.key) {
+                continue;
+            }
+            let (_, _, partition_id) = partition.key;
+            let partition_lock = partition.topic.get_partition(partition_id)?;
+            let partition_lock = partition_lock.read().await;
+            let next_offset = if partition_lock.should_increment_offset {
+                partition_lock.current_offset + 1
+            } else {
+                0
+            };
+            self.offsets.insert(partition.key, next_offset);
+        }
+        self.subscriptions
+            .retain(|subscription| subscription.filter.filter != filter.filter);
+        self.subscriptions.push(Subscription { filter, qos });
+        Ok(())
+    }
+
+    /// Removes the subscription, returns `false` if there's no subscription with the filter.
+    /// The messages in flight are still delivered.
+    pub fn unsubscribe(&mut self, filter: &str) -> bool {
+        let count = self.subscriptions.len();
+        self.subscriptions
+            .retain(|subscription| subscription.filter.filter != filter);
+        self.subscriptions.len() < count
+    }
+
+    /// Completes the delivery of the QoS 1 message, returns `false` if there's no such message in flight.
+    pub fn acknowledge(&mut self, packet_id: u16) -> bool {
+        let Some(index) = self
+            .in_flight
+            .iter()
+            .position(|message| message.publish.packet_id == Some(packet_id))
+        else {
+            return false;
+        };
+        self.in_flight.remove(index);
+        true
+    }
+
+    /// Returns the messages in flight sent at least `interval` ago, marked as the duplicates to send again.
+    pub fn resend(&mut self, interval: Duration) -> Vec<Publish> {
+        let now = Instant::now();
+        self.in_flight
+            .iter_mut()
+            .filter(|message| now.duration_since(message.sent_at) >= interval)
+            .map(|message| {
+                message.publish.dup = true;
+                message.sent_at = now;
+                message.publish.clone()
+            })
+            .collect()
+    }
+
+    /// Polls the new messages matching the subscriptions, until the number of the messages in flight reaches
+    /// the limit. The partitions created after subscribing (e.g. the topics matching the wildcard) are delivered
+    /// from the beginning. The message matching multiple subscriptions is delivered once, with the highest QoS.
+    pub async fn poll(
+        &mut self,
+        system: &System,
+        session: &Session,
+        max_in_flight: usize,
+    ) -> Vec<Publish> {
+        let mut partitions = BTreeMap::new();
+        for (index, subscription) in self.subscriptions.iter().enumerate() {
+            match resolve_partitions(system, session, &subscription.filter) {
+                Ok(matching_partitions) => {
+                    for partition in matching_partitions {
+                        partitions
+                            .entry(partition.key)
+                            .or_insert_with(|| (partition, Vec::new()))
+                            .1
+                            .push(index);
+                    }
+                }
+                Err(error) => debug!(
+                    "Cannot resolve MQTT subscription: {} of client: {session}, error: {error}",
+                    subscription.filter.filter
+                ),
+            }
+        }
+
+        let mut packets = Vec::new();
+        for (key, (partition, subscription_indexes)) in partitions {
+            if self.in_flight.len() >= max_in_flight {
+                break;
+            }
+            let offset = self.offsets.get(&key).copied().unwrap_or(0);
+            if !has_messages(partition.topic, key.2, offset).await {
+                continue;
+            }
+            let messages = match poll_partition(system, session, key, offset).await {
+                Ok(messages) => messages,
+                Err(error) => {
+                    debug!("Cannot poll messages for MQTT client: {session}, error: {error}");
+                    continue;
+                }
+            };
+
+            for message in messages {
+                if self.in_flight.len() >= max_in_flight {
+                    break;
+                }
+                self.offsets.insert(key, message.offset + 1);
+                // The messages appended by the regular Iggy clients (without the MQTT topic header)
+                // are published to `stream/topic`.
+                let name = get_topic_name(&message).unwrap_or_else(|| {
+                    format!("{}/{}", partition.stream.name, partition.topic.name)
+                });
+                let Some(qos) = subscription_indexes
+                    .iter()
+                    .map(|index| &self.subscriptions[*index])
+                    .filter(|subscription| subscription.filter.matches(&name))
+                    .map(|subscription| subscription.qos)
+                    .max()
+                else {
+                    continue;
+                };
+
+                let mut publish = Publish {
+                    qos,
+                    topic: name,
+                    payload: message.payload,
+                    ..Default::default()
+                };
+                if qos > 0 {
+                    publish.packet_id = Some(self.next_packet_id());
+                    self.in_flight.push_back(InFlightMessage {
+                        publish: publish.clone(),
+                        sent_at: Instant::now(),
+                    });
+                }
+                packets.push(publish);
+            }
+        }
+        packets
+    }
+
+    /// Returns the next packet ID, skipping the ones of the messages in flight.
+    fn next_packet_id(&mut self) -> u16 {
+        loop {
+            self.last_packet_id = self.last_packet_id.checked_add(1).unwrap_or(1);
+            let packet_id = Some(self.last_packet_id);
+            if !self
+                .in_flight
+                .iter()
+                .any(|message| message.publish.packet_id == packet_id)
+            {
+                return self.last_packet_id;
+            }
+        }
+    }
+}
+
+/// Returns the partitions the messages matching the filter can be appended to. As the messages published
+/// by the MQTT clients are partitioned by the topic name, the filter without wildcards having more than
+/// two levels (which can't match the regular Iggy messages) maps onto a single partition.
+fn resolve_partitions<'a>(
+    system: &'a System,
+    session: &Session,
+    filter: &TopicFilter,
+) -> Result<Vec<MatchingPartition<'a>>, IggyError> {
+    let single_partition = filter.is_literal() && filter.filter.matches('/').count() > 1;
+    let mut partitions = Vec::new();
+    for (stream, topic) in resolve_topics(system, session, filter)? {
+        let partitions_count = topic.get_partitions_count();
+        if partitions_count == 0 {
+            continue;
+        }
+        let partition_ids = if single_partition {
+            vec![topic.calculate_partition_id_by_messages_key_hash(filter.filter.as_bytes())]
+        } else {
+            (1..=partitions_count).collect()
+        };
+        partitions.extend(
+            partition_ids
+                .into_iter()
+                .map(|partition_id| MatchingPartition {
+                    key: (topic.stream_id, topic.topic_id, partition_id),
+                    stream,
+                    topic,
+                }),
+        );
+    }
+    Ok(partitions)
+}
+
+/// Returns the topics matching the filter. The topics matching the wildcards are skipped if the user
+/// can't poll their messages, while the explicitly named ones fail the subscription.
+fn resolve_topics<'a>(
+    system: &'a System,
+    session: &Session,
+    filter: &TopicFilter,
+) -> Result<Vec<(&'a Stream, &'a Topic)>, IggyError> {
+    system.ensure_authenticated(session)?;
+    let streams = match &filter.stream {
+        Some(stream) => vec![system.get_stream(&Identifier::named(stream)?)?],
+        None => system.get_streams(),
+    };
+
+    let mut topics = Vec::new();
+    for stream in streams {
+        let stream_id = Identifier::numeric(stream.stream_id)?;
+        let stream_topics = match (&filter.stream, &filter.topic) {
+            (Some(_), Some(topic)) => {
+                vec![system.find_topic(session, &stream_id, &Identifier::named(topic)?)?]
+            }
+            (Some(_), None) => system.find_topics(session, &stream_id)?,
+            (None, Some(topic)) => stream
+                .get_topic(&Identifier::named(topic)?)
+                .into_iter()
+                .collect(),
+            (None, None) => stream.get_topics(),
+        };
+        for topic in stream_topics {
+            match system.permissioner.poll_messages(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            ) {
+                Ok(()) => topics.push((stream, topic)),
+                Err(error) if filter.stream.is_some() && filter.topic.is_some() => {
+                    return Err(error)
+                }
+                Err(_) => {}
+            }
+        }
+    }
+    Ok(topics)
+}
+
+async fn has_messages(topic: &Topic, partition_id: u32, offset: u64) -> bool {
+    let Ok(partition) = topic.get_partition(partition_id) else {
+        return false;
+    };
+    let partition = partition.read().await;
+    partition.should_increment_offset && partition.current_offset >= offset
+}
+
+async fn poll_partition(
+    system: &System,
+    session: &Session,
+    (stream_id, topic_id, partition_id): PartitionKey,
+    offset: u64,
+) -> Result<Vec<PolledMessage>, IggyError> {
+    let polled_messages = system
+        .poll_messages(
+            session,
+            PollingConsumer::Consumer(0, partition_id),
+            &Identifier::numeric(stream_id)?,
+            &Identifier::numeric(topic_id)?,
+            PollingArgs::new(PollingStrategy::offset(offset), POLL_BATCH_SIZE, false),
+        )
+        .await?;
+    Ok(polled_messages.messages)
+}
+
+fn get_topic_name(message: &PolledMessage) -> Option<String> {
+    let key = HeaderKey::new(TOPIC_HEADER).ok()?;
+    let value = message.headers.as_ref()?.get(&key)?;
+    value.as_str().ok().map(|name| name.to_string())
+}
@@ -0,0 +1,192 @@
+/// Reserved header storing the full MQTT topic name, as only its first two levels map onto the stream and topic.
+pub const TOPIC_HEADER: &str = "mqtt-topic";
+
+const LEVEL_SEPARATOR: char = '/';
+const SINGLE_LEVEL_WILDCARD: &str = "+";
+const MULTI_LEVEL_WILDCARD: &str = "#";
+/// The topic name is kept in the header, so it can't be longer than the header value.
+const MAX_TOPIC_NAME_LENGTH: usize = 255;
+
+/// The stream and topic names the MQTT topic name maps onto, e.g. `factory/sensors/device-1/temperature`
+/// is appended to the topic `sensors` of the stream `factory`. The names are case-sensitive, as in MQTT.
+#[derive(Debug, PartialEq)]
+pub(crate) struct TopicName {
+    pub stream: String,
+    pub topic: String,
+}
+
+impl TopicName {
+    /// Parses the name of the published topic, which must have at least two non-empty levels
+    /// and can't contain any wildcards.
+    pub fn parse(name: &str) -> Option<Self> {
+        if name.is_empty()
+            || name.len() > MAX_TOPIC_NAME_LENGTH
+            || name.contains(['+', '#', '\0'])
+            || name.starts_with('$')
+        {
+            return None;
+        }
+        let mut levels = name.split(LEVEL_SEPARATOR);
+        let stream = levels.next().filter(|level| !level.is_empty())?;
+        let topic = levels.next().filter(|level| !level.is_empty())?;
+        Some(Self {
+            stream: stream.to_string(),
+            topic: topic.to_string(),
+        })
+    }
+}
+
+/// The subscription topic filter. The wildcard at the first level (the stream) matches all the streams,
+/// and the one at the second level (the topic) matches all the topics of the matching streams.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TopicFilter {
+    pub filter: String,
+    /// The name of the stream, or `None` if all the streams match.
+    pub stream: Option<String>,
+    /// The name of the topic, or `None` if all the topics of the stream match.
+    pub topic: Option<String>,
+}
+
+impl TopicFilter {
+    pub fn parse(filter: &str) -> Option<Self> {
+        if filter.is_empty() || filter.contains('\0') || filter.starts_with('$') {
+            return None;
+        }
+        let levels = filter.split(LEVEL_SEPARATOR).collect::<Vec<_>>();
+        for (index, level) in levels.iter().enumerate() {
+            let is_last = index == levels.len() - 1;
+            if level.contains('#') && (*level != MULTI_LEVEL_WILDCARD || !is_last) {
+                return None;
+            }
+            if level.contains('+') && *level != SINGLE_LEVEL_WILDCARD {
+                return None;
+            }
+        }
+
+        // The published names have at least two non-empty levels, so the filters which can't match them are rejected.
+        let stream = match levels[0] {
+            MULTI_LEVEL_WILDCARD => {
+                return Some(Self {
+                    filter: filter.to_string(),
+                    stream: None,
+                    topic: None,
+                })
+            }
+            SINGLE_LEVEL_WILDCARD => None,
+            "" => return None,
+            stream => Some(stream.to_string()),
+        };
+        let topic = match levels.get(1) {
+            Some(topic) if is_wildcard(topic) => None,
+            Some(topic) if !topic.is_empty() => Some(topic.to_string()),
+            _ => return None,
+        };
+        Some(Self {
+            filter: filter.to_string(),
+            stream,
+            topic,
+        })
+    }
+
+    /// Checks if the filter matches only a single topic name, e.g. `factory/sensors/device-1`.
+    pub fn is_literal(&self) -> bool {
+        !self.filter.contains(['+', '#'])
+    }
+
+    /// Checks if the topic name matches the filter, following the MQTT wildcards semantics.
+    pub fn matches(&self, name: &str) -> bool {
+        let mut filter_levels = self.filter.split(LEVEL_SEPARATOR);
+        let mut name_levels = name.split(LEVEL_SEPARATOR);
+        loop {
+            match (filter_levels.next(), name_levels.next()) {
+                (Some(MULTI_LEVEL_WILDCARD), _) => return true,
+                (Some(SINGLE_LEVEL_WILDCARD), Some(_)) => continue,
+                (Some(filter_level), Some(name_level)) if filter_level == name_level => continue,
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+}
+
+fn is_wildcard(level: &str) -> bool {
+    level == SINGLE_LEVEL_WILDCARD || level == MULTI_LEVEL_WILDCARD
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topic_name_should_map_onto_stream_and_topic() {
+        assert_eq!(
+            TopicName::parse("Factory/sensors/device-1/temperature"),
+            Some(TopicName {
+                stream: "Factory".to_string(),
+                topic: "sensors".to_string(),
+            })
+        );
+        assert!(TopicName::parse("factory").is_none());
+        assert!(TopicName::parse("factory/").is_none());
+        assert!(TopicName::parse("/factory/sensors").is_none());
+        assert!(TopicName::parse("factory/sensors/+").is_none());
+        assert!(TopicName::parse("$SYS/broker").is_none());
+        assert!(TopicName::parse(&format!("factory/{}", "a".repeat(255))).is_none());
+    }
+
+    #[test]
+    fn topic_filter_should_be_parsed() {
+        let filter = TopicFilter::parse("Factory/sensors/+/temperature").unwrap();
+        assert_eq!(filter.stream.as_deref(), Some("Factory"));
+        assert_eq!(filter.topic.as_deref(), Some("sensors"));
+        assert!(!filter.is_literal());
+        let filter = TopicFilter::parse("factory/#").unwrap();
+        assert_eq!(filter.topic, None);
+        let filter = TopicFilter::parse("factory/+/device-1").unwrap();
+        assert_eq!(filter.topic, None);
+        let filter = TopicFilter::parse("+/sensors").unwrap();
+        assert_eq!(filter.stream, None);
+        assert_eq!(filter.topic.as_deref(), Some("sensors"));
+        let filter = TopicFilter::parse("#").unwrap();
+        assert_eq!((filter.stream, filter.topic), (None, None));
+        assert!(TopicFilter::parse("factory/sensors/device-1")
+            .unwrap()
+            .is_literal());
+
+        assert!(TopicFilter::parse("+").is_none());
+        assert!(TopicFilter::parse("/sensors").is_none());
+        assert!(TopicFilter::parse("factory").is_none());
+        assert!(TopicFilter::parse("factory/#/device").is_none());
+        assert!(TopicFilter::parse("factory/sensors#").is_none());
+        assert!(TopicFilter::parse("factory/sens+").is_none());
+        assert!(TopicFilter::parse("$share/group/factory/sensors").is_none());
+    }
+
+    #[test]
+    fn topic_filter_should_match_topic_names() {
+        let filter = TopicFilter::parse("factory/sensors/+/temperature").unwrap();
+        assert!(filter.matches("factory/sensors/device-1/temperature"));
+        assert!(!filter.matches("factory/sensors/device-1/humidity"));
+        assert!(!filter.matches("factory/sensors/device-1/temperature/celsius"));
+        assert!(!filter.matches("factory/sensors/temperature"));
+
+        let filter = TopicFilter::parse("factory/sensors/#").unwrap();
+        assert!(filter.matches("factory/sensors"));
+        assert!(filter.matches("factory/sensors/device-1"));
+        assert!(filter.matches("factory/sensors/device-1/temperature"));
+        assert!(!filter.matches("factory/actuators/device-1"));
+
+        let filter = TopicFilter::parse("factory/sensors").unwrap();
+        assert!(filter.matches("factory/sensors"));
+        assert!(!filter.matches("factory/sensors/device-1"));
+        assert!(!filter.matches("Factory/sensors"));
+
+        let filter = TopicFilter::parse("+/sensors/#").unwrap();
+        assert!(filter.matches("factory/sensors/device-1"));
+        assert!(filter.matches("warehouse/sensors"));
+        assert!(!filter.matches("factory/actuators/device-1"));
+
+        let filter = TopicFilter::parse("#").unwrap();
+        assert!(filter.matches("factory/sensors/device-1"));
+    }
+}
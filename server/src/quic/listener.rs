@@ -126,7 +126,13 @@ async fn handle_stream(
         send: send_stream,
         recv: recv_stream,
     };
-    command::handle(command, &mut sender, session.as_ref(), system.clone())
-        .await
-        .with_context(|| "Error when handling the QUIC request.")
+    command::handle(
+        command,
+        &mut sender,
+        session.as_ref(),
+        system.clone(),
+        Transport::Quic,
+    )
+    .await
+    .with_context(|| "Error when handling the QUIC request.")
 }
@@ -29,6 +29,12 @@ pub enum ServerError {
     StdoutReloadFailure,
     #[error("Logging file reload failure")]
     FileReloadFailure,
+    #[error("Logging telemetry reload failure")]
+    TelemetryReloadFailure,
+    #[error("Cannot initialize telemetry: {0}")]
+    CannotInitializeTelemetry(String),
+    #[error("Cannot initialize logging: {0}")]
+    CannotInitializeLogging(String),
     #[error("Cache config validation failure: {0}")]
     CacheConfigValidationFailure(String),
     #[error("Command length error: {0}")]
@@ -39,6 +45,8 @@ pub enum ServerError {
     InvalidMessageOffsetFormatConversion,
     #[error("Invalid batch base offset, when performing format conversion")]
     InvalidBatchBaseOffsetFormatConversion,
+    #[error("Invalid message checksum, when performing format conversion")]
+    InvalidMessageChecksumFormatConversion,
     #[error("Cannot read message batch, when performing format conversion, {0}")]
     CannotReadMessageBatchFormatConversion(String),
     #[error("Cannot remove old segment files")]
@@ -1,7 +1,10 @@
+use bytes::Bytes;
+
 #[derive(Debug)]
 pub struct AppendableBatchInfo {
     pub batch_size: u64,
     pub partition_id: u32,
+    pub messages_key: Option<Bytes>,
 }
 
 impl AppendableBatchInfo {
@@ -9,6 +12,12 @@ impl AppendableBatchInfo {
         Self {
             batch_size,
             partition_id,
+            messages_key: None,
         }
     }
+
+    pub fn with_messages_key(mut self, messages_key: Option<Bytes>) -> Self {
+        self.messages_key = messages_key;
+        self
+    }
 }
@@ -5,11 +5,12 @@ use iggy::locking::IggySharedMutFn;
 use iggy::models::user_info::UserId;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use std::net::SocketAddr;
+use std::net::{Ipv4Addr, SocketAddr};
 
 #[derive(Debug, Default)]
 pub struct ClientManager {
     clients: HashMap<u32, IggySharedMut<Client>>,
+    next_client_id: u32,
 }
 
 #[derive(Debug)]
@@ -32,6 +33,11 @@ pub struct ConsumerGroup {
 pub enum Transport {
     Tcp,
     Quic,
+    Http,
+    Grpc,
+    Kafka,
+    Mqtt,
+    Unix,
 }
 
 impl Display for Transport {
@@ -39,6 +45,11 @@ impl Display for Transport {
         match self {
             Transport::Tcp => write!(f, "TCP"),
             Transport::Quic => write!(f, "QUIC"),
+            Transport::Http => write!(f, "HTTP"),
+            Transport::Grpc => write!(f, "gRPC"),
+            Transport::Kafka => write!(f, "Kafka"),
+            Transport::Mqtt => write!(f, "MQTT"),
+            Transport::Unix => write!(f, "Unix"),
         }
     }
 }
@@ -58,6 +69,26 @@ impl ClientManager {
         id
     }
 
+    /// Adds the client connected via the transport without the network address (e.g. Unix domain socket),
+    /// which is identified only by the unused client ID, and its address remains unspecified.
+    pub fn add_client_without_address(&mut self, transport: Transport) -> u32 {
+        let mut id = self.next_client_id;
+        while id == 0 || self.clients.contains_key(&id) {
+            id = id.wrapping_add(1);
+        }
+        self.next_client_id = id.wrapping_add(1);
+        let client = Client {
+            client_id: id,
+            user_id: None,
+            address: SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)),
+            transport,
+            consumer_groups: Vec::new(),
+        };
+        self.clients
+            .insert(client.client_id, IggySharedMut::new(client));
+        id
+    }
+
     pub async fn set_user_id(&mut self, client_id: u32, user_id: UserId) -> Result<(), IggyError> {
         let client = self.clients.get(&client_id);
         if client.is_none() {
@@ -119,9 +150,8 @@ impl ClientManager {
         Ok(())
     }
 
-    pub fn delete_client(&mut self, address: &SocketAddr) -> Option<IggySharedMut<Client>> {
-        let id = hash::calculate_32(address.to_string().as_bytes());
-        self.clients.remove(&id)
+    pub fn delete_client(&mut self, client_id: u32) -> Option<IggySharedMut<Client>> {
+        self.clients.remove(&client_id)
     }
 
     pub async fn join_consumer_group(
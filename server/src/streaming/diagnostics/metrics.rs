@@ -1,9 +1,120 @@
 use prometheus_client::encoding::text::encode;
+use prometheus_client::encoding::EncodeLabelSet;
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
 use prometheus_client::registry::Registry;
+use std::sync::OnceLock;
+use std::time::Duration;
 use tracing::error;
 
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct PartitionLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct ConsumerLagLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub consumer_kind: String,
+    pub consumer_id: u32,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct RequestLabels {
+    pub transport: String,
+    pub command: String,
+}
+
+type HistogramFamily<S> = Family<S, Histogram, fn() -> Histogram>;
+
+/// Process-wide metrics recorded on the hot paths of the partitions and segments,
+/// which don't have access to the system, in the same way as the cache memory tracker.
+#[derive(Debug)]
+pub(crate) struct StreamingMetrics {
+    messages_in: Family<PartitionLabels, Counter>,
+    bytes_in: Family<PartitionLabels, Counter>,
+    messages_out: Family<PartitionLabels, Counter>,
+    bytes_out: Family<PartitionLabels, Counter>,
+    cache_hits: Family<PartitionLabels, Counter>,
+    cache_misses: Family<PartitionLabels, Counter>,
+    persist_duration: Histogram,
+    sync_duration: Histogram,
+    request_duration: HistogramFamily<RequestLabels>,
+}
+
+impl StreamingMetrics {
+    pub fn get() -> &'static StreamingMetrics {
+        static METRICS: OnceLock<StreamingMetrics> = OnceLock::new();
+        METRICS.get_or_init(|| StreamingMetrics {
+            messages_in: Family::default(),
+            bytes_in: Family::default(),
+            messages_out: Family::default(),
+            bytes_out: Family::default(),
+            cache_hits: Family::default(),
+            cache_misses: Family::default(),
+            persist_duration: create_duration_histogram(),
+            sync_duration: create_duration_histogram(),
+            request_duration: Family::new_with_constructor(create_duration_histogram),
+        })
+    }
+
+    pub fn record_messages_in(&self, labels: &PartitionLabels, messages: u64, bytes: u64) {
+        self.messages_in.get_or_create(labels).inc_by(messages);
+        self.bytes_in.get_or_create(labels).inc_by(bytes);
+    }
+
+    pub fn record_messages_out(&self, labels: &PartitionLabels, messages: u64, bytes: u64) {
+        self.messages_out.get_or_create(labels).inc_by(messages);
+        self.bytes_out.get_or_create(labels).inc_by(bytes);
+    }
+
+    pub fn record_cache_hit(&self, labels: &PartitionLabels) {
+        self.cache_hits.get_or_create(labels).inc();
+    }
+
+    pub fn record_cache_miss(&self, labels: &PartitionLabels) {
+        self.cache_misses.get_or_create(labels).inc();
+    }
+
+    pub fn record_persist_duration(&self, duration: Duration) {
+        self.persist_duration.observe(duration.as_secs_f64());
+    }
+
+    pub fn record_sync_duration(&self, duration: Duration) {
+        self.sync_duration.observe(duration.as_secs_f64());
+    }
+
+    pub fn record_request_duration(&self, transport: &str, command: &str, duration: Duration) {
+        self.request_duration
+            .get_or_create(&RequestLabels {
+                transport: transport.to_string(),
+                command: command.to_string(),
+            })
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Removes the label sets of the deleted partition, so that the stale series are no longer exposed.
+    pub fn remove_partition(&self, labels: &PartitionLabels) {
+        self.messages_in.remove(labels);
+        self.bytes_in.remove(labels);
+        self.messages_out.remove(labels);
+        self.bytes_out.remove(labels);
+        self.cache_hits.remove(labels);
+        self.cache_misses.remove(labels);
+    }
+}
+
+fn create_duration_histogram() -> Histogram {
+    // From 50 microseconds up to ~3.3 seconds.
+    Histogram::new(exponential_buckets(0.00005, 2.0, 17))
+}
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
@@ -15,6 +126,9 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    scheduled_messages: Gauge,
+    delivered_scheduled_messages: Counter,
+    consumer_lag: Family<ConsumerLagLabels, Gauge>,
 }
 
 impl Metrics {
@@ -29,6 +143,9 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            scheduled_messages: Gauge::default(),
+            delivered_scheduled_messages: Counter::default(),
+            consumer_lag: Family::default(),
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
@@ -39,10 +156,75 @@ impl Metrics {
         metrics.register_gauge("messages", metrics.messages.clone());
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
+        metrics.registry.register(
+            "scheduled_messages",
+            "number of delayed or scheduled messages waiting for the delivery",
+            metrics.scheduled_messages.clone(),
+        );
+        metrics.registry.register(
+            "delivered_scheduled_messages",
+            "total count of delayed or scheduled messages appended to the topics once due",
+            metrics.delivered_scheduled_messages.clone(),
+        );
+        metrics.register_streaming_metrics(StreamingMetrics::get());
 
         metrics
     }
 
+    fn register_streaming_metrics(&mut self, streaming: &StreamingMetrics) {
+        let registry = &mut self.registry;
+        registry.register(
+            "partition_messages_in",
+            "total count of messages appended to the partition",
+            streaming.messages_in.clone(),
+        );
+        registry.register(
+            "partition_bytes_in",
+            "total size of messages appended to the partition",
+            streaming.bytes_in.clone(),
+        );
+        registry.register(
+            "partition_messages_out",
+            "total count of messages polled from the partition",
+            streaming.messages_out.clone(),
+        );
+        registry.register(
+            "partition_bytes_out",
+            "total size of messages polled from the partition",
+            streaming.bytes_out.clone(),
+        );
+        registry.register(
+            "partition_cache_hits",
+            "total count of polls served from the partition cache",
+            streaming.cache_hits.clone(),
+        );
+        registry.register(
+            "partition_cache_misses",
+            "total count of polls served from the disk",
+            streaming.cache_misses.clone(),
+        );
+        registry.register(
+            "consumer_lag",
+            "number of messages not yet consumed by the consumer or consumer group",
+            self.consumer_lag.clone(),
+        );
+        registry.register(
+            "segment_persist_duration_seconds",
+            "duration of persisting the messages on disk",
+            streaming.persist_duration.clone(),
+        );
+        registry.register(
+            "segment_sync_duration_seconds",
+            "duration of syncing (fsync) the persisted messages",
+            streaming.sync_duration.clone(),
+        );
+        registry.register(
+            "request_duration_seconds",
+            "duration of handling the request per command and transport",
+            streaming.request_duration.clone(),
+        );
+    }
+
     fn register_counter(&mut self, name: &str, counter: Counter) {
         self.registry
             .register(name, format!("total count of {name}"), counter)
@@ -61,6 +243,15 @@ impl Metrics {
         buffer
     }
 
+    /// Consumer lag is computed at the scrape time, thus the previous values are cleared first.
+    pub fn clear_consumer_lag(&self) {
+        self.consumer_lag.clear();
+    }
+
+    pub fn set_consumer_lag(&self, labels: &ConsumerLagLabels, lag: u64) {
+        self.consumer_lag.get_or_create(labels).set(lag as i64);
+    }
+
     pub fn increment_http_requests(&self) {
         self.http_requests.inc();
     }
@@ -120,4 +311,55 @@ impl Metrics {
     pub fn decrement_clients(&self, count: u32) {
         self.clients.dec_by(count as i64);
     }
+
+    pub fn increment_scheduled_messages(&self, count: u64) {
+        self.scheduled_messages.inc_by(count as i64);
+    }
+
+    pub fn decrement_scheduled_messages(&self, count: u64) {
+        self.scheduled_messages.dec_by(count as i64);
+    }
+
+    pub fn increment_delivered_scheduled_messages(&self, count: u64) {
+        self.delivered_scheduled_messages.inc_by(count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labelled_metrics_should_be_included_in_formatted_output() {
+        let metrics = Metrics::init();
+        let labels = PartitionLabels {
+            stream_id: 1001,
+            topic_id: 2002,
+            partition_id: 3003,
+        };
+        StreamingMetrics::get().record_messages_in(&labels, 10, 1000);
+        metrics.set_consumer_lag(
+            &ConsumerLagLabels {
+                stream_id: 1001,
+                topic_id: 2002,
+                partition_id: 3003,
+                consumer_kind: "consumer".to_string(),
+                consumer_id: 4004,
+            },
+            5,
+        );
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains(
+            r#"partition_messages_in_total{stream_id="1001",topic_id="2002",partition_id="3003"} 10"#
+        ));
+        assert!(output.contains(
+            r#"consumer_lag{stream_id="1001",topic_id="2002",partition_id="3003",consumer_kind="consumer",consumer_id="4004"} 5"#
+        ));
+
+        StreamingMetrics::get().remove_partition(&labels);
+        metrics.clear_consumer_lag();
+        let output = metrics.get_formatted_output();
+        assert!(!output.contains(r#"partition_id="3003""#));
+    }
 }
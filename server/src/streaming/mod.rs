@@ -8,6 +8,8 @@ pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod scheduling;
+pub mod schemas;
 pub mod segments;
 pub mod session;
 pub mod sizeable;
@@ -25,6 +25,7 @@ pub struct RetainedMessage {
     pub checksum: u32,
     pub message_state: MessageState,
     pub headers: Option<Bytes>,
+    pub key: Option<Bytes>,
     pub payload: Bytes,
 }
 
@@ -39,6 +40,7 @@ impl TryFrom<RetainedMessage> for PolledMessage {
             id: value.id,
             checksum: value.checksum,
             headers,
+            key: value.key,
             length: value.payload.len() as u32,
             payload: value.payload,
         };
@@ -47,7 +49,7 @@ impl TryFrom<RetainedMessage> for PolledMessage {
 }
 
 impl RetainedMessage {
-    pub fn new(offset: u64, timestamp: u64, message: Message) -> Self {
+    pub fn new(offset: u64, timestamp: u64, message: Message, key: Option<Bytes>) -> Self {
         RetainedMessage {
             offset,
             timestamp,
@@ -56,6 +58,7 @@ impl RetainedMessage {
             id: message.id,
             payload: message.payload.clone(),
             headers: message.headers.as_ref().map(|headers| headers.as_bytes()),
+            key,
         }
     }
 
@@ -68,6 +71,7 @@ impl RetainedMessage {
         let checksum = self.checksum;
         let message_state = self.message_state;
         let headers = &self.headers;
+        let key = &self.key;
 
         bytes.put_u32_le(length);
         bytes.put_u64_le(offset);
@@ -82,6 +86,13 @@ impl RetainedMessage {
         } else {
             bytes.put_u32_le(0u32);
         }
+        if let Some(key) = key {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(key.len() as u8);
+            bytes.put_slice(key);
+        } else {
+            bytes.put_u8(0);
+        }
         bytes.put_slice(&payload);
     }
 
@@ -98,6 +109,13 @@ impl RetainedMessage {
             None
         };
         let position = 41 + headers_length as usize;
+        let key_length = bytes[position] as usize;
+        let key = if key_length > 0 {
+            Some(bytes.slice(position + 1..position + 1 + key_length))
+        } else {
+            None
+        };
+        let position = position + 1 + key_length;
         let payload = bytes.slice(position..);
 
         Ok(RetainedMessage {
@@ -107,6 +125,7 @@ impl RetainedMessage {
             checksum,
             message_state,
             headers,
+            key,
             payload,
         })
     }
@@ -119,6 +138,7 @@ impl Sizeable for RetainedMessage {
             .as_ref()
             .map(|h| 4 + h.len() as u32)
             .unwrap_or(4);
-        16 + 8 + 8 + 4 + 1 + headers_len + self.payload.len() as u32
+        let key_len = self.key.as_ref().map_or(1, |k| 1 + k.len() as u32);
+        16 + 8 + 8 + 4 + 1 + headers_len + key_len + self.payload.len() as u32
     }
 }
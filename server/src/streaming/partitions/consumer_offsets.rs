@@ -2,7 +2,10 @@ use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::polling_consumer::PollingConsumer;
 use dashmap::DashMap;
 use iggy::consumer::ConsumerKind;
+use iggy::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
 use iggy::error::IggyError;
+use iggy::models::consumer_lag::PartitionLag;
+use iggy::models::consumer_offsets_reset::PartitionOffsetReset;
 use tracing::trace;
 
 impl Partition {
@@ -21,8 +24,8 @@ impl Partition {
                     return Ok(consumer_offset.offset);
                 }
             }
-            PollingConsumer::ConsumerGroup(consumer_group_id, _) => {
-                let consumer_offset = self.consumer_offsets.get(&consumer_group_id);
+            PollingConsumer::ConsumerGroup(consumer_group_id, ..) => {
+                let consumer_offset = self.consumer_group_offsets.get(&consumer_group_id);
                 if let Some(consumer_offset) = consumer_offset {
                     return Ok(consumer_offset.offset);
                 }
@@ -53,7 +56,7 @@ impl Partition {
                 self.store_offset(ConsumerKind::Consumer, consumer_id, offset)
                     .await?;
             }
-            PollingConsumer::ConsumerGroup(consumer_id, _) => {
+            PollingConsumer::ConsumerGroup(consumer_id, ..) => {
                 self.store_offset(ConsumerKind::ConsumerGroup, consumer_id, offset)
                     .await?;
             }
@@ -99,6 +102,111 @@ impl Partition {
         Ok(())
     }
 
+    pub async fn get_consumer_lag(
+        &self,
+        kind: ConsumerKind,
+        consumer_id: u32,
+    ) -> Result<PartitionLag, IggyError> {
+        let stored_offset = self
+            .get_consumer_offsets(kind)
+            .get(&consumer_id)
+            .map(|consumer_offset| consumer_offset.offset);
+        let end_offset = self.get_next_offset();
+        let mut partition_lag = PartitionLag {
+            partition_id: self.partition_id,
+            stored_offset,
+            end_offset,
+            lag: 0,
+            estimated_lag_time: 0,
+        };
+
+        if self.segments.is_empty() || end_offset == 0 {
+            return Ok(partition_lag);
+        }
+
+        // The messages before the first available offset are already gone, so they don't count as lag.
+        let first_offset = self.get_first_offset();
+        let next_offset = stored_offset
+            .map(|offset| offset + 1)
+            .unwrap_or(first_offset)
+            .max(first_offset);
+        if next_offset >= end_offset {
+            return Ok(partition_lag);
+        }
+
+        partition_lag.lag = end_offset - next_offset;
+        let next_timestamp = self.get_timestamp_for_offset(next_offset).await?;
+        let last_timestamp = self.get_timestamp_for_offset(self.current_offset).await?;
+        if let (Some(next_timestamp), Some(last_timestamp)) = (next_timestamp, last_timestamp) {
+            partition_lag.estimated_lag_time = last_timestamp.saturating_sub(next_timestamp);
+        }
+
+        Ok(partition_lag)
+    }
+
+    pub async fn reset_consumer_offset(
+        &self,
+        kind: ConsumerKind,
+        consumer_id: u32,
+        strategy: OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<PartitionOffsetReset, IggyError> {
+        let consumer_offsets = self.get_consumer_offsets(kind);
+        let previous_offset = consumer_offsets
+            .get(&consumer_id)
+            .map(|consumer_offset| consumer_offset.offset);
+        let first_offset = self.get_first_offset();
+        let end_offset = self.get_next_offset().max(first_offset);
+        let current_next_offset = previous_offset
+            .map(|offset| offset + 1)
+            .unwrap_or(first_offset);
+
+        // The strategy resolves the offset of the next message to be consumed.
+        let next_offset = match strategy {
+            OffsetResetStrategy::Earliest => first_offset,
+            OffsetResetStrategy::Latest => end_offset,
+            OffsetResetStrategy::Timestamp(timestamp) => self
+                .get_offset_for_timestamp(timestamp)
+                .await?
+                .unwrap_or(end_offset),
+            OffsetResetStrategy::Offset(offset) => offset,
+            OffsetResetStrategy::Shift(shift) => current_next_offset.saturating_add_signed(shift),
+        }
+        .clamp(first_offset, end_offset);
+
+        // The stored offset points to the last consumed message, so nothing is stored when starting from the very beginning.
+        let new_offset = next_offset.checked_sub(1);
+        trace!(
+            "Resetting offset for {} with ID: {}, partition: {} from: {:?} to: {:?}, dry run: {}...",
+            kind,
+            consumer_id,
+            self.partition_id,
+            previous_offset,
+            new_offset,
+            dry_run
+        );
+
+        if !dry_run {
+            match new_offset {
+                Some(offset) => self.store_offset(kind, consumer_id, offset).await?,
+                None => {
+                    if let Some((_, consumer_offset)) = consumer_offsets.remove(&consumer_id) {
+                        self.storage
+                            .partition
+                            .delete_consumer_offset(&consumer_offset)
+                            .await?;
+                    }
+                }
+            }
+        }
+
+        Ok(PartitionOffsetReset {
+            partition_id: self.partition_id,
+            previous_offset,
+            new_offset,
+        })
+    }
+
     pub async fn load_consumer_offsets(&mut self) -> Result<(), IggyError> {
         trace!(
                 "Loading consumer offsets for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
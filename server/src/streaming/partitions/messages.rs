@@ -1,14 +1,18 @@
 use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use crate::streaming::batching::batch_filter::BatchItemizer;
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::diagnostics::metrics::StreamingMetrics;
 use crate::streaming::models::messages::RetainedMessage;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::segments::segment::Segment;
 use bytes::BytesMut;
 use iggy::messages::send_messages::Message;
-use iggy::models::messages::POLLED_MESSAGE_METADATA;
+use iggy::models::appended_messages::AppendedMessages;
+use iggy::models::messages::{PolledMessage, POLLED_MESSAGE_METADATA};
+use iggy::utils::durability::Durability;
 use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::timestamp_type::TimestampType;
 use iggy::{error::IggyError, utils::duration::IggyDuration};
 use std::sync::{atomic::Ordering, Arc};
 use std::time::Duration;
@@ -136,12 +140,13 @@ impl Partition {
             return Ok(EMPTY_MESSAGES);
         }
 
+        // The messages before the low watermark were deleted, so they are never returned, regardless of the polling strategy.
+        let start_offset = start_offset.max(self.low_watermark);
         if start_offset > self.current_offset {
             return Ok(EMPTY_MESSAGES);
         }
 
         let end_offset = self.get_end_offset(start_offset, count);
-
         let messages = self.try_get_messages_from_cache(start_offset, end_offset);
         if let Some(messages) = messages {
             return Ok(messages);
@@ -155,6 +160,32 @@ impl Partition {
         }
     }
 
+    /// Records the metrics of the messages returned to the client, which excludes the internal reads of the partition.
+    /// The poll counts as the cache hit if its first message is still buffered in the cache.
+    pub fn record_polled_messages(&self, messages: &[PolledMessage]) {
+        let Some(first_message) = messages.first() else {
+            return;
+        };
+
+        let metrics = StreamingMetrics::get();
+        let labels = self.get_metrics_labels();
+        let cache_hit = self
+            .cache
+            .as_ref()
+            .is_some_and(|cache| !cache.is_empty() && cache[0].base_offset <= first_message.offset);
+        if cache_hit {
+            metrics.record_cache_hit(&labels);
+        } else {
+            metrics.record_cache_miss(&labels);
+        }
+
+        let size_bytes = messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum();
+        metrics.record_messages_out(&labels, messages.len() as u64, size_bytes);
+    }
+
     pub async fn get_first_messages(&self, count: u32) -> Result<Vec<RetainedMessage>, IggyError> {
         self.get_messages_by_offset(0, count).await
     }
@@ -177,7 +208,9 @@ impl Partition {
     ) -> Result<Vec<RetainedMessage>, IggyError> {
         let (consumer_offsets, consumer_id) = match consumer {
             PollingConsumer::Consumer(consumer_id, _) => (&self.consumer_offsets, consumer_id),
-            PollingConsumer::ConsumerGroup(group_id, _) => (&self.consumer_group_offsets, group_id),
+            PollingConsumer::ConsumerGroup(group_id, ..) => {
+                (&self.consumer_group_offsets, group_id)
+            }
         };
 
         let consumer_offset = consumer_offsets.get(&consumer_id);
@@ -376,7 +409,7 @@ impl Partition {
         &mut self,
         appendable_batch_info: AppendableBatchInfo,
         messages: Vec<Message>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<AppendedMessages, IggyError> {
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
             if last_segment.is_closed {
@@ -390,55 +423,68 @@ impl Partition {
         }
 
         let batch_size = appendable_batch_info.batch_size
-            + (POLLED_MESSAGE_METADATA * messages.len() as u32) as u64;
+            + ((POLLED_MESSAGE_METADATA
+                + appendable_batch_info
+                    .messages_key
+                    .as_ref()
+                    .map_or(0, |key| key.len() as u32))
+                * messages.len() as u32) as u64;
         let base_offset = if !self.should_increment_offset {
             0
         } else {
             self.current_offset + 1
         };
 
+        let timestamp_type = self.config.partition.timestamp_type;
+        let now = IggyTimestamp::now().to_micros();
+        if timestamp_type == TimestampType::CreateTime {
+            self.validate_messages_timestamps(&messages, now)?;
+        }
+
         let mut messages_count = 0u32;
-        // assume that messages have monotonic timestamps
+        let mut skipped_indexes = Vec::new();
         let mut max_timestamp = 0;
-        let mut min_timestamp = 0;
+        let mut min_timestamp = u64::MAX;
 
         let mut buffer = BytesMut::with_capacity(batch_size as usize);
         let mut batch_builder = RetainedMessageBatch::builder();
         batch_builder = batch_builder.base_offset(base_offset);
-        if let Some(message_deduplicator) = &self.message_deduplicator {
-            for message in messages {
+        for (index, message) in messages.into_iter().enumerate() {
+            if let Some(message_deduplicator) = &self.message_deduplicator {
                 if !message_deduplicator.try_insert(&message.id).await {
                     warn!(
                         "Ignored the duplicated message ID: {} for partition with ID: {}.",
                         message.id, self.partition_id
                     );
+                    skipped_indexes.push(index as u32);
                     continue;
                 }
-                max_timestamp = IggyTimestamp::now().to_micros();
-
-                if messages_count == 0 {
-                    min_timestamp = max_timestamp;
-                }
-                let message_offset = base_offset + messages_count as u64;
-                let message = RetainedMessage::new(message_offset, max_timestamp, message);
-                message.extend(&mut buffer);
-                messages_count += 1;
             }
-        } else {
-            for message in messages {
-                max_timestamp = IggyTimestamp::now().to_micros();
 
-                if messages_count == 0 {
-                    min_timestamp = max_timestamp;
-                }
-                let message_offset = base_offset + messages_count as u64;
-                let message = RetainedMessage::new(message_offset, max_timestamp, message);
-                message.extend(&mut buffer);
-                messages_count += 1;
-            }
+            let timestamp = match timestamp_type {
+                TimestampType::CreateTime if message.timestamp > 0 => message.timestamp,
+                _ => IggyTimestamp::now().to_micros(),
+            };
+            max_timestamp = max_timestamp.max(timestamp);
+            min_timestamp = min_timestamp.min(timestamp);
+            let message_offset = base_offset + messages_count as u64;
+            let message = RetainedMessage::new(
+                message_offset,
+                timestamp,
+                message,
+                appendable_batch_info.messages_key.clone(),
+            );
+            message.extend(&mut buffer);
+            messages_count += 1;
         }
+        let appended_messages = AppendedMessages {
+            partition_id: self.partition_id,
+            base_offset,
+            messages_count,
+            skipped_indexes,
+        };
         if messages_count == 0 {
-            return Ok(());
+            return Ok(appended_messages);
         }
 
         let avg_timestamp_delta =
@@ -471,14 +517,21 @@ impl Partition {
             last_segment.append_batch(batch.clone()).await?;
         }
 
+        let batch_length = batch.length;
         if let Some(cache) = &mut self.cache {
             cache.append(batch);
         }
 
+        StreamingMetrics::get().record_messages_in(
+            &self.get_metrics_labels(),
+            messages_count as u64,
+            batch_length as u64,
+        );
         self.unsaved_messages_count += messages_count;
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
-            if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
+            if self.durability.requires_immediate_write()
+                || self.unsaved_messages_count >= self.config.partition.messages_required_to_save
                 || last_segment.is_full().await
             {
                 trace!(
@@ -489,6 +542,34 @@ impl Partition {
 
                 last_segment.persist_messages().await.unwrap();
                 self.unsaved_messages_count = 0;
+                self.durability_tracker.mark_written(last_offset + 1);
+                // The closed segment won't be synced by the message saver or the group committer anymore.
+                if self.durability == Durability::EveryBatch
+                    || (self.durability != Durability::None && last_segment.is_closed)
+                {
+                    last_segment.sync().await?;
+                    self.durability_tracker.mark_durable(last_offset + 1);
+                }
+            }
+        }
+
+        Ok(appended_messages)
+    }
+
+    fn validate_messages_timestamps(
+        &self,
+        messages: &[Message],
+        now: u64,
+    ) -> Result<(), IggyError> {
+        let max_timestamp_skew = self.config.partition.max_timestamp_skew;
+        if max_timestamp_skew.is_zero() {
+            return Ok(());
+        }
+
+        let max_timestamp_skew = max_timestamp_skew.as_micros();
+        for message in messages {
+            if message.timestamp > 0 && message.timestamp.abs_diff(now) > max_timestamp_skew {
+                return Err(IggyError::InvalidMessageTimestamp(message.timestamp));
             }
         }
 
@@ -520,18 +601,21 @@ mod tests {
     use std::sync::atomic::{AtomicU32, AtomicU64};
 
     use super::*;
-    use crate::configs::system::{MessageDeduplicationConfig, SystemConfig};
+    use crate::configs::system::{MessageDeduplicationConfig, SegmentConfig, SystemConfig};
     use crate::streaming::partitions::create_messages;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use bytes::Bytes;
+    use iggy::consumer::ConsumerKind;
 
     #[tokio::test]
     async fn given_disabled_message_deduplication_all_messages_should_be_appended() {
-        let mut partition = create_partition(false);
+        let mut partition = create_partition(false, Durability::None);
         let messages = create_messages();
         let messages_count = messages.len() as u32;
         let appendable_batch_info = AppendableBatchInfo {
             batch_size: messages.iter().map(|m| m.get_size_bytes() as u64).sum(),
             partition_id: partition.partition_id,
+            messages_key: None,
         };
         partition
             .append_messages(appendable_batch_info, messages)
@@ -545,15 +629,43 @@ mod tests {
         assert_eq!(loaded_messages.len(), messages_count as usize);
     }
 
+    #[tokio::test]
+    async fn given_messages_key_it_should_be_stored_with_each_message() {
+        let mut partition = create_partition(false, Durability::None);
+        let messages = create_messages();
+        let messages_count = messages.len() as u32;
+        let messages_key = Bytes::from("user-1");
+        let appendable_batch_info = AppendableBatchInfo::new(
+            messages.iter().map(|m| m.get_size_bytes() as u64).sum(),
+            partition.partition_id,
+        )
+        .with_messages_key(Some(messages_key.clone()));
+        partition
+            .append_messages(appendable_batch_info, messages)
+            .await
+            .unwrap();
+
+        let loaded_messages = partition
+            .get_messages_by_offset(0, messages_count)
+            .await
+            .unwrap();
+        assert_eq!(loaded_messages.len(), messages_count as usize);
+        for (index, message) in loaded_messages.iter().enumerate() {
+            assert_eq!(message.offset, index as u64);
+            assert_eq!(message.key.as_ref(), Some(&messages_key));
+        }
+    }
+
     #[tokio::test]
     async fn given_enabled_message_deduplication_only_messages_with_unique_id_should_be_appended() {
-        let mut partition = create_partition(true);
+        let mut partition = create_partition(true, Durability::None);
         let messages = create_messages();
         let messages_count = messages.len() as u32;
         let unique_messages_count = 3;
         let appendable_batch_info = AppendableBatchInfo {
             batch_size: messages.iter().map(|m| m.get_size_bytes() as u64).sum(),
             partition_id: partition.partition_id,
+            messages_key: None,
         };
         partition
             .append_messages(appendable_batch_info, messages)
@@ -567,19 +679,133 @@ mod tests {
         assert_eq!(loaded_messages.len(), unique_messages_count);
     }
 
-    fn create_partition(deduplication_enabled: bool) -> Partition {
-        let storage = Arc::new(get_test_system_storage());
-        let stream_id = 1;
-        let topic_id = 2;
-        let partition_id = 3;
-        let with_segment = true;
-        let config = Arc::new(SystemConfig {
+    #[tokio::test]
+    async fn given_every_batch_durability_appended_messages_should_be_durable() {
+        let mut partition = create_partition(false, Durability::EveryBatch);
+        let messages = create_messages();
+        let messages_count = messages.len() as u64;
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages.iter().map(|m| m.get_size_bytes() as u64).sum(),
+            partition_id: partition.partition_id,
+            messages_key: None,
+        };
+        partition
+            .append_messages(appendable_batch_info, messages)
+            .await
+            .unwrap();
+
+        assert_eq!(partition.unsaved_messages_count, 0);
+        assert_eq!(
+            partition.durability_tracker.durable_offset(),
+            messages_count
+        );
+        assert!(partition.ensure_durability().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn given_group_commit_durability_appended_messages_should_be_durable_after_sync() {
+        let mut partition = create_partition(false, Durability::GroupCommit);
+        let messages = create_messages();
+        let messages_count = messages.len() as u64;
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages.iter().map(|m| m.get_size_bytes() as u64).sum(),
+            partition_id: partition.partition_id,
+            messages_key: None,
+        };
+        partition
+            .append_messages(appendable_batch_info, messages)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            partition.durability_tracker.written_offset(),
+            messages_count
+        );
+        assert_eq!(partition.durability_tracker.durable_offset(), 0);
+        let durability_waiter = partition.ensure_durability().await.unwrap().unwrap();
+
+        partition.sync_messages().await.unwrap();
+        assert_eq!(
+            partition.durability_tracker.durable_offset(),
+            messages_count
+        );
+        assert!(durability_waiter.wait().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn given_none_durability_ensuring_durability_should_persist_and_sync_messages() {
+        let mut partition = create_partition(false, Durability::None);
+        let messages = create_messages();
+        let messages_count = messages.len() as u64;
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages.iter().map(|m| m.get_size_bytes() as u64).sum(),
+            partition_id: partition.partition_id,
+            messages_key: None,
+        };
+        partition
+            .append_messages(appendable_batch_info, messages)
+            .await
+            .unwrap();
+
+        assert!(partition.unsaved_messages_count > 0);
+        assert_eq!(partition.durability_tracker.durable_offset(), 0);
+        assert!(partition.ensure_durability().await.unwrap().is_none());
+        assert_eq!(partition.unsaved_messages_count, 0);
+        assert_eq!(
+            partition.durability_tracker.durable_offset(),
+            messages_count
+        );
+    }
+
+    #[tokio::test]
+    async fn given_unsaved_messages_consumer_lag_should_include_estimated_lag_time() {
+        let config = SystemConfig {
+            segment: SegmentConfig {
+                cache_time_indexes: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut partition = create_partition_with_config(config, Durability::None);
+        for _ in 0..2 {
+            let messages = create_messages();
+            let appendable_batch_info = AppendableBatchInfo::new(
+                messages.iter().map(|m| m.get_size_bytes() as u64).sum(),
+                partition.partition_id,
+            );
+            partition
+                .append_messages(appendable_batch_info, messages)
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let partition_lag = partition
+            .get_consumer_lag(ConsumerKind::Consumer, 1)
+            .await
+            .unwrap();
+        assert_eq!(partition_lag.lag, partition.get_messages_count());
+        assert!(partition_lag.estimated_lag_time > 0);
+    }
+
+    fn create_partition(deduplication_enabled: bool, durability: Durability) -> Partition {
+        let config = SystemConfig {
             message_deduplication: MessageDeduplicationConfig {
                 enabled: deduplication_enabled,
                 ..Default::default()
             },
             ..Default::default()
-        });
+        };
+        create_partition_with_config(config, durability)
+    }
+
+    fn create_partition_with_config(config: SystemConfig, durability: Durability) -> Partition {
+        let storage = Arc::new(get_test_system_storage());
+        let stream_id = 1;
+        let topic_id = 2;
+        let partition_id = 3;
+        let with_segment = true;
+        let config = Arc::new(config);
         Partition::create(
             stream_id,
             topic_id,
@@ -593,6 +819,7 @@ mod tests {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            durability,
         )
     }
 }
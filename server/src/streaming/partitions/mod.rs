@@ -7,6 +7,7 @@ pub mod partition;
 pub mod persistence;
 pub mod segments;
 pub mod storage;
+pub mod time_indexes;
 
 #[allow(dead_code)]
 fn create_messages() -> Vec<send_messages::Message> {
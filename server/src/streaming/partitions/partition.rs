@@ -3,10 +3,14 @@ use crate::streaming::batching::message_batch::RetainedMessageBatch;
 use crate::streaming::cache::buffer::SmartCache;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::deduplication::message_deduplicator::MessageDeduplicator;
+use crate::streaming::diagnostics::metrics::PartitionLabels;
+use crate::streaming::persistence::durability::DurabilityTracker;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::storage::SystemStorage;
 use dashmap::DashMap;
 use iggy::consumer::ConsumerKind;
+use iggy::error::IggyError;
+use iggy::utils::durability::Durability;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
@@ -19,6 +23,7 @@ pub struct Partition {
     pub partition_id: u32,
     pub path: String,
     pub current_offset: u64,
+    pub low_watermark: u64,
     pub cache: Option<SmartCache<Arc<RetainedMessageBatch>>>,
     pub cached_memory_tracker: Option<Arc<CacheMemoryTracker>>,
     pub message_deduplicator: Option<MessageDeduplicator>,
@@ -34,6 +39,8 @@ pub struct Partition {
     pub size_bytes: Arc<AtomicU64>,
     pub segments_count_of_parent_stream: Arc<AtomicU32>,
     pub(crate) message_expiry: Option<u32>,
+    pub(crate) durability: Durability,
+    pub(crate) durability_tracker: DurabilityTracker,
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) segments: Vec<Segment>,
@@ -94,6 +101,7 @@ impl Partition {
         size_of_parent_stream: Arc<AtomicU64>,
         size_of_parent_topic: Arc<AtomicU64>,
         segments_count_of_parent_stream: Arc<AtomicU32>,
+        durability: Durability,
     ) -> Partition {
         let path = config.get_partition_path(stream_id, topic_id, partition_id);
         let (cached_memory_tracker, messages) = match config.cache.enabled {
@@ -110,27 +118,14 @@ impl Partition {
             partition_id,
             path,
             message_expiry,
+            durability,
+            durability_tracker: DurabilityTracker::default(),
             cache: messages,
             cached_memory_tracker,
-            message_deduplicator: match config.message_deduplication.enabled {
-                true => Some(MessageDeduplicator::new(
-                    if config.message_deduplication.max_entries > 0 {
-                        Some(config.message_deduplication.max_entries)
-                    } else {
-                        None
-                    },
-                    {
-                        if config.message_deduplication.expiry.is_zero() {
-                            None
-                        } else {
-                            Some(config.message_deduplication.expiry)
-                        }
-                    },
-                )),
-                false => None,
-            },
+            message_deduplicator: Self::create_message_deduplicator(&config),
             segments: vec![],
             current_offset: 0,
+            low_watermark: 0,
             unsaved_messages_count: 0,
             should_increment_offset: false,
             consumer_offsets: DashMap::new(),
@@ -173,9 +168,91 @@ impl Partition {
         partition
     }
 
+    fn create_message_deduplicator(config: &SystemConfig) -> Option<MessageDeduplicator> {
+        match config.message_deduplication.enabled {
+            true => Some(MessageDeduplicator::new(
+                if config.message_deduplication.max_entries > 0 {
+                    Some(config.message_deduplication.max_entries)
+                } else {
+                    None
+                },
+                {
+                    if config.message_deduplication.expiry.is_zero() {
+                        None
+                    } else {
+                        Some(config.message_deduplication.expiry)
+                    }
+                },
+            )),
+            false => None,
+        }
+    }
+
+    /// Applies the configuration and storage of the topic to the partition and all its segments.
+    /// The unsaved messages are persisted first, so the caches enabled by the new configuration can be loaded from disk.
+    /// If the deduplication settings have changed, the deduplicator is recreated and filled with the stored message IDs.
+    pub async fn update_config(
+        &mut self,
+        config: Arc<SystemConfig>,
+        storage: Arc<SystemStorage>,
+    ) -> Result<(), IggyError> {
+        self.persist_messages().await?;
+        let deduplication_changed =
+            self.config.message_deduplication != config.message_deduplication;
+        self.config = config;
+        self.storage = storage;
+        for segment in self.segments.iter_mut() {
+            segment
+                .update_config(self.config.clone(), self.storage.clone())
+                .await?;
+        }
+
+        if !deduplication_changed {
+            return Ok(());
+        }
+
+        self.message_deduplicator = Self::create_message_deduplicator(&self.config);
+        if let Some(message_deduplicator) = &self.message_deduplicator {
+            for segment in self.segments.iter() {
+                let message_ids = segment.storage.segment.load_message_ids(segment).await?;
+                for message_id in message_ids {
+                    message_deduplicator.try_insert(&message_id).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the offset following the last appended message, or 0 if the partition is empty.
+    pub fn get_next_offset(&self) -> u64 {
+        if self.should_increment_offset {
+            self.current_offset + 1
+        } else {
+            0
+        }
+    }
+
+    /// Returns the offset of the first message available in the partition.
+    /// The messages before the low watermark are considered deleted, even if their segment still exists.
+    pub fn get_first_offset(&self) -> u64 {
+        self.segments
+            .first()
+            .map_or(0, |segment| segment.start_offset)
+            .max(self.low_watermark)
+    }
+
     pub fn get_size_bytes(&self) -> u64 {
         self.size_bytes.load(std::sync::atomic::Ordering::SeqCst)
     }
+
+    pub(crate) fn get_metrics_labels(&self) -> PartitionLabels {
+        PartitionLabels {
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partition_id: self.partition_id,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -209,6 +286,7 @@ mod tests {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Default::default(),
         );
 
         assert_eq!(partition.stream_id, stream_id);
@@ -248,6 +326,7 @@ mod tests {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Default::default(),
         );
         assert!(partition.cache.is_none());
     }
@@ -269,6 +348,7 @@ mod tests {
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
+            Default::default(),
         );
         assert!(partition.segments.is_empty());
     }
@@ -1,13 +1,20 @@
 use std::sync::atomic::Ordering;
 
+use crate::streaming::diagnostics::metrics::StreamingMetrics;
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::persistence::durability::DurabilityWaiter;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
+use iggy::models::partition_truncation::PartitionTruncation;
+use iggy::utils::durability::Durability;
 
 impl Partition {
     pub async fn load(&mut self) -> Result<(), IggyError> {
         let storage = self.storage.clone();
-        storage.partition.load(self).await
+        storage.partition.load(self).await?;
+        // The messages loaded from disk are considered durable.
+        self.durability_tracker.mark_durable(self.get_next_offset());
+        Ok(())
     }
 
     pub async fn persist(&self) -> Result<(), IggyError> {
@@ -20,13 +27,112 @@ impl Partition {
             self.segments_count_of_parent_stream
                 .fetch_sub(1, Ordering::SeqCst);
         }
-        self.storage.partition.delete(self).await
+        self.storage.partition.delete(self).await?;
+        StreamingMetrics::get().remove_partition(&self.get_metrics_labels());
+        Ok(())
+    }
+
+    pub async fn persist_messages(&mut self) -> Result<usize, IggyError> {
+        let mut saved_messages_number = 0;
+        for segment in self.segments.iter_mut() {
+            saved_messages_number += segment.persist_messages().await?;
+        }
+
+        if saved_messages_number > 0 {
+            self.unsaved_messages_count = 0;
+            self.durability_tracker.mark_written(self.get_next_offset());
+        }
+
+        if self.durability != Durability::None {
+            self.sync_messages().await?;
+        }
+
+        Ok(saved_messages_number)
+    }
+
+    /// Fsyncs the messages, which were already written to disk, and marks them as durable.
+    pub async fn sync_messages(&self) -> Result<(), IggyError> {
+        if !self.durability_tracker.has_pending_sync() {
+            return Ok(());
+        }
+
+        let written_offset = self.durability_tracker.written_offset();
+        if let Some(last_segment) = self.segments.last() {
+            last_segment.sync().await?;
+        }
+        self.durability_tracker.mark_durable(written_offset);
+        Ok(())
+    }
+
+    /// Makes sure that all the appended messages become durable, regardless of the durability level of the partition.
+    /// In case of the group commit, the messages are synced by the group committer, so the returned waiter has to be awaited.
+    pub async fn ensure_durability(&mut self) -> Result<Option<DurabilityWaiter>, IggyError> {
+        let end_offset = self.get_next_offset();
+        if self.durability_tracker.durable_offset() >= end_offset {
+            return Ok(None);
+        }
+
+        if self.durability == Durability::GroupCommit
+            && self.durability_tracker.written_offset() >= end_offset
+        {
+            return Ok(Some(
+                self.durability_tracker
+                    .waiter(self.partition_id, end_offset),
+            ));
+        }
+
+        if let Some(last_segment) = self.segments.last_mut() {
+            last_segment.persist_messages().await?;
+        }
+        self.unsaved_messages_count = 0;
+        self.durability_tracker.mark_written(end_offset);
+        self.sync_messages().await?;
+        Ok(None)
+    }
+
+    /// Deletes the messages before the provided offset, which becomes the new low watermark of the partition.
+    /// The low watermark is persisted first, then the closed segments containing only the deleted messages are removed.
+    /// It never moves backward or beyond the next offset of the partition.
+    pub async fn truncate(&mut self, offset: u64) -> Result<PartitionTruncation, IggyError> {
+        let mut truncation = PartitionTruncation {
+            partition_id: self.partition_id,
+            low_watermark: self.low_watermark,
+            deleted_segments: 0,
+            deleted_messages: 0,
+        };
+        let low_watermark = offset.min(self.get_next_offset());
+        if low_watermark <= self.low_watermark {
+            return Ok(truncation);
+        }
+
+        self.low_watermark = low_watermark;
+        truncation.low_watermark = low_watermark;
+        self.persist().await?;
+
+        // The last segment is never deleted, as the new messages are appended to it.
+        let segments_count = self.segments.len();
+        let deleted_start_offsets = self
+            .segments
+            .iter()
+            .take(segments_count.saturating_sub(1))
+            .filter(|segment| segment.is_closed && segment.current_offset < low_watermark)
+            .map(|segment| segment.start_offset)
+            .collect::<Vec<_>>();
+        for start_offset in deleted_start_offsets {
+            let deleted_segment = self.delete_segment(start_offset).await?;
+            truncation.deleted_segments += 1;
+            truncation.deleted_messages += deleted_segment.messages_count;
+        }
+
+        Ok(truncation)
     }
 
     pub async fn purge(&mut self) -> Result<(), IggyError> {
         self.current_offset = 0;
+        self.low_watermark = 0;
         self.unsaved_messages_count = 0;
         self.should_increment_offset = false;
+        self.durability_tracker.reset();
         if let Some(cache) = self.cache.as_mut() {
             cache.purge();
         }
@@ -55,6 +161,7 @@ impl Partition {
             )
             .await?;
         self.add_persisted_segment(0).await?;
+        self.persist().await?;
 
         Ok(())
     }
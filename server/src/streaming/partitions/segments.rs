@@ -35,6 +35,26 @@ impl Partition {
         expired_segments
     }
 
+    /// Closes the last segment once it reached the maximum age, even if no more messages are appended to the partition,
+    /// so that it can expire. Returns `true` if the segment has been closed.
+    pub async fn close_aged_segment(&mut self, now: u64) -> Result<bool, IggyError> {
+        let Some(last_segment) = self.segments.last() else {
+            return Ok(false);
+        };
+        if last_segment.is_closed || !last_segment.has_reached_max_age(now) {
+            return Ok(false);
+        }
+
+        self.persist_messages().await?;
+        let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        last_segment.close();
+        info!(
+            "Closed segment with start offset: {}, end offset: {} for partition with ID: {}, topic with ID: {}, stream with ID: {} as it reached the maximum age.",
+            last_segment.start_offset, last_segment.end_offset, self.partition_id, self.topic_id, self.stream_id
+        );
+        Ok(true)
+    }
+
     pub async fn add_persisted_segment(&mut self, start_offset: u64) -> Result<(), IggyError> {
         info!(
             "Creating the new segment for partition with ID: {}, stream with ID: {}, topic with ID: {}...",
@@ -98,6 +98,23 @@ impl PartitionStorage for FilePartitionStorage {
         Ok(consumer_offsets)
     }
 
+    async fn delete_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), IggyError> {
+        if let Err(err) = self
+            .db
+            .remove(&offset.key)
+            .with_context(|| format!("Failed to delete consumer offset, key: {}", offset.key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        trace!(
+            "Deleted consumer offset for {} with ID: {}",
+            offset.kind,
+            offset.consumer_id
+        );
+        Ok(())
+    }
+
     async fn delete_consumer_offsets(
         &self,
         kind: ConsumerKind,
@@ -137,6 +154,8 @@ impl PartitionStorage for FilePartitionStorage {
 #[derive(Debug, Serialize, Deserialize)]
 struct PartitionData {
     created_at: u64,
+    #[serde(default)]
+    low_watermark: u64,
 }
 
 #[async_trait]
@@ -185,6 +204,7 @@ impl Storage<Partition> for FilePartitionStorage {
         };
 
         partition.created_at = partition_data.created_at;
+        partition.low_watermark = partition_data.low_watermark;
 
         let mut dir_entries = dir_entries.unwrap();
         while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
@@ -242,6 +262,7 @@ impl Storage<Partition> for FilePartitionStorage {
                         // Found old format, need to convert it
                         info!("Detected changes in binary schema for partition with ID: {} and segment with start offset: {}", partition.partition_id, start_offset);
                         segment.convert_segment_from_schema(schema).await?;
+                        break;
                     }
                     Err(err) if idx + 1 == samplers_count => {
                         // Didn't find any message format, return an error
@@ -351,6 +372,7 @@ impl Storage<Partition> for FilePartitionStorage {
         );
         match rmp_serde::to_vec(&PartitionData {
             created_at: partition.created_at,
+            low_watermark: partition.low_watermark,
         })
         .with_context(|| format!("Failed to serialize partition with key: {}", key))
         {
@@ -374,6 +396,7 @@ impl Storage<Partition> for FilePartitionStorage {
                 &key,
                 rmp_serde::to_vec(&PartitionData {
                     created_at: partition.created_at,
+                    low_watermark: partition.low_watermark,
                 })
                 .unwrap(),
             )
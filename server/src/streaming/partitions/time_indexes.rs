@@ -0,0 +1,116 @@
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::storage::TIME_INDEX_SIZE;
+use crate::streaming::segments::time_index::TimeIndex;
+use iggy::error::IggyError;
+use iggy::models::partition_offsets::PartitionOffsets;
+use std::borrow::Cow;
+use tracing::trace;
+
+impl Partition {
+    pub async fn get_offsets_for_timestamp(
+        &self,
+        timestamp: u64,
+    ) -> Result<PartitionOffsets, IggyError> {
+        trace!(
+            "Getting offsets for timestamp: {} for partition: {}...",
+            timestamp,
+            self.partition_id
+        );
+        let mut partition_offsets = PartitionOffsets {
+            partition_id: self.partition_id,
+            first_offset: None,
+            last_offset: None,
+            offset: None,
+        };
+        let first_offset = self.get_first_offset();
+        if self.segments.is_empty() || self.get_next_offset() <= first_offset {
+            return Ok(partition_offsets);
+        }
+
+        partition_offsets.first_offset = Some(first_offset);
+        partition_offsets.last_offset = Some(self.current_offset);
+        partition_offsets.offset = self.get_offset_for_timestamp(timestamp).await?;
+        Ok(partition_offsets)
+    }
+
+    /// Returns the offset of the first message with the timestamp greater than or equal to the provided one.
+    /// The batch containing such a message is found using the time indexes, and then only its messages are checked.
+    pub async fn get_offset_for_timestamp(&self, timestamp: u64) -> Result<Option<u64>, IggyError> {
+        for segment in self.segments.iter() {
+            let time_indexes = Self::get_time_indexes(segment).await?;
+            let Some(position) = time_indexes
+                .iter()
+                .position(|time_index| time_index.timestamp >= timestamp)
+            else {
+                continue;
+            };
+
+            // Each time index points to the last message of the batch, so the batch starts right after the previous one.
+            let batch_start_offset = match position {
+                0 => segment.start_offset,
+                _ => segment.start_offset + time_indexes[position - 1].relative_offset as u64 + 1,
+            };
+            let batch_last_offset =
+                segment.start_offset + time_indexes[position].relative_offset as u64;
+            let messages = self
+                .get_messages_by_offset(
+                    batch_start_offset,
+                    (batch_last_offset - batch_start_offset + 1) as u32,
+                )
+                .await?;
+            let offset = messages
+                .iter()
+                .find(|message| message.timestamp >= timestamp)
+                .map(|message| message.offset)
+                .unwrap_or(batch_start_offset)
+                .max(self.low_watermark);
+            // The low watermark might have reached the end of the partition, so no message is available.
+            return Ok((offset < self.get_next_offset()).then_some(offset));
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the timestamp of the batch containing the provided offset, based on the time indexes.
+    pub async fn get_timestamp_for_offset(&self, offset: u64) -> Result<Option<u64>, IggyError> {
+        let segment = match self
+            .segments
+            .iter()
+            .rev()
+            .find(|segment| segment.start_offset <= offset)
+        {
+            Some(segment) => segment,
+            None => return Ok(None),
+        };
+
+        let time_indexes = Self::get_time_indexes(segment).await?;
+        Ok(time_indexes
+            .iter()
+            .find(|time_index| segment.start_offset + time_index.relative_offset as u64 >= offset)
+            .map(|time_index| time_index.timestamp))
+    }
+
+    /// Returns the time indexes of the segment, either cached or loaded from disk along with the ones not saved yet.
+    async fn get_time_indexes(segment: &Segment) -> Result<Cow<'_, [TimeIndex]>, IggyError> {
+        if let Some(time_indexes) = segment.time_indexes.as_ref() {
+            return Ok(Cow::Borrowed(time_indexes));
+        }
+
+        let mut time_indexes = segment
+            .storage
+            .segment
+            .load_all_time_indexes(segment)
+            .await?;
+        time_indexes.extend(
+            segment
+                .unsaved_timestamps
+                .chunks_exact(TIME_INDEX_SIZE as usize)
+                .map(|chunk| TimeIndex {
+                    relative_offset: u32::from_le_bytes(chunk[0..4].try_into().unwrap()),
+                    timestamp: u64::from_le_bytes(chunk[4..12].try_into().unwrap()),
+                }),
+        );
+        Ok(Cow::Owned(time_indexes))
+    }
+}
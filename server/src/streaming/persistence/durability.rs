@@ -0,0 +1,127 @@
+use iggy::error::IggyError;
+use tokio::sync::watch;
+
+/// Keeps track of the messages of the partition, which were written to disk,
+/// and the ones, which are already durable (fsynced). The offsets are exclusive,
+/// which means that the value `N` covers all the messages with the offset lower than `N`.
+#[derive(Debug)]
+pub struct DurabilityTracker {
+    written_offset: watch::Sender<u64>,
+    durable_offset: watch::Sender<u64>,
+}
+
+impl Default for DurabilityTracker {
+    fn default() -> Self {
+        Self {
+            written_offset: watch::Sender::new(0),
+            durable_offset: watch::Sender::new(0),
+        }
+    }
+}
+
+impl DurabilityTracker {
+    /// Marks the messages up to the provided (exclusive) offset as written to disk, but not yet fsynced.
+    pub fn mark_written(&self, end_offset: u64) {
+        self.written_offset.send_if_modified(|offset| {
+            if end_offset > *offset {
+                *offset = end_offset;
+                return true;
+            }
+            false
+        });
+    }
+
+    /// Marks the messages up to the provided (exclusive) offset as durable.
+    pub fn mark_durable(&self, end_offset: u64) {
+        self.mark_written(end_offset);
+        self.durable_offset.send_if_modified(|offset| {
+            if end_offset > *offset {
+                *offset = end_offset;
+                return true;
+            }
+            false
+        });
+    }
+
+    /// Resets the tracker, e.g. when all the messages of the partition have been purged.
+    pub fn reset(&self) {
+        self.written_offset.send_replace(0);
+        self.durable_offset.send_replace(0);
+    }
+
+    /// Returns the (exclusive) offset up to which the messages were written to disk.
+    pub fn written_offset(&self) -> u64 {
+        *self.written_offset.borrow()
+    }
+
+    /// Returns the (exclusive) offset up to which the messages are durable.
+    pub fn durable_offset(&self) -> u64 {
+        *self.durable_offset.borrow()
+    }
+
+    /// Returns `true` if some of the written messages are still waiting to be fsynced.
+    pub fn has_pending_sync(&self) -> bool {
+        self.written_offset() > self.durable_offset()
+    }
+
+    /// Returns the waiter, which completes once the messages up to the provided (exclusive) offset are durable.
+    pub fn waiter(&self, partition_id: u32, end_offset: u64) -> DurabilityWaiter {
+        DurabilityWaiter {
+            partition_id,
+            end_offset,
+            receiver: self.durable_offset.subscribe(),
+        }
+    }
+}
+
+/// Awaits the durability of the appended messages, without holding any lock on the partition.
+#[derive(Debug)]
+pub struct DurabilityWaiter {
+    partition_id: u32,
+    end_offset: u64,
+    receiver: watch::Receiver<u64>,
+}
+
+impl DurabilityWaiter {
+    pub async fn wait(mut self) -> Result<(), IggyError> {
+        let end_offset = self.end_offset;
+        if self
+            .receiver
+            .wait_for(|offset| *offset >= end_offset)
+            .await
+            .is_err()
+        {
+            return Err(IggyError::CannotSyncSegment(self.partition_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn waiter_should_complete_once_messages_are_durable() {
+        let tracker = DurabilityTracker::default();
+        let waiter = tracker.waiter(1, 10);
+        tracker.mark_written(10);
+        assert!(tracker.has_pending_sync());
+
+        let handle = tokio::spawn(waiter.wait());
+        tracker.mark_durable(10);
+        assert!(!tracker.has_pending_sync());
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[test]
+    fn offsets_should_never_move_backwards() {
+        let tracker = DurabilityTracker::default();
+        tracker.mark_durable(10);
+        tracker.mark_written(5);
+        tracker.mark_durable(7);
+        assert_eq!(tracker.written_offset(), 10);
+        assert_eq!(tracker.durable_offset(), 10);
+    }
+}
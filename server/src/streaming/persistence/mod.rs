@@ -1 +1,2 @@
+pub mod durability;
 pub mod persister;
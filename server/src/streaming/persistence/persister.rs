@@ -10,6 +10,7 @@ pub trait Persister: Sync + Send {
     async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError>;
     async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), IggyError>;
     async fn delete(&self, path: &str) -> Result<(), IggyError>;
+    async fn sync(&self, path: &str) -> Result<(), IggyError>;
 }
 
 impl Debug for dyn Persister {
@@ -50,6 +51,12 @@ impl Persister for FilePersister {
         fs::remove_file(path).await?;
         Ok(())
     }
+
+    async fn sync(&self, path: &str) -> Result<(), IggyError> {
+        let file = file::append(path).await?;
+        file.sync_all().await?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -72,4 +79,10 @@ impl Persister for FileWithSyncPersister {
         fs::remove_file(path).await?;
         Ok(())
     }
+
+    async fn sync(&self, path: &str) -> Result<(), IggyError> {
+        let file = file::append(path).await?;
+        file.sync_all().await?;
+        Ok(())
+    }
 }
@@ -1,12 +1,13 @@
 use crate::streaming::utils::hash;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::identifier::{IdKind, Identifier};
+use iggy::messages::poll_messages::{PollMessages, PollMessagesVersion};
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum PollingConsumer {
-    Consumer(u32, u32),      // Consumer ID + Partition ID
-    ConsumerGroup(u32, u32), // Consumer Group ID + Member ID
+    Consumer(u32, u32),                   // Consumer ID + Partition ID
+    ConsumerGroup(u32, u32, Option<u32>), // Consumer Group ID + Member ID + requested Partition ID
 }
 
 impl PollingConsumer {
@@ -16,10 +17,24 @@ impl PollingConsumer {
             ConsumerKind::Consumer => {
                 PollingConsumer::Consumer(consumer_id, partition_id.unwrap_or(0))
             }
-            ConsumerKind::ConsumerGroup => PollingConsumer::ConsumerGroup(consumer_id, client_id),
+            ConsumerKind::ConsumerGroup => {
+                PollingConsumer::ConsumerGroup(consumer_id, client_id, partition_id)
+            }
         }
     }
 
+    /// Creates the polling consumer of `PollMessages` command, the partition ID of the consumer group is ignored
+    /// (so the partition is chosen by the server) unless the command is polled with `V3`.
+    pub fn from_poll_messages(command: &PollMessages, client_id: u32) -> Self {
+        let partition_id = match (command.consumer.kind, command.version) {
+            (ConsumerKind::ConsumerGroup, PollMessagesVersion::V1 | PollMessagesVersion::V2) => {
+                None
+            }
+            _ => command.partition_id,
+        };
+        Self::from_consumer(&command.consumer, client_id, partition_id)
+    }
+
     pub fn resolve_consumer_id(identifier: &Identifier) -> u32 {
         match identifier.kind {
             IdKind::Numeric => identifier.get_u32_value().unwrap(),
@@ -36,7 +51,7 @@ impl Display for PollingConsumer {
                 "consumer ID: {}, partition ID: {}",
                 consumer_id, partition_id
             ),
-            PollingConsumer::ConsumerGroup(consumer_group_id, member_id) => {
+            PollingConsumer::ConsumerGroup(consumer_group_id, member_id, _) => {
                 write!(
                     f,
                     "consumer group ID: {}, member ID: {}",
@@ -96,11 +111,51 @@ mod tests {
 
         assert_eq!(
             polling_consumer,
-            PollingConsumer::ConsumerGroup(group_id, client_id)
+            PollingConsumer::ConsumerGroup(group_id, client_id, None)
         );
         assert_eq!(group_id, PollingConsumer::resolve_consumer_id(&consumer.id));
     }
 
+    #[test]
+    fn given_consumer_group_with_partition_id_polling_consumer_group_should_keep_it() {
+        let group_id = 1;
+        let client_id = 2;
+        let partition_id = 3;
+        let consumer = Consumer::group(Identifier::numeric(group_id).unwrap());
+        let polling_consumer =
+            PollingConsumer::from_consumer(&consumer, client_id, Some(partition_id));
+
+        assert_eq!(
+            polling_consumer,
+            PollingConsumer::ConsumerGroup(group_id, client_id, Some(partition_id))
+        );
+    }
+
+    #[test]
+    fn given_poll_messages_of_consumer_group_partition_id_should_be_kept_only_since_v3() {
+        let group_id = 1;
+        let client_id = 2;
+        let partition_id = 3;
+        let mut command = PollMessages {
+            consumer: Consumer::group(Identifier::numeric(group_id).unwrap()),
+            partition_id: Some(partition_id),
+            ..PollMessages::default()
+        };
+        for version in [PollMessagesVersion::V1, PollMessagesVersion::V2] {
+            command.version = version;
+            assert_eq!(
+                PollingConsumer::from_poll_messages(&command, client_id),
+                PollingConsumer::ConsumerGroup(group_id, client_id, None)
+            );
+        }
+
+        command.version = PollMessagesVersion::V3;
+        assert_eq!(
+            PollingConsumer::from_poll_messages(&command, client_id),
+            PollingConsumer::ConsumerGroup(group_id, client_id, Some(partition_id))
+        );
+    }
+
     #[test]
     fn given_consumer_group_with_named_id_polling_consumer_group_should_be_created() {
         let consumer_group_name = "consumer_group";
@@ -111,7 +166,7 @@ mod tests {
         let consumer_id = PollingConsumer::resolve_consumer_id(&consumer.id);
         assert_eq!(
             polling_consumer,
-            PollingConsumer::ConsumerGroup(consumer_id, client_id)
+            PollingConsumer::ConsumerGroup(consumer_id, client_id, None)
         );
     }
 
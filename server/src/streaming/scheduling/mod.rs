@@ -0,0 +1,2 @@
+pub mod scheduled_message;
+pub mod storage;
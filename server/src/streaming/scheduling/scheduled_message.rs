@@ -0,0 +1,123 @@
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::{Message, Partitioning};
+use uuid::Uuid;
+
+/// The message held by the server until its delivery time, after which it's appended to the target topic
+/// using the original partitioning, so that it's only then visible to the consumers.
+#[derive(Debug, Clone)]
+pub struct ScheduledMessage {
+    pub id: u128,
+    pub deliver_at: u64,
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partitioning: Partitioning,
+    pub message: Message,
+}
+
+impl ScheduledMessage {
+    pub fn new(
+        deliver_at: u64,
+        stream_id: u32,
+        topic_id: u32,
+        partitioning: Partitioning,
+        message: Message,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4().as_u128(),
+            deliver_at,
+            stream_id,
+            topic_id,
+            partitioning,
+            message,
+        }
+    }
+
+    pub fn is_due(&self, now: u64) -> bool {
+        self.deliver_at <= now
+    }
+}
+
+impl BytesSerializable for ScheduledMessage {
+    fn as_bytes(&self) -> Bytes {
+        let partitioning = self.partitioning.as_bytes();
+        let message = self.message.as_bytes();
+        let mut bytes = BytesMut::with_capacity(32 + partitioning.len() + message.len());
+        bytes.put_u128_le(self.id);
+        bytes.put_u64_le(self.deliver_at);
+        bytes.put_u32_le(self.stream_id);
+        bytes.put_u32_le(self.topic_id);
+        bytes.put_slice(&partitioning);
+        bytes.put_slice(&message);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() < 34 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let id = u128::from_le_bytes(bytes[..16].try_into()?);
+        let deliver_at = u64::from_le_bytes(bytes[16..24].try_into()?);
+        let stream_id = u32::from_le_bytes(bytes[24..28].try_into()?);
+        let topic_id = u32::from_le_bytes(bytes[28..32].try_into()?);
+        let partitioning = Partitioning::from_bytes(bytes.slice(32..))?;
+        let message_position = 32 + partitioning.get_size_bytes() as usize;
+        let message = Message::from_bytes(bytes.slice(message_position..))?;
+        Ok(Self {
+            id,
+            deliver_at,
+            stream_id,
+            topic_id,
+            partitioning,
+            message,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn scheduled_message_should_be_serialized_and_deserialized() {
+        let scheduled_message = ScheduledMessage::new(
+            1_000_000,
+            1,
+            2,
+            Partitioning::messages_key_str("user-1").unwrap(),
+            Message::new(Some(1), Bytes::from("hello"), None),
+        );
+
+        let bytes = scheduled_message.as_bytes();
+        let deserialized = ScheduledMessage::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized.id, scheduled_message.id);
+        assert_eq!(deserialized.deliver_at, scheduled_message.deliver_at);
+        assert_eq!(deserialized.stream_id, scheduled_message.stream_id);
+        assert_eq!(deserialized.topic_id, scheduled_message.topic_id);
+        assert_eq!(deserialized.partitioning, scheduled_message.partitioning);
+        assert_eq!(deserialized.message.id, scheduled_message.message.id);
+        assert_eq!(
+            deserialized.message.payload,
+            scheduled_message.message.payload
+        );
+    }
+
+    #[test]
+    fn scheduled_message_should_be_due_at_its_delivery_time() {
+        let scheduled_message = ScheduledMessage::new(
+            1_000_000,
+            1,
+            2,
+            Partitioning::balanced(),
+            Message::from_str("hello").unwrap(),
+        );
+
+        assert!(!scheduled_message.is_due(999_999));
+        assert!(scheduled_message.is_due(1_000_000));
+        assert!(scheduled_message.is_due(1_000_001));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::streaming::scheduling::scheduled_message::ScheduledMessage;
+use crate::streaming::storage::{ScheduledMessageStorage, Storage};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use sled::Db;
+use std::sync::Arc;
+use tracing::debug;
+
+const KEY_PREFIX: &str = "scheduled_message";
+
+#[derive(Debug)]
+pub struct FileScheduledMessageStorage {
+    db: Arc<Db>,
+}
+
+impl FileScheduledMessageStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileScheduledMessageStorage {}
+unsafe impl Sync for FileScheduledMessageStorage {}
+
+#[async_trait]
+impl ScheduledMessageStorage for FileScheduledMessageStorage {
+    async fn load_due(&self, now: u64, count: u32) -> Result<Vec<ScheduledMessage>, IggyError> {
+        let mut scheduled_messages = Vec::new();
+        // The keys are ordered by the delivery time, so the scan can stop at the first message which is not due yet.
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            if scheduled_messages.len() >= count as usize {
+                break;
+            }
+
+            let scheduled_message = match data.with_context(|| {
+                format!(
+                    "Failed to load scheduled message, when searching by key: {}",
+                    KEY_PREFIX
+                )
+            }) {
+                Ok((_, value)) => ScheduledMessage::from_bytes(value.to_vec().into())?,
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            };
+            if !scheduled_message.is_due(now) {
+                break;
+            }
+
+            scheduled_messages.push(scheduled_message);
+        }
+
+        Ok(scheduled_messages)
+    }
+
+    async fn count(&self) -> Result<u64, IggyError> {
+        let mut count = 0;
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)).keys() {
+            if let Err(err) = data.with_context(|| "Failed to count scheduled messages") {
+                return Err(IggyError::CannotLoadResource(err));
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    async fn flush(&self) -> Result<(), IggyError> {
+        if let Err(err) = self
+            .db
+            .flush_async()
+            .await
+            .with_context(|| "Failed to flush scheduled messages")
+        {
+            return Err(IggyError::CannotSaveResource(err));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage<ScheduledMessage> for FileScheduledMessageStorage {
+    async fn load(&self, scheduled_message: &mut ScheduledMessage) -> Result<(), IggyError> {
+        let key = get_key(scheduled_message.deliver_at, scheduled_message.id);
+        let data = match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load scheduled message, key: {}", key))
+        {
+            Ok(Some(data)) => data,
+            Ok(None) => return Err(IggyError::ResourceNotFound(key)),
+            Err(err) => return Err(IggyError::CannotLoadResource(err)),
+        };
+
+        *scheduled_message = ScheduledMessage::from_bytes(data.to_vec().into())?;
+        Ok(())
+    }
+
+    async fn save(&self, scheduled_message: &ScheduledMessage) -> Result<(), IggyError> {
+        let key = get_key(scheduled_message.deliver_at, scheduled_message.id);
+        if let Err(err) = self
+            .db
+            .insert(&key, scheduled_message.as_bytes().to_vec())
+            .with_context(|| format!("Failed to save scheduled message, key: {}", key))
+        {
+            return Err(IggyError::CannotSaveResource(err));
+        }
+
+        debug!(
+            "Saved scheduled message for stream with ID: {}, topic with ID: {}, to be delivered at: {}.",
+            scheduled_message.stream_id, scheduled_message.topic_id, scheduled_message.deliver_at
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, scheduled_message: &ScheduledMessage) -> Result<(), IggyError> {
+        let key = get_key(scheduled_message.deliver_at, scheduled_message.id);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete scheduled message, key: {}", key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        debug!(
+            "Deleted scheduled message for stream with ID: {}, topic with ID: {}.",
+            scheduled_message.stream_id, scheduled_message.topic_id
+        );
+        Ok(())
+    }
+}
+
+// The delivery time is zero-padded, so that the lexicographical order of the keys matches the delivery order.
+fn get_key(deliver_at: u64, id: u128) -> String {
+    format!("{}:{:020}:{:032x}", KEY_PREFIX, deliver_at, id)
+}
@@ -0,0 +1,154 @@
+use iggy::error::IggyError;
+use iggy::models::schema::Schema;
+use iggy::schemas::schema_definition::SchemaDefinition;
+use std::collections::HashMap;
+
+/// The schema registered for the topic, along with its parsed definition used to validate the payloads.
+#[derive(Debug)]
+pub struct RegisteredSchema {
+    pub schema: Schema,
+    pub definition: SchemaDefinition,
+}
+
+/// The in-memory registry of the versioned schemas, indexed by the unique schema ID and by the topic.
+#[derive(Debug, Default)]
+pub struct SchemaRegistry {
+    schemas: HashMap<u32, RegisteredSchema>,
+    topics: HashMap<(u32, u32), Vec<u32>>,
+}
+
+impl SchemaRegistry {
+    /// Parses the definition of the stored schema and adds it to the registry.
+    pub fn load(&mut self, schema: Schema) -> Result<(), IggyError> {
+        let definition = SchemaDefinition::parse(schema.schema_type, &schema.definition)?;
+        self.add(schema, definition);
+        Ok(())
+    }
+
+    pub fn add(&mut self, schema: Schema, definition: SchemaDefinition) {
+        let schema_ids = self
+            .topics
+            .entry((schema.stream_id, schema.topic_id))
+            .or_default();
+        schema_ids.push(schema.id);
+        schema_ids.sort_by_key(|schema_id| {
+            self.schemas
+                .get(schema_id)
+                .map_or(schema.version, |registered| registered.schema.version)
+        });
+        self.schemas
+            .insert(schema.id, RegisteredSchema { schema, definition });
+    }
+
+    pub fn get(&self, schema_id: u32) -> Option<&RegisteredSchema> {
+        self.schemas.get(&schema_id)
+    }
+
+    pub fn get_latest(&self, stream_id: u32, topic_id: u32) -> Option<&RegisteredSchema> {
+        self.topics
+            .get(&(stream_id, topic_id))
+            .and_then(|schema_ids| schema_ids.last())
+            .and_then(|schema_id| self.schemas.get(schema_id))
+    }
+
+    pub fn get_for_topic(&self, stream_id: u32, topic_id: u32) -> Vec<&RegisteredSchema> {
+        self.topics
+            .get(&(stream_id, topic_id))
+            .map(|schema_ids| {
+                schema_ids
+                    .iter()
+                    .filter_map(|schema_id| self.schemas.get(schema_id))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn next_id(&self) -> u32 {
+        self.schemas
+            .keys()
+            .max()
+            .map_or(1, |schema_id| schema_id + 1)
+    }
+
+    /// Removes all the schemas of the topic, returning the removed ones.
+    pub fn remove_for_topic(&mut self, stream_id: u32, topic_id: u32) -> Vec<Schema> {
+        let schema_ids = self
+            .topics
+            .remove(&(stream_id, topic_id))
+            .unwrap_or_default();
+        schema_ids
+            .iter()
+            .filter_map(|schema_id| self.schemas.remove(schema_id))
+            .map(|registered| registered.schema)
+            .collect()
+    }
+
+    /// Removes all the schemas of the topics belonging to the stream, returning the removed ones.
+    pub fn remove_for_stream(&mut self, stream_id: u32) -> Vec<Schema> {
+        let topic_ids = self
+            .topics
+            .keys()
+            .filter(|(schema_stream_id, _)| *schema_stream_id == stream_id)
+            .map(|(_, topic_id)| *topic_id)
+            .collect::<Vec<_>>();
+        topic_ids
+            .into_iter()
+            .flat_map(|topic_id| self.remove_for_topic(stream_id, topic_id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::schema_compatibility::SchemaCompatibility;
+    use iggy::utils::schema_type::SchemaType;
+
+    fn schema(id: u32, stream_id: u32, topic_id: u32, version: u32) -> Schema {
+        Schema {
+            id,
+            stream_id,
+            topic_id,
+            version,
+            schema_type: SchemaType::Json,
+            compatibility: SchemaCompatibility::Backward,
+            validation_enabled: true,
+            created_at: 0,
+            definition: r#"{"type":"object"}"#.to_string(),
+        }
+    }
+
+    #[test]
+    fn latest_schema_should_be_resolved_by_version() {
+        let mut registry = SchemaRegistry::default();
+        registry.load(schema(3, 1, 1, 2)).unwrap();
+        registry.load(schema(1, 1, 1, 1)).unwrap();
+        registry.load(schema(2, 1, 2, 1)).unwrap();
+
+        assert_eq!(registry.next_id(), 4);
+        assert_eq!(registry.get_latest(1, 1).unwrap().schema.id, 3);
+        assert_eq!(
+            registry
+                .get_for_topic(1, 1)
+                .iter()
+                .map(|registered| registered.schema.version)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert!(registry.get_latest(2, 1).is_none());
+    }
+
+    #[test]
+    fn schemas_should_be_removed_with_topic_and_stream() {
+        let mut registry = SchemaRegistry::default();
+        registry.load(schema(1, 1, 1, 1)).unwrap();
+        registry.load(schema(2, 1, 2, 1)).unwrap();
+        registry.load(schema(3, 2, 1, 1)).unwrap();
+
+        assert_eq!(registry.remove_for_topic(1, 1).len(), 1);
+        assert!(registry.get(1).is_none());
+        assert_eq!(registry.remove_for_stream(1).len(), 1);
+        assert!(registry.get(2).is_none());
+        assert!(registry.get(3).is_some());
+    }
+}
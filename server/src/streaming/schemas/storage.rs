@@ -0,0 +1,126 @@
+use crate::streaming::storage::{SchemaStorage, Storage};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use iggy::models::schema::Schema;
+use sled::Db;
+use std::sync::Arc;
+use tracing::info;
+
+const KEY_PREFIX: &str = "schema";
+
+#[derive(Debug)]
+pub struct FileSchemaStorage {
+    db: Arc<Db>,
+}
+
+impl FileSchemaStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileSchemaStorage {}
+unsafe impl Sync for FileSchemaStorage {}
+
+#[async_trait]
+impl SchemaStorage for FileSchemaStorage {
+    async fn load_all(&self) -> Result<Vec<Schema>, IggyError> {
+        let mut schemas = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            let schema = match data.with_context(|| {
+                format!(
+                    "Failed to load schema, when searching by key: {}",
+                    KEY_PREFIX
+                )
+            }) {
+                Ok((_, value)) => match rmp_serde::from_slice::<Schema>(&value).with_context(|| {
+                    format!(
+                        "Failed to deserialize schema, when searching by key: {}",
+                        KEY_PREFIX
+                    )
+                }) {
+                    Ok(schema) => schema,
+                    Err(err) => {
+                        return Err(IggyError::CannotDeserializeResource(err));
+                    }
+                },
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            };
+            schemas.push(schema);
+        }
+
+        Ok(schemas)
+    }
+}
+
+#[async_trait]
+impl Storage<Schema> for FileSchemaStorage {
+    async fn load(&self, schema: &mut Schema) -> Result<(), IggyError> {
+        let key = get_key(schema.id);
+        let data = match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load schema, key: {}", key))
+        {
+            Ok(Some(data)) => data,
+            Ok(None) => return Err(IggyError::ResourceNotFound(key)),
+            Err(err) => return Err(IggyError::CannotLoadResource(err)),
+        };
+
+        *schema = match rmp_serde::from_slice::<Schema>(&data)
+            .with_context(|| format!("Failed to deserialize schema, key: {}", key))
+        {
+            Ok(schema) => schema,
+            Err(err) => return Err(IggyError::CannotDeserializeResource(err)),
+        };
+        Ok(())
+    }
+
+    async fn save(&self, schema: &Schema) -> Result<(), IggyError> {
+        let key = get_key(schema.id);
+        match rmp_serde::to_vec(&schema)
+            .with_context(|| format!("Failed to serialize schema, key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, data)
+                    .with_context(|| format!("Failed to insert schema, key: {}", key))
+                {
+                    return Err(IggyError::CannotSaveResource(err));
+                }
+            }
+            Err(err) => return Err(IggyError::CannotSerializeResource(err)),
+        }
+
+        info!(
+            "Saved schema with ID: {}, version: {} for topic with ID: {} for stream with ID: {}.",
+            schema.id, schema.version, schema.topic_id, schema.stream_id
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, schema: &Schema) -> Result<(), IggyError> {
+        let key = get_key(schema.id);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete schema, key: {}", key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        info!(
+            "Deleted schema with ID: {} for topic with ID: {} for stream with ID: {}.",
+            schema.id, schema.topic_id, schema.stream_id
+        );
+        Ok(())
+    }
+}
+
+fn get_key(schema_id: u32) -> String {
+    format!("{}:{}", KEY_PREFIX, schema_id)
+}
@@ -1,5 +1,6 @@
 use crate::streaming::batching::batch_filter::BatchItemizer;
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::diagnostics::metrics::StreamingMetrics;
 use crate::streaming::models::messages::RetainedMessage;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
@@ -7,8 +8,10 @@ use crate::streaming::segments::time_index::TimeIndex;
 use crate::streaming::sizeable::Sizeable;
 use bytes::BufMut;
 use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Instant;
 use tracing::{trace, warn};
 
 const EMPTY_MESSAGES: Vec<RetainedMessage> = vec![];
@@ -219,9 +222,13 @@ impl Segment {
 
         let last_offset = batch.base_offset + batch.last_offset_delta as u64;
         self.current_offset = last_offset;
+        self.first_batch_timestamp
+            .get_or_insert_with(|| IggyTimestamp::now().to_micros());
         self.end_offset = last_offset;
 
-        self.store_offset_and_timestamp_index_for_batch(last_offset, batch.max_timestamp);
+        // Event timestamps might arrive out of order, but the time index must remain monotonic.
+        self.max_timestamp = self.max_timestamp.max(batch.max_timestamp);
+        self.store_offset_and_timestamp_index_for_batch(last_offset, self.max_timestamp);
 
         let messages_size = batch.get_size_bytes();
         let messages_count = batch.last_offset_delta + 1;
@@ -306,11 +313,13 @@ impl Segment {
             self.partition_id
         );
 
+        let started_at = Instant::now();
         let saved_bytes = storage.save_batches(self, unsaved_batches).await?;
         storage.save_index(self).await?;
         self.unsaved_indexes.clear();
         storage.save_time_index(self).await?;
         self.unsaved_timestamps.clear();
+        StreamingMetrics::get().record_persist_duration(started_at.elapsed());
 
         trace!(
             "Saved {} messages on disk in segment with start offset: {} for partition with ID: {}, total bytes written: {}.",
@@ -321,9 +330,7 @@ impl Segment {
         );
 
         if self.is_full().await {
-            self.end_offset = self.current_offset;
-            self.is_closed = true;
-            self.unsaved_batches = None;
+            self.close();
         } else {
             self.unsaved_batches.as_mut().unwrap().clear();
         }
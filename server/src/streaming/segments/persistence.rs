@@ -1,5 +1,7 @@
+use crate::streaming::diagnostics::metrics::StreamingMetrics;
 use crate::streaming::segments::segment::Segment;
 use iggy::error::IggyError;
+use std::time::Instant;
 
 impl Segment {
     pub async fn load(&mut self) -> Result<(), IggyError> {
@@ -10,4 +12,11 @@ impl Segment {
     pub async fn persist(&self) -> Result<(), IggyError> {
         self.storage.segment.save(self).await
     }
+
+    pub async fn sync(&self) -> Result<(), IggyError> {
+        let started_at = Instant::now();
+        self.storage.segment.sync(self).await?;
+        StreamingMetrics::get().record_sync_duration(started_at.elapsed());
+        Ok(())
+    }
 }
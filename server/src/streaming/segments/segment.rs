@@ -4,7 +4,7 @@ use crate::compat::conversion_writer::ConversionWriter;
 use crate::compat::message_converter::MessageFormatConverterPersister;
 use crate::compat::message_stream::MessageStream;
 use crate::compat::snapshots::retained_batch_snapshot::RetainedMessageBatchSnapshot;
-use crate::compat::streams::retained_batch::RetainedBatchWriter;
+use crate::compat::streams::retained_batch::{RetainedBatchStream, RetainedBatchWriter};
 use crate::compat::streams::retained_message::RetainedMessageStream;
 use crate::configs::system::SystemConfig;
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
@@ -46,6 +46,8 @@ pub struct Segment {
     pub messages_count_of_parent_partition: Arc<AtomicU64>,
     pub is_closed: bool,
     pub(crate) message_expiry: Option<u32>,
+    pub(crate) first_batch_timestamp: Option<u64>,
+    pub(crate) max_timestamp: u64,
     pub(crate) unsaved_batches: Option<Vec<Arc<RetainedMessageBatch>>>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) indexes: Option<Vec<Index>>,
@@ -98,6 +100,8 @@ impl Segment {
             unsaved_timestamps: Vec::new(),
             unsaved_batches: None,
             is_closed: false,
+            first_batch_timestamp: None,
+            max_timestamp: 0,
             size_of_parent_stream,
             size_of_parent_partition,
             size_of_parent_topic,
@@ -109,12 +113,72 @@ impl Segment {
         }
     }
 
+    /// Applies the configuration and storage of the partition, loading or dropping the cached indexes if needed.
+    pub async fn update_config(
+        &mut self,
+        config: Arc<SystemConfig>,
+        storage: Arc<SystemStorage>,
+    ) -> Result<(), IggyError> {
+        self.config = config;
+        self.storage = storage;
+        match (self.config.segment.cache_indexes, self.indexes.is_some()) {
+            (true, false) => {
+                let indexes = self.storage.segment.load_all_indexes(self).await?;
+                self.indexes = Some(indexes);
+            }
+            (false, true) => self.indexes = None,
+            _ => {}
+        }
+        match (
+            self.config.segment.cache_time_indexes,
+            self.time_indexes.is_some(),
+        ) {
+            (true, false) => {
+                let time_indexes = self.storage.segment.load_all_time_indexes(self).await?;
+                self.time_indexes = Some(time_indexes);
+            }
+            (false, true) => self.time_indexes = None,
+            _ => {}
+        }
+        Ok(())
+    }
+
     pub async fn is_full(&self) -> bool {
         if self.size_bytes >= self.config.segment.size.as_bytes_u64() as u32 {
             return true;
         }
 
-        self.is_expired(IggyTimestamp::now().to_micros()).await
+        let max_messages = self.config.segment.max_messages;
+        if max_messages > 0 && self.get_messages_count() >= max_messages {
+            return true;
+        }
+
+        let now = IggyTimestamp::now().to_micros();
+        if self.has_reached_max_age(now) {
+            return true;
+        }
+
+        self.is_expired(now).await
+    }
+
+    /// Returns `true` if the first batch of the segment is older than the configured maximum age of the segment.
+    pub fn has_reached_max_age(&self, now: u64) -> bool {
+        let max_age = self.config.segment.max_age;
+        if max_age.is_zero() {
+            return false;
+        }
+
+        match self.first_batch_timestamp {
+            Some(timestamp) => timestamp + max_age.as_micros() <= now,
+            None => false,
+        }
+    }
+
+    /// Closes the segment, no more messages can be appended to it and it becomes eligible for the expiry.
+    pub fn close(&mut self) {
+        self.end_offset = self.current_offset;
+        self.is_closed = true;
+        self.unsaved_batches = None;
     }
 
     pub async fn is_expired(&self, now: u64) -> bool {
@@ -218,7 +282,58 @@ impl Segment {
                 conversion_writer.replace_with_converted().await?;
                 Ok(())
             }
-            BinarySchema::RetainedMessageBatchSchema => Ok(()),
+            BinarySchema::RetainedMessageBatchSchema => {
+                let file = file::open(&self.log_path).await?;
+                let file_size = file.metadata().await?.len();
+                if file_size == 0 {
+                    return Ok(());
+                }
+
+                let compat_backup_path = self.config.get_compatibility_backup_path();
+                let conversion_writer = ConversionWriter::init(
+                    log_path,
+                    index_path,
+                    time_index_path,
+                    &compat_backup_path,
+                );
+                conversion_writer.create_alt_directories().await?;
+                let retained_batch_writer = RetainedBatchWriter::init(
+                    file::append(&conversion_writer.alt_log_path).await?,
+                    file::append(&conversion_writer.alt_index_path).await?,
+                    file::append(&conversion_writer.alt_time_index_path).await?,
+                );
+
+                let stream = RetainedBatchStream::new(file, file_size).into_stream();
+                pin_mut!(stream);
+                let (_, mut retained_batch_writer) = stream
+                    .try_fold((0u32, retained_batch_writer), |(position, mut retained_batch_writer), batch| async move {
+                        let batch = batch.try_with_empty_messages_keys()?;
+                        let size = batch.get_size_bytes();
+                        info!("Converted message batch with base offset: {} and last offset: {}, with binary schema: {:?} to newest schema",
+                        batch.base_offset, batch.get_last_offset(), schema);
+
+                        batch.persist(&mut retained_batch_writer.log_writer).await?;
+                        let relative_offset = (batch.get_last_offset() - self.start_offset) as u32;
+                        batch
+                            .persist_index(position, relative_offset, &mut retained_batch_writer.index_writer)
+                            .await?;
+                        batch
+                            .persist_time_index(batch.max_timestamp, relative_offset, &mut retained_batch_writer.time_index_writer)
+                            .await?;
+                        let position = position + size;
+
+                        Ok((position, retained_batch_writer))
+                    })
+                    .await?;
+                retained_batch_writer.log_writer.flush().await?;
+                retained_batch_writer.index_writer.flush().await?;
+                retained_batch_writer.time_index_writer.flush().await?;
+
+                conversion_writer.create_old_segment_backup().await?;
+                conversion_writer.replace_with_converted().await?;
+                Ok(())
+            }
+            BinarySchema::RetainedMessageBatchWithKeySchema => Ok(()),
         }
     }
 }
@@ -228,6 +343,7 @@ mod tests {
     use super::*;
     use crate::configs::system::SegmentConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use iggy::utils::duration::IggyDuration;
 
     #[tokio::test]
     async fn should_be_created_given_valid_parameters() {
@@ -363,4 +479,58 @@ mod tests {
         );
         assert!(segment.time_indexes.is_none());
     }
+
+    #[tokio::test]
+    async fn should_be_full_when_max_messages_count_is_reached() {
+        let mut segment = create_segment(SegmentConfig {
+            max_messages: 10,
+            ..Default::default()
+        });
+        segment.size_bytes = 100;
+        segment.current_offset = 8;
+        assert!(!segment.is_full().await);
+
+        segment.current_offset = 9;
+        assert!(segment.is_full().await);
+    }
+
+    #[tokio::test]
+    async fn should_be_full_when_max_age_is_reached() {
+        let mut segment = create_segment(SegmentConfig {
+            max_age: IggyDuration::from(60),
+            ..Default::default()
+        });
+        let now = IggyTimestamp::now().to_micros();
+        assert!(!segment.has_reached_max_age(now));
+
+        segment.first_batch_timestamp = Some(now - 30 * 1_000_000);
+        assert!(!segment.has_reached_max_age(now));
+        assert!(!segment.is_full().await);
+
+        segment.first_batch_timestamp = Some(now - 60 * 1_000_000);
+        assert!(segment.has_reached_max_age(now));
+        assert!(segment.is_full().await);
+    }
+
+    fn create_segment(segment_config: SegmentConfig) -> Segment {
+        let config = Arc::new(SystemConfig {
+            segment: segment_config,
+            ..Default::default()
+        });
+        Segment::create(
+            1,
+            2,
+            3,
+            0,
+            config,
+            Arc::new(get_test_system_storage()),
+            None,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        )
+    }
 }
@@ -15,6 +15,8 @@ use bytes::{BufMut, BytesMut};
 use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::checksum;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::timestamp_type::TimestampType;
 use std::io::SeekFrom;
 use std::path::Path;
 use std::sync::atomic::Ordering;
@@ -28,6 +30,23 @@ pub(crate) const INDEX_SIZE: u32 = 8;
 pub(crate) const TIME_INDEX_SIZE: u32 = 12;
 const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
 
+/// The first time index holds the append time of the first batch, unless the event time is stored (`CreateTime`),
+/// which is at most the max timestamp skew older than the append time, so the latest possible append time is assumed
+/// (but not later than now), and the segment which has been backfilled doesn't reach its max age too early.
+fn get_first_batch_timestamp(segment: &Segment, first_time_index: &TimeIndex) -> u64 {
+    if segment.config.partition.timestamp_type != TimestampType::CreateTime {
+        return first_time_index.timestamp;
+    }
+
+    let now = IggyTimestamp::now().to_micros();
+    let max_timestamp_skew = segment.config.partition.max_timestamp_skew;
+    if max_timestamp_skew.is_zero() {
+        return now;
+    }
+
+    (first_time_index.timestamp + max_timestamp_skew.as_micros()).min(now)
+}
+
 #[derive(Debug)]
 pub struct FileSegmentStorage {
     persister: Arc<dyn Persister>,
@@ -37,6 +56,29 @@ impl FileSegmentStorage {
     pub fn new(persister: Arc<dyn Persister>) -> Self {
         Self { persister }
     }
+
+    async fn load_first_time_index(
+        &self,
+        segment: &Segment,
+    ) -> Result<Option<TimeIndex>, IggyError> {
+        trace!("Loading first time index from file...");
+        let mut file = file::open(&segment.time_index_path).await?;
+        let file_size = file.metadata().await?.len() as usize;
+        if file_size < TIME_INDEX_SIZE as usize {
+            trace!("Time index file is empty.");
+            return Ok(None);
+        }
+
+        let index_offset = file.read_u32_le().await?;
+        let timestamp = file.read_u64_le().await?;
+        let index = TimeIndex {
+            relative_offset: index_offset,
+            timestamp,
+        };
+
+        trace!("Loaded first time index from file: {:?}", index);
+        Ok(Some(index))
+    }
 }
 
 unsafe impl Send for FileSegmentStorage {}
@@ -51,7 +93,7 @@ impl Storage<Segment> for FileSegmentStorage {
             segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
         );
         let log_file = file::open(&segment.log_path).await?;
-        let file_size = log_file.metadata().await.unwrap().len() as u64;
+        let file_size = log_file.metadata().await.unwrap().len();
         segment.size_bytes = file_size as u32;
 
         if segment.config.segment.cache_indexes {
@@ -71,6 +113,9 @@ impl Storage<Segment> for FileSegmentStorage {
             if !time_indexes.is_empty() {
                 let last_index = time_indexes.last().unwrap();
                 segment.current_offset = segment.start_offset + last_index.relative_offset as u64;
+                segment.first_batch_timestamp =
+                    Some(get_first_batch_timestamp(segment, &time_indexes[0]));
+                segment.max_timestamp = last_index.timestamp;
                 segment.time_indexes = Some(time_indexes);
             }
 
@@ -86,6 +131,11 @@ impl Storage<Segment> for FileSegmentStorage {
             let last_time_index = self.load_last_time_index(segment).await?;
             if let Some(last_index) = last_time_index {
                 segment.current_offset = segment.start_offset + last_index.relative_offset as u64;
+                segment.max_timestamp = last_index.timestamp;
+                if let Some(first_index) = self.load_first_time_index(segment).await? {
+                    segment.first_batch_timestamp =
+                        Some(get_first_batch_timestamp(segment, &first_index));
+                }
                 info!(
                 "Loaded last time index for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
                 segment.start_offset,
@@ -499,7 +549,7 @@ impl SegmentStorage for FileSegmentStorage {
                 );
                 error
             })?;
-            let timestamp = reader.read_u64().await.map_err(|error| {
+            let timestamp = reader.read_u64_le().await.map_err(|error| {
                 error!(
                     "Cannot read timestamp from index file for offset: {}. Error: {}",
                     offset, &error
@@ -567,6 +617,137 @@ impl SegmentStorage for FileSegmentStorage {
 
         Ok(())
     }
+
+    async fn sync(&self, segment: &Segment) -> Result<(), IggyError> {
+        for path in [
+            &segment.log_path,
+            &segment.index_path,
+            &segment.time_index_path,
+        ] {
+            if let Err(err) = self.persister.sync(path).await {
+                error!("Failed to sync segment file: {path}. Error: {err}");
+                return Err(IggyError::CannotSyncSegment(segment.partition_id));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Segment storage fsyncing the segment files after the writes of the wrapped one,
+/// which is used by the topics enforcing the fsync, when their parent storage doesn't.
+#[derive(Debug)]
+pub struct SyncedSegmentStorage {
+    inner: Arc<dyn SegmentStorage>,
+}
+
+impl SyncedSegmentStorage {
+    pub fn new(inner: Arc<dyn SegmentStorage>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl Storage<Segment> for SyncedSegmentStorage {
+    async fn load(&self, segment: &mut Segment) -> Result<(), IggyError> {
+        self.inner.load(segment).await
+    }
+
+    async fn save(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.inner.save(segment).await?;
+        self.inner.sync(segment).await
+    }
+
+    async fn delete(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.inner.delete(segment).await
+    }
+}
+
+#[async_trait]
+impl SegmentStorage for SyncedSegmentStorage {
+    async fn load_message_batches(
+        &self,
+        segment: &Segment,
+        index_range: &IndexRange,
+    ) -> Result<Vec<RetainedMessageBatch>, IggyError> {
+        self.inner.load_message_batches(segment, index_range).await
+    }
+
+    async fn load_newest_batches_by_size(
+        &self,
+        segment: &Segment,
+        size_bytes: u64,
+    ) -> Result<Vec<RetainedMessageBatch>, IggyError> {
+        self.inner
+            .load_newest_batches_by_size(segment, size_bytes)
+            .await
+    }
+
+    async fn save_batches(
+        &self,
+        segment: &Segment,
+        batches: &[Arc<RetainedMessageBatch>],
+    ) -> Result<u32, IggyError> {
+        self.inner.save_batches(segment, batches).await
+    }
+
+    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, IggyError> {
+        self.inner.load_message_ids(segment).await
+    }
+
+    async fn load_checksums(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.inner.load_checksums(segment).await
+    }
+
+    async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, IggyError> {
+        self.inner.load_all_indexes(segment).await
+    }
+
+    async fn load_index_range(
+        &self,
+        segment: &Segment,
+        index_start_offset: u64,
+        index_end_offset: u64,
+    ) -> Result<Option<IndexRange>, IggyError> {
+        self.inner
+            .load_index_range(segment, index_start_offset, index_end_offset)
+            .await
+    }
+
+    async fn save_index(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.inner.save_index(segment).await
+    }
+
+    async fn try_load_time_index_for_timestamp(
+        &self,
+        segment: &Segment,
+        timestamp: u64,
+    ) -> Result<Option<TimeIndex>, IggyError> {
+        self.inner
+            .try_load_time_index_for_timestamp(segment, timestamp)
+            .await
+    }
+
+    async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, IggyError> {
+        self.inner.load_all_time_indexes(segment).await
+    }
+
+    async fn load_last_time_index(
+        &self,
+        segment: &Segment,
+    ) -> Result<Option<TimeIndex>, IggyError> {
+        self.inner.load_last_time_index(segment).await
+    }
+
+    // The time index is written last when persisting the messages, so all the segment files are synced at once.
+    async fn save_time_index(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.inner.save_time_index(segment).await?;
+        self.inner.sync(segment).await
+    }
+
+    async fn sync(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.inner.sync(segment).await
+    }
 }
 
 async fn load_batches_by_range(
@@ -4,9 +4,12 @@ use crate::streaming::partitions::storage::FilePartitionStorage;
 use crate::streaming::persistence::persister::Persister;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::personal_access_tokens::storage::FilePersonalAccessTokenStorage;
+use crate::streaming::scheduling::scheduled_message::ScheduledMessage;
+use crate::streaming::scheduling::storage::FileScheduledMessageStorage;
+use crate::streaming::schemas::storage::FileSchemaStorage;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
-use crate::streaming::segments::storage::FileSegmentStorage;
+use crate::streaming::segments::storage::{FileSegmentStorage, SyncedSegmentStorage};
 use crate::streaming::segments::time_index::TimeIndex;
 use crate::streaming::streams::storage::FileStreamStorage;
 use crate::streaming::streams::stream::Stream;
@@ -20,6 +23,7 @@ use crate::streaming::users::user::User;
 use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
+use iggy::models::schema::Schema;
 use iggy::models::user_info::UserId;
 use sled::Db;
 use std::fmt::{Debug, Formatter};
@@ -55,6 +59,18 @@ pub trait PersonalAccessTokenStorage: Storage<PersonalAccessToken> {
     async fn delete_for_user(&self, user_id: UserId, name: &str) -> Result<(), IggyError>;
 }
 
+#[async_trait]
+pub trait ScheduledMessageStorage: Storage<ScheduledMessage> {
+    async fn load_due(&self, now: u64, count: u32) -> Result<Vec<ScheduledMessage>, IggyError>;
+    async fn count(&self) -> Result<u64, IggyError>;
+    async fn flush(&self) -> Result<(), IggyError>;
+}
+
+#[async_trait]
+pub trait SchemaStorage: Storage<Schema> {
+    async fn load_all(&self) -> Result<Vec<Schema>, IggyError>;
+}
+
 #[async_trait]
 pub trait StreamStorage: Storage<Stream> {}
 
@@ -83,6 +99,7 @@ pub trait PartitionStorage: Storage<Partition> {
         topic_id: u32,
         partition_id: u32,
     ) -> Result<Vec<ConsumerOffset>, IggyError>;
+    async fn delete_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), IggyError>;
     async fn delete_consumer_offsets(
         &self,
         kind: ConsumerKind,
@@ -128,13 +145,16 @@ pub trait SegmentStorage: Storage<Segment> {
     async fn load_last_time_index(&self, segment: &Segment)
         -> Result<Option<TimeIndex>, IggyError>;
     async fn save_time_index(&self, segment: &Segment) -> Result<(), IggyError>;
+    async fn sync(&self, segment: &Segment) -> Result<(), IggyError>;
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SystemStorage {
     pub info: Arc<dyn SystemInfoStorage>,
     pub user: Arc<dyn UserStorage>,
     pub personal_access_token: Arc<dyn PersonalAccessTokenStorage>,
+    pub scheduled_message: Arc<dyn ScheduledMessageStorage>,
+    pub schema: Arc<dyn SchemaStorage>,
     pub stream: Arc<dyn StreamStorage>,
     pub topic: Arc<dyn TopicStorage>,
     pub partition: Arc<dyn PartitionStorage>,
@@ -147,12 +167,22 @@ impl SystemStorage {
             info: Arc::new(FileSystemInfoStorage::new(db.clone())),
             user: Arc::new(FileUserStorage::new(db.clone())),
             personal_access_token: Arc::new(FilePersonalAccessTokenStorage::new(db.clone())),
+            scheduled_message: Arc::new(FileScheduledMessageStorage::new(db.clone())),
+            schema: Arc::new(FileSchemaStorage::new(db.clone())),
             stream: Arc::new(FileStreamStorage::new(db.clone())),
             topic: Arc::new(FileTopicStorage::new(db.clone())),
             partition: Arc::new(FilePartitionStorage::new(db.clone())),
             segment: Arc::new(FileSegmentStorage::new(persister.clone())),
         }
     }
+
+    /// Returns the storage sharing all the components, with the segments one wrapped to fsync the files after the writes.
+    pub fn with_enforced_fsync(&self) -> Self {
+        Self {
+            segment: Arc::new(SyncedSegmentStorage::new(self.segment.clone())),
+            ..self.clone()
+        }
+    }
 }
 
 impl Debug for dyn SystemInfoStorage {
@@ -173,6 +203,18 @@ impl Debug for dyn PersonalAccessTokenStorage {
     }
 }
 
+impl Debug for dyn ScheduledMessageStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ScheduledMessageStorage")
+    }
+}
+
+impl Debug for dyn SchemaStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SchemaStorage")
+    }
+}
+
 impl Debug for dyn StreamStorage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "StreamStorage")
@@ -212,6 +254,8 @@ pub(crate) mod tests {
     struct TestSystemInfoStorage {}
     struct TestUserStorage {}
     struct TestPersonalAccessTokenStorage {}
+    struct TestSchemaStorage {}
+    struct TestScheduledMessageStorage {}
     struct TestStreamStorage {}
     struct TestTopicStorage {}
     struct TestPartitionStorage {}
@@ -319,6 +363,62 @@ pub(crate) mod tests {
         }
     }
 
+    #[async_trait]
+    impl Storage<ScheduledMessage> for TestScheduledMessageStorage {
+        async fn load(&self, _scheduled_message: &mut ScheduledMessage) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save(&self, _scheduled_message: &ScheduledMessage) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _scheduled_message: &ScheduledMessage) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ScheduledMessageStorage for TestScheduledMessageStorage {
+        async fn load_due(
+            &self,
+            _now: u64,
+            _count: u32,
+        ) -> Result<Vec<ScheduledMessage>, IggyError> {
+            Ok(vec![])
+        }
+
+        async fn count(&self) -> Result<u64, IggyError> {
+            Ok(0)
+        }
+
+        async fn flush(&self) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Storage<Schema> for TestSchemaStorage {
+        async fn load(&self, _schema: &mut Schema) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save(&self, _schema: &Schema) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _schema: &Schema) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl SchemaStorage for TestSchemaStorage {
+        async fn load_all(&self) -> Result<Vec<Schema>, IggyError> {
+            Ok(vec![])
+        }
+    }
+
     #[async_trait]
     impl Storage<Stream> for TestStreamStorage {
         async fn load(&self, _stream: &mut Stream) -> Result<(), IggyError> {
@@ -408,6 +508,10 @@ pub(crate) mod tests {
             Ok(vec![])
         }
 
+        async fn delete_consumer_offset(&self, _offset: &ConsumerOffset) -> Result<(), IggyError> {
+            Ok(())
+        }
+
         async fn delete_consumer_offsets(
             &self,
             _kind: ConsumerKind,
@@ -485,6 +589,10 @@ pub(crate) mod tests {
             Ok(())
         }
 
+        async fn sync(&self, _segment: &Segment) -> Result<(), IggyError> {
+            Ok(())
+        }
+
         async fn try_load_time_index_for_timestamp(
             &self,
             _segment: &Segment,
@@ -517,6 +625,8 @@ pub(crate) mod tests {
             info: Arc::new(TestSystemInfoStorage {}),
             user: Arc::new(TestUserStorage {}),
             personal_access_token: Arc::new(TestPersonalAccessTokenStorage {}),
+            scheduled_message: Arc::new(TestScheduledMessageStorage {}),
+            schema: Arc::new(TestSchemaStorage {}),
             stream: Arc::new(TestStreamStorage {}),
             topic: Arc::new(TestTopicStorage {}),
             partition: Arc::new(TestPartitionStorage {}),
@@ -28,6 +28,15 @@ impl Stream {
         Ok(saved_messages_number)
     }
 
+    pub async fn sync_group_commit_messages(&self) -> Result<usize, IggyError> {
+        let mut synced_partitions_number = 0;
+        for topic in self.get_topics() {
+            synced_partitions_number += topic.sync_group_commit_messages().await?;
+        }
+
+        Ok(synced_partitions_number)
+    }
+
     pub async fn purge(&self) -> Result<(), IggyError> {
         for topic in self.get_topics() {
             topic.purge().await?;
@@ -5,6 +5,7 @@ use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::join_all;
 use iggy::error::IggyError;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
 use sled::Db;
@@ -35,6 +36,8 @@ impl StreamStorage for FileStreamStorage {}
 struct StreamData {
     name: String,
     created_at: IggyTimestamp,
+    #[serde(default)]
+    topic_config_overrides: TopicConfigOverrides,
 }
 
 #[async_trait]
@@ -78,6 +81,8 @@ impl Storage<Stream> for FileStreamStorage {
 
         stream.name = stream_data.name;
         stream.created_at = stream_data.created_at;
+        stream.topic_config_overrides = stream_data.topic_config_overrides;
+        let (topics_config, topics_storage) = stream.get_topics_config();
         let mut unloaded_topics = Vec::new();
         let dir_entries = fs::read_dir(&stream.topics_path).await;
         if dir_entries.is_err() {
@@ -100,8 +105,8 @@ impl Storage<Stream> for FileStreamStorage {
                 stream.size_bytes.clone(),
                 stream.messages_count.clone(),
                 stream.segments_count.clone(),
-                stream.config.clone(),
-                stream.storage.clone(),
+                topics_config.clone(),
+                topics_storage.clone(),
             );
             unloaded_topics.push(topic);
         }
@@ -173,6 +178,7 @@ impl Storage<Stream> for FileStreamStorage {
         match rmp_serde::to_vec(&StreamData {
             name: stream.name.clone(),
             created_at: stream.created_at,
+            topic_config_overrides: stream.topic_config_overrides,
         })
         .with_context(|| format!("Failed to serialize stream with key: {}", key))
         {
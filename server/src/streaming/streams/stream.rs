@@ -1,6 +1,7 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::storage::SystemStorage;
-use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::topic::{apply_config_overrides, Topic};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
@@ -18,6 +19,7 @@ pub struct Stream {
     pub size_bytes: Arc<AtomicU64>,
     pub messages_count: Arc<AtomicU64>,
     pub segments_count: Arc<AtomicU32>,
+    pub topic_config_overrides: TopicConfigOverrides,
     pub(crate) topics: HashMap<u32, Topic>,
     pub(crate) topics_ids: HashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
@@ -48,6 +50,7 @@ impl Stream {
             size_bytes: Arc::new(AtomicU64::new(0)),
             messages_count: Arc::new(AtomicU64::new(0)),
             segments_count: Arc::new(AtomicU32::new(0)),
+            topic_config_overrides: TopicConfigOverrides::default(),
             topics: HashMap::new(),
             topics_ids: HashMap::new(),
             storage,
@@ -55,6 +58,11 @@ impl Stream {
         }
     }
 
+    /// Returns the configuration and storage inherited by the topics, with the stream defaults applied.
+    pub fn get_topics_config(&self) -> (Arc<SystemConfig>, Arc<SystemStorage>) {
+        apply_config_overrides(&self.config, &self.storage, &self.topic_config_overrides)
+    }
+
     pub fn get_size(&self) -> IggyByteSize {
         IggyByteSize::from(self.size_bytes.load(Ordering::SeqCst))
     }
@@ -4,7 +4,9 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::durability::Durability;
 use iggy::utils::text;
 use std::sync::atomic::Ordering;
 use tracing::{debug, info};
@@ -24,6 +26,8 @@ impl Stream {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError> {
         let name = text::to_lowercase_non_whitespace(name);
         if self.topics_ids.contains_key(&name) {
@@ -52,13 +56,18 @@ impl Stream {
         }
 
         // TODO: check if max_topic_size is not lower than system.segment.size
+        let (topics_config, topics_storage) = self.get_topics_config();
+        validate_durability(
+            durability.unwrap_or(topics_config.partition.durability),
+            &config_overrides,
+        )?;
         let topic = Topic::create(
             self.stream_id,
             id,
             &name,
             partitions_count,
-            self.config.clone(),
-            self.storage.clone(),
+            topics_config,
+            topics_storage,
             self.size_bytes.clone(),
             self.messages_count.clone(),
             self.segments_count.clone(),
@@ -66,6 +75,8 @@ impl Stream {
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            durability,
+            config_overrides,
         )?;
         topic.persist().await?;
         info!("Created topic {}", topic);
@@ -75,6 +86,7 @@ impl Stream {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_topic(
         &mut self,
         id: &Identifier,
@@ -83,11 +95,33 @@ impl Stream {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        durability: Option<Durability>,
+        config_overrides: Option<TopicConfigOverrides>,
     ) -> Result<(), IggyError> {
         let topic_id;
+        // The clients which don't send the overrides keep the current ones.
+        let mut config_overrides = match config_overrides {
+            Some(config_overrides) => config_overrides,
+            None => self.get_topic(id)?.config_overrides,
+        };
         {
             let topic = self.get_topic(id)?;
             topic_id = topic.topic_id;
+            // Changing the partitioning scheme would remap the messages keys and break their ordering.
+            match config_overrides.partitioning_scheme {
+                Some(scheme) if scheme != topic.partitioning_scheme => {
+                    return Err(IggyError::CannotChangePartitioningScheme(
+                        topic_id,
+                        self.stream_id,
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    config_overrides.partitioning_scheme =
+                        topic.config_overrides.partitioning_scheme
+                }
+            }
+            validate_durability(durability.unwrap_or(topic.durability), &config_overrides)?;
         }
 
         let updated_name = text::to_lowercase_non_whitespace(name);
@@ -109,21 +143,31 @@ impl Stream {
         };
 
         {
+            let (topics_config, topics_storage) = self.get_topics_config();
             self.topics_ids.remove(&old_topic_name.clone());
             self.topics_ids.insert(updated_name.clone(), topic_id);
             let topic = self.get_topic_mut(id)?;
             topic.name = updated_name;
             topic.message_expiry = message_expiry;
             topic.compression_algorithm = compression_algorithm;
+            if let Some(durability) = durability {
+                topic.durability = durability;
+            }
             for partition in topic.partitions.values_mut() {
                 let mut partition = partition.write().await;
                 partition.message_expiry = message_expiry;
+                partition.durability = topic.durability;
                 for segment in partition.segments.iter_mut() {
                     segment.message_expiry = message_expiry;
                 }
             }
             topic.max_topic_size = max_topic_size;
             topic.replication_factor = replication_factor;
+            if topic.config_overrides != config_overrides {
+                topic
+                    .update_config_overrides(&topics_config, &topics_storage, config_overrides)
+                    .await?;
+            }
 
             topic.persist().await?;
             info!("Updated topic: {topic}");
@@ -132,6 +176,22 @@ impl Stream {
         Ok(())
     }
 
+    /// Replaces the default config overrides of the topics and applies the resulting configuration to all of them.
+    pub async fn update_topic_config_overrides(
+        &mut self,
+        topic_config_overrides: TopicConfigOverrides,
+    ) -> Result<(), IggyError> {
+        self.topic_config_overrides = topic_config_overrides;
+        let (topics_config, topics_storage) = self.get_topics_config();
+        for topic in self.topics.values_mut() {
+            let config_overrides = topic.config_overrides;
+            topic
+                .update_config_overrides(&topics_config, &topics_storage, config_overrides)
+                .await?;
+        }
+        Ok(())
+    }
+
     pub fn remove_topic(&mut self, identifier: &Identifier) -> Result<Topic, IggyError> {
         match identifier.kind {
             IdKind::Numeric => self.remove_topic_by_id(identifier.get_u32_value()?),
@@ -222,6 +282,18 @@ impl Stream {
     }
 }
 
+// Enforcing fsync for every write would silently override the durability of the topic, which is never fsynced.
+fn validate_durability(
+    durability: Durability,
+    config_overrides: &TopicConfigOverrides,
+) -> Result<(), IggyError> {
+    if durability == Durability::None && config_overrides.enforce_fsync == Some(true) {
+        return Err(IggyError::FsyncEnforcedWithoutDurability);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,6 +322,8 @@ mod tests {
                 compression_algorithm,
                 max_topic_size,
                 1,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await
             .unwrap();
@@ -268,4 +342,113 @@ mod tests {
         assert_eq!(topic.name, topic_name);
         assert_eq!(topic.compression_algorithm, compression_algorithm);
     }
+
+    #[tokio::test]
+    async fn should_keep_topic_durability_and_config_overrides_when_update_does_not_provide_them() {
+        let topic_id = Identifier::numeric(1).unwrap();
+        let config = Arc::new(SystemConfig::default());
+        let storage = Arc::new(get_test_system_storage());
+        let mut stream = Stream::create(1, "test_stream", config, storage);
+        let config_overrides = TopicConfigOverrides {
+            segment_max_messages: Some(10),
+            ..Default::default()
+        };
+        stream
+            .create_topic(
+                Some(1),
+                "test_topic",
+                1,
+                None,
+                CompressionAlgorithm::None,
+                None,
+                1,
+                Some(Durability::EveryBatch),
+                config_overrides,
+            )
+            .await
+            .unwrap();
+
+        stream
+            .update_topic(
+                &topic_id,
+                "test_topic",
+                None,
+                CompressionAlgorithm::None,
+                None,
+                1,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let topic = stream.get_topic(&topic_id).unwrap();
+        assert_eq!(topic.durability, Durability::EveryBatch);
+        assert_eq!(topic.config_overrides, config_overrides);
+        let partition = topic.partitions.get(&1).unwrap().read().await;
+        assert_eq!(partition.durability, Durability::EveryBatch);
+    }
+
+    #[tokio::test]
+    async fn should_not_enforce_fsync_for_topic_without_durability() {
+        let topic_id = Identifier::numeric(1).unwrap();
+        let config = Arc::new(SystemConfig::default());
+        let storage = Arc::new(get_test_system_storage());
+        let mut stream = Stream::create(1, "test_stream", config, storage);
+        let config_overrides = TopicConfigOverrides {
+            enforce_fsync: Some(true),
+            ..Default::default()
+        };
+        let result = stream
+            .create_topic(
+                Some(1),
+                "test_topic",
+                1,
+                None,
+                CompressionAlgorithm::None,
+                None,
+                1,
+                None,
+                config_overrides,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(IggyError::FsyncEnforcedWithoutDurability)
+        ));
+
+        stream
+            .create_topic(
+                Some(1),
+                "test_topic",
+                1,
+                None,
+                CompressionAlgorithm::None,
+                None,
+                1,
+                Some(Durability::EveryBatch),
+                config_overrides,
+            )
+            .await
+            .unwrap();
+
+        let result = stream
+            .update_topic(
+                &topic_id,
+                "test_topic",
+                None,
+                CompressionAlgorithm::None,
+                None,
+                1,
+                Some(Durability::None),
+                None,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(IggyError::FsyncEnforcedWithoutDurability)
+        ));
+        let topic = stream.get_topic(&topic_id).unwrap();
+        assert_eq!(topic.durability, Durability::EveryBatch);
+    }
 }
@@ -17,10 +17,16 @@ impl System {
         client_id
     }
 
+    pub async fn add_client_without_address(&self, transport: Transport) -> u32 {
+        let mut client_manager = self.client_manager.write().await;
+        let client_id = client_manager.add_client_without_address(transport);
+        info!("Added {transport} client with ID: {client_id}");
+        self.metrics.increment_clients(1);
+        client_id
+    }
+
     pub async fn delete_client(&self, address: &SocketAddr) {
-        let consumer_groups: Vec<(u32, u32, u32)>;
         let client_id;
-
         {
             let client_manager = self.client_manager.read().await;
             let client = client_manager.get_client_by_address(address);
@@ -28,9 +34,24 @@ impl System {
                 return;
             }
 
+            client_id = client.unwrap().read().await.client_id;
+        }
+
+        self.delete_client_by_id(client_id).await;
+    }
+
+    pub async fn delete_client_by_id(&self, client_id: u32) {
+        let consumer_groups: Vec<(u32, u32, u32)>;
+
+        {
+            let client_manager = self.client_manager.read().await;
+            let client = client_manager.get_client_by_id(client_id);
+            if client.is_err() {
+                return;
+            }
+
             let client = client.unwrap();
             let client = client.read().await;
-            client_id = client.client_id;
 
             consumer_groups = client
                 .consumer_groups
@@ -58,7 +79,7 @@ impl System {
 
         {
             let mut client_manager = self.client_manager.write().await;
-            let client = client_manager.delete_client(address);
+            let client = client_manager.delete_client(client_id);
             if client.is_none() {
                 return;
             }
@@ -1,9 +1,13 @@
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offsets_reset::ConsumerOffsetsReset;
 
 impl System {
     pub async fn store_consumer_offset(
@@ -44,4 +48,46 @@ impl System {
 
         topic.get_consumer_offset(consumer).await
     }
+
+    pub async fn get_consumer_lag(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ConsumerLag, IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner.get_consumer_offset(
+            session.get_user_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+
+        topic.get_consumer_lag(consumer).await
+    }
+
+    pub async fn reset_consumer_offsets(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        strategy: OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerOffsetsReset, IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner.store_consumer_offset(
+            session.get_user_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+
+        topic
+            .reset_consumer_offsets(consumer, strategy, dry_run)
+            .await
+    }
 }
@@ -1,11 +1,14 @@
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::persistence::durability::DurabilityWaiter;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use bytes::Bytes;
+use iggy::locking::IggySharedMutFn;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::Message;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{Partitioning, PartitioningKind};
+use iggy::models::appended_messages::AppendedMessages;
 use iggy::models::messages::{PolledMessage, PolledMessages};
 use iggy::{error::IggyError, identifier::Identifier};
 use tracing::{error, trace};
@@ -35,9 +38,24 @@ impl System {
 
         let partition_id = match consumer {
             PollingConsumer::Consumer(_, partition_id) => partition_id,
-            PollingConsumer::ConsumerGroup(group_id, member_id) => {
+            PollingConsumer::ConsumerGroup(group_id, member_id, partition_id) => {
                 let consumer_group = topic.get_consumer_group_by_id(group_id)?.read().await;
-                consumer_group.calculate_partition_id(member_id).await?
+                match partition_id {
+                    Some(partition_id) => {
+                        if !consumer_group
+                            .is_partition_assigned(member_id, partition_id)
+                            .await?
+                        {
+                            return Err(IggyError::ConsumerGroupPartitionNotAssigned(
+                                partition_id,
+                                member_id,
+                                group_id,
+                            ));
+                        }
+                        partition_id
+                    }
+                    None => consumer_group.calculate_partition_id(member_id).await?,
+                }
             }
         };
 
@@ -74,6 +92,7 @@ impl System {
                         length: payload.len() as u32,
                         payload: Bytes::from(payload),
                         headers: message.headers.clone(),
+                        key: message.key.clone(),
                     });
                 }
                 Err(error) => {
@@ -86,6 +105,31 @@ impl System {
         Ok(polled_messages)
     }
 
+    /// Records the metrics of the messages polled by the client, the reads done internally by the server aren't counted.
+    pub async fn record_polled_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        polled_messages: &PolledMessages,
+    ) {
+        if polled_messages.messages.is_empty() {
+            return;
+        }
+
+        let Ok(topic) = self
+            .get_stream(stream_id)
+            .and_then(|stream| stream.get_topic(topic_id))
+        else {
+            return;
+        };
+        if let Some(partition) = topic.partitions.get(&polled_messages.partition_id) {
+            partition
+                .read()
+                .await
+                .record_polled_messages(&polled_messages.messages);
+        }
+    }
+
     pub async fn append_messages(
         &self,
         session: &Session,
@@ -93,7 +137,34 @@ impl System {
         topic_id: Identifier,
         partitioning: Partitioning,
         messages: Vec<Message>,
-    ) -> Result<(), IggyError> {
+        wait_for_durability: bool,
+    ) -> Result<(AppendedMessages, Option<DurabilityWaiter>), IggyError> {
+        self.append_keyed_messages(
+            session,
+            stream_id,
+            topic_id,
+            partitioning,
+            None,
+            messages,
+            wait_for_durability,
+        )
+        .await
+    }
+
+    /// Appends the messages stored with the explicit messages key (unless the partitioning provides one),
+    /// so that the key can be kept along with the partition chosen by the client (e.g. the Kafka record key).
+    /// The key isn't kept for the messages scheduled for the delayed delivery.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn append_keyed_messages(
+        &self,
+        session: &Session,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partitioning: Partitioning,
+        messages_key: Option<Bytes>,
+        messages: Vec<Message>,
+        wait_for_durability: bool,
+    ) -> Result<(AppendedMessages, Option<DurabilityWaiter>), IggyError> {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(&stream_id)?;
         let topic = stream.get_topic(&topic_id)?;
@@ -103,40 +174,125 @@ impl System {
             topic.topic_id,
         )?;
 
-        let mut batch_size_bytes = 0;
         let mut messages = messages;
+        let stream_id = stream.stream_id;
+        let topic_id = topic.topic_id;
+        // The schemas validate the plaintext payloads, so they're applied before the encryption.
+        self.apply_schemas(stream_id, topic_id, &mut messages)?;
         if let Some(encryptor) = &self.encryptor {
             for message in messages.iter_mut() {
-                let payload = encryptor.decrypt(&message.payload);
+                let payload = encryptor.encrypt(&message.payload);
                 match payload {
                     Ok(payload) => {
                         message.payload = Bytes::from(payload);
-                        batch_size_bytes += message.get_size_bytes() as u64;
                     }
                     Err(error) => {
-                        error!("Cannot decrypt the message. Error: {}", error);
-                        return Err(IggyError::CannotDecryptData);
+                        error!("Cannot encrypt the message. Error: {}", error);
+                        return Err(IggyError::CannotEncryptData);
                     }
                 }
             }
-        } else {
-            batch_size_bytes = messages.iter().map(|msg| msg.get_size_bytes() as u64).sum();
         }
 
+        let messages_count = messages.len() as u32;
+        let (messages, scheduled_indexes) = self
+            .schedule_messages(
+                stream_id,
+                topic_id,
+                &partitioning,
+                messages,
+                wait_for_durability,
+            )
+            .await?;
+        if messages.is_empty() {
+            // The partition of the scheduled messages is known upfront only if it's provided by the client.
+            let partition_id = match partitioning.kind {
+                PartitioningKind::PartitionId => u32::from_le_bytes(
+                    partitioning.value[..partitioning.length as usize].try_into()?,
+                ),
+                _ => 0,
+            };
+            let appended_messages = AppendedMessages {
+                partition_id,
+                skipped_indexes: scheduled_indexes,
+                ..AppendedMessages::default()
+            };
+            return Ok((appended_messages, None));
+        }
+
+        let (mut appended_messages, durability_waiter) = self
+            .append_messages_bypass_auth(
+                stream_id,
+                topic_id,
+                partitioning,
+                messages_key,
+                messages,
+                wait_for_durability,
+            )
+            .await?;
+        if !scheduled_indexes.is_empty() {
+            appended_messages.skipped_indexes = map_skipped_indexes(
+                messages_count,
+                &scheduled_indexes,
+                &appended_messages.skipped_indexes,
+            );
+        }
+        Ok((appended_messages, durability_waiter))
+    }
+
+    pub(crate) async fn append_messages_bypass_auth(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partitioning: Partitioning,
+        messages_key: Option<Bytes>,
+        messages: Vec<Message>,
+        wait_for_durability: bool,
+    ) -> Result<(AppendedMessages, Option<DurabilityWaiter>), IggyError> {
+        let topic = self
+            .get_stream(&Identifier::numeric(stream_id)?)?
+            .get_topic(&Identifier::numeric(topic_id)?)?;
+        let batch_size_bytes = messages.iter().map(|msg| msg.get_size_bytes() as u64).sum();
         if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
             if !memory_tracker.will_fit_into_cache(batch_size_bytes) {
                 self.clean_cache(batch_size_bytes).await;
             }
         }
         let messages_count = messages.len() as u64;
-        topic
-            .append_messages(batch_size_bytes, partitioning, messages)
+        let appended = topic
+            .append_messages_with_durability(
+                batch_size_bytes,
+                partitioning,
+                messages_key,
+                messages,
+                wait_for_durability,
+            )
             .await?;
         self.metrics.increment_messages(messages_count);
-        Ok(())
+        Ok(appended)
     }
 }
 
+/// Maps the indexes of the messages skipped by the partition (relative to the appended ones) onto the indexes of the sent messages,
+/// merging them with the indexes of the messages scheduled for the delayed delivery.
+fn map_skipped_indexes(
+    messages_count: u32,
+    scheduled_indexes: &[u32],
+    skipped_indexes: &[u32],
+) -> Vec<u32> {
+    let appended_indexes = (0..messages_count)
+        .filter(|index| scheduled_indexes.binary_search(index).is_err())
+        .collect::<Vec<_>>();
+    let mut indexes = scheduled_indexes.to_vec();
+    indexes.extend(
+        skipped_indexes
+            .iter()
+            .map(|index| appended_indexes[*index as usize]),
+    );
+    indexes.sort_unstable();
+    indexes
+}
+
 #[derive(Debug)]
 pub struct PollingArgs {
     pub strategy: PollingStrategy,
@@ -153,3 +309,15 @@ impl PollingArgs {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skipped_indexes_should_be_mapped_onto_sent_messages() {
+        // Messages 1 and 4 were scheduled, while the partition skipped the duplicates at 0 and 2 out of [0, 2, 3, 5].
+        let indexes = map_skipped_indexes(6, &[1, 4], &[0, 2]);
+        assert_eq!(indexes, vec![0, 1, 3, 4]);
+    }
+}
@@ -5,6 +5,8 @@ pub mod info;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod scheduled_messages;
+pub mod schemas;
 pub mod stats;
 pub mod storage;
 pub mod streams;
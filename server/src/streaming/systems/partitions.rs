@@ -2,6 +2,8 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::models::partition_offsets::PartitionOffsets;
+use iggy::models::partition_truncation::PartitionTruncation;
 
 impl System {
     pub async fn create_partitions(
@@ -58,4 +60,46 @@ impl System {
         }
         Ok(())
     }
+
+    pub async fn get_offsets_for_timestamp(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_ids: &[u32],
+        timestamp: u64,
+    ) -> Result<Vec<PartitionOffsets>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .poll_messages(session.get_user_id(), stream.stream_id, topic.topic_id)?;
+
+        topic
+            .get_offsets_for_timestamp(partition_ids, timestamp)
+            .await
+    }
+
+    pub async fn truncate_partition(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<PartitionTruncation, IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner.truncate_partition(
+            session.get_user_id(),
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+
+        let truncation = topic.truncate_partition(partition_id, offset).await?;
+        self.metrics.decrement_segments(truncation.deleted_segments);
+        self.metrics.decrement_messages(truncation.deleted_messages);
+        Ok(truncation)
+    }
 }
@@ -0,0 +1,156 @@
+use crate::streaming::scheduling::scheduled_message::ScheduledMessage;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::messages::delivery_schedule::DeliverySchedule;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::{error, info, warn};
+
+impl System {
+    pub(crate) async fn load_scheduled_messages(&self) -> Result<(), IggyError> {
+        let count = self.storage.scheduled_message.count().await?;
+        self.metrics.increment_scheduled_messages(count);
+        if count > 0 {
+            info!("Loaded {count} scheduled message(s) waiting for the delivery.");
+        }
+        Ok(())
+    }
+
+    /// Holds the messages having the delivery schedule in the future until they're due, and returns the remaining ones,
+    /// which should be appended to the topic right away. The messages scheduled further than the configured maximum delay
+    /// are rejected.
+    pub(crate) async fn schedule_messages(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partitioning: &Partitioning,
+        messages: Vec<Message>,
+        wait_for_durability: bool,
+    ) -> Result<(Vec<Message>, Vec<u32>), IggyError> {
+        let now = IggyTimestamp::now().to_micros();
+        let max_delay = self.message_scheduler.max_delay;
+        let mut scheduled_messages = Vec::new();
+        let mut scheduled_indexes = Vec::new();
+        let mut messages_to_append = Vec::with_capacity(messages.len());
+        for (index, mut message) in messages.into_iter().enumerate() {
+            let Some(delivery_schedule) = message.get_delivery_schedule()? else {
+                messages_to_append.push(message);
+                continue;
+            };
+
+            let deliver_at = delivery_schedule.get_deliver_at(now);
+            if deliver_at <= now {
+                messages_to_append.push(message);
+                continue;
+            }
+
+            if !max_delay.is_zero() && deliver_at - now > max_delay.as_micros() {
+                return Err(IggyError::TooDistantMessageDelivery(deliver_at));
+            }
+
+            // The delay is resolved once, so that the message is not delayed again when it's delivered.
+            DeliverySchedule::At(deliver_at).inject(&mut message.headers)?;
+            scheduled_indexes.push(index as u32);
+            scheduled_messages.push(ScheduledMessage::new(
+                deliver_at,
+                stream_id,
+                topic_id,
+                partitioning.clone(),
+                message,
+            ));
+        }
+
+        if scheduled_messages.is_empty() {
+            return Ok((messages_to_append, scheduled_indexes));
+        }
+
+        for scheduled_message in &scheduled_messages {
+            self.storage
+                .scheduled_message
+                .save(scheduled_message)
+                .await?;
+        }
+        if wait_for_durability {
+            self.storage.scheduled_message.flush().await?;
+        }
+        self.metrics
+            .increment_scheduled_messages(scheduled_messages.len() as u64);
+        Ok((messages_to_append, scheduled_indexes))
+    }
+
+    /// Appends the due scheduled messages to their topics using the original partitioning, and returns their count.
+    ///
+    /// The delivery is at-least-once: the messages are removed from the schedule only after they're appended,
+    /// without waiting for the durability, so the ones appended right before the crash might be delivered again.
+    pub async fn deliver_scheduled_messages(&self, count: u32) -> Result<u64, IggyError> {
+        let now = IggyTimestamp::now().to_micros();
+        let scheduled_messages = self.storage.scheduled_message.load_due(now, count).await?;
+        if scheduled_messages.is_empty() {
+            return Ok(0);
+        }
+
+        // The messages sharing the same target are appended in a single batch, keeping their delivery order.
+        let mut batches: Vec<(u32, u32, Partitioning, Vec<ScheduledMessage>)> = Vec::new();
+        for scheduled_message in scheduled_messages {
+            let batch = batches
+                .iter_mut()
+                .find(|(stream_id, topic_id, partitioning, _)| {
+                    *stream_id == scheduled_message.stream_id
+                        && *topic_id == scheduled_message.topic_id
+                        && *partitioning == scheduled_message.partitioning
+                });
+            match batch {
+                Some((_, _, _, batch)) => batch.push(scheduled_message),
+                None => batches.push((
+                    scheduled_message.stream_id,
+                    scheduled_message.topic_id,
+                    scheduled_message.partitioning.clone(),
+                    vec![scheduled_message],
+                )),
+            }
+        }
+
+        let mut delivered_messages_count = 0;
+        for (stream_id, topic_id, partitioning, scheduled_messages) in batches {
+            let messages = scheduled_messages
+                .iter()
+                .map(|scheduled_message| scheduled_message.message.clone())
+                .collect::<Vec<_>>();
+            match self
+                .append_messages_bypass_auth(
+                    stream_id,
+                    topic_id,
+                    partitioning,
+                    None,
+                    messages,
+                    false,
+                )
+                .await
+            {
+                Ok(_) => delivered_messages_count += scheduled_messages.len() as u64,
+                Err(IggyError::StreamIdNotFound(_)) | Err(IggyError::TopicIdNotFound(_, _)) => {
+                    warn!(
+                        "Dropping {} scheduled message(s) for the deleted stream with ID: {stream_id} or topic with ID: {topic_id}.",
+                        scheduled_messages.len()
+                    );
+                }
+                Err(err) => {
+                    error!("Failed to deliver {} scheduled message(s) for stream with ID: {stream_id}, topic with ID: {topic_id}. Error: {err}", scheduled_messages.len());
+                    continue;
+                }
+            }
+
+            for scheduled_message in &scheduled_messages {
+                self.storage
+                    .scheduled_message
+                    .delete(scheduled_message)
+                    .await?;
+                self.metrics.decrement_scheduled_messages(1);
+            }
+        }
+
+        self.metrics
+            .increment_delivered_scheduled_messages(delivered_messages_count);
+        Ok(delivered_messages_count)
+    }
+}
@@ -0,0 +1,183 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::Message;
+use iggy::models::schema::Schema;
+use iggy::schemas::schema_definition::SchemaDefinition;
+use iggy::schemas::schema_id::{extract_schema_id, inject_schema_id};
+use iggy::utils::schema_compatibility::SchemaCompatibility;
+use iggy::utils::schema_type::SchemaType;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::{info, warn};
+
+impl System {
+    pub(crate) async fn load_schemas(&mut self) -> Result<(), IggyError> {
+        let schemas = self.storage.schema.load_all().await?;
+        let mut count = 0;
+        for schema in schemas {
+            let topic_exists = self
+                .get_stream(&Identifier::numeric(schema.stream_id)?)
+                .and_then(|stream| stream.get_topic(&Identifier::numeric(schema.topic_id)?))
+                .is_ok();
+            if !topic_exists {
+                warn!(
+                    "Schema with ID: {} was registered for the non-existing topic with ID: {} for stream with ID: {}, removing it.",
+                    schema.id, schema.topic_id, schema.stream_id
+                );
+                self.storage.schema.delete(&schema).await?;
+                continue;
+            }
+
+            self.schemas.load(schema)?;
+            count += 1;
+        }
+
+        if count > 0 {
+            info!("Loaded {count} schema(s) from disk.");
+        }
+        Ok(())
+    }
+
+    pub fn get_schema(&self, session: &Session, schema_id: u32) -> Result<&Schema, IggyError> {
+        self.ensure_authenticated(session)?;
+        let schema = &self
+            .schemas
+            .get(schema_id)
+            .ok_or(IggyError::SchemaNotFound(schema_id))?
+            .schema;
+        self.permissioner
+            .get_topic(session.get_user_id(), schema.stream_id, schema.topic_id)?;
+        Ok(schema)
+    }
+
+    pub fn get_schemas(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<&Schema>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .get_topic(session.get_user_id(), stream.stream_id, topic.topic_id)?;
+        Ok(self
+            .schemas
+            .get_for_topic(stream.stream_id, topic.topic_id)
+            .into_iter()
+            .map(|registered| &registered.schema)
+            .collect())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn register_schema(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        compatibility: SchemaCompatibility,
+        validation_enabled: bool,
+        definition: String,
+    ) -> Result<Schema, IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        let stream_id = stream.stream_id;
+        let topic_id = topic.topic_id;
+        self.permissioner
+            .update_topic(session.get_user_id(), stream_id, topic_id)?;
+
+        let parsed_definition = SchemaDefinition::parse(schema_type, &definition)?;
+        let version = match self.schemas.get_latest(stream_id, topic_id) {
+            Some(latest) => {
+                parsed_definition.check_compatibility(&latest.definition, compatibility)?;
+                latest.schema.version + 1
+            }
+            None => 1,
+        };
+
+        let schema = Schema {
+            id: self.schemas.next_id(),
+            stream_id,
+            topic_id,
+            version,
+            schema_type,
+            compatibility,
+            validation_enabled,
+            created_at: IggyTimestamp::now().to_micros(),
+            definition,
+        };
+        self.storage.schema.save(&schema).await?;
+        self.schemas.add(schema.clone(), parsed_definition);
+        info!(
+            "Registered {} schema with ID: {}, version: {} for topic with ID: {} for stream with ID: {}.",
+            schema.schema_type, schema.id, schema.version, topic_id, stream_id
+        );
+        Ok(schema)
+    }
+
+    pub(crate) async fn delete_schemas_for_topic(
+        &mut self,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        for schema in self.schemas.remove_for_topic(stream_id, topic_id) {
+            self.storage.schema.delete(&schema).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn delete_schemas_for_stream(
+        &mut self,
+        stream_id: u32,
+    ) -> Result<(), IggyError> {
+        for schema in self.schemas.remove_for_stream(stream_id) {
+            self.storage.schema.delete(&schema).await?;
+        }
+        Ok(())
+    }
+
+    /// Stamps the ID of the latest topic schema on the messages which don't reference any schema,
+    /// and validates the payloads against the referenced schema if its validation is enabled.
+    pub(crate) fn apply_schemas(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        let latest = self.schemas.get_latest(stream_id, topic_id);
+        for message in messages.iter_mut() {
+            let registered = match extract_schema_id(&message.headers)? {
+                Some(schema_id) => self
+                    .schemas
+                    .get(schema_id)
+                    .filter(|registered| {
+                        registered.schema.stream_id == stream_id
+                            && registered.schema.topic_id == topic_id
+                    })
+                    .ok_or(IggyError::SchemaNotRegisteredForTopic(
+                        schema_id, topic_id, stream_id,
+                    ))?,
+                None => match latest {
+                    Some(latest) => {
+                        inject_schema_id(&mut message.headers, latest.schema.id)?;
+                        latest
+                    }
+                    None => continue,
+                },
+            };
+
+            if registered.schema.validation_enabled {
+                registered
+                    .definition
+                    .decode(&message.payload)
+                    .map_err(|error| {
+                        IggyError::InvalidSchemaPayload(registered.schema.id, error)
+                    })?;
+            }
+        }
+        Ok(())
+    }
+}
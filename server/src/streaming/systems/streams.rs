@@ -5,6 +5,7 @@ use futures::future::try_join_all;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::text;
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -200,6 +201,7 @@ impl System {
         session: &Session,
         id: &Identifier,
         name: &str,
+        topic_config_overrides: Option<TopicConfigOverrides>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let stream_id;
@@ -225,6 +227,14 @@ impl System {
             let stream = self.get_stream_mut(id)?;
             old_name = stream.name.clone();
             stream.name.clone_from(&updated_name);
+            // The clients which don't send the overrides keep the current ones.
+            if let Some(topic_config_overrides) = topic_config_overrides {
+                if stream.topic_config_overrides != topic_config_overrides {
+                    stream
+                        .update_topic_config_overrides(topic_config_overrides)
+                        .await?;
+                }
+            }
             stream.persist().await?;
         }
 
@@ -268,6 +278,7 @@ impl System {
             CURRENT_STREAM_ID.store(stream_id, Ordering::SeqCst);
         }
 
+        self.delete_schemas_for_stream(stream_id).await?;
         let client_manager = self.client_manager.read().await;
         client_manager
             .delete_consumer_groups_for_stream(stream_id)
@@ -290,7 +301,7 @@ impl System {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::configs::server::PersonalAccessTokenConfig;
+    use crate::configs::server::{MessageSchedulerConfig, PersonalAccessTokenConfig};
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
     use crate::streaming::users::user::User;
@@ -306,8 +317,13 @@ mod tests {
         let stream_name = "test";
         let config = Arc::new(SystemConfig::default());
         let storage = get_test_system_storage();
-        let mut system =
-            System::create(config, storage, None, PersonalAccessTokenConfig::default());
+        let mut system = System::create(
+            config,
+            storage,
+            None,
+            PersonalAccessTokenConfig::default(),
+            MessageSchedulerConfig::default(),
+        );
         let root = User::root(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD);
         let session = Session::new(
             1,
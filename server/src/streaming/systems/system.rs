@@ -1,9 +1,10 @@
-use crate::configs::server::PersonalAccessTokenConfig;
+use crate::configs::server::{MessageSchedulerConfig, PersonalAccessTokenConfig};
 use crate::configs::system::SystemConfig;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::clients::client_manager::ClientManager;
-use crate::streaming::diagnostics::metrics::Metrics;
+use crate::streaming::diagnostics::metrics::{ConsumerLagLabels, Metrics};
 use crate::streaming::persistence::persister::*;
+use crate::streaming::schemas::registry::SchemaRegistry;
 use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
@@ -38,6 +39,10 @@ impl SharedSystem {
         self.system.read()
     }
 
+    pub fn try_read(&self) -> Option<SharedReadLock<'_, System>> {
+        self.system.try_read()
+    }
+
     pub fn write(&self) -> SharedWriteLock<System> {
         self.system.write()
     }
@@ -62,7 +67,9 @@ pub struct System {
     pub(crate) encryptor: Option<Box<dyn Encryptor>>,
     pub(crate) metrics: Metrics,
     pub(crate) db: Option<Arc<Db>>,
+    pub(crate) schemas: SchemaRegistry,
     pub personal_access_token: PersonalAccessTokenConfig,
+    pub message_scheduler: MessageSchedulerConfig,
 }
 
 /// For each cache eviction, we want to remove more than the size we need.
@@ -74,6 +81,7 @@ impl System {
         config: Arc<SystemConfig>,
         db: Option<Arc<Db>>,
         pat_config: PersonalAccessTokenConfig,
+        scheduler_config: MessageSchedulerConfig,
     ) -> System {
         let db = match db {
             Some(db) => db,
@@ -94,6 +102,7 @@ impl System {
             SystemStorage::new(db.clone(), persister),
             Some(db),
             pat_config,
+            scheduler_config,
         )
     }
 
@@ -102,6 +111,7 @@ impl System {
         storage: SystemStorage,
         db: Option<Arc<Db>>,
         pat_config: PersonalAccessTokenConfig,
+        scheduler_config: MessageSchedulerConfig,
     ) -> System {
         info!(
             "Server-side encryption is {}.",
@@ -122,7 +132,9 @@ impl System {
             permissioner: Permissioner::default(),
             metrics: Metrics::init(),
             db,
+            schemas: SchemaRegistry::default(),
             personal_access_token: pat_config,
+            message_scheduler: scheduler_config,
         }
     }
 
@@ -155,6 +167,8 @@ impl System {
         self.load_version().await?;
         self.load_users().await?;
         self.load_streams().await?;
+        self.load_schemas().await?;
+        self.load_scheduled_messages().await?;
         info!("Initialized system in {} ms.", now.elapsed().as_millis());
         Ok(())
     }
@@ -174,6 +188,45 @@ impl System {
         Ok(saved_messages_number)
     }
 
+    pub async fn sync_group_commit_messages(&self) -> Result<usize, IggyError> {
+        trace!("Syncing the written messages of group commit topics...");
+        let mut synced_partitions_number = 0;
+        for stream in self.streams.values() {
+            synced_partitions_number += stream.sync_group_commit_messages().await?;
+        }
+
+        Ok(synced_partitions_number)
+    }
+
+    /// Returns the metrics in the Prometheus text format, including the consumer lag computed at the time of the call.
+    pub async fn get_formatted_metrics(&self) -> String {
+        self.metrics.clear_consumer_lag();
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    let partition = partition.read().await;
+                    let offsets = partition
+                        .consumer_offsets
+                        .iter()
+                        .chain(partition.consumer_group_offsets.iter());
+                    for offset in offsets {
+                        let labels = ConsumerLagLabels {
+                            stream_id: partition.stream_id,
+                            topic_id: partition.topic_id,
+                            partition_id: partition.partition_id,
+                            consumer_kind: offset.kind.to_string(),
+                            consumer_id: offset.consumer_id,
+                        };
+                        let lag = partition.current_offset.saturating_sub(offset.offset);
+                        self.metrics.set_consumer_lag(&labels, lag);
+                    }
+                }
+            }
+        }
+
+        self.metrics.get_formatted_output()
+    }
+
     pub fn ensure_authenticated(&self, session: &Session) -> Result<(), IggyError> {
         match session.is_authenticated() {
             true => Ok(()),
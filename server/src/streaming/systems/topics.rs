@@ -5,7 +5,9 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::durability::Durability;
 
 impl System {
     pub fn find_topic(
@@ -46,6 +48,8 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: Option<u8>,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -63,6 +67,8 @@ impl System {
                 compression_algorithm,
                 max_topic_size,
                 replication_factor.unwrap_or(1),
+                durability,
+                config_overrides,
             )
             .await?;
         self.metrics.increment_topics(1);
@@ -82,6 +88,8 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: Option<u8>,
+        durability: Option<Durability>,
+        config_overrides: Option<TopicConfigOverrides>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -102,6 +110,8 @@ impl System {
                 compression_algorithm,
                 max_topic_size,
                 replication_factor.unwrap_or(1),
+                durability,
+                config_overrides,
             )
             .await?;
 
@@ -141,6 +151,8 @@ impl System {
         self.metrics.decrement_messages(topic.get_messages_count());
         self.metrics
             .decrement_segments(topic.get_segments_count().await);
+        self.delete_schemas_for_topic(stream_id_value, topic.topic_id)
+            .await?;
         let client_manager = self.client_manager.read().await;
         client_manager
             .delete_consumer_groups_for_topic(stream_id_value, topic.topic_id)
@@ -64,6 +64,27 @@ impl ConsumerGroup {
         ))
     }
 
+    pub async fn is_partition_assigned(
+        &self,
+        member_id: u32,
+        partition_id: u32,
+    ) -> Result<bool, IggyError> {
+        let member = self.members.get(&member_id);
+        if let Some(member) = member {
+            return Ok(member
+                .read()
+                .await
+                .partitions
+                .values()
+                .any(|id| *id == partition_id));
+        }
+        Err(IggyError::ConsumerGroupMemberNotFound(
+            member_id,
+            self.group_id,
+            self.topic_id,
+        ))
+    }
+
     pub async fn add_member(&mut self, member_id: u32) {
         self.members.insert(
             member_id,
@@ -196,6 +217,39 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn should_check_if_partition_is_assigned_to_the_member() {
+        let member1_id = 123;
+        let member2_id = 456;
+        let mut consumer_group = ConsumerGroup {
+            topic_id: 1,
+            group_id: 1,
+            name: "test".to_string(),
+            partitions_count: 2,
+            members: HashMap::new(),
+        };
+
+        consumer_group.add_member(member1_id).await;
+        consumer_group.add_member(member2_id).await;
+        let mut assigned_partitions = 0;
+        for partition_id in 1..=consumer_group.partitions_count {
+            let assigned_to_member1 = consumer_group
+                .is_partition_assigned(member1_id, partition_id)
+                .await
+                .unwrap();
+            let assigned_to_member2 = consumer_group
+                .is_partition_assigned(member2_id, partition_id)
+                .await
+                .unwrap();
+            assert_ne!(assigned_to_member1, assigned_to_member2);
+            if assigned_to_member1 {
+                assigned_partitions += 1;
+            }
+        }
+        assert_eq!(assigned_partitions, 1);
+        assert!(consumer_group.is_partition_assigned(789, 1).await.is_err());
+    }
+
     #[tokio::test]
     async fn should_assign_partitions_to_the_multiple_members() {
         let member1_id = 123;
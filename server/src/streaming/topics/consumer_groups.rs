@@ -194,6 +194,7 @@ mod tests {
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
     use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::models::topic_config_overrides::TopicConfigOverrides;
     use std::sync::atomic::{AtomicU32, AtomicU64};
     use std::sync::Arc;
 
@@ -367,6 +368,8 @@ mod tests {
             compression_algorithm,
             None,
             1,
+            None,
+            TopicConfigOverrides::default(),
         )
         .unwrap()
     }
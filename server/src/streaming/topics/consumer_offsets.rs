@@ -1,10 +1,14 @@
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::topics::topic::Topic;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offsets_reset::ConsumerOffsetsReset;
 
 impl Topic {
     pub async fn store_consumer_offset(
@@ -31,13 +35,76 @@ impl Topic {
         })
     }
 
+    pub async fn get_consumer_lag(&self, consumer: &Consumer) -> Result<ConsumerLag, IggyError> {
+        let consumer_id = self.resolve_consumer_id(consumer).await?;
+        let mut partitions = Vec::with_capacity(self.partitions.len());
+        for partition_id in self.get_sorted_partition_ids() {
+            let partition = self.partitions.get(&partition_id).unwrap().read().await;
+            partitions.push(
+                partition
+                    .get_consumer_lag(consumer.kind, consumer_id)
+                    .await?,
+            );
+        }
+
+        Ok(ConsumerLag {
+            kind: consumer.kind,
+            consumer_id,
+            total_lag: partitions.iter().map(|partition| partition.lag).sum(),
+            partitions,
+        })
+    }
+
+    pub async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        strategy: OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerOffsetsReset, IggyError> {
+        let consumer_id = self.resolve_consumer_id(consumer).await?;
+        let mut partitions = Vec::with_capacity(self.partitions.len());
+        for partition_id in self.get_sorted_partition_ids() {
+            let partition = self.partitions.get(&partition_id).unwrap().read().await;
+            partitions.push(
+                partition
+                    .reset_consumer_offset(consumer.kind, consumer_id, strategy, dry_run)
+                    .await?,
+            );
+        }
+
+        Ok(ConsumerOffsetsReset {
+            kind: consumer.kind,
+            consumer_id,
+            dry_run,
+            partitions,
+        })
+    }
+
+    async fn resolve_consumer_id(&self, consumer: &Consumer) -> Result<u32, IggyError> {
+        match consumer.kind {
+            ConsumerKind::Consumer => Ok(PollingConsumer::resolve_consumer_id(&consumer.id)),
+            ConsumerKind::ConsumerGroup => {
+                Ok(self.get_consumer_group(&consumer.id)?.read().await.group_id)
+            }
+        }
+    }
+
+    fn get_sorted_partition_ids(&self) -> Vec<u32> {
+        let mut partition_ids = self.partitions.keys().copied().collect::<Vec<u32>>();
+        partition_ids.sort();
+        partition_ids
+    }
+
     async fn resolve_partition(
         &self,
         consumer: PollingConsumer,
     ) -> Result<&IggySharedMut<Partition>, IggyError> {
         let partition_id = match consumer {
             PollingConsumer::Consumer(_, partition_id) => Ok(partition_id),
-            PollingConsumer::ConsumerGroup(group_id, member_id) => {
+            // The offset belongs to the group, so it can be stored for the partition which is no longer assigned
+            // to the member, e.g. to commit the messages consumed before the rebalancing.
+            PollingConsumer::ConsumerGroup(_, _, Some(partition_id)) => Ok(partition_id),
+            PollingConsumer::ConsumerGroup(group_id, member_id, None) => {
                 let consumer_group = self.get_consumer_group_by_id(group_id)?.read().await;
                 consumer_group.get_current_partition_id(member_id).await
             }
@@ -1,14 +1,16 @@
 use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::persistence::durability::DurabilityWaiter;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::sizeable::Sizeable;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::utils::file::folder_size;
-use crate::streaming::utils::hash;
+use bytes::Bytes;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, PartitioningKind};
+use iggy::models::appended_messages::AppendedMessages;
 use iggy::models::messages::PolledMessages;
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
@@ -68,12 +70,27 @@ impl Topic {
         partitioning: Partitioning,
         messages: Vec<Message>,
     ) -> Result<(), IggyError> {
+        self.append_messages_with_durability(batch_size, partitioning, None, messages, false)
+            .await?;
+        Ok(())
+    }
+
+    /// Appends the messages and, if requested, returns the waiter which completes once the messages are durable.
+    /// The messages key is taken from the partitioning, unless it's provided explicitly.
+    pub async fn append_messages_with_durability(
+        &self,
+        batch_size: u64,
+        partitioning: Partitioning,
+        messages_key: Option<Bytes>,
+        messages: Vec<Message>,
+        wait_for_durability: bool,
+    ) -> Result<(AppendedMessages, Option<DurabilityWaiter>), IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
         }
 
         if messages.is_empty() {
-            return Ok(());
+            return Ok((AppendedMessages::default(), None));
         }
 
         let partition_id = match partitioning.kind {
@@ -86,8 +103,13 @@ impl Topic {
             }
         };
 
-        let appendable_batch_info = AppendableBatchInfo::new(batch_size, partition_id);
-        self.append_messages_to_partition(appendable_batch_info, messages)
+        let messages_key = match partitioning.kind {
+            PartitioningKind::MessagesKey => Some(Bytes::from(partitioning.value)),
+            _ => messages_key,
+        };
+        let appendable_batch_info =
+            AppendableBatchInfo::new(batch_size, partition_id).with_messages_key(messages_key);
+        self.append_messages_to_partition(appendable_batch_info, messages, wait_for_durability)
             .await
     }
 
@@ -95,9 +117,10 @@ impl Topic {
         &self,
         appendable_batch_info: AppendableBatchInfo,
         messages: Vec<Message>,
-    ) -> Result<(), IggyError> {
+        wait_for_durability: bool,
+    ) -> Result<(AppendedMessages, Option<DurabilityWaiter>), IggyError> {
         let partition = self.partitions.get(&appendable_batch_info.partition_id);
-        partition
+        let mut partition = partition
             .ok_or_else(|| {
                 IggyError::PartitionNotFound(
                     appendable_batch_info.partition_id,
@@ -106,11 +129,17 @@ impl Topic {
                 )
             })?
             .write()
-            .await
+            .await;
+        let appended_messages = partition
             .append_messages(appendable_batch_info, messages)
             .await?;
 
-        Ok(())
+        if !wait_for_durability {
+            return Ok((appended_messages, None));
+        }
+
+        let durability_waiter = partition.ensure_durability().await?;
+        Ok((appended_messages, durability_waiter))
     }
 
     fn get_next_partition_id(&self) -> u32 {
@@ -125,18 +154,16 @@ impl Topic {
         partition_id
     }
 
-    fn calculate_partition_id_by_messages_key_hash(&self, messages_key: &[u8]) -> u32 {
-        let messages_key_hash = hash::calculate_32(messages_key);
+    pub(crate) fn calculate_partition_id_by_messages_key_hash(&self, messages_key: &[u8]) -> u32 {
         let partitions_count = self.get_partitions_count();
-        let mut partition_id = messages_key_hash % partitions_count;
-        if partition_id == 0 {
-            partition_id = partitions_count;
-        }
+        let partition_id = self
+            .partitioning_scheme
+            .calculate_partition_id(messages_key, partitions_count);
         trace!(
-            "Calculated partition ID: {} for messages key: {:?}, hash: {}",
+            "Calculated partition ID: {} for messages key: {:?} using {} partitioning scheme",
             partition_id,
             messages_key,
-            messages_key_hash
+            self.partitioning_scheme
         );
         partition_id
     }
@@ -228,6 +255,22 @@ impl Topic {
         true
     }
 
+    /// Closes the last segments of the partitions, which reached the maximum age, returns the number of closed segments.
+    pub async fn close_aged_segments(&self, now: u64) -> Result<u32, IggyError> {
+        let mut closed_segments = 0;
+        if self.config.segment.max_age.is_zero() {
+            return Ok(closed_segments);
+        }
+
+        for partition in self.partitions.values() {
+            if partition.write().await.close_aged_segment(now).await? {
+                closed_segments += 1;
+            }
+        }
+
+        Ok(closed_segments)
+    }
+
     pub async fn get_expired_segments_start_offsets_per_partition(
         &self,
         now: u64,
@@ -254,8 +297,11 @@ mod tests {
     use super::*;
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use crate::streaming::utils::hash;
     use bytes::Bytes;
     use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::models::topic_config_overrides::TopicConfigOverrides;
+    use iggy::utils::partitioning_scheme::PartitioningScheme;
     use std::sync::atomic::AtomicU32;
     use std::sync::atomic::AtomicU64;
     use std::sync::Arc;
@@ -357,7 +403,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_jump_hash_partitioning_scheme_keys_should_stay_in_place_when_partitions_are_added() {
+        let partitions_count = 3;
+        let messages_count = 1000;
+        let mut topic = init_topic_with_overrides(
+            partitions_count,
+            TopicConfigOverrides {
+                partitioning_scheme: Some(PartitioningScheme::JumpHash),
+                ..Default::default()
+            },
+        );
+        assert_eq!(topic.partitioning_scheme, PartitioningScheme::JumpHash);
+
+        let keys = (1..=messages_count)
+            .map(Partitioning::messages_key_u32)
+            .collect::<Vec<_>>();
+        let partition_ids = keys
+            .iter()
+            .map(|key| topic.calculate_partition_id_by_messages_key_hash(&key.value))
+            .collect::<Vec<_>>();
+        topic.add_partitions(1).unwrap();
+
+        for (key, partition_id) in keys.iter().zip(partition_ids) {
+            let new_partition_id = topic.calculate_partition_id_by_messages_key_hash(&key.value);
+            assert_eq!(
+                new_partition_id,
+                PartitioningScheme::JumpHash.calculate_partition_id(&key.value, 4)
+            );
+            if new_partition_id != partition_id {
+                assert_eq!(new_partition_id, 4);
+            }
+        }
+    }
+
     fn init_topic(partitions_count: u32) -> Topic {
+        init_topic_with_overrides(partitions_count, TopicConfigOverrides::default())
+    }
+
+    fn init_topic_with_overrides(
+        partitions_count: u32,
+        config_overrides: TopicConfigOverrides,
+    ) -> Topic {
         let storage = Arc::new(get_test_system_storage());
         let stream_id = 1;
         let id = 2;
@@ -382,6 +469,8 @@ mod tests {
             compression_algorithm,
             None,
             1,
+            None,
+            config_overrides,
         )
         .unwrap()
     }
@@ -3,6 +3,8 @@ use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::partition_offsets::PartitionOffsets;
+use iggy::models::partition_truncation::PartitionTruncation;
 
 const MAX_PARTITIONS_COUNT: u32 = 100_000;
 
@@ -40,6 +42,7 @@ impl Topic {
                 self.size_of_parent_stream.clone(),
                 self.size_bytes.clone(),
                 self.segments_count_of_parent_stream.clone(),
+                self.durability,
             );
             self.partitions
                 .insert(partition_id, IggySharedMut::new(partition));
@@ -87,6 +90,47 @@ impl Topic {
             messages_count,
         }))
     }
+
+    pub async fn get_offsets_for_timestamp(
+        &self,
+        partition_ids: &[u32],
+        timestamp: u64,
+    ) -> Result<Vec<PartitionOffsets>, IggyError> {
+        let partition_ids = match partition_ids.is_empty() {
+            true => {
+                let mut partition_ids = self.partitions.keys().copied().collect::<Vec<u32>>();
+                partition_ids.sort();
+                partition_ids
+            }
+            false => partition_ids.to_vec(),
+        };
+
+        let mut offsets = Vec::with_capacity(partition_ids.len());
+        for partition_id in partition_ids {
+            let partition =
+                self.partitions
+                    .get(&partition_id)
+                    .ok_or(IggyError::PartitionNotFound(
+                        partition_id,
+                        self.topic_id,
+                        self.stream_id,
+                    ))?;
+            let partition = partition.read().await;
+            offsets.push(partition.get_offsets_for_timestamp(timestamp).await?);
+        }
+
+        Ok(offsets)
+    }
+
+    pub async fn truncate_partition(
+        &self,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<PartitionTruncation, IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let mut partition = partition.write().await;
+        partition.truncate(offset).await
+    }
 }
 
 pub struct DeletedPartitions {
@@ -2,6 +2,7 @@ use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::durability::Durability;
 use tokio::sync::RwLock;
 
 impl Topic {
@@ -42,14 +43,29 @@ impl Topic {
         let mut saved_messages_number = 0;
         for partition in self.get_partitions() {
             let mut partition = partition.write().await;
-            for segment in partition.get_segments_mut() {
-                saved_messages_number += segment.persist_messages().await?;
-            }
+            saved_messages_number += partition.persist_messages().await?;
         }
 
         Ok(saved_messages_number)
     }
 
+    pub async fn sync_group_commit_messages(&self) -> Result<usize, IggyError> {
+        if self.durability != Durability::GroupCommit {
+            return Ok(0);
+        }
+
+        let mut synced_partitions_number = 0;
+        for partition in self.get_partitions() {
+            let partition = partition.read().await;
+            if partition.durability_tracker.has_pending_sync() {
+                partition.sync_messages().await?;
+                synced_partitions_number += 1;
+            }
+        }
+
+        Ok(synced_partitions_number)
+    }
+
     pub async fn purge(&self) -> Result<(), IggyError> {
         for partition in self.get_partitions() {
             let mut partition = partition.write().await;
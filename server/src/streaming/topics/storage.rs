@@ -1,7 +1,7 @@
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::storage::{Storage, TopicStorage};
 use crate::streaming::topics::consumer_group::ConsumerGroup;
-use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::topic::{apply_config_overrides, Topic};
 use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::join_all;
@@ -9,7 +9,10 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::durability::Durability;
+use iggy::utils::partitioning_scheme::PartitioningScheme;
 use serde::{Deserialize, Serialize};
 use sled::Db;
 use std::path::Path;
@@ -143,6 +146,12 @@ struct TopicData {
     compression_algorithm: CompressionAlgorithm,
     max_topic_size: Option<IggyByteSize>,
     replication_factor: u8,
+    #[serde(default)]
+    durability: Option<Durability>,
+    #[serde(default)]
+    config_overrides: TopicConfigOverrides,
+    #[serde(default)]
+    partitioning_scheme: Option<PartitioningScheme>,
 }
 
 #[async_trait]
@@ -183,6 +192,15 @@ impl Storage<Topic> for FileTopicStorage {
         topic.compression_algorithm = topic_data.compression_algorithm;
         topic.max_topic_size = topic_data.max_topic_size;
         topic.replication_factor = topic_data.replication_factor;
+        topic.durability = topic_data
+            .durability
+            .unwrap_or(topic.config.partition.durability);
+        // The topic is created with the configuration of its stream, so the overrides are applied on top of it.
+        topic.config_overrides = topic_data.config_overrides;
+        (topic.config, topic.storage) =
+            apply_config_overrides(&topic.config, &topic.storage, &topic.config_overrides);
+        // The topics created before the partitioning scheme was introduced always use the modulo one.
+        topic.partitioning_scheme = topic_data.partitioning_scheme.unwrap_or_default();
 
         let dir_entries = fs::read_dir(&topic.partitions_path).await
             .with_context(|| format!("Failed to read partition with ID: {} for stream with ID: {} for topic with ID: {} and path: {}",
@@ -220,6 +238,7 @@ impl Storage<Topic> for FileTopicStorage {
                 topic.size_of_parent_stream.clone(),
                 topic.size_bytes.clone(),
                 topic.segments_count_of_parent_stream.clone(),
+                topic.durability,
             );
             unloaded_partitions.push(partition);
         }
@@ -286,6 +305,9 @@ impl Storage<Topic> for FileTopicStorage {
             compression_algorithm: topic.compression_algorithm,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
+            durability: Some(topic.durability),
+            config_overrides: topic.config_overrides,
+            partitioning_scheme: Some(topic.partitioning_scheme),
         })
         .with_context(|| format!("Failed to serialize topic with key: {key}"))
         {
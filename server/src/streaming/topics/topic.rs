@@ -5,8 +5,11 @@ use crate::streaming::topics::consumer_group::ConsumerGroup;
 use core::fmt;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
-use iggy::locking::IggySharedMut;
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::durability::Durability;
+use iggy::utils::partitioning_scheme::PartitioningScheme;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
@@ -36,6 +39,9 @@ pub struct Topic {
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: Option<IggyByteSize>,
     pub replication_factor: u8,
+    pub durability: Durability,
+    pub partitioning_scheme: PartitioningScheme,
+    pub config_overrides: TopicConfigOverrides,
     pub created_at: u64,
 }
 
@@ -63,6 +69,8 @@ impl Topic {
             Default::default(),
             None,
             1,
+            None,
+            TopicConfigOverrides::default(),
         )
         .unwrap()
     }
@@ -82,7 +90,10 @@ impl Topic {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        durability: Option<Durability>,
+        config_overrides: TopicConfigOverrides,
     ) -> Result<Topic, IggyError> {
+        let (config, storage) = apply_config_overrides(&config, &storage, &config_overrides);
         let path = config.get_topic_path(stream_id, topic_id);
         let partitions_path = config.get_partitions_path(stream_id, topic_id);
         let mut topic = Topic {
@@ -115,6 +126,9 @@ impl Topic {
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            durability: durability.unwrap_or(config.partition.durability),
+            partitioning_scheme: config.topic.partitioning_scheme,
+            config_overrides,
             config,
             created_at: IggyTimestamp::now().to_micros(),
         };
@@ -123,6 +137,28 @@ impl Topic {
         Ok(topic)
     }
 
+    /// Replaces the config overrides of the topic and applies them on top of the configuration and storage of the stream.
+    /// The new configuration is propagated to all the partitions and their segments.
+    pub async fn update_config_overrides(
+        &mut self,
+        parent_config: &Arc<SystemConfig>,
+        parent_storage: &Arc<SystemStorage>,
+        config_overrides: TopicConfigOverrides,
+    ) -> Result<(), IggyError> {
+        let (config, storage) =
+            apply_config_overrides(parent_config, parent_storage, &config_overrides);
+        self.config_overrides = config_overrides;
+        self.config = config;
+        self.storage = storage;
+        for partition in self.partitions.values() {
+            let mut partition = partition.write().await;
+            partition
+                .update_config(self.config.clone(), self.storage.clone())
+                .await?;
+        }
+        Ok(())
+    }
+
     pub fn get_size(&self) -> IggyByteSize {
         IggyByteSize::from(self.size_bytes.load(Ordering::SeqCst))
     }
@@ -156,10 +192,34 @@ impl fmt::Display for Topic {
         write!(f, "partitions count: {:?}, ", self.partitions.len())?;
         write!(f, "message expiry (s): {:?}, ", self.message_expiry)?;
         write!(f, "max topic size (B): {:?}, ", max_topic_size)?;
-        write!(f, "replication factor: {}, ", self.replication_factor)
+        write!(f, "replication factor: {}, ", self.replication_factor)?;
+        write!(f, "durability: {}, ", self.durability)?;
+        write!(f, "partitioning scheme: {}, ", self.partitioning_scheme)
     }
 }
 
+/// Returns the configuration and storage derived from the parent ones (server or stream) with the overrides applied.
+/// The parent storage is wrapped only if the overrides enforce the fsync, which the parent one doesn't.
+/// The fsync enforced by the parent can't be disabled, as its storage syncs every write on its own.
+pub(crate) fn apply_config_overrides(
+    parent_config: &Arc<SystemConfig>,
+    parent_storage: &Arc<SystemStorage>,
+    overrides: &TopicConfigOverrides,
+) -> (Arc<SystemConfig>, Arc<SystemStorage>) {
+    if overrides.is_empty() {
+        return (parent_config.clone(), parent_storage.clone());
+    }
+
+    let mut config = parent_config.with_topic_config_overrides(overrides);
+    config.partition.enforce_fsync |= parent_config.partition.enforce_fsync;
+    let storage = if config.partition.enforce_fsync == parent_config.partition.enforce_fsync {
+        parent_storage.clone()
+    } else {
+        Arc::new(parent_storage.with_enforced_fsync())
+    };
+    (Arc::new(config), storage)
+}
+
 #[cfg(test)]
 mod tests {
     use iggy::locking::IggySharedMutFn;
@@ -199,6 +259,8 @@ mod tests {
             compression_algorithm,
             Some(max_topic_size),
             replication_factor,
+            None,
+            TopicConfigOverrides::default(),
         )
         .unwrap();
 
@@ -217,4 +279,87 @@ mod tests {
             assert_eq!(partition.segments.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn config_overrides_should_be_applied_to_partitions() {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SystemConfig::default());
+        let config_overrides = TopicConfigOverrides {
+            segment_size: Some(IggyByteSize::from_str("1 MB").unwrap()),
+            enforce_fsync: Some(!config.partition.enforce_fsync),
+            ..Default::default()
+        };
+
+        let mut topic = Topic::create(
+            1,
+            2,
+            "test",
+            2,
+            config.clone(),
+            storage.clone(),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            None,
+            CompressionAlgorithm::None,
+            None,
+            1,
+            None,
+            config_overrides,
+        )
+        .unwrap();
+
+        assert_eq!(topic.config_overrides, config_overrides);
+        assert_eq!(
+            topic.config.segment.size,
+            config_overrides.segment_size.unwrap()
+        );
+        assert_ne!(
+            topic.config.partition.enforce_fsync,
+            config.partition.enforce_fsync
+        );
+        assert!(!Arc::ptr_eq(&topic.storage, &storage));
+        for partition in topic.get_partitions() {
+            let partition = partition.read().await;
+            assert_eq!(
+                partition.config.segment.size,
+                config_overrides.segment_size.unwrap()
+            );
+            assert!(Arc::ptr_eq(&partition.storage, &topic.storage));
+        }
+
+        topic
+            .update_config_overrides(&config, &storage, TopicConfigOverrides::default())
+            .await
+            .unwrap();
+
+        assert!(topic.config_overrides.is_empty());
+        assert!(Arc::ptr_eq(&topic.config, &config));
+        assert!(Arc::ptr_eq(&topic.storage, &storage));
+        for partition in topic.get_partitions() {
+            let partition = partition.read().await;
+            assert_eq!(partition.config.segment.size, config.segment.size);
+            for segment in partition.get_segments() {
+                assert_eq!(segment.config.segment.size, config.segment.size);
+            }
+        }
+    }
+
+    #[test]
+    fn fsync_enforced_by_parent_should_not_be_disabled_by_overrides() {
+        let storage = Arc::new(get_test_system_storage());
+        let mut config = SystemConfig::default();
+        config.partition.enforce_fsync = true;
+        let config = Arc::new(config);
+        let config_overrides = TopicConfigOverrides {
+            enforce_fsync: Some(false),
+            ..Default::default()
+        };
+
+        let (topic_config, topic_storage) =
+            apply_config_overrides(&config, &storage, &config_overrides);
+
+        assert!(topic_config.partition.enforce_fsync);
+        assert!(Arc::ptr_eq(&topic_storage, &storage));
+    }
 }
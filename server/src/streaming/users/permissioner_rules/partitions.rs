@@ -19,4 +19,13 @@ impl Permissioner {
     ) -> Result<(), IggyError> {
         self.update_topic(user_id, stream_id, topic_id)
     }
+
+    pub fn truncate_partition(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.purge_topic(user_id, stream_id, topic_id)
+    }
 }
@@ -22,6 +22,17 @@ pub(crate) async fn handle_connection(
     let client_id = system.read().add_client(&address, Transport::Tcp).await;
 
     let session = Session::from_client_id(client_id, address);
+    handle_requests(&session, sender, system, Transport::Tcp).await
+}
+
+/// Reads the binary protocol requests from the stream-based transport (TCP or Unix domain socket)
+/// and handles them within the session until the connection is closed.
+pub(crate) async fn handle_requests(
+    session: &Session,
+    sender: &mut dyn Sender,
+    system: SharedSystem,
+    transport: Transport,
+) -> Result<(), ServerError> {
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
     loop {
         let read_length = match sender.read(&mut initial_buffer).await {
@@ -38,13 +49,13 @@ pub(crate) async fn handle_connection(
 
         if read_length != INITIAL_BYTES_LENGTH {
             sender.send_error_response(IggyError::CommandLengthError(format!(
-                "Unable to read the TCP request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {read_length} bytes."
+                "Unable to read the {transport} request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {read_length} bytes."
             ))).await?;
             continue;
         }
 
         let length = u32::from_le_bytes(initial_buffer);
-        debug!("Received a TCP request, length: {length}");
+        debug!("Received a {transport} request, length: {length}");
         let mut command_buffer = BytesMut::with_capacity(length as usize);
         command_buffer.put_bytes(0, length as usize);
         sender.read(&mut command_buffer).await?;
@@ -55,9 +66,9 @@ pub(crate) async fn handle_connection(
                 continue;
             }
         };
-        debug!("Received a TCP command: {command}, payload size: {length}");
-        command::handle(command, sender, &session, system.clone()).await?;
-        debug!("Sent a TCP response.");
+        debug!("Received a {transport} command: {command}, payload size: {length}");
+        command::handle(command, sender, session, system.clone(), transport).await?;
+        debug!("Sent a {transport} response.");
     }
 }
 
@@ -0,0 +1,2 @@
+mod unix_sender;
+pub mod unix_server;
@@ -0,0 +1,121 @@
+use crate::configs::unix::UnixConfig;
+use crate::server_error::ServerError;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::{handle_error, handle_requests};
+use crate::unix::unix_sender::UnixSender;
+use std::collections::HashMap;
+use std::fs::{DirBuilder, Permissions};
+use std::io::ErrorKind;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::net::{UnixListener, UnixStream};
+use tracing::{error, info, warn};
+
+/// Starts the Unix domain socket listener serving the binary protocol on the configured path.
+pub async fn start(config: UnixConfig, system: SharedSystem) {
+    info!("Initializing Iggy Unix domain socket server...");
+    let path = Path::new(&config.path);
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            panic!(
+                "Unable to start Unix domain socket server, the path: {} is not a socket.",
+                config.path
+            );
+        }
+
+        warn!("Removing the stale Unix domain socket: {}", config.path);
+        std::fs::remove_file(path).expect("Unable to remove the stale Unix domain socket.");
+    }
+
+    // The permissions and peer users have been already validated.
+    let permissions = config.get_permissions().unwrap_or_default();
+    let listener = bind(path, permissions).expect("Unable to start Unix domain socket server.");
+    let peer_users = Arc::new(config.get_peer_users().unwrap_or_default());
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let system = system.clone();
+                    let peer_users = peer_users.clone();
+                    tokio::spawn(async move {
+                        let client_id = system
+                            .read()
+                            .add_client_without_address(Transport::Unix)
+                            .await;
+                        info!("Accepted new Unix domain socket connection for client with ID: {client_id}");
+                        if let Err(error) =
+                            handle_connection(client_id, stream, system.clone(), &peer_users).await
+                        {
+                            handle_error(error);
+                            system.read().delete_client_by_id(client_id).await;
+                        }
+                    });
+                }
+                Err(error) => error!("Unable to accept Unix domain socket, error: {error}"),
+            }
+        }
+    });
+    info!(
+        "Iggy Unix domain socket server has started on: {}",
+        config.path
+    );
+}
+
+/// Binds the socket in the private (0700) directory next to the configured path, and moves it there
+/// once its permissions are set, so it's never accessible with the default ones (derived from the umask).
+fn bind(path: &Path, permissions: u32) -> std::io::Result<UnixListener> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let file_name = path.file_name().ok_or_else(|| {
+        std::io::Error::new(ErrorKind::InvalidInput, "Invalid Unix domain socket path.")
+    })?;
+    let private_directory = parent.join(format!(
+        ".{}.{}",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+    DirBuilder::new().mode(0o700).create(&private_directory)?;
+    let private_path = private_directory.join(file_name);
+    let result = UnixListener::bind(&private_path).and_then(|listener| {
+        std::fs::set_permissions(&private_path, Permissions::from_mode(permissions))?;
+        std::fs::rename(&private_path, path)?;
+        Ok(listener)
+    });
+    let _ = std::fs::remove_file(&private_path);
+    std::fs::remove_dir(&private_directory)?;
+    result
+}
+
+async fn handle_connection(
+    client_id: u32,
+    stream: UnixStream,
+    system: SharedSystem,
+    peer_users: &HashMap<u32, String>,
+) -> Result<(), ServerError> {
+    // The peers connected via the socket have no network address, the client ID identifies the session.
+    let session = Session::from_client_id(client_id, SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)));
+    if let Some(username) = stream
+        .peer_cred()
+        .ok()
+        .and_then(|credentials| peer_users.get(&credentials.uid()))
+    {
+        // The peer process is trusted by its UID, so the user is logged in without the password.
+        if let Err(error) = system
+            .read()
+            .login_user_with_credentials(username, None, Some(&session))
+            .await
+        {
+            warn!("Unable to authenticate Unix domain socket peer as user: {username}, error: {error}");
+        }
+    }
+
+    let mut sender = UnixSender { stream };
+    handle_requests(&session, &mut sender, system, Transport::Unix).await
+}
@@ -3,6 +3,7 @@ use iggy::clients::client::IggyClient;
 use iggy::error::IggyError;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::topic_config_overrides::TopicConfigOverrides;
 use iggy::utils::expiry::IggyExpiry;
 use rand::Rng;
 use std::collections::HashMap;
@@ -40,6 +41,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await?;
 
@@ -53,6 +56,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await?;
 
@@ -66,6 +71,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await?;
 
@@ -79,6 +86,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await?;
 
@@ -92,6 +101,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 None,
+                None,
+                TopicConfigOverrides::default(),
             )
             .await?;
     }